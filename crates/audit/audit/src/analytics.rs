@@ -625,6 +625,7 @@ mod tests {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         };
 
@@ -669,6 +670,7 @@ mod tests {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         };
         let result = analytics.query_analytics(&query).await.unwrap();
@@ -695,6 +697,7 @@ mod tests {
             to: None,
             group_by: Some("outcome".to_string()),
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         };
 
@@ -727,6 +730,7 @@ mod tests {
             to: None,
             group_by: None,
             top_n: Some(3),
+            drill_into: None,
             tenant_scope: Vec::new(),
         };
 
@@ -759,6 +763,7 @@ mod tests {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         };
 
@@ -794,6 +799,7 @@ mod tests {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         };
 
@@ -822,6 +828,7 @@ mod tests {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         };
 
@@ -872,6 +879,7 @@ mod tests {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         };
 
@@ -0,0 +1,563 @@
+//! Batched audit writes.
+//!
+//! This module provides a [`BatchingAuditStore`] wrapper that queues audit
+//! records and writes them to the inner store in batches, flushing when a
+//! batch reaches [`AuditBatchConfig::max_batch_size`] records or when
+//! [`AuditBatchConfig::flush_interval`] has passed since the first queued
+//! record, whichever comes first.
+//!
+//! A single background task drains the queue and writes batches one at a
+//! time, in the order records were queued. Records of the same
+//! `(namespace, tenant)` therefore reach the inner store in dispatch order,
+//! which [`HashChainAuditStore`](crate::HashChainAuditStore) relies on to
+//! link each record to its predecessor. Wrap the batching store *outside*
+//! any hash chain or compliance decorators. The hash chain decorator still
+//! writes its records one at a time, since each needs the current chain tip;
+//! the other decorators pass batches through to the backend's bulk insert.
+//!
+//! A batch the inner store rejects is retried, then written one record at a
+//! time, so a single bad record costs only itself. Records that still fail
+//! are reported to the drop hook given to
+//! [`BatchingAuditStore::with_drop_hook`].
+//!
+//! Batching trades durability for throughput: queued records are lost if
+//! the process dies before they are flushed. It must not be combined with
+//! synchronous audit writes.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+use tracing::{error, warn};
+
+use crate::analytics::AnalyticsStore;
+use crate::error::AuditError;
+use crate::record::{AuditPage, AuditQuery, AuditRecord};
+use crate::store::AuditStore;
+
+/// What to do when the batching queue is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverflowPolicy {
+    /// Wait for space in the queue, slowing down the writer. On the dispatch
+    /// path this stalls dispatches behind a slow audit backend.
+    Block,
+    /// Fail the write immediately with [`AuditError::Storage`].
+    #[default]
+    Reject,
+}
+
+/// Configuration for batched audit writes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditBatchConfig {
+    /// Flush once this many records are buffered.
+    #[serde(default = "default_max_batch_size")]
+    pub max_batch_size: usize,
+
+    /// Flush once the oldest buffered record has waited this long.
+    #[serde(default = "default_flush_interval", with = "duration_ms")]
+    pub flush_interval: Duration,
+
+    /// Maximum number of records waiting to be batched.
+    #[serde(default = "default_queue_capacity")]
+    pub queue_capacity: usize,
+
+    /// Behavior when the queue is full.
+    #[serde(default)]
+    pub overflow: OverflowPolicy,
+}
+
+fn default_max_batch_size() -> usize {
+    100
+}
+
+fn default_flush_interval() -> Duration {
+    Duration::from_millis(100)
+}
+
+fn default_queue_capacity() -> usize {
+    10_000
+}
+
+impl Default for AuditBatchConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: default_max_batch_size(),
+            flush_interval: default_flush_interval(),
+            queue_capacity: default_queue_capacity(),
+            overflow: OverflowPolicy::default(),
+        }
+    }
+}
+
+impl AuditBatchConfig {
+    /// Set the number of records that triggers a flush.
+    #[must_use]
+    pub fn with_max_batch_size(mut self, size: usize) -> Self {
+        self.max_batch_size = size;
+        self
+    }
+
+    /// Set the maximum time a record may wait before being flushed.
+    #[must_use]
+    pub fn with_flush_interval(mut self, interval: Duration) -> Self {
+        self.flush_interval = interval;
+        self
+    }
+
+    /// Set the capacity of the queue in front of the batcher.
+    #[must_use]
+    pub fn with_queue_capacity(mut self, capacity: usize) -> Self {
+        self.queue_capacity = capacity;
+        self
+    }
+
+    /// Set the behavior when the queue is full.
+    #[must_use]
+    pub fn with_overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+mod duration_ms {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn serialize<S: Serializer>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(value.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
+
+/// Attempts at writing a batch in one call before falling back to writing
+/// its records one at a time.
+const BATCH_WRITE_ATTEMPTS: u32 = 3;
+
+/// Delay before the first batch retry; doubles after each failed attempt.
+const BATCH_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Callback told how many records a flush dropped.
+type DropHook = Arc<dyn Fn(u64) + Send + Sync>;
+
+enum Message {
+    Record(Box<AuditRecord>),
+    Flush(oneshot::Sender<()>),
+}
+
+/// An [`AuditStore`] wrapper that coalesces writes into batches.
+///
+/// [`record`](AuditStore::record) only queues the record; a background task
+/// hands full batches to the inner store's
+/// [`record_batch`](AuditStore::record_batch). Reads go straight to the inner
+/// store and do not see records that are still queued; call
+/// [`flush`](Self::flush) first when that matters.
+pub struct BatchingAuditStore {
+    inner: Arc<dyn AuditStore>,
+    sender: mpsc::Sender<Message>,
+    overflow: OverflowPolicy,
+}
+
+impl BatchingAuditStore {
+    /// Wrap `inner` and spawn the background flush task.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn new(inner: Arc<dyn AuditStore>, config: &AuditBatchConfig) -> Self {
+        Self::with_drop_hook(inner, config, |_| {})
+    }
+
+    /// Like [`new`](Self::new), but calls `on_drop` with the number of
+    /// records lost whenever a batch and the per-record fallback both fail,
+    /// e.g. to count them in a metric.
+    pub fn with_drop_hook(
+        inner: Arc<dyn AuditStore>,
+        config: &AuditBatchConfig,
+        on_drop: impl Fn(u64) + Send + Sync + 'static,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel(config.queue_capacity.max(1));
+        tokio::spawn(run_batcher(
+            Arc::clone(&inner),
+            receiver,
+            config.max_batch_size.max(1),
+            config.flush_interval,
+            Arc::new(on_drop),
+        ));
+        Self {
+            inner,
+            sender,
+            overflow: config.overflow,
+        }
+    }
+
+    /// Write every record queued so far to the inner store.
+    ///
+    /// Returns once those records have been flushed. Call this on shutdown to
+    /// avoid losing buffered records.
+    pub async fn flush(&self) {
+        let (done, flushed) = oneshot::channel();
+        if self.sender.send(Message::Flush(done)).await.is_ok() {
+            let _ = flushed.await;
+        }
+    }
+}
+
+#[async_trait]
+impl AuditStore for BatchingAuditStore {
+    async fn record(&self, entry: AuditRecord) -> Result<(), AuditError> {
+        let message = Message::Record(Box::new(entry));
+        match self.overflow {
+            OverflowPolicy::Block => self
+                .sender
+                .send(message)
+                .await
+                .map_err(|_| AuditError::Storage("audit batcher stopped".to_owned())),
+            OverflowPolicy::Reject => self.sender.try_send(message).map_err(|e| match e {
+                mpsc::error::TrySendError::Full(_) => {
+                    AuditError::Storage("audit batch queue full".to_owned())
+                }
+                mpsc::error::TrySendError::Closed(_) => {
+                    AuditError::Storage("audit batcher stopped".to_owned())
+                }
+            }),
+        }
+    }
+
+    async fn get_by_action_id(&self, action_id: &str) -> Result<Option<AuditRecord>, AuditError> {
+        self.inner.get_by_action_id(action_id).await
+    }
+
+    async fn get_by_id(&self, id: &str) -> Result<Option<AuditRecord>, AuditError> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn query(&self, query: &AuditQuery) -> Result<AuditPage, AuditError> {
+        self.inner.query(query).await
+    }
+
+    async fn cleanup_expired(&self) -> Result<u64, AuditError> {
+        self.inner.cleanup_expired().await
+    }
+
+    /// Flushes queued records first so a hold also covers records that are
+    /// still waiting in the batch.
+    async fn clear_expiry(&self, action_id: &str) -> Result<u64, AuditError> {
+        self.flush().await;
+        self.inner.clear_expiry(action_id).await
+    }
+
+    fn analytics(&self) -> Option<Arc<dyn AnalyticsStore>> {
+        self.inner.analytics()
+    }
+}
+
+/// Drain `receiver`, writing batches to `inner` until every sender is gone.
+async fn run_batcher(
+    inner: Arc<dyn AuditStore>,
+    mut receiver: mpsc::Receiver<Message>,
+    max_batch_size: usize,
+    flush_interval: Duration,
+    on_drop: DropHook,
+) {
+    let mut batch: Vec<AuditRecord> = Vec::with_capacity(max_batch_size);
+    let mut deadline = Instant::now();
+    loop {
+        let message = if batch.is_empty() {
+            receiver.recv().await
+        } else if let Ok(message) = tokio::time::timeout_at(deadline, receiver.recv()).await {
+            message
+        } else {
+            write_batch(inner.as_ref(), &mut batch, on_drop.as_ref()).await;
+            continue;
+        };
+
+        match message {
+            Some(Message::Record(record)) => {
+                if batch.is_empty() {
+                    deadline = Instant::now() + flush_interval;
+                }
+                batch.push(*record);
+                if batch.len() >= max_batch_size {
+                    write_batch(inner.as_ref(), &mut batch, on_drop.as_ref()).await;
+                }
+            }
+            Some(Message::Flush(done)) => {
+                write_batch(inner.as_ref(), &mut batch, on_drop.as_ref()).await;
+                let _ = done.send(());
+            }
+            None => {
+                write_batch(inner.as_ref(), &mut batch, on_drop.as_ref()).await;
+                return;
+            }
+        }
+    }
+}
+
+/// Write `batch` to `inner`, retrying the whole batch before falling back to
+/// one write per record so a single bad record does not take the rest of the
+/// batch down with it.
+async fn write_batch(
+    inner: &dyn AuditStore,
+    batch: &mut Vec<AuditRecord>,
+    on_drop: &(dyn Fn(u64) + Send + Sync),
+) {
+    if batch.is_empty() {
+        return;
+    }
+    let records = std::mem::take(batch);
+    let count = records.len();
+    let mut backoff = BATCH_RETRY_BACKOFF;
+    for attempt in 1..=BATCH_WRITE_ATTEMPTS {
+        match inner.record_batch(records.clone()).await {
+            Ok(()) => return,
+            Err(e) => warn!(error = %e, count, attempt, "batched audit write failed"),
+        }
+        if attempt < BATCH_WRITE_ATTEMPTS {
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+    }
+
+    let mut dropped = 0;
+    for record in records {
+        let id = record.id.clone();
+        if let Err(e) = inner.record(record).await {
+            error!(error = %e, record_id = %id, "audit record dropped");
+            dropped += 1;
+        }
+    }
+    if dropped > 0 {
+        on_drop(dropped);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::Utc;
+
+    use super::*;
+
+    /// Records every batch it receives.
+    #[derive(Default)]
+    struct BatchRecorder {
+        batches: Mutex<Vec<Vec<AuditRecord>>>,
+        /// A record the store refuses, failing any write that contains it.
+        poison: Option<String>,
+    }
+
+    impl BatchRecorder {
+        fn batch_sizes(&self) -> Vec<usize> {
+            self.batches.lock().unwrap().iter().map(Vec::len).collect()
+        }
+
+        fn ids_for(&self, tenant: &str) -> Vec<String> {
+            self.batches
+                .lock()
+                .unwrap()
+                .iter()
+                .flatten()
+                .filter(|r| r.tenant == tenant)
+                .map(|r| r.id.clone())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl AuditStore for BatchRecorder {
+        async fn record(&self, entry: AuditRecord) -> Result<(), AuditError> {
+            self.record_batch(vec![entry]).await
+        }
+
+        async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+            if entries.iter().any(|r| Some(&r.id) == self.poison.as_ref()) {
+                return Err(AuditError::Storage("bad record".to_owned()));
+            }
+            self.batches.lock().unwrap().push(entries);
+            Ok(())
+        }
+
+        async fn get_by_action_id(&self, _: &str) -> Result<Option<AuditRecord>, AuditError> {
+            Ok(None)
+        }
+
+        async fn get_by_id(&self, _: &str) -> Result<Option<AuditRecord>, AuditError> {
+            Ok(None)
+        }
+
+        async fn query(&self, _: &AuditQuery) -> Result<AuditPage, AuditError> {
+            Ok(AuditPage {
+                records: Vec::new(),
+                total: Some(0),
+                limit: 0,
+                offset: 0,
+                next_cursor: None,
+            })
+        }
+
+        async fn cleanup_expired(&self) -> Result<u64, AuditError> {
+            Ok(0)
+        }
+    }
+
+    fn make_record(id: &str, tenant: &str) -> AuditRecord {
+        let now = Utc::now();
+        AuditRecord {
+            id: id.into(),
+            action_id: format!("action-{id}"),
+            chain_id: None,
+            namespace: "ns".into(),
+            tenant: tenant.into(),
+            provider: "email".into(),
+            action_type: "send".into(),
+            verdict: "allow".into(),
+            matched_rule: None,
+            outcome: "executed".into(),
+            action_payload: None,
+            verdict_details: serde_json::json!({}),
+            outcome_details: serde_json::json!({}),
+            metadata: serde_json::json!({}),
+            dispatched_at: now,
+            completed_at: now,
+            duration_ms: 0,
+            expires_at: None,
+            caller_id: String::new(),
+            auth_method: String::new(),
+            record_hash: None,
+            previous_hash: None,
+            sequence_number: None,
+            attachment_metadata: Vec::new(),
+            signature: None,
+            signer_id: None,
+            kid: None,
+            canonical_hash: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn preserves_order_within_tenant() {
+        let inner = Arc::new(BatchRecorder::default());
+        let store = BatchingAuditStore::new(
+            Arc::clone(&inner) as Arc<dyn AuditStore>,
+            &AuditBatchConfig::default().with_max_batch_size(7),
+        );
+
+        for i in 0..50 {
+            let tenant = if i % 3 == 0 { "tenant-a" } else { "tenant-b" };
+            store
+                .record(make_record(&format!("{i:03}"), tenant))
+                .await
+                .unwrap();
+        }
+        store.flush().await;
+
+        for tenant in ["tenant-a", "tenant-b"] {
+            let ids = inner.ids_for(tenant);
+            let mut sorted = ids.clone();
+            sorted.sort();
+            assert_eq!(ids, sorted, "{tenant} records reordered");
+        }
+        assert_eq!(inner.batch_sizes().iter().sum::<usize>(), 50);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn flushes_when_batch_is_full() {
+        let inner = Arc::new(BatchRecorder::default());
+        let store = BatchingAuditStore::new(
+            Arc::clone(&inner) as Arc<dyn AuditStore>,
+            &AuditBatchConfig::default()
+                .with_max_batch_size(3)
+                .with_flush_interval(Duration::from_secs(3600)),
+        );
+
+        for i in 0..7 {
+            store
+                .record(make_record(&i.to_string(), "t"))
+                .await
+                .unwrap();
+        }
+        // Let the batcher drain the queue without reaching the interval.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(inner.batch_sizes(), vec![3, 3]);
+
+        store.flush().await;
+        assert_eq!(inner.batch_sizes(), vec![3, 3, 1]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn flushes_after_interval() {
+        let inner = Arc::new(BatchRecorder::default());
+        let store = BatchingAuditStore::new(
+            Arc::clone(&inner) as Arc<dyn AuditStore>,
+            &AuditBatchConfig::default()
+                .with_max_batch_size(100)
+                .with_flush_interval(Duration::from_secs(5)),
+        );
+
+        store.record(make_record("1", "t")).await.unwrap();
+        store.record(make_record("2", "t")).await.unwrap();
+        tokio::time::sleep(Duration::from_secs(4)).await;
+        assert!(inner.batch_sizes().is_empty());
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        assert_eq!(inner.batch_sizes(), vec![2]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_batch_falls_back_to_single_records() {
+        let inner = Arc::new(BatchRecorder {
+            poison: Some("2".into()),
+            ..BatchRecorder::default()
+        });
+        let dropped = Arc::new(std::sync::atomic::AtomicU64::new(0));
+        let counter = Arc::clone(&dropped);
+        let store = BatchingAuditStore::with_drop_hook(
+            Arc::clone(&inner) as Arc<dyn AuditStore>,
+            &AuditBatchConfig::default(),
+            move |n| {
+                counter.fetch_add(n, std::sync::atomic::Ordering::Relaxed);
+            },
+        );
+
+        for i in 1..=4 {
+            store
+                .record(make_record(&i.to_string(), "t"))
+                .await
+                .unwrap();
+        }
+        store.flush().await;
+
+        // Only the bad record is lost, and it is counted.
+        assert_eq!(inner.ids_for("t"), vec!["1", "3", "4"]);
+        assert_eq!(dropped.load(std::sync::atomic::Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn reject_policy_fails_when_queue_is_full() {
+        // A current-thread runtime never polls the batcher while this test
+        // holds the thread, so the queue fills up deterministically.
+        let inner = Arc::new(BatchRecorder::default());
+        let store = BatchingAuditStore::new(
+            Arc::clone(&inner) as Arc<dyn AuditStore>,
+            &AuditBatchConfig::default()
+                .with_queue_capacity(2)
+                .with_overflow(OverflowPolicy::Reject),
+        );
+
+        store.record(make_record("1", "t")).await.unwrap();
+        store.record(make_record("2", "t")).await.unwrap();
+        let err = store.record(make_record("3", "t")).await.unwrap_err();
+        assert!(err.to_string().contains("queue full"));
+
+        store.flush().await;
+        assert_eq!(inner.ids_for("t"), vec!["1", "2"]);
+    }
+}
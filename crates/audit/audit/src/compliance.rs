@@ -18,7 +18,7 @@ use async_trait::async_trait;
 use sha2::{Digest, Sha256};
 use tracing::warn;
 
-use acteon_core::compliance::{ComplianceConfig, HashChainVerification};
+use acteon_core::compliance::{ComplianceConfig, HashChainVerification, RecordHashVerification};
 
 use crate::analytics::AnalyticsStore;
 use crate::error::AuditError;
@@ -74,6 +74,21 @@ impl HashChainAuditStore {
         format!("{:x}", hasher.finalize())
     }
 
+    /// Recompute the hash of a single record and compare it to the stored one.
+    ///
+    /// Only the record's own content is checked; its link to the previous
+    /// record is left to [`verify_chain`](Self::verify_chain).
+    pub fn verify_record(record: &AuditRecord) -> RecordHashVerification {
+        let computed_hash = Self::compute_record_hash(record);
+        RecordHashVerification {
+            action_id: record.action_id.clone(),
+            record_id: record.id.clone(),
+            valid: record.record_hash.as_ref() == Some(&computed_hash),
+            stored_hash: record.record_hash.clone(),
+            computed_hash,
+        }
+    }
+
     /// Fetch the current chain tip from the inner store (DB).
     ///
     /// Always queries fresh — no local caching — so multiple replicas see a
@@ -293,6 +308,10 @@ impl AuditStore for HashChainAuditStore {
         self.inner.cleanup_expired().await
     }
 
+    async fn clear_expiry(&self, action_id: &str) -> Result<u64, AuditError> {
+        self.inner.clear_expiry(action_id).await
+    }
+
     fn analytics(&self) -> Option<Arc<dyn AnalyticsStore>> {
         self.inner.analytics()
     }
@@ -327,6 +346,10 @@ impl AuditStore for ComplianceAuditStore {
         self.inner.record(entry).await
     }
 
+    async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+        self.inner.record_batch(entries).await
+    }
+
     async fn get_by_action_id(&self, action_id: &str) -> Result<Option<AuditRecord>, AuditError> {
         self.inner.get_by_action_id(action_id).await
     }
@@ -347,6 +370,10 @@ impl AuditStore for ComplianceAuditStore {
         self.inner.cleanup_expired().await
     }
 
+    async fn clear_expiry(&self, action_id: &str) -> Result<u64, AuditError> {
+        self.inner.clear_expiry(action_id).await
+    }
+
     fn analytics(&self) -> Option<Arc<dyn AnalyticsStore>> {
         self.inner.analytics()
     }
@@ -760,6 +787,32 @@ mod tests {
         assert_eq!(result.first_broken_at.as_deref(), Some("r2"));
     }
 
+    #[tokio::test]
+    async fn verify_record_detects_tampered_content() {
+        let inner = Arc::new(MemoryAudit::new());
+        let store = HashChainAuditStore::new(Arc::clone(&inner) as Arc<dyn AuditStore>);
+
+        store.record(make_record("r1", "ns", "t1")).await.unwrap();
+        let mut record = inner.raw_records().remove(0);
+
+        let result = HashChainAuditStore::verify_record(&record);
+        assert!(result.valid);
+        assert_eq!(result.record_id, "r1");
+        assert_eq!(
+            result.stored_hash.as_deref(),
+            Some(result.computed_hash.as_str())
+        );
+
+        record.outcome = "tampered".to_string();
+        let result = HashChainAuditStore::verify_record(&record);
+        assert!(!result.valid);
+        assert_eq!(result.stored_hash, record.record_hash);
+        assert_ne!(
+            result.stored_hash.as_deref(),
+            Some(result.computed_hash.as_str())
+        );
+    }
+
     #[tokio::test]
     async fn verify_chain_detects_broken_link() {
         let inner = Arc::new(MemoryAudit::new());
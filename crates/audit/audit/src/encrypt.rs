@@ -3,6 +3,8 @@
 //! This module provides an [`EncryptingAuditStore`] wrapper that encrypts the
 //! `action_payload` field in audit records before storage and decrypts it on
 //! read. All other audit fields remain in plaintext so they are queryable.
+//! Records whose metadata carries `"__encrypt_payload": false` (written by the
+//! gateway for actions that opted out of encryption) are stored as-is.
 
 use std::sync::Arc;
 
@@ -61,6 +63,20 @@ impl EncryptingAuditStore {
         Ok(payload.clone())
     }
 
+    /// Encrypt the `action_payload` field of a record unless the action
+    /// opted out.
+    fn encrypt_record(&self, mut entry: AuditRecord) -> Result<AuditRecord, AuditError> {
+        let opted_out = entry
+            .metadata
+            .get("__encrypt_payload")
+            .and_then(serde_json::Value::as_bool)
+            == Some(false);
+        if !opted_out && let Some(ref payload) = entry.action_payload {
+            entry.action_payload = Some(self.encrypt_payload(payload)?);
+        }
+        Ok(entry)
+    }
+
     /// Decrypt the `action_payload` field of a record in place.
     fn decrypt_record(&self, record: &mut AuditRecord) -> Result<(), AuditError> {
         if let Some(ref payload) = record.action_payload {
@@ -73,11 +89,15 @@ impl EncryptingAuditStore {
 #[async_trait]
 impl AuditStore for EncryptingAuditStore {
     async fn record(&self, entry: AuditRecord) -> Result<(), AuditError> {
-        let mut encrypted = entry;
-        if let Some(ref payload) = encrypted.action_payload {
-            encrypted.action_payload = Some(self.encrypt_payload(payload)?);
-        }
-        self.inner.record(encrypted).await
+        self.inner.record(self.encrypt_record(entry)?).await
+    }
+
+    async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+        let encrypted = entries
+            .into_iter()
+            .map(|entry| self.encrypt_record(entry))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.inner.record_batch(encrypted).await
     }
 
     async fn get_by_action_id(&self, action_id: &str) -> Result<Option<AuditRecord>, AuditError> {
@@ -112,6 +132,10 @@ impl AuditStore for EncryptingAuditStore {
         self.inner.cleanup_expired().await
     }
 
+    async fn clear_expiry(&self, action_id: &str) -> Result<u64, AuditError> {
+        self.inner.clear_expiry(action_id).await
+    }
+
     fn analytics(&self) -> Option<Arc<dyn AnalyticsStore>> {
         self.inner.analytics()
     }
@@ -228,6 +252,28 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn opted_out_record_is_stored_in_plaintext() {
+        let inner = Arc::new(MemoryAudit::new());
+        let store =
+            EncryptingAuditStore::new(Arc::clone(&inner) as Arc<dyn AuditStore>, test_encryptor());
+
+        let mut heartbeat = make_record("r1", Some(json!({"beat": 1})));
+        heartbeat.metadata = json!({"__encrypt_payload": false});
+        store.record(heartbeat).await.unwrap();
+        // A string label is not the gateway's marker and is ignored.
+        let mut other = make_record("r2", Some(json!({"secret": "x"})));
+        other.metadata = json!({"__encrypt_payload": "false"});
+        store.record(other).await.unwrap();
+
+        let raw = inner.raw_records();
+        assert_eq!(raw[0].action_payload, Some(json!({"beat": 1})));
+        assert!(matches!(
+            &raw[1].action_payload,
+            Some(serde_json::Value::String(s)) if acteon_crypto::is_encrypted(s)
+        ));
+    }
+
     #[tokio::test]
     async fn roundtrip_encrypt_decrypt() {
         let inner = Arc::new(MemoryAudit::new());
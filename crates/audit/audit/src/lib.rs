@@ -1,4 +1,5 @@
 pub mod analytics;
+pub mod batch;
 pub mod compliance;
 pub mod cursor;
 pub mod encrypt;
@@ -8,6 +9,7 @@ pub mod redact;
 pub mod store;
 
 pub use analytics::{AnalyticsStore, InMemoryAnalytics};
+pub use batch::{AuditBatchConfig, BatchingAuditStore, OverflowPolicy};
 pub use compliance::{ComplianceAuditStore, HashChainAuditStore};
 pub use cursor::{AuditCursor, CursorKind};
 pub use encrypt::EncryptingAuditStore;
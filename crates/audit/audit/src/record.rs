@@ -166,6 +166,28 @@ impl AuditRecord {
     }
 }
 
+impl acteon_core::AuditedAction for AuditRecord {
+    fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    fn tenant(&self) -> &str {
+        &self.tenant
+    }
+
+    fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    fn action_type(&self) -> &str {
+        &self.action_type
+    }
+
+    fn metadata(&self) -> &serde_json::Value {
+        &self.metadata
+    }
+}
+
 /// Query parameters for searching audit records.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
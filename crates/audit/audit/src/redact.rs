@@ -141,16 +141,27 @@ impl RedactingAuditStore {
             redactor: Redactor::new(config),
         }
     }
+
+    fn redact_record(&self, mut entry: AuditRecord) -> AuditRecord {
+        if let Some(ref mut payload) = entry.action_payload {
+            self.redactor.redact(payload);
+        }
+        entry
+    }
 }
 
 #[async_trait]
 impl AuditStore for RedactingAuditStore {
     async fn record(&self, entry: AuditRecord) -> Result<(), AuditError> {
-        let mut redacted = entry;
-        if let Some(ref mut payload) = redacted.action_payload {
-            self.redactor.redact(payload);
-        }
-        self.inner.record(redacted).await
+        self.inner.record(self.redact_record(entry)).await
+    }
+
+    async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+        let redacted = entries
+            .into_iter()
+            .map(|entry| self.redact_record(entry))
+            .collect();
+        self.inner.record_batch(redacted).await
     }
 
     async fn get_by_action_id(&self, action_id: &str) -> Result<Option<AuditRecord>, AuditError> {
@@ -169,6 +180,10 @@ impl AuditStore for RedactingAuditStore {
         self.inner.cleanup_expired().await
     }
 
+    async fn clear_expiry(&self, action_id: &str) -> Result<u64, AuditError> {
+        self.inner.clear_expiry(action_id).await
+    }
+
     fn analytics(&self) -> Option<Arc<dyn AnalyticsStore>> {
         self.inner.analytics()
     }
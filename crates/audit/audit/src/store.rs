@@ -14,6 +14,18 @@ pub trait AuditStore: Send + Sync {
     /// Persist an audit record.
     async fn record(&self, entry: AuditRecord) -> Result<(), AuditError>;
 
+    /// Persist several audit records, in order.
+    ///
+    /// The default writes them one at a time and stops at the first error.
+    /// Backends with bulk inserts can override this to write a batch in a
+    /// single round trip, as long as the order within the batch is kept.
+    async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+        for entry in entries {
+            self.record(entry).await?;
+        }
+        Ok(())
+    }
+
     /// Retrieve the most recent audit record for a given action ID.
     async fn get_by_action_id(&self, action_id: &str) -> Result<Option<AuditRecord>, AuditError>;
 
@@ -26,6 +38,17 @@ pub trait AuditStore: Send + Sync {
     /// Remove expired records. Returns the number of records deleted.
     async fn cleanup_expired(&self) -> Result<u64, AuditError>;
 
+    /// Exempt every record for `action_id` from expiry by clearing its
+    /// `expires_at`. Returns the number of records updated.
+    ///
+    /// Used to place legal holds on individual actions. The default reports
+    /// the operation as unsupported so a hold is never silently dropped.
+    async fn clear_expiry(&self, _action_id: &str) -> Result<u64, AuditError> {
+        Err(AuditError::Storage(
+            "this audit backend does not support clearing record expiry".to_owned(),
+        ))
+    }
+
     /// Return a native analytics store if the backend supports one.
     ///
     /// Backends with server-side aggregation (e.g. Postgres, `ClickHouse`)
@@ -417,6 +417,7 @@ mod tests {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         }
     }
@@ -331,6 +331,32 @@ impl AuditStore for ClickHouseAuditStore {
         Ok(())
     }
 
+    /// Write the whole batch in a single `INSERT`.
+    async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut insert = self
+            .client
+            .insert(&self.table)
+            .map_err(|e| AuditError::Storage(e.to_string()))?;
+
+        for entry in entries {
+            insert
+                .write(&AuditInsertRow::from(entry))
+                .await
+                .map_err(|e| AuditError::Storage(e.to_string()))?;
+        }
+
+        insert
+            .end()
+            .await
+            .map_err(|e| AuditError::Storage(e.to_string()))?;
+
+        Ok(())
+    }
+
     async fn get_by_id(&self, id: &str) -> Result<Option<AuditRecord>, AuditError> {
         let sql = format!("SELECT {SELECT_COLUMNS} FROM {} WHERE id = ?", self.table);
 
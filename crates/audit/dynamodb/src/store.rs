@@ -12,6 +12,12 @@ use acteon_audit::store::AuditStore;
 
 use crate::config::DynamoDbAuditConfig;
 
+/// Maximum number of items in one `BatchWriteItem` request.
+const MAX_BATCH_WRITE_ITEMS: usize = 25;
+
+/// How many times unprocessed `BatchWriteItem` items are resubmitted.
+const MAX_UNPROCESSED_RETRIES: u32 = 3;
+
 /// `DynamoDB`-backed implementation of [`AuditStore`].
 ///
 /// Uses a single `DynamoDB` table with `id` as the partition key, three Global
@@ -117,6 +123,51 @@ impl DynamoDbAuditStore {
         }
     }
 
+    /// Write plain (unsequenced) records with `BatchWriteItem`, in chunks of
+    /// [`MAX_BATCH_WRITE_ITEMS`], resubmitting any unprocessed items.
+    async fn put_items(&self, entries: &[AuditRecord]) -> Result<(), AuditError> {
+        for chunk in entries.chunks(MAX_BATCH_WRITE_ITEMS) {
+            let mut requests = chunk
+                .iter()
+                .map(|entry| {
+                    let put = aws_sdk_dynamodb::types::PutRequest::builder()
+                        .set_item(Some(record_to_item(entry)))
+                        .build()
+                        .map_err(|e| AuditError::Storage(e.to_string()))?;
+                    Ok(aws_sdk_dynamodb::types::WriteRequest::builder()
+                        .put_request(put)
+                        .build())
+                })
+                .collect::<Result<Vec<_>, AuditError>>()?;
+
+            for attempt in 0..=MAX_UNPROCESSED_RETRIES {
+                let output = self
+                    .client
+                    .batch_write_item()
+                    .request_items(&self.table_name, requests)
+                    .send()
+                    .await
+                    .map_err(|e| AuditError::Storage(e.to_string()))?;
+                requests = output
+                    .unprocessed_items()
+                    .and_then(|items| items.get(&self.table_name))
+                    .cloned()
+                    .unwrap_or_default();
+                if requests.is_empty() {
+                    break;
+                }
+                if attempt == MAX_UNPROCESSED_RETRIES {
+                    return Err(AuditError::Storage(format!(
+                        "{} audit records left unprocessed by BatchWriteItem",
+                        requests.len()
+                    )));
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(50 << attempt)).await;
+            }
+        }
+        Ok(())
+    }
+
     /// Build filter expressions and attribute values for query filtering.
     ///
     /// Returns `(filter_expression, expression_attribute_values)` for fields
@@ -258,6 +309,20 @@ impl AuditStore for DynamoDbAuditStore {
         Ok(())
     }
 
+    /// Write unsequenced records with `BatchWriteItem`. Hash-chained
+    /// records still go through the per-record fence transaction, in order.
+    async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+        let mut pending = 0;
+        for (i, entry) in entries.iter().enumerate() {
+            if let Some(seq) = entry.sequence_number {
+                self.put_items(&entries[pending..i]).await?;
+                self.record_with_sequence(entry, seq).await?;
+                pending = i + 1;
+            }
+        }
+        self.put_items(&entries[pending..]).await
+    }
+
     async fn get_by_action_id(&self, action_id: &str) -> Result<Option<AuditRecord>, AuditError> {
         let result = self
             .client
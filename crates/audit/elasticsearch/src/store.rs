@@ -167,6 +167,15 @@ struct SearchHit {
     sort: Vec<serde_json::Value>,
 }
 
+/// Response body of the `_bulk` API. Only the fields needed to detect
+/// per-item failures are read.
+#[derive(serde::Deserialize)]
+struct BulkResponse {
+    errors: bool,
+    #[serde(default)]
+    items: Vec<serde_json::Value>,
+}
+
 #[derive(serde::Deserialize)]
 struct DeleteByQueryResponse {
     deleted: u64,
@@ -199,6 +208,59 @@ impl AuditStore for ElasticsearchAuditStore {
         }
     }
 
+    /// Index the whole batch with a single `_bulk` request.
+    async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut body = String::new();
+        for entry in &entries {
+            let action = serde_json::json!({ "index": { "_id": entry.id } });
+            body.push_str(&action.to_string());
+            body.push('\n');
+            body.push_str(
+                &serde_json::to_string(entry)
+                    .map_err(|e| AuditError::Serialization(e.to_string()))?,
+            );
+            body.push('\n');
+        }
+
+        let path = format!("{}/_bulk", self.index);
+        let resp = self
+            .request(reqwest::Method::POST, &path)
+            .header(reqwest::header::CONTENT_TYPE, "application/x-ndjson")
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| AuditError::Storage(e.to_string()))?;
+
+        if !resp.status().is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            return Err(AuditError::Storage(format!(
+                "failed to bulk index audit records: {text}"
+            )));
+        }
+
+        let bulk: BulkResponse = resp
+            .json()
+            .await
+            .map_err(|e| AuditError::Serialization(e.to_string()))?;
+        if bulk.errors {
+            let first_error = bulk
+                .items
+                .iter()
+                .find_map(|item| item.get("index")?.get("error"))
+                .map_or_else(String::new, ToString::to_string);
+            return Err(AuditError::Storage(format!(
+                "failed to bulk index audit records: {first_error}"
+            )));
+        }
+
+        tracing::debug!(count = entries.len(), "audit records bulk indexed");
+        Ok(())
+    }
+
     async fn get_by_action_id(&self, action_id: &str) -> Result<Option<AuditRecord>, AuditError> {
         let path = format!("{}/_search", self.index);
 
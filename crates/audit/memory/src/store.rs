@@ -44,6 +44,17 @@ impl AuditStore for MemoryAuditStore {
         Ok(())
     }
 
+    async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+        for entry in entries {
+            self.action_index
+                .entry(entry.action_id.clone())
+                .or_default()
+                .push(entry.id.clone());
+            self.records.insert(entry.id.clone(), entry);
+        }
+        Ok(())
+    }
+
     async fn get_by_action_id(&self, action_id: &str) -> Result<Option<AuditRecord>, AuditError> {
         let ids = self.action_index.get(action_id);
         let Some(ids) = ids else {
@@ -252,6 +263,20 @@ impl AuditStore for MemoryAuditStore {
 
         Ok(removed)
     }
+
+    async fn clear_expiry(&self, action_id: &str) -> Result<u64, AuditError> {
+        let Some(ids) = self.action_index.get(action_id) else {
+            return Ok(0);
+        };
+        let mut updated = 0u64;
+        for id in ids.value() {
+            if let Some(mut rec) = self.records.get_mut(id) {
+                rec.expires_at = None;
+                updated += 1;
+            }
+        }
+        Ok(updated)
+    }
 }
 
 /// Check if a filter matches a value. `None` filter matches everything.
@@ -346,6 +371,22 @@ mod tests {
         assert_eq!(found.unwrap().action_id, "a1");
     }
 
+    #[tokio::test]
+    async fn record_batch_indexes_every_record() {
+        let store = MemoryAuditStore::new();
+        store
+            .record_batch(vec![
+                make_record("r1", "a1"),
+                make_record("r2", "a2"),
+                make_record("r3", "a1"),
+            ])
+            .await
+            .unwrap();
+
+        assert!(store.get_by_id("r2").await.unwrap().is_some());
+        assert_eq!(store.action_index.get("a1").unwrap().len(), 2);
+    }
+
     #[tokio::test]
     async fn get_by_action_id_returns_most_recent() {
         let store = MemoryAuditStore::new();
@@ -573,6 +614,24 @@ mod tests {
         assert!(store.get_by_id("r3").await.unwrap().is_some());
     }
 
+    #[tokio::test]
+    async fn clear_expiry_exempts_records_from_cleanup() {
+        let store = MemoryAuditStore::new();
+        let mut held = make_record("r1", "held-action");
+        held.expires_at = Some(Utc::now() - Duration::seconds(10));
+        let mut other = make_record("r2", "other-action");
+        other.expires_at = Some(Utc::now() - Duration::seconds(10));
+        store.record(held).await.unwrap();
+        store.record(other).await.unwrap();
+
+        assert_eq!(store.clear_expiry("held-action").await.unwrap(), 1);
+        assert_eq!(store.clear_expiry("missing").await.unwrap(), 0);
+
+        assert_eq!(store.cleanup_expired().await.unwrap(), 1);
+        assert!(store.get_by_id("r1").await.unwrap().is_some());
+        assert!(store.get_by_id("r2").await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn get_nonexistent_returns_none() {
         let store = MemoryAuditStore::new();
@@ -431,6 +431,7 @@ mod tests {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         }
     }
@@ -13,6 +13,23 @@ use crate::analytics::PostgresAnalyticsStore;
 use crate::config::PostgresAuditConfig;
 use crate::migrations;
 
+/// Rows per multi-row `INSERT` in [`AuditStore::record_batch`]. Each row
+/// binds 28 parameters; Postgres allows at most 65535 per statement.
+const MAX_ROWS_PER_INSERT: usize = 1000;
+
+/// Columns written by every audit `INSERT`, in the order
+/// [`PostgresAuditStore::insert_query`] binds them.
+const INSERT_COLUMNS: &str = "
+    id, action_id, chain_id, namespace, tenant, provider, action_type,
+    verdict, matched_rule, outcome,
+    action_payload, verdict_details, outcome_details, metadata,
+    dispatched_at, completed_at, duration_ms, expires_at,
+    caller_id, auth_method,
+    record_hash, previous_hash, sequence_number,
+    attachment_metadata,
+    signature, signer_id, kid, canonical_hash
+";
+
 /// Build `PgConnectOptions` from a [`PostgresAuditConfig`], applying SSL
 /// settings when configured.
 fn build_audit_connect_options(
@@ -87,6 +104,53 @@ impl PostgresAuditStore {
         &self.table
     }
 
+    /// Build one `INSERT` statement writing every record in `entries`.
+    fn insert_query<'a>(
+        &self,
+        entries: &'a [AuditRecord],
+    ) -> sqlx::QueryBuilder<'a, sqlx::Postgres> {
+        let mut builder = sqlx::QueryBuilder::<sqlx::Postgres>::new(format!(
+            "INSERT INTO {} ({INSERT_COLUMNS}) ",
+            self.table
+        ));
+        builder.push_values(entries, |mut row, entry| {
+            #[allow(clippy::cast_possible_wrap)]
+            let duration = entry.duration_ms as i64;
+            #[allow(clippy::cast_possible_wrap)]
+            let sequence_number = entry.sequence_number.map(|n| n as i64);
+
+            row.push_bind(&entry.id)
+                .push_bind(&entry.action_id)
+                .push_bind(&entry.chain_id)
+                .push_bind(&entry.namespace)
+                .push_bind(&entry.tenant)
+                .push_bind(&entry.provider)
+                .push_bind(&entry.action_type)
+                .push_bind(&entry.verdict)
+                .push_bind(&entry.matched_rule)
+                .push_bind(&entry.outcome)
+                .push_bind(&entry.action_payload)
+                .push_bind(&entry.verdict_details)
+                .push_bind(&entry.outcome_details)
+                .push_bind(&entry.metadata)
+                .push_bind(entry.dispatched_at)
+                .push_bind(entry.completed_at)
+                .push_bind(duration)
+                .push_bind(entry.expires_at)
+                .push_bind(&entry.caller_id)
+                .push_bind(&entry.auth_method)
+                .push_bind(&entry.record_hash)
+                .push_bind(&entry.previous_hash)
+                .push_bind(sequence_number)
+                .push_bind(serde_json::Value::Array(entry.attachment_metadata.clone()))
+                .push_bind(&entry.signature)
+                .push_bind(&entry.signer_id)
+                .push_bind(&entry.kid)
+                .push_bind(&entry.canonical_hash);
+        });
+        builder
+    }
+
     /// Create from an existing pool (useful for testing).
     pub async fn from_pool(pool: PgPool, prefix: &str) -> Result<Self, AuditError> {
         migrations::run_migrations(&pool, prefix)
@@ -103,72 +167,36 @@ impl PostgresAuditStore {
 #[async_trait]
 impl AuditStore for PostgresAuditStore {
     async fn record(&self, entry: AuditRecord) -> Result<(), AuditError> {
-        let sql = format!(
-            r"
-            INSERT INTO {} (
-                id, action_id, chain_id, namespace, tenant, provider, action_type,
-                verdict, matched_rule, outcome,
-                action_payload, verdict_details, outcome_details, metadata,
-                dispatched_at, completed_at, duration_ms, expires_at,
-                caller_id, auth_method,
-                record_hash, previous_hash, sequence_number,
-                attachment_metadata,
-                signature, signer_id, kid, canonical_hash
-            ) VALUES (
-                $1, $2, $3, $4, $5, $6, $7,
-                $8, $9, $10,
-                $11, $12, $13, $14,
-                $15, $16, $17, $18,
-                $19, $20,
-                $21, $22, $23,
-                $24,
-                $25, $26, $27, $28
-            )
-            ",
-            self.table
-        );
-
-        #[allow(clippy::cast_possible_wrap)]
-        let duration = entry.duration_ms as i64;
-        #[allow(clippy::cast_possible_wrap)]
-        let sequence_number = entry.sequence_number.map(|n| n as i64);
-
-        sqlx::query(&sql)
-            .bind(&entry.id)
-            .bind(&entry.action_id)
-            .bind(&entry.chain_id)
-            .bind(&entry.namespace)
-            .bind(&entry.tenant)
-            .bind(&entry.provider)
-            .bind(&entry.action_type)
-            .bind(&entry.verdict)
-            .bind(&entry.matched_rule)
-            .bind(&entry.outcome)
-            .bind(&entry.action_payload)
-            .bind(&entry.verdict_details)
-            .bind(&entry.outcome_details)
-            .bind(&entry.metadata)
-            .bind(entry.dispatched_at)
-            .bind(entry.completed_at)
-            .bind(duration)
-            .bind(entry.expires_at)
-            .bind(&entry.caller_id)
-            .bind(&entry.auth_method)
-            .bind(&entry.record_hash)
-            .bind(&entry.previous_hash)
-            .bind(sequence_number)
-            .bind(serde_json::Value::Array(entry.attachment_metadata.clone()))
-            .bind(&entry.signature)
-            .bind(&entry.signer_id)
-            .bind(&entry.kid)
-            .bind(&entry.canonical_hash)
+        self.insert_query(std::slice::from_ref(&entry))
+            .build()
             .execute(&self.pool)
             .await
             .map_err(|e| AuditError::Storage(e.to_string()))?;
-
         Ok(())
     }
 
+    /// Write the batch with multi-row `INSERT`s, one statement per
+    /// [`MAX_ROWS_PER_INSERT`] records, all in one transaction. A failed
+    /// batch therefore leaves nothing behind and can be safely retried or
+    /// rewritten record by record.
+    async fn record_batch(&self, entries: Vec<AuditRecord>) -> Result<(), AuditError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AuditError::Storage(e.to_string()))?;
+        for chunk in entries.chunks(MAX_ROWS_PER_INSERT) {
+            self.insert_query(chunk)
+                .build()
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AuditError::Storage(e.to_string()))?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| AuditError::Storage(e.to_string()))
+    }
+
     async fn get_by_action_id(&self, action_id: &str) -> Result<Option<AuditRecord>, AuditError> {
         let sql = format!(
             "SELECT * FROM {} WHERE action_id = $1 ORDER BY dispatched_at DESC LIMIT 1",
@@ -390,6 +418,21 @@ impl AuditStore for PostgresAuditStore {
         Ok(result.rows_affected())
     }
 
+    async fn clear_expiry(&self, action_id: &str) -> Result<u64, AuditError> {
+        let sql = format!(
+            "UPDATE {} SET expires_at = NULL WHERE action_id = $1",
+            self.table
+        );
+
+        let result = sqlx::query(&sql)
+            .bind(action_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AuditError::Storage(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+
     fn analytics(&self) -> Option<Arc<dyn AnalyticsStore>> {
         Some(Arc::new(PostgresAnalyticsStore::new(
             self.pool.clone(),
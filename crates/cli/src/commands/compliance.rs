@@ -52,6 +52,10 @@ pub async fn run(
                     );
                     info!(immutable_audit = resp.immutable_audit, "Immutable Audit");
                     info!(hash_chain = resp.hash_chain, "Hash Chain");
+                    info!(
+                        forbid_encryption_opt_out = resp.forbid_encryption_opt_out,
+                        "Forbid Encryption Opt-Out"
+                    );
                 }
             }
         }
@@ -48,16 +48,24 @@ pub enum QuotasCommand {
         /// JSON data (string or @file path). When set, all other
         /// --field flags are ignored.
         #[arg(long, conflicts_with_all = [
-            "namespace", "tenant", "provider", "principal", "per_principal",
+            "namespace", "tenant", "org", "member", "provider", "principal", "per_principal",
             "max_actions", "window", "overage_behavior", "description", "label",
         ])]
         data: Option<String>,
         /// Namespace scope.
         #[arg(long, required_unless_present = "data")]
         namespace: Option<String>,
-        /// Tenant scope.
-        #[arg(long, required_unless_present = "data")]
+        /// Tenant scope. Omit for an org-level quota.
+        #[arg(long, required_unless_present_any = ["data", "org"], conflicts_with = "org")]
         tenant: Option<String>,
+        /// Create an org-level quota shared by the `--member` tenants.
+        /// Requires a grant on all tenants and namespaces.
+        #[arg(long, requires = "member")]
+        org: Option<String>,
+        /// Member tenant of the org. Pass multiple times for multiple
+        /// members.
+        #[arg(long, requires = "org")]
+        member: Vec<String>,
         /// Provider scope. Omit for tenant-wide.
         #[arg(long)]
         provider: Option<String>,
@@ -190,6 +198,8 @@ pub async fn run(ops: &OpsClient, args: &QuotasArgs, format: &OutputFormat) -> a
             data,
             namespace,
             tenant,
+            org,
+            member,
             provider,
             principal,
             per_principal,
@@ -206,6 +216,8 @@ pub async fn run(ops: &OpsClient, args: &QuotasArgs, format: &OutputFormat) -> a
                 CreateQuotaRequest {
                     namespace: namespace.clone().unwrap_or_default(),
                     tenant: tenant.clone().unwrap_or_default(),
+                    org: org.clone(),
+                    members: member.clone(),
                     provider: provider.clone(),
                     principal: principal.clone(),
                     per_principal: *per_principal,
@@ -214,6 +226,7 @@ pub async fn run(ops: &OpsClient, args: &QuotasArgs, format: &OutputFormat) -> a
                     overage_behavior: overage_behavior.clone().unwrap_or_default(),
                     description: description.clone(),
                     labels: labels_vec_to_map(label.clone()),
+                    alert_thresholds: Vec::new(),
                 }
             };
             run_create(ops, &req, format).await
@@ -248,6 +261,8 @@ pub async fn run(ops: &OpsClient, args: &QuotasArgs, format: &OutputFormat) -> a
                     enabled: *enabled,
                     per_principal: *per_principal,
                     labels: labels_vec_to_map(label.clone()),
+                    alert_thresholds: None,
+                    members: None,
                 }
             };
             run_update(ops, id, &req, format).await
@@ -48,6 +48,9 @@ pub enum TemplatesCommand {
     Delete {
         /// Template ID.
         id: String,
+        /// Delete even if profiles or rules still reference the template.
+        #[arg(long)]
+        force: bool,
     },
     /// Manage template profiles.
     Profiles(ProfilesArgs),
@@ -191,8 +194,8 @@ pub async fn run(
                 }
             }
         }
-        TemplatesCommand::Delete { id } => {
-            ops.delete_template(id).await?;
+        TemplatesCommand::Delete { id, force } => {
+            ops.delete_template(id, *force).await?;
             info!(id = %id, "Template deleted");
         }
         TemplatesCommand::Profiles(profiles_args) => {
@@ -17,6 +17,7 @@
 use acteon_core::{Task, TaskMessage, TaskPushNotificationConfig};
 
 use crate::dispatch::ErrorResponse;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// `A2A-Version` header carried on every request. Mirrors
@@ -66,9 +67,8 @@ impl ActeonClient {
             .add_auth(self.client.post(&url))
             .header(A2A_VERSION_HEADER, A2A_PROTOCOL_VERSION)
             .json(&body)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<Task>()
                 .await
@@ -94,9 +94,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .header(A2A_VERSION_HEADER, A2A_PROTOCOL_VERSION)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<Task>()
                 .await
@@ -126,9 +125,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .header(A2A_VERSION_HEADER, A2A_PROTOCOL_VERSION)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<Task>()
                 .await
@@ -161,9 +159,8 @@ impl ActeonClient {
             .add_auth(self.client.post(&url))
             .header(A2A_VERSION_HEADER, A2A_PROTOCOL_VERSION)
             .json(body)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<TaskPushNotificationConfig>()
                 .await
@@ -189,9 +186,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .header(A2A_VERSION_HEADER, A2A_PROTOCOL_VERSION)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<Vec<TaskPushNotificationConfig>>()
                 .await
@@ -216,9 +212,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .header(A2A_VERSION_HEADER, A2A_PROTOCOL_VERSION)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<TaskPushNotificationConfig>()
                 .await
@@ -245,9 +240,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.delete(&url))
             .header(A2A_VERSION_HEADER, A2A_PROTOCOL_VERSION)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             Ok(())
         } else {
@@ -275,12 +269,7 @@ impl ActeonClient {
             "{}/a2a/{namespace}/{tenant}/.well-known/agent.json",
             self.base_url
         );
-        let resp = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let resp = self.client.get(&url).send_gated(&self.gate).await?;
         if resp.status().is_success() {
             resp.json::<AgentCard>()
                 .await
@@ -311,9 +300,8 @@ impl ActeonClient {
             .add_auth(self.client.post(&url))
             .header(A2A_VERSION_HEADER, A2A_PROTOCOL_VERSION)
             .json(&envelope)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if !resp.status().is_success() {
             return Err(map_error(resp).await);
         }
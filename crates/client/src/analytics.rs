@@ -3,13 +3,209 @@ pub use acteon_core::analytics::{
     AnalyticsTopEntry,
 };
 
+use chrono::{DateTime, Utc};
+
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
+/// Dimensions analytics can be grouped by.
+pub const ANALYTICS_DIMENSIONS: [&str; 5] =
+    ["namespace", "tenant", "provider", "action_type", "outcome"];
+
+/// Why an [`AnalyticsQueryBuilder`] could not build a query.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum AnalyticsQueryError {
+    #[error("analytics query needs a metric")]
+    MissingMetric,
+    #[error("analytics time range is empty: from {from} is not before to {to}")]
+    EmptyRange {
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    },
+    #[error(
+        "unsupported group_by dimension '{0}' (expected one of namespace, tenant, provider, action_type, outcome)"
+    )]
+    UnsupportedGroupBy(String),
+    #[error("top_n only applies to the top_action_types metric and must be at least 1")]
+    InvalidTopN,
+}
+
+/// Builds an [`AnalyticsQuery`], rejecting combinations the server would
+/// answer with a 400 or silently ignore.
+///
+/// # Example
+///
+/// ```
+/// use acteon_client::{AnalyticsInterval, AnalyticsMetric, AnalyticsQueryBuilder};
+/// use chrono::{Duration, Utc};
+///
+/// let now = Utc::now();
+/// let query = AnalyticsQueryBuilder::new()
+///     .metric(AnalyticsMetric::Latency)
+///     .interval(AnalyticsInterval::Hourly)
+///     .group_by("provider")
+///     .tenant("tenant-1")
+///     .from(now - Duration::hours(24))
+///     .to(now)
+///     .build()?;
+/// assert_eq!(query.group_by.as_deref(), Some("provider"));
+///
+/// // A range that ends before it starts is rejected before any request.
+/// assert!(AnalyticsQueryBuilder::new()
+///     .metric(AnalyticsMetric::Volume)
+///     .from(now)
+///     .to(now - Duration::hours(1))
+///     .build()
+///     .is_err());
+/// # Ok::<(), acteon_client::AnalyticsQueryError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsQueryBuilder {
+    metric: Option<AnalyticsMetric>,
+    interval: Option<AnalyticsInterval>,
+    namespace: Option<String>,
+    tenant: Option<String>,
+    provider: Option<String>,
+    action_type: Option<String>,
+    outcome: Option<String>,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    group_by: Option<String>,
+    top_n: Option<usize>,
+}
+
+impl AnalyticsQueryBuilder {
+    /// Start a query with no metric and every filter unset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the metric to compute. Required.
+    #[must_use]
+    pub fn metric(mut self, metric: AnalyticsMetric) -> Self {
+        self.metric = Some(metric);
+        self
+    }
+
+    /// Set the bucket interval. Defaults to daily.
+    #[must_use]
+    pub fn interval(mut self, interval: AnalyticsInterval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Group buckets by one of [`ANALYTICS_DIMENSIONS`].
+    #[must_use]
+    pub fn group_by(mut self, dimension: impl Into<String>) -> Self {
+        self.group_by = Some(dimension.into());
+        self
+    }
+
+    /// Start of the time range (inclusive). Defaults to 7 days ago.
+    #[must_use]
+    pub fn from(mut self, from: DateTime<Utc>) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// End of the time range (inclusive). Defaults to now.
+    #[must_use]
+    pub fn to(mut self, to: DateTime<Utc>) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Filter by namespace.
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Filter by tenant.
+    #[must_use]
+    pub fn tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.tenant = Some(tenant.into());
+        self
+    }
+
+    /// Filter by provider.
+    #[must_use]
+    pub fn provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    /// Filter by action type.
+    #[must_use]
+    pub fn action_type(mut self, action_type: impl Into<String>) -> Self {
+        self.action_type = Some(action_type.into());
+        self
+    }
+
+    /// Filter by outcome.
+    #[must_use]
+    pub fn outcome(mut self, outcome: impl Into<String>) -> Self {
+        self.outcome = Some(outcome.into());
+        self
+    }
+
+    /// Number of top entries for [`AnalyticsMetric::TopActionTypes`].
+    /// Defaults to 10 on the server.
+    #[must_use]
+    pub fn top_n(mut self, top_n: usize) -> Self {
+        self.top_n = Some(top_n);
+        self
+    }
+
+    /// Validate the settings and build the query.
+    ///
+    /// Fails if no metric was set, if both ends of the time range are set
+    /// and `from` is not before `to`, if `group_by` is not one of
+    /// [`ANALYTICS_DIMENSIONS`], or if `top_n` is zero or set for a metric
+    /// other than [`AnalyticsMetric::TopActionTypes`].
+    pub fn build(self) -> Result<AnalyticsQuery, AnalyticsQueryError> {
+        let metric = self.metric.ok_or(AnalyticsQueryError::MissingMetric)?;
+        if let (Some(from), Some(to)) = (self.from, self.to)
+            && from >= to
+        {
+            return Err(AnalyticsQueryError::EmptyRange { from, to });
+        }
+        if let Some(dimension) = &self.group_by
+            && !ANALYTICS_DIMENSIONS.contains(&dimension.as_str())
+        {
+            return Err(AnalyticsQueryError::UnsupportedGroupBy(dimension.clone()));
+        }
+        if self
+            .top_n
+            .is_some_and(|n| n == 0 || metric != AnalyticsMetric::TopActionTypes)
+        {
+            return Err(AnalyticsQueryError::InvalidTopN);
+        }
+        Ok(AnalyticsQuery {
+            metric,
+            namespace: self.namespace,
+            tenant: self.tenant,
+            provider: self.provider,
+            action_type: self.action_type,
+            outcome: self.outcome,
+            interval: self.interval.unwrap_or(AnalyticsInterval::Daily),
+            from: self.from,
+            to: self.to,
+            group_by: self.group_by,
+            top_n: self.top_n,
+            drill_into: None,
+            tenant_scope: Vec::new(),
+        })
+    }
+}
+
 impl ActeonClient {
     /// Query aggregated action analytics.
     ///
     /// Sends a GET request to `/v1/analytics` with the query parameters
-    /// derived from the given [`AnalyticsQuery`].
+    /// derived from the given [`AnalyticsQuery`]. [`AnalyticsQueryBuilder`]
+    /// catches malformed queries before they are sent.
     ///
     /// # Example
     ///
@@ -30,6 +226,7 @@ impl ActeonClient {
     ///     to: None,
     ///     group_by: None,
     ///     top_n: None,
+    ///     drill_into: None,
     ///     tenant_scope: Vec::new(), // server-set from grants; leave empty
     /// };
     ///
@@ -101,12 +298,17 @@ impl ActeonClient {
             params.push(("top_n", top_n.to_string()));
         }
 
+        // Drill-down travels as a dimension/value pair.
+        if let Some((ref dimension, ref value)) = query.drill_into {
+            params.push(("drill_dimension", dimension.clone()));
+            params.push(("drill_value", value.clone()));
+        }
+
         let response = self
             .add_auth(self.client.get(&url))
             .query(&params)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let resp = response
@@ -121,4 +323,90 @@ impl ActeonClient {
             })
         }
     }
+
+    /// Drill into a single entry of a previous analytics breakdown.
+    ///
+    /// Runs `query` scoped to `dimension = value` (e.g. a top tenant from an
+    /// earlier response), so its buckets and top entries describe only that
+    /// entry. Supported dimensions are `namespace`, `tenant`, `provider`,
+    /// `action_type`, and `outcome`; the server rejects others with a 400.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example(
+    /// #     client: &acteon_client::ActeonClient,
+    /// #     query: &acteon_client::AnalyticsQuery,
+    /// # ) -> Result<(), acteon_client::Error> {
+    /// // Top action types for the busiest tenant only.
+    /// let response = client.query_analytics_drill(query, "tenant", "acme").await?;
+    /// for entry in &response.top_entries {
+    ///     println!("{}: {}", entry.label, entry.count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_analytics_drill(
+        &self,
+        query: &AnalyticsQuery,
+        dimension: &str,
+        value: &str,
+    ) -> Result<AnalyticsResponse, Error> {
+        let mut query = query.clone();
+        query.drill_into = Some((dimension.to_string(), value.to_string()));
+        self.query_analytics(&query).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn builder_rejects_malformed_queries() {
+        let now = Utc::now();
+        let volume = || AnalyticsQueryBuilder::new().metric(AnalyticsMetric::Volume);
+
+        assert_eq!(
+            AnalyticsQueryBuilder::new()
+                .tenant("t1")
+                .build()
+                .unwrap_err(),
+            AnalyticsQueryError::MissingMetric
+        );
+        assert_eq!(
+            volume().from(now).to(now).build().unwrap_err(),
+            AnalyticsQueryError::EmptyRange { from: now, to: now }
+        );
+        assert_eq!(
+            volume().group_by("region").build().unwrap_err(),
+            AnalyticsQueryError::UnsupportedGroupBy("region".into())
+        );
+        assert_eq!(
+            volume().top_n(5).build().unwrap_err(),
+            AnalyticsQueryError::InvalidTopN
+        );
+        assert_eq!(
+            AnalyticsQueryBuilder::new()
+                .metric(AnalyticsMetric::TopActionTypes)
+                .top_n(0)
+                .build()
+                .unwrap_err(),
+            AnalyticsQueryError::InvalidTopN
+        );
+
+        let query = AnalyticsQueryBuilder::new()
+            .metric(AnalyticsMetric::TopActionTypes)
+            .top_n(5)
+            .group_by("tenant")
+            .from(now - Duration::days(1))
+            .build()
+            .unwrap();
+        assert_eq!(query.metric, AnalyticsMetric::TopActionTypes);
+        assert_eq!(query.interval, AnalyticsInterval::Daily);
+        assert_eq!(query.top_n, Some(5));
+        assert!(query.to.is_none());
+    }
 }
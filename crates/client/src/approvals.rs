@@ -1,7 +1,10 @@
 use std::fmt::Write;
 
+use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
 
+use crate::pagination::offset_pages;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Response from approving or rejecting an action.
@@ -39,10 +42,135 @@ pub struct ApprovalStatusResponse {
 pub struct ApprovalListResponse {
     /// List of pending approvals.
     pub approvals: Vec<ApprovalStatusResponse>,
-    /// Total number of approvals returned.
+    /// Number of approvals in this response.
     pub count: usize,
 }
 
+/// Compute the HMAC-SHA256 signature the server expects on approval URLs.
+///
+/// Uses the server's signing scheme ([`acteon_core::approval_signature`]),
+/// so tests and tooling that know the server's approval secret can mint URLs
+/// accepted by [`ActeonClient::approve`] and [`ActeonClient::reject`].
+/// `expires_at` is a Unix timestamp in seconds. The key ID is not part of
+/// the signature; pass it separately to [`ActeonClient::approve_with_kid`]
+/// when the server rotates keys.
+///
+/// # Example
+///
+/// ```
+/// use acteon_client::sign_approval;
+///
+/// let sig = sign_approval("payments", "tenant-1", "abc-123", 1_700_000_000, b"secret");
+/// assert_eq!(sig.len(), 64);
+/// ```
+#[must_use]
+pub fn sign_approval(
+    namespace: &str,
+    tenant: &str,
+    id: &str,
+    expires_at: i64,
+    secret: &[u8],
+) -> String {
+    acteon_core::approval_signature(namespace, tenant, id, expires_at, secret)
+}
+
+/// Everything needed to approve or reject one pending action: its scope and
+/// ID plus the HMAC parameters of its approval URL.
+///
+/// A challenge comes either from an approval URL the caller was given
+/// ([`from_url`](Self::from_url)) or from an `approval_required` stream
+/// event, which carries the signed URL parameters
+/// ([`from_event`](Self::from_event)).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalChallenge {
+    /// Namespace of the pending action.
+    pub namespace: String,
+    /// Tenant of the pending action.
+    pub tenant: String,
+    /// The approval ID.
+    pub id: String,
+    /// Hex-encoded HMAC signature.
+    pub sig: String,
+    /// Unix timestamp, in seconds, after which the signature is rejected.
+    pub expires_at: i64,
+    /// ID of the key that made the signature, if the server rotates keys.
+    pub kid: Option<String>,
+}
+
+impl ApprovalChallenge {
+    /// Parse an approve or reject URL, such as the `approve_url` of an
+    /// [`ActionOutcome::PendingApproval`](acteon_core::ActionOutcome::PendingApproval).
+    ///
+    /// Returns `None` unless the URL has the
+    /// `/v1/approvals/{namespace}/{tenant}/{id}/{approve|reject}` path and
+    /// `sig` and `expires_at` query parameters.
+    ///
+    /// ```
+    /// use acteon_client::ApprovalChallenge;
+    ///
+    /// let url = "https://acteon.example.com/v1/approvals/payments/tenant-1/abc-123/approve\
+    ///            ?sig=deadbeef&expires_at=1700000000&kid=k2";
+    /// let challenge = ApprovalChallenge::from_url(url).unwrap();
+    /// assert_eq!(challenge.id, "abc-123");
+    /// assert_eq!(challenge.kid.as_deref(), Some("k2"));
+    /// ```
+    pub fn from_url(url: &str) -> Option<Self> {
+        let url = reqwest::Url::parse(url).ok()?;
+        let segments: Vec<&str> = url.path_segments()?.collect();
+        let [
+            ..,
+            "v1",
+            "approvals",
+            namespace,
+            tenant,
+            id,
+            "approve" | "reject",
+        ] = segments.as_slice()
+        else {
+            return None;
+        };
+        let (mut sig, mut expires_at, mut kid) = (None, None, None);
+        for (name, value) in url.query_pairs() {
+            match name.as_ref() {
+                "sig" => sig = Some(value.into_owned()),
+                "expires_at" => expires_at = value.parse().ok(),
+                "kid" if !value.is_empty() => kid = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+        Some(Self {
+            namespace: (*namespace).to_owned(),
+            tenant: (*tenant).to_owned(),
+            id: (*id).to_owned(),
+            sig: sig?,
+            expires_at: expires_at?,
+            kid,
+        })
+    }
+
+    /// Read the challenge from an `approval_required` stream event.
+    ///
+    /// Returns `None` for any other event, or for an event from a server
+    /// that does not include the signed URL parameters.
+    pub fn from_event(event: &acteon_core::StreamEvent) -> Option<Self> {
+        let acteon_core::StreamEventType::ApprovalRequired {
+            approval_id,
+            url_params: Some(params),
+        } = &event.event_type
+        else {
+            return None;
+        };
+        Some(Self {
+            namespace: event.namespace.clone(),
+            tenant: event.tenant.clone(),
+            id: approval_id.clone(),
+            sig: params.sig.clone(),
+            expires_at: params.expires_at,
+            kid: params.kid.clone(),
+        })
+    }
+}
+
 impl ActeonClient {
     /// Approve a pending action by namespace, tenant, ID, and HMAC signature.
     ///
@@ -87,6 +215,8 @@ impl ActeonClient {
         expires_at: i64,
         kid: Option<&str>,
     ) -> Result<ApprovalActionResponse, Error> {
+        let namespace = self.scope.namespace(namespace);
+        let tenant = self.scope.tenant(tenant);
         let mut url = format!(
             "{}/v1/approvals/{}/{}/{}/approve?sig={}&expires_at={}",
             self.base_url, namespace, tenant, id, sig, expires_at
@@ -95,12 +225,7 @@ impl ActeonClient {
             write!(url, "&kid={k}").expect("writing to String cannot fail");
         }
 
-        let response = self
-            .client
-            .post(&url)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let response = self.client.post(&url).send_gated(&self.gate).await?;
 
         if response.status().is_success() {
             let result = response
@@ -168,6 +293,8 @@ impl ActeonClient {
         expires_at: i64,
         kid: Option<&str>,
     ) -> Result<ApprovalActionResponse, Error> {
+        let namespace = self.scope.namespace(namespace);
+        let tenant = self.scope.tenant(tenant);
         let mut url = format!(
             "{}/v1/approvals/{}/{}/{}/reject?sig={}&expires_at={}",
             self.base_url, namespace, tenant, id, sig, expires_at
@@ -176,12 +303,7 @@ impl ActeonClient {
             write!(url, "&kid={k}").expect("writing to String cannot fail");
         }
 
-        let response = self
-            .client
-            .post(&url)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let response = self.client.post(&url).send_gated(&self.gate).await?;
 
         if response.status().is_success() {
             let result = response
@@ -207,6 +329,58 @@ impl ActeonClient {
         }
     }
 
+    /// Approve the action behind `challenge`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::{ActeonClient, ApprovalChallenge, StreamFilter, StreamItem};
+    /// use futures::StreamExt;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let mut stream = client.stream(&StreamFilter::new()).await?;
+    /// while let Some(item) = stream.next().await {
+    ///     if let StreamItem::Event(event) = item?
+    ///         && let Some(challenge) = ApprovalChallenge::from_event(&event)
+    ///     {
+    ///         client.approve_challenge(&challenge).await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn approve_challenge(
+        &self,
+        challenge: &ApprovalChallenge,
+    ) -> Result<ApprovalActionResponse, Error> {
+        self.approve_with_kid(
+            &challenge.namespace,
+            &challenge.tenant,
+            &challenge.id,
+            &challenge.sig,
+            challenge.expires_at,
+            challenge.kid.as_deref(),
+        )
+        .await
+    }
+
+    /// Reject the action behind `challenge`.
+    pub async fn reject_challenge(
+        &self,
+        challenge: &ApprovalChallenge,
+    ) -> Result<ApprovalActionResponse, Error> {
+        self.reject_with_kid(
+            &challenge.namespace,
+            &challenge.tenant,
+            &challenge.id,
+            &challenge.sig,
+            challenge.expires_at,
+            challenge.kid.as_deref(),
+        )
+        .await
+    }
+
     /// Get the status of an approval by namespace, tenant, ID, and HMAC signature.
     ///
     /// Returns `None` if the approval is not found or has expired.
@@ -250,6 +424,8 @@ impl ActeonClient {
         expires_at: i64,
         kid: Option<&str>,
     ) -> Result<Option<ApprovalStatusResponse>, Error> {
+        let namespace = self.scope.namespace(namespace);
+        let tenant = self.scope.tenant(tenant);
         let mut url = format!(
             "{}/v1/approvals/{}/{}/{}?sig={}&expires_at={}",
             self.base_url, namespace, tenant, id, sig, expires_at
@@ -258,12 +434,7 @@ impl ActeonClient {
             write!(url, "&kid={k}").expect("writing to String cannot fail");
         }
 
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let response = self.client.get(&url).send_gated(&self.gate).await?;
 
         if response.status().is_success() {
             let result = response
@@ -303,15 +474,77 @@ impl ActeonClient {
         &self,
         namespace: &str,
         tenant: &str,
+    ) -> Result<ApprovalListResponse, Error> {
+        self.fetch_approvals(namespace, tenant, None).await
+    }
+
+    /// List one page of pending approvals, oldest first.
+    ///
+    /// Returns at most `limit` approvals after skipping the first `offset`.
+    /// Use [`list_approvals_stream`](Self::list_approvals_stream) to walk
+    /// every page.
+    pub async fn list_approvals_page(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        limit: usize,
+        offset: usize,
+    ) -> Result<ApprovalListResponse, Error> {
+        self.fetch_approvals(namespace, tenant, Some((limit, offset)))
+            .await
+    }
+
+    /// Stream every pending approval in a namespace and tenant.
+    ///
+    /// Requests pages of `page_size` approvals via
+    /// [`list_approvals_page`](Self::list_approvals_page) as the stream is
+    /// polled. The stream ends after the last page or the first error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    /// use futures::StreamExt;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let mut approvals = std::pin::pin!(client.list_approvals_stream("payments", "tenant-1", 100));
+    /// while let Some(approval) = approvals.next().await {
+    ///     println!("{}", approval?.token);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_approvals_stream<'a>(
+        &'a self,
+        namespace: &'a str,
+        tenant: &'a str,
+        page_size: usize,
+    ) -> impl Stream<Item = Result<ApprovalStatusResponse, Error>> + 'a {
+        offset_pages(page_size, move |limit, offset| async move {
+            self.list_approvals_page(namespace, tenant, limit, offset)
+                .await
+                .map(|page| page.approvals)
+        })
+    }
+
+    async fn fetch_approvals(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        page: Option<(usize, usize)>,
     ) -> Result<ApprovalListResponse, Error> {
         let url = format!("{}/v1/approvals", self.base_url);
+        let namespace = self.scope.namespace(namespace);
+        let tenant = self.scope.tenant(tenant);
 
-        let response = self
+        let mut request = self
             .add_auth(self.client.get(&url))
-            .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .query(&[("namespace", namespace), ("tenant", tenant)]);
+        if let Some((limit, offset)) = page {
+            request = request.query(&[("limit", limit), ("offset", offset)]);
+        }
+        let response = request.send_gated(&self.gate).await?;
 
         if response.status().is_success() {
             let result = response
@@ -327,3 +560,167 @@ impl ActeonClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    fn query_param(target: &str, name: &str) -> Option<usize> {
+        target
+            .split(['?', '&'])
+            .find_map(|p| p.strip_prefix(name)?.strip_prefix('='))
+            .and_then(|v| v.parse().ok())
+    }
+
+    /// Serve `GET /v1/approvals` from a fixed list of `total` approvals,
+    /// honoring `limit`/`offset`, and record each requested offset.
+    async fn start_approvals_server(total: usize) -> (String, Arc<Mutex<Vec<usize>>>) {
+        let offsets = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&offsets);
+        let base_url = mock_server::serve(move |request| {
+            let offset = query_param(&request.target, "offset").unwrap_or(0);
+            let limit = query_param(&request.target, "limit").unwrap_or(usize::MAX);
+            seen.lock().unwrap().push(offset);
+
+            let approvals: Vec<_> = (0..total)
+                .skip(offset)
+                .take(limit)
+                .map(|i| {
+                    serde_json::json!({
+                        "token": format!("appr-{i}"),
+                        "status": "pending",
+                        "rule": "approve-refunds",
+                        "created_at": "2026-01-01T00:00:00Z",
+                        "expires_at": "2026-01-02T00:00:00Z",
+                        "decided_at": null,
+                        "message": null,
+                    })
+                })
+                .collect();
+            Response::json(
+                200,
+                &serde_json::json!({
+                    "count": approvals.len(),
+                    "approvals": approvals,
+                }),
+            )
+        })
+        .await;
+        (base_url, offsets)
+    }
+
+    #[tokio::test]
+    async fn list_approvals_stream_walks_every_page() {
+        let (base_url, offsets) = start_approvals_server(5).await;
+        let client = ActeonClient::new(base_url);
+
+        let tokens: Vec<String> = client
+            .list_approvals_stream("payments", "tenant-1", 2)
+            .map(|a| a.unwrap().token)
+            .collect()
+            .await;
+
+        assert_eq!(tokens, ["appr-0", "appr-1", "appr-2", "appr-3", "appr-4"]);
+        // The short third page ends the stream without another request.
+        assert_eq!(*offsets.lock().unwrap(), [0, 2, 4]);
+    }
+
+    #[tokio::test]
+    async fn list_approvals_stream_stops_on_empty_page() {
+        let (base_url, offsets) = start_approvals_server(4).await;
+        let client = ActeonClient::new(base_url);
+
+        let count = client
+            .list_approvals_stream("payments", "tenant-1", 2)
+            .count()
+            .await;
+
+        assert_eq!(count, 4);
+        assert_eq!(*offsets.lock().unwrap(), [0, 2, 4]);
+    }
+
+    #[tokio::test]
+    async fn challenges_resolve_from_urls_and_events() {
+        use acteon_core::{ActionOutcome, ApprovalUrlParams, StreamEvent, StreamEventType};
+
+        let sig = sign_approval("payments", "tenant-1", "abc-123", 1_700_000_000, b"secret");
+        let event = StreamEvent {
+            id: "evt-1".into(),
+            timestamp: chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            event_type: StreamEventType::ApprovalRequired {
+                approval_id: "abc-123".into(),
+                url_params: Some(ApprovalUrlParams {
+                    sig: sig.clone(),
+                    expires_at: 1_700_000_000,
+                    kid: Some("k2".into()),
+                }),
+            },
+            namespace: "payments".into(),
+            tenant: "tenant-1".into(),
+            action_type: Some("refund".into()),
+            action_id: Some("action-1".into()),
+            traceparent: None,
+        };
+        let from_event = ApprovalChallenge::from_event(&event).unwrap();
+
+        let url = format!(
+            "https://acteon.example.com/v1/approvals/payments/tenant-1/abc-123/reject\
+             ?sig={sig}&expires_at=1700000000&kid=k2"
+        );
+        assert_eq!(ApprovalChallenge::from_url(&url).unwrap(), from_event);
+        assert!(ApprovalChallenge::from_url("https://acteon.example.com/v1/approvals").is_none());
+
+        // Dispatch events redact the URLs, and older servers omit the
+        // parameters from approval events.
+        let dispatched = StreamEvent {
+            event_type: StreamEventType::ActionDispatched {
+                outcome: ActionOutcome::PendingApproval {
+                    approval_id: "abc-123".into(),
+                    expires_at: event.timestamp,
+                    approve_url: "[redacted]".into(),
+                    reject_url: "[redacted]".into(),
+                    notification_sent: true,
+                },
+                provider: "payments".into(),
+            },
+            ..event.clone()
+        };
+        assert!(ApprovalChallenge::from_event(&dispatched).is_none());
+        let unsigned = StreamEvent {
+            event_type: StreamEventType::ApprovalRequired {
+                approval_id: "abc-123".into(),
+                url_params: None,
+            },
+            ..event
+        };
+        assert!(ApprovalChallenge::from_event(&unsigned).is_none());
+
+        let routes = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&routes);
+        let base_url = mock_server::serve(move |request| {
+            seen.lock().unwrap().push(request.route());
+            Response::json(
+                200,
+                &serde_json::json!({"id": "abc-123", "status": "approved", "outcome": null}),
+            )
+        })
+        .await;
+        let result = ActeonClient::new(base_url)
+            .approve_challenge(&from_event)
+            .await
+            .unwrap();
+        assert_eq!(result.status, "approved");
+        assert_eq!(
+            *routes.lock().unwrap(),
+            [format!(
+                "POST /v1/approvals/payments/tenant-1/abc-123/approve\
+                 ?sig={sig}&expires_at=1700000000&kid=k2"
+            )]
+        );
+    }
+}
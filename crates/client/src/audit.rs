@@ -1,5 +1,10 @@
+use std::collections::{HashSet, VecDeque};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt as _;
 use serde::{Deserialize, Serialize};
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Query parameters for audit search.
@@ -23,7 +28,7 @@ pub struct AuditQuery {
     /// Maximum number of records to return.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
-    /// Number of records to skip.
+    /// Number of records to skip. Ignored when `cursor` is set.
     ///
     /// Prefer `cursor` for deep pagination — large offsets degrade
     /// linearly on every backend.
@@ -38,6 +43,209 @@ pub struct AuditQuery {
     pub cursor: Option<String>,
 }
 
+impl AuditQuery {
+    /// Filter by outcome.
+    ///
+    /// Accepts an [`OutcomeFilter`] or, for outcomes this client does not
+    /// know yet, a raw string.
+    #[must_use]
+    pub fn with_outcome(mut self, outcome: impl Into<OutcomeFilter>) -> Self {
+        self.outcome = Some(outcome.into().to_string());
+        self
+    }
+}
+
+/// Outcome values accepted by the `outcome` filter of audit queries.
+///
+/// [`Display`](std::fmt::Display) yields the canonical string stored on
+/// audit records. Converting from a string maps known values onto their
+/// variant and keeps anything else as [`OutcomeFilter::Other`], so filters
+/// for outcomes added by newer servers still work.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OutcomeFilter {
+    Executed,
+    Deduplicated,
+    Suppressed,
+    Rerouted,
+    Throttled,
+    Failed,
+    Grouped,
+    StateChanged,
+    PendingApproval,
+    ChainStarted,
+    DryRun,
+    CircuitOpen,
+    Scheduled,
+    RecurringCreated,
+    QuotaExceeded,
+    Silenced,
+    Muted,
+    /// A compliance-mode intent record (see [`INTENT_OUTCOME`]).
+    Intent,
+    /// Any other outcome string, passed through unchanged.
+    Other(String),
+}
+
+impl OutcomeFilter {
+    const KNOWN: [Self; 18] = [
+        Self::Executed,
+        Self::Deduplicated,
+        Self::Suppressed,
+        Self::Rerouted,
+        Self::Throttled,
+        Self::Failed,
+        Self::Grouped,
+        Self::StateChanged,
+        Self::PendingApproval,
+        Self::ChainStarted,
+        Self::DryRun,
+        Self::CircuitOpen,
+        Self::Scheduled,
+        Self::RecurringCreated,
+        Self::QuotaExceeded,
+        Self::Silenced,
+        Self::Muted,
+        Self::Intent,
+    ];
+
+    /// The canonical query value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Executed => "executed",
+            Self::Deduplicated => "deduplicated",
+            Self::Suppressed => "suppressed",
+            Self::Rerouted => "rerouted",
+            Self::Throttled => "throttled",
+            Self::Failed => "failed",
+            Self::Grouped => "grouped",
+            Self::StateChanged => "state_changed",
+            Self::PendingApproval => "pending_approval",
+            Self::ChainStarted => "chain_started",
+            Self::DryRun => "dry_run",
+            Self::CircuitOpen => "circuit_open",
+            Self::Scheduled => "scheduled",
+            Self::RecurringCreated => "recurring_created",
+            Self::QuotaExceeded => "quota_exceeded",
+            Self::Silenced => "silenced",
+            Self::Muted => "muted",
+            Self::Intent => INTENT_OUTCOME,
+            Self::Other(raw) => raw,
+        }
+    }
+}
+
+impl std::fmt::Display for OutcomeFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for OutcomeFilter {
+    fn from(raw: &str) -> Self {
+        Self::KNOWN
+            .into_iter()
+            .find(|known| known.as_str() == raw)
+            .unwrap_or_else(|| Self::Other(raw.to_owned()))
+    }
+}
+
+impl From<String> for OutcomeFilter {
+    fn from(raw: String) -> Self {
+        Self::from(raw.as_str())
+    }
+}
+
+impl Serialize for OutcomeFilter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Verdict values accepted by the `verdict` filter of replay queries.
+///
+/// Mirrors [`OutcomeFilter`]: known verdicts get a variant, anything else
+/// is kept verbatim as [`VerdictFilter::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VerdictFilter {
+    Allow,
+    Deny,
+    Deduplicate,
+    Suppress,
+    Reroute,
+    Throttle,
+    Modify,
+    StateMachine,
+    Group,
+    RequestApproval,
+    Chain,
+    Schedule,
+    /// Any other verdict string, passed through unchanged.
+    Other(String),
+}
+
+impl VerdictFilter {
+    const KNOWN: [Self; 12] = [
+        Self::Allow,
+        Self::Deny,
+        Self::Deduplicate,
+        Self::Suppress,
+        Self::Reroute,
+        Self::Throttle,
+        Self::Modify,
+        Self::StateMachine,
+        Self::Group,
+        Self::RequestApproval,
+        Self::Chain,
+        Self::Schedule,
+    ];
+
+    /// The canonical query value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Allow => "allow",
+            Self::Deny => "deny",
+            Self::Deduplicate => "deduplicate",
+            Self::Suppress => "suppress",
+            Self::Reroute => "reroute",
+            Self::Throttle => "throttle",
+            Self::Modify => "modify",
+            Self::StateMachine => "state_machine",
+            Self::Group => "group",
+            Self::RequestApproval => "request_approval",
+            Self::Chain => "chain",
+            Self::Schedule => "schedule",
+            Self::Other(raw) => raw,
+        }
+    }
+}
+
+impl std::fmt::Display for VerdictFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for VerdictFilter {
+    fn from(raw: &str) -> Self {
+        Self::KNOWN
+            .into_iter()
+            .find(|known| known.as_str() == raw)
+            .unwrap_or_else(|| Self::Other(raw.to_owned()))
+    }
+}
+
+impl From<String> for VerdictFilter {
+    fn from(raw: String) -> Self {
+        Self::from(raw.as_str())
+    }
+}
+
+impl Serialize for VerdictFilter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 /// Paginated audit results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditPage {
@@ -71,6 +279,9 @@ pub struct AuditRecord {
     pub id: String,
     /// Action ID.
     pub action_id: String,
+    /// Chain execution ID, if this record is part of a chain lifecycle.
+    #[serde(default)]
+    pub chain_id: Option<String>,
     /// Namespace.
     pub namespace: String,
     /// Tenant.
@@ -85,6 +296,9 @@ pub struct AuditRecord {
     pub outcome: String,
     /// Name of matched rule, if any.
     pub matched_rule: Option<String>,
+    /// Outcome-specific details, e.g. the `approval_id` of a pending approval.
+    #[serde(default)]
+    pub outcome_details: serde_json::Value,
     /// Processing duration in milliseconds.
     pub duration_ms: u64,
     /// Dispatch timestamp.
@@ -98,6 +312,32 @@ pub struct AuditRecord {
     /// Monotonic sequence number within the `(namespace, tenant)` pair (compliance mode).
     #[serde(default)]
     pub sequence_number: Option<u64>,
+    /// Action metadata labels, including `__`-prefixed system fields used to
+    /// rebuild the action with [`Action::from_audit`](acteon_core::Action::from_audit).
+    #[serde(default)]
+    pub metadata: serde_json::Value,
+}
+
+impl acteon_core::AuditedAction for AuditRecord {
+    fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    fn tenant(&self) -> &str {
+        &self.tenant
+    }
+
+    fn provider(&self) -> &str {
+        &self.provider
+    }
+
+    fn action_type(&self) -> &str {
+        &self.action_type
+    }
+
+    fn metadata(&self) -> &serde_json::Value {
+        &self.metadata
+    }
 }
 
 impl AuditRecord {
@@ -144,6 +384,22 @@ pub struct ReplayQuery {
     pub limit: Option<u32>,
 }
 
+impl ReplayQuery {
+    /// Filter by outcome. Accepts an [`OutcomeFilter`] or a raw string.
+    #[must_use]
+    pub fn with_outcome(mut self, outcome: impl Into<OutcomeFilter>) -> Self {
+        self.outcome = Some(outcome.into().to_string());
+        self
+    }
+
+    /// Filter by verdict. Accepts a [`VerdictFilter`] or a raw string.
+    #[must_use]
+    pub fn with_verdict(mut self, verdict: impl Into<VerdictFilter>) -> Self {
+        self.verdict = Some(verdict.into().to_string());
+        self
+    }
+}
+
 /// Result of replaying a single action.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReplayResult {
@@ -170,6 +426,36 @@ pub struct ReplaySummary {
     pub results: Vec<ReplayResult>,
 }
 
+/// Pacing options for [`ActeonClient::replay_audit_controlled`].
+#[derive(Debug, Clone)]
+pub struct ReplayOptions {
+    /// Maximum number of replays in flight at once (values below 1 are
+    /// treated as 1).
+    pub concurrency: usize,
+    /// Maximum number of replays started per second, or `None` for no cap.
+    pub rate_per_sec: Option<u32>,
+}
+
+impl Default for ReplayOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            rate_per_sec: None,
+        }
+    }
+}
+
+/// Summary of a client-paced replay, with observed throughput.
+#[derive(Debug, Clone)]
+pub struct ReplayReport {
+    /// Replay counts and per-action results.
+    pub summary: ReplaySummary,
+    /// Wall-clock time from the first replay to the last completion.
+    pub elapsed: Duration,
+    /// Records processed (replayed, failed, or skipped) per second.
+    pub throughput_per_sec: f64,
+}
+
 impl ActeonClient {
     /// Query audit records.
     ///
@@ -197,13 +483,20 @@ impl ActeonClient {
     /// ```
     pub async fn query_audit(&self, query: &AuditQuery) -> Result<AuditPage, Error> {
         let url = format!("{}/v1/audit", self.base_url);
+        let mut query = query.clone();
+        self.scope
+            .fill_optional(&mut query.namespace, &mut query.tenant);
+        if query.cursor.is_some() {
+            // The cursor already marks the position; an offset would skip
+            // records past it.
+            query.offset = None;
+        }
 
         let response = self
             .add_auth(self.client.get(&url))
-            .query(query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .query(&query)
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let page = response
@@ -219,6 +512,117 @@ impl ActeonClient {
         }
     }
 
+    /// Stream every audit record matching `query`, fetching pages lazily.
+    ///
+    /// The first page starts at `query.offset`, and `query.limit` sets the
+    /// page size (the server default when unset); `query.cursor` is ignored.
+    /// Later pages follow the `next_cursor` the server returns, which stays
+    /// put when records are inserted during the walk. Servers that return
+    /// no cursor are walked by offset instead. The stream ends once it has
+    /// yielded the `total` reported with the first page, at the last page,
+    /// or after the first error.
+    ///
+    /// When walking by offset, records inserted while the walk is in
+    /// progress can shift later pages; records already yielded are skipped
+    /// rather than repeated.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::{ActeonClient, AuditQuery};
+    /// use futures::StreamExt;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let query = AuditQuery {
+    ///     tenant: Some("tenant-1".to_string()),
+    ///     limit: Some(500),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut records = std::pin::pin!(client.audit_stream(&query));
+    /// while let Some(record) = records.next().await {
+    ///     println!("{}", record?.action_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn audit_stream<'a>(
+        &'a self,
+        query: &AuditQuery,
+    ) -> impl futures::Stream<Item = Result<AuditRecord, Error>> + 'a {
+        struct Walk {
+            query: AuditQuery,
+            /// Records left to yield, from the first page's `total`.
+            remaining: Option<u64>,
+            seen: HashSet<String>,
+            buffered: VecDeque<AuditRecord>,
+            done: bool,
+        }
+
+        let mut query = query.clone();
+        query.cursor = None;
+        let walk = Walk {
+            query,
+            remaining: None,
+            seen: HashSet::new(),
+            buffered: VecDeque::new(),
+            done: false,
+        };
+
+        futures::stream::unfold(walk, move |mut walk| async move {
+            loop {
+                if walk.remaining == Some(0) {
+                    return None;
+                }
+                if let Some(record) = walk.buffered.pop_front() {
+                    if let Some(remaining) = walk.remaining.as_mut() {
+                        *remaining -= 1;
+                    }
+                    return Some((Ok(record), walk));
+                }
+                if walk.done {
+                    return None;
+                }
+                let page = match self.query_audit(&walk.query).await {
+                    Ok(page) => page,
+                    Err(e) => {
+                        walk.done = true;
+                        return Some((Err(e), walk));
+                    }
+                };
+                let offset = u64::from(walk.query.offset.unwrap_or(0));
+                if walk.remaining.is_none() {
+                    walk.remaining = page.total.map(|total| total.saturating_sub(offset));
+                }
+                let fetched = page.records.len() as u64;
+                if let Some(next) = page.next_cursor {
+                    walk.done = fetched == 0;
+                    walk.query.cursor = Some(next);
+                    walk.query.offset = None;
+                } else if walk.query.cursor.is_some() {
+                    walk.done = true;
+                } else {
+                    walk.done = fetched == 0 || fetched < page.limit;
+                    match u32::try_from(offset + fetched) {
+                        Ok(next) => walk.query.offset = Some(next),
+                        Err(_) => walk.done = true,
+                    }
+                }
+                // Keep the server's default page size stable across pages.
+                walk.query
+                    .limit
+                    .get_or_insert(u32::try_from(page.limit).unwrap_or(u32::MAX));
+                let seen = &mut walk.seen;
+                walk.buffered.extend(
+                    page.records
+                        .into_iter()
+                        .filter(|record| seen.insert(record.id.clone())),
+                );
+            }
+        })
+    }
+
     /// Get a specific audit record by action ID.
     ///
     /// Returns `None` if the record is not found.
@@ -241,9 +645,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let record = response
@@ -261,6 +664,62 @@ impl ActeonClient {
         }
     }
 
+    /// Get the payload stored with an action's audit record.
+    ///
+    /// Returns `None` if no record exists or the payload was not stored
+    /// (privacy mode). Pair it with [`get_audit_record`](Self::get_audit_record)
+    /// and [`Action::from_audit`](acteon_core::Action::from_audit) to rebuild
+    /// the original action locally.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::{ActeonClient, Action};
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let record = client.get_audit_record("action-id-123").await?;
+    /// let payload = client.get_audit_payload("action-id-123").await?;
+    /// if let (Some(record), Some(payload)) = (record, payload) {
+    ///     let action = Action::from_audit(&record, payload);
+    ///     println!("{} -> {}", action.action_type, action.provider);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_audit_payload(
+        &self,
+        action_id: &str,
+    ) -> Result<Option<serde_json::Value>, Error> {
+        #[derive(Deserialize)]
+        struct PayloadOnly {
+            #[serde(default)]
+            action_payload: Option<serde_json::Value>,
+        }
+
+        let url = format!("{}/v1/audit/{}", self.base_url, action_id);
+
+        let response = self
+            .add_auth(self.client.get(&url))
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            let record = response
+                .json::<PayloadOnly>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            Ok(record.action_payload.filter(|p| !p.is_null()))
+        } else if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Ok(None)
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: format!("Failed to get audit payload: {}", response.status()),
+            })
+        }
+    }
+
     /// Replay a single action from the audit trail by its action ID.
     ///
     /// Reconstructs the original action from the stored audit payload and
@@ -285,9 +744,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.post(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -341,9 +799,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .query(query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let summary = response
@@ -358,4 +815,477 @@ impl ActeonClient {
             })
         }
     }
+
+    /// Replay actions from the audit trail matching the given query, pacing
+    /// the replays on the client.
+    ///
+    /// Unlike [`replay_audit`](Self::replay_audit), which replays the whole
+    /// set server-side in one request, this lists the matching records and
+    /// replays them one by one, keeping at most `options.concurrency` in
+    /// flight and starting no more than `options.rate_per_sec` per second.
+    /// `query.limit` caps the number of records (default 50).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::{ActeonClient, ReplayOptions, ReplayQuery};
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let query = ReplayQuery::default().with_outcome("failed");
+    /// let options = ReplayOptions { concurrency: 8, rate_per_sec: Some(50) };
+    ///
+    /// let report = client.replay_audit_controlled(&query, &options).await?;
+    /// println!("{:.1} replays/s", report.throughput_per_sec);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn replay_audit_controlled(
+        &self,
+        query: &ReplayQuery,
+        options: &ReplayOptions,
+    ) -> Result<ReplayReport, Error> {
+        let action_ids = self.replay_candidates(query).await?;
+
+        let start = Instant::now();
+        // Starts are spaced from the previous start rather than from a
+        // fixed schedule, so replays held back by slow ones don't go out
+        // in a burst once a slot frees up.
+        let pacer = options.rate_per_sec.filter(|rate| *rate > 0).map(|rate| {
+            let mut interval = tokio::time::interval(Duration::from_secs(1) / rate);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            tokio::sync::Mutex::new(interval)
+        });
+        let pacer = pacer.as_ref();
+        let results: Vec<Result<ReplayResult, (String, Error)>> = futures::stream::iter(action_ids)
+            .map(|action_id| async move {
+                if let Some(pacer) = pacer {
+                    pacer.lock().await.tick().await;
+                }
+                self.replay_action(&action_id)
+                    .await
+                    .map_err(|e| (action_id, e))
+            })
+            .buffer_unordered(options.concurrency.max(1))
+            .collect()
+            .await;
+        let elapsed = start.elapsed();
+
+        let mut summary = ReplaySummary {
+            replayed: 0,
+            failed: 0,
+            skipped: 0,
+            results: Vec::with_capacity(results.len()),
+        };
+        for result in results {
+            match result {
+                Ok(result) => {
+                    if result.success {
+                        summary.replayed += 1;
+                    } else {
+                        summary.failed += 1;
+                    }
+                    summary.results.push(result);
+                }
+                // No stored payload: mirrors the server-side bulk replay.
+                Err((_, Error::Http { status: 422, .. })) => summary.skipped += 1,
+                Err((action_id, e)) => {
+                    summary.failed += 1;
+                    summary.results.push(ReplayResult {
+                        original_action_id: action_id,
+                        new_action_id: String::new(),
+                        success: false,
+                        error: Some(e.to_string()),
+                    });
+                }
+            }
+        }
+
+        let processed = summary.replayed + summary.failed + summary.skipped;
+        #[allow(clippy::cast_precision_loss)]
+        let throughput_per_sec = if elapsed.is_zero() {
+            0.0
+        } else {
+            processed as f64 / elapsed.as_secs_f64()
+        };
+        Ok(ReplayReport {
+            summary,
+            elapsed,
+            throughput_per_sec,
+        })
+    }
+
+    /// List the action IDs a replay query matches, following cursors until
+    /// `query.limit` records have been collected.
+    async fn replay_candidates(&self, query: &ReplayQuery) -> Result<Vec<String>, Error> {
+        let url = format!("{}/v1/audit", self.base_url);
+        let wanted = query.limit.unwrap_or(50) as usize;
+        let mut action_ids = Vec::new();
+        let mut cursor: Option<String> = None;
+
+        while action_ids.len() < wanted {
+            let mut request = self.add_auth(self.client.get(&url)).query(query);
+            if let Some(ref c) = cursor {
+                request = request.query(&[("cursor", c)]);
+            }
+            let response = request.send_gated(&self.gate).await?;
+            if !response.status().is_success() {
+                return Err(Error::Http {
+                    status: response.status().as_u16(),
+                    message: format!("Failed to query audit: {}", response.status()),
+                });
+            }
+            let page = response
+                .json::<AuditPage>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            action_ids.extend(page.records.into_iter().map(|r| r.action_id));
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        action_ids.truncate(wanted);
+        Ok(action_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    fn query_string<T: Serialize>(query: &T) -> String {
+        reqwest::Client::new()
+            .get("http://localhost/v1/audit")
+            .query(query)
+            .build()
+            .unwrap()
+            .url()
+            .query()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    #[test]
+    fn typed_filters_serialize_to_canonical_values() {
+        let query = AuditQuery::default().with_outcome(OutcomeFilter::QuotaExceeded);
+        assert_eq!(query_string(&query), "outcome=quota_exceeded");
+
+        let query = ReplayQuery::default()
+            .with_outcome(OutcomeFilter::Failed)
+            .with_verdict(VerdictFilter::RequestApproval);
+        assert_eq!(
+            query_string(&query),
+            "outcome=failed&verdict=request_approval"
+        );
+    }
+
+    #[test]
+    fn raw_strings_map_to_known_variants() {
+        assert_eq!(OutcomeFilter::from("dry_run"), OutcomeFilter::DryRun);
+        assert_eq!(OutcomeFilter::from(INTENT_OUTCOME), OutcomeFilter::Intent);
+        assert_eq!(
+            VerdictFilter::from("state_machine"),
+            VerdictFilter::StateMachine
+        );
+    }
+
+    #[test]
+    fn unknown_raw_strings_pass_through() {
+        let outcome = OutcomeFilter::from("teleported");
+        assert_eq!(outcome, OutcomeFilter::Other("teleported".into()));
+        assert_eq!(serde_json::to_value(&outcome).unwrap(), "teleported");
+
+        let query = ReplayQuery::default()
+            .with_outcome("teleported")
+            .with_verdict(String::from("escalate"));
+        assert_eq!(query_string(&query), "outcome=teleported&verdict=escalate");
+    }
+
+    /// Serve `count` audit records from `GET /v1/audit` and succeed every
+    /// `POST /v1/audit/{id}/replay`, recording when each replay arrived.
+    /// Replays of the first `stalled` records answer after 300ms.
+    async fn start_replay_server(
+        count: usize,
+        stalled: usize,
+    ) -> (String, Arc<Mutex<Vec<Instant>>>) {
+        let arrivals = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&arrivals);
+        let base_url = mock_server::serve(move |request| {
+            let mut delay = Duration::ZERO;
+            let body = if let Some(rest) = request.path().strip_prefix("/v1/audit/") {
+                seen.lock().unwrap().push(Instant::now());
+                let id = rest.trim_end_matches("/replay");
+                let n: usize = id.trim_start_matches("act-").parse().unwrap();
+                if n < stalled {
+                    delay = Duration::from_millis(300);
+                }
+                serde_json::json!({
+                    "original_action_id": id,
+                    "new_action_id": format!("{id}-replay"),
+                    "success": true,
+                    "error": null,
+                })
+            } else {
+                let records: Vec<serde_json::Value> = (0..count)
+                    .map(|i| {
+                        serde_json::json!({
+                            "id": format!("rec-{i}"),
+                            "action_id": format!("act-{i}"),
+                            "namespace": "ns",
+                            "tenant": "t1",
+                            "provider": "email",
+                            "action_type": "send",
+                            "verdict": "allow",
+                            "outcome": "failed",
+                            "matched_rule": null,
+                            "duration_ms": 5,
+                            "dispatched_at": "2026-01-01T00:00:00Z",
+                        })
+                    })
+                    .collect();
+                serde_json::json!({"records": records, "limit": 50, "offset": 0})
+            };
+            Response::json(200, &body).delay(delay)
+        })
+        .await;
+        (base_url, arrivals)
+    }
+
+    #[tokio::test]
+    async fn controlled_replay_stays_within_rate_cap() {
+        let (base_url, arrivals) = start_replay_server(6, 0).await;
+        let client = ActeonClient::new(base_url);
+        let options = ReplayOptions {
+            concurrency: 4,
+            rate_per_sec: Some(20),
+        };
+
+        let report = client
+            .replay_audit_controlled(&ReplayQuery::default().with_outcome("failed"), &options)
+            .await
+            .unwrap();
+
+        assert_eq!(report.summary.replayed, 6);
+        assert_eq!(report.summary.results.len(), 6);
+        assert!(report.throughput_per_sec > 0.0);
+
+        // Six starts at 20/s span at least five 50ms intervals.
+        let arrivals = arrivals.lock().unwrap();
+        assert_eq!(arrivals.len(), 6);
+        let span = *arrivals.iter().max().unwrap() - *arrivals.iter().min().unwrap();
+        #[allow(clippy::cast_precision_loss)]
+        let observed = (arrivals.len() - 1) as f64 / span.as_secs_f64();
+        // Allow for scheduling jitter on loaded CI machines.
+        assert!(observed <= 20.0 * 1.25, "observed {observed:.1}/s");
+    }
+
+    #[tokio::test]
+    async fn controlled_replay_does_not_burst_after_a_stall() {
+        // Both slots are held by slow replays, so the next starts are
+        // overdue by the time one frees up.
+        let (base_url, arrivals) = start_replay_server(6, 2).await;
+        let client = ActeonClient::new(base_url);
+        let options = ReplayOptions {
+            concurrency: 2,
+            rate_per_sec: Some(20),
+        };
+
+        let report = client
+            .replay_audit_controlled(&ReplayQuery::default().with_outcome("failed"), &options)
+            .await
+            .unwrap();
+        assert_eq!(report.summary.replayed, 6);
+
+        let mut arrivals = arrivals.lock().unwrap().clone();
+        arrivals.sort();
+        for pair in arrivals[2..].windows(2) {
+            let gap = pair[1] - pair[0];
+            // 50ms apart at 20/s, less scheduling jitter.
+            assert!(gap >= Duration::from_millis(40), "gap {gap:?}");
+        }
+    }
+
+    /// Serve `GET /v1/audit` by offset from a newest-first list of `count`
+    /// records. With `insert_after_first`, a new record is prepended once
+    /// the first page has been served. Returns the offsets requested.
+    async fn start_paged_audit_server(
+        count: usize,
+        insert_after_first: bool,
+    ) -> (String, Arc<Mutex<Vec<usize>>>) {
+        let offsets = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&offsets);
+        let ids = Mutex::new((0..count).rev().collect::<Vec<usize>>());
+        let base_url = mock_server::serve(move |request| {
+            let param = |name: &str| {
+                request
+                    .query()
+                    .unwrap_or_default()
+                    .split('&')
+                    .find_map(|p| p.strip_prefix(name)?.strip_prefix('='))
+                    .and_then(|v| v.parse::<usize>().ok())
+            };
+            let offset = param("offset").unwrap_or(0);
+            let limit = param("limit").unwrap_or(50);
+            seen.lock().unwrap().push(offset);
+
+            let mut ids = ids.lock().unwrap();
+            let records: Vec<serde_json::Value> = ids
+                .iter()
+                .skip(offset)
+                .take(limit)
+                .map(|&i| paged_record(i))
+                .collect();
+            let body = serde_json::json!({
+                "records": records,
+                "total": ids.len(),
+                "limit": limit,
+                "offset": offset,
+            });
+            if insert_after_first && offset == 0 {
+                let next = ids.len();
+                ids.insert(0, next);
+            }
+            Response::json(200, &body)
+        })
+        .await;
+        (base_url, offsets)
+    }
+
+    fn paged_record(i: usize) -> serde_json::Value {
+        serde_json::json!({
+            "id": format!("rec-{i}"),
+            "action_id": format!("act-{i}"),
+            "namespace": "ns",
+            "tenant": "t1",
+            "provider": "email",
+            "action_type": "send",
+            "verdict": "allow",
+            "outcome": "executed",
+            "matched_rule": null,
+            "duration_ms": 5,
+            "dispatched_at": "2026-01-01T00:00:00Z",
+        })
+    }
+
+    /// Serve `GET /v1/audit` by cursor from a newest-first list of `count`
+    /// records, prepending a new record once the first page has been
+    /// served. A cursor names the last record of the previous page. Returns
+    /// the query strings requested.
+    async fn start_cursor_audit_server(count: usize) -> (String, Arc<Mutex<Vec<String>>>) {
+        let queries = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&queries);
+        let ids = Mutex::new((0..count).rev().collect::<Vec<usize>>());
+        let base_url = mock_server::serve(move |request| {
+            let query = request.query().unwrap_or_default().to_owned();
+            let param = |name: &str| {
+                query
+                    .split('&')
+                    .find_map(|p| p.strip_prefix(name)?.strip_prefix('='))
+                    .map(str::to_owned)
+            };
+            let mut ids = ids.lock().unwrap();
+            let limit = param("limit").and_then(|v| v.parse().ok()).unwrap_or(50);
+            let start = param("cursor")
+                .and_then(|c| c.strip_prefix("rec-")?.parse::<usize>().ok())
+                .and_then(|last| ids.iter().position(|&i| i == last))
+                .map_or(0, |position| position + 1);
+            let first = param("cursor").is_none();
+            seen.lock().unwrap().push(query.clone());
+
+            let page: Vec<usize> = ids.iter().skip(start).take(limit).copied().collect();
+            let next_cursor = (start + page.len() < ids.len())
+                .then(|| page.last().map(|i| format!("rec-{i}")))
+                .flatten();
+            let body = serde_json::json!({
+                "records": page.iter().map(|&i| paged_record(i)).collect::<Vec<_>>(),
+                "total": first.then_some(ids.len()),
+                "limit": limit,
+                "offset": 0,
+                "next_cursor": next_cursor,
+            });
+            if first {
+                let next = ids.len();
+                ids.insert(0, next);
+            }
+            Response::json(200, &body)
+        })
+        .await;
+        (base_url, queries)
+    }
+
+    fn page_query(limit: u32) -> AuditQuery {
+        AuditQuery {
+            limit: Some(limit),
+            ..AuditQuery::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn audit_stream_stops_after_total() {
+        let (base_url, offsets) = start_paged_audit_server(4, false).await;
+        let client = ActeonClient::new(base_url);
+
+        let ids: Vec<String> = client
+            .audit_stream(&page_query(2))
+            .map(|r| r.unwrap().id)
+            .collect()
+            .await;
+
+        assert_eq!(ids, ["rec-3", "rec-2", "rec-1", "rec-0"]);
+        // Two full pages cover `total`, so no empty third page is fetched.
+        assert_eq!(*offsets.lock().unwrap(), [0, 2]);
+    }
+
+    #[tokio::test]
+    async fn audit_stream_skips_records_shifted_by_inserts() {
+        let (base_url, offsets) = start_paged_audit_server(5, true).await;
+        let client = ActeonClient::new(base_url);
+
+        let ids: Vec<String> = client
+            .audit_stream(&page_query(2))
+            .map(|r| r.unwrap().id)
+            .collect()
+            .await;
+
+        // `rec-5` arrives after the walk starts; `rec-3` shifts onto the
+        // second page but is not yielded twice.
+        assert_eq!(ids, ["rec-4", "rec-3", "rec-2", "rec-1", "rec-0"]);
+        assert_eq!(*offsets.lock().unwrap(), [0, 2, 4]);
+    }
+
+    #[tokio::test]
+    async fn audit_stream_follows_cursors_past_inserts() {
+        let (base_url, queries) = start_cursor_audit_server(5).await;
+        let client = ActeonClient::new(base_url);
+
+        let ids: Vec<String> = client
+            .audit_stream(&page_query(2))
+            .map(|r| r.unwrap().id)
+            .collect()
+            .await;
+
+        // `rec-5` arrives after the walk starts, but cursors keep later
+        // pages in place and no offset is sent alongside them.
+        assert_eq!(ids, ["rec-4", "rec-3", "rec-2", "rec-1", "rec-0"]);
+        assert_eq!(
+            *queries.lock().unwrap(),
+            ["limit=2", "limit=2&cursor=rec-3", "limit=2&cursor=rec-1"]
+        );
+
+        let query = AuditQuery {
+            offset: Some(10),
+            cursor: Some("rec-3".into()),
+            ..page_query(2)
+        };
+        client.query_audit(&query).await.unwrap();
+        assert_eq!(
+            queries.lock().unwrap().last().unwrap(),
+            "limit=2&cursor=rec-3"
+        );
+    }
 }
@@ -0,0 +1,401 @@
+//! Resumable bulk export of the audit trail.
+//!
+//! [`ActeonClient::export_audit`] pages through `/v1/audit` with the
+//! server's opaque cursor and streams every record to an [`AsyncWrite`] as
+//! NDJSON or CSV, so multi-gigabyte archives never sit in memory. Progress
+//! is tracked in an [`ExportCheckpoint`]; when an export fails part-way the
+//! error carries the checkpoint and
+//! [`ActeonClient::export_audit_from`] picks up at the next unwritten
+//! record.
+
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{ActeonClient, AuditQuery, AuditRecord, Error};
+
+/// Page size used when the query does not set `limit` (the server maximum).
+const DEFAULT_EXPORT_PAGE_SIZE: u32 = 1000;
+
+/// Columns written by [`ExportFormat::Csv`], in order.
+const CSV_HEADER: &str = "id,action_id,namespace,tenant,provider,action_type,verdict,outcome,\
+matched_rule,duration_ms,dispatched_at,record_hash,previous_hash,sequence_number\n";
+
+/// Output format for [`ActeonClient::export_audit`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// One JSON object per line.
+    #[default]
+    Ndjson,
+    /// RFC 4180 CSV with a header row.
+    Csv,
+}
+
+/// Position of an export in the audit trail.
+///
+/// Persist this (it is serializable) and pass it to
+/// [`ActeonClient::export_audit_from`] to resume an interrupted export
+/// without duplicating or skipping records.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportCheckpoint {
+    /// Cursor of the page containing the next unwritten record (`None` for
+    /// the first page).
+    pub cursor: Option<String>,
+    /// Records of that page already written.
+    pub page_offset: usize,
+    /// Total records written so far.
+    pub records_written: u64,
+    /// `dispatched_at` of the last record written.
+    pub last_dispatched_at: Option<String>,
+    /// `id` of the last record written.
+    pub last_id: Option<String>,
+}
+
+/// Summary of a completed export.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportStats {
+    /// Total records written, including those written before a resume.
+    pub records: u64,
+    /// Pages fetched by this call.
+    pub pages: u64,
+    /// Final position; resuming from it writes nothing.
+    pub checkpoint: ExportCheckpoint,
+}
+
+impl ActeonClient {
+    /// Export every audit record matching `query` to `writer`.
+    ///
+    /// `query.limit` sets the page size and `query.offset` is ignored. If
+    /// the export fails part-way, the returned
+    /// [`Error::ExportInterrupted`] carries the checkpoint to pass to
+    /// [`export_audit_from`](Self::export_audit_from).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::{ActeonClient, AuditQuery, ExportFormat};
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let mut archive = Vec::new();
+    /// let stats = client
+    ///     .export_audit(AuditQuery::default(), &mut archive, ExportFormat::Ndjson)
+    ///     .await?;
+    /// println!("exported {} records", stats.records);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_audit<W: AsyncWrite + Unpin>(
+        &self,
+        query: AuditQuery,
+        writer: W,
+        format: ExportFormat,
+    ) -> Result<ExportStats, Error> {
+        self.export_audit_from(query, writer, format, ExportCheckpoint::default())
+            .await
+    }
+
+    /// Resume an export from `checkpoint`, appending to `writer`.
+    ///
+    /// The CSV header is only written when nothing has been exported yet.
+    pub async fn export_audit_from<W: AsyncWrite + Unpin>(
+        &self,
+        mut query: AuditQuery,
+        mut writer: W,
+        format: ExportFormat,
+        mut checkpoint: ExportCheckpoint,
+    ) -> Result<ExportStats, Error> {
+        query.limit = Some(query.limit.unwrap_or(DEFAULT_EXPORT_PAGE_SIZE));
+        query.offset = None;
+        let mut pages = 0;
+
+        let interrupted = |checkpoint: &ExportCheckpoint, source: Error| Error::ExportInterrupted {
+            checkpoint: Box::new(checkpoint.clone()),
+            source: Box::new(source),
+        };
+
+        if format == ExportFormat::Csv && checkpoint.records_written == 0 {
+            write_all(&mut writer, CSV_HEADER.as_bytes())
+                .await
+                .map_err(|e| interrupted(&checkpoint, e))?;
+        }
+
+        loop {
+            query.cursor.clone_from(&checkpoint.cursor);
+            let page = self
+                .query_audit(&query)
+                .await
+                .map_err(|e| interrupted(&checkpoint, e))?;
+            pages += 1;
+
+            for record in page.records.iter().skip(checkpoint.page_offset) {
+                let line = encode_record(record, format);
+                write_all(&mut writer, line.as_bytes())
+                    .await
+                    .map_err(|e| interrupted(&checkpoint, e))?;
+                checkpoint.page_offset += 1;
+                checkpoint.records_written += 1;
+                checkpoint.last_dispatched_at = Some(record.dispatched_at.clone());
+                checkpoint.last_id = Some(record.id.clone());
+            }
+
+            match page.next_cursor {
+                Some(next) if !page.records.is_empty() => {
+                    checkpoint.cursor = Some(next);
+                    checkpoint.page_offset = 0;
+                }
+                _ => break,
+            }
+        }
+
+        writer
+            .flush()
+            .await
+            .map_err(|e| interrupted(&checkpoint, Error::Io(e.to_string())))?;
+
+        Ok(ExportStats {
+            records: checkpoint.records_written,
+            pages,
+            checkpoint,
+        })
+    }
+}
+
+async fn write_all<W: AsyncWrite + Unpin>(writer: &mut W, bytes: &[u8]) -> Result<(), Error> {
+    writer
+        .write_all(bytes)
+        .await
+        .map_err(|e| Error::Io(e.to_string()))
+}
+
+fn encode_record(record: &AuditRecord, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Ndjson => {
+            // Serializing a struct of strings and integers cannot fail.
+            let mut line = serde_json::to_string(record).unwrap_or_default();
+            line.push('\n');
+            line
+        }
+        ExportFormat::Csv => {
+            let fields = [
+                csv_field(&record.id),
+                csv_field(&record.action_id),
+                csv_field(&record.namespace),
+                csv_field(&record.tenant),
+                csv_field(&record.provider),
+                csv_field(&record.action_type),
+                csv_field(&record.verdict),
+                csv_field(&record.outcome),
+                csv_field(record.matched_rule.as_deref().unwrap_or_default()),
+                record.duration_ms.to_string(),
+                csv_field(&record.dispatched_at),
+                csv_field(record.record_hash.as_deref().unwrap_or_default()),
+                csv_field(record.previous_hash.as_deref().unwrap_or_default()),
+                record
+                    .sequence_number
+                    .map(|n| n.to_string())
+                    .unwrap_or_default(),
+            ];
+            let mut line = fields.join(",");
+            line.push('\n');
+            line
+        }
+    }
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    fn record(n: u32) -> serde_json::Value {
+        serde_json::json!({
+            "id": format!("rec-{n}"),
+            "action_id": format!("act-{n}"),
+            "namespace": "ns",
+            "tenant": "t1",
+            "provider": "email",
+            "action_type": "send",
+            "verdict": "allow",
+            "outcome": "executed",
+            "matched_rule": if n == 2 { Some("a,\"b\"") } else { None },
+            "duration_ms": 5,
+            "dispatched_at": format!("2026-01-01T00:00:0{n}Z"),
+        })
+    }
+
+    /// Serve three pages (3, 3, and 1 records) keyed by the `cursor` query
+    /// parameter, for as many requests as the test makes.
+    async fn start_audit_server() -> String {
+        let pages: HashMap<&str, serde_json::Value> = HashMap::from([
+            (
+                "",
+                serde_json::json!({"records": [record(1), record(2), record(3)],
+                    "limit": 3, "offset": 0, "next_cursor": "c1"}),
+            ),
+            (
+                "c1",
+                serde_json::json!({"records": [record(4), record(5), record(6)],
+                    "limit": 3, "offset": 0, "next_cursor": "c2"}),
+            ),
+            (
+                "c2",
+                serde_json::json!({"records": [record(7)],
+                    "limit": 3, "offset": 0, "next_cursor": null}),
+            ),
+        ]);
+        mock_server::serve(move |request| {
+            let cursor = request
+                .query()
+                .unwrap_or_default()
+                .split('&')
+                .find_map(|p| p.strip_prefix("cursor="))
+                .unwrap_or_default();
+            Response::json(200, &pages[cursor])
+        })
+        .await
+    }
+
+    /// An in-memory writer that fails after `writes_left` successful writes.
+    struct FlakyWriter {
+        buf: Vec<u8>,
+        writes_left: Option<usize>,
+    }
+
+    impl AsyncWrite for FlakyWriter {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            data: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            match self.writes_left {
+                Some(0) => return Poll::Ready(Err(io::Error::other("disk full"))),
+                Some(ref mut n) => *n -= 1,
+                None => {}
+            }
+            self.buf.extend_from_slice(data);
+            Poll::Ready(Ok(data.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    fn ids(ndjson: &[u8]) -> Vec<String> {
+        String::from_utf8_lossy(ndjson)
+            .lines()
+            .map(|l| {
+                let v: serde_json::Value = serde_json::from_str(l).unwrap();
+                v["id"].as_str().unwrap().to_owned()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn exports_all_pages_as_ndjson() {
+        let client = ActeonClient::new(start_audit_server().await);
+        let mut buf = Vec::new();
+        let stats = client
+            .export_audit(AuditQuery::default(), &mut buf, ExportFormat::Ndjson)
+            .await
+            .unwrap();
+
+        assert_eq!(stats.records, 7);
+        assert_eq!(stats.pages, 3);
+        assert_eq!(stats.checkpoint.last_id.as_deref(), Some("rec-7"));
+        assert_eq!(
+            ids(&buf),
+            (1..=7).map(|n| format!("rec-{n}")).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn resumes_after_mid_page_failure_without_duplicates() {
+        let client = ActeonClient::new(start_audit_server().await);
+
+        // Let exactly four records through, failing on the second record of
+        // the second page.
+        let mut writer = FlakyWriter {
+            buf: Vec::new(),
+            writes_left: Some(4),
+        };
+        let err = client
+            .export_audit(AuditQuery::default(), &mut writer, ExportFormat::Ndjson)
+            .await
+            .unwrap_err();
+        let Error::ExportInterrupted { checkpoint, source } = err else {
+            panic!("expected ExportInterrupted, got {err:?}");
+        };
+        assert!(matches!(*source, Error::Io(_)));
+        assert_eq!(checkpoint.records_written, 4);
+        assert_eq!(checkpoint.cursor.as_deref(), Some("c1"));
+        assert_eq!(checkpoint.page_offset, 1);
+        assert_eq!(checkpoint.last_id.as_deref(), Some("rec-4"));
+
+        writer.writes_left = None;
+        let stats = client
+            .export_audit_from(
+                AuditQuery::default(),
+                &mut writer,
+                ExportFormat::Ndjson,
+                *checkpoint,
+            )
+            .await
+            .unwrap();
+        assert_eq!(stats.records, 7);
+        assert_eq!(
+            ids(&writer.buf),
+            (1..=7).map(|n| format!("rec-{n}")).collect::<Vec<_>>()
+        );
+
+        // Resuming from a finished export writes nothing.
+        let before = writer.buf.len();
+        let again = client
+            .export_audit_from(
+                AuditQuery::default(),
+                &mut writer,
+                ExportFormat::Ndjson,
+                stats.checkpoint,
+            )
+            .await
+            .unwrap();
+        assert_eq!(again.records, 7);
+        assert_eq!(writer.buf.len(), before);
+    }
+
+    #[tokio::test]
+    async fn csv_has_one_header_and_escapes_fields() {
+        let client = ActeonClient::new(start_audit_server().await);
+        let mut buf = Vec::new();
+        let stats = client
+            .export_audit(AuditQuery::default(), &mut buf, ExportFormat::Csv)
+            .await
+            .unwrap();
+        assert_eq!(stats.records, 7);
+
+        let text = String::from_utf8(buf).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 8);
+        assert_eq!(format!("{}\n", lines[0]), CSV_HEADER);
+        assert!(lines[2].contains(",\"a,\"\"b\"\"\","), "{}", lines[2]);
+    }
+}
@@ -0,0 +1,263 @@
+//! Streaming batch dispatch.
+//!
+//! [`ActeonClient::dispatch_batch_stream`] asks the server for NDJSON, one
+//! `{"index": …, "result": …}` line per action written as soon as that
+//! action finishes, so results of a large batch can be handled while the
+//! rest are still running. Servers that only answer with the usual JSON
+//! array are handled too: the array is read in full and then yielded.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{Stream, StreamExt};
+use serde::Deserialize;
+use tokio::io::AsyncBufReadExt;
+use tokio_util::io::StreamReader;
+
+use crate::{ActeonClient, Action, BatchResult, Error, ErrorResponse};
+
+/// Media type of a streamed batch response.
+pub(crate) const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Error code of the batch results [`ActeonClient::dispatch_batch`] fills
+/// in for actions whose streamed result never arrived.
+pub const TRUNCATED_RESULT_CODE: &str = "RESPONSE_TRUNCATED";
+
+/// A result and the index of its action, or why it could not be read.
+type IndexedResult = Result<(usize, BatchResult), Error>;
+
+/// One line of a streamed batch response.
+#[derive(Debug, Deserialize)]
+struct BatchStreamLine {
+    /// Position of the action in the request.
+    index: usize,
+    result: BatchResult,
+}
+
+/// Results of [`ActeonClient::dispatch_batch_stream`], each paired with the
+/// index of its action in the request.
+///
+/// Results arrive in completion order, not request order. A line that
+/// cannot be parsed is yielded as [`Error::Deserialization`] and the stream
+/// continues; a dropped connection ends it with [`Error::Connection`].
+pub struct BatchResultStream {
+    inner: Pin<Box<dyn Stream<Item = IndexedResult> + Send>>,
+}
+
+impl Stream for BatchResultStream {
+    type Item = IndexedResult;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl BatchResultStream {
+    /// Stream the results of a batch response, reading NDJSON as it
+    /// arrives or, for any other content type, a buffered JSON array.
+    pub(crate) async fn from_response(response: reqwest::Response) -> Result<Self, Error> {
+        let is_ndjson = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with(NDJSON_CONTENT_TYPE));
+        if !is_ndjson {
+            let results = crate::dispatch::read_batch_results(response).await?;
+            return Ok(Self {
+                inner: Box::pin(futures::stream::iter(
+                    results.into_iter().enumerate().map(Ok),
+                )),
+            });
+        }
+
+        let reader = StreamReader::new(
+            response
+                .bytes_stream()
+                .map(|chunk| chunk.map_err(std::io::Error::other)),
+        );
+        let lines = tokio::io::BufReader::new(reader).lines();
+        let stream = futures::stream::unfold(lines, |mut lines| async move {
+            loop {
+                return match lines.next_line().await {
+                    Ok(Some(line)) if line.trim().is_empty() => continue,
+                    Ok(Some(line)) => Some((parse_line(&line), lines)),
+                    Ok(None) => None,
+                    Err(e) => Some((
+                        Err(Error::Connection(format!("batch stream error: {e}"))),
+                        lines,
+                    )),
+                };
+            }
+        });
+        Ok(Self {
+            inner: Box::pin(stream),
+        })
+    }
+}
+
+impl BatchResultStream {
+    /// Collect the results into request order, for a batch of `len`
+    /// actions.
+    ///
+    /// Results that never arrive, because the stream ended early or their
+    /// line could not be parsed, become [`TRUNCATED_RESULT_CODE`] errors.
+    /// Their actions may still have run, so they are not marked retryable.
+    pub(crate) async fn collect_in_order(mut self, len: usize) -> Vec<BatchResult> {
+        let mut slots: Vec<Option<BatchResult>> = (0..len).map(|_| None).collect();
+        while let Some(item) = self.next().await {
+            match item {
+                Ok((index, result)) => {
+                    if let Some(slot) = slots.get_mut(index) {
+                        *slot = Some(result);
+                    }
+                }
+                Err(Error::Deserialization(_)) => {}
+                Err(_) => break,
+            }
+        }
+        slots
+            .into_iter()
+            .map(|slot| {
+                slot.unwrap_or_else(|| BatchResult::Error {
+                    error: ErrorResponse {
+                        code: TRUNCATED_RESULT_CODE.to_owned(),
+                        message: "result missing from a truncated batch response".to_owned(),
+                        retryable: false,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+fn parse_line(line: &str) -> IndexedResult {
+    serde_json::from_str::<BatchStreamLine>(line)
+        .map(|line| (line.index, line.result))
+        .map_err(|e| Error::Deserialization(format!("invalid batch stream line: {e}")))
+}
+
+impl ActeonClient {
+    /// Dispatch multiple actions, yielding each result as soon as the
+    /// server finishes its action.
+    ///
+    /// Each item pairs the result with the index of its action in
+    /// `actions`. The request is sent with `Accept: application/x-ndjson`;
+    /// if the server answers with a plain JSON array instead, the whole
+    /// array is read first and its results are yielded in order. Errors
+    /// sending the request are returned before any result, and are retried
+    /// like [`dispatch_batch`](Self::dispatch_batch).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::{ActeonClient, BatchResult};
+    /// use acteon_core::Action;
+    /// use futures::StreamExt;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let actions: Vec<Action> = (0..1000)
+    ///     .map(|i| Action::new("ns", "tenant", "email", "send", serde_json::json!({ "n": i })))
+    ///     .collect();
+    ///
+    /// let mut results = client.dispatch_batch_stream(&actions).await?;
+    /// while let Some(item) = results.next().await {
+    ///     let (index, result) = item?;
+    ///     if let BatchResult::Error { error } = result {
+    ///         println!("action {index} failed: {}", error.message);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dispatch_batch_stream(
+        &self,
+        actions: &[Action],
+    ) -> Result<BatchResultStream, Error> {
+        let response = self
+            .send_batch(actions, false, None, Some(NDJSON_CONTENT_TYPE))
+            .await?;
+        BatchResultStream::from_response(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    /// Answer every request with `body` as `content_type`, forwarding each
+    /// request's `Accept` header.
+    async fn serve(
+        content_type: &'static str,
+        body: String,
+    ) -> (String, mpsc::UnboundedReceiver<String>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let base_url = mock_server::serve(move |request| {
+            let _ = tx.send(request.header("accept").unwrap_or_default().to_owned());
+            Response::new(200).body(content_type, body.clone())
+        })
+        .await;
+        (base_url, rx)
+    }
+
+    fn actions(n: usize) -> Vec<Action> {
+        (0..n)
+            .map(|i| Action::new("ns", "t1", "email", "send", serde_json::json!({ "n": i })))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn ndjson_results_keep_their_indexes() {
+        let body = concat!(
+            r#"{"index":2,"result":"Deduplicated"}"#,
+            "\n\n",
+            r#"{"index":0,"result":{"error":{"code":"RULE","message":"denied","retryable":false}}}"#,
+            "\n",
+            "not json\n",
+            r#"{"index":1,"result":"Deduplicated"}"#,
+            "\n",
+        );
+        let (base_url, mut accept) = serve("application/x-ndjson", body.to_owned()).await;
+        let client = ActeonClient::new(base_url);
+
+        let items: Vec<_> = client
+            .dispatch_batch_stream(&actions(3))
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        let accept = accept.recv().await.unwrap();
+        assert!(accept.contains("application/x-ndjson"), "{accept}");
+        assert_eq!(items.len(), 4);
+        assert!(matches!(&items[0], Ok((2, BatchResult::Success(_)))));
+        assert!(matches!(
+            &items[1],
+            Ok((0, BatchResult::Error { error })) if error.message == "denied"
+        ));
+        assert!(matches!(&items[2], Err(Error::Deserialization(_))));
+        assert!(matches!(&items[3], Ok((1, BatchResult::Success(_)))));
+    }
+
+    #[tokio::test]
+    async fn json_array_responses_fall_back_to_buffered_results() {
+        let body = r#"["Deduplicated",{"error":{"code":"X","message":"boom"}}]"#;
+        let (base_url, _accept) = serve("application/json", body.to_owned()).await;
+        let client = ActeonClient::new(base_url);
+
+        let items: Vec<(usize, BatchResult)> = client
+            .dispatch_batch_stream(&actions(2))
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 2);
+        assert!(matches!(items[0], (0, BatchResult::Success(_))));
+        assert!(matches!(items[1], (1, BatchResult::Error { .. })));
+    }
+}
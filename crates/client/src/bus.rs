@@ -16,6 +16,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::dispatch::ErrorResponse;
 use crate::stream::{SseEnvelope, sse_envelope_stream};
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 const PATH_SEGMENT: &AsciiSet = &CONTROLS
@@ -221,9 +222,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusTopic>()
                 .await
@@ -242,9 +242,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .query(filter)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<ListBusTopicsResponse>()
                 .await
@@ -260,9 +259,8 @@ impl ActeonClient {
         let url = format!("{}/v1/bus/topics/{encoded}", self.base_url);
         let resp = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         let status = resp.status();
         if status == reqwest::StatusCode::NO_CONTENT || status == reqwest::StatusCode::NOT_FOUND {
             Ok(())
@@ -277,9 +275,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(msg)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<PublishReceipt>()
                 .await
@@ -300,9 +297,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusSubscription>()
                 .await
@@ -321,9 +317,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .query(filter)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<ListBusSubscriptionsResponse>()
                 .await
@@ -344,9 +339,8 @@ impl ActeonClient {
         let url = self.subscription_url(namespace, tenant, id, None);
         let resp = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         let status = resp.status();
         if status == reqwest::StatusCode::NO_CONTENT || status == reqwest::StatusCode::NOT_FOUND {
             Ok(())
@@ -375,9 +369,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(&position)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             Ok(())
         } else {
@@ -395,9 +388,8 @@ impl ActeonClient {
         let url = self.subscription_url(namespace, tenant, id, Some("lag"));
         let resp = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusLag>()
                 .await
@@ -420,9 +412,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<DeadLetterReceipt>()
                 .await
@@ -457,9 +448,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusSchema>()
                 .await
@@ -480,9 +470,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .query(filter)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<ListBusSchemasResponse>()
                 .await
@@ -502,9 +491,8 @@ impl ActeonClient {
         let url = self.schema_subject_url(namespace, tenant, subject);
         let resp = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<ListBusSchemasResponse>()
                 .await
@@ -526,9 +514,8 @@ impl ActeonClient {
         let url = self.schema_version_url(namespace, tenant, subject, version);
         let resp = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusSchema>()
                 .await
@@ -550,9 +537,8 @@ impl ActeonClient {
         let url = self.schema_version_url(namespace, tenant, subject, &version.to_string());
         let resp = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             Ok(())
         } else {
@@ -578,9 +564,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.put(&url))
             .json(&req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BindTopicSchemaResponse>()
                 .await
@@ -601,9 +586,8 @@ impl ActeonClient {
         let url = self.topic_schema_url(namespace, tenant, topic_name);
         let resp = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             Ok(())
         } else {
@@ -678,9 +662,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusAgent>()
                 .await
@@ -699,9 +682,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .query(filter)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<ListBusAgentsResponse>()
                 .await
@@ -721,9 +703,8 @@ impl ActeonClient {
         let url = self.agent_url(namespace, tenant, agent_id, None);
         let resp = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusAgent>()
                 .await
@@ -745,9 +726,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.put(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusAgent>()
                 .await
@@ -768,9 +748,8 @@ impl ActeonClient {
         let url = self.agent_url(namespace, tenant, agent_id, None);
         let resp = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             Ok(())
         } else {
@@ -795,9 +774,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.put(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusAgent>()
                 .await
@@ -818,9 +796,8 @@ impl ActeonClient {
         let url = self.agent_url(namespace, tenant, agent_id, Some("heartbeat"));
         let resp = self
             .add_auth(self.client.post(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusAgentHeartbeat>()
                 .await
@@ -843,9 +820,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusAgentSendReceipt>()
                 .await
@@ -884,9 +860,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusConversation>()
                 .await
@@ -905,9 +880,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .query(filter)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<ListBusConversationsResponse>()
                 .await
@@ -927,9 +901,8 @@ impl ActeonClient {
         let url = self.conversation_url(namespace, tenant, conversation_id, None);
         let resp = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusConversation>()
                 .await
@@ -952,9 +925,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.put(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusConversation>()
                 .await
@@ -975,9 +947,8 @@ impl ActeonClient {
         let url = self.conversation_url(namespace, tenant, conversation_id, None);
         let resp = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             Ok(())
         } else {
@@ -999,9 +970,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(&serde_json::json!({ "transition": transition }))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusConversation>()
                 .await
@@ -1025,9 +995,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusConversationAppendReceipt>()
                 .await
@@ -1051,9 +1020,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .query(params)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusConversationReplay>()
                 .await
@@ -1096,9 +1064,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         let status = resp.status();
         if !status.is_success() {
             return Err(map_error(resp).await);
@@ -1133,9 +1100,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusToolEnvelopeReceipt>()
                 .await
@@ -1167,9 +1133,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .query(params)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusToolResultLookup>()
                 .await
@@ -1251,9 +1216,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusStreamEnvelopeReceipt>()
                 .await
@@ -1277,9 +1241,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusStreamEnvelopeReceipt>()
                 .await
@@ -1308,9 +1271,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .query(params)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<ListBusApprovalsResponse>()
                 .await
@@ -1336,9 +1298,8 @@ impl ActeonClient {
         );
         let resp = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusApprovalView>()
                 .await
@@ -1393,9 +1354,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if resp.status().is_success() {
             resp.json::<BusApprovalDecisionResponse>()
                 .await
@@ -2287,9 +2247,8 @@ impl ActeonClient {
         let resp = self
             .add_auth(self.client.get(&url))
             .query(params)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if !resp.status().is_success() {
             return Err(map_error(resp).await);
         }
@@ -2336,9 +2295,8 @@ impl ActeonClient {
         let url = self.bus_stream_consume_url(namespace, tenant, conversation_id, stream_id);
         let resp = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if !resp.status().is_success() {
             return Err(map_error(resp).await);
         }
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+
+use acteon_core::{StreamEvent, StreamEventType};
+use futures::stream::{Stream, StreamExt};
+
+use crate::{ActeonClient, ChainDetailResponse, Error, StreamItem};
+
+/// A normalized progress snapshot for a chain execution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainProgressUpdate {
+    /// Progress in `0.0..=1.0`. Never decreases, and only reaches `1.0`
+    /// when the chain completes successfully.
+    pub fraction: f64,
+    /// Name of the step currently executing, if any.
+    pub current_step: Option<String>,
+    /// Terminal status (`completed`, `failed`, `cancelled`, `timed_out`)
+    /// once the chain has finished.
+    pub status: Option<String>,
+}
+
+/// Aggregates a chain's stream events into a progress fraction.
+///
+/// Branching chains can skip or revisit steps, so the total number of steps
+/// executed is not known up front. Progress is estimated as completed steps
+/// over completed plus remaining steps, where remaining steps are counted
+/// from the next step's position in the chain definition, plus one final
+/// unit for the chain's completion itself. Jumping ahead therefore moves
+/// progress forward; looping back holds it steady rather than moving it
+/// backwards.
+#[derive(Debug, Clone)]
+pub struct ChainProgress {
+    chain_id: String,
+    total_steps: usize,
+    step_indices: HashMap<String, usize>,
+    completed_steps: usize,
+    fraction: f64,
+    current_step: Option<String>,
+    status: Option<String>,
+}
+
+impl ChainProgress {
+    /// Track a chain with `total_steps` configured steps, assuming steps run
+    /// in order unless events say otherwise.
+    pub fn new(chain_id: impl Into<String>, total_steps: usize) -> Self {
+        Self {
+            chain_id: chain_id.into(),
+            total_steps,
+            step_indices: HashMap::new(),
+            completed_steps: 0,
+            fraction: 0.0,
+            current_step: None,
+            status: None,
+        }
+    }
+
+    /// Track a chain starting from its current state, as returned by
+    /// [`ActeonClient::get_chain`].
+    ///
+    /// The step list lets branch targets be located by name, which gives a
+    /// better estimate of the remaining work than assuming in-order steps.
+    pub fn from_detail(detail: &ChainDetailResponse) -> Self {
+        let mut progress = Self::new(&detail.chain_id, detail.total_steps);
+        progress.step_indices = detail
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.name.clone(), i))
+            .collect();
+        progress.completed_steps = detail
+            .steps
+            .iter()
+            .filter(|s| matches!(s.status.as_str(), "completed" | "skipped"))
+            .count();
+        progress.current_step = detail
+            .steps
+            .get(detail.current_step)
+            .map(|s| s.name.clone());
+        let remaining = detail.total_steps.saturating_sub(detail.current_step);
+        progress.advance(remaining);
+        if !matches!(
+            detail.status.as_str(),
+            "running"
+                | "waiting_sub_chain"
+                | "waiting_parallel"
+                | "waiting_timer"
+                | "waiting_signal"
+                | "waiting_worker"
+        ) {
+            progress.finish(&detail.status);
+        }
+        progress
+    }
+
+    /// The current progress snapshot.
+    pub fn snapshot(&self) -> ChainProgressUpdate {
+        ChainProgressUpdate {
+            fraction: self.fraction,
+            current_step: self.current_step.clone(),
+            status: self.status.clone(),
+        }
+    }
+
+    /// Whether the chain has reached a terminal state.
+    pub fn is_finished(&self) -> bool {
+        self.status.is_some()
+    }
+
+    /// Fold one stream event into the aggregate.
+    ///
+    /// Returns the new snapshot when the event belongs to this chain and
+    /// moved it forward; other events are ignored.
+    pub fn observe(&mut self, event: &StreamEvent) -> Option<ChainProgressUpdate> {
+        if self.is_finished() {
+            return None;
+        }
+        match &event.event_type {
+            StreamEventType::ChainStepCompleted {
+                chain_id,
+                step_index,
+                next_step,
+                ..
+            } if *chain_id == self.chain_id => {
+                self.completed_steps += 1;
+                let remaining = match next_step {
+                    Some(next) => {
+                        let next_index = self
+                            .step_indices
+                            .get(next)
+                            .copied()
+                            .unwrap_or(step_index + 1);
+                        self.total_steps.saturating_sub(next_index).max(1)
+                    }
+                    None => 0,
+                };
+                self.current_step.clone_from(next_step);
+                self.advance(remaining);
+            }
+            StreamEventType::ChainCompleted {
+                chain_id, status, ..
+            } if *chain_id == self.chain_id => {
+                self.finish(status);
+            }
+            _ => return None,
+        }
+        Some(self.snapshot())
+    }
+
+    /// Recompute the fraction with `remaining` steps left. The extra unit
+    /// in the denominator stands for the completion event, so only
+    /// [`finish`](Self::finish) reaches `1.0`.
+    #[allow(clippy::cast_precision_loss)]
+    fn advance(&mut self, remaining: usize) {
+        let done = self.completed_steps as f64;
+        let estimate = done / (done + remaining as f64 + 1.0);
+        self.fraction = self.fraction.max(estimate);
+    }
+
+    fn finish(&mut self, status: &str) {
+        if status == "completed" {
+            self.fraction = 1.0;
+        }
+        self.current_step = None;
+        self.status = Some(status.to_owned());
+    }
+}
+
+impl ActeonClient {
+    /// Watch a chain's progress as it executes.
+    ///
+    /// Subscribes to the chain's events, seeds a [`ChainProgress`] from the
+    /// chain's current state, and yields a [`ChainProgressUpdate`] for every
+    /// event that changes it. The first item is the seeded snapshot; the
+    /// stream ends after the chain reaches a terminal state.
+    pub async fn watch_chain_progress(
+        &self,
+        chain_id: &str,
+        namespace: &str,
+        tenant: &str,
+    ) -> Result<impl Stream<Item = Result<ChainProgressUpdate, Error>> + use<>, Error> {
+        // Subscribe before reading the state so no step event can slip
+        // between the two.
+        let events = self.subscribe_chain(chain_id).await?;
+        let detail = self.get_chain(chain_id, namespace, tenant).await?;
+        let progress = ChainProgress::from_detail(&detail);
+        let initial = progress.snapshot();
+
+        let updates = futures::stream::unfold(
+            (events, progress, false),
+            |(mut events, mut progress, failed)| async move {
+                if failed || progress.is_finished() {
+                    return None;
+                }
+                while let Some(item) = events.next().await {
+                    match item {
+                        Ok(StreamItem::Event(event)) => {
+                            if let Some(update) = progress.observe(&event) {
+                                return Some((Ok(update), (events, progress, false)));
+                            }
+                        }
+                        Ok(_) => {}
+                        Err(e) => return Some((Err(e), (events, progress, true))),
+                    }
+                }
+                None
+            },
+        );
+        Ok(futures::stream::once(async move { Ok(initial) }).chain(updates))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn step_completed(step_name: &str, step_index: usize, next: Option<&str>) -> StreamEvent {
+        event(StreamEventType::ChainStepCompleted {
+            chain_id: "chain-1".into(),
+            step_name: step_name.into(),
+            step_index,
+            success: true,
+            next_step: next.map(Into::into),
+        })
+    }
+
+    fn event(event_type: StreamEventType) -> StreamEvent {
+        StreamEvent {
+            id: "evt".into(),
+            timestamp: Utc::now(),
+            event_type,
+            namespace: "ns".into(),
+            tenant: "t1".into(),
+            action_type: None,
+            action_id: None,
+            traceparent: None,
+        }
+    }
+
+    #[test]
+    fn progress_is_monotonic_and_reaches_one_on_completion() {
+        let mut progress = ChainProgress::new("chain-1", 5);
+        progress.step_indices = ["fetch", "check", "retry", "notify", "archive"]
+            .iter()
+            .enumerate()
+            .map(|(i, n)| ((*n).to_owned(), i))
+            .collect();
+
+        // fetch -> check -> (loop back) retry -> check -> (branch past
+        // notify) archive -> done.
+        let events = [
+            step_completed("fetch", 0, Some("check")),
+            step_completed("check", 1, Some("retry")),
+            step_completed("retry", 2, Some("check")),
+            step_completed("check", 1, Some("archive")),
+            step_completed("archive", 4, None),
+            event(StreamEventType::ChainCompleted {
+                chain_id: "chain-1".into(),
+                status: "completed".into(),
+                execution_path: vec![],
+            }),
+        ];
+
+        let mut last = 0.0;
+        for e in &events {
+            let update = progress.observe(e).expect("chain event should update");
+            assert!(
+                update.fraction >= last,
+                "progress went backwards: {last} -> {}",
+                update.fraction
+            );
+            if update.status.is_none() {
+                assert!(update.fraction < 1.0, "reached 1.0 before completion");
+            }
+            last = update.fraction;
+        }
+        let done = progress.snapshot();
+        assert!((done.fraction - 1.0).abs() < f64::EPSILON);
+        assert_eq!(done.status.as_deref(), Some("completed"));
+        assert_eq!(done.current_step, None);
+    }
+
+    #[test]
+    fn other_chains_and_events_are_ignored() {
+        let mut progress = ChainProgress::new("chain-1", 2);
+        let other = event(StreamEventType::ChainStepCompleted {
+            chain_id: "chain-2".into(),
+            step_name: "a".into(),
+            step_index: 0,
+            success: true,
+            next_step: Some("b".into()),
+        });
+        assert!(progress.observe(&other).is_none());
+        let unrelated = event(StreamEventType::ChainAdvanced {
+            chain_id: "chain-1".into(),
+        });
+        assert!(progress.observe(&unrelated).is_none());
+        assert!(progress.snapshot().fraction.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn failed_chain_keeps_partial_progress() {
+        let mut progress = ChainProgress::new("chain-1", 4);
+        progress.observe(&step_completed("a", 0, Some("b")));
+        let update = progress
+            .observe(&event(StreamEventType::ChainCompleted {
+                chain_id: "chain-1".into(),
+                status: "failed".into(),
+                execution_path: vec![],
+            }))
+            .unwrap();
+        assert!(update.fraction > 0.0 && update.fraction < 1.0);
+        assert_eq!(update.status.as_deref(), Some("failed"));
+    }
+}
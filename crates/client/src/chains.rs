@@ -1,8 +1,29 @@
+use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
 use crate::dispatch::ErrorResponse;
-use crate::{ActeonClient, Error};
+use crate::transport::GatedSend;
+use crate::{ActeonClient, Error, WaitOptions};
+
+/// Every chain status the server can filter on, in the order
+/// [`ActeonClient::list_chains_stream`] visits them.
+pub const CHAIN_STATUSES: &[&str] = &[
+    "running",
+    "waiting_sub_chain",
+    "waiting_parallel",
+    "waiting_timer",
+    "waiting_signal",
+    "waiting_worker",
+    "completed",
+    "failed",
+    "cancelled",
+    "timed_out",
+];
+
+/// Chain statuses after which a chain makes no further progress.
+pub const TERMINAL_CHAIN_STATUSES: &[&str] = &["completed", "failed", "cancelled", "timed_out"];
 
 /// Summary of a chain for list responses.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -236,6 +257,9 @@ pub struct ChainDefinitionSummary {
     /// Optional timeout in seconds.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timeout_seconds: Option<u64>,
+    /// Labels attached to the definition.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
 }
 
 /// Response from listing chain definitions.
@@ -243,6 +267,49 @@ pub struct ChainDefinitionSummary {
 pub struct ListChainDefinitionsResponse {
     /// List of chain definition summaries.
     pub definitions: Vec<ChainDefinitionSummary>,
+    /// Number of definitions matching the filters, before pagination.
+    #[serde(default)]
+    pub total: usize,
+}
+
+/// Filter parameters for listing chain definitions.
+#[derive(Debug, Default, Clone)]
+pub struct ChainDefinitionFilter {
+    /// Only match definitions whose name contains this substring
+    /// (case-insensitive).
+    pub name: Option<String>,
+    /// Labels a definition must carry, all with the given values.
+    pub labels: BTreeMap<String, String>,
+    /// Maximum number of results.
+    pub limit: Option<usize>,
+    /// Number of results to skip.
+    pub offset: Option<usize>,
+}
+
+impl ChainDefinitionFilter {
+    /// Encode the filter as query parameters.
+    fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(name) = &self.name {
+            pairs.push(("name", name.clone()));
+        }
+        if !self.labels.is_empty() {
+            let selector = self
+                .labels
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            pairs.push(("label", selector));
+        }
+        if let Some(limit) = self.limit {
+            pairs.push(("limit", limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            pairs.push(("offset", offset.to_string()));
+        }
+        pairs
+    }
 }
 
 impl ActeonClient {
@@ -278,9 +345,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -296,6 +362,76 @@ impl ActeonClient {
         }
     }
 
+    /// Stream every chain in a namespace and tenant, across all statuses.
+    ///
+    /// Issues one [`list_chains`](Self::list_chains) request per entry in
+    /// [`CHAIN_STATUSES`] and yields the summaries as each response arrives.
+    /// A chain that changes status between requests is yielded only once.
+    /// The stream ends after the first error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    /// use futures::StreamExt;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let mut chains = std::pin::pin!(client.list_chains_stream("notifications", "tenant-1"));
+    /// while let Some(chain) = chains.next().await {
+    ///     let chain = chain?;
+    ///     println!("{}: {}", chain.chain_id, chain.status);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_chains_stream<'a>(
+        &'a self,
+        namespace: &'a str,
+        tenant: &'a str,
+    ) -> impl Stream<Item = Result<ChainSummary, Error>> + 'a {
+        struct State {
+            next_status: usize,
+            buffered: VecDeque<ChainSummary>,
+            seen: HashSet<String>,
+            failed: bool,
+        }
+
+        let state = State {
+            next_status: 0,
+            buffered: VecDeque::new(),
+            seen: HashSet::new(),
+            failed: false,
+        };
+
+        futures::stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(chain) = state.buffered.pop_front() {
+                    return Some((Ok(chain), state));
+                }
+                if state.failed {
+                    return None;
+                }
+                let status = CHAIN_STATUSES.get(state.next_status)?;
+                state.next_status += 1;
+                match self.list_chains(namespace, tenant, Some(status)).await {
+                    Ok(page) => {
+                        let seen = &mut state.seen;
+                        state.buffered.extend(
+                            page.chains
+                                .into_iter()
+                                .filter(|c| seen.insert(c.chain_id.clone())),
+                        );
+                    }
+                    Err(e) => {
+                        state.failed = true;
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+    }
+
     /// Get the full details of a chain by ID.
     ///
     /// # Example
@@ -321,9 +457,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -344,6 +479,62 @@ impl ActeonClient {
         }
     }
 
+    /// Poll a chain with [`get_chain`](Self::get_chain) until it reaches a
+    /// terminal status.
+    ///
+    /// Returns the chain's details as soon as its status is one of
+    /// [`TERMINAL_CHAIN_STATUSES`]. Fails with [`Error::Timeout`] if
+    /// `opts.timeout` elapses first. Errors from `get_chain`, including the
+    /// 404 for an unknown chain, end the wait immediately. Polling backs off
+    /// as described by [`poll_until`](Self::poll_until).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use std::time::Duration;
+    /// use acteon_client::{ActeonClient, PollInterval, WaitOptions};
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let opts = WaitOptions {
+    ///     poll_interval: PollInterval::fixed(Duration::from_millis(500)),
+    ///     timeout: Duration::from_secs(60),
+    /// };
+    /// let detail = client
+    ///     .wait_for_chain("chain-123", "notifications", "tenant-1", opts)
+    ///     .await?;
+    /// println!("chain finished: {}", detail.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_chain(
+        &self,
+        chain_id: &str,
+        namespace: &str,
+        tenant: &str,
+        opts: WaitOptions,
+    ) -> Result<ChainDetailResponse, Error> {
+        // Last status seen, for the timeout message.
+        let mut last_status = String::from("unfinished");
+        self.poll_until(
+            || self.get_chain(chain_id, namespace, tenant),
+            |detail| {
+                last_status.clone_from(&detail.status);
+                TERMINAL_CHAIN_STATUSES.contains(&detail.status.as_str())
+            },
+            &opts.poll_interval,
+            std::time::Instant::now() + opts.timeout,
+        )
+        .await
+        .map_err(|e| match e {
+            Error::Timeout(_) => Error::Timeout(format!(
+                "chain {chain_id} still {last_status} after {:?}",
+                opts.timeout
+            )),
+            other => other,
+        })
+    }
+
     /// Get per-step execution history with retry attempts for a chain.
     ///
     /// # Example
@@ -371,9 +562,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -438,9 +628,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(&body)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -494,9 +683,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -539,9 +727,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -568,13 +755,42 @@ impl ActeonClient {
 
     /// List all registered chain definitions.
     pub async fn list_chain_definitions(&self) -> Result<ListChainDefinitionsResponse, Error> {
+        self.list_chain_definitions_filtered(&ChainDefinitionFilter::default())
+            .await
+    }
+
+    /// List chain definitions matching a name substring and/or labels, one
+    /// page at a time. Results are sorted by name.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use acteon_client::{ActeonClient, ChainDefinitionFilter};
+    ///
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let mut filter = ChainDefinitionFilter {
+    ///     name: Some("onboarding".into()),
+    ///     limit: Some(50),
+    ///     ..Default::default()
+    /// };
+    /// filter.labels.insert("team".into(), "growth".into());
+    /// let page = client.list_chain_definitions_filtered(&filter).await?;
+    /// println!("{} of {} definitions", page.definitions.len(), page.total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_chain_definitions_filtered(
+        &self,
+        filter: &ChainDefinitionFilter,
+    ) -> Result<ListChainDefinitionsResponse, Error> {
         let url = format!("{}/v1/chains/definitions", self.base_url);
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .query(&filter.query_pairs())
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -596,9 +812,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -626,9 +841,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(config)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -665,9 +879,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() || response.status() == reqwest::StatusCode::NO_CONTENT {
             Ok(())
@@ -684,3 +897,216 @@ impl ActeonClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::PollInterval;
+    use crate::mock_server::{self, Response};
+
+    fn summary(id: &str, status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "chain_id": id,
+            "chain_name": "pipeline",
+            "status": status,
+            "current_step": 0,
+            "total_steps": 2,
+            "started_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+        })
+    }
+
+    /// Serve `GET /v1/chains`, answering each `status` filter from a fixed
+    /// table. `c2` shows up under both statuses, as if it completed between
+    /// requests.
+    async fn start_chains_server() -> String {
+        mock_server::serve(|request| {
+            let status = request
+                .query()
+                .unwrap_or_default()
+                .split('&')
+                .find_map(|p| p.strip_prefix("status="))
+                .unwrap_or_default();
+            let chains = match status {
+                "running" => vec![summary("c1", "running"), summary("c2", "running")],
+                "completed" => vec![summary("c2", "completed"), summary("c3", "completed")],
+                _ => vec![],
+            };
+            Response::json(200, &serde_json::json!({ "chains": chains }))
+        })
+        .await
+    }
+
+    /// Serve `GET /v1/chains/definitions`, applying the `name` filter the
+    /// way the server does.
+    async fn start_definitions_server() -> String {
+        mock_server::serve(|request| {
+            let needle = request
+                .query()
+                .unwrap_or_default()
+                .split('&')
+                .find_map(|p| p.strip_prefix("name="))
+                .unwrap_or_default();
+            let definitions: Vec<_> = ["billing-retry", "onboarding", "user-onboarding-v2"]
+                .into_iter()
+                .filter(|name| name.contains(needle))
+                .map(|name| {
+                    serde_json::json!({
+                        "name": name,
+                        "steps_count": 2,
+                        "has_branches": false,
+                        "has_parallel": false,
+                        "has_sub_chains": false,
+                        "on_failure": "Abort",
+                    })
+                })
+                .collect();
+            Response::json(
+                200,
+                &serde_json::json!({
+                    "total": definitions.len(),
+                    "definitions": definitions,
+                }),
+            )
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn list_chain_definitions_filtered_by_name() {
+        let client = ActeonClient::new(start_definitions_server().await);
+        let filter = ChainDefinitionFilter {
+            name: Some("onboarding".into()),
+            ..Default::default()
+        };
+        let page = client
+            .list_chain_definitions_filtered(&filter)
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = page.definitions.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, ["onboarding", "user-onboarding-v2"]);
+        assert_eq!(page.total, 2);
+    }
+
+    #[test]
+    fn chain_definition_filter_encodes_label_selector() {
+        let mut filter = ChainDefinitionFilter {
+            limit: Some(10),
+            ..Default::default()
+        };
+        filter.labels.insert("tier".into(), "gold".into());
+        filter.labels.insert("team".into(), "payments".into());
+        assert_eq!(
+            filter.query_pairs(),
+            [
+                ("label", "team=payments,tier=gold".to_string()),
+                ("limit", "10".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn list_chains_stream_yields_every_status() {
+        let client = ActeonClient::new(start_chains_server().await);
+        let chains: Vec<ChainSummary> = client
+            .list_chains_stream("ns", "t1")
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        let ids: Vec<&str> = chains.iter().map(|c| c.chain_id.as_str()).collect();
+        assert_eq!(ids, ["c1", "c2", "c3"]);
+        assert_eq!(chains[2].status, "completed");
+    }
+
+    #[tokio::test]
+    async fn list_chains_stream_stops_after_error() {
+        // Nothing listens on this port once the listener is dropped.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let client = ActeonClient::new(base_url);
+        let items: Vec<_> = client.list_chains_stream("ns", "t1").collect().await;
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], Err(Error::Connection(_))));
+    }
+
+    /// Serve `GET /v1/chains/{id}`: the chain reports `running` for the
+    /// first `running_polls` polls and `final_status` after that. Any id but
+    /// `c1` is a 404. Returns the base URL and the poll counter.
+    async fn start_polling_server(
+        running_polls: usize,
+        final_status: &'static str,
+    ) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let polls = std::sync::Arc::new(AtomicUsize::new(0));
+        let counter = std::sync::Arc::clone(&polls);
+        let base_url = mock_server::serve(move |request| {
+            if request.path() != "/v1/chains/c1" {
+                return Response::new(404);
+            }
+            let poll = counter.fetch_add(1, Ordering::SeqCst);
+            let status = if poll < running_polls {
+                "running"
+            } else {
+                final_status
+            };
+            let mut detail = summary("c1", status);
+            detail["steps"] = serde_json::json!([]);
+            Response::json(200, &detail)
+        })
+        .await;
+        (base_url, polls)
+    }
+
+    fn fast_wait(timeout: Duration) -> WaitOptions {
+        WaitOptions {
+            poll_interval: PollInterval::fixed(Duration::from_millis(5)),
+            timeout,
+        }
+    }
+
+    #[tokio::test]
+    async fn wait_for_chain_returns_once_terminal() {
+        use std::sync::atomic::Ordering;
+
+        for final_status in ["completed", "failed", "cancelled"] {
+            let (base_url, polls) = start_polling_server(3, final_status).await;
+            let client = ActeonClient::new(base_url);
+            let detail = client
+                .wait_for_chain("c1", "ns", "t1", fast_wait(Duration::from_secs(5)))
+                .await
+                .unwrap();
+            assert_eq!(detail.status, final_status);
+            assert_eq!(polls.load(Ordering::SeqCst), 4);
+        }
+    }
+
+    #[tokio::test]
+    async fn wait_for_chain_times_out_and_reports_missing_chains() {
+        let (base_url, _) = start_polling_server(usize::MAX, "completed").await;
+        let client = ActeonClient::new(base_url);
+
+        let err = client
+            .wait_for_chain("c1", "ns", "t1", fast_wait(Duration::from_millis(50)))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::Timeout(msg) if msg.contains("running")),
+            "{err:?}"
+        );
+
+        let err = client
+            .wait_for_chain("gone", "ns", "t1", fast_wait(Duration::from_secs(5)))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Http { status: 404, .. }), "{err:?}");
+    }
+}
@@ -1,6 +1,7 @@
 use acteon_core::{CircuitBreakerActionResponse, ListCircuitBreakersResponse};
 
 use crate::dispatch::ErrorResponse;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 impl ActeonClient {
@@ -12,9 +13,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -41,9 +41,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.post(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -78,9 +77,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.post(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
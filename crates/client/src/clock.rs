@@ -0,0 +1,144 @@
+//! Clock skew detection against the server.
+//!
+//! Signed approval URLs and `expires_at` checks compare client timestamps
+//! with the server's clock, so a skewed local clock shows up as spurious
+//! expiry or signature failures. These helpers read the server time from the
+//! HTTP `Date` header of the health endpoint and compare it with local time.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::transport::GatedSend;
+use crate::{ActeonClient, Error};
+
+/// Default tolerance for [`ActeonClient::check_clock_skew`].
+///
+/// The `Date` header has one-second resolution, so tolerances below a couple
+/// of seconds report noise rather than skew.
+pub const DEFAULT_CLOCK_SKEW_TOLERANCE: Duration = Duration::from_secs(5);
+
+/// Difference between the local clock and the server clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockSkew {
+    /// Server time, as reported by the `Date` header.
+    pub server_time: DateTime<Utc>,
+    /// Local time at the midpoint of the request.
+    pub local_time: DateTime<Utc>,
+}
+
+impl ClockSkew {
+    /// Signed offset of the server clock from the local clock.
+    ///
+    /// Positive when the server is ahead of this machine.
+    pub fn offset(&self) -> chrono::Duration {
+        self.server_time - self.local_time
+    }
+
+    /// Returns `true` if the clocks differ by more than `tolerance`, in
+    /// either direction.
+    pub fn exceeds(&self, tolerance: Duration) -> bool {
+        self.offset().abs().to_std().unwrap_or(Duration::MAX) > tolerance
+    }
+}
+
+impl ActeonClient {
+    /// Fetch the current server time from the `Date` header of `/health`.
+    pub async fn server_time(&self) -> Result<DateTime<Utc>, Error> {
+        Ok(self.measure_clock_skew().await?.server_time)
+    }
+
+    /// Measure the offset between the local clock and the server clock.
+    ///
+    /// Local time is sampled at the midpoint of the request to cancel out
+    /// most of the round-trip latency.
+    pub async fn measure_clock_skew(&self) -> Result<ClockSkew, Error> {
+        let url = format!("{}/health", self.base_url);
+        let sent_at = Utc::now();
+        let response = self
+            .add_auth(self.client.get(&url))
+            .send_gated(&self.gate)
+            .await?;
+        let received_at = Utc::now();
+
+        let header = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .ok_or_else(|| Error::Deserialization("server sent no Date header".to_string()))?;
+        let server_time = header
+            .to_str()
+            .ok()
+            .and_then(|value| DateTime::parse_from_rfc2822(value).ok())
+            .ok_or_else(|| Error::Deserialization(format!("invalid Date header: {header:?}")))?
+            .with_timezone(&Utc);
+
+        Ok(ClockSkew {
+            server_time,
+            local_time: sent_at + (received_at - sent_at) / 2,
+        })
+    }
+
+    /// Check whether the local clock has drifted from the server clock.
+    ///
+    /// Returns `Some` with the measured skew when the clocks differ by more
+    /// than `tolerance` (see [`DEFAULT_CLOCK_SKEW_TOLERANCE`]), and `None`
+    /// when they agree. Call this at startup to catch skew before it surfaces
+    /// as expired approval links.
+    pub async fn check_clock_skew(&self, tolerance: Duration) -> Result<Option<ClockSkew>, Error> {
+        let skew = self.measure_clock_skew().await?;
+        Ok(skew.exceeds(tolerance).then_some(skew))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    /// Answer every request with a `Date` header `offset` away from now.
+    async fn start_server_with_offset(offset: chrono::Duration) -> String {
+        mock_server::serve(move |_| {
+            let date = (Utc::now() + offset).format("%a, %d %b %Y %H:%M:%S GMT");
+            Response::json(200, &serde_json::json!({})).header("Date", date.to_string())
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn detects_server_clock_ahead() {
+        let offset = chrono::Duration::minutes(10);
+        let client = ActeonClient::new(start_server_with_offset(offset).await);
+
+        let skew = client
+            .check_clock_skew(DEFAULT_CLOCK_SKEW_TOLERANCE)
+            .await
+            .unwrap()
+            .expect("ten minutes of skew should be reported");
+        let drift = (skew.offset() - offset).num_seconds().abs();
+        assert!(drift <= 2, "measured offset {:?}", skew.offset());
+
+        let server_time = client.server_time().await.unwrap();
+        assert!(server_time > Utc::now() + chrono::Duration::minutes(9));
+    }
+
+    #[tokio::test]
+    async fn synchronized_clocks_report_no_skew() {
+        let client = ActeonClient::new(start_server_with_offset(chrono::Duration::zero()).await);
+        let skew = client
+            .check_clock_skew(DEFAULT_CLOCK_SKEW_TOLERANCE)
+            .await
+            .unwrap();
+        assert!(skew.is_none());
+    }
+
+    #[test]
+    fn exceeds_is_symmetric() {
+        let now = Utc::now();
+        let behind = ClockSkew {
+            server_time: now - chrono::Duration::seconds(30),
+            local_time: now,
+        };
+        assert!(behind.exceeds(Duration::from_secs(10)));
+        assert!(!behind.exceeds(Duration::from_secs(60)));
+    }
+}
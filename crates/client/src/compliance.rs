@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Current compliance configuration status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ComplianceStatus {
     /// The active compliance mode (`"none"`, `"soc2"`, or `"hipaa"`).
     pub mode: String,
@@ -13,6 +15,9 @@ pub struct ComplianceStatus {
     pub immutable_audit: bool,
     /// Whether `SHA-256` hash chaining is enabled for audit records.
     pub hash_chain: bool,
+    /// Whether actions are forbidden from opting out of payload encryption.
+    #[serde(default)]
+    pub forbid_encryption_opt_out: bool,
 }
 
 /// Result of verifying the integrity of an audit hash chain.
@@ -33,6 +38,22 @@ pub struct HashChainVerification {
     pub last_record_id: Option<String>,
 }
 
+/// Result of recomputing the hash of a single audit record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordHashVerification {
+    /// Action the record belongs to.
+    pub action_id: String,
+    /// ID of the verified record.
+    pub record_id: String,
+    /// Whether the stored hash matches the one computed from the record.
+    pub valid: bool,
+    /// The `record_hash` stored with the record, if it has one.
+    #[serde(default)]
+    pub stored_hash: Option<String>,
+    /// The hash the server recomputed from the record's canonical fields.
+    pub computed_hash: String,
+}
+
 /// Request body for hash chain verification.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerifyHashChainRequest {
@@ -54,9 +75,8 @@ impl ActeonClient {
         let url = format!("{}/v1/compliance/status", self.base_url);
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -80,9 +100,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -96,4 +115,72 @@ impl ActeonClient {
             })
         }
     }
+
+    /// Verify the `record_hash` of the most recent audit record for an
+    /// action against the hash the server recomputes from its content.
+    ///
+    /// Unlike [`verify_audit_chain`](Self::verify_audit_chain), this does
+    /// not check the record's link to the previous one in its chain.
+    pub async fn verify_audit_record(
+        &self,
+        action_id: &str,
+    ) -> Result<RecordHashVerification, Error> {
+        let url = format!("{}/v1/audit/{}/verify", self.base_url, action_id);
+        let response = self
+            .add_auth(self.client.get(&url))
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            response
+                .json::<RecordHashVerification>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: "Failed to verify audit record".into(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    /// Answer `GET /v1/audit/{id}/verify` as if `tampered` had been edited
+    /// after it was written.
+    async fn start_server() -> String {
+        mock_server::serve(|request| {
+            let body = if request.route() == "GET /v1/audit/tampered/verify" {
+                serde_json::json!({"action_id": "tampered", "record_id": "r2", "valid": false,
+                    "stored_hash": "abc", "computed_hash": "def"})
+            } else {
+                serde_json::json!({"action_id": "intact", "record_id": "r1", "valid": true,
+                    "stored_hash": "abc", "computed_hash": "abc"})
+            };
+            Response::json(200, &body)
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn verify_audit_record_reports_match_and_mismatch() {
+        let client = ActeonClient::new(start_server().await);
+
+        let intact = client.verify_audit_record("intact").await.unwrap();
+        assert!(intact.valid);
+        assert_eq!(
+            intact.stored_hash.as_deref(),
+            Some(intact.computed_hash.as_str())
+        );
+
+        let tampered = client.verify_audit_record("tampered").await.unwrap();
+        assert!(!tampered.valid);
+        assert_eq!(tampered.record_id, "r2");
+        assert_eq!(tampered.stored_hash.as_deref(), Some("abc"));
+        assert_eq!(tampered.computed_hash, "def");
+    }
 }
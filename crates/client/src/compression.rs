@@ -0,0 +1,107 @@
+//! Request body compression.
+//!
+//! With [`ActeonClientBuilder::compress_requests`] enabled, `POST` and `PUT`
+//! bodies of at least [`COMPRESSION_THRESHOLD`] bytes are gzip-compressed
+//! and sent with `Content-Encoding: gzip`. Smaller bodies gain little from
+//! compression and are sent as is, as are streamed bodies whose size is not
+//! known up front. Responses are decompressed by `reqwest` itself.
+//!
+//! [`ActeonClientBuilder::compress_requests`]: crate::ActeonClientBuilder::compress_requests
+
+use std::io::Write;
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use reqwest::header::{CONTENT_ENCODING, CONTENT_LENGTH, HeaderValue};
+use reqwest::{Method, Request};
+
+/// Smallest body, in bytes, that is compressed.
+pub const COMPRESSION_THRESHOLD: usize = 1024;
+
+/// Gzip-compress the body of `request` in place if it is a large enough
+/// `POST` or `PUT` body that is not already encoded.
+pub(crate) fn compress_request_body(request: &mut Request) {
+    if !matches!(*request.method(), Method::POST | Method::PUT)
+        || request.headers().contains_key(CONTENT_ENCODING)
+    {
+        return;
+    }
+    let Some(body) = request.body().and_then(reqwest::Body::as_bytes) else {
+        return;
+    };
+    if body.len() < COMPRESSION_THRESHOLD {
+        return;
+    }
+    let mut encoder = GzEncoder::new(Vec::with_capacity(body.len() / 4), Compression::default());
+    let Ok(compressed) = encoder.write_all(body).and_then(|()| encoder.finish()) else {
+        return;
+    };
+    let headers = request.headers_mut();
+    headers.insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+    headers.remove(CONTENT_LENGTH);
+    *request.body_mut() = Some(compressed.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+    use tokio::sync::mpsc;
+
+    use crate::ActeonClientBuilder;
+    use crate::mock_server::{self, Response};
+    use crate::transport::GatedSend;
+
+    /// Accept requests, forwarding each one's content encoding and body.
+    async fn start_server() -> (String, mpsc::UnboundedReceiver<(Option<String>, Vec<u8>)>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let base_url = mock_server::serve(move |request| {
+            let encoding = request.header("content-encoding").map(str::to_owned);
+            let _ = tx.send((encoding, request.body));
+            Response::new(200)
+        })
+        .await;
+        (base_url, rx)
+    }
+
+    #[tokio::test]
+    async fn compresses_only_large_bodies() {
+        let (base_url, mut requests) = start_server().await;
+        let client = ActeonClientBuilder::new(&base_url)
+            .compress_requests(true)
+            .build()
+            .unwrap();
+        let large = serde_json::json!({ "data": "x".repeat(64 * 1024) });
+        let tiny = serde_json::json!({ "data": "x" });
+
+        for body in [&large, &tiny] {
+            client
+                .client
+                .post(format!("{base_url}/v1/dispatch/batch"))
+                .json(body)
+                .send_gated(&client.gate)
+                .await
+                .unwrap();
+        }
+
+        let (encoding, body) = requests.recv().await.unwrap();
+        assert_eq!(encoding.as_deref(), Some("gzip"));
+        assert!(body.len() < 1024, "{} bytes", body.len());
+        let mut decoded = String::new();
+        GzDecoder::new(body.as_slice())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&decoded).unwrap(),
+            large
+        );
+
+        let (encoding, body) = requests.recv().await.unwrap();
+        assert_eq!(encoding, None);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            tiny
+        );
+    }
+}
@@ -8,6 +8,7 @@ pub use acteon_core::coverage::{
     CoverageAggregate, CoverageEntry, CoverageKey, CoverageQuery, CoverageReport,
 };
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 impl ActeonClient {
@@ -53,10 +54,7 @@ impl ActeonClient {
             req = req.query(&[("to", to.to_rfc3339())]);
         }
 
-        let response = req
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let response = req.send_gated(&self.gate).await?;
 
         if response.status().is_success() {
             response
@@ -0,0 +1,197 @@
+//! Layout helpers for chain DAGs returned by
+//! [`ActeonClient::get_chain_dag`](crate::ActeonClient::get_chain_dag) and
+//! [`ActeonClient::get_chain_definition_dag`](crate::ActeonClient::get_chain_definition_dag).
+//!
+//! These are pure functions over a [`DagResponse`], so any visualization can
+//! reuse them without talking to the server.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::DagResponse;
+
+/// Errors that can occur when ordering a chain DAG.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DagError {
+    /// Two nodes share the same name.
+    #[error("duplicate node: {0}")]
+    DuplicateNode(String),
+
+    /// An edge references a node that is not in the DAG.
+    #[error("edge {from} -> {to} references unknown node {unknown}")]
+    UnknownNode {
+        /// Source node of the offending edge.
+        from: String,
+        /// Target node of the offending edge.
+        to: String,
+        /// The name that matched no node.
+        unknown: String,
+    },
+
+    /// The graph contains a cycle. Lists every node that lies on or behind
+    /// a cycle, in node order.
+    #[error("cycle detected among nodes: {}", nodes.join(", "))]
+    Cycle {
+        /// Nodes that could not be ordered.
+        nodes: Vec<String>,
+    },
+}
+
+/// Group the nodes of `dag` into dependency levels.
+///
+/// Level 0 holds the nodes without incoming edges; every other node sits one
+/// level below the deepest of its predecessors, so each edge points to a
+/// strictly later level. Within a level, nodes keep their order in
+/// [`DagResponse::nodes`]. Only top-level nodes and edges are considered;
+/// nested sub-chain and parallel DAGs can be ordered separately.
+pub fn topological_levels(dag: &DagResponse) -> Result<Vec<Vec<String>>, DagError> {
+    let mut index: HashMap<&str, usize> = HashMap::with_capacity(dag.nodes.len());
+    for (i, node) in dag.nodes.iter().enumerate() {
+        if index.insert(node.name.as_str(), i).is_some() {
+            return Err(DagError::DuplicateNode(node.name.clone()));
+        }
+    }
+
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); dag.nodes.len()];
+    let mut in_degree = vec![0usize; dag.nodes.len()];
+    for edge in &dag.edges {
+        let lookup = |name: &str| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| DagError::UnknownNode {
+                    from: edge.source.clone(),
+                    to: edge.target.clone(),
+                    unknown: name.to_owned(),
+                })
+        };
+        let (from, to) = (lookup(&edge.source)?, lookup(&edge.target)?);
+        successors[from].push(to);
+        in_degree[to] += 1;
+    }
+
+    let mut levels = Vec::new();
+    let mut current: Vec<usize> = (0..dag.nodes.len())
+        .filter(|&i| in_degree[i] == 0)
+        .collect();
+    let mut placed = 0;
+    while !current.is_empty() {
+        placed += current.len();
+        let mut next = Vec::new();
+        for &node in &current {
+            for &succ in &successors[node] {
+                in_degree[succ] -= 1;
+                if in_degree[succ] == 0 {
+                    next.push(succ);
+                }
+            }
+        }
+        next.sort_unstable();
+        levels.push(current.iter().map(|&i| dag.nodes[i].name.clone()).collect());
+        current = next;
+    }
+
+    if placed < dag.nodes.len() {
+        let nodes = (0..dag.nodes.len())
+            .filter(|&i| in_degree[i] > 0)
+            .map(|i| dag.nodes[i].name.clone())
+            .collect();
+        return Err(DagError::Cycle { nodes });
+    }
+    Ok(levels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DagEdge, DagNode};
+
+    fn dag(nodes: &[&str], edges: &[(&str, &str)]) -> DagResponse {
+        DagResponse {
+            chain_name: "test".into(),
+            chain_id: None,
+            status: None,
+            nodes: nodes
+                .iter()
+                .map(|name| DagNode {
+                    name: (*name).into(),
+                    node_type: "step".into(),
+                    provider: None,
+                    action_type: None,
+                    sub_chain_name: None,
+                    status: None,
+                    child_chain_id: None,
+                    children: None,
+                    parallel_children: None,
+                    parallel_join: None,
+                })
+                .collect(),
+            edges: edges
+                .iter()
+                .map(|(source, target)| DagEdge {
+                    source: (*source).into(),
+                    target: (*target).into(),
+                    label: None,
+                    on_execution_path: false,
+                })
+                .collect(),
+            execution_path: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn linear_chain_has_one_node_per_level() {
+        let levels = topological_levels(&dag(&["a", "b", "c"], &[("a", "b"), ("b", "c")])).unwrap();
+        assert_eq!(levels, vec![vec!["a"], vec!["b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn diamond_groups_siblings_and_waits_for_deepest_parent() {
+        // a fans out to b and c, which join at d; e hangs off c and d, so it
+        // must sit below d even though c is only one level deep.
+        let levels = topological_levels(&dag(
+            &["a", "b", "c", "d", "e"],
+            &[
+                ("a", "c"),
+                ("a", "b"),
+                ("b", "d"),
+                ("c", "d"),
+                ("c", "e"),
+                ("d", "e"),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(
+            levels,
+            vec![vec!["a"], vec!["b", "c"], vec!["d"], vec!["e"]]
+        );
+    }
+
+    #[test]
+    fn cycle_is_reported() {
+        let err = topological_levels(&dag(
+            &["start", "a", "b", "c"],
+            &[("start", "a"), ("a", "b"), ("b", "c"), ("c", "a")],
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err,
+            DagError::Cycle {
+                nodes: vec!["a".into(), "b".into(), "c".into()],
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_and_duplicate_nodes_are_rejected() {
+        assert!(matches!(
+            topological_levels(&dag(&["a"], &[("a", "ghost")])),
+            Err(DagError::UnknownNode { unknown, .. }) if unknown == "ghost"
+        ));
+        assert_eq!(
+            topological_levels(&dag(&["a", "a"], &[])),
+            Err(DagError::DuplicateNode("a".into()))
+        );
+    }
+}
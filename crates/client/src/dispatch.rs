@@ -1,6 +1,12 @@
-use acteon_core::{Action, ActionOutcome, Attachment};
+use std::borrow::Cow;
+
+use acteon_core::{Action, ActionOutcome, Attachment, DispatchPreview};
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 
+use crate::batch_stream::{BatchResultStream, NDJSON_CONTENT_TYPE};
+use crate::idempotency::IDEMPOTENCY_KEY_HEADER;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Error response from the API.
@@ -78,6 +84,111 @@ impl ActeonClient {
         self.dispatch_inner(action, false).await
     }
 
+    /// Dispatch each action with its own request, running at most
+    /// `concurrency` requests at a time.
+    ///
+    /// Unlike [`dispatch_batch`](Self::dispatch_batch), every action is a
+    /// separate [`dispatch`](Self::dispatch), with its own retries and
+    /// idempotency key, and a failure affects only that action's result.
+    /// Results are returned in the order of `actions`. A `concurrency` of
+    /// zero is treated as one.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    /// use acteon_core::Action;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let actions: Vec<Action> = ["acme", "globex", "initech"]
+    ///     .into_iter()
+    ///     .map(|tenant| Action::new("ns", tenant, "email", "send", serde_json::json!({})))
+    ///     .collect();
+    ///
+    /// let results = client.dispatch_many(actions.clone(), 2).await;
+    /// for (action, result) in actions.iter().zip(results) {
+    ///     if let Err(e) = result {
+    ///         eprintln!("{} failed: {e}", action.tenant);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dispatch_many(
+        &self,
+        actions: Vec<Action>,
+        concurrency: usize,
+    ) -> Vec<Result<ActionOutcome, Error>> {
+        // Run unordered so one slow request doesn't hold back the next
+        // starts, then put the results back in request order.
+        let mut results: Vec<_> = futures::stream::iter(actions.into_iter().enumerate())
+            .map(|(i, action)| async move { (i, self.dispatch(&action).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+        results.sort_unstable_by_key(|(i, _)| *i);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Dispatch a single action with an `Idempotency-Key` header.
+    ///
+    /// Send the same `key` when re-sending an action after a timeout so the
+    /// server, or a proxy in front of it, can recognize the repeat. The key
+    /// overrides any generator set with
+    /// [`ActeonClientBuilder::idempotency_key_fn`](crate::ActeonClientBuilder::idempotency_key_fn).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    /// use acteon_core::Action;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let action = Action::new("ns", "tenant", "email", "send", serde_json::json!({}));
+    ///
+    /// let outcome = client
+    ///     .dispatch_with_idempotency(&action, &action.id.to_string())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dispatch_with_idempotency(
+        &self,
+        action: &Action,
+        key: &str,
+    ) -> Result<ActionOutcome, Error> {
+        let (outcome, _) = self.dispatch_with_headers(action, false, Some(key)).await?;
+        Ok(outcome)
+    }
+
+    /// Dispatch a single action and return only the provider's response body.
+    ///
+    /// Returns `Some(body)` when the action was executed and `None` for any
+    /// other outcome (suppressed, deduplicated, failed, ...). Use
+    /// [`dispatch`](Self::dispatch) when the outcome itself matters.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    /// use acteon_core::Action;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let action = Action::new("ns", "tenant", "email", "send", serde_json::json!({}));
+    ///
+    /// if let Some(body) = client.dispatch_body(&action).await? {
+    ///     println!("Provider said: {body}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dispatch_body(&self, action: &Action) -> Result<Option<serde_json::Value>, Error> {
+        Ok(self.dispatch(action).await?.into_response_body())
+    }
+
     /// Sign an action with an Ed25519 key, then dispatch it.
     ///
     /// Computes the action's canonical bytes, signs them, sets the
@@ -110,7 +221,8 @@ impl ActeonClient {
         action: &Action,
         key: &acteon_crypto::signing::ActionSigningKey,
     ) -> Result<ActionOutcome, Error> {
-        let mut signed = action.clone();
+        // Merge defaults first: metadata is covered by the signature.
+        let mut signed = self.with_default_metadata(action).into_owned();
         let canonical = signed.canonical_bytes();
         signed.signature = Some(key.sign(&canonical));
         signed.signer_id = Some(key.signer_id().to_owned());
@@ -138,7 +250,8 @@ impl ActeonClient {
         key: &acteon_crypto::signing::ActionSigningKey,
         kid: impl Into<String>,
     ) -> Result<ActionOutcome, Error> {
-        let mut signed = action.clone();
+        // Merge defaults first: metadata is covered by the signature.
+        let mut signed = self.with_default_metadata(action).into_owned();
         let canonical = signed.canonical_bytes();
         signed.signature = Some(key.sign(&canonical));
         signed.signer_id = Some(key.signer_id().to_owned());
@@ -170,6 +283,95 @@ impl ActeonClient {
         self.dispatch_inner(action, true).await
     }
 
+    /// Preview the provider request an action would produce.
+    ///
+    /// Runs rules and template rendering and returns the final payload,
+    /// verdict, and target provider without executing anything. Unlike
+    /// [`dispatch_dry_run`](Self::dispatch_dry_run), the result carries the
+    /// rendered request body.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    /// use acteon_core::Action;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let action = Action::new("ns", "tenant", "email", "send", serde_json::json!({"name": "Alice"}))
+    ///     .with_template("welcome");
+    ///
+    /// let preview = client.preview_dispatch(&action).await?;
+    /// println!("{:?} would receive {}", preview.provider, preview.payload);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn preview_dispatch(&self, action: &Action) -> Result<DispatchPreview, Error> {
+        let url = format!("{}/v1/dispatch/preview", self.base_url);
+        self.request_preview(&url, action).await
+    }
+
+    /// Dispatch a single action in dry-run mode and return the full preview.
+    ///
+    /// Like [`dispatch_dry_run`](Self::dispatch_dry_run), the action goes
+    /// through the dispatch endpoint's checks (signature verification,
+    /// replay protection) without being executed. Instead of the bare
+    /// verdict, the result carries the matched rule, the provider(s) the
+    /// action would reach, the rendered payload, and the resolved
+    /// attachments.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    /// use acteon_core::Action;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let action = Action::new("ns", "tenant", "email", "send", serde_json::json!({"name": "Alice"}))
+    ///     .with_template("welcome");
+    ///
+    /// let report = client.dispatch_dry_run_verbose(&action).await?;
+    /// println!("{} via {:?}: {}", report.verdict, report.providers, report.payload);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dispatch_dry_run_verbose(
+        &self,
+        action: &Action,
+    ) -> Result<DispatchPreview, Error> {
+        let url = format!("{}/v1/dispatch?dry_run=true&verbose=true", self.base_url);
+        self.request_preview(&url, action).await
+    }
+
+    /// POST `action` to `url` and decode a [`DispatchPreview`].
+    async fn request_preview(&self, url: &str, action: &Action) -> Result<DispatchPreview, Error> {
+        let action = self.with_default_metadata(action);
+
+        let response = self
+            .add_auth(self.client.post(url))
+            .json(&action)
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            response
+                .json::<DispatchPreview>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            Err(Error::Api {
+                code: error.code,
+                message: error.message,
+                retryable: error.retryable,
+            })
+        }
+    }
+
     /// Dispatch a single action with file attachments.
     ///
     /// This is a convenience wrapper that clones the action, sets the given
@@ -211,41 +413,104 @@ impl ActeonClient {
         self.dispatch_inner(&action, false).await
     }
 
+    /// Authorize a dispatch request and attach its idempotency key, if any.
+    fn with_idempotency_key(
+        &self,
+        request: reqwest::RequestBuilder,
+        key: Option<&str>,
+    ) -> reqwest::RequestBuilder {
+        let request = self.add_auth(request);
+        match key {
+            Some(key) => request.header(IDEMPOTENCY_KEY_HEADER, key),
+            None => request,
+        }
+    }
+
+    /// Merge the builder's default metadata into `action`, keeping any label
+    /// the action already sets. Borrows when there is nothing to add.
+    fn with_default_metadata<'a>(&self, action: &'a Action) -> Cow<'a, Action> {
+        let labels = &action.metadata.labels;
+        if self
+            .default_metadata
+            .keys()
+            .all(|key| labels.contains_key(key))
+        {
+            return Cow::Borrowed(action);
+        }
+        let mut action = action.clone();
+        for (key, value) in &self.default_metadata {
+            action
+                .metadata
+                .labels
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+        Cow::Owned(action)
+    }
+
     async fn dispatch_inner(&self, action: &Action, dry_run: bool) -> Result<ActionOutcome, Error> {
+        let (outcome, _) = self.dispatch_with_headers(action, dry_run, None).await?;
+        Ok(outcome)
+    }
+
+    /// Dispatch an action and return the outcome with the response headers.
+    ///
+    /// Without an explicit `idempotency_key`, the builder's generator picks
+    /// one. Dry runs never send a key.
+    pub(crate) async fn dispatch_with_headers(
+        &self,
+        action: &Action,
+        dry_run: bool,
+        idempotency_key: Option<&str>,
+    ) -> Result<(ActionOutcome, reqwest::header::HeaderMap), Error> {
         let mut url = format!("{}/v1/dispatch", self.base_url);
         if dry_run {
             url.push_str("?dry_run=true");
         }
+        let action = self.with_default_metadata(action);
+        let idempotency_key = if dry_run {
+            None
+        } else {
+            idempotency_key
+                .map(str::to_owned)
+                .or_else(|| self.idempotency_keys.for_action(&action))
+        };
 
-        let response = self
-            .add_auth(self.client.post(&url))
-            .json(action)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        self.gate
+            .retry(
+                &reqwest::Method::POST,
+                "/v1/dispatch",
+                || async {
+                    let response = self
+                        .with_idempotency_key(self.client.post(&url), idempotency_key.as_deref())
+                        .json(&action)
+                        .send_gated(&self.gate)
+                        .await?;
 
-        if response.status().is_success() {
-            let outcome = response
-                .json::<ActionOutcome>()
-                .await
-                .map_err(|e| Error::Deserialization(e.to_string()))?;
-            Ok(outcome)
-        } else {
-            let error = response
-                .json::<ErrorResponse>()
-                .await
-                .map_err(|e| Error::Deserialization(e.to_string()))?;
-            Err(Error::Api {
-                code: error.code,
-                message: error.message,
-                retryable: error.retryable,
-            })
-        }
+                    if response.status().is_success() {
+                        let headers = response.headers().clone();
+                        let outcome = response
+                            .json::<ActionOutcome>()
+                            .await
+                            .map_err(|e| Error::Deserialization(e.to_string()))?;
+                        Ok((outcome, headers))
+                    } else {
+                        Err(dispatch_error(response).await)
+                    }
+                },
+                |_| None,
+            )
+            .await
     }
 
     /// Dispatch multiple actions in a single request.
     ///
-    /// Returns a result for each action, preserving order.
+    /// Returns a result for each action, preserving order. A response cut
+    /// off part-way, e.g. by a proxy body size limit, fails with
+    /// [`Error::ResponseTooLarge`]; with
+    /// [`stream_batch_responses`](crate::ActeonClientBuilder::stream_batch_responses)
+    /// enabled, results that arrived are kept and the missing ones are
+    /// reported as [`TRUNCATED_RESULT_CODE`] errors instead.
     ///
     /// # Example
     ///
@@ -271,7 +536,19 @@ impl ActeonClient {
     /// # }
     /// ```
     pub async fn dispatch_batch(&self, actions: &[Action]) -> Result<Vec<BatchResult>, Error> {
-        self.dispatch_batch_inner(actions, false).await
+        self.dispatch_batch_inner(actions, false, None).await
+    }
+
+    /// Dispatch multiple actions with an `Idempotency-Key` header.
+    ///
+    /// The key covers the whole batch request. Send the same key when
+    /// re-sending the same batch.
+    pub async fn dispatch_batch_with_idempotency(
+        &self,
+        actions: &[Action],
+        key: &str,
+    ) -> Result<Vec<BatchResult>, Error> {
+        self.dispatch_batch_inner(actions, false, Some(key)).await
     }
 
     /// Dispatch multiple actions in dry-run mode.
@@ -282,42 +559,471 @@ impl ActeonClient {
         &self,
         actions: &[Action],
     ) -> Result<Vec<BatchResult>, Error> {
-        self.dispatch_batch_inner(actions, true).await
+        self.dispatch_batch_inner(actions, true, None).await
     }
 
     async fn dispatch_batch_inner(
         &self,
         actions: &[Action],
         dry_run: bool,
+        idempotency_key: Option<&str>,
     ) -> Result<Vec<BatchResult>, Error> {
+        if self.stream_batch_responses {
+            let response = self
+                .send_batch(actions, dry_run, idempotency_key, Some(NDJSON_CONTENT_TYPE))
+                .await?;
+            return Ok(BatchResultStream::from_response(response)
+                .await?
+                .collect_in_order(actions.len())
+                .await);
+        }
+        let response = self
+            .send_batch(actions, dry_run, idempotency_key, None)
+            .await?;
+        read_batch_results(response).await
+    }
+
+    /// Send a batch, retrying per the client's policy, and return the
+    /// successful response with its body unread.
+    ///
+    /// `accept` sets the `Accept` header, letting the caller ask for a
+    /// response format other than a JSON array.
+    pub(crate) async fn send_batch(
+        &self,
+        actions: &[Action],
+        dry_run: bool,
+        idempotency_key: Option<&str>,
+        accept: Option<&str>,
+    ) -> Result<reqwest::Response, Error> {
         let mut url = format!("{}/v1/dispatch/batch", self.base_url);
         if dry_run {
             url.push_str("?dry_run=true");
         }
+        let actions: Vec<Cow<'_, Action>> = actions
+            .iter()
+            .map(|action| self.with_default_metadata(action))
+            .collect();
+        let idempotency_key = if dry_run {
+            None
+        } else {
+            idempotency_key.map(str::to_owned).or_else(|| {
+                self.idempotency_keys
+                    .for_batch(actions.iter().map(AsRef::as_ref))
+            })
+        };
 
-        let response = self
-            .add_auth(self.client.post(&url))
-            .json(actions)
-            .send()
+        self.gate
+            .retry(
+                &reqwest::Method::POST,
+                "/v1/dispatch/batch",
+                || async {
+                    let mut request = self
+                        .with_idempotency_key(self.client.post(&url), idempotency_key.as_deref())
+                        .json(&actions);
+                    if let Some(accept) = accept {
+                        request = request.header(reqwest::header::ACCEPT, accept);
+                    }
+                    let response = request.send_gated(&self.gate).await?;
+
+                    if response.status().is_success() {
+                        Ok(response)
+                    } else {
+                        Err(dispatch_error(response).await)
+                    }
+                },
+                |_| None,
+            )
             .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+    }
+}
 
-        if response.status().is_success() {
-            let results = response
-                .json::<Vec<BatchResult>>()
-                .await
-                .map_err(|e| Error::Deserialization(e.to_string()))?;
-            Ok(results)
+/// Read a batch response's JSON array, telling a body cut off part-way
+/// ([`Error::ResponseTooLarge`]) apart from one that is not valid JSON.
+pub(crate) async fn read_batch_results(
+    response: reqwest::Response,
+) -> Result<Vec<BatchResult>, Error> {
+    let expected = response.content_length();
+    let mut body = Vec::new();
+    let mut chunks = response.bytes_stream();
+    while let Some(chunk) = chunks.next().await {
+        match chunk {
+            Ok(chunk) => body.extend_from_slice(&chunk),
+            Err(e) => return Err(body_error(&e, body.len(), expected)),
+        }
+    }
+    serde_json::from_slice(&body).map_err(|e| {
+        if e.is_eof() {
+            Error::ResponseTooLarge {
+                received: body.len(),
+                expected,
+            }
         } else {
-            let error = response
-                .json::<ErrorResponse>()
-                .await
-                .map_err(|e| Error::Deserialization(e.to_string()))?;
-            Err(Error::Api {
-                code: error.code,
-                message: error.message,
-                retryable: error.retryable,
-            })
+            Error::Deserialization(e.to_string())
         }
+    })
+}
+
+/// Map an error reading a response body after `received` bytes.
+///
+/// Only a body that ended before its declared length is
+/// [`Error::ResponseTooLarge`]; a timeout or reset while reading is
+/// reported as such so the retry policy sees it.
+fn body_error(e: &reqwest::Error, received: usize, expected: Option<u64>) -> Error {
+    if e.is_timeout() {
+        return Error::Timeout(format!("reading response body: {e}"));
+    }
+    let mut source = std::error::Error::source(e);
+    while let Some(cause) = source {
+        if cause
+            .downcast_ref::<std::io::Error>()
+            .is_some_and(|io| io.kind() == std::io::ErrorKind::UnexpectedEof)
+        {
+            return Error::ResponseTooLarge { received, expected };
+        }
+        source = cause.source();
+    }
+    Error::Connection(format!("reading response body: {e}"))
+}
+
+/// Map a failed dispatch response to an [`Error`].
+///
+/// A JSON error body becomes [`Error::Api`]; anything else, such as a bare
+/// 503 from a load balancer, becomes [`Error::Http`] so its status stays
+/// visible to the retry policy.
+async fn dispatch_error(response: reqwest::Response) -> Error {
+    let status = response.status();
+    match response.json::<ErrorResponse>().await {
+        Ok(error) => Error::Api {
+            code: error.code,
+            message: error.message,
+            retryable: error.retryable,
+        },
+        Err(_) => Error::Http {
+            status: status.as_u16(),
+            message: format!("dispatch failed: {status}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::ActeonClientBuilder;
+    use crate::mock_server::{self, Response};
+
+    /// Accept requests, forward their JSON bodies, and answer `Deduplicated`.
+    async fn start_capturing_server() -> (String, mpsc::UnboundedReceiver<serde_json::Value>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let base_url = mock_server::serve(move |request| {
+            let _ = tx.send(request.json());
+            Response::json(200, &serde_json::json!("Deduplicated"))
+        })
+        .await;
+        (base_url, rx)
+    }
+
+    #[tokio::test]
+    async fn default_metadata_is_merged_and_overridable() {
+        let (base_url, mut bodies) = start_capturing_server().await;
+        let client = ActeonClientBuilder::new(base_url)
+            .default_metadata(HashMap::from([
+                ("env".to_string(), "prod".to_string()),
+                ("service".to_string(), "billing".to_string()),
+            ]))
+            .build()
+            .unwrap();
+
+        let action = Action::new("ns", "t1", "email", "send", serde_json::json!({})).with_metadata(
+            acteon_core::ActionMetadata {
+                labels: HashMap::from([("env".to_string(), "staging".to_string())]),
+                ..Default::default()
+            },
+        );
+        client.dispatch(&action).await.unwrap();
+
+        let body = bodies.recv().await.unwrap();
+        assert_eq!(body["metadata"]["env"], "staging");
+        assert_eq!(body["metadata"]["service"], "billing");
+    }
+
+    #[tokio::test]
+    async fn dispatch_many_bounds_concurrency_and_keeps_order() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let (server_in_flight, server_peak) = (Arc::clone(&in_flight), Arc::clone(&peak));
+        let base_url = mock_server::serve_async(move |request| {
+            let (in_flight, peak) = (Arc::clone(&server_in_flight), Arc::clone(&server_peak));
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                let n = request.json()["payload"]["n"].as_u64().unwrap_or(0);
+                // Later actions answer first, so order comes from the client.
+                tokio::time::sleep(std::time::Duration::from_millis(50 - n * 8)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                if n == 2 {
+                    Response::json(
+                        400,
+                        &serde_json::json!({"code": "INVALID", "message": "bad", "retryable": false}),
+                    )
+                } else {
+                    let outcome = ActionOutcome::Suppressed {
+                        rule: format!("rule-{n}"),
+                    };
+                    Response::json(200, &serde_json::to_value(&outcome).unwrap())
+                }
+            }
+        })
+        .await;
+
+        let client = ActeonClient::new(base_url);
+        let actions = (0..5)
+            .map(|n| Action::new("ns", "t1", "email", "send", serde_json::json!({ "n": n })))
+            .collect();
+        let results = client.dispatch_many(actions, 2).await;
+
+        assert_eq!(results.len(), 5);
+        for (n, result) in results.iter().enumerate() {
+            match result {
+                Err(Error::Api { code, .. }) if n == 2 => assert_eq!(code, "INVALID"),
+                Ok(ActionOutcome::Suppressed { rule }) => assert_eq!(rule, &format!("rule-{n}")),
+                other => panic!("unexpected result {n}: {other:?}"),
+            }
+        }
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn dispatch_many_is_not_held_up_by_a_slow_action() {
+        let (arrivals_tx, mut arrivals) = mpsc::unbounded_channel();
+        let base_url = mock_server::serve_async(move |request| {
+            let arrivals_tx = arrivals_tx.clone();
+            async move {
+                let n = request.json()["payload"]["n"].as_u64().unwrap_or(0);
+                let _ = arrivals_tx.send(n);
+                let delay = if n == 0 { 500 } else { 10 };
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                let outcome = ActionOutcome::Suppressed {
+                    rule: format!("rule-{n}"),
+                };
+                Response::json(200, &serde_json::to_value(&outcome).unwrap())
+            }
+        })
+        .await;
+
+        let client = ActeonClient::new(base_url);
+        let actions = (0..5)
+            .map(|n| Action::new("ns", "t1", "email", "send", serde_json::json!({ "n": n })))
+            .collect();
+        let dispatch = tokio::spawn(async move { client.dispatch_many(actions, 2).await });
+
+        // Every other action goes out while the first is still running.
+        let mut seen = Vec::new();
+        tokio::time::timeout(Duration::from_millis(400), async {
+            while seen.len() < 5 {
+                seen.push(arrivals.recv().await.unwrap());
+            }
+        })
+        .await
+        .unwrap_or_else(|_| panic!("only {seen:?} started behind the slow action"));
+
+        let results = dispatch.await.unwrap();
+        for (n, result) in results.iter().enumerate() {
+            assert!(
+                matches!(result, Ok(ActionOutcome::Suppressed { rule }) if *rule == format!("rule-{n}")),
+                "{n}: {result:?}"
+            );
+        }
+    }
+
+    /// Answer every request with `status` and `body`.
+    async fn start_outcome_server(status: u16, body: String) -> String {
+        mock_server::serve(move |_| Response::new(status).body("application/json", body.clone()))
+            .await
+    }
+
+    #[tokio::test]
+    async fn dispatch_body_returns_executed_response_only() {
+        let action = Action::new("ns", "t1", "email", "send", serde_json::json!({}));
+
+        let executed = ActionOutcome::Executed(acteon_core::ProviderResponse::success(
+            serde_json::json!({"message_id": "m-1"}),
+        ));
+        let client = ActeonClient::new(
+            start_outcome_server(200, serde_json::to_string(&executed).unwrap()).await,
+        );
+        assert_eq!(
+            client.dispatch_body(&action).await.unwrap(),
+            Some(serde_json::json!({"message_id": "m-1"}))
+        );
+
+        let suppressed = ActionOutcome::Suppressed {
+            rule: "block-spam".into(),
+        };
+        let client = ActeonClient::new(
+            start_outcome_server(200, serde_json::to_string(&suppressed).unwrap()).await,
+        );
+        assert_eq!(client.dispatch_body(&action).await.unwrap(), None);
+
+        let error = r#"{"code":"INVALID","message":"bad action","retryable":false}"#;
+        let client = ActeonClient::new(start_outcome_server(400, error.into()).await);
+        let err = client.dispatch_body(&action).await.unwrap_err();
+        assert!(
+            matches!(err, Error::Api { ref code, .. } if code == "INVALID"),
+            "got {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn verbose_dry_run_returns_preview() {
+        let base_url = mock_server::serve(|request| {
+            if request.route() != "POST /v1/dispatch?dry_run=true&verbose=true" {
+                return Response::new(404);
+            }
+            Response::json(
+                200,
+                &serde_json::json!({
+                    "verdict": "reroute",
+                    "matched_rule": "reroute-sms",
+                    "provider": "sms",
+                    "providers": ["sms"],
+                    "action_type": "send",
+                    "payload": {"subject": "Hello, Alice!"},
+                    "attachments": [{
+                        "id": "a1",
+                        "name": "logo",
+                        "filename": "logo.png",
+                        "content_type": "image/png",
+                        "size_bytes": 8,
+                    }],
+                }),
+            )
+        })
+        .await;
+
+        let client = ActeonClient::new(base_url);
+        let action = Action::new(
+            "ns",
+            "t1",
+            "email",
+            "send",
+            serde_json::json!({"name": "Alice"}),
+        )
+        .with_template("welcome");
+        let report = client.dispatch_dry_run_verbose(&action).await.unwrap();
+
+        assert_eq!(report.verdict, "reroute");
+        assert_eq!(report.matched_rule.as_deref(), Some("reroute-sms"));
+        assert_eq!(report.providers, vec!["sms"]);
+        assert_eq!(report.payload["subject"], "Hello, Alice!");
+        assert_eq!(report.attachments[0].size_bytes, 8);
+    }
+
+    /// Answer every request with the raw HTTP `response`, then close.
+    async fn serve_raw(response: String) -> String {
+        mock_server::serve(move |_| Response::raw(response.clone())).await
+    }
+
+    #[tokio::test]
+    async fn stalled_batch_bodies_time_out() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = [0_u8; 4096];
+            let _ = tokio::io::AsyncReadExt::read(&mut stream, &mut request).await;
+            let head = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                        Content-Length: 4096\r\n\r\n[\"Deduplicated\",";
+            let _ = tokio::io::AsyncWriteExt::write_all(&mut stream, head.as_bytes()).await;
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let client = ActeonClientBuilder::new(base_url)
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let action = Action::new("ns", "t1", "email", "send", serde_json::json!({}));
+        let err = client.dispatch_batch(&[action]).await.unwrap_err();
+        assert!(matches!(err, Error::Timeout(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn truncated_batch_responses_are_reported_or_kept() {
+        let actions: Vec<Action> = (0..3)
+            .map(|i| Action::new("ns", "t1", "email", "send", serde_json::json!({ "n": i })))
+            .collect();
+        let cut_off = |content_type: &str, body: &str| {
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\n\
+                 Content-Length: 4096\r\nConnection: close\r\n\r\n{body}"
+            )
+        };
+
+        // The body stops short of its declared length.
+        let body = r#"["Deduplicated","Dedup"#;
+        let client = ActeonClient::new(serve_raw(cut_off("application/json", body)).await);
+        let err = client.dispatch_batch(&actions).await.unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::ResponseTooLarge { received, expected: Some(4096) } if received == body.len()
+            ),
+            "{err:?}"
+        );
+
+        // Read through the stream API, the same body is reported the same way.
+        let client = ActeonClient::new(serve_raw(cut_off("application/json", body)).await);
+        let err = client.dispatch_batch_stream(&actions).await.err();
+        assert!(
+            matches!(
+                err,
+                Some(Error::ResponseTooLarge {
+                    expected: Some(4096),
+                    ..
+                })
+            ),
+            "{err:?}"
+        );
+
+        // A complete body whose JSON is cut off is truncated too, while
+        // malformed JSON is still a deserialization error.
+        let base_url = start_outcome_server(200, r#"["Deduplicated","#.into()).await;
+        let err = ActeonClient::new(base_url).dispatch_batch(&actions).await;
+        assert!(
+            matches!(err, Err(Error::ResponseTooLarge { .. })),
+            "{err:?}"
+        );
+        let base_url = start_outcome_server(200, "[nope]".into()).await;
+        let err = ActeonClient::new(base_url).dispatch_batch(&actions).await;
+        assert!(matches!(err, Err(Error::Deserialization(_))), "{err:?}");
+
+        // Streamed, the results that arrived are kept in request order.
+        let body = concat!(
+            r#"{"index":2,"result":"Deduplicated"}"#,
+            "\n",
+            r#"{"index":0,"result":"Deduplicated"}"#,
+            "\n",
+            r#"{"index":1,"res"#,
+        );
+        let base_url = serve_raw(cut_off("application/x-ndjson", body)).await;
+        let client = ActeonClientBuilder::new(base_url)
+            .stream_batch_responses(true)
+            .build()
+            .unwrap();
+        let results = client.dispatch_batch(&actions).await.unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_success());
+        assert!(matches!(
+            &results[1],
+            BatchResult::Error { error } if error.code == crate::TRUNCATED_RESULT_CODE
+        ));
+        assert!(results[2].is_success());
     }
 }
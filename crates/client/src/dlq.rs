@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Dead letter queue statistics.
@@ -61,9 +62,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -103,9 +103,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.post(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
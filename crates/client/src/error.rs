@@ -29,29 +29,97 @@ pub enum Error {
         retryable: bool,
     },
 
+    /// The request conflicts with the current state of a resource, e.g.
+    /// deleting a template that is still referenced.
+    #[error("conflict: {0}")]
+    Conflict(String),
+
+    /// The server answered HTTP 429 Too Many Requests.
+    #[error("rate limited{}", retry_after.map(|d| format!(", retry after {d:?}")).unwrap_or_default())]
+    RateLimited {
+        /// How long the server asked the client to wait, from the
+        /// `Retry-After` header, or `None` if it sent no usable value.
+        retry_after: Option<std::time::Duration>,
+    },
+
     /// Response deserialization error.
     #[error("failed to deserialize response: {0}")]
     Deserialization(String),
 
+    /// The response body ended early, typically because a proxy cut it off
+    /// at its body size limit.
+    ///
+    /// For batch dispatches, enable
+    /// [`ActeonClientBuilder::stream_batch_responses`](crate::ActeonClientBuilder::stream_batch_responses)
+    /// to keep the results that did arrive.
+    #[error("response truncated after {received} bytes{}", expected.map(|n| format!(" of {n}")).unwrap_or_default())]
+    ResponseTooLarge {
+        /// Bytes of the body that arrived.
+        received: usize,
+        /// Length the server declared in `Content-Length`, if any.
+        expected: Option<u64>,
+    },
+
     /// Client configuration error.
     #[error("configuration error: {0}")]
     Configuration(String),
+
+    /// Writing to a caller-supplied writer failed.
+    #[error("I/O error: {0}")]
+    Io(String),
+
+    /// A bulk audit export stopped part-way. Pass `checkpoint` to
+    /// [`ActeonClient::export_audit_from`](crate::ActeonClient::export_audit_from)
+    /// to resume.
+    #[error("export interrupted after {} records: {source}", checkpoint.records_written)]
+    ExportInterrupted {
+        /// Where to resume from.
+        checkpoint: Box<crate::ExportCheckpoint>,
+        /// The error that stopped the export.
+        source: Box<Error>,
+    },
+
+    /// A client-side wait, such as
+    /// [`ActeonClient::wait_for_chain`](crate::ActeonClient::wait_for_chain),
+    /// reached its deadline before the awaited condition held.
+    #[error("timed out: {0}")]
+    Timeout(String),
+
+    /// The client is shutting down and no longer accepts requests.
+    #[error("client is shutting down")]
+    ShuttingDown,
+
+    /// [`ActeonClient::shutdown`](crate::ActeonClient::shutdown) gave up
+    /// waiting with requests still in flight.
+    #[error("shutdown grace period elapsed with {pending} requests in flight")]
+    ShutdownTimeout {
+        /// Requests still awaiting a response.
+        pending: usize,
+    },
 }
 
 impl Error {
     /// Returns `true` if this error is retryable.
     ///
-    /// Connection errors and API errors marked as retryable return `true`.
-    /// HTTP 5xx errors retry. HTTP 408 (Request Timeout) also retries —
+    /// Connection errors, rate limiting, and API errors marked as retryable
+    /// return `true`. HTTP 5xx errors retry. HTTP 408 (Request Timeout) also retries —
     /// callers waiting for an async outcome (e.g. a bus
     /// `lookup_bus_tool_result` that hasn't seen the matching result
     /// yet) typically just want to issue another request.
     pub fn is_retryable(&self) -> bool {
         match self {
-            Self::Connection(_) => true,
+            Self::Connection(_) | Self::RateLimited { .. } => true,
             Self::Http { status, .. } => *status >= 500 || *status == 408,
             Self::Api { retryable, .. } => *retryable,
-            Self::Deserialization(_) | Self::Configuration(_) => false,
+            Self::ExportInterrupted { source, .. } => source.is_retryable(),
+            Self::Conflict(_)
+            | Self::Deserialization(_)
+            | Self::ResponseTooLarge { .. }
+            | Self::Configuration(_)
+            | Self::Io(_)
+            | Self::Timeout(_)
+            | Self::ShuttingDown
+            | Self::ShutdownTimeout { .. } => false,
         }
     }
 
@@ -148,6 +216,13 @@ mod tests {
         assert!(!err.is_retryable());
     }
 
+    #[test]
+    fn client_side_timeout_is_not_retryable() {
+        let err = Error::Timeout("chain c1 still running after 5s".to_string());
+        assert!(!err.is_retryable());
+        assert!(!err.is_timeout());
+    }
+
     #[test]
     fn http_408_is_typed_timeout_and_retryable() {
         let err = Error::Http {
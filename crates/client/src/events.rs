@@ -1,6 +1,8 @@
+use acteon_core::EventTransition;
 use serde::{Deserialize, Serialize};
 
 use crate::dispatch::ErrorResponse;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Query parameters for listing events.
@@ -53,6 +55,15 @@ pub struct TransitionResponse {
     pub notify: bool,
 }
 
+/// Transition history of an event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventHistory {
+    /// The event fingerprint.
+    pub fingerprint: String,
+    /// Recorded transitions, oldest first.
+    pub transitions: Vec<EventTransition>,
+}
+
 impl ActeonClient {
     /// List events filtered by namespace, tenant, and optionally status.
     ///
@@ -78,13 +89,14 @@ impl ActeonClient {
     /// ```
     pub async fn list_events(&self, query: &EventQuery) -> Result<EventListResponse, Error> {
         let url = format!("{}/v1/events", self.base_url);
+        let mut query = query.clone();
+        self.scope.fill(&mut query.namespace, &mut query.tenant);
 
         let response = self
             .add_auth(self.client.get(&url))
-            .query(query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .query(&query)
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -126,9 +138,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let event = response
@@ -146,6 +157,56 @@ impl ActeonClient {
         }
     }
 
+    /// Get the transition history of an event by fingerprint.
+    ///
+    /// Returns `None` if the event does not exist. The history is bounded,
+    /// so very long-lived events only keep their most recent transitions.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// if let Some(history) = client.get_event_history("fingerprint-123", "notifications", "tenant-1").await? {
+    ///     for t in history.transitions {
+    ///         println!("{} {} -> {} by {}", t.at, t.from, t.to, t.actor);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_event_history(
+        &self,
+        fingerprint: &str,
+        namespace: &str,
+        tenant: &str,
+    ) -> Result<Option<EventHistory>, Error> {
+        let url = format!("{}/v1/events/{}/history", self.base_url, fingerprint);
+
+        let response = self
+            .add_auth(self.client.get(&url))
+            .query(&[("namespace", namespace), ("tenant", tenant)])
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            let history = response
+                .json::<EventHistory>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            Ok(Some(history))
+        } else if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Ok(None)
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: format!("Failed to get event history: {}", response.status()),
+            })
+        }
+    }
+
     /// Transition an event to a new state.
     ///
     /// # Example
@@ -183,9 +244,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(&body)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Summary of one execution for visibility queries.
@@ -118,9 +119,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -146,9 +146,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -175,9 +174,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -214,9 +212,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(&body)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             Ok(())
@@ -246,9 +243,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(&body)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -289,9 +285,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(&body)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -0,0 +1,86 @@
+//! Feature flags CRUD — thin HTTP wrappers for the `/v1/flags` endpoints.
+//!
+//! Flags are per-tenant booleans that rules read as `flags.<name>` and
+//! providers receive in their dispatch context.
+
+pub use acteon_core::{FeatureFlags, TenantFeatureFlags};
+
+use crate::transport::GatedSend;
+use crate::{ActeonClient, Error};
+
+impl ActeonClient {
+    /// List the feature flags of a `(namespace, tenant)` scope.
+    pub async fn list_feature_flags(
+        &self,
+        namespace: &str,
+        tenant: &str,
+    ) -> Result<TenantFeatureFlags, Error> {
+        let url = format!("{}/v1/flags/{namespace}/{tenant}", self.base_url);
+        let response = self
+            .add_auth(self.client.get(&url))
+            .send_gated(&self.gate)
+            .await?;
+        if response.status().is_success() {
+            response
+                .json::<TenantFeatureFlags>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: format!("failed to list feature flags: {}", response.status()),
+            })
+        }
+    }
+
+    /// Turn a feature flag on or off. Returns the tenant's updated flags.
+    pub async fn set_feature_flag(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        name: &str,
+        enabled: bool,
+    ) -> Result<TenantFeatureFlags, Error> {
+        let url = format!("{}/v1/flags/{namespace}/{tenant}/{name}", self.base_url);
+        let response = self
+            .add_auth(self.client.put(&url))
+            .json(&serde_json::json!({ "enabled": enabled }))
+            .send_gated(&self.gate)
+            .await?;
+        if response.status().is_success() {
+            response
+                .json::<TenantFeatureFlags>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: format!("failed to set feature flag: {}", response.status()),
+            })
+        }
+    }
+
+    /// Remove a feature flag. Returns `false` if the flag was not set.
+    pub async fn delete_feature_flag(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        name: &str,
+    ) -> Result<bool, Error> {
+        let url = format!("{}/v1/flags/{namespace}/{tenant}/{name}", self.base_url);
+        let response = self
+            .add_auth(self.client.delete(&url))
+            .send_gated(&self.gate)
+            .await?;
+        if response.status().is_success() {
+            Ok(true)
+        } else if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Ok(false)
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: format!("failed to delete feature flag: {}", response.status()),
+            })
+        }
+    }
+}
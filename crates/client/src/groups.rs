@@ -1,6 +1,9 @@
+use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
 
 use crate::dispatch::ErrorResponse;
+use crate::pagination::offset_pages;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Summary of an event group.
@@ -34,7 +37,8 @@ pub struct GroupListResponse {
 pub struct GroupDetail {
     /// Group summary.
     pub group: GroupSummary,
-    /// Event fingerprints in this group.
+    /// Event fingerprints in this group, oldest first: ordered by arrival
+    /// time, then action ID.
     pub events: Vec<String>,
     /// Labels used to group events.
     pub labels: std::collections::HashMap<String, String>,
@@ -70,13 +74,63 @@ impl ActeonClient {
     /// # }
     /// ```
     pub async fn list_groups(&self) -> Result<GroupListResponse, Error> {
+        self.fetch_groups(None).await
+    }
+
+    /// List one page of active event groups, oldest first.
+    ///
+    /// Returns at most `limit` groups after skipping the first `offset`.
+    /// `total` still counts every active group. Use
+    /// [`list_groups_stream`](Self::list_groups_stream) to walk every page.
+    pub async fn list_groups_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<GroupListResponse, Error> {
+        self.fetch_groups(Some((limit, offset))).await
+    }
+
+    /// Stream every active event group.
+    ///
+    /// Requests pages of `page_size` groups via
+    /// [`list_groups_page`](Self::list_groups_page) as the stream is polled.
+    /// The stream ends after the last page or the first error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    /// use futures::StreamExt;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let mut groups = std::pin::pin!(client.list_groups_stream(100));
+    /// while let Some(group) = groups.next().await {
+    ///     let group = group?;
+    ///     println!("{}: {} events", group.group_id, group.event_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_groups_stream(
+        &self,
+        page_size: usize,
+    ) -> impl Stream<Item = Result<GroupSummary, Error>> + '_ {
+        offset_pages(page_size, move |limit, offset| async move {
+            self.list_groups_page(limit, offset)
+                .await
+                .map(|page| page.groups)
+        })
+    }
+
+    async fn fetch_groups(&self, page: Option<(usize, usize)>) -> Result<GroupListResponse, Error> {
         let url = format!("{}/v1/groups", self.base_url);
 
-        let response = self
-            .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let mut request = self.add_auth(self.client.get(&url));
+        if let Some((limit, offset)) = page {
+            request = request.query(&[("limit", limit), ("offset", offset)]);
+        }
+        let response = request.send_gated(&self.gate).await?;
 
         if response.status().is_success() {
             let result = response
@@ -112,9 +166,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -151,9 +204,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
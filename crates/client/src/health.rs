@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::transport::GatedSend;
+use crate::{ActeonClient, Error};
+
+/// Health of each component behind the gateway, as returned by
+/// [`ActeonClient::health_detailed`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthReport {
+    /// `ok` when every component is `ok` or `disabled`, otherwise `degraded`.
+    pub status: String,
+    /// Health of each component, keyed by name (`state_store`,
+    /// `audit_store`, `providers`).
+    pub components: HashMap<String, ComponentHealth>,
+}
+
+impl HealthReport {
+    /// Whether every component is `ok` or `disabled`.
+    pub fn is_healthy(&self) -> bool {
+        self.status == "ok"
+    }
+}
+
+/// Health of a single component.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentHealth {
+    /// One of `ok`, `degraded` (partly failing), `down`, or `disabled`.
+    pub status: String,
+    /// Why the component is not `ok`.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// How long the probe took, in milliseconds. `None` when disabled.
+    #[serde(default)]
+    pub latency_ms: Option<u64>,
+}
+
+impl ActeonClient {
+    /// Get the health of each component behind the gateway.
+    ///
+    /// Unlike [`health`](Self::health), which only says whether the server
+    /// answers, this probes the state store, the audit store, and every
+    /// provider. A degraded server answers `503` with the same report, so
+    /// it is returned as `Ok` rather than as an error. The endpoint requires
+    /// authentication.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let report = client.health_detailed().await?;
+    /// for (name, component) in &report.components {
+    ///     println!("{name}: {} {:?}", component.status, component.error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health_detailed(&self) -> Result<HealthReport, Error> {
+        let url = format!("{}/health/detailed", self.base_url);
+        let response = self
+            .add_auth(self.client.get(&url))
+            .send_gated(&self.gate)
+            .await?;
+
+        let status = response.status();
+        if status.is_success() || status == reqwest::StatusCode::SERVICE_UNAVAILABLE {
+            response
+                .json::<HealthReport>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))
+        } else {
+            Err(Error::Http {
+                status: status.as_u16(),
+                message: format!("Failed to get detailed health: {status}"),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    #[tokio::test]
+    async fn degraded_reports_are_returned_not_errors() {
+        let base_url = mock_server::serve(|_| {
+            Response::json(
+                503,
+                &serde_json::json!({
+                    "status": "degraded",
+                    "components": {
+                        "state_store": { "status": "ok", "latency_ms": 1 },
+                        "audit_store": { "status": "disabled" },
+                        "providers": {
+                            "status": "degraded",
+                            "error": "slack: connection refused",
+                            "latency_ms": 12,
+                        },
+                    },
+                }),
+            )
+        })
+        .await;
+
+        let report = ActeonClient::new(base_url).health_detailed().await.unwrap();
+
+        assert!(!report.is_healthy());
+        assert_eq!(report.components["state_store"].latency_ms, Some(1));
+        assert_eq!(report.components["audit_store"].status, "disabled");
+        let providers = &report.components["providers"];
+        assert_eq!(providers.status, "degraded");
+        assert_eq!(
+            providers.error.as_deref(),
+            Some("slack: connection refused")
+        );
+    }
+}
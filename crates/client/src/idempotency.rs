@@ -0,0 +1,210 @@
+//! `Idempotency-Key` headers on dispatch requests.
+//!
+//! A dispatch that timed out may still have executed on the server, so
+//! re-sending it can deliver the same notification twice. An idempotency key
+//! lets the server (or a proxy in front of it) recognize the repeat. The key
+//! is sent as the [`IDEMPOTENCY_KEY_HEADER`] header on `POST /v1/dispatch`
+//! and `POST /v1/dispatch/batch` only; dry runs and reads never carry it.
+//!
+//! Keys come either from an explicit argument to
+//! [`ActeonClient::dispatch_with_idempotency`] or from a generator set with
+//! [`ActeonClientBuilder::idempotency_key_fn`]. Retries of one call always
+//! reuse the same key.
+//!
+//! [`ActeonClient::dispatch_with_idempotency`]: crate::ActeonClient::dispatch_with_idempotency
+//! [`ActeonClientBuilder::idempotency_key_fn`]: crate::ActeonClientBuilder::idempotency_key_fn
+
+use std::sync::Arc;
+
+use acteon_core::Action;
+use sha2::{Digest, Sha256};
+
+/// Name of the HTTP header carrying the idempotency key.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Derives an idempotency key for an action, or `None` to send none.
+pub type IdempotencyKeyFn = dyn Fn(&Action) -> Option<String> + Send + Sync;
+
+/// Idempotency key derived from the action's fingerprint.
+///
+/// Hashes the namespace, tenant, fingerprint, and status, so re-sends of the
+/// same event share a key while its lifecycle transitions (`firing`, then
+/// `resolved`) do not. Returns `None` for actions without a fingerprint.
+///
+/// # Example
+///
+/// ```
+/// use acteon_client::fingerprint_idempotency_key;
+/// use acteon_core::Action;
+///
+/// let action = Action::new("ns", "tenant", "pagerduty", "alert", serde_json::json!({}))
+///     .with_fingerprint("disk-full:db-1");
+/// let rebuilt = Action::new("ns", "tenant", "pagerduty", "alert", serde_json::json!({}))
+///     .with_fingerprint("disk-full:db-1");
+/// assert_eq!(
+///     fingerprint_idempotency_key(&action),
+///     fingerprint_idempotency_key(&rebuilt),
+/// );
+/// ```
+#[must_use]
+pub fn fingerprint_idempotency_key(action: &Action) -> Option<String> {
+    let fingerprint = action.fingerprint.as_deref()?;
+    let mut hasher = Sha256::new();
+    for part in [
+        action.namespace.as_str(),
+        action.tenant.as_str(),
+        fingerprint,
+        action.status.as_deref().unwrap_or_default(),
+    ] {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part.as_bytes());
+    }
+    Some(hex::encode(hasher.finalize()))
+}
+
+/// The builder's key generator, if any.
+#[derive(Clone, Default)]
+pub(crate) struct IdempotencyKeys(pub(crate) Option<Arc<IdempotencyKeyFn>>);
+
+impl IdempotencyKeys {
+    /// Generated key for a single dispatch.
+    pub(crate) fn for_action(&self, action: &Action) -> Option<String> {
+        self.0.as_ref().and_then(|generate| generate(action))
+    }
+
+    /// Generated key for a batch: a hash of every action's key, or `None`
+    /// unless each action has one.
+    pub(crate) fn for_batch<'a>(
+        &self,
+        actions: impl IntoIterator<Item = &'a Action>,
+    ) -> Option<String> {
+        let generate = self.0.as_ref()?;
+        let mut hasher = Sha256::new();
+        for action in actions {
+            let key = generate(action)?;
+            hasher.update((key.len() as u64).to_be_bytes());
+            hasher.update(key.as_bytes());
+        }
+        Some(hex::encode(hasher.finalize()))
+    }
+}
+
+impl std::fmt::Debug for IdempotencyKeys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.0.is_some() {
+            "IdempotencyKeyFn"
+        } else {
+            "None"
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::mock_server::{self, Response};
+    use crate::{ActeonClient, ActeonClientBuilder};
+
+    /// Answer every request with `body` and forward the route and
+    /// `Idempotency-Key` header value of each.
+    async fn start_header_server(
+        body: &'static str,
+    ) -> (String, mpsc::UnboundedReceiver<(String, Option<String>)>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let base_url = mock_server::serve(move |request| {
+            let key = request.header(IDEMPOTENCY_KEY_HEADER).map(str::to_owned);
+            let _ = tx.send((request.route(), key));
+            Response::new(200).body("application/json", body)
+        })
+        .await;
+        (base_url, rx)
+    }
+
+    fn alert(fingerprint: &str) -> Action {
+        Action::new("ns", "t1", "pagerduty", "alert", serde_json::json!({}))
+            .with_fingerprint(fingerprint)
+    }
+
+    #[tokio::test]
+    async fn explicit_keys_are_sent_on_dispatch_and_batch() {
+        let (base_url, mut rx) = start_header_server("\"Deduplicated\"").await;
+        let client = ActeonClient::new(&base_url);
+        client
+            .dispatch_with_idempotency(&alert("fp-1"), "key-1")
+            .await
+            .unwrap();
+        let (route, key) = rx.recv().await.unwrap();
+        assert_eq!(route, "POST /v1/dispatch");
+        assert_eq!(key.as_deref(), Some("key-1"));
+
+        // Without a generator, plain dispatches carry no key.
+        client.dispatch(&alert("fp-1")).await.unwrap();
+        assert_eq!(rx.recv().await.unwrap().1, None);
+
+        let (base_url, mut rx) = start_header_server("[\"Deduplicated\"]").await;
+        let client = ActeonClient::new(&base_url);
+        client
+            .dispatch_batch_with_idempotency(&[alert("fp-1")], "batch-1")
+            .await
+            .unwrap();
+        let (route, key) = rx.recv().await.unwrap();
+        assert_eq!(route, "POST /v1/dispatch/batch");
+        assert_eq!(key.as_deref(), Some("batch-1"));
+    }
+
+    #[tokio::test]
+    async fn generated_keys_skip_dry_runs_and_reads() {
+        let (base_url, mut rx) = start_header_server("\"Deduplicated\"").await;
+        let client = ActeonClientBuilder::new(&base_url)
+            .idempotency_key_fn(fingerprint_idempotency_key)
+            .build()
+            .unwrap();
+
+        client.dispatch(&alert("fp-1")).await.unwrap();
+        let first = rx.recv().await.unwrap().1.expect("generated key");
+        client.dispatch(&alert("fp-1")).await.unwrap();
+        assert_eq!(rx.recv().await.unwrap().1, Some(first.clone()));
+
+        // An explicit key wins over the generator.
+        client
+            .dispatch_with_idempotency(&alert("fp-1"), "explicit")
+            .await
+            .unwrap();
+        assert_eq!(rx.recv().await.unwrap().1.as_deref(), Some("explicit"));
+
+        client.dispatch_dry_run(&alert("fp-1")).await.unwrap();
+        assert_eq!(rx.recv().await.unwrap().1, None);
+        let _ = client.health().await;
+        assert_eq!(rx.recv().await.unwrap().1, None);
+
+        // No fingerprint, no key.
+        client
+            .dispatch(&Action::new(
+                "ns",
+                "t1",
+                "email",
+                "send",
+                serde_json::json!({}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(rx.recv().await.unwrap().1, None);
+    }
+
+    #[test]
+    fn fingerprint_keys_differ_by_status_and_batch_needs_every_key() {
+        let firing = fingerprint_idempotency_key(&alert("fp-1").with_status("firing"));
+        let resolved = fingerprint_idempotency_key(&alert("fp-1").with_status("resolved"));
+        assert!(firing.is_some());
+        assert_ne!(firing, resolved);
+
+        let keys = IdempotencyKeys(Some(Arc::new(fingerprint_idempotency_key)));
+        let batch = [alert("fp-1"), alert("fp-2")];
+        assert!(keys.for_batch(&batch).is_some());
+        assert_eq!(keys.for_batch(&batch), keys.for_batch(&batch.clone()));
+        let unkeyed = Action::new("ns", "t1", "email", "send", serde_json::json!({}));
+        assert_eq!(keys.for_batch(&[alert("fp-1"), unkeyed]), None);
+    }
+}
@@ -0,0 +1,258 @@
+//! Everything an incident view needs, fetched in one call.
+//!
+//! [`ActeonClient::incident_view`] gathers an event's state and history, the
+//! audit records of the actions that carried its fingerprint, and the chains
+//! and pending approvals those actions started. The requests run
+//! concurrently; records are related to the event by the `__fingerprint`
+//! system field in their metadata, chains by `chain_id`, and approvals by
+//! the `approval_id` in the outcome details.
+
+use std::collections::HashSet;
+
+use crate::{
+    ActeonClient, ApprovalStatusResponse, AuditQuery, AuditRecord, ChainSummary, Error,
+    EventHistory, EventState,
+};
+
+/// Number of recent audit records searched for the event's actions.
+///
+/// This is the largest page the server returns. Actions of a long-lived
+/// event that fell out of this window are not part of the view; query them
+/// with [`ActeonClient::query_audit`] instead.
+pub const INCIDENT_AUDIT_LIMIT: u32 = 1000;
+
+/// An event joined with the chains, audit records, and approvals related to
+/// it, as returned by [`ActeonClient::incident_view`].
+#[derive(Debug, Clone)]
+pub struct IncidentView {
+    /// The event fingerprint.
+    pub fingerprint: String,
+    /// Current state of the event, or `None` if it does not exist.
+    pub event: Option<EventState>,
+    /// Transition history of the event, or `None` if it does not exist.
+    pub history: Option<EventHistory>,
+    /// Audit records of actions carrying the event's fingerprint, as
+    /// ordered by the server.
+    pub audit: Vec<AuditRecord>,
+    /// Chains started by those actions.
+    pub chains: Vec<ChainSummary>,
+    /// Approvals still pending for those actions.
+    pub approvals: Vec<ApprovalStatusResponse>,
+}
+
+impl ActeonClient {
+    /// Fetch an event together with its related chains, audit records, and
+    /// pending approvals.
+    ///
+    /// The event, its history, the namespace's chains and pending
+    /// approvals, and the latest [`INCIDENT_AUDIT_LIMIT`] audit records are
+    /// requested concurrently and then joined on the fingerprint. Any
+    /// failed request fails the whole view.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let view = client.incident_view("fingerprint-123", "alerts", "tenant-1").await?;
+    /// if let Some(event) = &view.event {
+    ///     println!("{} is {}", view.fingerprint, event.state);
+    /// }
+    /// for record in &view.audit {
+    ///     println!("{} {} -> {}", record.dispatched_at, record.action_type, record.outcome);
+    /// }
+    /// println!("{} chains, {} pending approvals", view.chains.len(), view.approvals.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn incident_view(
+        &self,
+        fingerprint: &str,
+        namespace: &str,
+        tenant: &str,
+    ) -> Result<IncidentView, Error> {
+        let audit_query = AuditQuery {
+            namespace: Some(namespace.to_owned()),
+            tenant: Some(tenant.to_owned()),
+            limit: Some(INCIDENT_AUDIT_LIMIT),
+            ..AuditQuery::default()
+        };
+        let (event, history, chains, audit, approvals) = futures::try_join!(
+            self.get_event(fingerprint, namespace, tenant),
+            self.get_event_history(fingerprint, namespace, tenant),
+            self.list_chains(namespace, tenant, None),
+            self.query_audit(&audit_query),
+            self.list_approvals(namespace, tenant),
+        )?;
+
+        let audit: Vec<AuditRecord> = audit
+            .records
+            .into_iter()
+            .filter(|record| {
+                record
+                    .metadata
+                    .get("__fingerprint")
+                    .and_then(|f| f.as_str())
+                    == Some(fingerprint)
+            })
+            .collect();
+        let chain_ids: HashSet<&str> = audit
+            .iter()
+            .filter_map(|record| record.chain_id.as_deref())
+            .collect();
+        let approval_ids: HashSet<&str> = audit
+            .iter()
+            .filter_map(|record| record.outcome_details.get("approval_id")?.as_str())
+            .collect();
+        let chains = chains
+            .chains
+            .into_iter()
+            .filter(|chain| chain_ids.contains(chain.chain_id.as_str()))
+            .collect();
+        let approvals = approvals
+            .approvals
+            .into_iter()
+            .filter(|approval| approval_ids.contains(approval.token.as_str()))
+            .collect();
+
+        Ok(IncidentView {
+            fingerprint: fingerprint.to_owned(),
+            event,
+            history,
+            audit,
+            chains,
+            approvals,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    /// Answer each request with the JSON for its path, as a server holding
+    /// one incident would.
+    async fn serve_incident() -> String {
+        mock_server::serve(|request| Response::json(200, &incident_response(request.path()))).await
+    }
+
+    fn audit_record(
+        action_id: &str,
+        fingerprint: &str,
+        outcome: &str,
+        chain_id: Option<&str>,
+        approval_id: Option<&str>,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "id": format!("rec-{action_id}"),
+            "action_id": action_id,
+            "chain_id": chain_id,
+            "namespace": "alerts",
+            "tenant": "t1",
+            "provider": "pagerduty",
+            "action_type": "page",
+            "verdict": "allow",
+            "outcome": outcome,
+            "matched_rule": null,
+            "outcome_details": { "approval_id": approval_id },
+            "duration_ms": 3,
+            "dispatched_at": "2026-01-01T00:00:00Z",
+            "metadata": { "__fingerprint": fingerprint },
+        })
+    }
+
+    fn approval(token: &str) -> serde_json::Value {
+        serde_json::json!({
+            "token": token,
+            "status": "pending",
+            "rule": "needs-approval",
+            "created_at": "2026-01-01T00:00:00Z",
+            "expires_at": "2026-01-02T00:00:00Z",
+            "decided_at": null,
+            "message": null,
+        })
+    }
+
+    fn chain(chain_id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "chain_id": chain_id,
+            "chain_name": "escalate",
+            "status": "running",
+            "current_step": 1,
+            "total_steps": 3,
+            "started_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:05Z",
+        })
+    }
+
+    fn incident_response(path: &str) -> serde_json::Value {
+        match path {
+            "/v1/events/fp-1" => serde_json::json!({
+                "fingerprint": "fp-1",
+                "state": "investigating",
+                "action_type": "page",
+                "updated_at": "2026-01-01T00:00:05Z",
+            }),
+            "/v1/events/fp-1/history" => serde_json::json!({
+                "fingerprint": "fp-1",
+                "transitions": [],
+            }),
+            "/v1/chains" => serde_json::json!({
+                "chains": [chain("chain-1"), chain("chain-other")],
+            }),
+            "/v1/audit" => serde_json::json!({
+                "records": [
+                    audit_record(
+                        "a1",
+                        "fp-1",
+                        "chain_started",
+                        Some("chain-1"),
+                        None,
+                    ),
+                    audit_record(
+                        "a2",
+                        "fp-1",
+                        "pending_approval",
+                        None,
+                        Some("appr-1"),
+                    ),
+                    audit_record(
+                        "a3",
+                        "fp-other",
+                        "pending_approval",
+                        Some("chain-other"),
+                        Some("appr-other"),
+                    ),
+                ],
+                "total": 3,
+                "limit": 1000,
+                "offset": 0,
+            }),
+            "/v1/approvals" => serde_json::json!({
+                "approvals": [approval("appr-1"), approval("appr-other")],
+                "count": 2,
+            }),
+            _ => serde_json::json!({}),
+        }
+    }
+
+    #[tokio::test]
+    async fn assembles_every_part_of_the_incident() {
+        let client = ActeonClient::new(serve_incident().await);
+
+        let view = client.incident_view("fp-1", "alerts", "t1").await.unwrap();
+
+        assert_eq!(view.fingerprint, "fp-1");
+        assert_eq!(view.event.unwrap().state, "investigating");
+        assert!(view.history.unwrap().transitions.is_empty());
+        let actions: Vec<_> = view.audit.iter().map(|r| r.action_id.as_str()).collect();
+        assert_eq!(actions, ["a1", "a2"]);
+        let chains: Vec<_> = view.chains.iter().map(|c| c.chain_id.as_str()).collect();
+        assert_eq!(chains, ["chain-1"]);
+        let approvals: Vec<_> = view.approvals.iter().map(|a| a.token.as_str()).collect();
+        assert_eq!(approvals, ["appr-1"]);
+    }
+}
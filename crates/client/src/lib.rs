@@ -60,6 +60,7 @@
 pub mod a2a;
 pub mod aws;
 pub mod azure;
+pub mod dag;
 mod error;
 pub mod gcp;
 pub mod stream;
@@ -69,63 +70,114 @@ pub mod webhook;
 mod analytics;
 mod approvals;
 mod audit;
+mod audit_export;
+mod batch_stream;
 mod bus;
+mod chain_progress;
 mod chains;
 mod circuit_breakers;
+mod clock;
 mod compliance;
+mod compression;
 mod coverage;
 mod dispatch;
 mod dlq;
 mod events;
 mod executions;
+mod feature_flags;
 mod groups;
+mod health;
+mod idempotency;
+mod incident;
+mod metrics;
+mod pagination;
 mod plugins;
+mod polling;
+mod propagation;
 mod providers;
 mod queues;
 mod quotas;
 mod recurring;
+mod response_hook;
 mod retention;
+mod retry;
+mod routes;
 mod rules;
+mod scope;
+mod shutdown;
 mod signing_keys;
 mod silences;
 mod streaming;
 mod swarm;
 mod templates;
 mod time_intervals;
+mod timeouts;
+mod timing;
+#[cfg(feature = "tls")]
+mod tls;
+mod transport;
 mod workflows;
 
+#[cfg(test)]
+mod mock_server;
+
 pub use error::Error;
-pub use stream::{EventStream, StreamFilter, StreamItem};
+pub use reqwest::Method;
+pub use stream::{EventStream, StreamFilter, StreamItem, StreamReconnect};
+use transport::GatedSend;
 
 // Re-export core attachment type so callers don't need a direct `acteon_core` dependency.
 pub use acteon_core::Attachment;
 
+// Re-export the action type, its builder, and the audit reconstruction trait
+// for client-side replay.
+pub use acteon_core::{Action, ActionBuildError, ActionBuilder, AuditedAction};
+
+// Re-export core event history types returned by `get_event_history`.
+pub use acteon_core::{EventTransition, TransitionTrigger};
+
 // Re-export all public types from domain modules so the public API is unchanged.
 pub use analytics::*;
 pub use approvals::*;
 pub use audit::*;
+pub use audit_export::*;
+pub use batch_stream::*;
 pub use bus::*;
+pub use chain_progress::*;
 pub use chains::*;
+pub use clock::*;
 pub use compliance::*;
+pub use compression::COMPRESSION_THRESHOLD;
 pub use coverage::*;
 pub use dispatch::*;
 pub use dlq::*;
 pub use events::*;
 pub use executions::*;
+pub use feature_flags::*;
 pub use groups::*;
+pub use health::*;
+pub use idempotency::{IDEMPOTENCY_KEY_HEADER, IdempotencyKeyFn, fingerprint_idempotency_key};
+pub use incident::*;
+pub use metrics::*;
 pub use plugins::*;
+pub use polling::{PollInterval, WaitOptions};
+pub use propagation::PropagateHeadersFn;
 pub use queues::*;
 pub use quotas::*;
 pub use recurring::*;
+pub use response_hook::{OnResponseFn, RequestInfo, ResponseInfo};
 pub use retention::*;
+pub use retry::{RetryClassifierFn, RetryPolicy};
 pub use rules::*;
 pub use signing_keys::*;
 pub use silences::*;
 pub use swarm::*;
 pub use templates::*;
 pub use time_intervals::*;
+pub use timing::*;
 pub use workflows::*;
 
+use std::collections::HashMap;
 use std::time::Duration;
 
 use reqwest::Client;
@@ -133,6 +185,13 @@ use reqwest::Client;
 /// Default request timeout.
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// Scheme of base URLs that dial a Unix domain socket.
+const UNIX_SOCKET_SCHEME: &str = "unix:";
+
+/// Base URL that request paths are formatted against when dialing a Unix
+/// domain socket. The host only fills the `Host` header.
+const UNIX_SOCKET_BASE_URL: &str = "http://localhost";
+
 /// HTTP client for the Acteon action gateway.
 ///
 /// Provides methods for dispatching actions, managing rules, and querying audit logs
@@ -142,6 +201,11 @@ pub struct ActeonClient {
     pub(crate) client: Client,
     pub(crate) base_url: String,
     pub(crate) api_key: Option<String>,
+    pub(crate) default_metadata: HashMap<String, String>,
+    pub(crate) idempotency_keys: idempotency::IdempotencyKeys,
+    pub(crate) stream_batch_responses: bool,
+    pub(crate) scope: scope::DefaultScope,
+    pub(crate) gate: std::sync::Arc<transport::RequestGate>,
 }
 
 /// Builder for configuring an [`ActeonClient`].
@@ -150,25 +214,67 @@ pub struct ActeonClientBuilder {
     base_url: String,
     timeout: Duration,
     api_key: Option<String>,
+    default_metadata: HashMap<String, String>,
     client: Option<Client>,
     ca_cert_path: Option<String>,
     client_cert_path: Option<String>,
     client_key_path: Option<String>,
     danger_accept_invalid_certs: bool,
+    #[cfg(feature = "tls")]
+    tls: tls::TlsConfig,
+    recorder: metrics::SharedRecorder,
+    endpoint_timeouts: timeouts::EndpointTimeouts,
+    retry_policy: RetryPolicy,
+    idempotency_keys: idempotency::IdempotencyKeys,
+    retry_classifier: retry::RetryClassifier,
+    compress_requests: bool,
+    stream_batch_responses: bool,
+    scope: scope::DefaultScope,
+    propagator: propagation::HeaderPropagator,
+    on_response: response_hook::ResponseHook,
+    unix_socket: Option<std::path::PathBuf>,
 }
 
 impl ActeonClientBuilder {
     /// Create a new builder with the given base URL.
+    ///
+    /// A `unix:/path/to/sock` base URL sends every request over that Unix
+    /// domain socket, with paths formatted against `http://localhost`. It
+    /// needs the `unix-socket` feature; without it [`build`](Self::build)
+    /// fails with [`Error::Configuration`]. A custom client set with
+    /// [`client`](Self::client) must be configured for the socket itself.
     pub fn new(base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+        let (base_url, unix_socket) = match base_url.strip_prefix(UNIX_SOCKET_SCHEME) {
+            Some(path) => (
+                UNIX_SOCKET_BASE_URL.to_owned(),
+                Some(path.strip_prefix("//").unwrap_or(path).into()),
+            ),
+            None => (base_url.trim_end_matches('/').to_string(), None),
+        };
         Self {
-            base_url: base_url.into().trim_end_matches('/').to_string(),
+            base_url,
             timeout: DEFAULT_TIMEOUT,
             api_key: None,
+            default_metadata: HashMap::new(),
             client: None,
             ca_cert_path: None,
             client_cert_path: None,
             client_key_path: None,
             danger_accept_invalid_certs: false,
+            #[cfg(feature = "tls")]
+            tls: tls::TlsConfig::default(),
+            recorder: metrics::SharedRecorder::default(),
+            endpoint_timeouts: timeouts::EndpointTimeouts::default(),
+            retry_policy: RetryPolicy::default(),
+            idempotency_keys: idempotency::IdempotencyKeys::default(),
+            retry_classifier: retry::RetryClassifier::default(),
+            compress_requests: false,
+            stream_batch_responses: false,
+            scope: scope::DefaultScope::default(),
+            propagator: propagation::HeaderPropagator::default(),
+            on_response: response_hook::ResponseHook::default(),
+            unix_socket,
         }
     }
 
@@ -179,6 +285,225 @@ impl ActeonClientBuilder {
         self
     }
 
+    /// Use a different timeout for requests whose path matches `pattern`.
+    ///
+    /// Patterns are URL paths matched segment by segment, where a `*`
+    /// segment matches any single segment (e.g. `/v1/audit/*`). When several
+    /// patterns match, the one with the fewest wildcards wins. Requests that
+    /// match no pattern use [`timeout`](Self::timeout).
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use acteon_client::ActeonClientBuilder;
+    ///
+    /// let client = ActeonClientBuilder::new("http://localhost:8080")
+    ///     .timeout(Duration::from_secs(5))
+    ///     .endpoint_timeout("/v1/audit", Duration::from_secs(300))
+    ///     .build()?;
+    /// # Ok::<(), acteon_client::Error>(())
+    /// ```
+    #[must_use]
+    pub fn endpoint_timeout(mut self, pattern: impl AsRef<str>, timeout: Duration) -> Self {
+        self.endpoint_timeouts.insert(pattern.as_ref(), timeout);
+        self
+    }
+
+    /// Retry transient failures according to `policy`.
+    ///
+    /// Applies to dispatches (single and batch) and to `GET` requests. Only
+    /// connection failures, HTTP 429 and 503 responses, and API errors marked
+    /// `retryable` are retried. Retries are reported to
+    /// [`MetricsRecorder::record_retry`]. By default nothing is retried.
+    ///
+    /// A dispatch whose response was lost may already have executed, so give
+    /// actions a dedup key when a retried dispatch must not run twice.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use acteon_client::{ActeonClientBuilder, RetryPolicy};
+    ///
+    /// let client = ActeonClientBuilder::new("http://localhost:8080")
+    ///     .retry_policy(RetryPolicy {
+    ///         max_attempts: 3,
+    ///         base_delay: Duration::from_millis(250),
+    ///         ..RetryPolicy::default()
+    ///     })
+    ///     .build()?;
+    /// # Ok::<(), acteon_client::Error>(())
+    /// ```
+    #[must_use]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Decide which failures are retried, replacing the default
+    /// classification.
+    ///
+    /// By default connection failures, rate limits, HTTP 503 responses, and
+    /// API errors marked `retryable` are retried. A classifier sees every
+    /// failed attempt, including non-success responses to `GET` requests as
+    /// [`Error::Http`], and returns whether to retry it. It only takes
+    /// effect with a [`retry_policy`](Self::retry_policy) that allows more
+    /// than one attempt.
+    ///
+    /// ```no_run
+    /// use acteon_client::{ActeonClientBuilder, Error, RetryPolicy};
+    ///
+    /// // Also retry 502 and 504 from the load balancer.
+    /// let client = ActeonClientBuilder::new("http://localhost:8080")
+    ///     .retry_policy(RetryPolicy { max_attempts: 3, ..RetryPolicy::default() })
+    ///     .retry_classifier(|error| match error {
+    ///         Error::Connection(_) | Error::RateLimited { .. } => true,
+    ///         Error::Http { status, .. } => matches!(status, 429 | 502..=504),
+    ///         Error::Api { retryable, .. } => *retryable,
+    ///         _ => false,
+    ///     })
+    ///     .build()?;
+    /// # Ok::<(), acteon_client::Error>(())
+    /// ```
+    #[must_use]
+    pub fn retry_classifier(
+        mut self,
+        classify: impl Fn(&Error) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.retry_classifier = retry::RetryClassifier(Some(std::sync::Arc::new(classify)));
+        self
+    }
+
+    /// Derive an `Idempotency-Key` header for dispatches from each action.
+    ///
+    /// Applies to [`dispatch`](ActeonClient::dispatch) and
+    /// [`dispatch_batch`](ActeonClient::dispatch_batch) calls that do not
+    /// pass a key explicitly. A batch gets a key only if every action in it
+    /// does. Dry runs never carry a key.
+    ///
+    /// ```no_run
+    /// use acteon_client::{ActeonClientBuilder, fingerprint_idempotency_key};
+    ///
+    /// let client = ActeonClientBuilder::new("http://localhost:8080")
+    ///     .idempotency_key_fn(fingerprint_idempotency_key)
+    ///     .build()?;
+    /// # Ok::<(), acteon_client::Error>(())
+    /// ```
+    #[must_use]
+    pub fn idempotency_key_fn(
+        mut self,
+        generate: impl Fn(&Action) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.idempotency_keys = idempotency::IdempotencyKeys(Some(std::sync::Arc::new(generate)));
+        self
+    }
+
+    /// Gzip-compress large request bodies.
+    ///
+    /// When enabled, `POST` and `PUT` bodies of at least
+    /// [`COMPRESSION_THRESHOLD`] bytes, such as big batches or plugin
+    /// uploads, are sent with `Content-Encoding: gzip`. Smaller bodies are
+    /// sent uncompressed. Disabled by default.
+    ///
+    /// ```no_run
+    /// use acteon_client::ActeonClientBuilder;
+    ///
+    /// let client = ActeonClientBuilder::new("http://localhost:8080")
+    ///     .compress_requests(true)
+    ///     .build()?;
+    /// # Ok::<(), acteon_client::Error>(())
+    /// ```
+    #[must_use]
+    pub fn compress_requests(mut self, enabled: bool) -> Self {
+        self.compress_requests = enabled;
+        self
+    }
+
+    /// Have [`dispatch_batch`](ActeonClient::dispatch_batch) read results
+    /// as they are streamed instead of as one JSON array.
+    ///
+    /// Batch requests then ask the server for NDJSON, one line per action,
+    /// and the lines are put back into request order. If the response is
+    /// cut off part-way, e.g. by a proxy body size limit, the results that
+    /// arrived are kept and each missing one is reported as a
+    /// [`TRUNCATED_RESULT_CODE`] error, rather than the whole call failing
+    /// with [`Error::ResponseTooLarge`]. Servers that ignore the request
+    /// and answer with a JSON array still work.
+    ///
+    /// ```no_run
+    /// use acteon_client::ActeonClientBuilder;
+    ///
+    /// let client = ActeonClientBuilder::new("http://localhost:8080")
+    ///     .stream_batch_responses(true)
+    ///     .build()?;
+    /// # Ok::<(), acteon_client::Error>(())
+    /// ```
+    #[must_use]
+    pub fn stream_batch_responses(mut self, enabled: bool) -> Self {
+        self.stream_batch_responses = enabled;
+        self
+    }
+
+    /// Add the headers returned by `propagate` to every request.
+    ///
+    /// `propagate` runs once per request, when it is sent, so it can read
+    /// task-local state such as a request ID or trace context. It applies
+    /// to dispatch, streaming, and admin endpoints alike. Headers the
+    /// client sets itself, such as `Authorization`, take precedence. With
+    /// the `otel` feature the current OpenTelemetry context is injected as
+    /// W3C `traceparent` and `tracestate` headers as well.
+    ///
+    /// ```no_run
+    /// use acteon_client::ActeonClientBuilder;
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// let client = ActeonClientBuilder::new("http://localhost:8080")
+    ///     .propagate_headers(|| {
+    ///         let mut headers = HeaderMap::new();
+    ///         headers.insert("x-request-id", HeaderValue::from_static("req-42"));
+    ///         headers
+    ///     })
+    ///     .build()?;
+    /// # Ok::<(), acteon_client::Error>(())
+    /// ```
+    #[must_use]
+    pub fn propagate_headers(
+        mut self,
+        propagate: impl Fn() -> reqwest::header::HeaderMap + Send + Sync + 'static,
+    ) -> Self {
+        self.propagator = propagation::HeaderPropagator(Some(std::sync::Arc::new(propagate)));
+        self
+    }
+
+    /// Call `hook` after every request with its method, URL, status, and
+    /// elapsed time.
+    ///
+    /// It runs for every endpoint and every retry attempt, whether the
+    /// server answered with success, with an error status, or not at all,
+    /// in which case the status is `None`. Bodies are not included. The
+    /// hook is called inline on the request path, so it should be cheap.
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    ///
+    /// use acteon_client::{ActeonClientBuilder, RequestInfo, ResponseInfo};
+    ///
+    /// let client = ActeonClientBuilder::new("http://localhost:8080")
+    ///     .on_response(Arc::new(|request: &RequestInfo<'_>, response: &ResponseInfo| {
+    ///         tracing::debug!(
+    ///             method = %request.method,
+    ///             url = %request.url,
+    ///             status = ?response.status,
+    ///             elapsed_ms = response.elapsed.as_millis(),
+    ///             "acteon call",
+    ///         );
+    ///     }))
+    ///     .build()?;
+    /// # Ok::<(), acteon_client::Error>(())
+    /// ```
+    #[must_use]
+    pub fn on_response(mut self, hook: std::sync::Arc<OnResponseFn>) -> Self {
+        self.on_response = response_hook::ResponseHook(Some(hook));
+        self
+    }
+
     /// Set the API key for authentication.
     #[must_use]
     pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
@@ -186,6 +511,39 @@ impl ActeonClientBuilder {
         self
     }
 
+    /// Add metadata labels to every dispatched action.
+    ///
+    /// Useful for tagging all traffic with the host, environment, or service
+    /// name. Labels set on the action itself take precedence over these
+    /// defaults. Calling this more than once merges the maps.
+    #[must_use]
+    pub fn default_metadata(mut self, metadata: HashMap<String, String>) -> Self {
+        self.default_metadata.extend(metadata);
+        self
+    }
+
+    /// Use `namespace` for calls that leave the namespace unset.
+    ///
+    /// Applies to [`AuditQuery`], [`EventQuery`], and [`RecurringFilter`]
+    /// whose namespace is empty or `None`, and to approval calls given an
+    /// empty namespace. A namespace set on the request always wins.
+    #[must_use]
+    pub fn default_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.scope.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Use `tenant` for calls that leave the tenant unset.
+    ///
+    /// Applies to the same calls as
+    /// [`default_namespace`](Self::default_namespace). A tenant set on the
+    /// request always wins.
+    #[must_use]
+    pub fn default_tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.scope.tenant = Some(tenant.into());
+        self
+    }
+
     /// Set a custom CA certificate file (PEM) for server verification.
     ///
     /// When set, only certificates signed by this CA will be trusted.
@@ -210,8 +568,38 @@ impl ActeonClientBuilder {
         self
     }
 
+    /// Present a client certificate and key (PEM) to the server for mTLS.
+    ///
+    /// Like [`client_cert`](Self::client_cert), but for PEM already in
+    /// memory, e.g. read from a secret store. It replaces an identity set
+    /// by `client_cert`. Malformed PEM makes [`build`](Self::build) fail
+    /// with [`Error::Configuration`]. Ignored when a custom client is set
+    /// with [`client`](Self::client).
+    #[cfg(feature = "tls")]
+    #[must_use]
+    pub fn identity(mut self, client_cert_pem: &[u8], key_pem: &[u8]) -> Self {
+        self.tls.set_identity(client_cert_pem, key_pem);
+        self
+    }
+
+    /// Trust servers whose certificate is signed by this CA (PEM).
+    ///
+    /// Like [`ca_cert_path`](Self::ca_cert_path), but for PEM already in
+    /// memory. Can be called more than once to trust several CAs. Ignored
+    /// when a custom client is set with [`client`](Self::client).
+    #[cfg(feature = "tls")]
+    #[must_use]
+    pub fn add_root_cert(mut self, pem: &[u8]) -> Self {
+        self.tls.add_root_cert(pem);
+        self
+    }
+
     /// Skip certificate verification (dev/test only).
     ///
+    /// Lets the client talk to a local server with a self-signed
+    /// certificate without building a custom reqwest client. Ignored when a
+    /// custom client is set with [`client`](Self::client).
+    ///
     /// # Warning
     ///
     /// This completely disables TLS certificate validation. Only use in
@@ -222,9 +610,21 @@ impl ActeonClientBuilder {
         self
     }
 
+    /// Report request metrics to `recorder`.
+    ///
+    /// The recorder sees the method, endpoint, status, and duration of every
+    /// request. Defaults to [`NoopRecorder`].
+    #[must_use]
+    pub fn metrics_recorder(mut self, recorder: std::sync::Arc<dyn MetricsRecorder>) -> Self {
+        self.recorder = metrics::SharedRecorder(recorder);
+        self
+    }
+
     /// Use a custom reqwest Client.
     ///
     /// Useful for configuring TLS, proxies, or other advanced settings.
+    /// The custom client takes precedence: the builder's timeout and TLS
+    /// options are ignored.
     #[must_use]
     pub fn client(mut self, client: Client) -> Self {
         self.client = Some(client);
@@ -233,6 +633,8 @@ impl ActeonClientBuilder {
 
     /// Build the client.
     pub fn build(self) -> Result<ActeonClient, Error> {
+        self.retry_policy.validate().map_err(Error::Configuration)?;
+
         let client = if let Some(c) = self.client {
             c
         } else {
@@ -267,6 +669,23 @@ impl ActeonClientBuilder {
                 builder = builder.identity(identity);
             }
 
+            #[cfg(feature = "tls")]
+            {
+                builder = self.tls.apply(builder)?;
+            }
+
+            if let Some(path) = self.unix_socket {
+                #[cfg(all(unix, feature = "unix-socket"))]
+                {
+                    builder = builder.unix_socket(path);
+                }
+                #[cfg(not(all(unix, feature = "unix-socket")))]
+                return Err(Error::Configuration(format!(
+                    "cannot dial unix socket {}: built without the `unix-socket` feature",
+                    path.display()
+                )));
+            }
+
             builder
                 .build()
                 .map_err(|e| Error::Configuration(e.to_string()))?
@@ -276,6 +695,19 @@ impl ActeonClientBuilder {
             client,
             base_url: self.base_url,
             api_key: self.api_key,
+            default_metadata: self.default_metadata,
+            idempotency_keys: self.idempotency_keys,
+            stream_batch_responses: self.stream_batch_responses,
+            scope: self.scope,
+            gate: std::sync::Arc::new(transport::RequestGate::new(
+                self.recorder,
+                self.endpoint_timeouts,
+                self.retry_policy,
+                self.retry_classifier,
+                self.compress_requests,
+                self.propagator,
+                self.on_response,
+            )),
         })
     }
 }
@@ -314,6 +746,78 @@ impl ActeonClient {
         }
     }
 
+    /// Send a request to an arbitrary API path and deserialize the JSON response.
+    ///
+    /// An escape hatch for endpoints the server exposes before this client
+    /// gains a typed method for them. The API key, base URL, and error mapping
+    /// are applied exactly as for the typed methods: a 429 becomes
+    /// [`Error::RateLimited`], any other non-2xx response with a JSON error
+    /// body becomes [`Error::Api`], and anything else [`Error::Http`].
+    /// An empty response body deserializes as JSON `null`, so `T = ()` works
+    /// for endpoints that return `204 No Content`.
+    ///
+    /// **Unstable:** the untyped endpoints this reaches can change shape
+    /// without notice, and this method may change or go away once typed
+    /// coverage catches up. Prefer a typed method when one exists.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::{ActeonClient, Method};
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let stats: serde_json::Value = client
+    ///     .request(Method::GET, "/v1/new-endpoint", None, &[("tenant", "tenant-1")])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+        query: &[(&str, &str)],
+    ) -> Result<T, Error> {
+        let url = format!("{}/{}", self.base_url, path.trim_start_matches('/'));
+
+        let mut req = self.add_auth(self.client.request(method, &url));
+        if !query.is_empty() {
+            req = req.query(query);
+        }
+        if let Some(body) = body {
+            req = req.json(body);
+        }
+        let response = req.send_gated(&self.gate).await?;
+
+        let status = response.status();
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| Error::Connection(e.to_string()))?;
+
+        if status.is_success() {
+            let result = if bytes.is_empty() {
+                serde_json::from_value(serde_json::Value::Null)
+            } else {
+                serde_json::from_slice(&bytes)
+            };
+            result.map_err(|e| Error::Deserialization(e.to_string()))
+        } else if let Ok(error) = serde_json::from_slice::<ErrorResponse>(&bytes) {
+            Err(Error::Api {
+                code: error.code,
+                message: error.message,
+                retryable: error.retryable,
+            })
+        } else {
+            Err(Error::Http {
+                status: status.as_u16(),
+                message: format!("Request to {path} failed: {status}"),
+            })
+        }
+    }
+
     // =========================================================================
     // Health
     // =========================================================================
@@ -336,17 +840,210 @@ impl ActeonClient {
         let url = format!("{}/health", self.base_url);
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         Ok(response.status().is_success())
     }
+
+    /// Prime the client before its first real request.
+    ///
+    /// Calls the health endpoint so DNS resolution and the TCP/TLS handshakes
+    /// happen now rather than on the first dispatch. This is best-effort: the
+    /// pooled connection can still be closed by the server or by the pool's
+    /// idle timeout before it is reused.
+    ///
+    /// Returns an error if the server cannot be reached or reports unhealthy.
+    pub async fn warm_up(&self) -> Result<(), Error> {
+        self.warm_up_pool(1).await
+    }
+
+    /// Like [`warm_up`](Self::warm_up), but opens up to `connections` pooled
+    /// connections by issuing that many health checks concurrently.
+    ///
+    /// Useful for callers that fan out several dispatches at once right after
+    /// startup. A `connections` of zero is treated as one.
+    pub async fn warm_up_pool(&self, connections: usize) -> Result<(), Error> {
+        let url = format!("{}/health", self.base_url);
+        let checks = (0..connections.max(1)).map(|_| async {
+            let response = self
+                .add_auth(self.client.get(&url))
+                .send_gated(&self.gate)
+                .await?;
+            let status = response.status();
+            if status.is_success() {
+                Ok(())
+            } else {
+                Err(Error::Http {
+                    status: status.as_u16(),
+                    message: "health check failed during warm-up".to_string(),
+                })
+            }
+        });
+        futures::future::try_join_all(checks).await?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_server::{self, Response};
+
+    /// Answer every request with `status` and an empty JSON body.
+    async fn start_health_server(status: u16) -> String {
+        mock_server::serve(move |_| Response::json(status, &serde_json::json!({}))).await
+    }
+
+    /// Answer every request with an empty audit page after `delay`.
+    async fn start_slow_server(delay: Duration) -> String {
+        mock_server::serve(move |_| {
+            Response::json(
+                200,
+                &serde_json::json!({"records": [], "limit": 100, "offset": 0, "next_cursor": null}),
+            )
+            .delay(delay)
+        })
+        .await
+    }
+
+    /// Echo the request line and body back as JSON, or fail `/v1/missing`
+    /// with a structured API error.
+    async fn start_echo_server() -> String {
+        mock_server::serve(|request| {
+            if request.path() == "/v1/missing" {
+                return Response::json(
+                    404,
+                    &serde_json::json!({
+                        "code": "NOT_FOUND",
+                        "message": "no such thing",
+                        "retryable": false,
+                    }),
+                );
+            }
+            Response::json(
+                200,
+                &serde_json::json!({
+                    "route": request.route(),
+                    "authorized": request.header("authorization") == Some("Bearer secret"),
+                    "body": request.json(),
+                }),
+            )
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn request_reaches_custom_endpoint() {
+        let client = ActeonClientBuilder::new(start_echo_server().await)
+            .api_key("secret")
+            .build()
+            .unwrap();
+
+        let echoed: serde_json::Value = client
+            .request(
+                Method::POST,
+                "/v1/custom/widgets",
+                Some(&serde_json::json!({"size": 3})),
+                &[("tenant", "t1")],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(echoed["route"], "POST /v1/custom/widgets?tenant=t1");
+        assert_eq!(echoed["authorized"], true);
+        assert_eq!(echoed["body"]["size"], 3);
+    }
+
+    #[tokio::test]
+    async fn request_maps_api_errors() {
+        let client = ActeonClient::new(start_echo_server().await);
+        let err = client
+            .request::<serde_json::Value>(Method::GET, "v1/missing", None, &[])
+            .await
+            .unwrap_err();
+        assert_eq!(err.api_code(), Some("NOT_FOUND"));
+    }
+
+    /// Serve health, dispatch, and stream requests on a Unix socket in a
+    /// fresh temporary directory, forwarding each request's route.
+    #[cfg(all(unix, feature = "unix-socket"))]
+    fn start_unix_socket_server() -> (
+        std::path::PathBuf,
+        tokio::sync::mpsc::UnboundedReceiver<String>,
+    ) {
+        let path = std::env::temp_dir().join(format!(
+            "acteon-client-{}-{}.sock",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        mock_server::serve_unix(&path, move |request| {
+            let _ = tx.send(request.route());
+            match request.path() {
+                "/v1/stream" => Response::new(200).body("text/event-stream", ": keep-alive\n\n"),
+                "/v1/dispatch" => Response::json(200, &serde_json::json!("Deduplicated")),
+                _ => Response::json(200, &serde_json::json!({})),
+            }
+        });
+        (path, rx)
+    }
+
+    #[cfg(all(unix, feature = "unix-socket"))]
+    #[tokio::test]
+    async fn unix_socket_base_url_dials_the_socket() {
+        use futures::StreamExt;
+
+        let (path, mut requests) = start_unix_socket_server();
+        let client = ActeonClientBuilder::new(format!("unix:{}", path.display()))
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url(), UNIX_SOCKET_BASE_URL);
+
+        assert!(client.health().await.unwrap());
+        let action = Action::new("ns", "t1", "email", "send", serde_json::json!({}));
+        client.dispatch(&action).await.unwrap();
+        let mut stream = client.stream(&StreamFilter::new()).await.unwrap();
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(StreamItem::KeepAlive))
+        ));
+
+        let mut lines = Vec::new();
+        while let Ok(line) = requests.try_recv() {
+            lines.push(line);
+        }
+        assert_eq!(lines[0], "GET /health");
+        assert_eq!(lines[1], "POST /v1/dispatch");
+        assert!(lines[2].starts_with("GET /v1/stream"), "{lines:?}");
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[cfg(not(feature = "unix-socket"))]
+    #[test]
+    fn unix_socket_base_url_needs_the_feature() {
+        let err = ActeonClientBuilder::new("unix:///run/acteon.sock")
+            .build()
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::Configuration(m) if m.contains("/run/acteon.sock")),
+            "{err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn warm_up_succeeds_against_healthy_server() {
+        let client = ActeonClient::new(start_health_server(200).await);
+        client.warm_up().await.unwrap();
+        client.warm_up_pool(4).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn warm_up_reports_unhealthy_server() {
+        let client = ActeonClient::new(start_health_server(503).await);
+        let err = client.warm_up().await.unwrap_err();
+        assert!(matches!(err, Error::Http { status: 503, .. }));
+    }
 
     #[test]
     fn client_trims_trailing_slash() {
@@ -369,6 +1066,65 @@ mod tests {
         assert_eq!(client.api_key, Some("test-key".to_string()));
     }
 
+    #[test]
+    fn builder_accepts_invalid_certs_flag() {
+        let client = ActeonClientBuilder::new("https://localhost:8443")
+            .danger_accept_invalid_certs(true)
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url(), "https://localhost:8443");
+    }
+
+    #[cfg(feature = "tls")]
+    #[test]
+    fn builder_loads_in_memory_tls_material() {
+        let key = rcgen::KeyPair::generate().unwrap();
+        let cert = rcgen::CertificateParams::new(vec!["localhost".to_owned()])
+            .unwrap()
+            .self_signed(&key)
+            .unwrap();
+        let (cert_pem, key_pem) = (cert.pem(), key.serialize_pem());
+
+        ActeonClientBuilder::new("https://localhost:8443")
+            .identity(cert_pem.as_bytes(), key_pem.as_bytes())
+            .add_root_cert(cert_pem.as_bytes())
+            .build()
+            .unwrap();
+
+        let err = ActeonClientBuilder::new("https://localhost:8443")
+            .identity(cert_pem.as_bytes(), b"not a key")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, Error::Configuration(_)), "{err:?}");
+
+        // A custom client wins, so the malformed identity is never parsed.
+        ActeonClientBuilder::new("https://localhost:8443")
+            .identity(b"not a cert", b"not a key")
+            .client(Client::new())
+            .build()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn endpoint_timeout_overrides_default_for_matching_paths() {
+        let client = ActeonClientBuilder::new(start_slow_server(Duration::from_millis(300)).await)
+            .timeout(Duration::from_millis(100))
+            .endpoint_timeout("/v1/audit", Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        // The export pages through `/v1/audit` and outlasts the default.
+        let mut buf = Vec::new();
+        let stats = client
+            .export_audit(AuditQuery::default(), &mut buf, ExportFormat::Ndjson)
+            .await
+            .unwrap();
+        assert_eq!(stats.pages, 1);
+
+        // The health check has no override and times out.
+        assert!(matches!(client.health().await, Err(Error::Connection(_))));
+    }
+
     #[test]
     fn batch_result_helpers() {
         use acteon_core::{ActionOutcome, ProviderResponse};
@@ -0,0 +1,206 @@
+//! Client-side request metrics.
+//!
+//! Every request the client sends is reported to a [`MetricsRecorder`] once
+//! its response headers arrive or the request fails, and so is every retry
+//! made under the client's [`RetryPolicy`](crate::RetryPolicy). The default recorder
+//! does nothing; plug in your own with
+//! [`ActeonClientBuilder::metrics_recorder`](crate::ActeonClientBuilder::metrics_recorder).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Method;
+
+/// A single completed request, as reported to a [`MetricsRecorder`].
+#[derive(Debug, Clone)]
+pub struct RequestMetrics<'a> {
+    /// HTTP method of the request.
+    pub method: &'a Method,
+    /// URL path of the request (e.g. `/v1/dispatch`). Path parameters such
+    /// as IDs are included as sent.
+    pub endpoint: &'a str,
+    /// Server route the request targets, with path parameters left as
+    /// placeholders (e.g. `/v1/rules/{name}/enabled`), or `"other"` for a
+    /// path that matches no known route. Unlike `endpoint`, this is safe to
+    /// use as a metric label.
+    pub route: &'a str,
+    /// HTTP status of the response, or `None` when no response arrived
+    /// (connection failure, timeout, or a refused request during shutdown).
+    pub status: Option<u16>,
+    /// Time from sending the request until the response headers arrived or
+    /// the request failed.
+    pub duration: Duration,
+}
+
+impl RequestMetrics<'_> {
+    /// Whether the request failed: no response, or a 4xx/5xx status.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        self.status.is_none_or(|status| status >= 400)
+    }
+}
+
+/// A failed attempt that is about to be retried, as reported to a
+/// [`MetricsRecorder`].
+#[derive(Debug, Clone)]
+pub struct RetryEvent<'a> {
+    /// HTTP method of the request.
+    pub method: &'a Method,
+    /// URL path of the request (e.g. `/v1/dispatch`).
+    pub endpoint: &'a str,
+    /// Server route the request targets; see [`RequestMetrics::route`].
+    pub route: &'a str,
+    /// The attempt that failed, starting at 1.
+    pub attempt: u32,
+    /// How long the client waits before the next attempt.
+    pub delay: Duration,
+    /// Why the attempt failed (e.g. `HTTP 503 Service Unavailable`).
+    pub reason: &'a str,
+}
+
+/// Receives metrics for every request an [`ActeonClient`](crate::ActeonClient)
+/// sends.
+///
+/// Implementations are called inline on the request path, so they should be
+/// cheap and must not block.
+pub trait MetricsRecorder: Send + Sync {
+    /// Record one completed request.
+    fn record_request(&self, request: &RequestMetrics<'_>);
+
+    /// Record a failed attempt that the client is about to retry.
+    ///
+    /// Every attempt is also reported to
+    /// [`record_request`](Self::record_request). Does nothing by default.
+    fn record_retry(&self, _retry: &RetryEvent<'_>) {}
+}
+
+/// A recorder that discards all metrics. This is the default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopRecorder;
+
+impl MetricsRecorder for NoopRecorder {
+    fn record_request(&self, _request: &RequestMetrics<'_>) {}
+}
+
+/// Adapter that reports request metrics through the [`metrics`] crate
+/// facade.
+///
+/// Emits, labelled by `method`, `endpoint`, and `status` (`"none"` when no
+/// response arrived), where `endpoint` is the request's
+/// [route template](RequestMetrics::route) rather than its raw path:
+///
+/// - `acteon_client_requests_total` (counter)
+/// - `acteon_client_request_errors_total` (counter)
+/// - `acteon_client_request_duration_seconds` (histogram)
+///
+/// and, labelled by `method` and `endpoint`:
+///
+/// - `acteon_client_retries_total` (counter)
+///
+/// [`metrics`]: https://docs.rs/metrics
+#[cfg(feature = "metrics")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MetricsCrateRecorder;
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for MetricsCrateRecorder {
+    fn record_request(&self, request: &RequestMetrics<'_>) {
+        let labels = [
+            ("method", request.method.to_string()),
+            ("endpoint", request.route.to_owned()),
+            (
+                "status",
+                request
+                    .status
+                    .map_or_else(|| "none".to_owned(), |s| s.to_string()),
+            ),
+        ];
+        ::metrics::counter!("acteon_client_requests_total", &labels).increment(1);
+        if request.is_error() {
+            ::metrics::counter!("acteon_client_request_errors_total", &labels).increment(1);
+        }
+        ::metrics::histogram!("acteon_client_request_duration_seconds", &labels)
+            .record(request.duration.as_secs_f64());
+    }
+
+    fn record_retry(&self, retry: &RetryEvent<'_>) {
+        let labels = [
+            ("method", retry.method.to_string()),
+            ("endpoint", retry.route.to_owned()),
+        ];
+        ::metrics::counter!("acteon_client_retries_total", &labels).increment(1);
+    }
+}
+
+/// Shared handle to the configured recorder.
+#[derive(Clone)]
+pub(crate) struct SharedRecorder(pub(crate) Arc<dyn MetricsRecorder>);
+
+impl Default for SharedRecorder {
+    fn default() -> Self {
+        Self(Arc::new(NoopRecorder))
+    }
+}
+
+impl std::fmt::Debug for SharedRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MetricsRecorder")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use acteon_core::Action;
+
+    use super::*;
+    use crate::ActeonClientBuilder;
+    use crate::mock_server::{self, Response};
+
+    /// `(method, endpoint, route, status, duration)` of one request.
+    type Captured = (String, String, String, Option<u16>, Duration);
+
+    /// Keeps every reported request.
+    #[derive(Default)]
+    struct CapturingRecorder {
+        requests: Mutex<Vec<Captured>>,
+    }
+
+    impl MetricsRecorder for CapturingRecorder {
+        fn record_request(&self, request: &RequestMetrics<'_>) {
+            self.requests.lock().unwrap().push((
+                request.method.to_string(),
+                request.endpoint.to_owned(),
+                request.route.to_owned(),
+                request.status,
+                request.duration,
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn recorder_observes_dispatch() {
+        let base_url = mock_server::serve(|_| {
+            Response::json(200, &serde_json::json!("Deduplicated")).delay(Duration::from_millis(50))
+        })
+        .await;
+
+        let recorder = Arc::new(CapturingRecorder::default());
+        let client = ActeonClientBuilder::new(base_url)
+            .metrics_recorder(recorder.clone())
+            .build()
+            .unwrap();
+        let action = Action::new("ns", "t1", "email", "send", serde_json::json!({}));
+        client.dispatch(&action).await.unwrap();
+
+        let requests = recorder.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let (method, endpoint, route, status, duration) = &requests[0];
+        assert_eq!(method, "POST");
+        assert_eq!(endpoint, "/v1/dispatch");
+        assert_eq!(route, "/v1/dispatch");
+        assert_eq!(*status, Some(200));
+        assert!(*duration >= Duration::from_millis(50));
+    }
+}
@@ -0,0 +1,317 @@
+//! In-process HTTP server for unit tests.
+//!
+//! [`serve`] binds a local port (or [`serve_unix`] a Unix socket) and
+//! answers every request with a handler.
+//! Requests are read in full (headers, then a `Content-Length` or chunked
+//! body) before the handler runs, so tests see the whole body however the
+//! client splits its writes. Each response is sent with `Connection: close`.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// A request received by the mock server.
+#[derive(Debug, Clone)]
+pub(crate) struct Request {
+    /// HTTP method, e.g. `GET`.
+    pub(crate) method: String,
+    /// Request target: path plus query string.
+    pub(crate) target: String,
+    /// Headers, keyed by lower-case name.
+    pub(crate) headers: HashMap<String, String>,
+    /// Body as sent on the wire (not decompressed).
+    pub(crate) body: Vec<u8>,
+}
+
+impl Request {
+    /// Path without the query string.
+    pub(crate) fn path(&self) -> &str {
+        self.target.split_once('?').map_or(&self.target, |(p, _)| p)
+    }
+
+    /// Query string, if any.
+    pub(crate) fn query(&self) -> Option<&str> {
+        self.target.split_once('?').map(|(_, q)| q)
+    }
+
+    /// Value of the header `name` (case-insensitive).
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Body parsed as JSON, or `Null` when it is not JSON.
+    pub(crate) fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).unwrap_or_default()
+    }
+
+    /// `"METHOD target"`, handy for matching routes.
+    pub(crate) fn route(&self) -> String {
+        format!("{} {}", self.method, self.target)
+    }
+}
+
+/// A response for the mock server to send.
+#[derive(Debug, Clone)]
+pub(crate) struct Response {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    delay: Option<Duration>,
+    raw: Option<Vec<u8>>,
+}
+
+impl Response {
+    /// A response with `status` and an empty body.
+    pub(crate) fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+            delay: None,
+            raw: None,
+        }
+    }
+
+    /// A JSON response.
+    pub(crate) fn json(status: u16, body: &serde_json::Value) -> Self {
+        Self::new(status).body("application/json", body.to_string())
+    }
+
+    /// Bytes written to the socket as-is, for malformed or truncated
+    /// responses.
+    pub(crate) fn raw(bytes: impl Into<Vec<u8>>) -> Self {
+        Self {
+            raw: Some(bytes.into()),
+            ..Self::new(200)
+        }
+    }
+
+    /// Set the body and its content type.
+    pub(crate) fn body(mut self, content_type: &str, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self.header("Content-Type", content_type)
+    }
+
+    /// Add a header.
+    pub(crate) fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_owned(), value.into()));
+        self
+    }
+
+    /// Wait `delay` before sending the response.
+    pub(crate) fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    fn into_bytes(self) -> Vec<u8> {
+        if let Some(raw) = self.raw {
+            return raw;
+        }
+        let reason = StatusCode::from_u16(self.status)
+            .ok()
+            .and_then(|s| s.canonical_reason())
+            .unwrap_or("Unknown");
+        let mut head = format!("HTTP/1.1 {} {reason}\r\n", self.status);
+        for (name, value) in &self.headers {
+            let _ = write!(head, "{name}: {value}\r\n");
+        }
+        let _ = write!(
+            head,
+            "Content-Length: {}\r\nConnection: close\r\n\r\n",
+            self.body.len()
+        );
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(&self.body);
+        bytes
+    }
+}
+
+/// Serve `handler` on a local port and return the base URL.
+pub(crate) async fn serve<F>(handler: F) -> String
+where
+    F: Fn(Request) -> Response + Send + Sync + 'static,
+{
+    let handler = Arc::new(handler);
+    serve_async(move |request| {
+        let handler = Arc::clone(&handler);
+        async move { handler(request) }
+    })
+    .await
+}
+
+/// Like [`serve`], with a handler that can await (e.g. to hold a request
+/// open while tracking concurrency).
+pub(crate) async fn serve_async<F, Fut>(handler: F) -> String
+where
+    F: Fn(Request) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Response> + Send + 'static,
+{
+    serve_limited(usize::MAX, handler).await
+}
+
+/// Like [`serve_async`], but stop listening after `connections` requests so
+/// later connections are refused.
+pub(crate) async fn serve_limited<F, Fut>(connections: usize, handler: F) -> String
+where
+    F: Fn(Request) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Response> + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handler = Arc::new(handler);
+    tokio::spawn(async move {
+        for _ in 0..connections {
+            let Ok((stream, _)) = listener.accept().await else {
+                return;
+            };
+            let handler = Arc::clone(&handler);
+            tokio::spawn(async move {
+                handle(stream, handler.as_ref()).await;
+            });
+        }
+    });
+    base_url
+}
+
+/// Serve `handler` on a Unix socket at `path`.
+#[cfg(all(unix, feature = "unix-socket"))]
+pub(crate) fn serve_unix<F>(path: &std::path::Path, handler: F)
+where
+    F: Fn(Request) -> Response + Send + Sync + 'static,
+{
+    let listener = tokio::net::UnixListener::bind(path).unwrap();
+    let handler = Arc::new(move |request| std::future::ready(handler(request)));
+    tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                return;
+            };
+            let handler = Arc::clone(&handler);
+            tokio::spawn(async move {
+                handle(stream, handler.as_ref()).await;
+            });
+        }
+    });
+}
+
+async fn handle<S, F, Fut>(mut stream: S, handler: &F)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+    F: Fn(Request) -> Fut,
+    Fut: Future<Output = Response>,
+{
+    let Some(request) = read_request(&mut stream).await else {
+        return;
+    };
+    let response = handler(request).await;
+    if let Some(delay) = response.delay {
+        tokio::time::sleep(delay).await;
+    }
+    let _ = stream.write_all(&response.into_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Read one request: headers, then the body by `Content-Length` or chunked
+/// encoding. Returns `None` if the connection closes before the headers end.
+async fn read_request<S: AsyncRead + Unpin>(stream: &mut S) -> Option<Request> {
+    let mut data = Vec::new();
+    let header_end = loop {
+        if let Some(pos) = find(&data, b"\r\n\r\n") {
+            break pos;
+        }
+        if !read_more(stream, &mut data).await {
+            return None;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&data[..header_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_owned();
+    let target = request_line.next()?.to_owned();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_owned()))
+        .collect();
+
+    let mut rest = data.split_off(header_end + 4);
+    let body = if headers
+        .get("transfer-encoding")
+        .is_some_and(|te| te.eq_ignore_ascii_case("chunked"))
+    {
+        read_chunked(stream, rest).await
+    } else {
+        let length = headers
+            .get("content-length")
+            .and_then(|len| len.parse().ok())
+            .unwrap_or(0);
+        while rest.len() < length {
+            if !read_more(stream, &mut rest).await {
+                break;
+            }
+        }
+        rest.truncate(length);
+        rest
+    };
+
+    Some(Request {
+        method,
+        target,
+        headers,
+        body,
+    })
+}
+
+async fn read_chunked<S: AsyncRead + Unpin>(stream: &mut S, mut data: Vec<u8>) -> Vec<u8> {
+    let mut body = Vec::new();
+    loop {
+        let line_end = loop {
+            if let Some(pos) = find(&data, b"\r\n") {
+                break pos;
+            }
+            if !read_more(stream, &mut data).await {
+                return body;
+            }
+        };
+        let size_line = String::from_utf8_lossy(&data[..line_end]).into_owned();
+        let size = usize::from_str_radix(size_line.split(';').next().unwrap_or("").trim(), 16)
+            .unwrap_or(0);
+        let chunk_start = line_end + 2;
+        while data.len() < chunk_start + size + 2 {
+            if !read_more(stream, &mut data).await {
+                return body;
+            }
+        }
+        if size == 0 {
+            return body;
+        }
+        body.extend_from_slice(&data[chunk_start..chunk_start + size]);
+        data.drain(..chunk_start + size + 2);
+    }
+}
+
+async fn read_more<S: AsyncRead + Unpin>(stream: &mut S, data: &mut Vec<u8>) -> bool {
+    let mut buf = [0u8; 8192];
+    match stream.read(&mut buf).await {
+        Ok(0) | Err(_) => false,
+        Ok(n) => {
+            data.extend_from_slice(&buf[..n]);
+            true
+        }
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
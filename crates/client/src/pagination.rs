@@ -0,0 +1,60 @@
+//! Offset-based pagination shared by the `*_stream` list helpers.
+
+use std::collections::VecDeque;
+use std::future::Future;
+
+use futures::stream::Stream;
+
+use crate::Error;
+
+/// Stream every item of an offset-paginated list endpoint.
+///
+/// `fetch(limit, offset)` returns one page. Pages are requested lazily as
+/// the stream is polled; a page shorter than `page_size` ends the stream,
+/// and so does the first error.
+pub(crate) fn offset_pages<'a, T, F, Fut>(
+    page_size: usize,
+    fetch: F,
+) -> impl Stream<Item = Result<T, Error>> + 'a
+where
+    T: 'a,
+    F: Fn(usize, usize) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>, Error>> + 'a,
+{
+    struct State<T, F> {
+        fetch: F,
+        offset: usize,
+        buffered: VecDeque<T>,
+        done: bool,
+    }
+
+    let page_size = page_size.max(1);
+    let state = State {
+        fetch,
+        offset: 0,
+        buffered: VecDeque::new(),
+        done: false,
+    };
+
+    futures::stream::unfold(state, move |mut state| async move {
+        if let Some(item) = state.buffered.pop_front() {
+            return Some((Ok(item), state));
+        }
+        if state.done {
+            return None;
+        }
+        match (state.fetch)(page_size, state.offset).await {
+            Ok(page) => {
+                state.done = page.len() < page_size;
+                state.offset += page.len();
+                state.buffered.extend(page);
+                let item = state.buffered.pop_front()?;
+                Some((Ok(item), state))
+            }
+            Err(e) => {
+                state.done = true;
+                Some((Err(e), state))
+            }
+        }
+    })
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Configuration for a WASM plugin.
@@ -102,9 +103,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -127,9 +127,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -151,9 +150,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -177,9 +175,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status() == reqwest::StatusCode::NO_CONTENT {
             Ok(())
@@ -207,9 +204,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
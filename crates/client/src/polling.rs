@@ -0,0 +1,311 @@
+//! Polling with exponential backoff and a deadline.
+//!
+//! [`ActeonClient::poll_until`] repeats a request until its result satisfies
+//! a predicate, waiting longer between attempts each time and giving up with
+//! [`Error::Timeout`] once the deadline passes. The `wait_for_*` helpers are
+//! built on it, and it is public for polling endpoints that have no helper
+//! of their own.
+
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::retry::backoff_delay;
+use crate::{ActeonClient, Error};
+
+/// Delays between the polls of [`ActeonClient::poll_until`].
+///
+/// The delay after poll `n` is `initial * multiplier^(n - 1)`, capped at
+/// `max`, then shortened by a random fraction of up to `jitter`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use acteon_client::PollInterval;
+///
+/// // Wait 250ms, 500ms, 1s, then 2s between every later poll.
+/// let interval = PollInterval {
+///     initial: Duration::from_millis(250),
+///     max: Duration::from_secs(2),
+///     ..PollInterval::default()
+/// };
+/// assert!(interval.validate().is_ok());
+/// assert!(PollInterval::fixed(Duration::from_secs(1)).validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PollInterval {
+    /// Delay after the first poll.
+    pub initial: Duration,
+    /// Upper bound on the delay between polls.
+    pub max: Duration,
+    /// Factor the delay grows by after each poll. Must be at least `1.0`.
+    pub multiplier: f64,
+    /// Fraction of each delay, between `0.0` and `1.0`, that is randomly
+    /// cut off so that many pollers do not hit the server in lockstep.
+    pub jitter: f64,
+}
+
+impl Default for PollInterval {
+    /// Start at one second and double up to 30 seconds, with 10% jitter.
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.1,
+        }
+    }
+}
+
+impl PollInterval {
+    /// Poll at a constant `interval`, without backoff or jitter.
+    #[must_use]
+    pub fn fixed(interval: Duration) -> Self {
+        Self {
+            initial: interval,
+            max: interval,
+            multiplier: 1.0,
+            jitter: 0.0,
+        }
+    }
+
+    /// Validate configuration values.
+    ///
+    /// Returns `Err` with a description if `multiplier` is below `1.0` or
+    /// `jitter` is outside `0.0..=1.0`.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.multiplier >= 1.0 && self.multiplier.is_finite()) {
+            return Err("poll interval multiplier must be a finite value >= 1.0".into());
+        }
+        if !(0.0..=1.0).contains(&self.jitter) {
+            return Err("poll interval jitter must be between 0.0 and 1.0".into());
+        }
+        Ok(())
+    }
+
+    /// Delay after poll number `poll`, starting at 1.
+    pub(crate) fn delay(&self, poll: u32) -> Duration {
+        backoff_delay(self.initial, self.max, self.multiplier, self.jitter, poll)
+    }
+}
+
+/// How the `wait_for_*` helpers poll.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaitOptions {
+    /// Delays between polls.
+    pub poll_interval: PollInterval,
+    /// Overall deadline, measured from the start of the wait.
+    pub timeout: Duration,
+}
+
+impl Default for WaitOptions {
+    /// Back off from one second to 30 seconds, for up to five minutes.
+    fn default() -> Self {
+        Self {
+            poll_interval: PollInterval::default(),
+            timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+impl ActeonClient {
+    /// Call `op` until its result satisfies `is_terminal`, backing off
+    /// between calls.
+    ///
+    /// Returns the first result for which `is_terminal` is `true`. An error
+    /// from `op` ends polling immediately. Once `deadline` passes, an
+    /// in-flight call is abandoned and the poll fails with
+    /// [`Error::Timeout`]; the wait before the last poll is shortened so it
+    /// never sleeps past the deadline. An invalid `interval` fails with
+    /// [`Error::Configuration`] before `op` is called.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use std::time::{Duration, Instant};
+    /// use acteon_client::{ActeonClient, PollInterval};
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let deadline = Instant::now() + Duration::from_secs(30);
+    /// client
+    ///     .poll_until(
+    ///         || client.health(),
+    ///         |healthy| *healthy,
+    ///         &PollInterval::default(),
+    ///         deadline,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn poll_until<T, F, Fut, P>(
+        &self,
+        mut op: F,
+        mut is_terminal: P,
+        interval: &PollInterval,
+        deadline: Instant,
+    ) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+        P: FnMut(&T) -> bool,
+    {
+        interval.validate().map_err(Error::Configuration)?;
+        let deadline = tokio::time::Instant::from_std(deadline);
+        let timed_out = || Error::Timeout("deadline passed before polling finished".into());
+        let mut poll = 0;
+        loop {
+            let value = tokio::time::timeout_at(deadline, op())
+                .await
+                .map_err(|_| timed_out())??;
+            if is_terminal(&value) {
+                return Ok(value);
+            }
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(timed_out());
+            }
+            poll += 1;
+            tokio::time::sleep(interval.delay(poll).min(deadline - now)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    fn client() -> ActeonClient {
+        ActeonClient::new("http://127.0.0.1:1")
+    }
+
+    #[tokio::test]
+    async fn backs_off_between_polls() {
+        let client = client();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let interval = PollInterval {
+            initial: Duration::from_millis(10),
+            max: Duration::from_millis(40),
+            multiplier: 2.0,
+            jitter: 0.0,
+        };
+        let polls = client
+            .poll_until(
+                || {
+                    let calls = Arc::clone(&calls);
+                    async move {
+                        let mut calls = calls.lock().unwrap();
+                        calls.push(Instant::now());
+                        Ok(calls.len())
+                    }
+                },
+                |polls| *polls == 5,
+                &interval,
+                Instant::now() + Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        assert_eq!(polls, 5);
+
+        let calls = calls.lock().unwrap();
+        let gaps: Vec<_> = calls.windows(2).map(|w| w[1] - w[0]).collect();
+        for (gap, expected) in gaps.iter().zip([10, 20, 40, 40]) {
+            assert!(*gap >= Duration::from_millis(expected), "{gaps:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn gives_up_at_the_deadline() {
+        let client = client();
+        let start = Instant::now();
+        let err = client
+            .poll_until(
+                || async { Ok(()) },
+                |()| false,
+                &PollInterval::fixed(Duration::from_millis(5)),
+                start + Duration::from_millis(60),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Timeout(_)), "{err:?}");
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(60), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(2), "{elapsed:?}");
+
+        // A call still running at the deadline is abandoned, and a long
+        // backoff is cut short rather than overshooting.
+        let start = Instant::now();
+        let err = client
+            .poll_until(
+                std::future::pending::<Result<(), Error>>,
+                |()| true,
+                &PollInterval::default(),
+                start + Duration::from_millis(50),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Timeout(_)), "{err:?}");
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn errors_and_bad_intervals_end_polling() {
+        let client = client();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut calls = 0;
+        let err = client
+            .poll_until(
+                || {
+                    calls += 1;
+                    async { Err::<(), _>(Error::Connection("refused".into())) }
+                },
+                |()| false,
+                &PollInterval::fixed(Duration::from_millis(1)),
+                deadline,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Connection(_)), "{err:?}");
+        assert_eq!(calls, 1);
+
+        let bad = PollInterval {
+            multiplier: 0.5,
+            ..PollInterval::default()
+        };
+        let err = client
+            .poll_until(|| async { Ok(()) }, |()| true, &bad, deadline)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Configuration(_)), "{err:?}");
+    }
+
+    #[test]
+    fn delay_grows_caps_and_jitters() {
+        let interval = PollInterval {
+            initial: Duration::from_millis(100),
+            max: Duration::from_millis(500),
+            multiplier: 3.0,
+            jitter: 0.0,
+        };
+        assert_eq!(interval.delay(1), Duration::from_millis(100));
+        assert_eq!(interval.delay(2), Duration::from_millis(300));
+        assert_eq!(interval.delay(3), Duration::from_millis(500));
+        assert_eq!(interval.delay(u32::MAX), Duration::from_millis(500));
+
+        let jittered = PollInterval {
+            jitter: 0.5,
+            ..interval
+        };
+        for _ in 0..100 {
+            let delay = jittered.delay(2);
+            assert!(delay > Duration::from_millis(150) && delay <= Duration::from_millis(300));
+        }
+        assert_eq!(
+            PollInterval::fixed(Duration::from_secs(2)).delay(10),
+            Duration::from_secs(2)
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Correlation headers added to every request.
+//!
+//! [`ActeonClientBuilder::propagate_headers`] registers a function whose
+//! headers are added to each request as it is sent, so request IDs or trace
+//! context follow calls into Acteon. With the `otel` feature the current
+//! OpenTelemetry context is also injected as W3C `traceparent` and
+//! `tracestate` headers through the global text-map propagator, which adds
+//! nothing when no propagator is registered. Headers already set on a
+//! request are never overwritten.
+//!
+//! [`ActeonClientBuilder::propagate_headers`]: crate::ActeonClientBuilder::propagate_headers
+
+use std::sync::Arc;
+
+use reqwest::header::HeaderMap;
+
+/// Produces the headers added to every request.
+pub type PropagateHeadersFn = dyn Fn() -> HeaderMap + Send + Sync;
+
+/// The builder's header propagation function, if any.
+#[derive(Clone, Default)]
+pub(crate) struct HeaderPropagator(pub(crate) Option<Arc<PropagateHeadersFn>>);
+
+impl HeaderPropagator {
+    /// Add the propagated headers to `headers`, keeping any already set.
+    pub(crate) fn apply(&self, headers: &mut HeaderMap) {
+        if let Some(propagate) = &self.0 {
+            insert_missing(headers, propagate());
+        }
+        #[cfg(feature = "otel")]
+        insert_missing(headers, otel::trace_context_headers());
+    }
+}
+
+impl std::fmt::Debug for HeaderPropagator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.0.is_some() {
+            "PropagateHeadersFn"
+        } else {
+            "None"
+        })
+    }
+}
+
+/// Copy `extra` into `headers`, skipping names `headers` already has.
+fn insert_missing(headers: &mut HeaderMap, extra: HeaderMap) {
+    let mut name = None;
+    for (key, value) in extra {
+        // Repeated values of one header come with `None` after the first.
+        if key.is_some() {
+            name = key.filter(|key| !headers.contains_key(key));
+        }
+        if let Some(name) = &name {
+            headers.append(name, value);
+        }
+    }
+}
+
+#[cfg(feature = "otel")]
+mod otel {
+    use opentelemetry::propagation::Injector;
+    use opentelemetry::{Context, global};
+    use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+    /// A [`HeaderMap`]-backed injector for OpenTelemetry propagators.
+    struct HeaderInjector<'a>(&'a mut HeaderMap);
+
+    impl Injector for HeaderInjector<'_> {
+        fn set(&mut self, key: &str, value: String) {
+            if let Ok(name) = HeaderName::from_bytes(key.as_bytes())
+                && let Ok(value) = HeaderValue::from_str(&value)
+            {
+                self.0.insert(name, value);
+            }
+        }
+    }
+
+    /// Headers carrying the current OpenTelemetry context.
+    pub(super) fn trace_context_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let cx = Context::current();
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&cx, &mut HeaderInjector(&mut headers));
+        });
+        headers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use reqwest::header::HeaderValue;
+    use tokio::sync::mpsc;
+
+    use crate::mock_server::{self, Response};
+    use crate::{ActeonClientBuilder, StreamFilter};
+
+    /// Answer every request with an empty JSON object, forwarding its
+    /// headers.
+    async fn start_server() -> (String, mpsc::UnboundedReceiver<HashMap<String, String>>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let base_url = mock_server::serve(move |request| {
+            let _ = tx.send(request.headers);
+            Response::json(200, &serde_json::json!({}))
+        })
+        .await;
+        (base_url, rx)
+    }
+
+    #[tokio::test]
+    async fn propagated_headers_reach_every_endpoint() {
+        let (base_url, mut requests) = start_server().await;
+        let client = ActeonClientBuilder::new(&base_url)
+            .api_key("secret")
+            .propagate_headers(|| {
+                let mut headers = reqwest::header::HeaderMap::new();
+                headers.insert("x-request-id", HeaderValue::from_static("req-42"));
+                headers.insert("authorization", HeaderValue::from_static("Bearer other"));
+                headers
+            })
+            .build()
+            .unwrap();
+
+        let _ = client.health().await;
+        let _ = client.list_chains("ns", "t1", None).await;
+        let _ = client.stream(&StreamFilter::default()).await;
+
+        for _ in 0..3 {
+            let headers = requests.recv().await.unwrap();
+            assert_eq!(headers["x-request-id"], "req-42");
+            assert_eq!(headers["authorization"], "Bearer secret");
+        }
+    }
+
+    #[cfg(feature = "otel")]
+    #[tokio::test]
+    async fn otel_context_becomes_traceparent() {
+        use opentelemetry::trace::{
+            SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState,
+        };
+        use opentelemetry::{Context, global};
+
+        global::set_text_map_propagator(
+            opentelemetry_sdk::propagation::TraceContextPropagator::new(),
+        );
+        let (base_url, mut requests) = start_server().await;
+        let client = ActeonClientBuilder::new(&base_url).build().unwrap();
+        let span = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        );
+
+        let _guard = Context::current().with_remote_span_context(span).attach();
+        let _ = client.health().await;
+
+        let headers = requests.recv().await.unwrap();
+        assert_eq!(
+            headers["traceparent"],
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 impl ActeonClient {
@@ -9,9 +10,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
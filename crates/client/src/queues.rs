@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// A worker task delivered through a named queue.
@@ -176,9 +177,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -209,9 +209,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -237,9 +236,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(url))
             .json(body)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::dispatch::ErrorResponse;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Request to create a quota policy.
@@ -8,8 +9,17 @@ use crate::{ActeonClient, Error};
 pub struct CreateQuotaRequest {
     /// Namespace.
     pub namespace: String,
-    /// Tenant.
+    /// Tenant. Leave empty for org-level policies.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub tenant: String,
+    /// Optional organization. When set, the policy is a single
+    /// org-wide cap shared by every tenant in `members`, on top of
+    /// per-tenant caps. Requires a grant on all tenants and namespaces.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org: Option<String>,
+    /// Member tenants of an org-level policy.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<String>,
     /// Optional provider scope. When omitted, the policy is
     /// generic and counts every dispatch for the tenant. When set,
     /// only dispatches to the named provider count against this
@@ -39,6 +49,10 @@ pub struct CreateQuotaRequest {
     /// Arbitrary key-value labels.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Usage percentages (1–100) that emit a `quota_threshold_crossed`
+    /// stream event once per window.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alert_thresholds: Vec<u8>,
 }
 
 /// Request to update a quota policy.
@@ -69,6 +83,13 @@ pub struct UpdateQuotaRequest {
     /// Replacement label set. `None` leaves existing labels unchanged.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Replacement alert thresholds. `None` leaves them unchanged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert_thresholds: Option<Vec<u8>>,
+    /// Replacement member tenants of an org-level policy. `None`
+    /// leaves them unchanged.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<String>>,
 }
 
 /// A quota policy.
@@ -80,6 +101,12 @@ pub struct QuotaPolicy {
     pub namespace: String,
     /// Tenant.
     pub tenant: String,
+    /// Optional organization whose shared counter this quota uses.
+    #[serde(default)]
+    pub org: Option<String>,
+    /// Member tenants of an org-level policy.
+    #[serde(default)]
+    pub members: Vec<String>,
     /// Optional provider scope (`None` = generic catch-all).
     #[serde(default)]
     pub provider: Option<String>,
@@ -106,6 +133,9 @@ pub struct QuotaPolicy {
     /// Arbitrary labels.
     #[serde(default)]
     pub labels: Option<std::collections::HashMap<String, String>>,
+    /// Alert thresholds, as percentages of `max_actions`.
+    #[serde(default)]
+    pub alert_thresholds: Vec<u8>,
 }
 
 /// Response from listing quota policies.
@@ -146,9 +176,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -202,9 +231,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -228,9 +256,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -259,9 +286,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(update)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -294,9 +320,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.delete(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status() == reqwest::StatusCode::NO_CONTENT {
             Ok(())
@@ -319,9 +344,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
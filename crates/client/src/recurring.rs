@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::dispatch::ErrorResponse;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Request to create a recurring action.
@@ -225,9 +226,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -275,13 +275,14 @@ impl ActeonClient {
         filter: &RecurringFilter,
     ) -> Result<ListRecurringResponse, Error> {
         let url = format!("{}/v1/recurring", self.base_url);
+        let mut filter = filter.clone();
+        self.scope.fill(&mut filter.namespace, &mut filter.tenant);
 
         let response = self
             .add_auth(self.client.get(&url))
-            .query(filter)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .query(&filter)
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -311,9 +312,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -342,9 +342,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(update)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -382,9 +381,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.delete(&url))
             .query(&[("namespace", namespace), ("tenant", tenant)])
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status() == reqwest::StatusCode::NO_CONTENT {
             Ok(())
@@ -416,9 +414,8 @@ impl ActeonClient {
                 "namespace": namespace,
                 "tenant": tenant,
             }))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -459,9 +456,8 @@ impl ActeonClient {
                 "namespace": namespace,
                 "tenant": tenant,
             }))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -538,9 +534,8 @@ impl ActeonClient {
                 "end": end,
                 "limit": limit,
             }))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
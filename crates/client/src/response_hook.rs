@@ -0,0 +1,146 @@
+//! Per-request logging hook.
+//!
+//! [`ActeonClientBuilder::on_response`] registers a function called after
+//! every request attempt with its method, URL, status, and elapsed time, so
+//! calls can be routed into the application's own tracing without enabling
+//! reqwest's wire logging. It runs for successful responses, error
+//! statuses, and requests that got no response at all. Bodies are never
+//! passed to it.
+//!
+//! [`ActeonClientBuilder::on_response`]: crate::ActeonClientBuilder::on_response
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Method, Url};
+
+/// The request half of a call, as passed to an [`OnResponseFn`].
+#[derive(Debug, Clone)]
+pub struct RequestInfo<'a> {
+    /// HTTP method of the request.
+    pub method: &'a Method,
+    /// Full URL of the request, including the query string.
+    pub url: &'a Url,
+    /// URL path of the request (e.g. `/v1/dispatch`).
+    pub path: &'a str,
+}
+
+/// The outcome of a call, as passed to an [`OnResponseFn`].
+#[derive(Debug, Clone)]
+pub struct ResponseInfo {
+    /// HTTP status of the response, or `None` when no response arrived
+    /// (connection failure, timeout, or a refused request during shutdown).
+    pub status: Option<u16>,
+    /// Time from sending the request until the response headers arrived or
+    /// the request failed.
+    pub elapsed: Duration,
+}
+
+/// Called after every request attempt.
+pub type OnResponseFn = dyn Fn(&RequestInfo<'_>, &ResponseInfo) + Send + Sync;
+
+/// The builder's response hook, if any.
+#[derive(Clone, Default)]
+pub(crate) struct ResponseHook(pub(crate) Option<Arc<OnResponseFn>>);
+
+impl ResponseHook {
+    /// Report one finished attempt to the hook.
+    pub(crate) fn call(&self, request: &RequestInfo<'_>, response: &ResponseInfo) {
+        if let Some(hook) = &self.0 {
+            hook(request, response);
+        }
+    }
+}
+
+impl std::fmt::Debug for ResponseHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.0.is_some() {
+            "OnResponseFn"
+        } else {
+            "None"
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::mock_server::{self, Response};
+    use crate::{ActeonClientBuilder, AuditQuery};
+
+    /// Answer `/health` with 200 and everything else with 500.
+    async fn start_server() -> String {
+        mock_server::serve(|request| {
+            let status = if request.route() == "GET /health" {
+                200
+            } else {
+                500
+            };
+            Response::json(status, &serde_json::json!({}))
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn hook_sees_successes_and_failures() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&calls);
+        let client = ActeonClientBuilder::new(start_server().await)
+            .on_response(Arc::new(
+                move |request: &RequestInfo<'_>, response: &ResponseInfo| {
+                    seen.lock().unwrap().push((
+                        request.method.to_string(),
+                        request.url.path().to_owned(),
+                        request.url.query().map(str::to_owned),
+                        response.status,
+                    ));
+                },
+            ))
+            .build()
+            .unwrap();
+
+        let _ = client.health().await;
+        let _ = client
+            .query_audit(&AuditQuery {
+                tenant: Some("t1".into()),
+                ..AuditQuery::default()
+            })
+            .await;
+        let unreachable = ActeonClientBuilder::new("http://127.0.0.1:1")
+            .on_response(Arc::new({
+                let calls = Arc::clone(&calls);
+                move |request: &RequestInfo<'_>, response: &ResponseInfo| {
+                    calls.lock().unwrap().push((
+                        request.method.to_string(),
+                        request.path.to_owned(),
+                        None,
+                        response.status,
+                    ));
+                }
+            }))
+            .build()
+            .unwrap();
+        let _ = unreachable.health().await;
+
+        let calls = calls.lock().unwrap();
+        assert_eq!(
+            calls[0],
+            ("GET".to_owned(), "/health".to_owned(), None, Some(200))
+        );
+        assert_eq!(
+            calls[1],
+            (
+                "GET".to_owned(),
+                "/v1/audit".to_owned(),
+                Some("tenant=t1".to_owned()),
+                Some(500)
+            )
+        );
+        assert_eq!(
+            calls.last().unwrap(),
+            &("GET".to_owned(), "/health".to_owned(), None, None)
+        );
+    }
+}
@@ -1,5 +1,9 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Request to create a data retention policy.
@@ -26,7 +30,111 @@ pub struct CreateRetentionRequest {
     pub description: Option<String>,
     /// Arbitrary key-value labels.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<std::collections::HashMap<String, String>>,
+    pub labels: Option<HashMap<String, String>>,
+}
+
+impl CreateRetentionRequest {
+    /// Start building a policy for `namespace` and `tenant` with TTLs given
+    /// as [`Duration`]s rather than raw second counts.
+    pub fn builder(
+        namespace: impl Into<String>,
+        tenant: impl Into<String>,
+    ) -> CreateRetentionRequestBuilder {
+        CreateRetentionRequestBuilder {
+            namespace: namespace.into(),
+            tenant: tenant.into(),
+            audit_ttl: None,
+            state_ttl: None,
+            event_ttl: None,
+            compliance_hold: false,
+            description: None,
+            labels: HashMap::new(),
+        }
+    }
+}
+
+/// Builder for a [`CreateRetentionRequest`].
+#[derive(Debug, Clone)]
+pub struct CreateRetentionRequestBuilder {
+    namespace: String,
+    tenant: String,
+    audit_ttl: Option<Duration>,
+    state_ttl: Option<Duration>,
+    event_ttl: Option<Duration>,
+    compliance_hold: bool,
+    description: Option<String>,
+    labels: HashMap<String, String>,
+}
+
+impl CreateRetentionRequestBuilder {
+    /// Override the global audit TTL.
+    #[must_use]
+    pub fn audit_ttl(mut self, ttl: Duration) -> Self {
+        self.audit_ttl = Some(ttl);
+        self
+    }
+
+    /// Set the TTL for completed chain state records.
+    #[must_use]
+    pub fn state_ttl(mut self, ttl: Duration) -> Self {
+        self.state_ttl = Some(ttl);
+        self
+    }
+
+    /// Set the TTL for resolved event state records.
+    #[must_use]
+    pub fn event_ttl(mut self, ttl: Duration) -> Self {
+        self.event_ttl = Some(ttl);
+        self
+    }
+
+    /// Keep audit records forever (compliance hold).
+    #[must_use]
+    pub fn compliance_hold(mut self, hold: bool) -> Self {
+        self.compliance_hold = hold;
+        self
+    }
+
+    /// Set a description.
+    #[must_use]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a label.
+    #[must_use]
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    /// Build the request, converting TTLs to whole seconds.
+    ///
+    /// Returns [`Error::Configuration`] if any TTL is shorter than one second
+    /// and `compliance_hold` is not set.
+    pub fn build(self) -> Result<CreateRetentionRequest, Error> {
+        let compliance_hold = self.compliance_hold;
+        let seconds = |field: &str, ttl: Option<Duration>| -> Result<Option<u64>, Error> {
+            match ttl.map(|ttl| ttl.as_secs()) {
+                Some(0) if !compliance_hold => Err(Error::Configuration(format!(
+                    "{field} must be at least one second unless compliance_hold is set"
+                ))),
+                secs => Ok(secs),
+            }
+        };
+
+        Ok(CreateRetentionRequest {
+            audit_ttl_seconds: seconds("audit_ttl", self.audit_ttl)?,
+            state_ttl_seconds: seconds("state_ttl", self.state_ttl)?,
+            event_ttl_seconds: seconds("event_ttl", self.event_ttl)?,
+            namespace: self.namespace,
+            tenant: self.tenant,
+            compliance_hold,
+            description: self.description,
+            labels: (!self.labels.is_empty()).then_some(self.labels),
+        })
+    }
 }
 
 /// Request to update a data retention policy.
@@ -52,7 +160,7 @@ pub struct UpdateRetentionRequest {
     pub description: Option<String>,
     /// Updated labels.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub labels: Option<std::collections::HashMap<String, String>>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 /// A data retention policy.
@@ -87,7 +195,7 @@ pub struct RetentionPolicy {
     pub description: Option<String>,
     /// Arbitrary labels.
     #[serde(default)]
-    pub labels: Option<std::collections::HashMap<String, String>>,
+    pub labels: Option<HashMap<String, String>>,
 }
 
 /// Response from listing retention policies.
@@ -99,6 +207,32 @@ pub struct ListRetentionResponse {
     pub count: usize,
 }
 
+/// A legal hold placed on a single audit record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditHold {
+    /// Action ID of the held audit record.
+    pub action_id: String,
+    /// Namespace of the held record.
+    pub namespace: String,
+    /// Tenant of the held record.
+    pub tenant: String,
+    /// Why the record is held (e.g. a case or ticket reference).
+    pub reason: String,
+    /// Identity of the caller that placed the hold.
+    pub held_by: String,
+    /// When the hold was placed.
+    pub held_at: String,
+}
+
+/// Response from listing audit holds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListAuditHoldsResponse {
+    /// Held audit records.
+    pub holds: Vec<AuditHold>,
+    /// Total count of results.
+    pub count: usize,
+}
+
 impl ActeonClient {
     /// Create a new retention policy.
     pub async fn create_retention(
@@ -110,9 +244,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -147,9 +280,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -171,9 +303,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -202,9 +333,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(update)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -231,9 +361,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status() == reqwest::StatusCode::NO_CONTENT {
             Ok(())
@@ -249,4 +378,162 @@ impl ActeonClient {
             })
         }
     }
+
+    /// Place a legal hold on a single audit record, exempting it from
+    /// retention cleanup.
+    pub async fn hold_audit_record(
+        &self,
+        action_id: &str,
+        reason: &str,
+    ) -> Result<AuditHold, Error> {
+        let url = format!("{}/v1/audit/{}/hold", self.base_url, action_id);
+
+        let response = self
+            .add_auth(self.client.post(&url))
+            .json(&serde_json::json!({ "reason": reason }))
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            let result = response
+                .json::<AuditHold>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            Ok(result)
+        } else if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Err(Error::Http {
+                status: 404,
+                message: format!("Audit record not found: {action_id}"),
+            })
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: "Failed to place audit hold".to_string(),
+            })
+        }
+    }
+
+    /// List audit records under a legal hold for a namespace and tenant.
+    pub async fn list_held_records(
+        &self,
+        namespace: &str,
+        tenant: &str,
+    ) -> Result<Vec<AuditHold>, Error> {
+        let url = format!("{}/v1/audit/holds", self.base_url);
+
+        let response = self
+            .add_auth(self.client.get(&url))
+            .query(&[("namespace", namespace), ("tenant", tenant)])
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            let result = response
+                .json::<ListAuditHoldsResponse>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            Ok(result.holds)
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: "Failed to list audit holds".to_string(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    /// Serve a single-record hold store: `POST .../hold` records the hold
+    /// and `GET /v1/audit/holds` lists whatever has been held so far.
+    async fn start_hold_server() -> String {
+        let holds = std::sync::Mutex::new(Vec::<serde_json::Value>::new());
+        mock_server::serve(move |request| {
+            let mut holds = holds.lock().unwrap();
+            let body = if request.method == "POST" {
+                let action_id = request
+                    .path()
+                    .trim_start_matches("/v1/audit/")
+                    .trim_end_matches("/hold");
+                let hold = serde_json::json!({
+                    "action_id": action_id,
+                    "namespace": "ns",
+                    "tenant": "t1",
+                    "reason": request.json()["reason"],
+                    "held_by": "admin",
+                    "held_at": "2026-01-01T00:00:00Z",
+                });
+                holds.push(hold.clone());
+                hold
+            } else {
+                serde_json::json!({"holds": *holds, "count": holds.len()})
+            };
+            Response::json(200, &body)
+        })
+        .await
+    }
+
+    #[test]
+    fn builder_converts_durations_to_seconds() {
+        let req = CreateRetentionRequest::builder("ns", "t1")
+            .audit_ttl(Duration::from_secs(30 * 24 * 60 * 60))
+            .state_ttl(Duration::from_secs(7 * 24 * 60 * 60))
+            .event_ttl(Duration::from_millis(90_500))
+            .label("team", "billing")
+            .build()
+            .unwrap();
+
+        assert_eq!(req.namespace, "ns");
+        assert_eq!(req.tenant, "t1");
+        assert_eq!(req.audit_ttl_seconds, Some(2_592_000));
+        assert_eq!(req.state_ttl_seconds, Some(604_800));
+        assert_eq!(req.event_ttl_seconds, Some(90));
+        assert!(!req.compliance_hold);
+        assert_eq!(req.labels.unwrap()["team"], "billing");
+    }
+
+    #[test]
+    fn builder_rejects_zero_ttl_without_hold() {
+        let err = CreateRetentionRequest::builder("ns", "t1")
+            .audit_ttl(Duration::ZERO)
+            .build()
+            .unwrap_err();
+        assert!(
+            matches!(&err, Error::Configuration(msg) if msg.contains("audit_ttl")),
+            "got {err:?}"
+        );
+
+        let err = CreateRetentionRequest::builder("ns", "t1")
+            .state_ttl(Duration::from_millis(500))
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, Error::Configuration(_)), "got {err:?}");
+
+        let req = CreateRetentionRequest::builder("ns", "t1")
+            .audit_ttl(Duration::ZERO)
+            .compliance_hold(true)
+            .build()
+            .unwrap();
+        assert_eq!(req.audit_ttl_seconds, Some(0));
+        assert!(req.compliance_hold);
+    }
+
+    #[tokio::test]
+    async fn held_record_is_listed() {
+        let client = ActeonClient::new(start_hold_server().await);
+
+        let hold = client
+            .hold_audit_record("act-1", "case 2026-114")
+            .await
+            .unwrap();
+        assert_eq!(hold.action_id, "act-1");
+
+        let holds = client.list_held_records("ns", "t1").await.unwrap();
+        assert_eq!(holds.len(), 1);
+        assert_eq!(holds[0].action_id, "act-1");
+        assert_eq!(holds[0].reason, "case 2026-114");
+    }
 }
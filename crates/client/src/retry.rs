@@ -0,0 +1,493 @@
+//! Automatic retries with exponential backoff.
+//!
+//! A [`RetryPolicy`] set with [`ActeonClientBuilder::retry_policy`] re-sends
+//! dispatches (single and batch) and `GET` requests that failed for a
+//! transient reason: a connection failure, an HTTP 429 or 503 response, or an
+//! API error the server marked `retryable`. Other 4xx responses are never
+//! retried. A 429 surfaces as [`Error::RateLimited`], and its `Retry-After`
+//! delay, capped at the policy's `max_delay`, replaces a shorter backoff. Each retry is reported to
+//! [`MetricsRecorder::record_retry`](crate::MetricsRecorder::record_retry).
+//!
+//! The default policy makes a single attempt, so nothing is retried unless a
+//! policy is configured. Which failures count as transient can be replaced
+//! with [`ActeonClientBuilder::retry_classifier`].
+//!
+//! [`ActeonClientBuilder::retry_policy`]: crate::ActeonClientBuilder::retry_policy
+//! [`ActeonClientBuilder::retry_classifier`]: crate::ActeonClientBuilder::retry_classifier
+
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::Error;
+
+/// How the client retries transient failures.
+///
+/// The delay before retry `n` is `base_delay * multiplier^(n - 1)`, capped
+/// at `max_delay`, then shortened by a random fraction of up to `jitter`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use acteon_client::RetryPolicy;
+///
+/// // Up to 4 attempts, waiting about 200ms, 400ms, then 800ms between them.
+/// let policy = RetryPolicy {
+///     max_attempts: 4,
+///     base_delay: Duration::from_millis(200),
+///     jitter: 0.2,
+///     ..RetryPolicy::default()
+/// };
+/// assert!(policy.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts per request, including the first. `1` disables
+    /// retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound on the delay between attempts.
+    pub max_delay: Duration,
+    /// Factor the delay grows by after each retry. Must be at least `1.0`.
+    pub multiplier: f64,
+    /// Fraction of each delay, between `0.0` and `1.0`, that is randomly
+    /// cut off so that many clients do not retry in lockstep.
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Validate configuration values.
+    ///
+    /// Returns `Err` with a description if `max_attempts` is zero,
+    /// `multiplier` is below `1.0`, or `jitter` is outside `0.0..=1.0`.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_attempts == 0 {
+            return Err("retry policy max_attempts must be >= 1".into());
+        }
+        if !(self.multiplier >= 1.0 && self.multiplier.is_finite()) {
+            return Err("retry policy multiplier must be a finite value >= 1.0".into());
+        }
+        if !(0.0..=1.0).contains(&self.jitter) {
+            return Err("retry policy jitter must be between 0.0 and 1.0".into());
+        }
+        Ok(())
+    }
+
+    /// Delay before retry number `retry`, starting at 1.
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        backoff_delay(
+            self.base_delay,
+            self.max_delay,
+            self.multiplier,
+            self.jitter,
+            retry,
+        )
+    }
+}
+
+/// Exponential backoff shared by retries and polling: the `n`-th delay,
+/// starting at 1, is `base * multiplier^(n - 1)` capped at `max`, minus a
+/// random fraction of up to `jitter`.
+pub(crate) fn backoff_delay(
+    base: Duration,
+    max: Duration,
+    multiplier: f64,
+    jitter: f64,
+    n: u32,
+) -> Duration {
+    let exponent = i32::try_from(n.saturating_sub(1)).unwrap_or(i32::MAX);
+    let backoff = base.as_secs_f64() * multiplier.powi(exponent);
+    let capped = backoff.min(max.as_secs_f64());
+    Duration::from_secs_f64(capped * (1.0 - jitter * random_unit()))
+}
+
+/// Decides whether a failed request is retried, in place of the default
+/// classification.
+pub type RetryClassifierFn = dyn Fn(&Error) -> bool + Send + Sync;
+
+/// The builder's retry classifier, if any.
+#[derive(Clone, Default)]
+pub(crate) struct RetryClassifier(pub(crate) Option<Arc<RetryClassifierFn>>);
+
+impl RetryClassifier {
+    /// Whether `error` should be retried: the custom classifier's answer, or
+    /// [`is_transient`] without one.
+    pub(crate) fn should_retry(&self, error: &Error) -> bool {
+        match &self.0 {
+            Some(classify) => classify(error),
+            None => is_transient(error),
+        }
+    }
+}
+
+impl std::fmt::Debug for RetryClassifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(if self.0.is_some() {
+            "RetryClassifierFn"
+        } else {
+            "None"
+        })
+    }
+}
+
+/// Whether a failed request is worth retrying.
+pub(crate) fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::Connection(_) | Error::RateLimited { .. } => true,
+        Error::Http { status, .. } => is_transient_status(*status),
+        Error::Api { retryable, .. } => *retryable,
+        _ => false,
+    }
+}
+
+/// Whether a response status signals a transient failure.
+pub(crate) fn is_transient_status(status: u16) -> bool {
+    matches!(status, 429 | 503)
+}
+
+/// Parse a `Retry-After` header value: either delay seconds or an HTTP-date.
+///
+/// A date in the past means "now" and yields a zero delay.
+pub(crate) fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// The [`Error::RateLimited`] for a 429 response.
+pub(crate) fn rate_limited(response: &reqwest::Response) -> Error {
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| parse_retry_after(value, Utc::now()));
+    Error::RateLimited { retry_after }
+}
+
+/// A random number in `0.0..1.0`, good enough to spread retries out.
+fn random_unit() -> f64 {
+    // Every `RandomState` is keyed differently, so hashing nothing yields a
+    // fresh random value without pulling in an RNG crate.
+    let bits = std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish();
+    f64::from(u32::try_from(bits >> 32).unwrap_or(0)) / (f64::from(u32::MAX) + 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use acteon_core::Action;
+
+    use super::*;
+    use crate::mock_server::{self, Response};
+    use crate::{ActeonClientBuilder, MetricsRecorder, RequestMetrics, RetryEvent};
+
+    /// Answer the n-th request with `responses[n]`, repeating the last one,
+    /// and count the requests received.
+    async fn start_scripted_server(responses: Vec<Response>) -> (String, Arc<Mutex<usize>>) {
+        let count = Arc::new(Mutex::new(0));
+        let served = Arc::clone(&count);
+        let base_url = mock_server::serve(move |_| {
+            let mut served = served.lock().unwrap();
+            *served += 1;
+            responses[(*served - 1).min(responses.len() - 1)].clone()
+        })
+        .await;
+        (base_url, count)
+    }
+
+    /// A JSON response with `status` and the raw `body`.
+    fn reply(status: u16, body: &str) -> Response {
+        Response::new(status).body("application/json", body)
+    }
+
+    /// Keeps `(endpoint, attempt, reason)` of every reported retry.
+    #[derive(Default)]
+    struct RetryRecorder {
+        retries: Mutex<Vec<(String, u32, String)>>,
+    }
+
+    impl MetricsRecorder for RetryRecorder {
+        fn record_request(&self, _request: &RequestMetrics<'_>) {}
+
+        fn record_retry(&self, retry: &RetryEvent<'_>) {
+            self.retries.lock().unwrap().push((
+                retry.endpoint.to_owned(),
+                retry.attempt,
+                retry.reason.to_owned(),
+            ));
+        }
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        }
+    }
+
+    fn action() -> Action {
+        Action::new("ns", "t1", "email", "send", serde_json::json!({}))
+    }
+
+    #[tokio::test]
+    async fn dispatch_retries_transient_failures() {
+        let responses = vec![
+            reply(503, "{}"),
+            reply(
+                500,
+                r#"{"code":"BUSY","message":"try again","retryable":true}"#,
+            ),
+            reply(200, "\"Deduplicated\""),
+        ];
+        let (base_url, count) = start_scripted_server(responses).await;
+        let recorder = Arc::new(RetryRecorder::default());
+        let client = ActeonClientBuilder::new(base_url)
+            .retry_policy(fast_policy(3))
+            .metrics_recorder(recorder.clone())
+            .build()
+            .unwrap();
+
+        client.dispatch(&action()).await.unwrap();
+
+        assert_eq!(*count.lock().unwrap(), 3);
+        let retries = recorder.retries.lock().unwrap();
+        assert_eq!(retries.len(), 2);
+        assert_eq!(retries[0].0, "/v1/dispatch");
+        assert_eq!((retries[0].1, retries[1].1), (1, 2));
+        assert!(retries[0].2.contains("503"), "{}", retries[0].2);
+        assert!(retries[1].2.contains("BUSY"), "{}", retries[1].2);
+    }
+
+    #[tokio::test]
+    async fn client_errors_and_default_policy_never_retry() {
+        let bad_request = vec![
+            reply(
+                400,
+                r#"{"code":"INVALID","message":"bad","retryable":false}"#,
+            ),
+            reply(200, "\"Deduplicated\""),
+        ];
+        let unavailable = vec![reply(503, "{}"), reply(200, "\"Deduplicated\"")];
+        let (base_url, count) = start_scripted_server(bad_request).await;
+        let client = ActeonClientBuilder::new(base_url)
+            .retry_policy(fast_policy(3))
+            .build()
+            .unwrap();
+        let err = client.dispatch(&action()).await.unwrap_err();
+        assert!(matches!(err, Error::Api { .. }), "got {err:?}");
+        assert_eq!(*count.lock().unwrap(), 1);
+
+        let (base_url, count) = start_scripted_server(unavailable).await;
+        let client = ActeonClientBuilder::new(base_url).build().unwrap();
+        let err = client.dispatch(&action()).await.unwrap_err();
+        assert!(
+            matches!(err, Error::Http { status: 503, .. }),
+            "got {err:?}"
+        );
+        assert_eq!(*count.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn reads_retry_until_attempts_run_out() {
+        let responses = vec![reply(503, "{}")];
+        let (base_url, count) = start_scripted_server(responses).await;
+        let client = ActeonClientBuilder::new(base_url)
+            .retry_policy(fast_policy(3))
+            .build()
+            .unwrap();
+
+        // The last response is handed back to the caller unchanged.
+        assert!(!client.health().await.unwrap());
+        assert_eq!(*count.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn rate_limits_surface_retry_after() {
+        let rate_limited = vec![reply(429, "{}").header("Retry-After", "7")];
+        let (base_url, count) = start_scripted_server(rate_limited).await;
+        let client = ActeonClientBuilder::new(base_url).build().unwrap();
+
+        let err = client.get_chain("c1", "ns", "t1").await.unwrap_err();
+        let expected = Some(Duration::from_secs(7));
+        assert!(
+            matches!(err, Error::RateLimited { retry_after } if retry_after == expected),
+            "got {err:?}"
+        );
+        let err = client.dispatch(&action()).await.unwrap_err();
+        assert!(
+            matches!(err, Error::RateLimited { retry_after } if retry_after == expected),
+            "got {err:?}"
+        );
+        assert!(err.is_retryable());
+        assert_eq!(*count.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn retries_wait_out_retry_after_up_to_max_delay() {
+        let responses = vec![
+            reply(429, "{}").header("Retry-After", "3600"),
+            reply(200, "\"Deduplicated\""),
+        ];
+        let (base_url, count) = start_scripted_server(responses).await;
+        let client = ActeonClientBuilder::new(base_url)
+            .retry_policy(RetryPolicy {
+                max_delay: Duration::from_millis(50),
+                ..fast_policy(2)
+            })
+            .build()
+            .unwrap();
+
+        let started = std::time::Instant::now();
+        client.dispatch(&action()).await.unwrap();
+        let elapsed = started.elapsed();
+        assert_eq!(*count.lock().unwrap(), 2);
+        assert!(elapsed >= Duration::from_millis(50), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn classifier_overrides_which_errors_are_retried() {
+        let responses = vec![reply(500, "oops"), reply(200, "\"Deduplicated\"")];
+        let unavailable = vec![reply(503, "{}")];
+        let (base_url, count) = start_scripted_server(responses).await;
+        let client = ActeonClientBuilder::new(base_url)
+            .retry_policy(fast_policy(3))
+            .retry_classifier(|e| matches!(e, Error::Http { status: 500, .. }))
+            .build()
+            .unwrap();
+        client.dispatch(&action()).await.unwrap();
+        assert_eq!(*count.lock().unwrap(), 2);
+
+        // Reads go through the classifier too, which can also opt out.
+        let (base_url, count) = start_scripted_server(unavailable).await;
+        let client = ActeonClientBuilder::new(base_url)
+            .retry_policy(fast_policy(3))
+            .retry_classifier(|e| !matches!(e, Error::Http { status: 503, .. }))
+            .build()
+            .unwrap();
+        assert!(!client.health().await.unwrap());
+        assert_eq!(*count.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn retry_after_accepts_seconds_and_http_dates() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_retry_after(" 120 ", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:29:30 GMT", now),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+        assert_eq!(parse_retry_after("-5", now), None);
+    }
+
+    #[test]
+    fn delay_grows_and_caps() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            jitter: 0.0,
+        };
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+        assert_eq!(policy.delay(4), Duration::from_millis(500));
+        assert_eq!(policy.delay(u32::MAX), Duration::from_millis(500));
+
+        let jittered = RetryPolicy {
+            jitter: 0.5,
+            ..policy
+        };
+        for _ in 0..100 {
+            let delay = jittered.delay(3);
+            assert!(delay > Duration::from_millis(200) && delay <= Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn only_transient_errors_are_retried() {
+        assert!(is_transient(&Error::Connection("reset".into())));
+        assert!(is_transient(&Error::RateLimited { retry_after: None }));
+        for status in [429, 503] {
+            assert!(is_transient(&Error::Http {
+                status,
+                message: String::new(),
+            }));
+        }
+        for status in [400, 404, 408, 500] {
+            assert!(!is_transient(&Error::Http {
+                status,
+                message: String::new(),
+            }));
+        }
+        assert!(is_transient(&Error::Api {
+            code: "UNAVAILABLE".into(),
+            message: String::new(),
+            retryable: true,
+        }));
+        assert!(!is_transient(&Error::Api {
+            code: "INVALID".into(),
+            message: String::new(),
+            retryable: false,
+        }));
+    }
+
+    #[test]
+    fn invalid_policies_are_rejected() {
+        assert!(RetryPolicy::default().validate().is_ok());
+        for policy in [
+            RetryPolicy {
+                max_attempts: 0,
+                ..RetryPolicy::default()
+            },
+            RetryPolicy {
+                multiplier: 0.5,
+                ..RetryPolicy::default()
+            },
+            RetryPolicy {
+                jitter: 1.5,
+                ..RetryPolicy::default()
+            },
+        ] {
+            assert!(policy.validate().is_err(), "{policy:?}");
+        }
+    }
+}
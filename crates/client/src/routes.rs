@@ -0,0 +1,215 @@
+//! Server route templates, for labelling requests by endpoint.
+//!
+//! Request paths embed IDs, names, and fingerprints, so using them as metric
+//! labels would create a series per resource. [`route_template`] maps a path
+//! back to the server route it targets, such as `/v1/rules/{name}/enabled`.
+
+/// Route templates served by the Acteon server. A `{...}` segment matches
+/// any single path segment.
+const ROUTES: &[&str] = &[
+    "/.well-known/acteon-signing-keys",
+    "/a2a/{namespace}/{tenant}",
+    "/a2a/{namespace}/{tenant}/.well-known/agent.json",
+    "/a2a/{namespace}/{tenant}/v1/message:send",
+    "/a2a/{namespace}/{tenant}/v1/tasks/{id}",
+    "/a2a/{namespace}/{tenant}/v1/tasks/{id}/events",
+    "/a2a/{namespace}/{tenant}/v1/tasks/{id}/pushNotificationConfigs",
+    "/a2a/{namespace}/{tenant}/v1/tasks/{id}/pushNotificationConfigs/{cfgId}",
+    "/admin/circuit-breakers",
+    "/admin/circuit-breakers/{provider}/reset",
+    "/admin/circuit-breakers/{provider}/trip",
+    "/admin/config",
+    "/health",
+    "/health/detailed",
+    "/metrics",
+    "/metrics/prometheus",
+    "/v1/a2a/{namespace}/{tenant}/push-dlq",
+    "/v1/a2a/{namespace}/{tenant}/push-dlq/{entryId}",
+    "/v1/actions/{id}/verify",
+    "/v1/analytics",
+    "/v1/approvals",
+    "/v1/approvals/{namespace}/{tenant}/{id}",
+    "/v1/approvals/{namespace}/{tenant}/{id}/approve",
+    "/v1/approvals/{namespace}/{tenant}/{id}/reject",
+    "/v1/audit",
+    "/v1/audit/holds",
+    "/v1/audit/replay",
+    "/v1/audit/verify",
+    "/v1/audit/{action_id}",
+    "/v1/audit/{action_id}/evaluate",
+    "/v1/audit/{action_id}/hold",
+    "/v1/audit/{action_id}/replay",
+    "/v1/audit/{action_id}/verify",
+    "/v1/auth/login",
+    "/v1/auth/logout",
+    "/v1/bus/agents",
+    "/v1/bus/agents/{namespace}/{tenant}/{agent_id}",
+    "/v1/bus/agents/{namespace}/{tenant}/{agent_id}/admin-state",
+    "/v1/bus/agents/{namespace}/{tenant}/{agent_id}/card",
+    "/v1/bus/agents/{namespace}/{tenant}/{agent_id}/heartbeat",
+    "/v1/bus/agents/{namespace}/{tenant}/{agent_id}/send",
+    "/v1/bus/approvals/{namespace}/{tenant}",
+    "/v1/bus/approvals/{namespace}/{tenant}/{approval_id}",
+    "/v1/bus/approvals/{namespace}/{tenant}/{approval_id}/approve",
+    "/v1/bus/approvals/{namespace}/{tenant}/{approval_id}/reject",
+    "/v1/bus/conversations",
+    "/v1/bus/conversations/{namespace}/{tenant}/{conversation_id}",
+    "/v1/bus/conversations/{namespace}/{tenant}/{conversation_id}/messages",
+    "/v1/bus/conversations/{namespace}/{tenant}/{conversation_id}/stream-chunks",
+    "/v1/bus/conversations/{namespace}/{tenant}/{conversation_id}/stream-end",
+    "/v1/bus/conversations/{namespace}/{tenant}/{conversation_id}/tool-calls",
+    "/v1/bus/conversations/{namespace}/{tenant}/{conversation_id}/tool-results",
+    "/v1/bus/conversations/{namespace}/{tenant}/{conversation_id}/transition",
+    "/v1/bus/publish",
+    "/v1/bus/schemas",
+    "/v1/bus/schemas/{namespace}/{tenant}/{subject}",
+    "/v1/bus/schemas/{namespace}/{tenant}/{subject}/{version}",
+    "/v1/bus/streams/{namespace}/{tenant}/{conversation_id}/{stream_id}",
+    "/v1/bus/subscribe/{subscription_id}",
+    "/v1/bus/subscriptions",
+    "/v1/bus/subscriptions/{namespace}/{tenant}/{id}",
+    "/v1/bus/subscriptions/{namespace}/{tenant}/{id}/ack",
+    "/v1/bus/subscriptions/{namespace}/{tenant}/{id}/deadletter",
+    "/v1/bus/subscriptions/{namespace}/{tenant}/{id}/lag",
+    "/v1/bus/tool-calls/{namespace}/{tenant}/{call_id}/result",
+    "/v1/bus/topics",
+    "/v1/bus/topics/{kafka_name}",
+    "/v1/bus/topics/{namespace}/{tenant}/{name}/schema",
+    "/v1/chains",
+    "/v1/chains/definitions",
+    "/v1/chains/definitions/{name}",
+    "/v1/chains/definitions/{name}/dag",
+    "/v1/chains/{chain_id}",
+    "/v1/chains/{chain_id}/cancel",
+    "/v1/chains/{chain_id}/dag",
+    "/v1/chains/{chain_id}/history",
+    "/v1/compliance/status",
+    "/v1/dispatch",
+    "/v1/dispatch/batch",
+    "/v1/dispatch/preview",
+    "/v1/dlq/drain",
+    "/v1/dlq/stats",
+    "/v1/embeddings/similarity",
+    "/v1/events",
+    "/v1/events/{fingerprint}",
+    "/v1/events/{fingerprint}/history",
+    "/v1/events/{fingerprint}/transition",
+    "/v1/executions",
+    "/v1/executions/{execution_id}",
+    "/v1/executions/{execution_id}/attributes",
+    "/v1/executions/{execution_id}/history",
+    "/v1/executions/{execution_id}/reset",
+    "/v1/executions/{execution_id}/signal/{signal_name}",
+    "/v1/flags/{namespace}/{tenant}",
+    "/v1/flags/{namespace}/{tenant}/{name}",
+    "/v1/groups",
+    "/v1/groups/{group_key}",
+    "/v1/plugins",
+    "/v1/plugins/{name}",
+    "/v1/providers/health",
+    "/v1/queues/tasks/{task_id}",
+    "/v1/queues/tasks/{task_id}/complete",
+    "/v1/queues/tasks/{task_id}/fail",
+    "/v1/queues/tasks/{task_id}/heartbeat",
+    "/v1/queues/{queue}/poll",
+    "/v1/queues/{queue}/tasks",
+    "/v1/quotas",
+    "/v1/quotas/reload",
+    "/v1/quotas/{id}",
+    "/v1/quotas/{id}/usage",
+    "/v1/recurring",
+    "/v1/recurring/{id}",
+    "/v1/recurring/{id}/backfill",
+    "/v1/recurring/{id}/pause",
+    "/v1/recurring/{id}/resume",
+    "/v1/retention",
+    "/v1/retention/{id}",
+    "/v1/rules",
+    "/v1/rules/coverage",
+    "/v1/rules/evaluate",
+    "/v1/rules/reload",
+    "/v1/rules/rollback",
+    "/v1/rules/versions",
+    "/v1/rules/{name}/enabled",
+    "/v1/silences",
+    "/v1/silences/{id}",
+    "/v1/stream",
+    "/v1/subscribe/{entity_type}/{entity_id}",
+    "/v1/swarm/runs",
+    "/v1/swarm/runs/{run_id}",
+    "/v1/swarm/runs/{run_id}/cancel",
+    "/v1/templates",
+    "/v1/templates/profiles",
+    "/v1/templates/profiles/{id}",
+    "/v1/templates/profiles/{id}/restore",
+    "/v1/templates/reload",
+    "/v1/templates/render",
+    "/v1/templates/{id}",
+    "/v1/templates/{id}/references",
+    "/v1/templates/{id}/restore",
+    "/v1/time-intervals",
+    "/v1/time-intervals/{namespace}/{tenant}/{name}",
+    "/v1/workflows/executions",
+    "/v1/workflows/executions/{execution_id}",
+    "/v1/workflows/executions/{execution_id}/cancel",
+    "/v1/workflows/executions/{execution_id}/checkpoints",
+    "/v1/workflows/executions/{execution_id}/children",
+    "/v1/workflows/executions/{execution_id}/signal/{signal_name}",
+    "/v1/workflows/start",
+];
+
+/// Label for paths that match no known route.
+pub(crate) const UNKNOWN_ROUTE: &str = "other";
+
+/// The server route template `path` targets, or [`UNKNOWN_ROUTE`].
+///
+/// Templates are matched against the end of the path, so a base URL with a
+/// path prefix (e.g. behind a reverse proxy) still resolves. When several
+/// templates match, the longest wins, then the one with the fewest
+/// parameters.
+pub(crate) fn route_template(path: &str) -> &'static str {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    ROUTES
+        .iter()
+        .filter_map(|route| params_if_matching(route, &segments).map(|params| (route, params)))
+        .min_by_key(|(route, params)| (std::cmp::Reverse(route.split('/').count()), *params))
+        .map_or(UNKNOWN_ROUTE, |(route, _)| route)
+}
+
+/// Number of parameter segments in `route` if it matches the tail of
+/// `segments`.
+fn params_if_matching(route: &str, segments: &[&str]) -> Option<usize> {
+    let pattern: Vec<&str> = route.trim_matches('/').split('/').collect();
+    let tail = segments.get(segments.len().checked_sub(pattern.len())?..)?;
+    let mut params = 0;
+    for (expected, actual) in pattern.iter().zip(tail) {
+        if expected.starts_with('{') {
+            params += 1;
+        } else if expected != actual {
+            return None;
+        }
+    }
+    Some(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_resolve_to_their_route() {
+        assert_eq!(route_template("/v1/dispatch"), "/v1/dispatch");
+        assert_eq!(
+            route_template("/v1/rules/block-spam/enabled"),
+            "/v1/rules/{name}/enabled"
+        );
+        // A static segment beats a parameter in the same position.
+        assert_eq!(route_template("/v1/audit/replay"), "/v1/audit/replay");
+        assert_eq!(route_template("/v1/audit/abc"), "/v1/audit/{action_id}");
+        assert_eq!(
+            route_template("/acteon/v1/audit/abc/replay"),
+            "/v1/audit/{action_id}/replay"
+        );
+        assert_eq!(route_template("/v1/nope/123"), UNKNOWN_ROUTE);
+    }
+}
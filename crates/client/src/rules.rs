@@ -1,6 +1,9 @@
+use std::collections::BTreeMap;
+
 use acteon_core::Action;
 use serde::{Deserialize, Serialize};
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Information about a loaded rule.
@@ -26,6 +29,19 @@ pub struct ReloadResult {
     pub errors: Vec<String>,
 }
 
+/// A rule set version retained by the server for rollback.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleSetVersion {
+    /// Version id, assigned in load order starting at 1.
+    pub version: u64,
+    /// When this rule set was loaded.
+    pub loaded_at: chrono::DateTime<chrono::Utc>,
+    /// Number of rules in the set.
+    pub rule_count: usize,
+    /// Whether this is the rule set currently being evaluated.
+    pub active: bool,
+}
+
 /// Options for rule evaluation playground requests.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct EvaluateRulesOptions {
@@ -41,6 +57,11 @@ pub struct EvaluateRulesOptions {
     /// Optional state key overrides for testing state-dependent conditions.
     #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
     pub mock_state: std::collections::HashMap<String, String>,
+    /// When `true` and `evaluate_at` is unset, pins evaluation to the
+    /// original dispatch time. Only used by
+    /// [`ActeonClient::evaluate_audit_record`].
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub at_dispatch_time: bool,
 }
 
 /// Details about a semantic match evaluation, used for explainability.
@@ -56,6 +77,52 @@ pub struct SemanticMatchDetail {
     pub threshold: f64,
 }
 
+/// Whether a semantic match cleared its rule's threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemanticMatchOutcome {
+    /// The similarity reached the threshold.
+    Passed,
+    /// The similarity fell short of the threshold.
+    Failed,
+}
+
+impl SemanticMatchDetail {
+    /// How far the similarity is from the threshold: positive when the match
+    /// passed, negative when it failed, and `0.0` exactly at the threshold.
+    pub fn margin(&self) -> f64 {
+        self.similarity - self.threshold
+    }
+
+    /// Whether the match passed. Like the rule engine, a similarity equal to
+    /// the threshold passes.
+    pub fn outcome(&self) -> SemanticMatchOutcome {
+        if self.similarity >= self.threshold {
+            SemanticMatchOutcome::Passed
+        } else {
+            SemanticMatchOutcome::Failed
+        }
+    }
+}
+
+/// Renders e.g. `passed by 0.050 (similarity 0.800, threshold 0.750, topic
+/// "billing")`.
+impl std::fmt::Display for SemanticMatchDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verb = match self.outcome() {
+            SemanticMatchOutcome::Passed => "passed",
+            SemanticMatchOutcome::Failed => "failed",
+        };
+        write!(
+            f,
+            "{verb} by {:.3} (similarity {:.3}, threshold {:.3}, topic {:?})",
+            self.margin().abs(),
+            self.similarity,
+            self.threshold,
+            self.topic
+        )
+    }
+}
+
 /// Per-rule trace entry returned by the playground.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuleTraceEntry {
@@ -134,6 +201,31 @@ pub struct RuleEvaluationTrace {
     pub context: TraceContext,
     /// The payload after any rule modifications, if changed.
     pub modified_payload: Option<serde_json::Value>,
+    /// Whether the server's evaluation budget cut the pass short.
+    #[serde(default)]
+    pub budget_exceeded: bool,
+}
+
+impl RuleEvaluationTrace {
+    /// The `n` trace entries that took longest to evaluate, slowest first.
+    /// Entries with equal durations keep their evaluation order.
+    pub fn slowest(&self, n: usize) -> Vec<&RuleTraceEntry> {
+        let mut entries: Vec<&RuleTraceEntry> = self.trace.iter().collect();
+        entries.sort_by_key(|entry| std::cmp::Reverse(entry.evaluation_duration_us));
+        entries.truncate(n);
+        entries
+    }
+
+    /// Cumulative evaluation time in microseconds for each rule `source`,
+    /// e.g. to compare time spent in CEL and YAML rules.
+    pub fn total_by_source(&self) -> BTreeMap<&str, u64> {
+        let mut totals = BTreeMap::new();
+        for entry in &self.trace {
+            let total: &mut u64 = totals.entry(entry.source.as_str()).or_default();
+            *total = total.saturating_add(entry.evaluation_duration_us);
+        }
+        totals
+    }
 }
 
 impl ActeonClient {
@@ -158,9 +250,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let rules = response
@@ -198,9 +289,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.post(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -236,9 +326,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(&serde_json::json!({ "enabled": enabled }))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             Ok(())
@@ -250,6 +339,117 @@ impl ActeonClient {
         }
     }
 
+    /// Enable or disable a specific rule and return its updated state.
+    ///
+    /// Like [`set_rule_enabled`](Self::set_rule_enabled), but the rule as
+    /// the server left it is parsed from the response, so there is no need
+    /// to call [`list_rules`](Self::list_rules) to confirm the change.
+    /// Servers that do not echo the rule's priority fail with
+    /// [`Error::Deserialization`]. An unknown rule fails with
+    /// [`Error::Http`] status 404.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let rule = client.set_rule_enabled_checked("block-spam", false).await?;
+    /// println!("{} (priority {}) enabled: {}", rule.name, rule.priority, rule.enabled);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_rule_enabled_checked(
+        &self,
+        rule_name: &str,
+        enabled: bool,
+    ) -> Result<RuleInfo, Error> {
+        let url = format!("{}/v1/rules/{}/enabled", self.base_url, rule_name);
+
+        let response = self
+            .add_auth(self.client.put(&url))
+            .json(&serde_json::json!({ "enabled": enabled }))
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            response
+                .json::<RuleInfo>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: format!("Failed to set rule enabled: {}", response.status()),
+            })
+        }
+    }
+
+    /// List the active rule set version and the versions retained for
+    /// rollback, most recently replaced first.
+    pub async fn list_rule_versions(&self) -> Result<Vec<RuleSetVersion>, Error> {
+        let url = format!("{}/v1/rules/versions", self.base_url);
+
+        let response = self
+            .add_auth(self.client.get(&url))
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            response
+                .json::<Vec<RuleSetVersion>>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: format!("Failed to list rule versions: {}", response.status()),
+            })
+        }
+    }
+
+    /// Atomically restore a previously loaded rule set.
+    ///
+    /// Returns the now-active version. Fails with HTTP 404 if the server no
+    /// longer retains `version`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let versions = client.list_rule_versions().await?;
+    /// if let Some(previous) = versions.iter().find(|v| !v.active) {
+    ///     client.rollback_rules(previous.version).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rollback_rules(&self, version: u64) -> Result<RuleSetVersion, Error> {
+        let url = format!("{}/v1/rules/rollback", self.base_url);
+
+        let response = self
+            .add_auth(self.client.post(&url))
+            .json(&serde_json::json!({ "version": version }))
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            response
+                .json::<RuleSetVersion>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: format!("Failed to roll back rules: {}", response.status()),
+            })
+        }
+    }
+
     /// Evaluate rules against a test action without dispatching.
     ///
     /// Returns a detailed trace showing how each rule would evaluate against
@@ -317,9 +517,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(&body)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let trace = response
@@ -334,4 +533,288 @@ impl ActeonClient {
             })
         }
     }
+
+    /// Re-run rule evaluation against the action a stored audit record was
+    /// written for, without dispatching.
+    ///
+    /// The server reconstructs the action from the record. Set
+    /// [`EvaluateRulesOptions::at_dispatch_time`] to evaluate time-based
+    /// conditions as of the original dispatch. Returns an
+    /// [`Error::Http`] with status 404 when no record exists and 422 when
+    /// the record has no stored payload.
+    pub async fn evaluate_audit_record(
+        &self,
+        action_id: &str,
+        options: &EvaluateRulesOptions,
+    ) -> Result<RuleEvaluationTrace, Error> {
+        let url = format!("{}/v1/audit/{}/evaluate", self.base_url, action_id);
+
+        let response = self
+            .add_auth(self.client.post(&url))
+            .json(options)
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            let trace = response
+                .json::<RuleEvaluationTrace>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            Ok(trace)
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: format!("Failed to evaluate audit record: {}", response.status()),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    fn semantic(similarity: f64, threshold: f64) -> SemanticMatchDetail {
+        SemanticMatchDetail {
+            extracted_text: "my invoice is wrong".into(),
+            topic: "billing".into(),
+            similarity,
+            threshold,
+        }
+    }
+
+    #[test]
+    fn semantic_match_margin_and_display() {
+        let passed = semantic(0.8, 0.75);
+        assert_eq!(passed.outcome(), SemanticMatchOutcome::Passed);
+        assert!((passed.margin() - 0.05).abs() < 1e-9);
+        assert_eq!(
+            passed.to_string(),
+            "passed by 0.050 (similarity 0.800, threshold 0.750, topic \"billing\")"
+        );
+
+        let failed = semantic(0.6, 0.75);
+        assert_eq!(failed.outcome(), SemanticMatchOutcome::Failed);
+        assert!((failed.margin() + 0.15).abs() < 1e-9);
+        assert!(failed.to_string().starts_with("failed by 0.150 "));
+    }
+
+    #[test]
+    fn semantic_match_at_threshold_passes() {
+        let borderline = semantic(0.75, 0.75);
+        assert!(borderline.margin().abs() < f64::EPSILON);
+        assert_eq!(borderline.outcome(), SemanticMatchOutcome::Passed);
+        assert!(borderline.to_string().starts_with("passed by 0.000 "));
+
+        // Just below the threshold fails, even if it rounds to zero.
+        let just_below = semantic(0.749_9, 0.75);
+        assert_eq!(just_below.outcome(), SemanticMatchOutcome::Failed);
+        assert!(just_below.to_string().starts_with("failed by 0.000 "));
+    }
+
+    fn entry(rule_name: &str, source: &str, evaluation_duration_us: u64) -> RuleTraceEntry {
+        RuleTraceEntry {
+            rule_name: rule_name.into(),
+            priority: 0,
+            enabled: true,
+            condition_display: "true".into(),
+            result: "no_match".into(),
+            evaluation_duration_us,
+            action: "allow".into(),
+            source: source.into(),
+            description: None,
+            skip_reason: None,
+            error: None,
+            semantic_details: None,
+            modify_patch: None,
+            modified_payload_preview: None,
+        }
+    }
+
+    #[test]
+    fn trace_timing_summaries() {
+        let trace = RuleEvaluationTrace {
+            verdict: "allow".into(),
+            matched_rule: None,
+            has_errors: false,
+            total_rules_evaluated: 4,
+            total_rules_skipped: 0,
+            evaluation_duration_us: 100,
+            trace: vec![
+                entry("a", "yaml", 5),
+                entry("b", "cel", 40),
+                entry("c", "yaml", 40),
+                entry("d", "cel", 15),
+            ],
+            context: TraceContext {
+                time: serde_json::json!({}),
+                environment_keys: vec![],
+                accessed_state_keys: vec![],
+                effective_timezone: None,
+            },
+            modified_payload: None,
+            budget_exceeded: false,
+        };
+
+        let slowest: Vec<&str> = trace
+            .slowest(3)
+            .iter()
+            .map(|e| e.rule_name.as_str())
+            .collect();
+        assert_eq!(slowest, ["b", "c", "d"]);
+        assert_eq!(trace.slowest(10).len(), 4);
+        assert_eq!(
+            trace.total_by_source(),
+            BTreeMap::from([("cel", 55), ("yaml", 45)])
+        );
+    }
+
+    /// Serve a trace for the audit record `act-1` and 404 for anything else.
+    async fn start_audit_evaluate_server() -> String {
+        mock_server::serve(|request| {
+            if request.target != "/v1/audit/act-1/evaluate" {
+                return Response::json(404, &serde_json::json!({"error": "no audit record found"}));
+            }
+            Response::json(
+                200,
+                &serde_json::json!({
+                    "verdict": "suppress",
+                    "matched_rule": "quiet-hours",
+                    "has_errors": false,
+                    "total_rules_evaluated": 1,
+                    "total_rules_skipped": 0,
+                    "evaluation_duration_us": 12,
+                    "trace": [],
+                    "context": {"time": {}},
+                    "modified_payload": null,
+                }),
+            )
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn evaluate_audit_record_returns_trace_or_404() {
+        let client = ActeonClient::new(start_audit_evaluate_server().await);
+        let options = EvaluateRulesOptions {
+            at_dispatch_time: true,
+            ..Default::default()
+        };
+
+        let trace = client
+            .evaluate_audit_record("act-1", &options)
+            .await
+            .unwrap();
+        assert_eq!(trace.verdict, "suppress");
+        assert_eq!(trace.matched_rule.as_deref(), Some("quiet-hours"));
+
+        let err = client
+            .evaluate_audit_record("missing", &options)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::Http { status: 404, .. }),
+            "got {err:?}"
+        );
+    }
+
+    /// Echo the toggled rule for `block-spam` and 404 for anything else.
+    async fn start_rule_toggle_server() -> String {
+        mock_server::serve(|request| {
+            if request.target != "/v1/rules/block-spam/enabled" {
+                return Response::json(404, &serde_json::json!({"error": "rule not found"}));
+            }
+            let enabled = request.json()["enabled"] == true;
+            Response::json(
+                200,
+                &serde_json::json!({
+                    "name": "block-spam",
+                    "priority": 10,
+                    "enabled": enabled,
+                    "description": "Blocks spam actions",
+                    "status": if enabled { "enabled" } else { "disabled" },
+                }),
+            )
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn set_rule_enabled_checked_returns_updated_rule() {
+        let client = ActeonClient::new(start_rule_toggle_server().await);
+
+        let rule = client
+            .set_rule_enabled_checked("block-spam", false)
+            .await
+            .unwrap();
+        assert_eq!(rule.name, "block-spam");
+        assert_eq!(rule.priority, 10);
+        assert!(!rule.enabled);
+        assert_eq!(rule.description.as_deref(), Some("Blocks spam actions"));
+
+        let err = client
+            .set_rule_enabled_checked("missing", true)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::Http { status: 404, .. }),
+            "got {err:?}"
+        );
+    }
+
+    /// Serve two rule set versions, switching the active one on rollback.
+    async fn start_rule_versions_server() -> String {
+        let active = std::sync::Mutex::new(2_u64);
+        let version = |v: u64, active: u64| {
+            serde_json::json!({
+                "version": v,
+                "loaded_at": "2026-01-01T00:00:00Z",
+                "rule_count": v,
+                "active": v == active,
+            })
+        };
+        mock_server::serve(move |request| {
+            let mut active = active.lock().unwrap();
+            if request.route() != "POST /v1/rules/rollback" {
+                let other = 3 - *active;
+                return Response::json(
+                    200,
+                    &serde_json::json!([version(*active, *active), version(other, *active)]),
+                );
+            }
+            let requested = request.json()["version"].as_u64().unwrap_or_default();
+            if requested == 1 || requested == 2 {
+                *active = requested;
+                Response::json(200, &version(requested, requested))
+            } else {
+                Response::json(
+                    404,
+                    &serde_json::json!({"error": "unknown rule set version"}),
+                )
+            }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn rollback_rules_switches_active_version() {
+        let client = ActeonClient::new(start_rule_versions_server().await);
+
+        let versions = client.list_rule_versions().await.unwrap();
+        assert_eq!(versions[0].version, 2);
+        assert!(versions[0].active);
+
+        let restored = client.rollback_rules(1).await.unwrap();
+        assert_eq!(restored.version, 1);
+        assert!(restored.active);
+        let versions = client.list_rule_versions().await.unwrap();
+        assert_eq!(versions[0].version, 1);
+
+        let err = client.rollback_rules(9).await.unwrap_err();
+        assert!(
+            matches!(err, Error::Http { status: 404, .. }),
+            "got {err:?}"
+        );
+    }
 }
@@ -0,0 +1,122 @@
+//! Namespace and tenant filled in when a request leaves them unset.
+//!
+//! [`ActeonClientBuilder::default_namespace`] and
+//! [`ActeonClientBuilder::default_tenant`] set the scope used by audit,
+//! event, recurring-action, and approval calls whose namespace or tenant is
+//! empty (or `None`). A value given on the request always wins.
+//!
+//! [`ActeonClientBuilder::default_namespace`]: crate::ActeonClientBuilder::default_namespace
+//! [`ActeonClientBuilder::default_tenant`]: crate::ActeonClientBuilder::default_tenant
+
+/// The builder's default namespace and tenant, if any.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DefaultScope {
+    pub(crate) namespace: Option<String>,
+    pub(crate) tenant: Option<String>,
+}
+
+impl DefaultScope {
+    /// `namespace`, or the default namespace when it is empty.
+    pub(crate) fn namespace<'a>(&'a self, namespace: &'a str) -> &'a str {
+        or_default(namespace, self.namespace.as_deref())
+    }
+
+    /// `tenant`, or the default tenant when it is empty.
+    pub(crate) fn tenant<'a>(&'a self, tenant: &'a str) -> &'a str {
+        or_default(tenant, self.tenant.as_deref())
+    }
+
+    /// Replace an empty `namespace` or `tenant` with the default.
+    pub(crate) fn fill(&self, namespace: &mut String, tenant: &mut String) {
+        fill_empty(namespace, self.namespace.as_deref());
+        fill_empty(tenant, self.tenant.as_deref());
+    }
+
+    /// Replace a `None` or empty `namespace` or `tenant` with the default.
+    pub(crate) fn fill_optional(
+        &self,
+        namespace: &mut Option<String>,
+        tenant: &mut Option<String>,
+    ) {
+        fill_none(namespace, self.namespace.as_deref());
+        fill_none(tenant, self.tenant.as_deref());
+    }
+}
+
+fn or_default<'a>(value: &'a str, default: Option<&'a str>) -> &'a str {
+    match default {
+        Some(default) if value.is_empty() => default,
+        _ => value,
+    }
+}
+
+fn fill_empty(value: &mut String, default: Option<&str>) {
+    if let Some(default) = default
+        && value.is_empty()
+    {
+        default.clone_into(value);
+    }
+}
+
+fn fill_none(value: &mut Option<String>, default: Option<&str>) {
+    if default.is_some() && value.as_deref().is_none_or(str::is_empty) {
+        *value = default.map(str::to_owned);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use crate::mock_server::{self, Response};
+    use crate::{ActeonClientBuilder, AuditQuery, EventQuery, RecurringFilter};
+
+    /// Answer every request with an empty JSON object, forwarding its
+    /// request target.
+    async fn start_server() -> (String, mpsc::UnboundedReceiver<String>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let base_url = mock_server::serve(move |request| {
+            let _ = tx.send(request.target);
+            Response::json(200, &serde_json::json!({}))
+        })
+        .await;
+        (base_url, rx)
+    }
+
+    #[tokio::test]
+    async fn defaults_fill_unset_scope_and_explicit_values_win() {
+        let (base_url, mut requests) = start_server().await;
+        let client = ActeonClientBuilder::new(&base_url)
+            .default_namespace("alerts")
+            .default_tenant("t1")
+            .build()
+            .unwrap();
+
+        let _ = client.query_audit(&AuditQuery::default()).await;
+        let _ = client
+            .list_events(&EventQuery {
+                tenant: "t2".into(),
+                ..EventQuery::default()
+            })
+            .await;
+        let _ = client
+            .list_recurring(&RecurringFilter {
+                namespace: "billing".into(),
+                ..RecurringFilter::default()
+            })
+            .await;
+        let _ = client.list_approvals("", "").await;
+        let _ = client.approve("", "t3", "id-1", "sig", 0).await;
+
+        let targets = [
+            "/v1/audit?namespace=alerts&tenant=t1",
+            "/v1/events?namespace=alerts&tenant=t2",
+            "/v1/recurring?namespace=billing&tenant=t1",
+            "/v1/approvals?namespace=alerts&tenant=t1",
+            "/v1/approvals/alerts/t3/id-1/approve?sig=sig&expires_at=0",
+        ];
+        for expected in targets {
+            assert_eq!(requests.recv().await.unwrap(), expected);
+        }
+    }
+}
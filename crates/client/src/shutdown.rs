@@ -0,0 +1,147 @@
+//! Graceful shutdown.
+//!
+//! Every request is admitted through an [`Admission`] shared by a client
+//! and its clones, which counts requests in flight.
+//! [`ActeonClient::shutdown`] closes it so new requests fail fast with
+//! [`Error::ShuttingDown`], then waits for the in-flight count to drain. A
+//! request counts as in flight until its response headers arrive, at which
+//! point the server has already decided the outcome.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use tokio::sync::Notify;
+
+use crate::{ActeonClient, Error};
+
+/// Counts requests in flight and refuses new ones once closed.
+#[derive(Debug, Default)]
+pub(crate) struct Admission {
+    closed: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: Notify,
+}
+
+impl Admission {
+    /// Admit one request, or refuse it once closed.
+    pub(crate) fn enter(self: &Arc<Self>) -> Result<InFlight, Error> {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        // Count first, then check: `drain` closes before reading the
+        // count, so it either sees this request or this request sees the
+        // admission closed.
+        let guard = InFlight(Arc::clone(self));
+        if self.closed.load(Ordering::SeqCst) {
+            return Err(Error::ShuttingDown);
+        }
+        Ok(guard)
+    }
+
+    fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Refuse new requests, then wait up to `grace` for the ones in flight.
+    async fn drain(&self, grace: Duration) -> Result<(), Error> {
+        self.closed.store(true, Ordering::SeqCst);
+
+        let deadline = tokio::time::Instant::now() + grace;
+        loop {
+            // Register for the wakeup before checking the count so a
+            // request finishing in between is not missed.
+            let idle = self.idle.notified();
+            if self.in_flight() == 0 {
+                return Ok(());
+            }
+            if tokio::time::timeout_at(deadline, idle).await.is_err() {
+                return match self.in_flight() {
+                    0 => Ok(()),
+                    pending => Err(Error::ShutdownTimeout { pending }),
+                };
+            }
+        }
+    }
+}
+
+/// Marks one request as in flight until dropped.
+pub(crate) struct InFlight(Arc<Admission>);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        if self.0.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
+
+impl ActeonClient {
+    /// Stop accepting requests and wait for in-flight ones to finish.
+    ///
+    /// Closes the request gate shared by this client and all of its clones,
+    /// so any request started afterwards fails with
+    /// [`Error::ShuttingDown`]. Then waits up to `grace` for requests
+    /// already in flight to get their responses. Returns
+    /// [`Error::ShutdownTimeout`] with the number of requests still pending
+    /// if the grace period runs out first.
+    pub async fn shutdown(self, grace: Duration) -> Result<(), Error> {
+        self.gate.admission().drain(grace).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    /// Answer every request with `200 OK` after `delay`.
+    async fn start_slow_server(delay: Duration) -> String {
+        mock_server::serve(move |_| {
+            Response::json(200, &serde_json::json!({"status": "ok"})).delay(delay)
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn shutdown_waits_for_in_flight_request() {
+        let client = ActeonClient::new(start_slow_server(Duration::from_millis(300)).await);
+
+        let in_flight = {
+            let client = client.clone();
+            tokio::spawn(async move { client.health().await })
+        };
+        // Let the request reach the server.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let late = client.clone();
+
+        let started = tokio::time::Instant::now();
+        client.shutdown(Duration::from_secs(5)).await.unwrap();
+        assert!(
+            started.elapsed() >= Duration::from_millis(200),
+            "shutdown returned before the request finished"
+        );
+        assert!(in_flight.await.unwrap().unwrap());
+
+        let err = late.health().await.unwrap_err();
+        assert!(matches!(err, Error::ShuttingDown), "got {err:?}");
+    }
+
+    #[tokio::test]
+    async fn shutdown_reports_pending_requests_after_grace() {
+        let client = ActeonClient::new(start_slow_server(Duration::from_secs(5)).await);
+
+        let _in_flight = {
+            let client = client.clone();
+            tokio::spawn(async move { client.health().await })
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let err = client
+            .shutdown(Duration::from_millis(100))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::ShutdownTimeout { pending: 1 }),
+            "got {err:?}"
+        );
+    }
+}
@@ -12,6 +12,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// One verifying key in the active set.
@@ -51,12 +52,7 @@ impl ActeonClient {
     /// on the server.
     pub async fn fetch_signing_keys(&self) -> Result<SigningKeysResponse, Error> {
         let url = format!("{}/.well-known/acteon-signing-keys", self.base_url);
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let response = self.client.get(&url).send_gated(&self.gate).await?;
 
         if response.status().is_success() {
             response
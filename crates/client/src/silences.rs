@@ -9,6 +9,7 @@ pub use acteon_core::{MatchOp, Silence, SilenceMatcher};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Request body for creating a silence.
@@ -100,9 +101,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -134,10 +134,7 @@ impl ActeonClient {
             req = req.query(&[("include_expired", "true")]);
         }
 
-        let response = req
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let response = req.send_gated(&self.gate).await?;
 
         if response.status().is_success() {
             response
@@ -157,9 +154,8 @@ impl ActeonClient {
         let url = format!("{}/v1/silences/{id}", self.base_url);
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -187,9 +183,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -209,9 +204,8 @@ impl ActeonClient {
         let url = format!("{}/v1/silences/{id}", self.base_url);
         let response = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             Ok(())
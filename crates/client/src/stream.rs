@@ -4,17 +4,18 @@
 //! an async [`Stream`] implementation that parses SSE frames from the
 //! `/v1/stream` endpoint.
 
+use std::path::PathBuf;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
-use tokio::io::AsyncBufReadExt;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
 use tokio_util::io::StreamReader;
 
 use acteon_core::StreamEvent;
 
-use crate::Error;
+use crate::{ActeonClient, Error, PollInterval};
 
 /// Filter parameters for the SSE event stream.
 ///
@@ -53,6 +54,44 @@ pub struct StreamFilter {
     /// Filter by action ID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action_id: Option<String>,
+    /// Reconnect after the connection drops. Off when `None`.
+    #[serde(skip)]
+    pub reconnect: Option<StreamReconnect>,
+}
+
+/// How an [`EventStream`] reconnects after its connection drops.
+///
+/// The stream re-issues its request with the same [`StreamFilter`] and a
+/// `Last-Event-ID` header carrying the last event it saw, so the server can
+/// replay what was missed, and yields [`StreamItem::Reconnected`] before
+/// anything from the new connection.
+///
+/// # Example
+///
+/// ```
+/// use acteon_client::{StreamFilter, StreamReconnect};
+///
+/// let filter = StreamFilter::new()
+///     .namespace("alerts")
+///     .reconnect(StreamReconnect::default());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StreamReconnect {
+    /// Reconnection attempts in a row, without an event received in
+    /// between, before the stream gives up and yields the last error.
+    pub max_attempts: u32,
+    /// Delays before each attempt.
+    pub backoff: PollInterval,
+}
+
+impl Default for StreamReconnect {
+    /// Up to five attempts, backing off from one second to 30 seconds.
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            backoff: PollInterval::default(),
+        }
+    }
 }
 
 impl StreamFilter {
@@ -110,6 +149,13 @@ impl StreamFilter {
         self.action_id = Some(action_id.into());
         self
     }
+
+    /// Reconnect automatically when the connection drops.
+    #[must_use]
+    pub fn reconnect(mut self, policy: StreamReconnect) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
 }
 
 /// A single SSE frame received from the server.
@@ -135,6 +181,22 @@ pub enum StreamItem {
     },
     /// A keep-alive comment was received (stream is still alive).
     KeepAlive,
+    /// A frame arrived that could not be parsed. The stream continues with
+    /// the next frame.
+    ParseError {
+        /// The frame's `data` field as received.
+        raw: String,
+        /// Why parsing failed.
+        error: String,
+    },
+    /// The connection dropped and a [`StreamReconnect`] policy opened a new
+    /// one. Events sent in between are replayed when the server still has
+    /// them, but some may have been missed.
+    Reconnected {
+        /// The `Last-Event-ID` the new connection resumed from, or `None`
+        /// if no event had been received yet.
+        last_event_id: Option<String>,
+    },
 }
 
 /// An async stream of SSE events from the Acteon gateway.
@@ -145,6 +207,51 @@ pub struct EventStream {
     inner: Pin<Box<dyn Stream<Item = Result<StreamItem, Error>> + Send>>,
 }
 
+impl EventStream {
+    /// Append each [`StreamItem::Event`] to the file at `path` as a line of
+    /// JSON (NDJSON) as it passes through the returned stream.
+    ///
+    /// Meant for debugging: the file is created on the first event, and a
+    /// failure to open or write it is logged and skipped rather than ending
+    /// the stream. Other items pass through without being written.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::{ActeonClient, StreamFilter};
+    /// use futures::StreamExt;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let mut stream = client
+    ///     .stream(&StreamFilter::new())
+    ///     .await?
+    ///     .tee_to_file("events.ndjson");
+    /// while let Some(item) = stream.next().await {
+    ///     println!("{:?}", item?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn tee_to_file(self, path: impl Into<PathBuf>) -> EventStream {
+        let sink = NdjsonSink {
+            path: path.into(),
+            file: None,
+        };
+        let stream = futures::stream::unfold((self, sink), |(mut inner, mut sink)| async move {
+            let item = inner.next().await?;
+            if let Ok(StreamItem::Event(event)) = &item {
+                sink.write(event).await;
+            }
+            Some((item, (inner, sink)))
+        });
+        EventStream {
+            inner: Box::pin(stream),
+        }
+    }
+}
+
 impl Stream for EventStream {
     type Item = Result<StreamItem, Error>;
 
@@ -153,6 +260,130 @@ impl Stream for EventStream {
     }
 }
 
+/// The file behind [`EventStream::tee_to_file`], opened on first use.
+struct NdjsonSink {
+    path: PathBuf,
+    file: Option<tokio::fs::File>,
+}
+
+impl NdjsonSink {
+    async fn write(&mut self, event: &StreamEvent) {
+        let mut line = match serde_json::to_vec(event) {
+            Ok(line) => line,
+            Err(e) => {
+                tracing::warn!(event_id = %event.id, error = %e, "failed to serialize stream event");
+                return;
+            }
+        };
+        line.push(b'\n');
+
+        if self.file.is_none() {
+            let opened = tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)
+                .await;
+            match opened {
+                Ok(file) => self.file = Some(file),
+                Err(e) => {
+                    tracing::warn!(path = %self.path.display(), error = %e, "failed to open stream tee file");
+                    return;
+                }
+            }
+        }
+        // `tokio::fs::File` finishes writes in the background; flush so each
+        // event is on disk before the stream moves on.
+        if let Some(file) = &mut self.file
+            && let Err(e) = async {
+                file.write_all(&line).await?;
+                file.flush().await
+            }
+            .await
+        {
+            tracing::warn!(path = %self.path.display(), error = %e, "failed to write stream event");
+        }
+    }
+}
+
+/// Where a reconnecting [`EventStream`] stands between connections.
+struct Reconnecting {
+    client: ActeonClient,
+    filter: StreamFilter,
+    policy: StreamReconnect,
+    current: Option<EventStream>,
+    last_event_id: Option<String>,
+    /// Reconnection attempts since the last event.
+    attempts: u32,
+}
+
+/// Wrap `first` so that, when it ends or fails, the subscription is opened
+/// again per `policy`, resuming after the last event seen.
+pub(crate) fn reconnecting_event_stream(
+    client: ActeonClient,
+    filter: StreamFilter,
+    policy: StreamReconnect,
+    first: EventStream,
+) -> EventStream {
+    let state = Reconnecting {
+        client,
+        filter,
+        policy,
+        current: Some(first),
+        last_event_id: None,
+        attempts: 0,
+    };
+    // `None` once the stream has given up.
+    let stream = futures::stream::unfold(Some(state), |state| async move {
+        let mut state = state?;
+        let mut error = None;
+        if let Some(current) = state.current.as_mut() {
+            match current.next().await {
+                Some(Ok(item)) => {
+                    if let StreamItem::Event(event) = &item {
+                        state.last_event_id = Some(event.id.clone());
+                        state.attempts = 0;
+                    }
+                    return Some((Ok(item), Some(state)));
+                }
+                Some(Err(e)) => error = Some(e),
+                None => {}
+            }
+            state.current = None;
+        }
+
+        loop {
+            if state.attempts >= state.policy.max_attempts {
+                return error.map(|e| (Err(e), None));
+            }
+            state.attempts += 1;
+            tokio::time::sleep(state.policy.backoff.delay(state.attempts)).await;
+            match state
+                .client
+                .open_stream(&state.filter, state.last_event_id.as_deref())
+                .await
+            {
+                Ok(stream) => {
+                    state.current = Some(stream);
+                    let marker = StreamItem::Reconnected {
+                        last_event_id: state.last_event_id.clone(),
+                    };
+                    return Some((Ok(marker), Some(state)));
+                }
+                // Retrying cannot fix a rejected request, e.g. bad credentials.
+                Err(e @ Error::Http { status, .. })
+                    if (400..500).contains(&status) && status != 429 =>
+                {
+                    return Some((Err(e), None));
+                }
+                Err(e) => error = Some(e),
+            }
+        }
+    });
+    EventStream {
+        inner: Box::pin(stream),
+    }
+}
+
 /// What the line-level SSE parser hands back for each turn of the stream.
 /// Either a complete frame (`event` + `data`) or a keep-alive comment.
 pub(crate) enum SseEnvelope {
@@ -216,7 +447,7 @@ pub(crate) fn sse_envelope_stream(
 /// Create an `EventStream` from a reqwest response that returns SSE data.
 pub(crate) fn event_stream_from_response(response: reqwest::Response) -> EventStream {
     let stream = sse_envelope_stream(response).map(|env| match env {
-        Ok(SseEnvelope::Frame(frame)) => parse_sse_frame(&frame),
+        Ok(SseEnvelope::Frame(frame)) => Ok(parse_sse_frame(frame)),
         Ok(SseEnvelope::KeepAlive) => Ok(StreamItem::KeepAlive),
         Err(e) => Err(e),
     });
@@ -253,7 +484,10 @@ impl SseFrameState {
 }
 
 /// Parse an SSE frame into a `StreamItem`.
-fn parse_sse_frame(frame: &SseFrame) -> Result<StreamItem, Error> {
+///
+/// A frame whose data does not parse becomes [`StreamItem::ParseError`]
+/// rather than an error, so one bad frame does not end the subscription.
+fn parse_sse_frame(frame: SseFrame) -> StreamItem {
     let event_type = frame.event.as_deref().unwrap_or("message");
 
     if event_type == "lagged" {
@@ -261,11 +495,15 @@ fn parse_sse_frame(frame: &SseFrame) -> Result<StreamItem, Error> {
             .ok()
             .and_then(|v| v.get("skipped")?.as_u64())
             .unwrap_or(0);
-        Ok(StreamItem::Lagged { skipped })
+        StreamItem::Lagged { skipped }
     } else {
-        let event: StreamEvent = serde_json::from_str(&frame.data)
-            .map_err(|e| Error::Deserialization(format!("failed to parse SSE event: {e}")))?;
-        Ok(StreamItem::Event(Box::new(event)))
+        match serde_json::from_str::<StreamEvent>(&frame.data) {
+            Ok(event) => StreamItem::Event(Box::new(event)),
+            Err(e) => StreamItem::ParseError {
+                raw: frame.data,
+                error: format!("failed to parse SSE event: {e}"),
+            },
+        }
     }
 }
 
@@ -275,6 +513,7 @@ use futures::StreamExt;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mock_server::{self, Response};
 
     #[test]
     fn stream_filter_serialization() {
@@ -303,7 +542,7 @@ mod tests {
             id: None,
             data: r#"{"skipped":42}"#.into(),
         };
-        let item = parse_sse_frame(&frame).unwrap();
+        let item = parse_sse_frame(frame);
         match item {
             StreamItem::Lagged { skipped } => assert_eq!(skipped, 42),
             other => panic!("expected Lagged, got {other:?}"),
@@ -323,6 +562,7 @@ mod tests {
             tenant: "t1".into(),
             action_type: None,
             action_id: None,
+            traceparent: None,
         };
         let json = serde_json::to_string(&event).unwrap();
 
@@ -332,7 +572,7 @@ mod tests {
             data: json,
         };
 
-        let item = parse_sse_frame(&frame).unwrap();
+        let item = parse_sse_frame(frame);
         match item {
             StreamItem::Event(e) => {
                 assert_eq!(e.id, "test-id");
@@ -356,6 +596,182 @@ mod tests {
         assert_eq!(frame.data, "line1\nline2");
     }
 
+    #[test]
+    fn parse_malformed_frame_yields_parse_error() {
+        let frame = SseFrame {
+            event: Some("action_dispatched".into()),
+            id: None,
+            data: "{not json".into(),
+        };
+        match parse_sse_frame(frame) {
+            StreamItem::ParseError { raw, error } => {
+                assert_eq!(raw, "{not json");
+                assert!(error.contains("failed to parse SSE event"));
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+    }
+
+    fn group_flushed_json(id: &str) -> String {
+        serde_json::to_string(&StreamEvent {
+            id: id.into(),
+            timestamp: chrono::Utc::now(),
+            event_type: acteon_core::StreamEventType::GroupFlushed {
+                group_id: "g1".into(),
+                event_count: 1,
+            },
+            namespace: "ns".into(),
+            tenant: "t1".into(),
+            action_type: None,
+            action_id: None,
+            traceparent: None,
+        })
+        .unwrap()
+    }
+
+    /// Serve `body` as a single SSE response and return the base URL.
+    async fn serve_sse(body: String) -> String {
+        mock_server::serve(move |_| Response::new(200).body("text/event-stream", body.clone()))
+            .await
+    }
+
+    #[tokio::test]
+    async fn tee_to_file_yields_and_writes_events() {
+        let body = format!(
+            "event: group_flushed\ndata: {}\n\n\
+             : keep-alive\n\n\
+             event: group_flushed\ndata: {}\n\n",
+            group_flushed_json("e1"),
+            group_flushed_json("e2"),
+        );
+        let path = std::env::temp_dir().join(format!(
+            "acteon-tee-{}-{}.ndjson",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+
+        let client = crate::ActeonClient::new(serve_sse(body).await);
+        let items: Vec<StreamItem> = client
+            .stream(&StreamFilter::new())
+            .await
+            .unwrap()
+            .tee_to_file(&path)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 3);
+        assert!(matches!(&items[0], StreamItem::Event(e) if e.id == "e1"));
+        assert!(matches!(&items[1], StreamItem::KeepAlive));
+        assert!(matches!(&items[2], StreamItem::Event(e) if e.id == "e2"));
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let ids: Vec<String> = written
+            .lines()
+            .map(|line| serde_json::from_str::<StreamEvent>(line).unwrap().id)
+            .collect();
+        assert_eq!(ids, ["e1", "e2"]);
+    }
+
+    #[tokio::test]
+    async fn tee_to_file_keeps_streaming_when_the_file_cannot_be_opened() {
+        let body = format!(
+            "event: group_flushed\ndata: {}\n\n",
+            group_flushed_json("e1")
+        );
+        let path = std::env::temp_dir()
+            .join("acteon-tee-missing-dir")
+            .join("events.ndjson");
+
+        let client = crate::ActeonClient::new(serve_sse(body).await);
+        let items: Vec<StreamItem> = client
+            .stream(&StreamFilter::new())
+            .await
+            .unwrap()
+            .tee_to_file(&path)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert!(matches!(&items[..], [StreamItem::Event(e)] if e.id == "e1"));
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn reconnects_with_last_event_id() {
+        let (tx, mut requests) = tokio::sync::mpsc::unbounded_channel();
+        let served = std::sync::atomic::AtomicUsize::new(0);
+        // Later connections are refused.
+        let base_url = mock_server::serve_limited(2, move |request| {
+            let id = ["e1", "e2"][served.fetch_add(1, std::sync::atomic::Ordering::SeqCst)];
+            let _ = tx.send(request);
+            let body = format!(
+                "event: group_flushed\nid: {id}\ndata: {}\n\n",
+                group_flushed_json(id)
+            );
+            std::future::ready(Response::new(200).body("text/event-stream", body))
+        })
+        .await;
+
+        let policy = StreamReconnect {
+            max_attempts: 2,
+            backoff: PollInterval::fixed(std::time::Duration::from_millis(1)),
+        };
+        let filter = StreamFilter::new().namespace("ns").reconnect(policy);
+        let items: Vec<Result<StreamItem, Error>> = crate::ActeonClient::new(base_url)
+            .stream(&filter)
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 4, "{items:?}");
+        assert!(matches!(&items[0], Ok(StreamItem::Event(e)) if e.id == "e1"));
+        assert!(matches!(
+            &items[1],
+            Ok(StreamItem::Reconnected { last_event_id: Some(id) }) if id == "e1"
+        ));
+        assert!(matches!(&items[2], Ok(StreamItem::Event(e)) if e.id == "e2"));
+        // Both attempts after the second connection were refused.
+        assert!(matches!(&items[3], Err(Error::Connection(_))), "{items:?}");
+
+        let first = requests.recv().await.unwrap();
+        let second = requests.recv().await.unwrap();
+        assert_eq!(first.header("last-event-id"), None);
+        assert_eq!(second.header("last-event-id"), Some("e1"));
+        assert_eq!(second.query(), Some("namespace=ns"));
+    }
+
+    #[tokio::test]
+    async fn stream_continues_after_malformed_frame() {
+        let event = group_flushed_json;
+        let body = format!(
+            "event: group_flushed\ndata: {}\n\n\
+             event: group_flushed\ndata: {{\"truncated\n\n\
+             event: group_flushed\ndata: {}\n\n",
+            event("e1"),
+            event("e2"),
+        );
+
+        let client = crate::ActeonClient::new(serve_sse(body).await);
+        let items: Vec<StreamItem> = client
+            .stream(&StreamFilter::new())
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 3);
+        assert!(matches!(&items[0], StreamItem::Event(e) if e.id == "e1"));
+        assert!(matches!(
+            &items[1],
+            StreamItem::ParseError { raw, .. } if raw == "{\"truncated"
+        ));
+        assert!(matches!(&items[2], StreamItem::Event(e) if e.id == "e2"));
+    }
+
     #[test]
     fn sse_frame_state_empty_returns_none() {
         let mut state = SseFrameState::default();
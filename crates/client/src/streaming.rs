@@ -1,4 +1,5 @@
 use crate::stream::{self, StreamFilter};
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error, EventStream};
 
 impl ActeonClient {
@@ -27,20 +28,49 @@ impl ActeonClient {
     ///             eprintln!("Missed {skipped} events");
     ///         }
     ///         acteon_client::StreamItem::KeepAlive => {}
+    ///         acteon_client::StreamItem::ParseError { error, .. } => {
+    ///             eprintln!("Skipping malformed frame: {error}");
+    ///         }
+    ///         acteon_client::StreamItem::Reconnected { .. } => {
+    ///             eprintln!("Reconnected; events may have been missed");
+    ///         }
     ///     }
     /// }
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// With [`StreamFilter::reconnect`] set, the stream reconnects when the
+    /// connection drops instead of ending; see
+    /// [`StreamReconnect`](crate::StreamReconnect).
     pub async fn stream(&self, filter: &StreamFilter) -> Result<EventStream, Error> {
+        let Some(policy) = filter.reconnect else {
+            return self.open_stream(filter, None).await;
+        };
+        policy.backoff.validate().map_err(Error::Configuration)?;
+        let first = self.open_stream(filter, None).await?;
+        Ok(stream::reconnecting_event_stream(
+            self.clone(),
+            filter.clone(),
+            policy,
+            first,
+        ))
+    }
+
+    /// Open one connection to `GET /v1/stream`, resuming after
+    /// `last_event_id` if given.
+    pub(crate) async fn open_stream(
+        &self,
+        filter: &StreamFilter,
+        last_event_id: Option<&str>,
+    ) -> Result<EventStream, Error> {
         let url = format!("{}/v1/stream", self.base_url);
 
-        let response = self
-            .add_auth(self.client.get(&url))
-            .query(filter)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let mut request = self.add_auth(self.client.get(&url)).query(filter);
+        if let Some(id) = last_event_id {
+            request = request.header("Last-Event-ID", id);
+        }
+        let response = request.send_gated(&self.gate).await?;
 
         if response.status().is_success() {
             Ok(stream::event_stream_from_response(response))
@@ -71,9 +101,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             Ok(stream::event_stream_from_response(response))
@@ -11,6 +11,7 @@ use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};
 use serde::{Deserialize, Serialize};
 
 use crate::dispatch::ErrorResponse;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Characters that must be escaped inside a path segment.
@@ -98,9 +99,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(filter)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -123,9 +123,8 @@ impl ActeonClient {
         let url = format!("{}/v1/swarm/runs/{encoded}", self.base_url);
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
@@ -152,9 +151,8 @@ impl ActeonClient {
         let url = format!("{}/v1/swarm/runs/{encoded}/cancel", self.base_url);
         let response = self
             .add_auth(self.client.post(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             response
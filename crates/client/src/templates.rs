@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::dispatch::ErrorResponse;
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// A reusable `MiniJinja` template stored in the system.
@@ -26,6 +27,9 @@ pub struct TemplateInfo {
     /// Arbitrary key-value labels.
     #[serde(default)]
     pub labels: Option<std::collections::HashMap<String, String>>,
+    /// When this template was soft-deleted, if it has been.
+    #[serde(default)]
+    pub deleted_at: Option<String>,
 }
 
 /// A template profile that maps payload fields to template content.
@@ -41,6 +45,10 @@ pub struct TemplateProfileInfo {
     pub tenant: String,
     /// Field-to-template mappings.
     pub fields: std::collections::HashMap<String, serde_json::Value>,
+    /// Output format deciding how values are escaped: `"html"`,
+    /// `"mrkdwn"`, or `"plain"`.
+    #[serde(default)]
+    pub output_type: Option<String>,
     /// Optional human-readable description.
     #[serde(default)]
     pub description: Option<String>,
@@ -51,6 +59,26 @@ pub struct TemplateProfileInfo {
     /// Arbitrary key-value labels.
     #[serde(default)]
     pub labels: Option<std::collections::HashMap<String, String>>,
+    /// When this profile was soft-deleted, if it has been.
+    #[serde(default)]
+    pub deleted_at: Option<String>,
+}
+
+/// A resource that uses a template.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateReference {
+    /// Kind of the referencing resource: `"profile"` or `"rule"`.
+    pub kind: String,
+    /// Name of the referencing resource.
+    pub name: String,
+    /// ID of the referencing resource, when it has one.
+    #[serde(default)]
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TemplateReferencesResponse {
+    references: Vec<TemplateReference>,
 }
 
 /// Request to create a new template.
@@ -97,6 +125,10 @@ pub struct CreateProfileRequest {
     pub tenant: String,
     /// Field-to-template mappings.
     pub fields: std::collections::HashMap<String, serde_json::Value>,
+    /// Output format deciding how values are escaped: `"html"`,
+    /// `"mrkdwn"`, or `"plain"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_type: Option<String>,
     /// Optional human-readable description.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -111,6 +143,9 @@ pub struct UpdateProfileRequest {
     /// Updated field-to-template mappings.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<std::collections::HashMap<String, serde_json::Value>>,
+    /// Updated output format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_type: Option<String>,
     /// Updated description.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -168,9 +203,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -210,9 +244,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -236,9 +269,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -267,9 +299,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(update)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -295,15 +326,64 @@ impl ActeonClient {
         }
     }
 
+    /// List the profiles and rules that use a template.
+    pub async fn template_references(&self, id: &str) -> Result<Vec<TemplateReference>, Error> {
+        let url = format!("{}/v1/templates/{id}/references", self.base_url);
+
+        let response = self
+            .add_auth(self.client.get(&url))
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            let result = response
+                .json::<TemplateReferencesResponse>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            Ok(result.references)
+        } else if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Err(Error::Http {
+                status: 404,
+                message: format!("Template not found: {id}"),
+            })
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: "Failed to list template references".to_string(),
+            })
+        }
+    }
+
     /// Delete a template.
-    pub async fn delete_template(&self, id: &str) -> Result<(), Error> {
+    ///
+    /// Unless `force` is set, fails with [`Error::Conflict`] while any
+    /// profile or rule still references the template (see
+    /// [`template_references`](Self::template_references)).
+    ///
+    /// The template is soft-deleted and can be brought back with
+    /// [`restore_template`](Self::restore_template) until it is purged.
+    pub async fn delete_template(&self, id: &str, force: bool) -> Result<(), Error> {
+        if !force {
+            let references = self.template_references(id).await?;
+            if !references.is_empty() {
+                let names: Vec<String> = references
+                    .iter()
+                    .map(|r| format!("{} {}", r.kind, r.name))
+                    .collect();
+                return Err(Error::Conflict(format!(
+                    "template {id} is referenced by: {}",
+                    names.join(", ")
+                )));
+            }
+        }
+
         let url = format!("{}/v1/templates/{id}", self.base_url);
 
         let response = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .query(&[("force", force)])
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status() == reqwest::StatusCode::NO_CONTENT {
             Ok(())
@@ -312,6 +392,11 @@ impl ActeonClient {
                 status: 404,
                 message: format!("Template not found: {id}"),
             })
+        } else if response.status() == reqwest::StatusCode::CONFLICT {
+            // A reference was added between the check and the delete.
+            Err(Error::Conflict(format!(
+                "template {id} is still referenced"
+            )))
         } else {
             Err(Error::Http {
                 status: response.status().as_u16(),
@@ -320,6 +405,34 @@ impl ActeonClient {
         }
     }
 
+    /// Restore a soft-deleted template.
+    pub async fn restore_template(&self, id: &str) -> Result<TemplateInfo, Error> {
+        let url = format!("{}/v1/templates/{id}/restore", self.base_url);
+
+        let response = self
+            .add_auth(self.client.post(&url))
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            let result = response
+                .json::<TemplateInfo>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            Ok(result)
+        } else if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Err(Error::Http {
+                status: 404,
+                message: format!("Template not found: {id}"),
+            })
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: "Failed to restore template".to_string(),
+            })
+        }
+    }
+
     /// Create a new template profile.
     pub async fn create_profile(
         &self,
@@ -330,9 +443,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -372,9 +484,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.get(&url))
             .query(&query)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -398,9 +509,8 @@ impl ActeonClient {
 
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -429,9 +539,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(update)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -458,14 +567,16 @@ impl ActeonClient {
     }
 
     /// Delete a template profile.
+    ///
+    /// The profile is soft-deleted and can be brought back with
+    /// [`restore_profile`](Self::restore_profile) until it is purged.
     pub async fn delete_profile(&self, id: &str) -> Result<(), Error> {
         let url = format!("{}/v1/templates/profiles/{id}", self.base_url);
 
         let response = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status() == reqwest::StatusCode::NO_CONTENT {
             Ok(())
@@ -482,6 +593,37 @@ impl ActeonClient {
         }
     }
 
+    /// Restore a soft-deleted template profile.
+    ///
+    /// Fails with a 409 if a template the profile references no longer
+    /// exists; restore that template first.
+    pub async fn restore_profile(&self, id: &str) -> Result<TemplateProfileInfo, Error> {
+        let url = format!("{}/v1/templates/profiles/{id}/restore", self.base_url);
+
+        let response = self
+            .add_auth(self.client.post(&url))
+            .send_gated(&self.gate)
+            .await?;
+
+        if response.status().is_success() {
+            let result = response
+                .json::<TemplateProfileInfo>()
+                .await
+                .map_err(|e| Error::Deserialization(e.to_string()))?;
+            Ok(result)
+        } else if response.status() == reqwest::StatusCode::NOT_FOUND {
+            Err(Error::Http {
+                status: 404,
+                message: format!("Template profile not found: {id}"),
+            })
+        } else {
+            Err(Error::Http {
+                status: response.status().as_u16(),
+                message: "Failed to restore template profile".to_string(),
+            })
+        }
+    }
+
     /// Render a template preview using the given profile and payload.
     pub async fn render_preview(
         &self,
@@ -492,9 +634,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
 
         if response.status().is_success() {
             let result = response
@@ -515,3 +656,58 @@ impl ActeonClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    /// Serve one template referenced by one profile. Returns the base URL and
+    /// a flag set once the server accepts a `DELETE`.
+    async fn start_referenced_template_server() -> (String, Arc<AtomicBool>) {
+        let deleted = Arc::new(AtomicBool::new(false));
+        let deleted_flag = Arc::clone(&deleted);
+        let base_url = mock_server::serve(move |request| {
+            if request.method != "DELETE" {
+                return Response::json(
+                    200,
+                    &serde_json::json!({
+                        "references": [
+                            {"kind": "profile", "name": "alert-email", "id": "prof-1"}
+                        ],
+                        "count": 1,
+                    }),
+                );
+            }
+            if request.query() == Some("force=true") {
+                deleted_flag.store(true, Ordering::SeqCst);
+                Response::new(204)
+            } else {
+                Response::json(409, &serde_json::json!({"error": "template is referenced"}))
+            }
+        })
+        .await;
+        (base_url, deleted)
+    }
+
+    #[tokio::test]
+    async fn referenced_template_delete_requires_force() {
+        let (base_url, deleted) = start_referenced_template_server().await;
+        let client = ActeonClient::new(base_url);
+
+        let references = client.template_references("tpl-1").await.unwrap();
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].kind, "profile");
+        assert_eq!(references[0].name, "alert-email");
+
+        let err = client.delete_template("tpl-1", false).await.unwrap_err();
+        assert!(matches!(err, Error::Conflict(_)), "got {err:?}");
+        assert!(!deleted.load(Ordering::SeqCst));
+
+        client.delete_template("tpl-1", true).await.unwrap();
+        assert!(deleted.load(Ordering::SeqCst));
+    }
+}
@@ -11,6 +11,7 @@ pub use acteon_core::time_interval::{
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::transport::GatedSend;
 use crate::{ActeonClient, Error};
 
 /// Time-of-day window in `HH:MM` form (matches the server API).
@@ -99,9 +100,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.post(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if response.status().is_success() {
             response
                 .json::<TimeIntervalResponse>()
@@ -128,10 +128,7 @@ impl ActeonClient {
         if let Some(ref t) = query.tenant {
             req = req.query(&[("tenant", t)]);
         }
-        let response = req
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+        let response = req.send_gated(&self.gate).await?;
         if response.status().is_success() {
             response
                 .json::<ListTimeIntervalsResponse>()
@@ -158,9 +155,8 @@ impl ActeonClient {
         );
         let response = self
             .add_auth(self.client.get(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if response.status().is_success() {
             response
                 .json::<TimeIntervalResponse>()
@@ -192,9 +188,8 @@ impl ActeonClient {
         let response = self
             .add_auth(self.client.put(&url))
             .json(req)
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if response.status().is_success() {
             response
                 .json::<TimeIntervalResponse>()
@@ -221,9 +216,8 @@ impl ActeonClient {
         );
         let response = self
             .add_auth(self.client.delete(&url))
-            .send()
-            .await
-            .map_err(|e| Error::Connection(e.to_string()))?;
+            .send_gated(&self.gate)
+            .await?;
         if response.status().is_success() {
             Ok(())
         } else {
@@ -0,0 +1,88 @@
+//! Per-endpoint request timeouts.
+//!
+//! A client talks to endpoints with very different latency profiles: a
+//! dispatch answers in milliseconds while an audit export can stream for
+//! minutes. [`ActeonClientBuilder::endpoint_timeout`] maps URL path patterns
+//! to their own timeout; requests whose path matches no pattern keep the
+//! client-wide timeout.
+//!
+//! Patterns are matched segment by segment against the request path. A `*`
+//! segment matches any single path segment, so `/v1/audit/*` matches
+//! `/v1/audit/abc` but neither `/v1/audit` nor `/v1/audit/abc/replay`. When
+//! several patterns match, the one with the fewest wildcards wins.
+//!
+//! [`ActeonClientBuilder::endpoint_timeout`]: crate::ActeonClientBuilder::endpoint_timeout
+
+use std::time::Duration;
+
+/// Ordered map of path pattern to timeout.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct EndpointTimeouts {
+    entries: Vec<(String, Duration)>,
+}
+
+impl EndpointTimeouts {
+    /// Set the timeout for `pattern`, replacing any earlier entry for it.
+    pub(crate) fn insert(&mut self, pattern: &str, timeout: Duration) {
+        let pattern = pattern.trim_end_matches('/').to_owned();
+        match self.entries.iter_mut().find(|(p, _)| *p == pattern) {
+            Some(entry) => entry.1 = timeout,
+            None => self.entries.push((pattern, timeout)),
+        }
+    }
+
+    /// Timeout for a request to `path`, or `None` to use the client default.
+    pub(crate) fn lookup(&self, path: &str) -> Option<Duration> {
+        self.entries
+            .iter()
+            .filter_map(|(pattern, timeout)| {
+                wildcards_if_matching(pattern, path).map(|wildcards| (wildcards, *timeout))
+            })
+            .min_by_key(|(wildcards, _)| *wildcards)
+            .map(|(_, timeout)| timeout)
+    }
+}
+
+/// Number of `*` segments in `pattern` if it matches `path`.
+fn wildcards_if_matching(pattern: &str, path: &str) -> Option<usize> {
+    let mut pattern_segments = pattern.trim_matches('/').split('/');
+    let mut path_segments = path.trim_matches('/').split('/');
+    let mut wildcards = 0;
+    loop {
+        match (pattern_segments.next(), path_segments.next()) {
+            (None, None) => return Some(wildcards),
+            (Some("*"), Some(_)) => wildcards += 1,
+            (Some(expected), Some(actual)) if expected == actual => {}
+            _ => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_pattern_wins() {
+        let mut timeouts = EndpointTimeouts::default();
+        timeouts.insert("/v1/audit/*", Duration::from_secs(5));
+        timeouts.insert("/v1/audit/", Duration::from_secs(300));
+        timeouts.insert("/v1/audit/export", Duration::from_secs(600));
+
+        assert_eq!(timeouts.lookup("/v1/audit"), Some(Duration::from_secs(300)));
+        assert_eq!(
+            timeouts.lookup("/v1/audit/abc"),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            timeouts.lookup("/v1/audit/export"),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(timeouts.lookup("/v1/audit/abc/replay"), None);
+        assert_eq!(timeouts.lookup("/health"), None);
+
+        // Re-registering a pattern replaces its timeout.
+        timeouts.insert("/v1/audit", Duration::from_secs(1));
+        assert_eq!(timeouts.lookup("/v1/audit"), Some(Duration::from_secs(1)));
+    }
+}
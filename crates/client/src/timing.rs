@@ -0,0 +1,139 @@
+//! Dispatch latency breakdown from the `Server-Timing` response header.
+//!
+//! When the server reports per-phase timings such as
+//! `Server-Timing: rules;dur=3, provider;dur=42`, these helpers turn them into
+//! durations so callers can see whether latency comes from rule evaluation,
+//! the provider call, or the network.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use acteon_core::{Action, ActionOutcome};
+
+use crate::{ActeonClient, Error};
+
+/// Latency breakdown of a single dispatch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DispatchTiming {
+    /// Server-side phases reported in the `Server-Timing` header, keyed by
+    /// metric name. Empty when the server sent no such header.
+    pub server: HashMap<String, Duration>,
+    /// Round-trip time measured by the client, including network latency.
+    pub round_trip: Duration,
+}
+
+impl DispatchTiming {
+    /// Duration of a server-side phase, if the server reported it.
+    pub fn get(&self, metric: &str) -> Option<Duration> {
+        self.server.get(metric).copied()
+    }
+}
+
+/// Parse a `Server-Timing` header value into durations keyed by metric name.
+///
+/// Durations are given in milliseconds (`dur=42.5`). Metrics without a valid
+/// `dur` parameter are skipped; if a metric appears more than once, the first
+/// occurrence wins.
+pub fn parse_server_timing(value: &str) -> HashMap<String, Duration> {
+    let mut timings = HashMap::new();
+    for metric in value.split(',') {
+        let mut parts = metric.split(';');
+        let name = parts.next().unwrap_or_default().trim();
+        if name.is_empty() {
+            continue;
+        }
+        let duration = parts.find_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            if !key.trim().eq_ignore_ascii_case("dur") {
+                return None;
+            }
+            let millis: f64 = value.trim().trim_matches('"').parse().ok()?;
+            Duration::try_from_secs_f64(millis / 1000.0).ok()
+        });
+        if let Some(duration) = duration {
+            timings.entry(name.to_owned()).or_insert(duration);
+        }
+    }
+    timings
+}
+
+impl ActeonClient {
+    /// Dispatch a single action and report where the time went.
+    ///
+    /// Behaves like [`dispatch`](Self::dispatch), and additionally returns the
+    /// server-reported `Server-Timing` phases and the client round-trip time.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), acteon_client::Error> {
+    /// use acteon_client::ActeonClient;
+    /// use acteon_core::Action;
+    ///
+    /// let client = ActeonClient::new("http://localhost:8080");
+    /// let action = Action::new("ns", "tenant", "email", "send", serde_json::json!({}));
+    ///
+    /// let (outcome, timing) = client.dispatch_with_timing(&action).await?;
+    /// println!("{outcome:?}: provider took {:?}", timing.get("provider"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dispatch_with_timing(
+        &self,
+        action: &Action,
+    ) -> Result<(ActionOutcome, DispatchTiming), Error> {
+        let started = Instant::now();
+        let (outcome, headers) = self.dispatch_with_headers(action, false, None).await?;
+        let round_trip = started.elapsed();
+
+        let mut server = HashMap::new();
+        for value in headers.get_all("server-timing") {
+            if let Ok(value) = value.to_str() {
+                for (name, duration) in parse_server_timing(value) {
+                    server.entry(name).or_insert(duration);
+                }
+            }
+        }
+        Ok((outcome, DispatchTiming { server, round_trip }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::{self, Response};
+
+    /// Answer every request with an outcome and the given `Server-Timing`.
+    async fn start_server_with_timing(server_timing: &'static str) -> String {
+        mock_server::serve(move |_| {
+            Response::json(200, &serde_json::json!("Deduplicated"))
+                .header("Server-Timing", server_timing)
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn dispatch_exposes_server_timing() {
+        let client =
+            ActeonClient::new(start_server_with_timing("rules;dur=3, provider;dur=42").await);
+        let action = Action::new("ns", "tenant", "email", "send", serde_json::json!({}));
+
+        let (outcome, timing) = client.dispatch_with_timing(&action).await.unwrap();
+        assert!(matches!(outcome, ActionOutcome::Deduplicated));
+        assert_eq!(timing.server.len(), 2);
+        assert_eq!(timing.get("rules"), Some(Duration::from_millis(3)));
+        assert_eq!(timing.get("provider"), Some(Duration::from_millis(42)));
+        assert!(timing.round_trip > Duration::ZERO);
+    }
+
+    #[test]
+    fn parses_descriptions_fractions_and_skips_invalid_metrics() {
+        let timings = parse_server_timing(
+            r#"db;desc="Primary DB";dur=1.5, cache;desc=miss, total;dur="12", bad;dur=-1, db;dur=9"#,
+        );
+        assert_eq!(timings.len(), 2);
+        assert_eq!(timings["db"], Duration::from_micros(1500));
+        assert_eq!(timings["total"], Duration::from_millis(12));
+        assert!(parse_server_timing("").is_empty());
+    }
+}
@@ -0,0 +1,60 @@
+//! In-memory TLS material for the builder's HTTP client.
+//!
+//! [`ActeonClientBuilder::identity`] and [`ActeonClientBuilder::add_root_cert`]
+//! take PEM bytes rather than file paths, for certificates that come from a
+//! secret store. They are parsed when the client is built.
+//!
+//! [`ActeonClientBuilder::identity`]: crate::ActeonClientBuilder::identity
+//! [`ActeonClientBuilder::add_root_cert`]: crate::ActeonClientBuilder::add_root_cert
+
+use reqwest::ClientBuilder;
+
+use crate::Error;
+
+/// Client identity and extra root certificates, as PEM.
+#[derive(Clone, Default)]
+pub(crate) struct TlsConfig {
+    /// Client certificate chain followed by its private key.
+    identity: Option<Vec<u8>>,
+    root_certs: Vec<Vec<u8>>,
+}
+
+impl TlsConfig {
+    /// Present `client_cert_pem` with `key_pem` to the server.
+    pub(crate) fn set_identity(&mut self, client_cert_pem: &[u8], key_pem: &[u8]) {
+        let mut combined = client_cert_pem.to_vec();
+        combined.push(b'\n');
+        combined.extend_from_slice(key_pem);
+        self.identity = Some(combined);
+    }
+
+    /// Trust servers whose certificate is signed by `pem`.
+    pub(crate) fn add_root_cert(&mut self, pem: &[u8]) {
+        self.root_certs.push(pem.to_vec());
+    }
+
+    /// Configure `builder` with the identity and root certificates.
+    pub(crate) fn apply(&self, mut builder: ClientBuilder) -> Result<ClientBuilder, Error> {
+        for pem in &self.root_certs {
+            let cert = reqwest::Certificate::from_pem(pem)
+                .map_err(|e| Error::Configuration(format!("invalid root cert: {e}")))?;
+            builder = builder.add_root_certificate(cert);
+        }
+        if let Some(pem) = &self.identity {
+            let identity = reqwest::Identity::from_pem(pem)
+                .map_err(|e| Error::Configuration(format!("invalid client identity: {e}")))?;
+            builder = builder.identity(identity);
+        }
+        Ok(builder)
+    }
+}
+
+impl std::fmt::Debug for TlsConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The identity holds a private key, so only say whether it is set.
+        f.debug_struct("TlsConfig")
+            .field("identity", &self.identity.as_ref().map(|_| "<redacted>"))
+            .field("root_certs", &self.root_certs.len())
+            .finish()
+    }
+}
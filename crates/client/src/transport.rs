@@ -0,0 +1,241 @@
+//! Request transport.
+//!
+//! Every request goes through a [`RequestGate`] shared by an
+//! [`ActeonClient`](crate::ActeonClient) and all of its clones. The gate
+//! admits the request (see [`Admission`]), applies per-endpoint timeouts,
+//! adds propagated headers, compresses large request bodies when enabled,
+//! retries transient failures, turns HTTP 429 responses into
+//! [`Error::RateLimited`], and reports each request to the client's
+//! [`MetricsRecorder`] and response hook.
+//!
+//! [`MetricsRecorder`]: crate::MetricsRecorder
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+
+use reqwest::Method;
+
+use crate::compression::compress_request_body;
+use crate::metrics::SharedRecorder;
+use crate::propagation::HeaderPropagator;
+use crate::response_hook::ResponseHook;
+use crate::retry::{RetryClassifier, RetryPolicy, rate_limited};
+use crate::routes::route_template;
+use crate::shutdown::{Admission, InFlight};
+use crate::timeouts::EndpointTimeouts;
+use crate::{Error, RequestInfo, RequestMetrics, ResponseInfo, RetryEvent};
+
+/// Request pipeline shared by an [`ActeonClient`](crate::ActeonClient) and
+/// all of its clones.
+#[derive(Debug, Default)]
+pub(crate) struct RequestGate {
+    admission: Arc<Admission>,
+    recorder: SharedRecorder,
+    timeouts: EndpointTimeouts,
+    retry: RetryPolicy,
+    classifier: RetryClassifier,
+    compress_requests: bool,
+    propagator: HeaderPropagator,
+    on_response: ResponseHook,
+}
+
+impl RequestGate {
+    pub(crate) fn new(
+        recorder: SharedRecorder,
+        timeouts: EndpointTimeouts,
+        retry: RetryPolicy,
+        classifier: RetryClassifier,
+        compress_requests: bool,
+        propagator: HeaderPropagator,
+        on_response: ResponseHook,
+    ) -> Self {
+        Self {
+            recorder,
+            timeouts,
+            retry,
+            classifier,
+            compress_requests,
+            propagator,
+            on_response,
+            ..Self::default()
+        }
+    }
+
+    /// Admission control for requests through this gate.
+    pub(crate) fn admission(&self) -> &Admission {
+        &self.admission
+    }
+
+    /// Admit one request, or refuse it once the client is shutting down.
+    fn enter(&self) -> Result<InFlight, Error> {
+        self.admission.enter()
+    }
+}
+
+/// Sends a request through the client's [`RequestGate`].
+pub(crate) trait GatedSend {
+    /// Send the request if the gate is open, mapping transport failures to
+    /// [`Error::Connection`].
+    fn send_gated(
+        self,
+        gate: &Arc<RequestGate>,
+    ) -> impl Future<Output = Result<reqwest::Response, Error>> + Send;
+}
+
+impl GatedSend for reqwest::RequestBuilder {
+    fn send_gated(
+        self,
+        gate: &Arc<RequestGate>,
+    ) -> impl Future<Output = Result<reqwest::Response, Error>> + Send {
+        let admitted = gate.enter();
+        let gate = Arc::clone(gate);
+        let (client, request) = self.build_split();
+        let request = request.map(|mut request| {
+            if request.timeout().is_none() {
+                *request.timeout_mut() = gate.timeouts.lookup(request.url().path());
+            }
+            gate.propagator.apply(request.headers_mut());
+            if gate.compress_requests {
+                compress_request_body(&mut request);
+            }
+            request
+        });
+        async move {
+            let request = request.map_err(|e| Error::Connection(e.to_string()))?;
+            // Reads are safe to repeat; writes opt in through `RequestGate::retry`.
+            if request.method() != Method::GET {
+                return gate.execute(&client, request, admitted).await;
+            }
+            let method = request.method().clone();
+            let endpoint = request.url().path().to_owned();
+            let mut admitted = Some(admitted);
+            gate.retry(
+                &method,
+                &endpoint,
+                || {
+                    let admitted = admitted.take().unwrap_or_else(|| gate.enter());
+                    let attempt = request.try_clone();
+                    let (gate, client) = (&gate, &client);
+                    async move {
+                        match attempt {
+                            Some(attempt) => gate.execute(client, attempt, admitted).await,
+                            None => Err(Error::Connection("request cannot be cloned".into())),
+                        }
+                    }
+                },
+                |response| {
+                    let status = response.status();
+                    (!status.is_success()).then(|| Error::Http {
+                        status: status.as_u16(),
+                        message: status.canonical_reason().unwrap_or_default().to_owned(),
+                    })
+                },
+            )
+            .await
+        }
+    }
+}
+
+impl RequestGate {
+    /// Send one attempt of `request` and report it to the recorder and the
+    /// response hook.
+    async fn execute(
+        &self,
+        client: &reqwest::Client,
+        request: reqwest::Request,
+        admitted: Result<InFlight, Error>,
+    ) -> Result<reqwest::Response, Error> {
+        let method = request.method().clone();
+        let url = request.url().clone();
+        let endpoint = url.path();
+        let started = Instant::now();
+        let result = match admitted {
+            Ok(_in_flight) => client
+                .execute(request)
+                .await
+                .map_err(|e| Error::Connection(e.to_string())),
+            Err(e) => Err(e),
+        };
+        let status = result.as_ref().ok().map(|r| r.status().as_u16());
+        let duration = started.elapsed();
+        self.recorder.0.record_request(&RequestMetrics {
+            method: &method,
+            endpoint,
+            route: route_template(endpoint),
+            status,
+            duration,
+        });
+        self.on_response.call(
+            &RequestInfo {
+                method: &method,
+                url: &url,
+                path: endpoint,
+            },
+            &ResponseInfo {
+                status,
+                elapsed: duration,
+            },
+        );
+        match result {
+            Ok(response) if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                Err(rate_limited(&response))
+            }
+            result => result,
+        }
+    }
+
+    /// Run `attempt` under the client's [`RetryPolicy`](crate::RetryPolicy).
+    ///
+    /// Errors the client's classifier deems transient are retried with
+    /// backoff, as are successful results for which `failure` returns such
+    /// an error. A rate limit's `Retry-After`
+    /// stretches the backoff, up to the policy's `max_delay`. The last
+    /// attempt's result is returned as-is once the policy runs out of
+    /// attempts.
+    pub(crate) async fn retry<T, F, Fut>(
+        &self,
+        method: &Method,
+        endpoint: &str,
+        mut attempt: F,
+        failure: impl Fn(&T) -> Option<Error>,
+    ) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut attempts = 1;
+        loop {
+            let result = attempt().await;
+            if attempts >= self.retry.max_attempts {
+                return result;
+            }
+            let reason = match &result {
+                Ok(value) => failure(value)
+                    .filter(|e| self.classifier.should_retry(e))
+                    .map(|e| e.to_string()),
+                Err(e) => self.classifier.should_retry(e).then(|| e.to_string()),
+            };
+            let Some(reason) = reason else {
+                return result;
+            };
+            let mut delay = self.retry.delay(attempts);
+            if let Err(Error::RateLimited {
+                retry_after: Some(retry_after),
+            }) = &result
+            {
+                delay = delay.max((*retry_after).min(self.retry.max_delay));
+            }
+            self.recorder.0.record_retry(&RetryEvent {
+                method,
+                endpoint,
+                route: route_template(endpoint),
+                attempt: attempts,
+                delay,
+                reason: &reason,
+            });
+            tokio::time::sleep(delay).await;
+            attempts += 1;
+        }
+    }
+}
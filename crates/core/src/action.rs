@@ -15,6 +15,13 @@ pub struct ActionMetadata {
     #[serde(flatten)]
     #[cfg_attr(feature = "openapi", schema(value_type = HashMap<String, String>))]
     pub labels: HashMap<String, String>,
+    /// W3C `traceparent` of the caller's trace, forwarded to providers as a
+    /// header and recorded in audit records and stream events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traceparent: Option<String>,
+    /// W3C `tracestate` accompanying [`traceparent`](Self::traceparent).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracestate: Option<String>,
 }
 
 /// An action to be dispatched through the gateway pipeline.
@@ -45,10 +52,14 @@ pub struct Action {
     /// Tenant that owns this action.
     pub tenant: TenantId,
 
-    /// Target provider for execution.
+    /// Target provider for execution. May be omitted when the namespace
+    /// sets a default provider (see [`NamespaceDefaults`]).
+    #[serde(default)]
     pub provider: ProviderId,
 
-    /// Action type discriminator (e.g. `send_email`, `send_sms`).
+    /// Action type discriminator (e.g. `send_email`, `send_sms`). May be
+    /// omitted when the namespace sets a default action type.
+    #[serde(default)]
     pub action_type: String,
 
     /// Arbitrary JSON payload for the provider.
@@ -122,6 +133,14 @@ pub struct Action {
     /// `GET /.well-known/acteon-signing-keys`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub kid: Option<String>,
+
+    /// Per-action override of payload encryption at rest. `Some(false)`
+    /// stores this action's payload in plaintext even when encryption is
+    /// enabled globally (e.g. for high-volume heartbeats); `None` follows
+    /// the gateway's configuration. Ignored when compliance mode forbids
+    /// opting out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypt_payload: Option<bool>,
 }
 
 impl Action {
@@ -155,9 +174,22 @@ impl Action {
             signature: None,
             signer_id: None,
             kid: None,
+            encrypt_payload: None,
         }
     }
 
+    /// Start building an action with [`ActionBuilder`].
+    ///
+    /// The payload defaults to an empty JSON object.
+    pub fn builder(
+        namespace: impl Into<String>,
+        tenant: impl Into<String>,
+        provider: impl Into<String>,
+        action_type: impl Into<String>,
+    ) -> ActionBuilder {
+        ActionBuilder::new(namespace, tenant, provider, action_type)
+    }
+
     /// Set a deduplication key.
     #[must_use]
     pub fn with_dedup_key(mut self, key: impl Into<String>) -> Self {
@@ -207,6 +239,31 @@ impl Action {
         self
     }
 
+    /// Set the caller's W3C `traceparent` (and optional `tracestate`) on the
+    /// action metadata.
+    #[must_use]
+    pub fn with_traceparent(
+        mut self,
+        traceparent: impl Into<String>,
+        tracestate: Option<String>,
+    ) -> Self {
+        self.metadata.traceparent = Some(traceparent.into());
+        self.metadata.tracestate = tracestate;
+        self
+    }
+
+    /// The `traceparent` identifying this action's trace.
+    ///
+    /// Prefers the caller-supplied [`ActionMetadata::traceparent`] and falls
+    /// back to the context captured from the server span at dispatch time.
+    #[must_use]
+    pub fn traceparent(&self) -> Option<&str> {
+        self.metadata
+            .traceparent
+            .as_deref()
+            .or_else(|| self.trace_context.get("traceparent").map(String::as_str))
+    }
+
     /// Set the template profile name for payload rendering.
     #[must_use]
     pub fn with_template(mut self, template: impl Into<String>) -> Self {
@@ -243,6 +300,13 @@ impl Action {
         self
     }
 
+    /// Override whether this action's payload is encrypted at rest.
+    #[must_use]
+    pub fn with_encrypt_payload(mut self, encrypt: bool) -> Self {
+        self.encrypt_payload = Some(encrypt);
+        self
+    }
+
     /// Compute the canonical byte representation used for signing.
     ///
     /// Returns a **compact** (no whitespace), deterministic JSON
@@ -280,12 +344,279 @@ impl Action {
             };
         serde_json::to_vec(&sorted).unwrap_or_default()
     }
+
+    /// Rebuild the action an audit record was written for, given its stored
+    /// payload.
+    ///
+    /// Restores the routing fields, metadata labels, trace context, and the
+    /// `__`-prefixed system fields (`dedup_key`, `fingerprint`, `status`,
+    /// `starts_at`, `ends_at`, `encrypt_payload`) the gateway records in
+    /// audit metadata. The result gets a fresh ID and `created_at`.
+    #[must_use]
+    pub fn from_audit(record: &impl AuditedAction, payload: serde_json::Value) -> Self {
+        let mut action = Self::new(
+            record.namespace(),
+            record.tenant(),
+            record.provider(),
+            record.action_type(),
+            payload,
+        );
+        let Some(labels) = record.metadata().as_object() else {
+            return action;
+        };
+
+        let as_str = |key: &str| labels.get(key).and_then(serde_json::Value::as_str);
+        let as_time = |key: &str| as_str(key).and_then(|s| s.parse::<DateTime<Utc>>().ok());
+
+        for (k, v) in labels {
+            // System-prefixed and trace keys are restored as typed fields below.
+            if k.starts_with("__") || k == "traceparent" || k == "tracestate" {
+                continue;
+            }
+            // Convert non-string values to their string representation instead
+            // of silently dropping them.
+            let s = v.as_str().map_or_else(|| v.to_string(), str::to_owned);
+            action.metadata.labels.insert(k.clone(), s);
+        }
+        action.metadata.traceparent = as_str("traceparent").map(str::to_owned);
+        action.metadata.tracestate = as_str("tracestate").map(str::to_owned);
+        action.dedup_key = as_str("__dedup_key").map(str::to_owned);
+        action.fingerprint = as_str("__fingerprint").map(str::to_owned);
+        action.status = as_str("__status").map(str::to_owned);
+        action.starts_at = as_time("__starts_at");
+        action.ends_at = as_time("__ends_at");
+        action.encrypt_payload = labels
+            .get("__encrypt_payload")
+            .and_then(serde_json::Value::as_bool);
+        action
+    }
+}
+
+/// Builds an [`Action`] without touching its fields directly.
+///
+/// [`build`](Self::build) checks that the routing fields are non-empty.
+///
+/// # Examples
+///
+/// ```
+/// use acteon_core::Action;
+///
+/// let action = Action::builder("notifications", "tenant-1", "email", "send_email")
+///     .payload(serde_json::json!({"to": "user@example.com"}))
+///     .label("team", "billing")
+///     .dedup_key("welcome-42")
+///     .build()
+///     .unwrap();
+/// assert_eq!(action.metadata.labels["team"], "billing");
+///
+/// assert!(Action::builder("notifications", "", "email", "send_email").build().is_err());
+/// ```
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ActionBuilder {
+    namespace: String,
+    tenant: String,
+    provider: String,
+    action_type: String,
+    payload: serde_json::Value,
+    labels: HashMap<String, String>,
+    attachments: Vec<Attachment>,
+    dedup_key: Option<String>,
+}
+
+impl ActionBuilder {
+    /// Start building an action for the given routing fields.
+    pub fn new(
+        namespace: impl Into<String>,
+        tenant: impl Into<String>,
+        provider: impl Into<String>,
+        action_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            namespace: namespace.into(),
+            tenant: tenant.into(),
+            provider: provider.into(),
+            action_type: action_type.into(),
+            payload: serde_json::Value::Object(serde_json::Map::new()),
+            labels: HashMap::new(),
+            attachments: Vec::new(),
+            dedup_key: None,
+        }
+    }
+
+    /// Set the JSON payload for the provider.
+    pub fn payload(mut self, payload: serde_json::Value) -> Self {
+        self.payload = payload;
+        self
+    }
+
+    /// Add a metadata label, replacing any earlier value for `key`.
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    /// Add a file attachment.
+    pub fn attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    /// Set the deduplication key.
+    pub fn dedup_key(mut self, key: impl Into<String>) -> Self {
+        self.dedup_key = Some(key.into());
+        self
+    }
+
+    /// Build the action, generating its id and `created_at`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActionBuildError::EmptyField`] naming the first of
+    /// `namespace`, `tenant`, `provider`, and `action_type` that is empty.
+    pub fn build(self) -> Result<Action, ActionBuildError> {
+        for (field, value) in [
+            ("namespace", &self.namespace),
+            ("tenant", &self.tenant),
+            ("provider", &self.provider),
+            ("action_type", &self.action_type),
+        ] {
+            if value.is_empty() {
+                return Err(ActionBuildError::EmptyField(field));
+            }
+        }
+        let mut action = Action::new(
+            self.namespace,
+            self.tenant,
+            self.provider,
+            self.action_type,
+            self.payload,
+        );
+        action.metadata.labels = self.labels;
+        action.attachments = self.attachments;
+        action.dedup_key = self.dedup_key;
+        Ok(action)
+    }
+}
+
+/// Why [`ActionBuilder::build`] rejected an action.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ActionBuildError {
+    #[error("action {0} must not be empty")]
+    EmptyField(&'static str),
+}
+
+/// The fields of an audit record needed to rebuild its [`Action`] with
+/// [`Action::from_audit`].
+///
+/// Implemented by the audit store's record type and by the client's, so
+/// both server-side replay and client-side inspection share one
+/// reconstruction.
+pub trait AuditedAction {
+    /// Namespace the action belonged to.
+    fn namespace(&self) -> &str;
+    /// Tenant that owned the action.
+    fn tenant(&self) -> &str;
+    /// Target provider.
+    fn provider(&self) -> &str;
+    /// Action type discriminator.
+    fn action_type(&self) -> &str;
+    /// Serialized action metadata, including `__`-prefixed system fields.
+    fn metadata(&self) -> &serde_json::Value;
+}
+
+/// Defaults applied to actions in one namespace that omit their provider or
+/// action type.
+///
+/// Fields the action sets explicitly always win; a default only fills a
+/// field the action left empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct NamespaceDefaults {
+    /// Provider used when the action does not name one.
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Action type used when the action does not set one.
+    #[serde(default)]
+    pub action_type: Option<String>,
+}
+
+impl NamespaceDefaults {
+    /// The provider and action type `action` resolves to once these
+    /// defaults are applied, without modifying it.
+    #[must_use]
+    pub fn resolve<'a>(&'a self, action: &'a Action) -> (&'a str, &'a str) {
+        let provider = match self.provider.as_deref() {
+            Some(default) if action.provider.is_empty() => default,
+            _ => action.provider.as_str(),
+        };
+        let action_type = match self.action_type.as_deref() {
+            Some(default) if action.action_type.is_empty() => default,
+            _ => action.action_type.as_str(),
+        };
+        (provider, action_type)
+    }
+
+    /// Fill in the provider and action type where `action` left them empty.
+    pub fn apply(&self, action: &mut Action) {
+        if action.provider.is_empty()
+            && let Some(provider) = &self.provider
+        {
+            action.provider = ProviderId::new(provider.clone());
+        }
+        if action.action_type.is_empty()
+            && let Some(action_type) = &self.action_type
+        {
+            action.action_type.clone_from(action_type);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn namespace_defaults_fill_omitted_fields() {
+        let mut action: Action = serde_json::from_value(serde_json::json!({
+            "id": "a1",
+            "namespace": "alerts",
+            "tenant": "t1",
+            "payload": {},
+            "created_at": "2026-01-01T00:00:00Z",
+        }))
+        .unwrap();
+        assert!(action.provider.is_empty());
+
+        let defaults = NamespaceDefaults {
+            provider: Some("slack".into()),
+            action_type: Some("post_message".into()),
+        };
+        assert_eq!(defaults.resolve(&action), ("slack", "post_message"));
+        defaults.apply(&mut action);
+        assert_eq!(action.provider.as_str(), "slack");
+        assert_eq!(action.action_type, "post_message");
+    }
+
+    #[test]
+    fn explicit_fields_override_namespace_defaults() {
+        let mut action = Action::new(
+            "alerts",
+            "t1",
+            "pagerduty",
+            "trigger",
+            serde_json::json!({}),
+        );
+        let defaults = NamespaceDefaults {
+            provider: Some("slack".into()),
+            action_type: Some("post_message".into()),
+        };
+        assert_eq!(defaults.resolve(&action), ("pagerduty", "trigger"));
+        defaults.apply(&mut action);
+        assert_eq!(action.provider.as_str(), "pagerduty");
+        assert_eq!(action.action_type, "trigger");
+    }
+
     #[test]
     fn action_creation() {
         let action = Action::new(
@@ -302,6 +633,52 @@ mod tests {
         assert!(action.dedup_key.is_none());
     }
 
+    #[test]
+    fn builder_sets_labels_attachments_and_dedup_key() {
+        let attachment = Attachment {
+            id: "att-1".into(),
+            name: "Report".into(),
+            filename: "report.txt".into(),
+            content_type: "text/plain".into(),
+            data_base64: "aGVsbG8=".into(),
+        };
+        let action = Action::builder("ns", "t1", "email", "send")
+            .payload(serde_json::json!({"to": "a@b.c"}))
+            .label("team", "ops")
+            .label("team", "billing")
+            .attachment(attachment)
+            .dedup_key("k1")
+            .build()
+            .unwrap();
+
+        assert_eq!(action.namespace.as_str(), "ns");
+        assert_eq!(action.payload["to"], "a@b.c");
+        assert_eq!(action.metadata.labels.len(), 1);
+        assert_eq!(action.metadata.labels["team"], "billing");
+        assert_eq!(action.attachments.len(), 1);
+        assert_eq!(action.dedup_key.as_deref(), Some("k1"));
+    }
+
+    #[test]
+    fn builder_rejects_empty_routing_fields() {
+        let action = Action::builder("ns", "t1", "email", "send")
+            .build()
+            .unwrap();
+        assert_eq!(action.payload, serde_json::json!({}));
+
+        for (builder, field) in [
+            (Action::builder("", "t1", "email", "send"), "namespace"),
+            (Action::builder("ns", "", "email", "send"), "tenant"),
+            (Action::builder("ns", "t1", "", "send"), "provider"),
+            (Action::builder("ns", "t1", "email", ""), "action_type"),
+        ] {
+            assert_eq!(
+                builder.build().unwrap_err(),
+                ActionBuildError::EmptyField(field)
+            );
+        }
+    }
+
     #[test]
     fn action_with_dedup() {
         let action = Action::new("ns", "t", "p", "type", serde_json::Value::Null)
@@ -318,6 +695,34 @@ mod tests {
         assert_eq!(back.payload, action.payload);
     }
 
+    #[test]
+    fn traceparent_roundtrips_through_metadata() {
+        let tp = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let action = Action::new("ns", "t", "p", "type", serde_json::Value::Null)
+            .with_traceparent(tp, Some("vendor=1".into()));
+        let json = serde_json::to_value(&action).unwrap();
+        assert_eq!(json["metadata"]["traceparent"], tp);
+        assert_eq!(json["metadata"]["tracestate"], "vendor=1");
+
+        let back: Action = serde_json::from_value(json).unwrap();
+        assert_eq!(back.metadata.traceparent.as_deref(), Some(tp));
+        assert_eq!(back.metadata.tracestate.as_deref(), Some("vendor=1"));
+        assert!(back.metadata.labels.is_empty());
+        assert_eq!(back.traceparent(), Some(tp));
+    }
+
+    #[test]
+    fn traceparent_falls_back_to_captured_context() {
+        let mut action = Action::new("ns", "t", "p", "type", serde_json::Value::Null);
+        assert!(action.traceparent().is_none());
+        action
+            .trace_context
+            .insert("traceparent".into(), "00-captured".into());
+        assert_eq!(action.traceparent(), Some("00-captured"));
+        action.metadata.traceparent = Some("00-caller".into());
+        assert_eq!(action.traceparent(), Some("00-caller"));
+    }
+
     #[test]
     fn action_with_status() {
         let action =
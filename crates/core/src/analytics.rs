@@ -69,6 +69,13 @@ pub struct AnalyticsQuery {
     /// Number of top entries to return for `TopActionTypes` (default: 10).
     #[serde(default)]
     pub top_n: Option<usize>,
+    /// Drill into a single entry of a previous breakdown, as a
+    /// `(dimension, value)` pair (e.g. `("tenant", "acme")`). The response
+    /// buckets and top entries are scoped to that entry, so a top tenant can
+    /// be broken down by action type in one query. See
+    /// [`AnalyticsQuery::resolve_drill`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drill_into: Option<(String, String)>,
     /// Server-set tenant authorization scope (hierarchical grant patterns the
     /// caller may read). Backends restrict aggregation to tenants covered by
     /// one of these; empty = unrestricted. See [`crate::tenant_scope`].
@@ -83,6 +90,60 @@ fn default_interval() -> AnalyticsInterval {
     AnalyticsInterval::Daily
 }
 
+/// Errors from resolving [`AnalyticsQuery::drill_into`].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum AnalyticsDrillError {
+    #[error(
+        "unsupported drill dimension '{0}' (expected one of namespace, tenant, provider, action_type, outcome)"
+    )]
+    UnsupportedDimension(String),
+    #[error("drill value for '{0}' must not be empty")]
+    EmptyValue(String),
+    #[error("drill into {dimension}={drilled} conflicts with filter {dimension}={existing}")]
+    ConflictingFilter {
+        dimension: String,
+        existing: String,
+        drilled: String,
+    },
+}
+
+impl AnalyticsQuery {
+    /// Fold [`drill_into`](Self::drill_into) into the matching filter field.
+    ///
+    /// Returns a copy of the query with the drilled dimension pinned as a
+    /// filter and `drill_into` cleared, so backends only ever see plain
+    /// filters. Drilling into a dimension that is already filtered is
+    /// allowed only when the values agree.
+    pub fn resolve_drill(&self) -> Result<Self, AnalyticsDrillError> {
+        let mut resolved = self.clone();
+        let Some((dimension, value)) = resolved.drill_into.take() else {
+            return Ok(resolved);
+        };
+        if value.is_empty() {
+            return Err(AnalyticsDrillError::EmptyValue(dimension));
+        }
+        let filter = match dimension.as_str() {
+            "namespace" => &mut resolved.namespace,
+            "tenant" => &mut resolved.tenant,
+            "provider" => &mut resolved.provider,
+            "action_type" => &mut resolved.action_type,
+            "outcome" => &mut resolved.outcome,
+            _ => return Err(AnalyticsDrillError::UnsupportedDimension(dimension)),
+        };
+        match filter {
+            Some(existing) if *existing != value => Err(AnalyticsDrillError::ConflictingFilter {
+                dimension,
+                existing: existing.clone(),
+                drilled: value,
+            }),
+            _ => {
+                *filter = Some(value);
+                Ok(resolved)
+            }
+        }
+    }
+}
+
 /// A single time bucket in an analytics response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
@@ -143,3 +204,81 @@ pub struct AnalyticsResponse {
     /// Total count of actions in the query range.
     pub total_count: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query() -> AnalyticsQuery {
+        AnalyticsQuery {
+            metric: AnalyticsMetric::TopActionTypes,
+            namespace: None,
+            tenant: None,
+            provider: None,
+            action_type: None,
+            outcome: None,
+            interval: AnalyticsInterval::Daily,
+            from: None,
+            to: None,
+            group_by: None,
+            top_n: None,
+            drill_into: None,
+            tenant_scope: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn resolve_drill_pins_dimension_as_filter() {
+        let mut q = query();
+        q.drill_into = Some(("tenant".into(), "acme".into()));
+        let resolved = q.resolve_drill().unwrap();
+        assert_eq!(resolved.tenant.as_deref(), Some("acme"));
+        assert!(resolved.drill_into.is_none());
+
+        // Without a drill the query is unchanged.
+        let plain = query().resolve_drill().unwrap();
+        assert!(plain.tenant.is_none());
+    }
+
+    #[test]
+    fn resolve_drill_rejects_invalid_drills() {
+        let mut q = query();
+        q.drill_into = Some(("matched_rule".into(), "r".into()));
+        assert!(matches!(
+            q.resolve_drill(),
+            Err(AnalyticsDrillError::UnsupportedDimension(_))
+        ));
+
+        q.drill_into = Some(("provider".into(), String::new()));
+        assert!(matches!(
+            q.resolve_drill(),
+            Err(AnalyticsDrillError::EmptyValue(_))
+        ));
+
+        q.provider = Some("email".into());
+        q.drill_into = Some(("provider".into(), "slack".into()));
+        assert!(matches!(
+            q.resolve_drill(),
+            Err(AnalyticsDrillError::ConflictingFilter { .. })
+        ));
+
+        q.drill_into = Some(("provider".into(), "email".into()));
+        assert_eq!(
+            q.resolve_drill().unwrap().provider.as_deref(),
+            Some("email")
+        );
+    }
+
+    #[test]
+    fn drill_into_serde_roundtrip() {
+        let mut q = query();
+        let json = serde_json::to_value(&q).unwrap();
+        assert!(json.get("drill_into").is_none());
+
+        q.drill_into = Some(("tenant".into(), "acme".into()));
+        let json = serde_json::to_value(&q).unwrap();
+        assert_eq!(json["drill_into"], serde_json::json!(["tenant", "acme"]));
+        let back: AnalyticsQuery = serde_json::from_value(json).unwrap();
+        assert_eq!(back.drill_into, q.drill_into);
+    }
+}
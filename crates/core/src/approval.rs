@@ -0,0 +1,74 @@
+//! Signatures on approval URLs.
+//!
+//! Approve and reject links carry an HMAC-SHA256 signature over the pending
+//! action's scope, approval ID, and expiry, so whoever holds the link can
+//! decide the action without authenticating.
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+/// The signed query parameters shared by an approval's approve and reject
+/// URLs.
+///
+/// Whoever holds them can decide the approval, so they are only handed out
+/// where the approve and reject URLs themselves would be.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApprovalUrlParams {
+    /// Hex-encoded HMAC signature, see [`approval_signature`].
+    pub sig: String,
+    /// Unix timestamp, in seconds, after which the signature is rejected.
+    pub expires_at: i64,
+    /// ID of the key that made the signature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+/// Compute the hex-encoded HMAC-SHA256 signature of an approval URL.
+///
+/// The message uses length-prefixed fields to prevent canonicalization
+/// attacks (e.g., `ns="a:b", tenant="c"` vs `ns="a", tenant="b:c"`).
+/// The `expires_at` Unix timestamp binds the signature to a specific expiry
+/// window so leaked links cannot be replayed after expiration.
+#[must_use]
+pub fn approval_signature(
+    namespace: &str,
+    tenant: &str,
+    id: &str,
+    expires_at: i64,
+    secret: &[u8],
+) -> String {
+    let msg = format!(
+        "{}:{}\n{}:{}\n{}:{}\n{}",
+        namespace.len(),
+        namespace,
+        tenant.len(),
+        tenant,
+        id.len(),
+        id,
+        expires_at,
+    );
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts any key size");
+    mac.update(msg.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields_are_length_prefixed() {
+        let a = approval_signature("a:b", "c", "id", 1, b"secret");
+        let b = approval_signature("a", "b:c", "id", 1, b"secret");
+        assert_ne!(a, b);
+        assert_eq!(a.len(), 64);
+    }
+
+    #[test]
+    fn expiry_and_secret_are_signed() {
+        let sig = approval_signature("ns", "t", "id", 1, b"secret");
+        assert_ne!(sig, approval_signature("ns", "t", "id", 2, b"secret"));
+        assert_ne!(sig, approval_signature("ns", "t", "id", 1, b"other"));
+    }
+}
@@ -1,4 +1,18 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+
+use base64::Engine;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Hex-encoded SHA-256 of attachment content.
+///
+/// Identical bytes always produce the same hash, so it can be used to key
+/// attachment storage regardless of the attachment's `id` or `filename`.
+#[must_use]
+pub fn content_hash(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
 
 /// An attachment to be included with an action dispatch.
 ///
@@ -24,10 +38,26 @@ pub struct Attachment {
     pub data_base64: String,
 }
 
+impl Attachment {
+    /// Content hash of the decoded attachment bytes. See [`content_hash`].
+    ///
+    /// Returns `None` if `data_base64` is not valid `base64`.
+    #[must_use]
+    pub fn content_hash(&self) -> Option<String> {
+        base64::engine::general_purpose::STANDARD
+            .decode(&self.data_base64)
+            .ok()
+            .map(|data| content_hash(&data))
+    }
+}
+
 /// A fully resolved attachment with decoded binary data.
 ///
 /// Built by the gateway after decoding `base64` content. Passed to providers
 /// via [`DispatchContext`](crate::attachment::ResolvedAttachment).
+///
+/// `data` is shared: attachments with the same content resolved through one
+/// [`AttachmentStore`] point at a single copy of the bytes.
 #[derive(Debug, Clone)]
 pub struct ResolvedAttachment {
     /// User-set identifier.
@@ -38,8 +68,78 @@ pub struct ResolvedAttachment {
     pub filename: String,
     /// MIME content type.
     pub content_type: String,
+    /// Hex-encoded SHA-256 of `data`.
+    pub content_hash: String,
     /// Decoded binary content.
-    pub data: Vec<u8>,
+    pub data: Arc<[u8]>,
+}
+
+/// Content-addressable storage for decoded attachment bytes.
+///
+/// Bytes are keyed by their [`content_hash`], so the same file attached to
+/// many actions is held in memory once. The store only keeps weak
+/// references: content is released when the last [`ResolvedAttachment`]
+/// using it is dropped.
+///
+/// The store is an in-process cache, not durable storage. It is empty after
+/// a restart and is not shared between replicas, so deduplication only
+/// applies to attachments in flight on the same instance at the same time,
+/// and [`get`](Self::get) only finds content resolved by this instance.
+#[derive(Debug, Default)]
+pub struct AttachmentStore {
+    entries: Mutex<Entries>,
+}
+
+/// Minimum number of entries before dead references are pruned.
+const MIN_PRUNE_THRESHOLD: usize = 64;
+
+#[derive(Debug, Default)]
+struct Entries {
+    map: HashMap<String, Weak<[u8]>>,
+    /// Size at which the next prune runs. Doubled after each prune so the
+    /// cost is amortized over inserts.
+    prune_at: usize,
+}
+
+impl AttachmentStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store `data` and return its content hash with the shared bytes.
+    ///
+    /// If identical content is already stored, `data` is dropped and the
+    /// existing copy is returned.
+    pub fn insert(&self, data: Vec<u8>) -> (String, Arc<[u8]>) {
+        let hash = content_hash(&data);
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(existing) = entries.map.get(&hash).and_then(Weak::upgrade) {
+            return (hash, existing);
+        }
+        if entries.map.len() >= entries.prune_at {
+            entries.map.retain(|_, weak| weak.strong_count() > 0);
+            entries.prune_at = (entries.map.len() * 2).max(MIN_PRUNE_THRESHOLD);
+        }
+        let shared: Arc<[u8]> = data.into();
+        entries.map.insert(hash.clone(), Arc::downgrade(&shared));
+        (hash, shared)
+    }
+
+    /// Look up stored content by hash.
+    #[must_use]
+    pub fn get(&self, hash: &str) -> Option<Arc<[u8]>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .map
+            .get(hash)
+            .and_then(Weak::upgrade)
+    }
 }
 
 #[cfg(test)]
@@ -66,6 +166,64 @@ mod tests {
         assert_eq!(back.data_base64, "SGVsbG8gV29ybGQ=");
     }
 
+    fn hello(id: &str) -> Attachment {
+        Attachment {
+            id: id.into(),
+            name: "Hello File".into(),
+            filename: format!("{id}.txt"),
+            content_type: "text/plain".into(),
+            data_base64: "SGVsbG8gV29ybGQ=".into(),
+        }
+    }
+
+    #[test]
+    fn identical_attachments_share_a_hash() {
+        let a = hello("att-1");
+        let b = hello("att-2");
+        let hash = a.content_hash().unwrap();
+        assert_eq!(hash, b.content_hash().unwrap());
+        assert_eq!(hash, content_hash(b"Hello World"));
+
+        let mut other = hello("att-3");
+        other.data_base64 = "R29vZGJ5ZQ==".into();
+        assert_ne!(other.content_hash().unwrap(), hash);
+
+        let mut invalid = hello("att-4");
+        invalid.data_base64 = "not base64!".into();
+        assert!(invalid.content_hash().is_none());
+    }
+
+    #[test]
+    fn store_keeps_one_copy_of_identical_content() {
+        let store = AttachmentStore::new();
+        let (hash_a, a) = store.insert(b"Hello World".to_vec());
+        let (hash_b, b) = store.insert(b"Hello World".to_vec());
+
+        assert_eq!(hash_a, hash_b);
+        assert_eq!(hash_a, hello("att-1").content_hash().unwrap());
+        assert_eq!(&*a, b"Hello World");
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(Arc::ptr_eq(&store.get(&hash_a).unwrap(), &a));
+    }
+
+    #[test]
+    fn store_releases_content_when_unused() {
+        let store = AttachmentStore::new();
+        let (hash, data) = store.insert(b"Hello World".to_vec());
+        drop(data);
+        assert!(store.get(&hash).is_none());
+    }
+
+    #[test]
+    fn store_prunes_dead_entries_lazily() {
+        let store = AttachmentStore::new();
+        for i in 0..MIN_PRUNE_THRESHOLD * 4 {
+            drop(store.insert(i.to_le_bytes().to_vec()));
+        }
+        let entries = store.entries.lock().unwrap();
+        assert!(entries.map.len() <= MIN_PRUNE_THRESHOLD);
+    }
+
     #[test]
     fn empty_attachments_vec_deserializes_from_missing_field() {
         // Simulates backward compatibility: old payloads without "attachments"
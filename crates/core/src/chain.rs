@@ -502,6 +502,15 @@ pub struct ChainStepConfig {
     /// completes.
     #[serde(default)]
     pub delay_seconds: Option<u64>,
+    /// Optional limit in seconds on how long this step's provider call may
+    /// run.
+    ///
+    /// A call that exceeds it is abandoned and the step fails with a
+    /// `TIMEOUT` error, which feeds the step's `retry` policy and then its
+    /// `on_failure` policy like any other retryable failure. Only supported
+    /// on provider steps (including parallel sub-steps).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
     /// Optional list of branch conditions evaluated after this step completes.
     ///
     /// Conditions are evaluated in order; the first matching condition determines
@@ -568,6 +577,7 @@ impl ChainStepConfig {
             payload_template,
             on_failure: None,
             delay_seconds: None,
+            timeout_seconds: None,
             branches: Vec::new(),
             default_next: None,
             sub_chain: None,
@@ -589,6 +599,7 @@ impl ChainStepConfig {
             payload_template: serde_json::Value::Object(serde_json::Map::new()),
             on_failure: None,
             delay_seconds: None,
+            timeout_seconds: None,
             branches: Vec::new(),
             default_next: None,
             sub_chain: Some(sub_chain_name.into()),
@@ -610,6 +621,7 @@ impl ChainStepConfig {
             payload_template: serde_json::Value::Object(serde_json::Map::new()),
             on_failure: None,
             delay_seconds: None,
+            timeout_seconds: None,
             branches: Vec::new(),
             default_next: None,
             sub_chain: None,
@@ -739,6 +751,13 @@ impl ChainStepConfig {
         self
     }
 
+    /// Limit (in seconds) how long this step's provider call may run.
+    #[must_use]
+    pub fn with_timeout(mut self, seconds: u64) -> Self {
+        self.timeout_seconds = Some(seconds);
+        self
+    }
+
     /// Add a branch condition to this step.
     #[must_use]
     pub fn with_branch(mut self, condition: BranchCondition) -> Self {
@@ -798,6 +817,9 @@ pub struct ChainConfig {
     /// Optional notification target dispatched when the chain is cancelled.
     #[serde(default)]
     pub on_cancel: Option<ChainNotificationTarget>,
+    /// Free-form labels used to organize and filter chain definitions.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
 }
 
 fn default_chain_version() -> u64 {
@@ -815,6 +837,7 @@ impl ChainConfig {
             on_failure: ChainFailurePolicy::default(),
             timeout_seconds: None,
             on_cancel: None,
+            labels: HashMap::new(),
         }
     }
 
@@ -846,6 +869,13 @@ impl ChainConfig {
         self
     }
 
+    /// Attach a label to the chain definition.
+    #[must_use]
+    pub fn with_label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
     /// Build a map from step name to step index for quick lookups.
     #[must_use]
     pub fn step_index_map(&self) -> HashMap<String, usize> {
@@ -1079,6 +1109,32 @@ impl ChainConfig {
             }
         }
 
+        // Step timeouts bound an in-process provider call, so they only
+        // apply to provider steps and to the provider sub-steps of a
+        // parallel group.
+        let timed_steps = self.steps.iter().chain(
+            self.steps
+                .iter()
+                .filter_map(|s| s.parallel.as_deref())
+                .flat_map(|group| group.steps.iter()),
+        );
+        for step in timed_steps {
+            let Some(timeout) = step.timeout_seconds else {
+                continue;
+            };
+            if timeout == 0 {
+                errors.push(format!(
+                    "step `{}`: `timeout_seconds` must be >= 1",
+                    step.name
+                ));
+            } else if !matches!(step.kind(), StepKind::Provider) {
+                errors.push(format!(
+                    "step `{}` has `timeout_seconds` set; step timeouts are only supported on provider steps",
+                    step.name
+                ));
+            }
+        }
+
         // Check that all branch targets reference existing steps.
         for step in &self.steps {
             for branch in &step.branches {
@@ -1196,6 +1252,110 @@ impl ChainStatus {
     }
 }
 
+/// Default maximum sub-chain nesting depth.
+pub const DEFAULT_MAX_CHAIN_DEPTH: u32 = 8;
+
+/// Default maximum number of steps executed per root chain.
+pub const DEFAULT_MAX_CHAIN_STEPS: u64 = 1_000;
+
+/// Default maximum number of sub-steps in a single parallel group.
+pub const DEFAULT_MAX_PARALLEL_FANOUT: usize = 64;
+
+/// Global limits that stop runaway chain executions.
+///
+/// Limits apply to a root chain and every sub-chain it spawns. An execution
+/// that would exceed one is aborted with a [`ChainLimitExceeded`] reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainLimits {
+    /// Maximum sub-chain nesting depth. A root chain has depth 0.
+    pub max_depth: u32,
+    /// Maximum steps executed across a root chain and its sub-chains.
+    /// Each parallel sub-step counts as a step.
+    pub max_steps: u64,
+    /// Maximum number of sub-steps in a single parallel group.
+    pub max_parallel_fanout: usize,
+}
+
+impl Default for ChainLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_CHAIN_DEPTH,
+            max_steps: DEFAULT_MAX_CHAIN_STEPS,
+            max_parallel_fanout: DEFAULT_MAX_PARALLEL_FANOUT,
+        }
+    }
+}
+
+/// The chain limit an execution was aborted for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "limit", rename_all = "snake_case")]
+pub enum ChainLimitExceeded {
+    /// Starting a sub-chain would exceed the maximum nesting depth.
+    Depth {
+        /// The configured maximum depth.
+        max: u32,
+    },
+    /// The root chain executed more steps than allowed.
+    Steps {
+        /// The configured maximum number of steps.
+        max: u64,
+    },
+    /// A parallel group has more sub-steps than allowed.
+    Fanout {
+        /// The configured maximum fan-out.
+        max: usize,
+        /// The number of sub-steps in the offending group.
+        requested: usize,
+    },
+}
+
+impl std::fmt::Display for ChainLimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Depth { max } => write!(f, "sub-chain depth limit of {max} exceeded"),
+            Self::Steps { max } => write!(f, "chain step limit of {max} exceeded"),
+            Self::Fanout { max, requested } => write!(
+                f,
+                "parallel fan-out of {requested} exceeds the limit of {max}"
+            ),
+        }
+    }
+}
+
+impl ChainLimits {
+    /// Account for starting `step` in `state` and check it against the limits.
+    ///
+    /// Adds the step (or each sub-step of a parallel group) to
+    /// [`ChainState::steps_executed`] and returns the limit it breaks, if any.
+    pub fn check_step(
+        &self,
+        state: &mut ChainState,
+        step: &ChainStepConfig,
+    ) -> Option<ChainLimitExceeded> {
+        let steps = match step.kind() {
+            StepKind::Parallel(group) => {
+                if group.steps.len() > self.max_parallel_fanout {
+                    return Some(ChainLimitExceeded::Fanout {
+                        max: self.max_parallel_fanout,
+                        requested: group.steps.len(),
+                    });
+                }
+                group.steps.len() as u64
+            }
+            StepKind::SubChain(_) if state.depth >= self.max_depth => {
+                return Some(ChainLimitExceeded::Depth {
+                    max: self.max_depth,
+                });
+            }
+            _ => 1,
+        };
+        state.steps_executed = state.steps_executed.saturating_add(steps);
+        (state.steps_executed > self.max_steps).then_some(ChainLimitExceeded::Steps {
+            max: self.max_steps,
+        })
+    }
+}
+
 /// Result of a single chain step execution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StepResult {
@@ -1349,6 +1509,18 @@ pub struct ChainState {
     /// waiting status.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wait_state: Option<WaitState>,
+    /// Sub-chain nesting depth: 0 for a root chain, parent depth + 1 for a
+    /// sub-chain.
+    #[serde(default)]
+    pub depth: u32,
+    /// Steps started so far by the root chain and its sub-chains. Sub-chains
+    /// start from their parent's count and hand it back when they finish.
+    #[serde(default)]
+    pub steps_executed: u64,
+    /// Why the execution was aborted, when it exceeded a [`ChainLimits`]
+    /// limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit_exceeded: Option<ChainLimitExceeded>,
 }
 
 /// DFS coloring for cycle detection.
@@ -2013,6 +2185,30 @@ mod tests {
         assert!(errors.iter().any(|e| e.contains("mutually exclusive")));
     }
 
+    #[test]
+    fn validate_step_timeout() {
+        let ok = ChainConfig::new("ok").with_step(
+            ChainStepConfig::new("s1", "provider-a", "action", serde_json::json!({}))
+                .with_timeout(5),
+        );
+        assert!(ok.validate().is_empty());
+
+        let zero = ChainConfig::new("zero").with_step(
+            ChainStepConfig::new("s1", "provider-a", "action", serde_json::json!({}))
+                .with_timeout(0),
+        );
+        assert!(zero.validate().iter().any(|e| e.contains(">= 1")));
+
+        let sub_chain = ChainConfig::new("sub")
+            .with_step(ChainStepConfig::new_sub_chain("s1", "other").with_timeout(5));
+        assert!(
+            sub_chain
+                .validate()
+                .iter()
+                .any(|e| e.contains("only supported on provider steps"))
+        );
+    }
+
     #[test]
     fn validate_chain_graph_no_cycles() {
         let mut chains = HashMap::new();
@@ -34,6 +34,7 @@ impl std::fmt::Display for ComplianceMode {
 /// can be individually overridden after construction.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ComplianceConfig {
     /// The compliance mode in effect.
     #[serde(default)]
@@ -52,6 +53,12 @@ pub struct ComplianceConfig {
     /// each `(namespace, tenant)` pair.
     #[serde(default)]
     pub hash_chain: bool,
+
+    /// Whether actions are forbidden from opting out of payload encryption
+    /// at rest (see `Action::encrypt_payload`). When set, every payload is
+    /// encrypted whenever encryption is enabled.
+    #[serde(default)]
+    pub forbid_encryption_opt_out: bool,
 }
 
 impl Default for ComplianceConfig {
@@ -64,8 +71,9 @@ impl ComplianceConfig {
     /// Create a new `ComplianceConfig` with mode-appropriate defaults.
     ///
     /// - `None`: all features disabled
-    /// - `Soc2`: sync writes + hash chain
-    /// - `Hipaa`: sync writes + hash chain + immutable audit
+    /// - `Soc2`: sync writes + hash chain + no encryption opt-out
+    /// - `Hipaa`: sync writes + hash chain + immutable audit + no encryption
+    ///   opt-out
     #[must_use]
     pub fn new(mode: ComplianceMode) -> Self {
         match mode {
@@ -74,18 +82,21 @@ impl ComplianceConfig {
                 sync_audit_writes: false,
                 immutable_audit: false,
                 hash_chain: false,
+                forbid_encryption_opt_out: false,
             },
             ComplianceMode::Soc2 => Self {
                 mode: ComplianceMode::Soc2,
                 sync_audit_writes: true,
                 immutable_audit: false,
                 hash_chain: true,
+                forbid_encryption_opt_out: true,
             },
             ComplianceMode::Hipaa => Self {
                 mode: ComplianceMode::Hipaa,
                 sync_audit_writes: true,
                 immutable_audit: true,
                 hash_chain: true,
+                forbid_encryption_opt_out: true,
             },
         }
     }
@@ -110,6 +121,13 @@ impl ComplianceConfig {
         self.hash_chain = enabled;
         self
     }
+
+    /// Override whether actions may opt out of payload encryption.
+    #[must_use]
+    pub fn with_forbid_encryption_opt_out(mut self, forbid: bool) -> Self {
+        self.forbid_encryption_opt_out = forbid;
+        self
+    }
 }
 
 /// Result of verifying the integrity of an audit hash chain.
@@ -131,6 +149,23 @@ pub struct HashChainVerification {
     pub last_record_id: Option<String>,
 }
 
+/// Result of recomputing the hash of a single audit record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RecordHashVerification {
+    /// Action the record belongs to.
+    pub action_id: String,
+    /// ID of the verified record.
+    pub record_id: String,
+    /// Whether the stored hash matches the one computed from the record.
+    pub valid: bool,
+    /// The `record_hash` stored with the record, if it has one.
+    #[serde(default)]
+    pub stored_hash: Option<String>,
+    /// The hash recomputed from the record's canonical fields.
+    pub computed_hash: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,6 +202,7 @@ mod tests {
         assert!(!config.sync_audit_writes);
         assert!(!config.immutable_audit);
         assert!(!config.hash_chain);
+        assert!(!config.forbid_encryption_opt_out);
     }
 
     #[test]
@@ -176,6 +212,7 @@ mod tests {
         assert!(config.sync_audit_writes);
         assert!(!config.immutable_audit);
         assert!(config.hash_chain);
+        assert!(config.forbid_encryption_opt_out);
     }
 
     #[test]
@@ -185,6 +222,7 @@ mod tests {
         assert!(config.sync_audit_writes);
         assert!(config.immutable_audit);
         assert!(config.hash_chain);
+        assert!(config.forbid_encryption_opt_out);
     }
 
     #[test]
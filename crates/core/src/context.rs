@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 
 use crate::action::Action;
+use crate::feature_flags::FeatureFlags;
 
 /// Context provided to the rules engine when evaluating an action.
 #[derive(Debug, Clone)]
@@ -13,6 +14,9 @@ pub struct ActionContext {
     pub environment: HashMap<String, String>,
     /// Evaluation timestamp.
     pub timestamp: DateTime<Utc>,
+    /// Feature flags of the action's `(namespace, tenant)`, resolved at
+    /// evaluation time.
+    pub flags: FeatureFlags,
 }
 
 impl ActionContext {
@@ -23,6 +27,7 @@ impl ActionContext {
             action,
             environment: HashMap::new(),
             timestamp: Utc::now(),
+            flags: FeatureFlags::new(),
         }
     }
 
@@ -39,4 +44,17 @@ impl ActionContext {
         self.environment = env;
         self
     }
+
+    /// Set the tenant's feature flags.
+    #[must_use]
+    pub fn with_flags(mut self, flags: FeatureFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Whether the named feature flag is enabled for this action's tenant.
+    #[must_use]
+    pub fn flag_enabled(&self, name: &str) -> bool {
+        self.flags.enabled(name)
+    }
 }
@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+/// How dedup keys are normalized before comparison.
+///
+/// Every step is off by default, so keys compare byte-for-byte unless a
+/// namespace opts in. Steps run in a fixed order: trim, Unicode NFC, then
+/// lowercase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DedupKeyNormalization {
+    /// Strip leading and trailing whitespace.
+    pub trim: bool,
+    /// Convert to Unicode Normalization Form C, so precomposed and
+    /// decomposed forms of the same character compare equal.
+    pub unicode_nfc: bool,
+    /// Lowercase the key (Unicode-aware).
+    pub lowercase: bool,
+}
+
+impl DedupKeyNormalization {
+    /// Enable every normalization step.
+    #[must_use]
+    pub fn all() -> Self {
+        Self {
+            trim: true,
+            unicode_nfc: true,
+            lowercase: true,
+        }
+    }
+
+    /// Returns `true` if no step is enabled.
+    #[must_use]
+    pub fn is_noop(&self) -> bool {
+        !(self.trim || self.unicode_nfc || self.lowercase)
+    }
+
+    /// Normalize `key` according to the enabled steps.
+    ///
+    /// Borrows `key` unchanged when no step applies.
+    #[must_use]
+    pub fn apply<'a>(&self, key: &'a str) -> Cow<'a, str> {
+        let mut out = Cow::Borrowed(key);
+        if self.trim {
+            out = match out {
+                Cow::Borrowed(s) => Cow::Borrowed(s.trim()),
+                Cow::Owned(s) => Cow::Owned(s.trim().to_owned()),
+            };
+        }
+        if self.unicode_nfc {
+            out = Cow::Owned(out.nfc().collect());
+        }
+        if self.lowercase {
+            out = Cow::Owned(out.to_lowercase());
+        }
+        out
+    }
+}
+
+/// How a dedup rule's window is measured.
+///
+/// With [`Fixed`](Self::Fixed), the window opens at the first occurrence
+/// and closes `ttl` later, so an occurrence just past the boundary goes
+/// through even if the stream never paused. With
+/// [`Sliding`](Self::Sliding), every occurrence restarts the window, so an
+/// occurrence is a duplicate whenever any earlier one happened within the
+/// trailing `ttl`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupWindow {
+    /// Window anchored at the first occurrence (the TTL behavior).
+    #[default]
+    Fixed,
+    /// Window trailing the most recent occurrence.
+    Sliding,
+}
+
+impl DedupWindow {
+    /// Decide whether an occurrence at `now` is a duplicate.
+    ///
+    /// `marker` is the window anchor stored for the key, or `None` when no
+    /// occurrence is on record. Returns whether the occurrence is a
+    /// duplicate and the anchor to store afterwards.
+    #[must_use]
+    pub fn observe(
+        self,
+        marker: Option<DateTime<Utc>>,
+        ttl: Duration,
+        now: DateTime<Utc>,
+    ) -> (bool, DateTime<Utc>) {
+        let duplicate = marker.is_some_and(|m| now < m + ttl);
+        let next = match (self, marker) {
+            (Self::Fixed, Some(m)) if duplicate => m,
+            _ => now,
+        };
+        (duplicate, next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_leaves_key_untouched() {
+        let n = DedupKeyNormalization::default();
+        assert!(n.is_noop());
+        assert_eq!(n.apply(" Order-1 "), " Order-1 ");
+        assert!(matches!(n.apply("x"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn trim_and_lowercase() {
+        let n = DedupKeyNormalization {
+            trim: true,
+            lowercase: true,
+            ..Default::default()
+        };
+        assert_eq!(n.apply("Order-1 "), "order-1");
+        assert_eq!(n.apply("\tORDER-1\n"), "order-1");
+    }
+
+    #[test]
+    fn nfc_merges_decomposed_forms() {
+        let n = DedupKeyNormalization {
+            unicode_nfc: true,
+            ..Default::default()
+        };
+        // "é" precomposed vs "e" + combining acute accent.
+        assert_eq!(n.apply("caf\u{e9}"), n.apply("cafe\u{301}"));
+        assert_ne!(
+            DedupKeyNormalization::default().apply("caf\u{e9}"),
+            DedupKeyNormalization::default().apply("cafe\u{301}")
+        );
+    }
+
+    #[test]
+    fn deserializes_partial_config() {
+        let n: DedupKeyNormalization = serde_json::from_str(r#"{"lowercase": true}"#).unwrap();
+        assert!(n.lowercase);
+        assert!(!n.trim);
+        assert!(!n.unicode_nfc);
+    }
+
+    #[test]
+    fn fixed_window_reopens_at_boundary() {
+        let t0 = Utc::now();
+        let ttl = Duration::seconds(60);
+        let (dup, marker) = DedupWindow::Fixed.observe(None, ttl, t0);
+        assert!(!dup);
+        let (dup, marker) =
+            DedupWindow::Fixed.observe(Some(marker), ttl, t0 + Duration::seconds(59));
+        assert!(dup);
+        assert_eq!(marker, t0, "fixed window keeps its anchor");
+        let (dup, _) = DedupWindow::Fixed.observe(Some(marker), ttl, t0 + ttl);
+        assert!(!dup);
+    }
+
+    #[test]
+    fn sliding_window_trails_latest_occurrence() {
+        let t0 = Utc::now();
+        let ttl = Duration::seconds(60);
+        let (_, marker) = DedupWindow::Sliding.observe(None, ttl, t0);
+        let t1 = t0 + Duration::seconds(59);
+        let (dup, marker) = DedupWindow::Sliding.observe(Some(marker), ttl, t1);
+        assert!(dup);
+        assert_eq!(marker, t1);
+        let (dup, _) = DedupWindow::Sliding.observe(Some(marker), ttl, t0 + ttl);
+        assert!(dup, "within 60s of the previous occurrence");
+    }
+
+    #[test]
+    fn window_mode_serde() {
+        assert_eq!(
+            serde_json::to_string(&DedupWindow::Sliding).unwrap(),
+            r#""sliding""#
+        );
+        let w: DedupWindow = serde_json::from_str(r#""fixed""#).unwrap();
+        assert_eq!(w, DedupWindow::Fixed);
+    }
+}
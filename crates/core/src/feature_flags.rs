@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A set of named boolean feature flags.
+///
+/// Flags that are not present are disabled, so gating new behavior on a
+/// flag is safe for tenants that never heard of it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(transparent)]
+pub struct FeatureFlags(BTreeMap<String, bool>);
+
+impl FeatureFlags {
+    /// Create an empty flag set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the named flag is enabled. Unknown flags are disabled.
+    #[must_use]
+    pub fn enabled(&self, name: &str) -> bool {
+        self.0.get(name).copied().unwrap_or(false)
+    }
+
+    /// Set a flag, returning its previous value if it was present.
+    pub fn set(&mut self, name: impl Into<String>, enabled: bool) -> Option<bool> {
+        self.0.insert(name.into(), enabled)
+    }
+
+    /// Remove a flag, returning its value if it was present.
+    pub fn remove(&mut self, name: &str) -> Option<bool> {
+        self.0.remove(name)
+    }
+
+    /// Iterate over all flags in name order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.0
+            .iter()
+            .map(|(name, enabled)| (name.as_str(), *enabled))
+    }
+
+    /// Number of flags in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the set contains no flags.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<K: Into<String>> FromIterator<(K, bool)> for FeatureFlags {
+    fn from_iter<I: IntoIterator<Item = (K, bool)>>(iter: I) -> Self {
+        Self(iter.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+}
+
+/// The feature flags of one `(namespace, tenant)` scope.
+///
+/// Flags are resolved at evaluation time: rules see them as `flags.<name>`
+/// (or `enabled(flags, "<name>")`) and providers through their dispatch
+/// context, so toggling a flag changes behavior on the next dispatch without
+/// a redeploy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TenantFeatureFlags {
+    /// Namespace the flags apply to.
+    pub namespace: String,
+    /// Tenant the flags apply to.
+    pub tenant: String,
+    /// The flags, keyed by name.
+    #[serde(default)]
+    pub flags: FeatureFlags,
+    /// When a flag in this scope was last changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl TenantFeatureFlags {
+    /// Create an empty flag set for the given scope.
+    #[must_use]
+    pub fn new(namespace: impl Into<String>, tenant: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            tenant: tenant.into(),
+            flags: FeatureFlags::new(),
+            updated_at: None,
+        }
+    }
+}
+
+/// Validate a feature flag name.
+///
+/// Names must be non-empty, at most 128 characters, and use only ASCII
+/// alphanumerics, `_`, and `-`. Dots are rejected because a YAML condition
+/// `field: flags.a.b` would read as a nested field rather than flag `a.b`.
+pub fn validate_flag_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("flag name must not be empty".into());
+    }
+    if name.len() > 128 {
+        return Err("flag name exceeds 128 characters".into());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!(
+            "flag name '{name}' contains characters outside [a-zA-Z0-9_-]"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_flags_are_disabled() {
+        let mut flags = FeatureFlags::new();
+        assert!(!flags.enabled("new_sms_vendor"));
+
+        flags.set("new_sms_vendor", true);
+        flags.set("beta_digest", false);
+        assert!(flags.enabled("new_sms_vendor"));
+        assert!(!flags.enabled("beta_digest"));
+
+        assert_eq!(flags.remove("new_sms_vendor"), Some(true));
+        assert!(!flags.enabled("new_sms_vendor"));
+    }
+
+    #[test]
+    fn serializes_as_plain_map() {
+        let flags: FeatureFlags = [("a", true), ("b", false)].into_iter().collect();
+        let json = serde_json::to_value(&flags).unwrap();
+        assert_eq!(json, serde_json::json!({"a": true, "b": false}));
+
+        let record = TenantFeatureFlags {
+            flags,
+            ..TenantFeatureFlags::new("ns", "t1")
+        };
+        let back: TenantFeatureFlags =
+            serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+        assert_eq!(back, record);
+    }
+
+    #[test]
+    fn flag_name_validation() {
+        assert!(validate_flag_name("new_sms-vendor_v2").is_ok());
+        assert!(validate_flag_name("vendor.v2").is_err());
+        assert!(validate_flag_name("").is_err());
+        assert!(validate_flag_name("has space").is_err());
+        assert!(validate_flag_name(&"x".repeat(129)).is_err());
+    }
+}
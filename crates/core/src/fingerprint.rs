@@ -2,11 +2,98 @@
 //!
 //! Fingerprints are used to correlate related events across time,
 //! enabling state machine tracking and event grouping.
+//!
+//! The hashing scheme is versioned. Event state is stored under its
+//! fingerprint, so switching schemes would otherwise orphan every event that
+//! is still in flight. A [`FingerprintConfig`] names the active
+//! [`FingerprintVersion`] and, during a migration, a legacy version whose
+//! fingerprints are still looked up so existing events keep their state.
 
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
 use crate::Action;
 
+/// Version of the fingerprint hashing scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FingerprintVersion {
+    /// SHA-256 over `field=value;` for each field. Field values containing
+    /// `=` or `;` can collide with other field combinations.
+    #[default]
+    V1,
+    /// SHA-256 over length-prefixed field names and values, so no two
+    /// distinct field lists hash the same input.
+    V2,
+}
+
+impl FingerprintVersion {
+    /// The newest fingerprint version.
+    pub const LATEST: Self = Self::V2;
+}
+
+impl std::fmt::Display for FingerprintVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::V1 => f.write_str("v1"),
+            Self::V2 => f.write_str("v2"),
+        }
+    }
+}
+
+/// Which fingerprint versions are computed and looked up.
+///
+/// New events are always fingerprinted with `version`. While
+/// `dual_read_version` is set, callers should also look up state under that
+/// version's fingerprint and keep using it for events that already exist
+/// there, so events started before an upgrade don't fragment. Clear it once
+/// those events have resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FingerprintConfig {
+    /// The version used for new fingerprints.
+    #[serde(default)]
+    pub version: FingerprintVersion,
+
+    /// A previous version still honored for existing state.
+    #[serde(default)]
+    pub dual_read_version: Option<FingerprintVersion>,
+}
+
+impl FingerprintConfig {
+    /// Fingerprint with `version` and no migration window.
+    #[must_use]
+    pub fn new(version: FingerprintVersion) -> Self {
+        Self {
+            version,
+            dual_read_version: None,
+        }
+    }
+
+    /// Also honor fingerprints computed with `version` during a migration.
+    ///
+    /// Has no effect when `version` is the active version.
+    #[must_use]
+    pub fn with_dual_read(mut self, version: FingerprintVersion) -> Self {
+        self.dual_read_version = (version != self.version).then_some(version);
+        self
+    }
+
+    /// Fingerprint `action` with the active version.
+    #[must_use]
+    pub fn compute(&self, action: &Action, fields: &[String]) -> String {
+        compute_fingerprint_versioned(action, fields, self.version)
+    }
+
+    /// Fingerprint `action` with the dual-read version, if one is set.
+    #[must_use]
+    pub fn compute_dual_read(&self, action: &Action, fields: &[String]) -> Option<String> {
+        self.dual_read_version
+            .map(|version| compute_fingerprint_versioned(action, fields, version))
+    }
+}
+
 /// Compute a fingerprint for an action based on specified fields.
 ///
 /// The fingerprint is a hex-encoded SHA-256 hash of the concatenated
@@ -30,16 +117,46 @@ use crate::Action;
 /// let fp = compute_fingerprint(&action, &["action_type".to_string(), "tenant".to_string()]);
 /// assert!(!fp.is_empty());
 /// ```
+///
+/// This is the [`FingerprintVersion::V1`] scheme; use
+/// [`compute_fingerprint_versioned`] to pick another version.
 #[must_use]
 pub fn compute_fingerprint(action: &Action, fields: &[String]) -> String {
+    compute_fingerprint_versioned(action, fields, FingerprintVersion::V1)
+}
+
+/// Compute a fingerprint for an action with a specific scheme version.
+///
+/// Fingerprints of different versions never match, so state stored under one
+/// version is only found again through the same version.
+#[must_use]
+pub fn compute_fingerprint_versioned(
+    action: &Action,
+    fields: &[String],
+    version: FingerprintVersion,
+) -> String {
     let mut hasher = Sha256::new();
 
-    for field in fields {
-        let value = extract_field_value(action, field);
-        hasher.update(field.as_bytes());
-        hasher.update(b"=");
-        hasher.update(value.as_bytes());
-        hasher.update(b";");
+    match version {
+        FingerprintVersion::V1 => {
+            for field in fields {
+                let value = extract_field_value(action, field);
+                hasher.update(field.as_bytes());
+                hasher.update(b"=");
+                hasher.update(value.as_bytes());
+                hasher.update(b";");
+            }
+        }
+        FingerprintVersion::V2 => {
+            hasher.update(b"acteon-fingerprint-v2");
+            for field in fields {
+                let value = extract_field_value(action, field);
+                for part in [field.as_bytes(), value.as_bytes()] {
+                    hasher.update((part.len() as u64).to_be_bytes());
+                    hasher.update(part);
+                }
+            }
+        }
     }
 
     let result = hasher.finalize();
@@ -140,8 +257,12 @@ mod tests {
         labels.insert("cluster".to_string(), "prod-1".to_string());
         labels.insert("region".to_string(), "us-east".to_string());
 
-        let action = Action::new("ns", "t", "p", "alert", serde_json::json!({}))
-            .with_metadata(ActionMetadata { labels });
+        let action = Action::new("ns", "t", "p", "alert", serde_json::json!({})).with_metadata(
+            ActionMetadata {
+                labels,
+                ..Default::default()
+            },
+        );
 
         let fp = compute_fingerprint(
             &action,
@@ -200,6 +321,56 @@ mod tests {
         assert!(!fp.is_empty());
     }
 
+    #[test]
+    fn v2_separates_values_that_collide_under_v1() {
+        let fields = ["payload.a".to_string(), "payload.b".to_string()];
+        let first = Action::new(
+            "ns",
+            "t",
+            "p",
+            "alert",
+            serde_json::json!({"a": "x;payload.b=y"}),
+        );
+        let second = Action::new(
+            "ns",
+            "t",
+            "p",
+            "alert",
+            serde_json::json!({"a": "x", "b": "y;payload.b="}),
+        );
+
+        assert_eq!(
+            compute_fingerprint(&first, &fields),
+            compute_fingerprint(&second, &fields)
+        );
+        assert_ne!(
+            compute_fingerprint_versioned(&first, &fields, FingerprintVersion::V2),
+            compute_fingerprint_versioned(&second, &fields, FingerprintVersion::V2)
+        );
+        assert_eq!(
+            compute_fingerprint(&first, &fields),
+            compute_fingerprint_versioned(&first, &fields, FingerprintVersion::V1)
+        );
+    }
+
+    #[test]
+    fn dual_read_keeps_the_previous_fingerprint_reachable() {
+        let action = Action::new("ns", "t", "p", "alert", serde_json::json!({"host": "db-1"}));
+        let fields = ["action_type".to_string(), "payload.host".to_string()];
+        let v1 = FingerprintConfig::default().compute(&action, &fields);
+
+        let upgraded = FingerprintConfig::new(FingerprintVersion::V2);
+        assert_ne!(upgraded.compute(&action, &fields), v1);
+        assert_eq!(upgraded.compute_dual_read(&action, &fields), None);
+
+        let migrating = upgraded.with_dual_read(FingerprintVersion::V1);
+        assert_eq!(migrating.compute_dual_read(&action, &fields), Some(v1));
+
+        // Dual-reading the active version is a no-op.
+        let same = upgraded.with_dual_read(FingerprintVersion::V2);
+        assert_eq!(same.dual_read_version, None);
+    }
+
     #[test]
     fn extract_json_value_nested() {
         let json = serde_json::json!({
@@ -53,7 +53,8 @@ pub struct EventGroup {
     pub labels: HashMap<String, String>,
 
     /// Events contained in this group, capped at [`max_group_size`](Self::max_group_size).
-    /// When a new event arrives at capacity, the oldest event is dropped.
+    /// Kept sorted by [`GroupedEvent::ordering_key`], oldest first; when a
+    /// new event arrives at capacity, the oldest event is dropped.
     pub events: Vec<GroupedEvent>,
 
     /// When this group will be flushed and notification sent.
@@ -112,6 +113,12 @@ pub struct EventGroup {
     #[serde(default)]
     #[cfg_attr(feature = "openapi", schema(value_type = HashMap<String, String>))]
     pub trace_context: HashMap<String, String>,
+
+    /// `MiniJinja` source of the digest rendered when the group flushes,
+    /// captured at group creation from the rule's `template`. See
+    /// [`digest_context`](Self::digest_context) for the variables it sees.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest_template: Option<String>,
 }
 
 /// Maximum number of consecutive idle re-flushes before a persistent
@@ -164,6 +171,7 @@ impl EventGroup {
             max_group_size: default_max_group_size(),
             idle_flushes: 0,
             trace_context: HashMap::new(),
+            digest_template: None,
         }
     }
 
@@ -193,19 +201,31 @@ impl EventGroup {
         self
     }
 
-    /// Add an event to this group. When the group is at capacity, the
-    /// oldest event is dropped before the new one is appended. This
-    /// enforces the [`max_group_size`](Self::max_group_size) cap for
-    /// persistent groups that re-flush over time.
+    /// Add an event to this group, in [`GroupedEvent::ordering_key`]
+    /// order. When the group is over capacity afterwards, the oldest event
+    /// is dropped — which may be the new one if it arrived late with an
+    /// older `received_at`. This enforces the
+    /// [`max_group_size`](Self::max_group_size) cap for persistent groups
+    /// that re-flush over time.
     pub fn add_event(&mut self, event: GroupedEvent) {
-        if self.max_group_size > 0 && self.events.len() >= self.max_group_size {
-            // Drop the oldest event to make room.
+        let key = event.ordering_key();
+        let at = self.events.partition_point(|e| e.ordering_key() <= key);
+        self.events.insert(at, event);
+        if self.max_group_size > 0 && self.events.len() > self.max_group_size {
             self.events.remove(0);
         }
-        self.events.push(event);
         self.updated_at = Utc::now();
     }
 
+    /// Sort member events by [`GroupedEvent::ordering_key`].
+    ///
+    /// [`add_event`](Self::add_event) keeps events sorted; this restores
+    /// the order for groups deserialized from records written without it.
+    pub fn sort_events(&mut self) {
+        self.events
+            .sort_by(|a, b| a.ordering_key().cmp(&b.ordering_key()));
+    }
+
     /// Get the number of events in this group.
     #[must_use]
     pub fn size(&self) -> usize {
@@ -241,6 +261,49 @@ impl EventGroup {
         self.repeat_interval_seconds.is_some()
     }
 
+    /// Set the digest template rendered when this group flushes.
+    #[must_use]
+    pub fn with_digest_template(mut self, template: impl Into<String>) -> Self {
+        self.digest_template = Some(template.into());
+        self
+    }
+
+    /// Distinct values of a dot-separated payload field across member
+    /// events, in first-seen order. Events missing the field are skipped.
+    #[must_use]
+    pub fn distinct_values(&self, field: &str) -> Vec<serde_json::Value> {
+        let mut values: Vec<serde_json::Value> = Vec::new();
+        for event in &self.events {
+            let found = field
+                .split('.')
+                .try_fold(&event.payload, |current, segment| current.get(segment));
+            if let Some(value) = found
+                && !value.is_null()
+                && !values.contains(value)
+            {
+                values.push(value.clone());
+            }
+        }
+        values
+    }
+
+    /// Context exposed to the digest template at flush.
+    ///
+    /// Contains `group_id`, `group_key`, `labels`, `count` (number of
+    /// member events) and `events` (member payloads in
+    /// [`GroupedEvent::ordering_key`] order, oldest first).
+    #[must_use]
+    pub fn digest_context(&self) -> serde_json::Value {
+        let payloads: Vec<&serde_json::Value> = self.events.iter().map(|e| &e.payload).collect();
+        serde_json::json!({
+            "group_id": self.group_id,
+            "group_key": self.group_key,
+            "labels": self.labels,
+            "count": self.size(),
+            "events": payloads,
+        })
+    }
+
     /// Whether this persistent group has been idle (re-firing with no
     /// new events) long enough to be evicted from the cache.
     ///
@@ -287,6 +350,16 @@ impl GroupedEvent {
         }
     }
 
+    /// Key member events are ordered by within a group: arrival time, then
+    /// action ID to break ties between events received at the same instant.
+    ///
+    /// The order is total, so a group's events — and the digest rendered
+    /// from them — come out the same however they were inserted.
+    #[must_use]
+    pub fn ordering_key(&self) -> (DateTime<Utc>, &str) {
+        (self.received_at, self.action_id.as_str())
+    }
+
     /// Set the fingerprint.
     #[must_use]
     pub fn with_fingerprint(mut self, fingerprint: impl Into<String>) -> Self {
@@ -371,6 +444,54 @@ mod tests {
         assert_eq!(ids, vec!["action-2", "action-3"]);
     }
 
+    #[test]
+    fn events_are_ordered_by_arrival_then_action_id() {
+        let notify_at = Utc::now() + chrono::Duration::seconds(60);
+        let base = Utc::now();
+        let event = |id: &str, offset: i64| {
+            let mut event = GroupedEvent::new(
+                ActionId::new(id.to_string()),
+                serde_json::json!({ "id": id }),
+            );
+            event.received_at = base + chrono::Duration::seconds(offset);
+            event
+        };
+        let arrivals = [event("c", 2), event("b", 1), event("a", 2), event("z", 0)];
+
+        let mut group = EventGroup::new("group-1", "key-abc", notify_at);
+        for e in arrivals.iter().cloned() {
+            group.add_event(e);
+        }
+        let mut reversed = EventGroup::new("group-1", "key-abc", notify_at);
+        for e in arrivals.iter().rev().cloned() {
+            reversed.add_event(e);
+        }
+
+        let ids = |g: &EventGroup| -> Vec<String> {
+            g.events
+                .iter()
+                .map(|e| e.action_id.as_str().to_owned())
+                .collect()
+        };
+        assert_eq!(ids(&group), vec!["z", "b", "a", "c"]);
+        assert_eq!(ids(&reversed), ids(&group));
+        assert_eq!(group.digest_context(), reversed.digest_context());
+
+        // Groups stored out of order are sorted back, and at capacity the
+        // oldest event goes even if it is the one being added.
+        let mut stored = EventGroup::new("group-1", "key-abc", notify_at);
+        stored.events = arrivals.to_vec();
+        stored.sort_events();
+        assert_eq!(ids(&stored), ids(&group));
+
+        let mut capped =
+            EventGroup::new("group-1", "key-abc", notify_at).with_timing(60, 300, None, 2);
+        for e in arrivals {
+            capped.add_event(e);
+        }
+        assert_eq!(ids(&capped), vec!["a", "c"]);
+    }
+
     #[test]
     fn is_persistent_reflects_repeat_interval() {
         let notify_at = Utc::now() + chrono::Duration::seconds(60);
@@ -398,6 +519,28 @@ mod tests {
         assert!(!group.is_ready());
     }
 
+    #[test]
+    fn distinct_values_dedupes_nested_fields() {
+        let notify_at = Utc::now() + chrono::Duration::seconds(60);
+        let mut group = EventGroup::new("g", "k", notify_at);
+        for (i, host) in ["web-1", "web-2", "web-1"].iter().enumerate() {
+            group.add_event(GroupedEvent::new(
+                ActionId::new(format!("action-{i}")),
+                serde_json::json!({"resource": {"host": host}}),
+            ));
+        }
+        group.add_event(GroupedEvent::new(
+            ActionId::new("action-x".to_string()),
+            serde_json::json!({"other": true}),
+        ));
+
+        assert_eq!(
+            group.distinct_values("resource.host"),
+            vec![serde_json::json!("web-1"), serde_json::json!("web-2")]
+        );
+        assert_eq!(group.digest_context()["count"], 4);
+    }
+
     #[test]
     fn backward_compat_deserialize_old_group_record() {
         // An old EventGroup JSON without Phase-2 timing fields should
@@ -1,5 +1,6 @@
 pub mod action;
 pub mod analytics;
+pub mod approval;
 pub mod attachment;
 pub mod bus_agent;
 pub mod bus_agent_card;
@@ -18,13 +19,16 @@ pub mod circuit_breaker;
 pub mod compliance;
 pub mod context;
 pub mod coverage;
+pub mod dedup;
 pub mod enrichment;
 pub mod error;
 pub mod execution_history;
+pub mod feature_flags;
 pub mod fingerprint;
 pub mod group;
 pub mod key;
 pub mod outcome;
+pub mod preview;
 pub mod provider_health;
 pub mod quota;
 pub mod recurring;
@@ -40,12 +44,15 @@ pub mod types;
 pub mod worker_task;
 pub mod workflow;
 
-pub use action::{Action, ActionMetadata};
+pub use action::{
+    Action, ActionBuildError, ActionBuilder, ActionMetadata, AuditedAction, NamespaceDefaults,
+};
 pub use analytics::{
-    AnalyticsBucket, AnalyticsInterval, AnalyticsMetric, AnalyticsQuery, AnalyticsResponse,
-    AnalyticsTopEntry,
+    AnalyticsBucket, AnalyticsDrillError, AnalyticsInterval, AnalyticsMetric, AnalyticsQuery,
+    AnalyticsResponse, AnalyticsTopEntry,
 };
-pub use attachment::{Attachment, ResolvedAttachment};
+pub use approval::{ApprovalUrlParams, approval_signature};
+pub use attachment::{Attachment, AttachmentStore, ResolvedAttachment};
 pub use bus_agent::{
     Agent, AgentAdminState, AgentStatus, AgentValidationError, DEFAULT_AGENT_INBOX_SUFFIX,
     DEFAULT_HEARTBEAT_TTL_MS,
@@ -87,44 +94,59 @@ pub use bus_tool::{ToolCall, ToolEnvelopeValidationError, ToolResult, ToolResult
 pub use bus_topic::{Topic, TopicValidationError};
 pub use caller::Caller;
 pub use chain::{
-    BranchCondition, BranchOperator, ChainConfig, ChainFailurePolicy, ChainNotificationTarget,
-    ChainState, ChainStatus, ChainStepConfig, ParallelExecutionState, ParallelFailurePolicy,
-    ParallelJoinPolicy, ParallelStepGroup, ParallelSubStepStatus, SignalStepConfig,
-    StepFailurePolicy, StepKind, StepResult, TimerStepConfig, WaitState, WorkerStepConfig,
-    validate_chain_graph,
+    BranchCondition, BranchOperator, ChainConfig, ChainFailurePolicy, ChainLimitExceeded,
+    ChainLimits, ChainNotificationTarget, ChainState, ChainStatus, ChainStepConfig,
+    ParallelExecutionState, ParallelFailurePolicy, ParallelJoinPolicy, ParallelStepGroup,
+    ParallelSubStepStatus, SignalStepConfig, StepFailurePolicy, StepKind, StepResult,
+    TimerStepConfig, WaitState, WorkerStepConfig, validate_chain_graph,
 };
 pub use chain_dag::{DagEdge, DagNode, DagResponse};
 pub use circuit_breaker::{
     CircuitBreakerActionResponse, CircuitBreakerStatus, ListCircuitBreakersResponse,
 };
-pub use compliance::{ComplianceConfig, ComplianceMode, HashChainVerification};
+pub use compliance::{
+    ComplianceConfig, ComplianceMode, HashChainVerification, RecordHashVerification,
+};
 pub use context::ActionContext;
 pub use coverage::{
     CoverageAggregate, CoverageEntry, CoverageKey, CoverageQuery, CoverageReport, build_report,
 };
+pub use dedup::{DedupKeyNormalization, DedupWindow};
 pub use enrichment::{EnrichmentConfig, EnrichmentFailurePolicy, EnrichmentOutcome};
 pub use error::ActeonError;
 pub use execution_history::{
     ExecutionEvent, ExecutionEventType, ExecutionHistory, MAX_HISTORY_EVENTS,
 };
-pub use fingerprint::compute_fingerprint;
+pub use feature_flags::{FeatureFlags, TenantFeatureFlags, validate_flag_name};
+pub use fingerprint::{
+    FingerprintConfig, FingerprintVersion, compute_fingerprint, compute_fingerprint_versioned,
+};
 pub use group::{EventGroup, GroupState, GroupedEvent};
 pub use key::ActionKey;
-pub use outcome::{ActionError, ActionOutcome, ProviderResponse, ResponseStatus};
-pub use provider_health::{ListProviderHealthResponse, ProviderHealthStatus};
+pub use outcome::{
+    ActionError, ActionOutcome, FailoverAttempt, ProviderResponse, ReasonCode, ResponseStatus,
+};
+pub use preview::{AttachmentPreview, DispatchPreview};
+pub use provider_health::{
+    HealthClassifier, HealthHysteresis, ListProviderHealthResponse, ProviderHealthStatus,
+};
 pub use quota::{
-    MAX_POLICIES_PER_BUCKET, MAX_QUOTA_IDENTIFIER_LEN, MAX_WINDOW_SECONDS, OverageBehavior,
-    QuotaIdentifierError, QuotaPolicy, QuotaUsage, QuotaWindow, compute_window_boundaries,
-    quota_counter_key, validate_quota_scope_identifier,
+    MAX_POLICIES_PER_BUCKET, MAX_QUOTA_IDENTIFIER_LEN, MAX_WINDOW_SECONDS,
+    ORG_QUOTA_COUNTER_TENANT, OverageBehavior, QuotaIdentifierError, QuotaPolicy, QuotaUsage,
+    QuotaWindow, compute_window_boundaries, quota_counter_key, quota_counter_tenant,
+    validate_alert_thresholds, validate_quota_scope_identifier,
 };
 pub use recurring::{
-    CronValidationError, DEFAULT_MIN_INTERVAL_SECONDS, OverlapPolicy, RecurringAction,
-    RecurringActionTemplate, next_occurrence, occurrences_between, validate_cron_expr,
-    validate_min_interval, validate_timezone,
+    ConcurrencyPolicy, CronValidationError, DEFAULT_MIN_INTERVAL_SECONDS, OverlapPolicy,
+    RecurringAction, RecurringActionTemplate, next_occurrence, occurrences_between,
+    validate_cron_expr, validate_min_interval, validate_timezone,
 };
-pub use retention::RetentionPolicy;
+pub use retention::{AuditHold, RetentionPolicy};
 pub use silence::{MatchOp, Silence, SilenceMatcher};
-pub use state_machine::{StateMachineConfig, TimeoutConfig, TransitionConfig, TransitionEffects};
+pub use state_machine::{
+    EventTransition, MAX_EVENT_HISTORY, ResponseTransition, StateMachineConfig, TimeoutConfig,
+    TransitionConfig, TransitionEffects, TransitionTrigger, push_bounded_history,
+};
 pub use stream::{
     StreamEvent, StreamEventType, outcome_category, reconstruct_outcome, sanitize_outcome,
     timestamp_from_event_id,
@@ -135,7 +157,7 @@ pub use task_push::{
     PushDeliveryDlqEntry, TaskPushConfigValidationError, TaskPushNotificationConfig,
 };
 pub use template::{
-    Template, TemplateProfile, TemplateProfileField, validate_template_content,
+    Template, TemplateOutputType, TemplateProfile, TemplateProfileField, validate_template_content,
     validate_template_name,
 };
 pub use time_interval::{
@@ -19,6 +19,10 @@ pub enum ActionOutcome {
         original_provider: String,
         new_provider: String,
         response: ProviderResponse,
+        /// Providers that were tried and failed before `new_provider`
+        /// succeeded, in order. Empty unless a failover route was followed.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        failed_attempts: Vec<FailoverAttempt>,
     },
     /// Action was throttled — caller should retry later.
     Throttled {
@@ -150,6 +154,32 @@ pub enum ActionOutcome {
     },
 }
 
+impl ActionOutcome {
+    /// The stable reason code for an outcome that did not execute, if any.
+    ///
+    /// Set for [`Failed`](Self::Failed), [`CircuitOpen`](Self::CircuitOpen),
+    /// and [`QuotaExceeded`](Self::QuotaExceeded); `None` otherwise.
+    #[must_use]
+    pub fn reason_code(&self) -> Option<ReasonCode> {
+        match self {
+            Self::Failed(err) => Some(err.reason_code),
+            Self::CircuitOpen { .. } => Some(ReasonCode::CircuitOpen),
+            Self::QuotaExceeded { .. } => Some(ReasonCode::QuotaExceeded),
+            _ => None,
+        }
+    }
+
+    /// The provider's response body if the action was
+    /// [`Executed`](Self::Executed), `None` for every other outcome.
+    #[must_use]
+    pub fn into_response_body(self) -> Option<serde_json::Value> {
+        match self {
+            Self::Executed(response) => Some(response.body),
+            _ => None,
+        }
+    }
+}
+
 /// Response from a provider after executing an action.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
@@ -196,12 +226,79 @@ pub enum ResponseStatus {
     Partial,
 }
 
+/// Stable, machine-readable reason an action did not execute.
+///
+/// Unlike [`ActionError::message`], these values never change once
+/// released, so callers can match on them. They serialize as
+/// `SCREAMING_SNAKE_CASE` strings (e.g. `"PROVIDER_TIMEOUT"`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReasonCode {
+    /// The provider (or a chain step) did not respond in time.
+    ProviderTimeout,
+    /// The target provider is not registered.
+    ProviderNotFound,
+    /// The provider ran but reported a failure.
+    ProviderFailed,
+    /// The provider could not be reached.
+    ProviderUnavailable,
+    /// The provider rejected the request due to rate limiting.
+    ProviderRateLimited,
+    /// The provider is misconfigured.
+    ProviderMisconfigured,
+    /// The request or response could not be (de)serialized.
+    SerializationFailed,
+    /// The action itself was invalid (e.g. a malformed attachment).
+    ValidationFailed,
+    /// The tenant exceeded its quota.
+    QuotaExceeded,
+    /// The provider's circuit breaker is open.
+    CircuitOpen,
+    /// Retries stopped because the provider's retry budget was spent.
+    RetryBudgetExhausted,
+    /// No more specific code applies, or the record predates reason codes.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+impl ReasonCode {
+    /// The wire form of this code.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ProviderTimeout => "PROVIDER_TIMEOUT",
+            Self::ProviderNotFound => "PROVIDER_NOT_FOUND",
+            Self::ProviderFailed => "PROVIDER_FAILED",
+            Self::ProviderUnavailable => "PROVIDER_UNAVAILABLE",
+            Self::ProviderRateLimited => "PROVIDER_RATE_LIMITED",
+            Self::ProviderMisconfigured => "PROVIDER_MISCONFIGURED",
+            Self::SerializationFailed => "SERIALIZATION_FAILED",
+            Self::ValidationFailed => "VALIDATION_FAILED",
+            Self::QuotaExceeded => "QUOTA_EXCEEDED",
+            Self::CircuitOpen => "CIRCUIT_OPEN",
+            Self::RetryBudgetExhausted => "RETRY_BUDGET_EXHAUSTED",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl std::fmt::Display for ReasonCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Error detail when an action fails.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ActionError {
     /// Error code or category.
     pub code: String,
+    /// Stable reason code to match on programmatically.
+    #[serde(default)]
+    pub reason_code: ReasonCode,
     /// Human-readable message.
     pub message: String,
     /// Whether the error is retryable.
@@ -210,6 +307,17 @@ pub struct ActionError {
     pub attempts: u32,
 }
 
+/// A provider attempt that failed before a failover route found a provider
+/// that succeeded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct FailoverAttempt {
+    /// Provider that was tried.
+    pub provider: String,
+    /// Why it failed.
+    pub error: ActionError,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,6 +335,64 @@ mod tests {
         let _back: ActionOutcome = serde_json::from_str(&json).unwrap();
     }
 
+    #[test]
+    fn reason_code_serializes_as_stable_string() {
+        let json = serde_json::to_value(ReasonCode::ProviderTimeout).unwrap();
+        assert_eq!(json, "PROVIDER_TIMEOUT");
+        assert_eq!(ReasonCode::ProviderTimeout.as_str(), "PROVIDER_TIMEOUT");
+        let back: ReasonCode = serde_json::from_value(serde_json::json!("NOT_A_CODE")).unwrap();
+        assert_eq!(back, ReasonCode::Unknown);
+    }
+
+    #[test]
+    fn action_error_without_reason_code_defaults_to_unknown() {
+        let err: ActionError = serde_json::from_value(serde_json::json!({
+            "code": "TIMEOUT",
+            "message": "timed out",
+            "retryable": true,
+            "attempts": 1,
+        }))
+        .unwrap();
+        assert_eq!(err.reason_code, ReasonCode::Unknown);
+    }
+
+    #[test]
+    fn outcome_reason_codes() {
+        let circuit = ActionOutcome::CircuitOpen {
+            provider: "email".into(),
+            fallback_chain: vec![],
+        };
+        assert_eq!(circuit.reason_code(), Some(ReasonCode::CircuitOpen));
+        assert_eq!(ActionOutcome::Deduplicated.reason_code(), None);
+    }
+
+    #[test]
+    fn response_body_only_for_executed_outcomes() {
+        let executed = ActionOutcome::Executed(ProviderResponse::success(
+            serde_json::json!({"message_id": "m-1"}),
+        ));
+        assert_eq!(
+            executed.into_response_body(),
+            Some(serde_json::json!({"message_id": "m-1"}))
+        );
+
+        let suppressed = ActionOutcome::Suppressed {
+            rule: "block-spam".into(),
+        };
+        assert_eq!(suppressed.into_response_body(), None);
+
+        let failed: ActionOutcome = serde_json::from_value(serde_json::json!({
+            "Failed": {
+                "code": "PROVIDER_ERROR",
+                "message": "boom",
+                "retryable": false,
+                "attempts": 3,
+            }
+        }))
+        .unwrap();
+        assert_eq!(failed.into_response_body(), None);
+    }
+
     #[test]
     fn outcome_deduplicated() {
         let outcome = ActionOutcome::Deduplicated;
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// What a dispatch would send to its provider, computed without executing.
+///
+/// Unlike a dry-run (which only reports the verdict), a preview carries the
+/// final request body: the payload after enrichment, template rendering,
+/// and any `modify` rule patch, plus the resolved attachments.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DispatchPreview {
+    /// The verdict that would be applied (e.g. `"allow"`, `"reroute"`).
+    pub verdict: String,
+    /// Name of the matched rule, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matched_rule: Option<String>,
+    /// The provider that would receive the request. `None` when the verdict
+    /// never hands this payload to a provider as-is (deny, suppress, group,
+    /// chain).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// Every provider the action would reach, in order: `provider` itself,
+    /// or each distinct step provider when the verdict starts a chain.
+    /// Sub-chains invoked by a step are not expanded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub providers: Vec<String>,
+    /// The action type passed to the provider.
+    pub action_type: String,
+    /// The final payload the provider would receive.
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub payload: serde_json::Value,
+    /// Attachments as the provider would receive them (content omitted).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<AttachmentPreview>,
+}
+
+/// A resolved attachment in a [`DispatchPreview`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AttachmentPreview {
+    /// User-set identifier.
+    pub id: String,
+    /// Human-readable display name.
+    pub name: String,
+    /// Filename with extension.
+    pub filename: String,
+    /// MIME content type.
+    pub content_type: String,
+    /// Decoded size in bytes.
+    pub size_bytes: u64,
+}
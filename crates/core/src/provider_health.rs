@@ -30,10 +30,23 @@ pub struct ProviderHealthStatus {
     #[cfg_attr(feature = "utoipa", schema(example = "email"))]
     pub provider: String,
 
-    /// Whether the provider's health check passed.
+    /// Whether the provider is considered healthy.
+    ///
+    /// Classified with [`HealthHysteresis`], so a single failed or passed
+    /// check does not flip it on its own.
     #[cfg_attr(feature = "utoipa", schema(example = true))]
     pub healthy: bool,
 
+    /// Number of consecutive failed health checks, including the latest.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(example = 0))]
+    pub consecutive_failures: u32,
+
+    /// Number of consecutive passed health checks, including the latest.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(example = 12))]
+    pub consecutive_successes: u32,
+
     /// Health check error message (if unhealthy).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub health_check_error: Option<String>,
@@ -85,6 +98,101 @@ pub struct ProviderHealthStatus {
     pub last_error: Option<String>,
 }
 
+/// How many consecutive health check results it takes to change a
+/// provider's health.
+///
+/// Without hysteresis, a provider whose checks alternate between passing and
+/// failing flips state on every check and floods alerting with transitions.
+/// Both thresholds are clamped to at least `1`.
+///
+/// # Example
+///
+/// ```toml
+/// [provider_health]
+/// unhealthy_after = 3
+/// healthy_after = 2
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthHysteresis {
+    /// Consecutive failed checks before a healthy provider is marked
+    /// unhealthy.
+    pub unhealthy_after: u32,
+    /// Consecutive passed checks before an unhealthy provider is marked
+    /// healthy again.
+    pub healthy_after: u32,
+}
+
+impl Default for HealthHysteresis {
+    fn default() -> Self {
+        Self {
+            unhealthy_after: 3,
+            healthy_after: 2,
+        }
+    }
+}
+
+/// Tracks one provider's health check results and classifies its health
+/// with [`HealthHysteresis`].
+///
+/// A new classifier reports the provider as healthy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthClassifier {
+    hysteresis: HealthHysteresis,
+    healthy: bool,
+    consecutive_failures: u32,
+    consecutive_successes: u32,
+}
+
+impl HealthClassifier {
+    /// Create a classifier for a provider that starts out healthy.
+    #[must_use]
+    pub fn new(hysteresis: HealthHysteresis) -> Self {
+        Self {
+            hysteresis,
+            healthy: true,
+            consecutive_failures: 0,
+            consecutive_successes: 0,
+        }
+    }
+
+    /// Record the result of a health check and return the classified health.
+    pub fn observe(&mut self, passed: bool) -> bool {
+        if passed {
+            self.consecutive_successes = self.consecutive_successes.saturating_add(1);
+            self.consecutive_failures = 0;
+            if self.consecutive_successes >= self.hysteresis.healthy_after.max(1) {
+                self.healthy = true;
+            }
+        } else {
+            self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+            self.consecutive_successes = 0;
+            if self.consecutive_failures >= self.hysteresis.unhealthy_after.max(1) {
+                self.healthy = false;
+            }
+        }
+        self.healthy
+    }
+
+    /// The classified health.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.healthy
+    }
+
+    /// Consecutive failed checks, including the latest.
+    #[must_use]
+    pub fn consecutive_failures(&self) -> u32 {
+        self.consecutive_failures
+    }
+
+    /// Consecutive passed checks, including the latest.
+    #[must_use]
+    pub fn consecutive_successes(&self) -> u32 {
+        self.consecutive_successes
+    }
+}
+
 /// Response for listing provider health statuses.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
@@ -92,3 +200,52 @@ pub struct ListProviderHealthResponse {
     /// Per-provider health and performance data.
     pub providers: Vec<ProviderHealthStatus>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classifier() -> HealthClassifier {
+        HealthClassifier::new(HealthHysteresis {
+            unhealthy_after: 3,
+            healthy_after: 2,
+        })
+    }
+
+    #[test]
+    fn single_blip_does_not_flip_health() {
+        let mut health = classifier();
+        for passed in [true, false, true, false, false, true] {
+            assert!(health.observe(passed), "flipped on {passed}");
+        }
+        assert_eq!(health.consecutive_successes(), 1);
+        assert_eq!(health.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn sustained_breach_marks_unhealthy_until_sustained_recovery() {
+        let mut health = classifier();
+        assert!(health.observe(false));
+        assert!(health.observe(false));
+        assert!(!health.observe(false));
+        assert_eq!(health.consecutive_failures(), 3);
+
+        // One passing check is not enough to recover.
+        assert!(!health.observe(true));
+        assert!(!health.observe(false));
+        assert!(!health.observe(true));
+        assert!(health.observe(true));
+        assert_eq!(health.consecutive_successes(), 2);
+        assert_eq!(health.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn zero_thresholds_act_like_one() {
+        let mut health = HealthClassifier::new(HealthHysteresis {
+            unhealthy_after: 0,
+            healthy_after: 0,
+        });
+        assert!(!health.observe(false));
+        assert!(health.observe(true));
+    }
+}
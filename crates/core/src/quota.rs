@@ -104,6 +104,14 @@ impl std::fmt::Display for OverageBehavior {
 /// | `provider: Some("slack")` | Only dispatches to the `slack` provider | Provider bucket |
 /// | `principal: Some("svc-billing")` | Only dispatches by caller `svc-billing` | Principal bucket |
 /// | both set | Only dispatches by that caller to that provider | Per-(principal,provider) bucket |
+///
+/// Orthogonally, setting `org` turns a policy into an **org-level**
+/// quota. An org policy is a single record that defines both the org's
+/// limit and its membership: every tenant listed in `members` draws
+/// from one shared counter. Org policies live under the reserved
+/// [`ORG_QUOTA_COUNTER_TENANT`] tenant rather than any member's, and
+/// are evaluated alongside each member's own policies, so a dispatch
+/// is rejected when either the org or the tenant budget is exhausted.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct QuotaPolicy {
@@ -111,8 +119,17 @@ pub struct QuotaPolicy {
     pub id: String,
     /// Namespace this quota applies to.
     pub namespace: String,
-    /// Tenant this quota applies to.
+    /// Tenant this quota applies to. Org-level policies use
+    /// [`ORG_QUOTA_COUNTER_TENANT`].
     pub tenant: String,
+    /// Optional organization this quota is shared by. When
+    /// `Some(org)`, usage by any tenant in `members` is counted
+    /// against one org-wide counter.
+    #[serde(default)]
+    pub org: Option<String>,
+    /// Tenants that belong to `org`. Only set on org-level policies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<String>,
     /// Optional provider this quota applies to. When `None`, the
     /// policy is generic and applies to every dispatch for the
     /// `(namespace, tenant)` pair. When `Some(provider)`, only
@@ -160,6 +177,11 @@ pub struct QuotaPolicy {
     /// Arbitrary key-value labels for filtering and organization.
     #[serde(default)]
     pub labels: HashMap<String, String>,
+    /// Usage percentages (1–100) of `max_actions` at which a
+    /// `quota_threshold_crossed` stream event is emitted. Each
+    /// threshold fires at most once per window.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alert_thresholds: Vec<u8>,
 }
 
 fn default_enabled() -> bool {
@@ -254,6 +276,18 @@ impl QuotaPolicy {
         }
     }
 
+    /// Whether this policy applies to a dispatch by the given tenant.
+    /// A tenant policy applies to its own tenant; an org-level policy
+    /// applies to every tenant in `members`.
+    #[must_use]
+    pub fn applies_to_tenant(&self, tenant: &str) -> bool {
+        if self.org.is_some() {
+            self.members.iter().any(|m| m == tenant)
+        } else {
+            self.tenant == tenant
+        }
+    }
+
     /// Whether this policy applies to a dispatch made by the given
     /// caller. A policy without a `principal` scope (`principal:
     /// None`) applies to every caller. A principal-scoped policy
@@ -293,6 +327,32 @@ impl QuotaPolicy {
             .map_err(|e| format!("invalid namespace: {e}"))?;
         validate_quota_scope_identifier(&self.tenant)
             .map_err(|e| format!("invalid tenant: {e}"))?;
+        if let Some(ref o) = self.org {
+            validate_quota_scope_identifier(o).map_err(|e| format!("invalid org: {e}"))?;
+            if self.tenant != ORG_QUOTA_COUNTER_TENANT {
+                return Err(format!(
+                    "org-level policy must use tenant {ORG_QUOTA_COUNTER_TENANT}"
+                ));
+            }
+            if self.members.is_empty() {
+                return Err("org-level policy must list at least one member tenant".to_string());
+            }
+            for m in &self.members {
+                validate_quota_scope_identifier(m)
+                    .map_err(|e| format!("invalid member tenant: {e}"))?;
+                if m == ORG_QUOTA_COUNTER_TENANT {
+                    return Err(format!(
+                        "tenant {ORG_QUOTA_COUNTER_TENANT} cannot be an org member"
+                    ));
+                }
+            }
+        } else if self.tenant == ORG_QUOTA_COUNTER_TENANT {
+            return Err(format!(
+                "tenant {ORG_QUOTA_COUNTER_TENANT} is reserved for org-level policies"
+            ));
+        } else if !self.members.is_empty() {
+            return Err("members may only be set on org-level policies".to_string());
+        }
         if let Some(ref p) = self.provider {
             validate_quota_scope_identifier(p).map_err(|e| format!("invalid provider: {e}"))?;
         }
@@ -305,7 +365,42 @@ impl QuotaPolicy {
         if self.max_actions == 0 {
             return Err("max_actions must be greater than 0".to_string());
         }
-        Ok(())
+        validate_alert_thresholds(&self.alert_thresholds)
+    }
+
+    /// Alert thresholds crossed by the action that brought usage to
+    /// `used`.
+    ///
+    /// A threshold `t` is crossed exactly when `used` reaches
+    /// `ceil(max_actions * t / 100)`, so a counter that advances one
+    /// action at a time crosses each threshold once per window.
+    #[must_use]
+    pub fn crossed_thresholds(&self, used: u64) -> Vec<u8> {
+        self.alert_thresholds
+            .iter()
+            .copied()
+            .filter(|&t| threshold_count(self.max_actions, t) == used)
+            .collect()
+    }
+}
+
+/// Usage count at which `percent` of `max_actions` is reached
+/// (rounded up, and never below one action).
+fn threshold_count(max_actions: u64, percent: u8) -> u64 {
+    let count = (u128::from(max_actions) * u128::from(percent)).div_ceil(100);
+    u64::try_from(count).unwrap_or(u64::MAX).max(1)
+}
+
+/// Validate quota alert thresholds: each must be a percentage in
+/// `1..=100`.
+///
+/// # Errors
+///
+/// Returns an error string naming the first out-of-range threshold.
+pub fn validate_alert_thresholds(thresholds: &[u8]) -> Result<(), String> {
+    match thresholds.iter().find(|&&t| t == 0 || t > 100) {
+        Some(t) => Err(format!("alert threshold {t} must be between 1 and 100")),
+        None => Ok(()),
     }
 }
 
@@ -371,6 +466,25 @@ pub fn compute_window_boundaries(
     (window_start, window_end)
 }
 
+/// Reserved tenant under which org-level quota policies and their
+/// counters are stored.
+///
+/// Org policies are shared by several tenants, so they cannot live under
+/// any one tenant's key space.
+pub const ORG_QUOTA_COUNTER_TENANT: &str = "_org";
+
+/// Tenant component of the state key that holds a quota counter:
+/// the tenant itself, or [`ORG_QUOTA_COUNTER_TENANT`] for org-level
+/// policies.
+#[must_use]
+pub fn quota_counter_tenant<'a>(tenant: &'a str, org: Option<&str>) -> &'a str {
+    if org.is_some() {
+        ORG_QUOTA_COUNTER_TENANT
+    } else {
+        tenant
+    }
+}
+
 /// Build a state key suffix for a quota usage counter.
 ///
 /// Format: `{namespace}:{tenant}:{principal_or_*}:{provider_or_*}:{window_label}:{window_index}`
@@ -385,11 +499,16 @@ pub fn compute_window_boundaries(
 /// budget, and a per-caller policy does not interfere with the
 /// tenant-wide counter.
 ///
+/// For org-level policies (`org: Some(..)`) the tenant component is
+/// replaced by `~org:{org}`, so every tenant in the org shares one
+/// counter. The extra segment keeps org keys disjoint from tenant
+/// keys, since identifiers can never contain `:`.
+///
 /// Returns `None` instead of panicking when any of these would
 /// produce an unsafe or nonsensical key:
 ///
 /// * The window duration is zero (would divide by zero).
-/// * `namespace`, `tenant`, `provider`, or `principal` fails
+/// * `namespace`, `tenant`, `org`, `provider`, or `principal` fails
 ///   [`validate_quota_scope_identifier`] (e.g., contains the
 ///   reserved `:` separator that would enable cross-tenant key
 ///   collisions).
@@ -401,6 +520,7 @@ pub fn compute_window_boundaries(
 pub fn quota_counter_key(
     namespace: &str,
     tenant: &str,
+    org: Option<&str>,
     principal: Option<&str>,
     provider: Option<&str>,
     window: &QuotaWindow,
@@ -415,24 +535,23 @@ pub fn quota_counter_key(
     {
         return None;
     }
-    if let Some(p) = provider
-        && validate_quota_scope_identifier(p).is_err()
-    {
-        return None;
-    }
-    if let Some(p) = principal
-        && validate_quota_scope_identifier(p).is_err()
-    {
-        return None;
+    for scope in [org, provider, principal].into_iter().flatten() {
+        if validate_quota_scope_identifier(scope).is_err() {
+            return None;
+        }
     }
     let epoch = DateTime::UNIX_EPOCH;
     let elapsed = now.signed_duration_since(epoch);
     let window_secs = secs.cast_signed();
     let window_index = elapsed.num_seconds() / window_secs;
+    let owner = match org {
+        Some(o) => format!("~org:{o}"),
+        None => tenant.to_owned(),
+    };
     let principal_part = principal.unwrap_or("*");
     let provider_part = provider.unwrap_or("*");
     Some(format!(
-        "{namespace}:{tenant}:{principal_part}:{provider_part}:{}:{window_index}",
+        "{namespace}:{owner}:{principal_part}:{provider_part}:{}:{window_index}",
         window.label()
     ))
 }
@@ -542,6 +661,8 @@ mod tests {
             id: "q-001".into(),
             namespace: "notifications".into(),
             tenant: "tenant-1".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -553,6 +674,7 @@ mod tests {
             updated_at: Utc::now(),
             description: Some("Daily limit for tenant-1".into()),
             labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
         };
 
         let json = serde_json::to_string(&policy).unwrap();
@@ -654,24 +776,57 @@ mod tests {
         let now = chrono::DateTime::parse_from_rfc3339("2026-02-10T14:30:00Z")
             .unwrap()
             .with_timezone(&Utc);
-        let key =
-            quota_counter_key("ns", "tenant-1", None, None, &QuotaWindow::Hourly, &now).unwrap();
+        let key = quota_counter_key(
+            "ns",
+            "tenant-1",
+            None,
+            None,
+            None,
+            &QuotaWindow::Hourly,
+            &now,
+        )
+        .unwrap();
         // Generic (no principal, no provider) policies encode as "*:*".
         assert!(key.starts_with("ns:tenant-1:*:*:hourly:"));
         // Same time should produce the same key.
-        let key2 =
-            quota_counter_key("ns", "tenant-1", None, None, &QuotaWindow::Hourly, &now).unwrap();
+        let key2 = quota_counter_key(
+            "ns",
+            "tenant-1",
+            None,
+            None,
+            None,
+            &QuotaWindow::Hourly,
+            &now,
+        )
+        .unwrap();
         assert_eq!(key, key2);
     }
 
     #[test]
     fn quota_counter_key_per_provider_isolation() {
         let now = Utc::now();
-        let generic = quota_counter_key("ns", "t", None, None, &QuotaWindow::Hourly, &now).unwrap();
-        let slack =
-            quota_counter_key("ns", "t", None, Some("slack"), &QuotaWindow::Hourly, &now).unwrap();
-        let email =
-            quota_counter_key("ns", "t", None, Some("email"), &QuotaWindow::Hourly, &now).unwrap();
+        let generic =
+            quota_counter_key("ns", "t", None, None, None, &QuotaWindow::Hourly, &now).unwrap();
+        let slack = quota_counter_key(
+            "ns",
+            "t",
+            None,
+            None,
+            Some("slack"),
+            &QuotaWindow::Hourly,
+            &now,
+        )
+        .unwrap();
+        let email = quota_counter_key(
+            "ns",
+            "t",
+            None,
+            None,
+            Some("email"),
+            &QuotaWindow::Hourly,
+            &now,
+        )
+        .unwrap();
         // All three live in separate counter buckets.
         assert_ne!(generic, slack);
         assert_ne!(generic, email);
@@ -683,14 +838,32 @@ mod tests {
     #[test]
     fn quota_counter_key_per_principal_isolation() {
         let now = Utc::now();
-        let generic = quota_counter_key("ns", "t", None, None, &QuotaWindow::Hourly, &now).unwrap();
-        let alice =
-            quota_counter_key("ns", "t", Some("alice"), None, &QuotaWindow::Hourly, &now).unwrap();
-        let bob =
-            quota_counter_key("ns", "t", Some("bob"), None, &QuotaWindow::Hourly, &now).unwrap();
+        let generic =
+            quota_counter_key("ns", "t", None, None, None, &QuotaWindow::Hourly, &now).unwrap();
+        let alice = quota_counter_key(
+            "ns",
+            "t",
+            None,
+            Some("alice"),
+            None,
+            &QuotaWindow::Hourly,
+            &now,
+        )
+        .unwrap();
+        let bob = quota_counter_key(
+            "ns",
+            "t",
+            None,
+            Some("bob"),
+            None,
+            &QuotaWindow::Hourly,
+            &now,
+        )
+        .unwrap();
         let alice_slack = quota_counter_key(
             "ns",
             "t",
+            None,
             Some("alice"),
             Some("slack"),
             &QuotaWindow::Hourly,
@@ -705,11 +878,59 @@ mod tests {
         assert!(alice_slack.contains(":alice:slack:"));
     }
 
+    #[test]
+    fn quota_counter_key_org_shared_across_tenants() {
+        let now = Utc::now();
+        let a = quota_counter_key(
+            "ns",
+            "a",
+            Some("acme"),
+            None,
+            None,
+            &QuotaWindow::Hourly,
+            &now,
+        )
+        .unwrap();
+        let b = quota_counter_key(
+            "ns",
+            "b",
+            Some("acme"),
+            None,
+            None,
+            &QuotaWindow::Hourly,
+            &now,
+        )
+        .unwrap();
+        let tenant_a =
+            quota_counter_key("ns", "a", None, None, None, &QuotaWindow::Hourly, &now).unwrap();
+        // Tenants in the same org share one counter, disjoint from their own.
+        assert_eq!(a, b);
+        assert!(a.starts_with("ns:~org:acme:*:*:hourly:"));
+        assert_ne!(a, tenant_a);
+        assert!(
+            quota_counter_key(
+                "ns",
+                "a",
+                Some("ac:me"),
+                None,
+                None,
+                &QuotaWindow::Hourly,
+                &now
+            )
+            .is_none()
+        );
+        assert_eq!(
+            quota_counter_tenant("a", Some("acme")),
+            ORG_QUOTA_COUNTER_TENANT
+        );
+        assert_eq!(quota_counter_tenant("a", None), "a");
+    }
+
     #[test]
     fn quota_counter_key_different_windows() {
         let now = Utc::now();
-        let k1 = quota_counter_key("ns", "t", None, None, &QuotaWindow::Hourly, &now);
-        let k2 = quota_counter_key("ns", "t", None, None, &QuotaWindow::Daily, &now);
+        let k1 = quota_counter_key("ns", "t", None, None, None, &QuotaWindow::Hourly, &now);
+        let k2 = quota_counter_key("ns", "t", None, None, None, &QuotaWindow::Daily, &now);
         assert_ne!(k1, k2);
     }
 
@@ -722,6 +943,7 @@ mod tests {
                 "t",
                 None,
                 None,
+                None,
                 &QuotaWindow::Custom { seconds: 0 },
                 &now
             )
@@ -741,6 +963,7 @@ mod tests {
                 "acme",
                 "t",
                 None,
+                None,
                 Some("slack:acme:*"),
                 &QuotaWindow::Hourly,
                 &now
@@ -751,6 +974,7 @@ mod tests {
             quota_counter_key(
                 "acme",
                 "t",
+                None,
                 Some("alice:rogue"),
                 None,
                 &QuotaWindow::Hourly,
@@ -759,10 +983,28 @@ mod tests {
             .is_none()
         );
         assert!(
-            quota_counter_key("ns:rogue", "t", None, None, &QuotaWindow::Hourly, &now).is_none()
+            quota_counter_key(
+                "ns:rogue",
+                "t",
+                None,
+                None,
+                None,
+                &QuotaWindow::Hourly,
+                &now
+            )
+            .is_none()
         );
         assert!(
-            quota_counter_key("ns", "t:rogue", None, None, &QuotaWindow::Hourly, &now).is_none()
+            quota_counter_key(
+                "ns",
+                "t:rogue",
+                None,
+                None,
+                None,
+                &QuotaWindow::Hourly,
+                &now
+            )
+            .is_none()
         );
     }
 
@@ -815,6 +1057,8 @@ mod tests {
             id: "q-1".into(),
             namespace: "ns".into(),
             tenant: "t".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -826,6 +1070,7 @@ mod tests {
             updated_at: Utc::now(),
             description: None,
             labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
         };
         assert!(policy.validate_scope().is_ok());
 
@@ -849,6 +1094,49 @@ mod tests {
         assert!(policy.validate_scope().is_err());
     }
 
+    #[test]
+    fn org_policy_defines_membership_in_one_record() {
+        let mut policy = QuotaPolicy {
+            id: "q-org".into(),
+            namespace: "ns".into(),
+            tenant: ORG_QUOTA_COUNTER_TENANT.into(),
+            org: Some("acme".into()),
+            members: vec!["a".into(), "b".into()],
+            provider: None,
+            principal: None,
+            per_principal: false,
+            max_actions: 100,
+            window: QuotaWindow::Hourly,
+            overage_behavior: OverageBehavior::Block,
+            enabled: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            description: None,
+            labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
+        };
+        assert!(policy.validate_scope().is_ok());
+        assert!(policy.applies_to_tenant("a"));
+        assert!(policy.applies_to_tenant("b"));
+        assert!(!policy.applies_to_tenant("c"));
+
+        // An org policy must live under the reserved org tenant.
+        policy.tenant = "a".into();
+        assert!(policy.validate_scope().is_err());
+
+        // An org without members is rejected.
+        policy.tenant = ORG_QUOTA_COUNTER_TENANT.into();
+        policy.members.clear();
+        assert!(policy.validate_scope().is_err());
+
+        // Tenant policies cannot claim members or the reserved tenant.
+        policy.org = None;
+        policy.members = vec!["a".into()];
+        assert!(policy.validate_scope().is_err());
+        policy.members.clear();
+        assert!(policy.validate_scope().is_err());
+    }
+
     #[test]
     fn quota_window_custom_serde() {
         let window = QuotaWindow::Custom { seconds: 7200 };
@@ -866,6 +1154,8 @@ mod tests {
             id: "q-full".into(),
             namespace: "ns".into(),
             tenant: "t".into(),
+            org: None,
+            members: Vec::new(),
             provider: Some("slack".into()),
             principal: None,
             per_principal: false,
@@ -879,6 +1169,7 @@ mod tests {
             updated_at: Utc::now(),
             description: Some("Premium tier monthly quota".into()),
             labels,
+            alert_thresholds: Vec::new(),
         };
 
         let json = serde_json::to_string_pretty(&policy).unwrap();
@@ -898,6 +1189,8 @@ mod tests {
             id: "q-generic".into(),
             namespace: "ns".into(),
             tenant: "t".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -909,6 +1202,7 @@ mod tests {
             updated_at: Utc::now(),
             description: None,
             labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
         };
         // Generic policy applies to every provider.
         assert!(generic.applies_to_provider("slack"));
@@ -948,6 +1242,8 @@ mod tests {
             id: "q-p".into(),
             namespace: "ns".into(),
             tenant: "t".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -959,6 +1255,7 @@ mod tests {
             updated_at: Utc::now(),
             description: None,
             labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
         };
         // Unscoped policy matches every caller, including anonymous.
         assert!(policy.applies_to_principal(Some("alice")));
@@ -999,12 +1296,44 @@ mod tests {
         assert!(policy.applies_to_principal(None));
     }
 
+    #[test]
+    fn crossed_thresholds_round_up_and_validate() {
+        let mut policy: QuotaPolicy = serde_json::from_str(
+            r#"{
+                "id": "q-alert",
+                "namespace": "ns",
+                "tenant": "t",
+                "max_actions": 7,
+                "window": "daily",
+                "overage_behavior": "block",
+                "created_at": "2026-01-01T00:00:00Z",
+                "updated_at": "2026-01-01T00:00:00Z",
+                "alert_thresholds": [50, 80, 100]
+            }"#,
+        )
+        .unwrap();
+        // ceil(7 * 0.5) = 4, ceil(7 * 0.8) = 6, 7 * 1.0 = 7.
+        let hits: Vec<(u64, Vec<u8>)> = (1..=8)
+            .map(|used| (used, policy.crossed_thresholds(used)))
+            .filter(|(_, t)| !t.is_empty())
+            .collect();
+        assert_eq!(hits, [(4, vec![50]), (6, vec![80]), (7, vec![100])]);
+        assert!(policy.validate_scope().is_ok());
+
+        policy.alert_thresholds = vec![80, 101];
+        assert!(policy.validate_scope().is_err());
+        policy.alert_thresholds = vec![0];
+        assert!(policy.validate_scope().is_err());
+    }
+
     #[test]
     fn quota_policy_disabled() {
         let policy = QuotaPolicy {
             id: "q-dis".into(),
             namespace: "ns".into(),
             tenant: "t".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -1016,6 +1345,7 @@ mod tests {
             updated_at: Utc::now(),
             description: None,
             labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
         };
 
         let json = serde_json::to_string(&policy).unwrap();
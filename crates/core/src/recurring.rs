@@ -3,12 +3,12 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-/// What to do when a new occurrence fires while the execution spawned by the
-/// previous occurrence is still running.
+/// What to do when a new occurrence fires while the chain execution spawned
+/// by the previous occurrence is still running.
 ///
 /// Overlap is only trackable when the dispatched action starts a chain
-/// execution (the chain ID is recorded on the recurring action); plain
-/// provider dispatches complete synchronously and never overlap.
+/// execution (the chain ID is recorded on the recurring action). A previous
+/// dispatch that is still in flight is governed by [`ConcurrencyPolicy`].
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
@@ -20,6 +20,31 @@ pub enum OverlapPolicy {
     Skip,
     /// Cancel the still-running previous execution, then dispatch.
     CancelOther,
+    /// Wait (bounded) for the previous execution to finish, then dispatch.
+    /// The occurrence is skipped if the previous one is still running when
+    /// the wait times out.
+    Queue,
+}
+
+/// What to do when a new occurrence fires while the previous occurrence's
+/// dispatch is still in flight (a slow provider on a tight schedule).
+///
+/// Enforced with a distributed lock keyed by the recurring ID, so the guard
+/// holds across server instances.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum ConcurrencyPolicy {
+    /// Dispatch every occurrence, even while the previous one is in flight.
+    Allow,
+    /// Skip the new occurrence while the previous dispatch is in flight
+    /// (default).
+    #[default]
+    Skip,
+    /// Wait (bounded) for the previous dispatch to finish, then dispatch.
+    /// The occurrence is skipped if the previous one is still in flight when
+    /// the wait times out.
+    Queue,
 }
 
 /// Template for the action dispatched on each cron tick.
@@ -116,12 +141,90 @@ pub struct RecurringAction {
     /// execution spawned by the previous occurrence is still running.
     #[serde(default)]
     pub overlap_policy: OverlapPolicy,
+    /// Concurrency policy applied when a new occurrence fires while the
+    /// previous occurrence's dispatch is still in flight.
+    #[serde(default)]
+    pub concurrency_policy: ConcurrencyPolicy,
     /// Chain execution ID spawned by the most recent occurrence, when the
     /// dispatched action started a chain. Used to enforce `overlap_policy`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_execution_id: Option<String>,
 }
 
+impl RecurringAction {
+    /// Render the payload for the occurrence scheduled at `execution_time`.
+    ///
+    /// String values in the template payload may reference the occurrence
+    /// with `{{ name }}` placeholders (whitespace inside the braces is
+    /// optional). Date parts are computed in the action's `timezone`:
+    ///
+    /// - `exec_time` -- RFC 3339 timestamp with the timezone's offset
+    /// - `exec_timestamp` -- Unix timestamp in seconds
+    /// - `exec_date` -- `YYYY-MM-DD`
+    /// - `exec_year`, `exec_month`, `exec_day`, `exec_hour` -- zero-padded
+    /// - `exec_weekday` -- English weekday name, e.g. `Monday`
+    ///
+    /// Unknown placeholders are left untouched so payload templates rendered
+    /// later in the pipeline keep working. Object keys are never rewritten.
+    #[must_use]
+    pub fn render_payload(&self, execution_time: &DateTime<Utc>) -> serde_json::Value {
+        let tz = validate_timezone(&self.timezone).unwrap_or(chrono_tz::UTC);
+        let local = execution_time.with_timezone(&tz);
+        let vars = [
+            ("exec_time", local.to_rfc3339()),
+            ("exec_timestamp", execution_time.timestamp().to_string()),
+            ("exec_date", local.format("%Y-%m-%d").to_string()),
+            ("exec_year", local.format("%Y").to_string()),
+            ("exec_month", local.format("%m").to_string()),
+            ("exec_day", local.format("%d").to_string()),
+            ("exec_hour", local.format("%H").to_string()),
+            ("exec_weekday", local.format("%A").to_string()),
+        ];
+        let mut payload = self.action_template.payload.clone();
+        substitute_placeholders(&mut payload, &vars);
+        payload
+    }
+}
+
+/// Replace known `{{ name }}` placeholders in every string within `value`.
+fn substitute_placeholders(value: &mut serde_json::Value, vars: &[(&str, String)]) {
+    match value {
+        serde_json::Value::String(text) if text.contains("{{") => {
+            *text = substitute_str(text, vars);
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                substitute_placeholders(item, vars);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for item in map.values_mut() {
+                substitute_placeholders(item, vars);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn substitute_str(text: &str, vars: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + len].trim();
+        out.push_str(&rest[..start]);
+        match vars.iter().find(|(var, _)| *var == name) {
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(&rest[start..start + len + 4]),
+        }
+        rest = &rest[start + len + 4..];
+    }
+    out.push_str(rest);
+    out
+}
+
 fn default_timezone() -> String {
     "UTC".to_owned()
 }
@@ -294,6 +397,19 @@ mod tests {
         );
         let back: OverlapPolicy = serde_json::from_str("\"skip\"").unwrap();
         assert_eq!(back, OverlapPolicy::Skip);
+        let back: OverlapPolicy = serde_json::from_str("\"queue\"").unwrap();
+        assert_eq!(back, OverlapPolicy::Queue);
+    }
+
+    #[test]
+    fn concurrency_policy_serde_and_default() {
+        assert_eq!(ConcurrencyPolicy::default(), ConcurrencyPolicy::Skip);
+        assert_eq!(
+            serde_json::to_string(&ConcurrencyPolicy::Allow).unwrap(),
+            "\"allow\""
+        );
+        let back: ConcurrencyPolicy = serde_json::from_str("\"queue\"").unwrap();
+        assert_eq!(back, ConcurrencyPolicy::Queue);
     }
 
     #[test]
@@ -314,6 +430,7 @@ mod tests {
         });
         let rec: RecurringAction = serde_json::from_value(json).unwrap();
         assert_eq!(rec.overlap_policy, OverlapPolicy::AllowAll);
+        assert_eq!(rec.concurrency_policy, ConcurrencyPolicy::Skip);
         assert!(rec.last_execution_id.is_none());
     }
 
@@ -416,6 +533,7 @@ mod tests {
             description: Some("Test recurring action".into()),
             labels: HashMap::new(),
             overlap_policy: OverlapPolicy::default(),
+            concurrency_policy: ConcurrencyPolicy::default(),
             last_execution_id: None,
         };
 
@@ -669,6 +787,7 @@ mod tests {
             description: Some("Weekday morning digest for engineering".into()),
             labels,
             overlap_policy: OverlapPolicy::default(),
+            concurrency_policy: ConcurrencyPolicy::default(),
             last_execution_id: None,
         };
 
@@ -759,6 +878,7 @@ mod tests {
             description: None,
             labels: HashMap::new(),
             overlap_policy: OverlapPolicy::default(),
+            concurrency_policy: ConcurrencyPolicy::default(),
             last_execution_id: None,
         };
 
@@ -794,6 +914,7 @@ mod tests {
             description: None,
             labels: HashMap::new(),
             overlap_policy: OverlapPolicy::default(),
+            concurrency_policy: ConcurrencyPolicy::default(),
             last_execution_id: None,
         };
 
@@ -802,6 +923,46 @@ mod tests {
         assert_eq!(back.execution_count, u64::MAX);
     }
 
+    #[test]
+    fn render_payload_injects_execution_time_in_action_timezone() {
+        let mut rec: RecurringAction = serde_json::from_value(serde_json::json!({
+            "id": "rec-1",
+            "namespace": "ns",
+            "tenant": "t",
+            "cron_expr": "0 9 * * *",
+            "timezone": "Asia/Tokyo",
+            "action_template": {
+                "provider": "email",
+                "action_type": "digest",
+                "payload": {
+                    "subject": "Digest for {{ exec_date }} ({{exec_weekday}})",
+                    "at": ["{{exec_time}}", "{{ exec_hour }}h", 7],
+                    "body": "{{ name }} keeps {{unclosed",
+                    "{{ exec_date }}": true
+                }
+            },
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        // 20:00 UTC on Sunday is already Monday morning in Tokyo.
+        let at = "2026-03-01T20:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let payload = rec.render_payload(&at);
+        assert_eq!(payload["subject"], "Digest for 2026-03-02 (Monday)");
+        assert_eq!(payload["at"][0], "2026-03-02T05:00:00+09:00");
+        assert_eq!(payload["at"][1], "05h");
+        assert_eq!(payload["at"][2], 7);
+        assert_eq!(payload["body"], "{{ name }} keeps {{unclosed");
+        assert_eq!(payload["{{ exec_date }}"], true);
+
+        rec.timezone = "UTC".into();
+        assert_eq!(
+            rec.render_payload(&at)["subject"],
+            "Digest for 2026-03-01 (Sunday)"
+        );
+    }
+
     #[test]
     fn recurring_action_complex_payload() {
         let payload = serde_json::json!({
@@ -857,6 +1018,7 @@ mod tests {
             description: None,
             labels: labels.clone(),
             overlap_policy: OverlapPolicy::default(),
+            concurrency_policy: ConcurrencyPolicy::default(),
             last_execution_id: None,
         };
 
@@ -53,6 +53,28 @@ pub struct RetentionPolicy {
     pub labels: HashMap<String, String>,
 }
 
+/// A legal hold placed on a single action's audit records.
+///
+/// Held records never expire, regardless of the tenant's retention policy.
+/// Unlike [`RetentionPolicy::compliance_hold`], which covers a whole tenant,
+/// a hold targets one action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AuditHold {
+    /// Action whose audit records are held.
+    pub action_id: String,
+    /// Namespace of the held records.
+    pub namespace: String,
+    /// Tenant of the held records.
+    pub tenant: String,
+    /// Why the records are held (e.g. a case or ticket reference).
+    pub reason: String,
+    /// Caller that placed the hold.
+    pub held_by: String,
+    /// When the hold was placed.
+    pub held_at: DateTime<Utc>,
+}
+
 fn default_enabled() -> bool {
     true
 }
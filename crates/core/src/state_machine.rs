@@ -6,8 +6,14 @@
 
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// Maximum number of transitions retained in an event's history.
+///
+/// Older entries are dropped once the bound is reached.
+pub const MAX_EVENT_HISTORY: usize = 100;
+
 /// Configuration for a state machine.
 ///
 /// State machines are identified by name and define the valid states
@@ -30,6 +36,10 @@ pub struct StateMachineConfig {
     /// Automatic timeouts that trigger transitions.
     #[serde(default)]
     pub timeouts: Vec<TimeoutConfig>,
+
+    /// Provider response fields that drive transitions after execution.
+    #[serde(default)]
+    pub response_transitions: Vec<ResponseTransition>,
 }
 
 impl StateMachineConfig {
@@ -43,6 +53,7 @@ impl StateMachineConfig {
             states: vec![initial],
             transitions: Vec::new(),
             timeouts: Vec::new(),
+            response_transitions: Vec::new(),
         }
     }
 
@@ -70,6 +81,13 @@ impl StateMachineConfig {
         self
     }
 
+    /// Add a provider-response-driven transition to the machine.
+    #[must_use]
+    pub fn with_response_transition(mut self, transition: ResponseTransition) -> Self {
+        self.response_transitions.push(transition);
+        self
+    }
+
     /// Check if a state is valid in this machine.
     #[must_use]
     pub fn is_valid_state(&self, state: &str) -> bool {
@@ -97,6 +115,28 @@ impl StateMachineConfig {
     pub fn get_timeout_for_state(&self, state: &str) -> Option<&TimeoutConfig> {
         self.timeouts.iter().find(|t| t.in_state == state)
     }
+
+    /// Find the target state a provider response drives an event to.
+    ///
+    /// Returns the first response transition whose provider and field match
+    /// `body` and whose move out of `current_state` is an allowed transition.
+    /// Mappings that would leave the event in its current state are skipped.
+    #[must_use]
+    pub fn response_transition_for(
+        &self,
+        current_state: &str,
+        provider: &str,
+        body: &serde_json::Value,
+    ) -> Option<&str> {
+        self.response_transitions
+            .iter()
+            .find(|t| {
+                t.transition_to != current_state
+                    && t.matches(provider, body)
+                    && self.is_transition_allowed(current_state, &t.transition_to)
+            })
+            .map(|t| t.transition_to.as_str())
+    }
 }
 
 /// Configuration for a state transition.
@@ -193,6 +233,135 @@ impl TimeoutConfig {
     }
 }
 
+/// Maps a field of a provider's response body to a state transition.
+///
+/// After an action carrying an event fingerprint executes, the gateway checks
+/// the event's state machine for a mapping that matches the provider response
+/// and, if the transition is allowed, moves the event to `transition_to`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ResponseTransition {
+    /// Only consider responses from this provider (any provider when unset).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+
+    /// Dot-separated path into the response body (e.g. `ticket.status`).
+    pub field: String,
+
+    /// Value the field must have. Non-string fields are compared by their
+    /// JSON representation (e.g. `"true"` or `"42"`).
+    pub equals: String,
+
+    /// State to transition to when the field matches.
+    pub transition_to: String,
+}
+
+impl ResponseTransition {
+    /// Create a mapping that applies to responses from any provider.
+    #[must_use]
+    pub fn new(
+        field: impl Into<String>,
+        equals: impl Into<String>,
+        transition_to: impl Into<String>,
+    ) -> Self {
+        Self {
+            provider: None,
+            field: field.into(),
+            equals: equals.into(),
+            transition_to: transition_to.into(),
+        }
+    }
+
+    /// Restrict the mapping to responses from a single provider.
+    #[must_use]
+    pub fn for_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    /// Check whether a response from `provider` with `body` matches.
+    #[must_use]
+    pub fn matches(&self, provider: &str, body: &serde_json::Value) -> bool {
+        if self.provider.as_deref().is_some_and(|p| p != provider) {
+            return false;
+        }
+        let value = self
+            .field
+            .split('.')
+            .try_fold(body, |node, segment| node.get(segment));
+        match value {
+            Some(serde_json::Value::String(s)) => *s == self.equals,
+            Some(serde_json::Value::Null) | None => false,
+            Some(other) => serde_json::from_str::<serde_json::Value>(&self.equals)
+                .is_ok_and(|expected| expected == *other),
+        }
+    }
+}
+
+/// What caused an event to change state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionTrigger {
+    /// An action dispatched through a `state_machine` rule.
+    Dispatch,
+    /// An explicit transition through the events API.
+    Manual,
+    /// A configured state timeout expired.
+    Timeout,
+    /// A provider response matched a configured response transition.
+    ProviderResponse,
+}
+
+/// A single entry in an event's transition history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct EventTransition {
+    /// State before the transition.
+    pub from: String,
+
+    /// State after the transition.
+    pub to: String,
+
+    /// When the transition happened.
+    pub at: DateTime<Utc>,
+
+    /// Who performed the transition (caller ID, or `"system"` for timeouts).
+    pub actor: String,
+
+    /// What caused the transition.
+    pub trigger: TransitionTrigger,
+}
+
+impl EventTransition {
+    /// Create a history entry stamped with the current time.
+    #[must_use]
+    pub fn new(
+        from: impl Into<String>,
+        to: impl Into<String>,
+        actor: impl Into<String>,
+        trigger: TransitionTrigger,
+    ) -> Self {
+        Self {
+            from: from.into(),
+            to: to.into(),
+            at: Utc::now(),
+            actor: actor.into(),
+            trigger,
+        }
+    }
+}
+
+/// Append `transition` to `history`, dropping the oldest entries so that at
+/// most [`MAX_EVENT_HISTORY`] remain.
+pub fn push_bounded_history(history: &mut Vec<EventTransition>, transition: EventTransition) {
+    history.push(transition);
+    if history.len() > MAX_EVENT_HISTORY {
+        let excess = history.len() - MAX_EVENT_HISTORY;
+        history.drain(..excess);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,4 +425,76 @@ mod tests {
         assert_eq!(back.name, sm.name);
         assert_eq!(back.states.len(), sm.states.len());
     }
+
+    #[test]
+    fn history_is_bounded_and_keeps_newest() {
+        let mut history = Vec::new();
+        for i in 0..MAX_EVENT_HISTORY + 5 {
+            push_bounded_history(
+                &mut history,
+                EventTransition::new(
+                    format!("s{i}"),
+                    format!("s{}", i + 1),
+                    "alice",
+                    TransitionTrigger::Manual,
+                ),
+            );
+        }
+
+        assert_eq!(history.len(), MAX_EVENT_HISTORY);
+        assert_eq!(history[0].from, "s5");
+        assert_eq!(
+            history.last().unwrap().to,
+            format!("s{}", MAX_EVENT_HISTORY + 5)
+        );
+    }
+
+    #[test]
+    fn response_transition_honors_guards() {
+        let sm = StateMachineConfig::new("ticket", "open")
+            .with_state("resolved")
+            .with_state("archived")
+            .with_transition(TransitionConfig::new("open", "resolved"))
+            .with_response_transition(ResponseTransition::new(
+                "ticket.status",
+                "closed",
+                "resolved",
+            ))
+            .with_response_transition(
+                ResponseTransition::new("archived", "true", "archived").for_provider("jira"),
+            );
+        let closed = serde_json::json!({"ticket": {"status": "closed"}});
+
+        assert_eq!(
+            sm.response_transition_for("open", "jira", &closed),
+            Some("resolved")
+        );
+        // Already resolved: nothing to do.
+        assert_eq!(
+            sm.response_transition_for("resolved", "jira", &closed),
+            None
+        );
+        // Field does not match.
+        let pending = serde_json::json!({"ticket": {"status": "pending"}});
+        assert_eq!(sm.response_transition_for("open", "jira", &pending), None);
+        // Matches but open -> archived is not an allowed transition.
+        let archived = serde_json::json!({"archived": true});
+        assert_eq!(sm.response_transition_for("open", "jira", &archived), None);
+    }
+
+    #[test]
+    fn response_transition_filters_by_provider() {
+        let mapping = ResponseTransition::new("state", "done", "resolved").for_provider("jira");
+        let body = serde_json::json!({"state": "done"});
+
+        assert!(mapping.matches("jira", &body));
+        assert!(!mapping.matches("slack", &body));
+        assert!(!mapping.matches("jira", &serde_json::json!({"other": "done"})));
+    }
+
+    #[test]
+    fn transition_trigger_serializes_snake_case() {
+        let json = serde_json::to_string(&TransitionTrigger::Timeout).unwrap();
+        assert_eq!(json, "\"timeout\"");
+    }
 }
@@ -4,6 +4,7 @@ use std::time::Duration;
 use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::approval::ApprovalUrlParams;
 use crate::outcome::{ActionError, ActionOutcome, ProviderResponse, ResponseStatus};
 
 /// A real-time event emitted by the gateway for SSE streaming.
@@ -31,6 +32,9 @@ pub struct StreamEvent {
     /// Action ID, when the event originates from a dispatch.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action_id: Option<String>,
+    /// W3C `traceparent` of the originating action, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traceparent: Option<String>,
 }
 
 /// The type-specific payload of a [`StreamEvent`].
@@ -71,6 +75,10 @@ pub enum StreamEventType {
     ApprovalRequired {
         /// The approval request ID.
         approval_id: String,
+        /// Signed parameters of the approve and reject URLs. Absent on
+        /// events from gateways that predate them.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        url_params: Option<ApprovalUrlParams>,
     },
     /// A scheduled action has reached its dispatch time.
     ScheduledActionDue {
@@ -177,6 +185,32 @@ pub enum StreamEventType {
         /// True if this is the final chunk for the artifact.
         last_chunk: bool,
     },
+    /// A tenant's usage crossed one of a quota policy's alert
+    /// thresholds within the current window. Emitted once per
+    /// threshold per window.
+    QuotaThresholdCrossed {
+        /// The quota policy whose threshold was crossed.
+        policy_id: String,
+        /// The threshold, as a percentage of `limit`.
+        threshold_percent: u8,
+        /// Usage in the current window, including the triggering action.
+        used: u64,
+        /// The policy's `max_actions` for the window.
+        limit: u64,
+        /// The policy's window label (e.g. `"daily"`).
+        window: String,
+    },
+    /// An action was moved to the dead-letter queue after its final
+    /// execution attempt failed. The action ID is on the enclosing
+    /// [`StreamEvent`].
+    DlqEntryAdded {
+        /// The provider the action was sent to.
+        provider: String,
+        /// Description of the final error.
+        error: String,
+        /// Number of execution attempts made.
+        attempts: u32,
+    },
     /// Unknown event type (forward compatibility catch-all).
     ///
     /// Older clients that encounter a new event type they don't recognize
@@ -203,6 +237,7 @@ pub fn sanitize_outcome(outcome: &ActionOutcome) -> ActionOutcome {
             original_provider,
             new_provider,
             response,
+            failed_attempts,
         } => ActionOutcome::Rerouted {
             original_provider: original_provider.clone(),
             new_provider: new_provider.clone(),
@@ -211,6 +246,7 @@ pub fn sanitize_outcome(outcome: &ActionOutcome) -> ActionOutcome {
                 body: serde_json::Value::Null,
                 headers: HashMap::new(),
             },
+            failed_attempts: failed_attempts.clone(),
         },
         ActionOutcome::PendingApproval {
             approval_id,
@@ -302,11 +338,16 @@ pub fn reconstruct_outcome(
         }
         "failed" => {
             let code = details.get("code")?.as_str()?.to_owned();
+            let reason_code = details
+                .get("reason_code")
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or_default();
             let message = details.get("message")?.as_str()?.to_owned();
             let retryable = details.get("retryable")?.as_bool()?;
             let attempts = u32::try_from(details.get("attempts")?.as_u64()?).ok()?;
             Some(ActionOutcome::Failed(ActionError {
                 code,
+                reason_code,
                 message,
                 retryable,
                 attempts,
@@ -315,6 +356,10 @@ pub fn reconstruct_outcome(
         "rerouted" => {
             let original_provider = details.get("original_provider")?.as_str()?.to_owned();
             let new_provider = details.get("new_provider")?.as_str()?.to_owned();
+            let failed_attempts = details
+                .get("failed_attempts")
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or_default();
             Some(ActionOutcome::Rerouted {
                 original_provider,
                 new_provider,
@@ -323,6 +368,7 @@ pub fn reconstruct_outcome(
                     body: serde_json::Value::Null,
                     headers: HashMap::new(),
                 },
+                failed_attempts,
             })
         }
         "throttled" => {
@@ -449,7 +495,7 @@ pub fn reconstruct_outcome(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::outcome::{ActionError, ProviderResponse, ResponseStatus};
+    use crate::outcome::{ActionError, ProviderResponse, ReasonCode, ResponseStatus};
 
     // -- Helper --------------------------------------------------------------
 
@@ -462,6 +508,7 @@ mod tests {
             tenant: "t1".into(),
             action_type: None,
             action_id: None,
+            traceparent: None,
         }
     }
 
@@ -482,6 +529,7 @@ mod tests {
             tenant: "tenant-1".into(),
             action_type: Some("send_email".into()),
             action_id: Some("action-123".into()),
+            traceparent: None,
         };
 
         let json = serde_json::to_string(&event).unwrap();
@@ -510,6 +558,7 @@ mod tests {
             tenant: "t1".into(),
             action_type: None,
             action_id: None,
+            traceparent: None,
         };
 
         let json = serde_json::to_string(&event).unwrap();
@@ -543,6 +592,7 @@ mod tests {
             tenant: "t1".into(),
             action_type: None,
             action_id: None,
+            traceparent: None,
         };
 
         let json = serde_json::to_string(&event).unwrap();
@@ -586,14 +636,23 @@ mod tests {
     fn stream_event_approval_required_roundtrip() {
         let event = make_event(StreamEventType::ApprovalRequired {
             approval_id: "appr-xyz".into(),
+            url_params: Some(ApprovalUrlParams {
+                sig: "deadbeef".into(),
+                expires_at: 1_700_000_000,
+                kid: Some("k1".into()),
+            }),
         });
         let json = serde_json::to_string(&event).unwrap();
         assert!(json.contains("approval_required"));
         assert!(json.contains("appr-xyz"));
         let back: StreamEvent = serde_json::from_str(&json).unwrap();
         match back.event_type {
-            StreamEventType::ApprovalRequired { approval_id } => {
+            StreamEventType::ApprovalRequired {
+                approval_id,
+                url_params,
+            } => {
                 assert_eq!(approval_id, "appr-xyz");
+                assert_eq!(url_params.unwrap().expires_at, 1_700_000_000);
             }
             other => panic!("expected ApprovalRequired, got {other:?}"),
         }
@@ -631,6 +690,7 @@ mod tests {
             tenant: "t".into(),
             action_type: Some("send_email".into()),
             action_id: Some("act-1".into()),
+            traceparent: None,
         };
         let json = serde_json::to_string(&event).unwrap();
         assert!(json.contains("send_email"));
@@ -685,6 +745,7 @@ mod tests {
                 original_provider: "email".into(),
                 new_provider: "sms".into(),
                 response: ProviderResponse::success(serde_json::json!({})),
+                failed_attempts: Vec::new(),
             },
             provider: "email".into(),
         });
@@ -711,6 +772,7 @@ mod tests {
         let event = make_event(StreamEventType::ActionDispatched {
             outcome: ActionOutcome::Failed(ActionError {
                 code: "TIMEOUT".into(),
+                reason_code: ReasonCode::ProviderTimeout,
                 message: "timed out".into(),
                 retryable: true,
                 attempts: 3,
@@ -797,6 +859,7 @@ mod tests {
                 original_provider: "a".into(),
                 new_provider: "b".into(),
                 response: ProviderResponse::success(serde_json::Value::Null),
+                failed_attempts: Vec::new(),
             }),
             "rerouted"
         );
@@ -809,6 +872,7 @@ mod tests {
         assert_eq!(
             outcome_category(&ActionOutcome::Failed(ActionError {
                 code: "e".into(),
+                reason_code: ReasonCode::Unknown,
                 message: "m".into(),
                 retryable: false,
                 attempts: 1,
@@ -894,6 +958,7 @@ mod tests {
     // -- StreamEvent type tag in JSON -----------------------------------------
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn type_tag_is_snake_case() {
         let cases: Vec<(StreamEventType, &str)> = vec![
             (
@@ -928,6 +993,7 @@ mod tests {
             (
                 StreamEventType::ApprovalRequired {
                     approval_id: "a".into(),
+                    url_params: None,
                 },
                 "approval_required",
             ),
@@ -981,6 +1047,14 @@ mod tests {
                 },
                 "action_status_changed",
             ),
+            (
+                StreamEventType::DlqEntryAdded {
+                    provider: "email".into(),
+                    error: "connection refused".into(),
+                    attempts: 3,
+                },
+                "dlq_entry_added",
+            ),
         ];
         for (event_type, expected_tag) in cases {
             let event = make_event(event_type);
@@ -1037,6 +1111,7 @@ mod tests {
                 body: serde_json::json!({"internal_id": "secret-123"}),
                 headers: HashMap::from([("X-Internal".into(), "val".into())]),
             },
+            failed_attempts: Vec::new(),
         };
         let sanitized = sanitize_outcome(&outcome);
         match sanitized {
@@ -1044,6 +1119,7 @@ mod tests {
                 original_provider,
                 new_provider,
                 response,
+                ..
             } => {
                 assert_eq!(original_provider, "email");
                 assert_eq!(new_provider, "sms");
@@ -1165,6 +1241,7 @@ mod tests {
     fn reconstruct_failed() {
         let details = serde_json::json!({
             "code": "TIMEOUT",
+            "reason_code": "PROVIDER_TIMEOUT",
             "message": "timed out",
             "retryable": true,
             "attempts": 3
@@ -1173,6 +1250,7 @@ mod tests {
         match outcome {
             ActionOutcome::Failed(err) => {
                 assert_eq!(err.code, "TIMEOUT");
+                assert_eq!(err.reason_code, ReasonCode::ProviderTimeout);
                 assert_eq!(err.message, "timed out");
                 assert!(err.retryable);
                 assert_eq!(err.attempts, 3);
@@ -1839,6 +1917,7 @@ mod tests {
             tenant: "t1".into(),
             action_type: None,
             action_id: None,
+            traceparent: None,
         };
         let ts = timestamp_from_event_id(&event.id);
         assert!(
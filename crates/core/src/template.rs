@@ -9,6 +9,10 @@ const MAX_NAME_LEN: usize = 128;
 /// Maximum size of template content in bytes (512 KB).
 const MAX_CONTENT_BYTES: usize = 512 * 1024;
 
+/// How long a soft-deleted template or profile can be restored before it is
+/// purged (7 days).
+pub const DELETED_RETENTION_SECONDS: u64 = 7 * 24 * 60 * 60;
+
 /// A reusable `MiniJinja` template stored in the system.
 ///
 /// Templates contain raw Jinja2-compatible text that is rendered at dispatch
@@ -37,6 +41,13 @@ pub struct Template {
     /// Arbitrary key-value labels for filtering and organization.
     #[serde(default)]
     pub labels: HashMap<String, String>,
+    /// When this template was soft-deleted, if it has been.
+    ///
+    /// Deleted templates are hidden from lists and cannot be referenced by
+    /// profiles, but can be restored until they are purged
+    /// [`DELETED_RETENTION_SECONDS`] after deletion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 /// A template profile maps payload fields to template content.
@@ -57,6 +68,13 @@ pub struct TemplateProfile {
     pub tenant: String,
     /// Field-to-template mappings. Keys are target payload field names.
     pub fields: HashMap<String, TemplateProfileField>,
+    /// What the rendered fields are for, which decides how interpolated
+    /// values are escaped.
+    ///
+    /// When unset, only templates whose name ends in `.html` or `.htm` are
+    /// HTML-escaped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_type: Option<TemplateOutputType>,
     /// Optional human-readable description.
     #[serde(default)]
     pub description: Option<String>,
@@ -67,6 +85,30 @@ pub struct TemplateProfile {
     /// Arbitrary key-value labels for filtering and organization.
     #[serde(default)]
     pub labels: HashMap<String, String>,
+    /// When this profile was soft-deleted, if it has been.
+    ///
+    /// A deleted profile no longer renders for dispatched actions. Like a
+    /// deleted [`Template`], it can be restored until it is purged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// The format a [`TemplateProfile`] renders into.
+///
+/// Each output type escapes interpolated values (`{{ name }}`) for its
+/// format. Literal template text is never escaped, and values marked with
+/// the `safe` filter are inserted as-is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateOutputType {
+    /// HTML, such as an email body. Values are HTML-escaped.
+    Html,
+    /// Slack `mrkdwn`. `&`, `<`, and `>` are escaped so values cannot
+    /// inject links or mentions.
+    Mrkdwn,
+    /// Plain text, such as an SMS. Values are inserted unchanged.
+    Plain,
 }
 
 /// A field mapping within a [`TemplateProfile`].
@@ -139,6 +181,7 @@ mod tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             labels: HashMap::new(),
+            deleted_at: None,
         };
 
         let json = serde_json::to_string(&template).unwrap();
@@ -168,16 +211,20 @@ mod tests {
             namespace: "notifications".into(),
             tenant: "tenant-1".into(),
             fields,
+            output_type: Some(TemplateOutputType::Html),
             description: Some("Welcome email profile".into()),
             created_at: Utc::now(),
             updated_at: Utc::now(),
             labels: HashMap::new(),
+            deleted_at: None,
         };
 
         let json = serde_json::to_string(&profile).unwrap();
         let back: TemplateProfile = serde_json::from_str(&json).unwrap();
         assert_eq!(back.id, "prof-001");
         assert_eq!(back.fields.len(), 2);
+        assert!(json.contains(r#""output_type":"html""#));
+        assert_eq!(back.output_type, Some(TemplateOutputType::Html));
     }
 
     #[test]
@@ -313,6 +313,19 @@ impl PayloadEncryptor {
             .map_err(|e| CryptoError::InvalidFormat(format!("JSON parse failed: {e}")))
     }
 
+    /// Like [`decrypt_json`](Self::decrypt_json), but also returns the `kid`
+    /// recorded in the envelope.
+    ///
+    /// The `kid` is `None` for legacy envelopes written without one and for
+    /// plaintext input. Rotation tooling can use it to count values still
+    /// encrypted under a retired key.
+    pub fn decrypt_json_with_kid(
+        &self,
+        value: &str,
+    ) -> Result<(serde_json::Value, Option<String>), CryptoError> {
+        Ok((self.decrypt_json(value)?, extract_kid(value)))
+    }
+
     /// Encrypt a plaintext string, returning an `ENC[...]` envelope.
     pub fn encrypt_str(&self, value: &str) -> Result<String, CryptoError> {
         let entry = &self.keys[0];
@@ -326,6 +339,17 @@ impl PayloadEncryptor {
         Ok(self.decrypt_raw(value)?.expose_secret().clone())
     }
 
+    /// Like [`decrypt_str`](Self::decrypt_str), but also returns the `kid`
+    /// recorded in the envelope.
+    ///
+    /// The `kid` is `None` for legacy envelopes and plaintext input.
+    pub fn decrypt_str_with_kid(
+        &self,
+        value: &str,
+    ) -> Result<(String, Option<String>), CryptoError> {
+        Ok((self.decrypt_str(value)?, extract_kid(value)))
+    }
+
     /// Core multi-key decryption logic.
     ///
     /// 1. If the value is not an `ENC[...]` envelope, pass through.
@@ -591,6 +615,54 @@ mod tests {
         assert_eq!(extract_kid(&encrypted), Some("k0".to_owned()));
     }
 
+    #[test]
+    fn decrypt_with_kid_reports_envelope_kid() {
+        let enc = PayloadEncryptor::with_keys(vec![
+            PayloadKeyEntry {
+                kid: "k2".to_owned(),
+                key: test_key_2(),
+            },
+            PayloadKeyEntry {
+                kid: "k1".to_owned(),
+                key: test_key(),
+            },
+        ]);
+        let old = encrypt_value_with_kid("old", &test_key(), Some("k1")).unwrap();
+        let new = enc.encrypt_str("new").unwrap();
+
+        assert_eq!(
+            enc.decrypt_str_with_kid(&old).unwrap(),
+            ("old".to_owned(), Some("k1".to_owned()))
+        );
+        assert_eq!(
+            enc.decrypt_str_with_kid(&new).unwrap(),
+            ("new".to_owned(), Some("k2".to_owned()))
+        );
+
+        let value = serde_json::json!({"user": "alice"});
+        let encrypted = enc.encrypt_json(&value).unwrap();
+        assert_eq!(
+            enc.decrypt_json_with_kid(&encrypted).unwrap(),
+            (value, Some("k2".to_owned()))
+        );
+    }
+
+    #[test]
+    fn decrypt_with_kid_reports_none_for_legacy_and_plaintext() {
+        let enc = PayloadEncryptor::new(test_key());
+        let legacy = encrypt_value(r#"{"n":1}"#, &test_key()).unwrap();
+        assert_eq!(extract_kid(&legacy), None);
+
+        assert_eq!(
+            enc.decrypt_json_with_kid(&legacy).unwrap(),
+            (serde_json::json!({"n": 1}), None)
+        );
+        assert_eq!(
+            enc.decrypt_str_with_kid("plain").unwrap(),
+            ("plain".to_owned(), None)
+        );
+    }
+
     #[test]
     fn multi_key_encrypts_with_first_key() {
         let enc = PayloadEncryptor::with_keys(vec![
@@ -3,11 +3,12 @@ use std::sync::Arc;
 use tokio::sync::Semaphore;
 use tracing::{debug, instrument, warn};
 
-use acteon_core::{Action, ActionError, ActionOutcome};
+use acteon_core::{Action, ActionError, ActionOutcome, ReasonCode};
 use acteon_provider::{DispatchContext, DynProvider, ProviderError};
 
 use crate::config::ExecutorConfig;
 use crate::dlq::DeadLetterSink;
+use crate::retry_budget::RetryBudget;
 
 /// Executes actions against a provider with retry logic and bounded concurrency.
 ///
@@ -19,10 +20,15 @@ use crate::dlq::DeadLetterSink;
 ///
 /// When a dead-letter queue sink is configured, actions that exhaust all retries
 /// are pushed to the sink before returning [`ActionOutcome::Failed`].
+///
+/// An optional [`RetryBudget`] caps retries per provider across all actions.
+/// Once a provider's budget is spent, a retryable failure ends immediately
+/// with reason code [`ReasonCode::RetryBudgetExhausted`].
 pub struct ActionExecutor {
     config: ExecutorConfig,
     semaphore: Arc<Semaphore>,
     dlq: Option<Arc<dyn DeadLetterSink>>,
+    retry_budget: Option<Arc<RetryBudget>>,
 }
 
 impl ActionExecutor {
@@ -41,6 +47,7 @@ impl ActionExecutor {
             config,
             semaphore,
             dlq: None,
+            retry_budget: None,
         }
     }
 
@@ -53,9 +60,17 @@ impl ActionExecutor {
             config,
             semaphore,
             dlq: Some(dlq),
+            retry_budget: None,
         }
     }
 
+    /// Throttle retries with a per-provider [`RetryBudget`].
+    #[must_use]
+    pub fn with_retry_budget(mut self, budget: Arc<RetryBudget>) -> Self {
+        self.retry_budget = Some(budget);
+        self
+    }
+
     /// Return a reference to the executor configuration.
     pub fn config(&self) -> &ExecutorConfig {
         &self.config
@@ -66,6 +81,48 @@ impl ActionExecutor {
         self.dlq.as_ref()
     }
 
+    /// Return the per-provider retry budget, if configured.
+    pub fn retry_budget(&self) -> Option<&Arc<RetryBudget>> {
+        self.retry_budget.as_ref()
+    }
+
+    /// Spend a retry token for `provider`, or return the budget-exhausted
+    /// failure once its budget is spent.
+    async fn charge_retry(
+        &self,
+        action: &Action,
+        provider: &dyn DynProvider,
+        err: &ProviderError,
+        attempts: u32,
+    ) -> Result<(), ActionOutcome> {
+        let Some(ref budget) = self.retry_budget else {
+            return Ok(());
+        };
+        if budget.try_acquire(provider.name()) {
+            return Ok(());
+        }
+        warn!(
+            action_id = %action.id,
+            provider = provider.name(),
+            attempts,
+            error = %err,
+            "retry budget exhausted, not retrying"
+        );
+        let message = format!(
+            "retry budget exhausted for provider '{}': {err}",
+            provider.name()
+        );
+        self.push_to_dlq(action, &message, attempts).await;
+        tracing::Span::current().record("otel.status_code", "ERROR");
+        Err(ActionOutcome::Failed(ActionError {
+            code: "RETRY_BUDGET_EXHAUSTED".into(),
+            reason_code: ReasonCode::RetryBudgetExhausted,
+            message,
+            retryable: true,
+            attempts,
+        }))
+    }
+
     /// Push an action to the dead-letter queue if configured.
     async fn push_to_dlq(&self, action: &Action, error: &str, attempts: u32) {
         if let Some(ref dlq) = self.dlq {
@@ -152,6 +209,11 @@ impl ActionExecutor {
                 }
                 Ok(Err(err)) => {
                     if err.is_retryable() && attempt < self.config.max_retries {
+                        if let Err(outcome) =
+                            self.charge_retry(action, provider, &err, attempt + 1).await
+                        {
+                            return outcome;
+                        }
                         let delay = self.config.retry_strategy.delay_for(attempt);
                         warn!(
                             action_id = %action.id,
@@ -180,6 +242,7 @@ impl ActionExecutor {
                         tracing::Span::current().record("otel.status_code", "ERROR");
                         return ActionOutcome::Failed(ActionError {
                             code: error_code(&err),
+                            reason_code: reason_code(&err),
                             message: err.to_string(),
                             retryable: is_retryable,
                             attempts,
@@ -189,6 +252,11 @@ impl ActionExecutor {
                 Err(_elapsed) => {
                     let err = ProviderError::Timeout(self.config.execution_timeout);
                     if attempt < self.config.max_retries {
+                        if let Err(outcome) =
+                            self.charge_retry(action, provider, &err, attempt + 1).await
+                        {
+                            return outcome;
+                        }
                         let delay = self.config.retry_strategy.delay_for(attempt);
                         warn!(
                             action_id = %action.id,
@@ -210,6 +278,7 @@ impl ActionExecutor {
                         tracing::Span::current().record("otel.status_code", "ERROR");
                         return ActionOutcome::Failed(ActionError {
                             code: error_code(&err),
+                            reason_code: reason_code(&err),
                             message: err.to_string(),
                             retryable: true,
                             attempts,
@@ -227,6 +296,7 @@ impl ActionExecutor {
         tracing::Span::current().record("otel.status_code", "ERROR");
         ActionOutcome::Failed(ActionError {
             code: error_code(&err),
+            reason_code: reason_code(&err),
             message: err.to_string(),
             retryable: err.is_retryable(),
             attempts,
@@ -247,6 +317,18 @@ fn error_code(err: &ProviderError) -> String {
     }
 }
 
+fn reason_code(err: &ProviderError) -> ReasonCode {
+    match err {
+        ProviderError::NotFound(_) => ReasonCode::ProviderNotFound,
+        ProviderError::ExecutionFailed(_) => ReasonCode::ProviderFailed,
+        ProviderError::Timeout(_) => ReasonCode::ProviderTimeout,
+        ProviderError::Connection(_) => ReasonCode::ProviderUnavailable,
+        ProviderError::Configuration(_) => ReasonCode::ProviderMisconfigured,
+        ProviderError::RateLimited => ReasonCode::ProviderRateLimited,
+        ProviderError::Serialization(_) => ReasonCode::SerializationFailed,
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unnecessary_literal_bound)]
 mod tests {
@@ -422,11 +504,46 @@ mod tests {
                 assert!(!err.retryable);
                 assert_eq!(err.attempts, 1, "should fail on first attempt");
                 assert_eq!(err.code, "EXECUTION_FAILED");
+                assert_eq!(err.reason_code, ReasonCode::ProviderFailed);
             }
             other => panic!("expected Failed, got {other:?}"),
         }
     }
 
+    #[test]
+    fn every_provider_error_maps_to_a_reason_code() {
+        let cases = [
+            (
+                ProviderError::NotFound("x".into()),
+                ReasonCode::ProviderNotFound,
+            ),
+            (
+                ProviderError::ExecutionFailed("x".into()),
+                ReasonCode::ProviderFailed,
+            ),
+            (
+                ProviderError::Timeout(Duration::from_secs(1)),
+                ReasonCode::ProviderTimeout,
+            ),
+            (
+                ProviderError::Connection("x".into()),
+                ReasonCode::ProviderUnavailable,
+            ),
+            (
+                ProviderError::Configuration("x".into()),
+                ReasonCode::ProviderMisconfigured,
+            ),
+            (ProviderError::RateLimited, ReasonCode::ProviderRateLimited),
+            (
+                ProviderError::Serialization("x".into()),
+                ReasonCode::SerializationFailed,
+            ),
+        ];
+        for (err, expected) in cases {
+            assert_eq!(reason_code(&err), expected, "{err}");
+        }
+    }
+
     #[tokio::test]
     async fn execute_retries_on_retryable_error() {
         let executor = ActionExecutor::new(fast_config());
@@ -441,6 +558,27 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn exhausted_retry_budget_fails_without_retrying() {
+        let budget = Arc::new(RetryBudget::new(crate::RetryBudgetConfig {
+            max_retries: 1,
+            window: Duration::from_secs(3600),
+        }));
+        let executor = ActionExecutor::new(fast_config()).with_retry_budget(budget);
+        let provider = MockProvider::failing(true);
+
+        let outcome = executor.execute(&test_action(), &provider).await;
+        match outcome {
+            ActionOutcome::Failed(err) => {
+                assert_eq!(err.code, "RETRY_BUDGET_EXHAUSTED");
+                assert_eq!(err.reason_code, ReasonCode::RetryBudgetExhausted);
+                assert!(err.retryable);
+                assert_eq!(err.attempts, 2, "one budgeted retry, then stop");
+            }
+            other => panic!("expected Failed, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn execute_recovers_after_retries() {
         let executor = ActionExecutor::new(fast_config());
@@ -470,6 +608,7 @@ mod tests {
         match outcome {
             ActionOutcome::Failed(err) => {
                 assert_eq!(err.code, "TIMEOUT");
+                assert_eq!(err.reason_code, ReasonCode::ProviderTimeout);
                 assert!(err.retryable);
             }
             other => panic!("expected Failed(Timeout), got {other:?}"),
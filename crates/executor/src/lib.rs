@@ -3,8 +3,10 @@ pub mod config;
 pub mod dlq;
 pub mod executor;
 pub mod retry;
+pub mod retry_budget;
 
 pub use config::ExecutorConfig;
 pub use dlq::{DeadLetterEntry, DeadLetterQueue, DeadLetterSink};
 pub use executor::ActionExecutor;
 pub use retry::RetryStrategy;
+pub use retry_budget::{RetryBudget, RetryBudgetConfig};
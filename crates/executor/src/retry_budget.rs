@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// Configuration for a per-provider [`RetryBudget`].
+///
+/// Each provider gets a token bucket holding up to `max_retries` tokens that
+/// refills continuously over `window`. Every retry spends one token; the
+/// first attempt of an action is never charged.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use acteon_executor::RetryBudgetConfig;
+///
+/// // At most 20 retries per provider per minute.
+/// let config = RetryBudgetConfig {
+///     max_retries: 20,
+///     window: Duration::from_secs(60),
+/// };
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryBudgetConfig {
+    /// Retries a provider may absorb per window (the bucket capacity).
+    pub max_retries: u32,
+    /// Time for an empty bucket to refill completely.
+    pub window: Duration,
+}
+
+impl RetryBudgetConfig {
+    /// Validate configuration values.
+    ///
+    /// Returns `Err` with a description if `window` is zero.
+    /// `max_retries = 0` is allowed and disables retries entirely.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.window.is_zero() {
+            return Err("retry budget window must be > 0".into());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Process-wide retry budget shared by every action sent to a provider.
+///
+/// Throttles retries against a struggling provider independently of the
+/// circuit breaker: once a provider's bucket is empty, further retryable
+/// failures end immediately instead of piling more load onto it.
+#[derive(Debug)]
+pub struct RetryBudget {
+    config: RetryBudgetConfig,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RetryBudget {
+    /// Create a budget with the given configuration.
+    pub fn new(config: RetryBudgetConfig) -> Self {
+        Self {
+            config,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return the budget configuration.
+    pub fn config(&self) -> &RetryBudgetConfig {
+        &self.config
+    }
+
+    /// Spend one retry token for `provider`.
+    ///
+    /// Returns `false` when the provider's budget is exhausted.
+    pub fn try_acquire(&self, provider: &str) -> bool {
+        let capacity = f64::from(self.config.max_retries);
+        let now = Instant::now();
+        // A panic elsewhere cannot leave a bucket half-updated, so a poisoned
+        // lock is still safe to use; retries must not start panicking.
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        let bucket = buckets.entry(provider.to_owned()).or_insert(Bucket {
+            tokens: capacity,
+            refilled_at: now,
+        });
+
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        let refill = elapsed * capacity / self.config.window.as_secs_f64();
+        bucket.tokens = (bucket.tokens + refill).min(capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Whole retry tokens currently left for `provider`.
+    pub fn remaining(&self, provider: &str) -> u32 {
+        let buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        buckets.get(provider).map_or(self.config.max_retries, |b| {
+            // Tokens are clamped to `max_retries`, so the cast is lossless.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let whole = b.tokens.floor() as u32;
+            whole
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget(max_retries: u32, window_secs: u64) -> RetryBudget {
+        RetryBudget::new(RetryBudgetConfig {
+            max_retries,
+            window: Duration::from_secs(window_secs),
+        })
+    }
+
+    #[tokio::test]
+    async fn budget_is_per_provider_and_refills() {
+        tokio::time::pause();
+        let budget = budget(2, 10);
+
+        assert!(budget.try_acquire("email"));
+        assert!(budget.try_acquire("email"));
+        assert!(!budget.try_acquire("email"));
+        assert_eq!(budget.remaining("email"), 0);
+        // Other providers keep their own bucket.
+        assert!(budget.try_acquire("sms"));
+
+        // Half the window refills half the bucket.
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert!(budget.try_acquire("email"));
+        assert!(!budget.try_acquire("email"));
+
+        // Refill never exceeds capacity.
+        tokio::time::advance(Duration::from_secs(60)).await;
+        assert!(budget.try_acquire("email"));
+        assert_eq!(budget.remaining("email"), 1);
+    }
+
+    #[tokio::test]
+    async fn poisoned_lock_keeps_working() {
+        let budget = budget(2, 10);
+        let _ = std::panic::catch_unwind(|| {
+            let _guard = budget.buckets.lock().unwrap();
+            panic!("poison the bucket lock");
+        });
+        assert!(budget.buckets.is_poisoned());
+
+        assert!(budget.try_acquire("email"));
+        assert_eq!(budget.remaining("email"), 1);
+    }
+
+    #[test]
+    fn zero_window_is_rejected() {
+        assert!(
+            RetryBudgetConfig {
+                max_retries: 1,
+                window: Duration::ZERO,
+            }
+            .validate()
+            .is_err()
+        );
+    }
+}
@@ -8,12 +8,17 @@ use std::time::Duration;
 use chrono::Utc;
 
 use acteon_audit::{A2A_AUDIT_PROVIDER, AuditEventKind, AuditRecord};
-use acteon_core::{Action, ActionOutcome, Caller, Task, TaskState};
-use acteon_rules::RuleVerdict;
+use acteon_core::{Action, ActionOutcome, Caller, ReasonCode, Task, TaskState};
+use acteon_rules::{BUDGET_EXCEEDED_RULE, RuleVerdict};
 
 /// Extract the matched rule name from a `RuleVerdict`, if any.
+///
+/// Allow verdicts carry no rule, except an allow from an exhausted
+/// evaluation budget, which names [`BUDGET_EXCEEDED_RULE`] so the cutoff is
+/// visible in the audit trail.
 pub(crate) fn matched_rule_name(verdict: &RuleVerdict) -> Option<String> {
     match verdict {
+        RuleVerdict::Allow(Some(rule)) if rule == BUDGET_EXCEEDED_RULE => Some(rule.clone()),
         RuleVerdict::Allow(_) | RuleVerdict::Deduplicate { .. } => None,
         RuleVerdict::Deny(rule)
         | RuleVerdict::Suppress(rule)
@@ -100,6 +105,15 @@ pub(crate) fn enrich_audit_metadata(action: &Action) -> serde_json::Value {
         if let Some(t) = action.ends_at {
             obj.insert("__ends_at".into(), serde_json::json!(t));
         }
+        if action.encrypt_payload == Some(false) {
+            obj.insert("__encrypt_payload".into(), serde_json::json!(false));
+        }
+        // Record the server-captured trace when the caller supplied none.
+        if !obj.contains_key("traceparent")
+            && let Some(tp) = action.traceparent()
+        {
+            obj.insert("traceparent".into(), serde_json::json!(tp));
+        }
     }
     meta
 }
@@ -202,6 +216,7 @@ pub(crate) fn build_audit_record(
         }),
         ActionOutcome::Failed(err) => serde_json::json!({
             "code": err.code,
+            "reason_code": err.reason_code,
             "message": err.message,
             "retryable": err.retryable,
             "attempts": err.attempts,
@@ -210,11 +225,18 @@ pub(crate) fn build_audit_record(
         ActionOutcome::Rerouted {
             original_provider,
             new_provider,
+            failed_attempts,
             ..
-        } => serde_json::json!({
-            "original_provider": original_provider,
-            "new_provider": new_provider,
-        }),
+        } => {
+            let mut details = serde_json::json!({
+                "original_provider": original_provider,
+                "new_provider": new_provider,
+            });
+            if !failed_attempts.is_empty() {
+                details["failed_attempts"] = serde_json::json!(failed_attempts);
+            }
+            details
+        }
         ActionOutcome::Throttled { retry_after } => {
             serde_json::json!({ "retry_after_secs": retry_after.as_secs() })
         }
@@ -275,6 +297,7 @@ pub(crate) fn build_audit_record(
         } => serde_json::json!({
             "provider": provider,
             "fallback_chain": fallback_chain,
+            "reason_code": ReasonCode::CircuitOpen,
         }),
         ActionOutcome::Scheduled {
             action_id,
@@ -302,6 +325,7 @@ pub(crate) fn build_audit_record(
             "limit": limit,
             "used": used,
             "overage_behavior": overage_behavior,
+            "reason_code": ReasonCode::QuotaExceeded,
         }),
         ActionOutcome::Silenced {
             silence_id,
@@ -18,7 +18,7 @@ use tracing::{debug, error, info};
 
 use acteon_audit::store::AuditStore;
 use acteon_core::{EventGroup, StateMachineConfig, StreamEvent};
-use acteon_state::StateStore;
+use acteon_state::{DistributedLock, StateStore};
 
 use acteon_crypto::PayloadEncryptor;
 
@@ -137,6 +137,8 @@ pub struct GroupFlushEvent {
     pub group: EventGroup,
     /// When the flush occurred.
     pub flushed_at: chrono::DateTime<Utc>,
+    /// Digest rendered from the group's digest template, if it has one.
+    pub digest: Option<String>,
 }
 
 /// Event emitted when a state machine timeout fires.
@@ -201,6 +203,8 @@ pub struct RecurringActionDueEvent {
     pub recurring_id: String,
     /// The deserialized recurring action definition.
     pub recurring_action: acteon_core::RecurringAction,
+    /// When this occurrence was scheduled to fire.
+    pub scheduled_for: chrono::DateTime<Utc>,
 }
 
 /// Event emitted when a pending approval needs notification retry.
@@ -249,6 +253,9 @@ pub struct BackgroundProcessor {
     pub(crate) retention_policies: HashMap<String, acteon_core::RetentionPolicy>,
     /// Optional gateway reference for template sync.
     pub(crate) gateway: Option<Arc<tokio::sync::RwLock<crate::gateway::Gateway>>>,
+    /// The gateway's distributed lock. Timeout processing takes the same
+    /// per-event lock as the gateway before changing an event's state.
+    pub(crate) lock: Option<Arc<dyn DistributedLock>>,
     /// Optional audit sink. When set, the stale-task reaper records an
     /// A2A task-transition audit entry for every task it reaps.
     pub(crate) audit: Option<Arc<dyn AuditStore>>,
@@ -287,6 +294,7 @@ impl BackgroundProcessor {
             payload_encryptor: None,
             retention_policies: HashMap::new(),
             gateway: None,
+            lock: None,
             audit: None,
             stream_tx: None,
         }
@@ -336,6 +344,13 @@ impl BackgroundProcessor {
         self
     }
 
+    /// Set the distributed lock shared with the gateway.
+    #[must_use]
+    pub fn with_lock(mut self, lock: Arc<dyn DistributedLock>) -> Self {
+        self.lock = Some(lock);
+        self
+    }
+
     /// Set the payload encryptor for decrypting state values.
     #[must_use]
     pub fn with_payload_encryptor(mut self, enc: Arc<PayloadEncryptor>) -> Self {
@@ -583,6 +598,7 @@ pub struct BackgroundProcessorBuilder {
     payload_encryptor: Option<Arc<PayloadEncryptor>>,
     metrics: Option<Arc<GatewayMetrics>>,
     gateway: Option<Arc<tokio::sync::RwLock<crate::gateway::Gateway>>>,
+    lock: Option<Arc<dyn DistributedLock>>,
     audit: Option<Arc<dyn AuditStore>>,
     stream_tx: Option<broadcast::Sender<StreamEvent>>,
 }
@@ -605,6 +621,7 @@ impl BackgroundProcessorBuilder {
             payload_encryptor: None,
             metrics: None,
             gateway: None,
+            lock: None,
             audit: None,
             stream_tx: None,
         }
@@ -719,6 +736,14 @@ impl BackgroundProcessorBuilder {
         self
     }
 
+    /// Set the distributed lock shared with the gateway. Required when
+    /// timeout processing is enabled.
+    #[must_use]
+    pub fn lock(mut self, lock: Arc<dyn DistributedLock>) -> Self {
+        self.lock = Some(lock);
+        self
+    }
+
     /// Build the background processor.
     ///
     /// Returns the processor and a shutdown sender.
@@ -726,6 +751,9 @@ impl BackgroundProcessorBuilder {
         let group_manager = self.group_manager.ok_or("group_manager is required")?;
         let state = self.state.ok_or("state store is required")?;
         let metrics = self.metrics.ok_or("metrics is required")?;
+        if self.config.enable_timeout_processing && self.lock.is_none() {
+            return Err("lock is required for timeout processing");
+        }
 
         let (shutdown_tx, shutdown_rx) = mpsc::channel(1);
 
@@ -770,6 +798,10 @@ impl BackgroundProcessorBuilder {
             processor = processor.with_gateway(gw);
         }
 
+        if let Some(lock) = self.lock {
+            processor = processor.with_lock(lock);
+        }
+
         if let Some(audit) = self.audit {
             processor = processor.with_audit_store(audit);
         }
@@ -794,7 +826,7 @@ mod tests {
     use acteon_state::{KeyKind, StateKey};
     use std::sync::Arc;
 
-    use acteon_state_memory::MemoryStateStore;
+    use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
 
     #[tokio::test]
     async fn background_processor_starts_and_stops() {
@@ -833,6 +865,7 @@ mod tests {
             })
             .group_manager(group_manager)
             .state(state)
+            .lock(Arc::new(MemoryDistributedLock::new()))
             .build()
             .unwrap();
 
@@ -874,6 +907,7 @@ mod tests {
             })
             .group_manager(group_manager)
             .state(state)
+            .lock(Arc::new(MemoryDistributedLock::new()))
             .scheduled_action_channel(sched_tx)
             .build();
 
@@ -1252,6 +1286,7 @@ mod tests {
             description: None,
             labels: std::collections::HashMap::new(),
             overlap_policy: acteon_core::OverlapPolicy::default(),
+            concurrency_policy: acteon_core::ConcurrencyPolicy::default(),
             last_execution_id: None,
         }
     }
@@ -1408,6 +1443,7 @@ mod tests {
             })
             .group_manager(group_manager)
             .state(Arc::clone(&state))
+            .lock(Arc::new(MemoryDistributedLock::new()))
             .recurring_action_channel(rec_tx)
             .build()
             .unwrap();
@@ -1449,6 +1485,7 @@ mod tests {
             })
             .group_manager(group_manager)
             .state(state)
+            .lock(Arc::new(MemoryDistributedLock::new()))
             .recurring_action_channel(rec_tx)
             .build();
 
@@ -3,10 +3,11 @@ use std::time::Duration;
 use chrono::Utc;
 use tracing::{debug, info, warn};
 
-use acteon_core::{StreamEvent, StreamEventType};
+use acteon_core::{EventGroup, StreamEvent, StreamEventType};
 use acteon_state::{KeyKind, StateKey};
 
 use crate::group_manager::persist_group;
+use crate::template_engine::render_group_digest;
 
 use super::super::{BackgroundProcessor, GroupFlushEvent};
 
@@ -15,6 +16,15 @@ use super::super::{BackgroundProcessor, GroupFlushEvent};
 /// on instance A releases the claim for instance B on the next tick.
 const FLUSH_CLAIM_TTL: Duration = Duration::from_secs(60);
 
+/// Render the digest for a flushed group. A broken digest template must
+/// not block the notification, so render errors fall back to no digest.
+fn flush_digest(group: &EventGroup) -> Option<String> {
+    render_group_digest(group).unwrap_or_else(|e| {
+        warn!(group_id = %group.group_id, error = %e, "failed to render group digest");
+        None
+    })
+}
+
 impl BackgroundProcessor {
     /// Flush all groups that are ready.
     ///
@@ -28,6 +38,7 @@ impl BackgroundProcessor {
     /// scheduled re-flush (with a new `notify_at`) gets a fresh
     /// claim, so persistent groups can still re-fire across
     /// subsequent intervals.
+    #[allow(clippy::too_many_lines)]
     pub(crate) async fn flush_ready_groups(
         &self,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
@@ -103,6 +114,7 @@ impl BackgroundProcessor {
                     let event = GroupFlushEvent {
                         group: flushed_group.clone(),
                         flushed_at,
+                        digest: flush_digest(&flushed_group),
                     };
                     if tx.send(event).await.is_err() {
                         warn!("group flush event channel closed");
@@ -127,6 +139,7 @@ impl BackgroundProcessor {
                         tenant: flushed_group.tenant.clone(),
                         action_type: None,
                         action_id: None,
+                        traceparent: None,
                     });
                 }
 
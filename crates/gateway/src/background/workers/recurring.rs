@@ -292,11 +292,18 @@ impl BackgroundProcessor {
                 "dispatching recurring action"
             );
 
+            // The stored next execution is the occurrence that just fell due;
+            // fall back to the poll time if it is missing or in the future.
+            let scheduled_for = recurring
+                .next_execution_at
+                .filter(|at| *at <= now)
+                .unwrap_or(now);
             let event = RecurringActionDueEvent {
                 namespace: namespace.to_string(),
                 tenant: tenant.to_string(),
                 recurring_id: recurring_id.to_string(),
                 recurring_action: recurring,
+                scheduled_for,
             };
 
             if tx.send(event).await.is_err() {
@@ -1,17 +1,19 @@
-use chrono::Utc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
 use tracing::{debug, info, warn};
 
-use acteon_core::{StreamEvent, StreamEventType};
+use acteon_core::{EventTransition, StreamEvent, StreamEventType, TransitionTrigger};
 use acteon_state::{KeyKind, StateKey};
 
 use super::super::{BackgroundProcessor, TimeoutEvent};
+use crate::event_history::{event_lock_name, write_event_state};
 
 impl BackgroundProcessor {
     /// Process state machine timeouts.
     ///
     /// Uses an indexed approach to efficiently find expired timeouts in O(log N + M)
     /// where M is the number of expired entries, instead of scanning all timeout keys.
-    #[allow(clippy::too_many_lines)]
     pub(crate) async fn process_timeouts(
         &self,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
@@ -27,6 +29,10 @@ impl BackgroundProcessor {
 
         debug!(count = expired_keys.len(), "processing expired timeouts");
 
+        let Some(ref lock) = self.lock else {
+            return Err("timeout processing requires a distributed lock".into());
+        };
+
         for canonical_key in expired_keys {
             // Parse namespace and tenant from the key (format: namespace:tenant:kind:id)
             let key_parts: Vec<&str> = canonical_key.splitn(4, ':').collect();
@@ -41,93 +47,23 @@ impl BackgroundProcessor {
                 continue;
             };
 
-            // Fetch the timeout data from the state store
-            let timeout_key = StateKey::new(
-                namespace.as_str(),
-                tenant.as_str(),
-                KeyKind::EventTimeout,
-                &fingerprint,
-            );
-
-            let Some(value) = self.state.get(&timeout_key).await? else {
-                // Timeout was already processed or deleted, remove from index
-                self.state.remove_timeout_index(&timeout_key).await?;
-                continue;
-            };
-
-            // Decrypt and parse the timeout entry.
-            let decrypted_value = match self.decrypt_state_value(&value) {
-                Ok(v) => v,
-                Err(e) => {
-                    warn!(key = %canonical_key, error = %e, "failed to decrypt timeout data");
-                    continue;
-                }
-            };
-            let Ok(timeout_data) = serde_json::from_str::<serde_json::Value>(&decrypted_value)
-            else {
-                warn!(key = %canonical_key, "failed to parse timeout data");
+            // Take the gateway's per-event lock so the transition cannot
+            // interleave with a dispatch or manual transition of the event.
+            let guard = lock
+                .acquire(
+                    &event_lock_name(&namespace, &tenant, &fingerprint),
+                    Duration::from_secs(30),
+                    Duration::from_secs(5),
+                )
+                .await?;
+            let result = self
+                .expire_timeout(&namespace, &tenant, &fingerprint, now)
+                .await;
+            guard.release().await?;
+            let Some(event) = result? else {
                 continue;
             };
 
-            // fingerprint is already parsed from the key above
-            let state_machine_name = timeout_data
-                .get("state_machine")
-                .and_then(|v| v.as_str())
-                .unwrap_or_default()
-                .to_string();
-            let current_state = timeout_data
-                .get("current_state")
-                .and_then(|v| v.as_str())
-                .unwrap_or_default()
-                .to_string();
-            let transition_to = timeout_data
-                .get("transition_to")
-                .and_then(|v| v.as_str())
-                .unwrap_or_default()
-                .to_string();
-            let trace_context: std::collections::HashMap<String, String> = timeout_data
-                .get("trace_context")
-                .and_then(|v| serde_json::from_value(v.clone()).ok())
-                .unwrap_or_default();
-
-            info!(
-                fingerprint = %fingerprint,
-                namespace = %namespace,
-                tenant = %tenant,
-                state_machine = %state_machine_name,
-                from_state = %current_state,
-                to_state = %transition_to,
-                "processing expired timeout"
-            );
-
-            // Update the event state
-            let state_key = StateKey::new(
-                namespace.as_str(),
-                tenant.as_str(),
-                KeyKind::EventState,
-                &fingerprint,
-            );
-
-            let new_state_value = serde_json::json!({
-                "state": &transition_to,
-                "fingerprint": &fingerprint,
-                "updated_at": now.to_rfc3339(),
-                "transitioned_by": "timeout",
-            });
-
-            let encrypted_state = match self.payload_encryptor {
-                Some(ref enc) => enc
-                    .encrypt_str(&new_state_value.to_string())
-                    .unwrap_or_else(|_| new_state_value.to_string()),
-                None => new_state_value.to_string(),
-            };
-
-            self.state.set(&state_key, &encrypted_state, None).await?;
-
-            // Delete the processed timeout entry and remove from index
-            self.state.delete(&timeout_key).await?;
-            self.state.remove_timeout_index(&timeout_key).await?;
-
             // Emit ActionStatusChanged so /v1/subscribe consumers see
             // timeout-driven transitions. Best-effort; no live
             // subscribers is not an error.
@@ -136,33 +72,127 @@ impl BackgroundProcessor {
                 timestamp: now,
                 event_type: StreamEventType::ActionStatusChanged {
                     action_id: String::new(),
-                    fingerprint: fingerprint.clone(),
-                    state_machine: state_machine_name.clone(),
-                    previous_status: current_state.clone(),
-                    new_status: transition_to.clone(),
+                    fingerprint: event.fingerprint.clone(),
+                    state_machine: event.state_machine.clone(),
+                    previous_status: event.previous_state.clone(),
+                    new_status: event.new_state.clone(),
                 },
                 namespace: namespace.clone(),
                 tenant: tenant.clone(),
                 action_type: None,
                 action_id: None,
+                traceparent: None,
             });
 
             // Send timeout event if channel is configured
-            if let Some(ref tx) = self.timeout_tx {
-                let event = TimeoutEvent {
-                    fingerprint,
-                    state_machine: state_machine_name,
-                    previous_state: current_state,
-                    new_state: transition_to,
-                    fired_at: now,
-                    trace_context,
-                };
-                if tx.send(event).await.is_err() {
-                    warn!("timeout event channel closed");
-                }
+            if let Some(ref tx) = self.timeout_tx
+                && tx.send(event).await.is_err()
+            {
+                warn!("timeout event channel closed");
             }
         }
 
         Ok(())
     }
+
+    /// Apply the expired timeout of one event, if it is still pending.
+    ///
+    /// The caller holds the event's lock. Returns the event to publish once
+    /// the lock is released, or `None` if there was nothing to apply.
+    async fn expire_timeout(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        fingerprint: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<TimeoutEvent>, Box<dyn std::error::Error + Send + Sync>> {
+        let timeout_key = StateKey::new(namespace, tenant, KeyKind::EventTimeout, fingerprint);
+
+        let Some(value) = self.state.get(&timeout_key).await? else {
+            // Timeout was already processed or deleted, remove from index
+            self.state.remove_timeout_index(&timeout_key).await?;
+            return Ok(None);
+        };
+
+        // Decrypt and parse the timeout entry.
+        let decrypted_value = match self.decrypt_state_value(&value) {
+            Ok(v) => v,
+            Err(e) => {
+                warn!(key = %timeout_key, error = %e, "failed to decrypt timeout data");
+                return Ok(None);
+            }
+        };
+        let Ok(timeout_data) = serde_json::from_str::<serde_json::Value>(&decrypted_value) else {
+            warn!(key = %timeout_key, "failed to parse timeout data");
+            return Ok(None);
+        };
+
+        let state_machine_name = timeout_data
+            .get("state_machine")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let current_state = timeout_data
+            .get("current_state")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let transition_to = timeout_data
+            .get("transition_to")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let trace_context: std::collections::HashMap<String, String> = timeout_data
+            .get("trace_context")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default();
+
+        info!(
+            fingerprint = %fingerprint,
+            namespace = %namespace,
+            tenant = %tenant,
+            state_machine = %state_machine_name,
+            from_state = %current_state,
+            to_state = %transition_to,
+            "processing expired timeout"
+        );
+
+        // Update the event state and its history together.
+        let state_key = StateKey::new(namespace, tenant, KeyKind::EventState, fingerprint);
+        let new_state_value = serde_json::json!({
+            "state": &transition_to,
+            "fingerprint": fingerprint,
+            "updated_at": now.to_rfc3339(),
+            "transitioned_by": "timeout",
+            "state_machine": &state_machine_name,
+        });
+        let mut transition = EventTransition::new(
+            current_state.as_str(),
+            transition_to.as_str(),
+            "system",
+            TransitionTrigger::Timeout,
+        );
+        transition.at = now;
+        write_event_state(
+            self.state.as_ref(),
+            self.payload_encryptor.as_ref(),
+            &state_key,
+            &new_state_value.to_string(),
+            Some(transition),
+        )
+        .await?;
+
+        // Delete the processed timeout entry and remove from index
+        self.state.delete(&timeout_key).await?;
+        self.state.remove_timeout_index(&timeout_key).await?;
+
+        Ok(Some(TimeoutEvent {
+            fingerprint: fingerprint.to_owned(),
+            state_machine: state_machine_name,
+            previous_state: current_state,
+            new_state: transition_to,
+            fired_at: now,
+            trace_context,
+        }))
+    }
 }
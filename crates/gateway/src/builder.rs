@@ -8,7 +8,7 @@ use acteon_audit::store::AuditStore;
 use acteon_core::{ChainConfig, StateMachineConfig};
 use acteon_executor::{DeadLetterQueue, DeadLetterSink, ExecutorConfig};
 use acteon_provider::{DynProvider, ProviderRegistry};
-use acteon_rules::{Rule, RuleEngine};
+use acteon_rules::{EvalBudget, Rule, RuleEngine};
 use acteon_state::{DistributedLock, StateStore};
 use tokio_util::task::TaskTracker;
 
@@ -32,9 +32,12 @@ pub struct GatewayBuilder {
     state: Option<Arc<dyn StateStore>>,
     lock: Option<Arc<dyn DistributedLock>>,
     rules: Vec<Rule>,
+    rule_budget: EvalBudget,
+    rule_history_limit: usize,
     providers: ProviderRegistry,
     executor_config: ExecutorConfig,
     environment: HashMap<String, String>,
+    holiday_calendars: acteon_rules::HolidayCalendars,
     audit: Option<Arc<dyn AuditStore>>,
     audit_ttl_seconds: Option<u64>,
     audit_store_payload: bool,
@@ -51,16 +54,27 @@ pub struct GatewayBuilder {
     llm_fail_open: bool,
     chains: HashMap<String, ChainConfig>,
     completed_chain_ttl: Option<Duration>,
+    chain_limits: acteon_core::ChainLimits,
     embedding: Option<Arc<dyn acteon_rules::EmbeddingEvalSupport>>,
     default_timezone: Option<String>,
     circuit_breaker_default: Option<CircuitBreakerConfig>,
     circuit_breaker_overrides: HashMap<String, CircuitBreakerConfig>,
+    retry_budget: Option<acteon_executor::RetryBudgetConfig>,
     stream_buffer_size: usize,
     quota_policies: Vec<acteon_core::QuotaPolicy>,
     retention_policies: HashMap<String, acteon_core::RetentionPolicy>,
+    dedup_normalization: HashMap<String, acteon_core::DedupKeyNormalization>,
+    clock: crate::gateway::Clock,
+    provider_payload_defaults: HashMap<(String, String), serde_json::Value>,
+    namespace_defaults: HashMap<String, acteon_core::NamespaceDefaults>,
+    provider_failover: HashMap<String, Vec<String>>,
     payload_encryptor: Option<Arc<PayloadEncryptor>>,
+    plaintext_action_types: std::collections::HashSet<String>,
+    fingerprint_config: acteon_core::FingerprintConfig,
+    health_hysteresis: acteon_core::HealthHysteresis,
     wasm_runtime: Option<Arc<dyn acteon_wasm_runtime::WasmPluginRuntime>>,
     compliance_config: Option<acteon_core::ComplianceConfig>,
+    audit_batch: Option<acteon_audit::AuditBatchConfig>,
     enrichments: Vec<EnrichmentConfig>,
     resource_lookups: HashMap<String, Arc<dyn ResourceLookup>>,
     templates: HashMap<(String, String), HashMap<String, acteon_core::Template>>,
@@ -69,6 +83,7 @@ pub struct GatewayBuilder {
     time_intervals: Vec<acteon_core::TimeInterval>,
     max_inline_bytes: u64,
     max_attachments_per_action: usize,
+    follow_on_tx: Option<tokio::sync::mpsc::Sender<crate::follow_on::FollowOnEvent>>,
 }
 
 impl GatewayBuilder {
@@ -78,9 +93,12 @@ impl GatewayBuilder {
             state: None,
             lock: None,
             rules: Vec::new(),
+            rule_budget: EvalBudget::default(),
+            rule_history_limit: acteon_rules::DEFAULT_RULE_HISTORY,
             providers: ProviderRegistry::new(),
             executor_config: ExecutorConfig::default(),
             environment: HashMap::new(),
+            holiday_calendars: acteon_rules::HolidayCalendars::new(),
             audit: None,
             audit_ttl_seconds: None,
             audit_store_payload: true,
@@ -101,16 +119,27 @@ impl GatewayBuilder {
             llm_fail_open: false,
             chains: HashMap::new(),
             completed_chain_ttl: None,
+            chain_limits: acteon_core::ChainLimits::default(),
             embedding: None,
             default_timezone: None,
             circuit_breaker_default: None,
             circuit_breaker_overrides: HashMap::new(),
+            retry_budget: None,
             stream_buffer_size: 1024,
             quota_policies: Vec::new(),
             retention_policies: HashMap::new(),
+            dedup_normalization: HashMap::new(),
+            clock: Arc::new(chrono::Utc::now),
+            provider_payload_defaults: HashMap::new(),
+            namespace_defaults: HashMap::new(),
+            provider_failover: HashMap::new(),
             payload_encryptor: None,
+            plaintext_action_types: std::collections::HashSet::new(),
+            fingerprint_config: acteon_core::FingerprintConfig::default(),
+            health_hysteresis: acteon_core::HealthHysteresis::default(),
             wasm_runtime: None,
             compliance_config: None,
+            audit_batch: None,
             enrichments: Vec::new(),
             resource_lookups: HashMap::new(),
             templates: HashMap::new(),
@@ -119,6 +148,7 @@ impl GatewayBuilder {
             time_intervals: Vec::new(),
             max_inline_bytes: 5_242_880,
             max_attachments_per_action: 10,
+            follow_on_tx: None,
         }
     }
 
@@ -143,6 +173,22 @@ impl GatewayBuilder {
         self
     }
 
+    /// Bound how many rules (or how much time) a single evaluation pass
+    /// may use, and whether a cutoff allows or denies the action. The
+    /// budget survives rule reloads.
+    #[must_use]
+    pub fn rule_evaluation_budget(mut self, budget: EvalBudget) -> Self {
+        self.rule_budget = budget;
+        self
+    }
+
+    /// Set how many replaced rule sets are kept for rollback (default 5).
+    #[must_use]
+    pub fn rule_history_limit(mut self, limit: usize) -> Self {
+        self.rule_history_limit = limit;
+        self
+    }
+
     /// Register a provider with the gateway.
     #[must_use]
     pub fn provider(mut self, provider: Arc<dyn DynProvider>) -> Self {
@@ -164,6 +210,18 @@ impl GatewayBuilder {
         self
     }
 
+    /// Add a named holiday calendar consulted by `business_hours` rule
+    /// conditions. Dates in the calendar are never business hours.
+    #[must_use]
+    pub fn holiday_calendar(
+        mut self,
+        name: impl Into<String>,
+        dates: impl IntoIterator<Item = chrono::NaiveDate>,
+    ) -> Self {
+        self.holiday_calendars.insert(name, dates);
+        self
+    }
+
     /// Set the audit store for recording dispatch events.
     #[must_use]
     pub fn audit(mut self, store: Arc<dyn AuditStore>) -> Self {
@@ -178,6 +236,17 @@ impl GatewayBuilder {
         self
     }
 
+    /// Batch audit writes instead of spawning one write per dispatch.
+    ///
+    /// Records are flushed to the audit store in batches, by count or by
+    /// interval. Ignored when the compliance configuration requires
+    /// synchronous audit writes.
+    #[must_use]
+    pub fn audit_batching(mut self, config: acteon_audit::AuditBatchConfig) -> Self {
+        self.audit_batch = Some(config);
+        self
+    }
+
     /// Set whether to store the action payload in audit records.
     #[must_use]
     pub fn audit_store_payload(mut self, store: bool) -> Self {
@@ -324,6 +393,15 @@ impl GatewayBuilder {
         self
     }
 
+    /// Set the depth, step, and fan-out limits enforced on chain executions.
+    ///
+    /// Defaults to [`ChainLimits::default`](acteon_core::ChainLimits::default).
+    #[must_use]
+    pub fn chain_limits(mut self, limits: acteon_core::ChainLimits) -> Self {
+        self.chain_limits = limits;
+        self
+    }
+
     /// Set the embedding support for semantic matching in rule conditions.
     #[must_use]
     pub fn embedding_support(
@@ -374,6 +452,18 @@ impl GatewayBuilder {
         self
     }
 
+    /// Cap retries per provider with a token-bucket retry budget.
+    ///
+    /// The budget is shared by every action sent to a provider, so retries
+    /// against a failing provider are throttled before its circuit breaker
+    /// trips. Actions that hit an empty budget fail with reason code
+    /// `RETRY_BUDGET_EXHAUSTED`.
+    #[must_use]
+    pub fn retry_budget(mut self, config: acteon_executor::RetryBudgetConfig) -> Self {
+        self.retry_budget = Some(config);
+        self
+    }
+
     /// Set the buffer size for the SSE broadcast channel (default: 1024).
     ///
     /// This controls how many events the broadcast channel can hold before
@@ -422,6 +512,106 @@ impl GatewayBuilder {
         self
     }
 
+    /// Normalize dedup keys for a namespace before they are compared.
+    ///
+    /// Namespaces without an entry compare dedup keys exactly.
+    #[must_use]
+    pub fn dedup_normalization(
+        mut self,
+        namespace: impl Into<String>,
+        normalization: acteon_core::DedupKeyNormalization,
+    ) -> Self {
+        self.dedup_normalization
+            .insert(namespace.into(), normalization);
+        self
+    }
+
+    /// Override the clock used to measure dedup windows.
+    ///
+    /// Defaults to the system clock. Tests and simulations can inject a
+    /// controllable clock to step through window boundaries.
+    #[must_use]
+    pub fn clock(
+        mut self,
+        clock: impl Fn() -> chrono::DateTime<chrono::Utc> + Send + Sync + 'static,
+    ) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Register default payload fields for actions sent to `provider` in
+    /// `namespace`.
+    ///
+    /// `defaults` is deep-merged into the payload of each action executed by
+    /// `provider`, including actions rerouted or failed over to it; fields
+    /// the action sets take precedence. Calling this again for the same pair
+    /// replaces the earlier defaults.
+    #[must_use]
+    pub fn provider_payload_defaults(
+        mut self,
+        namespace: impl Into<String>,
+        provider: impl Into<String>,
+        defaults: serde_json::Value,
+    ) -> Self {
+        self.provider_payload_defaults
+            .insert((namespace.into(), provider.into()), defaults);
+        self
+    }
+
+    /// Set the default provider and action type for actions in `namespace`
+    /// that omit them.
+    ///
+    /// Explicit action fields always take precedence. Calling this again for
+    /// the same namespace replaces the earlier defaults.
+    #[must_use]
+    pub fn namespace_defaults(
+        mut self,
+        namespace: impl Into<String>,
+        defaults: acteon_core::NamespaceDefaults,
+    ) -> Self {
+        self.namespace_defaults.insert(namespace.into(), defaults);
+        self
+    }
+
+    /// Fail over to `fallbacks`, in order, when `provider` fails to execute
+    /// an action.
+    ///
+    /// Each fallback is tried only after the previous provider returned a
+    /// failure, and the first success ends the delivery with a `Rerouted`
+    /// outcome. Fallbacks whose circuit is open are skipped. Calling this
+    /// again for the same provider replaces the earlier list.
+    #[must_use]
+    pub fn provider_failover(
+        mut self,
+        provider: impl Into<String>,
+        fallbacks: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.provider_failover.insert(
+            provider.into(),
+            fallbacks.into_iter().map(Into::into).collect(),
+        );
+        self
+    }
+
+    /// Hand rule follow-on actions to `tx` instead of dispatching them
+    /// inline.
+    ///
+    /// The receiver is expected to dispatch each
+    /// [`FollowOnEvent`](crate::follow_on::FollowOnEvent) with
+    /// [`Gateway::dispatch_follow_on`](crate::Gateway::dispatch_follow_on),
+    /// so a follow-on's latency and errors never reach the action that
+    /// triggered it. A full channel delays the triggering dispatch until
+    /// there is room. Without a channel, follow-ons are dispatched inline
+    /// before [`Gateway::dispatch`](crate::Gateway::dispatch) returns.
+    #[must_use]
+    pub fn follow_on_channel(
+        mut self,
+        tx: tokio::sync::mpsc::Sender<crate::follow_on::FollowOnEvent>,
+    ) -> Self {
+        self.follow_on_tx = Some(tx);
+        self
+    }
+
     /// Set the payload encryptor for encrypting action payloads at rest.
     ///
     /// When set, the gateway encrypts payload-carrying state values before
@@ -433,6 +623,49 @@ impl GatewayBuilder {
         self
     }
 
+    /// Store payloads of these action types in plaintext by default.
+    ///
+    /// Applies to the audit trail and state store writes of actions that
+    /// leave [`Action::encrypt_payload`](acteon_core::Action::encrypt_payload)
+    /// unset. Ignored when the compliance config forbids the opt-out.
+    #[must_use]
+    pub fn plaintext_action_types<I, S>(mut self, action_types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.plaintext_action_types = action_types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the fingerprint algorithm version and migration window.
+    ///
+    /// State machine events are keyed by fingerprint. When upgrading the
+    /// version, set [`FingerprintConfig::dual_read_version`] to the old one
+    /// so events already in flight keep their state until they resolve.
+    /// Defaults to [`FingerprintVersion::V1`] with no dual read.
+    ///
+    /// [`FingerprintConfig::dual_read_version`]: acteon_core::FingerprintConfig::dual_read_version
+    /// [`FingerprintVersion::V1`]: acteon_core::FingerprintVersion::V1
+    #[must_use]
+    pub fn fingerprint_config(mut self, config: acteon_core::FingerprintConfig) -> Self {
+        self.fingerprint_config = config;
+        self
+    }
+
+    /// Set how many consecutive health check results it takes to change a
+    /// provider's reported health.
+    ///
+    /// Defaults to [`HealthHysteresis::default`]: three failed checks to mark
+    /// a provider unhealthy, two passed checks to mark it healthy again.
+    ///
+    /// [`HealthHysteresis::default`]: acteon_core::HealthHysteresis
+    #[must_use]
+    pub fn health_hysteresis(mut self, hysteresis: acteon_core::HealthHysteresis) -> Self {
+        self.health_hysteresis = hysteresis;
+        self
+    }
+
     /// Set the WASM plugin runtime for evaluating `WasmCall` expressions in rules.
     ///
     /// When set, rules containing `wasm()` conditions can invoke registered
@@ -564,7 +797,14 @@ impl GatewayBuilder {
     fn validate_and_wrap_quota_policies(
         policies: Vec<acteon_core::QuotaPolicy>,
     ) -> Result<HashMap<String, crate::gateway::CachedPolicy>, GatewayError> {
-        type DedupeKey = (String, String, Option<String>, Option<String>, bool);
+        type DedupeKey = (
+            String,
+            String,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            bool,
+        );
         for policy in &policies {
             let label = format!(
                 "{}:{}{}",
@@ -580,7 +820,7 @@ impl GatewayBuilder {
                 GatewayError::Configuration(format!("quota policy '{label}' invalid: {e}"))
             })?;
         }
-        // Reject duplicate (ns, tenant, provider, principal,
+        // Reject duplicate (ns, tenant, org, provider, principal,
         // per_principal) tuples — operators should pick exactly one
         // policy per scope; silent override would be surprising.
         // `per_principal` is part of the key because a shared-bucket
@@ -592,15 +832,17 @@ impl GatewayBuilder {
             let key = (
                 policy.namespace.clone(),
                 policy.tenant.clone(),
+                policy.org.clone(),
                 policy.provider.clone(),
                 policy.principal.clone(),
                 policy.per_principal,
             );
             if let Some(existing_id) = seen.get(&key) {
                 return Err(GatewayError::Configuration(format!(
-                    "duplicate quota policy for (namespace={}, tenant={}, provider={:?}, principal={:?}, per_principal={}): ids {existing_id} and {}",
+                    "duplicate quota policy for (namespace={}, tenant={}, org={:?}, provider={:?}, principal={:?}, per_principal={}): ids {existing_id} and {}",
                     policy.namespace,
                     policy.tenant,
+                    policy.org,
                     policy.provider,
                     policy.principal,
                     policy.per_principal,
@@ -705,6 +947,39 @@ impl GatewayBuilder {
         Ok(Some(registry))
     }
 
+    /// Check that every failover route names registered providers and never
+    /// falls back to its own primary.
+    fn validate_provider_failover(
+        routes: &HashMap<String, Vec<String>>,
+        providers: &ProviderRegistry,
+    ) -> Result<(), GatewayError> {
+        for (primary, fallbacks) in routes {
+            if providers.get(primary).is_none() {
+                return Err(GatewayError::Configuration(format!(
+                    "failover route references unknown provider '{primary}'"
+                )));
+            }
+            if fallbacks.is_empty() {
+                return Err(GatewayError::Configuration(format!(
+                    "failover route for '{primary}' has no fallback providers"
+                )));
+            }
+            for fallback in fallbacks {
+                if fallback == primary {
+                    return Err(GatewayError::Configuration(format!(
+                        "failover route for '{primary}' lists itself as a fallback"
+                    )));
+                }
+                if providers.get(fallback).is_none() {
+                    return Err(GatewayError::Configuration(format!(
+                        "failover route for '{primary}' references unknown fallback provider '{fallback}'"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Consume the builder and produce a configured [`Gateway`].
     ///
     /// Returns a [`GatewayError::Configuration`] if required fields
@@ -730,28 +1005,45 @@ impl GatewayBuilder {
             })
             .transpose()?;
 
-        let engine = RuleEngine::new(self.rules);
+        let engine = RuleEngine::new(self.rules)
+            .with_budget(self.rule_budget)
+            .with_history_limit(self.rule_history_limit);
+
+        // Create the broadcast channel for SSE event streaming.
+        let (stream_tx, _) = tokio::sync::broadcast::channel(self.stream_buffer_size);
 
-        // Create the DLQ if enabled, wrapping with encryption if configured.
+        // Create the DLQ if enabled, wrapping with encryption if configured
+        // and announcing every new entry on the event stream.
         let dlq: Option<Arc<dyn DeadLetterSink>> = if self.dlq_enabled {
             let raw_dlq = self.dlq.unwrap_or_else(|| Arc::new(DeadLetterQueue::new()));
-            if let Some(ref enc) = self.payload_encryptor {
-                Some(Arc::new(
-                    crate::encrypting_dlq::EncryptingDeadLetterSink::new(raw_dlq, Arc::clone(enc)),
+            let stored: Arc<dyn DeadLetterSink> = if let Some(ref enc) = self.payload_encryptor {
+                Arc::new(crate::encrypting_dlq::EncryptingDeadLetterSink::new(
+                    raw_dlq,
+                    Arc::clone(enc),
                 ))
             } else {
-                Some(raw_dlq)
-            }
+                raw_dlq
+            };
+            Some(Arc::new(
+                crate::streaming_dlq::StreamingDeadLetterSink::new(stored, stream_tx.clone()),
+            ))
         } else {
             None
         };
 
-        // Create the executor with optional DLQ.
-        let executor = if let Some(ref dlq_sink) = dlq {
+        // Create the executor with optional DLQ and retry budget.
+        let mut executor = if let Some(ref dlq_sink) = dlq {
             acteon_executor::ActionExecutor::with_dlq(self.executor_config, Arc::clone(dlq_sink))
         } else {
             acteon_executor::ActionExecutor::new(self.executor_config)
         };
+        if let Some(budget) = self.retry_budget {
+            budget
+                .validate()
+                .map_err(|e| GatewayError::Configuration(format!("retry budget: {e}")))?;
+            executor =
+                executor.with_retry_budget(Arc::new(acteon_executor::RetryBudget::new(budget)));
+        }
 
         // Use provided group manager or create a new one.
         let group_manager = self
@@ -781,6 +1073,8 @@ impl GatewayBuilder {
             Arc::clone(&lock),
         )?;
 
+        Self::validate_provider_failover(&self.provider_failover, &self.providers)?;
+
         let quota_policies = Self::validate_and_wrap_quota_policies(self.quota_policies)?;
 
         // Validate the sub-chain reference graph (dangling refs + cycles).
@@ -800,9 +1094,6 @@ impl GatewayBuilder {
             .map(|(name, config)| (name.clone(), config.step_index_map()))
             .collect();
 
-        // Create the broadcast channel for SSE event streaming.
-        let (stream_tx, _) = tokio::sync::broadcast::channel(self.stream_buffer_size);
-
         // Wrap the audit store with compliance decorators when configured.
         let mut hash_chain_store: Option<Arc<acteon_audit::HashChainAuditStore>> = None;
         let audit: Option<Arc<dyn AuditStore>> = if let Some(audit_store) = self.audit {
@@ -830,6 +1121,29 @@ impl GatewayBuilder {
             None
         };
 
+        // Batch asynchronous audit writes. The batcher wraps the compliance
+        // decorators so hash-chained records are still written one tenant
+        // chain at a time, in dispatch order.
+        let sync_audit_writes = self
+            .compliance_config
+            .as_ref()
+            .is_some_and(|c| c.sync_audit_writes);
+        let metrics = Arc::new(GatewayMetrics::default());
+        let mut audit_batcher: Option<Arc<acteon_audit::BatchingAuditStore>> = None;
+        let audit = match (audit, self.audit_batch) {
+            (Some(store), Some(batch)) if !sync_audit_writes => {
+                let drop_metrics = Arc::clone(&metrics);
+                let batcher = Arc::new(acteon_audit::BatchingAuditStore::with_drop_hook(
+                    store,
+                    &batch,
+                    move |count| drop_metrics.add_audit_records_dropped(count),
+                ));
+                audit_batcher = Some(Arc::clone(&batcher));
+                Some(batcher as Arc<dyn AuditStore>)
+            }
+            (audit, _) => audit,
+        };
+
         Ok(Gateway {
             state,
             lock,
@@ -837,7 +1151,8 @@ impl GatewayBuilder {
             providers: self.providers,
             executor,
             environment: self.environment,
-            metrics: Arc::new(GatewayMetrics::default()),
+            holiday_calendars: self.holiday_calendars,
+            metrics,
             audit,
             audit_ttl_seconds: self.audit_ttl_seconds,
             audit_store_payload: self.audit_store_payload,
@@ -855,17 +1170,28 @@ impl GatewayBuilder {
             chain_step_indices: parking_lot::RwLock::new(chain_step_indices),
             pinned_config_cache: parking_lot::RwLock::new(std::collections::HashMap::new()),
             completed_chain_ttl: self.completed_chain_ttl,
+            chain_limits: self.chain_limits,
             embedding: self.embedding,
             default_timezone,
             circuit_breakers,
             stream_tx,
             quota_policies: parking_lot::RwLock::new(quota_policies),
             retention_policies: parking_lot::RwLock::new(self.retention_policies),
+            dedup_normalization: self.dedup_normalization,
+            clock: self.clock,
+            provider_payload_defaults: self.provider_payload_defaults,
+            namespace_defaults: self.namespace_defaults,
+            provider_failover: self.provider_failover,
             payload_encryptor: self.payload_encryptor,
+            plaintext_action_types: self.plaintext_action_types,
+            fingerprint_config: self.fingerprint_config,
+            health_hysteresis: self.health_hysteresis,
+            provider_health: parking_lot::Mutex::new(HashMap::new()),
             provider_metrics: Arc::new(crate::metrics::ProviderMetrics::default()),
             wasm_runtime: self.wasm_runtime,
             compliance_config: self.compliance_config,
             hash_chain_store,
+            audit_batcher,
             enrichments: self.enrichments,
             resource_lookups: self.resource_lookups,
             templates: parking_lot::RwLock::new(self.templates),
@@ -876,6 +1202,8 @@ impl GatewayBuilder {
             )?),
             max_inline_bytes: self.max_inline_bytes,
             max_attachments_per_action: self.max_attachments_per_action,
+            follow_on_tx: self.follow_on_tx,
+            attachment_store: acteon_core::AttachmentStore::new(),
             sync_versions: crate::sync_state::SyncVersionTracker::default(),
         })
     }
@@ -1117,6 +1445,8 @@ mod tests {
             id: "q-bad".into(),
             namespace: "ns".into(),
             tenant: "t".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -1128,6 +1458,7 @@ mod tests {
             updated_at: chrono::Utc::now(),
             description: None,
             labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
         };
         let result = GatewayBuilder::new()
             .state(store)
@@ -1152,6 +1483,8 @@ mod tests {
             id: "q-bad2".into(),
             namespace: "ns".into(),
             tenant: "t".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -1163,6 +1496,7 @@ mod tests {
             updated_at: chrono::Utc::now(),
             description: None,
             labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
         };
         let result = GatewayBuilder::new()
             .state(store)
@@ -1207,6 +1541,34 @@ mod tests {
         assert!(result.is_ok(), "A→B→C (no cycle) should be accepted");
     }
 
+    #[test]
+    fn build_rejects_unknown_failover_provider() {
+        let result = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::new(StubProvider::new("sms-a")))
+            .provider_failover("sms-a", ["sms-b"])
+            .build();
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("unknown fallback provider 'sms-b'")
+        );
+    }
+
+    #[test]
+    fn build_rejects_self_referencing_failover() {
+        let result = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::new(StubProvider::new("sms-a")))
+            .provider(Arc::new(StubProvider::new("sms-b")))
+            .provider_failover("sms-a", ["sms-b", "sms-a"])
+            .build();
+        assert!(result.unwrap_err().to_string().contains("lists itself"));
+    }
+
     #[test]
     fn builder_wasm_runtime() {
         use acteon_wasm_runtime::MockWasmRuntime;
@@ -134,6 +134,19 @@ pub async fn apply_enrichments<S: BuildHasher>(
     action: &mut Action,
     enrichments: &[EnrichmentConfig],
     resource_lookups: &HashMap<String, Arc<dyn ResourceLookup>, S>,
+) -> Result<Vec<EnrichmentOutcome>, GatewayError> {
+    let mut outputs = HashMap::new();
+    apply_enrichments_collecting(action, enrichments, resource_lookups, &mut outputs).await
+}
+
+/// Like [`apply_enrichments`], but also records each successful lookup result
+/// in `outputs`, keyed by enrichment name, so rule conditions can reference
+/// it as `enrichment.<name>`.
+pub async fn apply_enrichments_collecting<S: BuildHasher, H: BuildHasher>(
+    action: &mut Action,
+    enrichments: &[EnrichmentConfig],
+    resource_lookups: &HashMap<String, Arc<dyn ResourceLookup>, S>,
+    outputs: &mut HashMap<String, serde_json::Value, H>,
 ) -> Result<Vec<EnrichmentOutcome>, GatewayError> {
     let mut outcomes = Vec::new();
 
@@ -194,6 +207,7 @@ pub async fn apply_enrichments<S: BuildHasher>(
                     elapsed_ms,
                     "enrichment lookup succeeded"
                 );
+                outputs.insert(config.name.clone(), data.clone());
                 // Merge the result into the payload.
                 if let Some(obj) = action.payload.as_object_mut() {
                     obj.insert(config.merge_key.clone(), data);
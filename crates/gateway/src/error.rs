@@ -56,6 +56,10 @@ pub enum GatewayError {
     #[error("attachment error: {0}")]
     Attachment(String),
 
+    /// The action names no provider and its namespace sets no default.
+    #[error("action has no provider and namespace '{0}' sets no default provider")]
+    MissingProvider(String),
+
     /// The pre-execution **intent** audit record could not be durably
     /// persisted in compliance mode (`sync_audit_writes`). The gateway fails
     /// **closed before executing the provider**, so the action did NOT run —
@@ -74,6 +78,7 @@ impl GatewayError {
         match self {
             Self::Provider(e) => format!("provider error: {}", e.public_message()),
             Self::ProviderNotFound(name) => format!("provider '{name}' not found"),
+            Self::MissingProvider(_) => self.to_string(),
             Self::TemplateRender(_) => {
                 "template rendering failed due to internal error".to_string()
             }
@@ -0,0 +1,178 @@
+//! Bounded transition history for state machine events.
+//!
+//! Every state change of an event — whether driven by a dispatched action,
+//! a manual transition through the API, or an expired timeout — is appended
+//! to a per-fingerprint list stored under [`KeyKind::EventHistory`]. The list
+//! is capped at [`MAX_EVENT_HISTORY`] entries, oldest first, so post-mortems
+//! can reconstruct how an event reached its current state.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use acteon_core::{EventTransition, MAX_EVENT_HISTORY, TransitionTrigger, push_bounded_history};
+use acteon_crypto::PayloadEncryptor;
+use acteon_state::{KeyKind, StateKey, StateStore};
+
+use crate::error::GatewayError;
+use crate::gateway::Gateway;
+
+/// Name of the lock that serializes every write to an event's state and
+/// history.
+pub(crate) fn event_lock_name(namespace: &str, tenant: &str, fingerprint: &str) -> String {
+    format!("state:{namespace}:{tenant}:{fingerprint}")
+}
+
+/// Store `state_value` as the state of `fingerprint` and append `transition`
+/// to its history.
+///
+/// The caller must hold the [`event_lock_name`] lock across this call, so no
+/// other writer can interleave with the history read-modify-write or land
+/// between the two writes. The history is written first, so any state a
+/// reader sees is already in the history.
+pub(crate) async fn write_event_state(
+    state: &dyn StateStore,
+    encryptor: Option<&Arc<PayloadEncryptor>>,
+    state_key: &StateKey,
+    state_value: &str,
+    transition: Option<EventTransition>,
+) -> Result<(), GatewayError> {
+    if let Some(transition) = transition {
+        let key = StateKey::new(
+            state_key.namespace.as_str(),
+            state_key.tenant.as_str(),
+            KeyKind::EventHistory,
+            state_key.id.as_str(),
+        );
+        let mut history = load_history(state, encryptor, &key).await?;
+        push_bounded_history(&mut history, transition);
+        let json = serde_json::to_string(&history).map_err(|e| {
+            GatewayError::Configuration(format!("event history encode failed: {e}"))
+        })?;
+        state.set(&key, &encrypt(encryptor, &json)?, None).await?;
+    }
+    state
+        .set(state_key, &encrypt(encryptor, state_value)?, None)
+        .await?;
+    Ok(())
+}
+
+fn encrypt(encryptor: Option<&Arc<PayloadEncryptor>>, value: &str) -> Result<String, GatewayError> {
+    match encryptor {
+        Some(enc) => enc
+            .encrypt_str(value)
+            .map_err(|e| GatewayError::Configuration(format!("payload encryption failed: {e}"))),
+        None => Ok(value.to_owned()),
+    }
+}
+
+async fn load_history(
+    state: &dyn StateStore,
+    encryptor: Option<&Arc<PayloadEncryptor>>,
+    key: &StateKey,
+) -> Result<Vec<EventTransition>, GatewayError> {
+    let Some(raw) = state.get(key).await? else {
+        return Ok(Vec::new());
+    };
+    let json = match encryptor {
+        Some(enc) => enc
+            .decrypt_str(&raw)
+            .map_err(|e| GatewayError::Configuration(format!("payload decryption failed: {e}")))?,
+        None => raw,
+    };
+    // A corrupt history must not block further transitions; start afresh.
+    let mut history: Vec<EventTransition> = serde_json::from_str(&json).unwrap_or_default();
+    if history.len() > MAX_EVENT_HISTORY {
+        history.drain(..history.len() - MAX_EVENT_HISTORY);
+    }
+    Ok(history)
+}
+
+impl Gateway {
+    /// Move an event to state `to` by hand and record the transition in its
+    /// history.
+    ///
+    /// Returns the state the event was in, or `None` if the event does not
+    /// exist.
+    pub async fn transition_event(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        fingerprint: &str,
+        to: &str,
+        actor: &str,
+    ) -> Result<Option<String>, GatewayError> {
+        let guard = self
+            .lock
+            .acquire(
+                &event_lock_name(namespace, tenant, fingerprint),
+                Duration::from_secs(30),
+                Duration::from_secs(5),
+            )
+            .await
+            .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
+        let result = self
+            .transition_event_locked(namespace, tenant, fingerprint, to, actor)
+            .await;
+        guard
+            .release()
+            .await
+            .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
+        result
+    }
+
+    async fn transition_event_locked(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        fingerprint: &str,
+        to: &str,
+        actor: &str,
+    ) -> Result<Option<String>, GatewayError> {
+        let state_key = StateKey::new(namespace, tenant, KeyKind::EventState, fingerprint);
+        let Some(raw) = self.state.get(&state_key).await? else {
+            return Ok(None);
+        };
+        let value = self.decrypt_state_value(&raw).unwrap_or(raw);
+        let parsed: serde_json::Value =
+            serde_json::from_str(&value).unwrap_or(serde_json::json!({"state": value}));
+        let current = parsed
+            .get("state")
+            .and_then(|s| s.as_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        // Keep the event linked to its state machine.
+        let mut new_value = serde_json::json!({
+            "state": to,
+            "fingerprint": fingerprint,
+            "updated_at": chrono::Utc::now().to_rfc3339(),
+        });
+        if let Some(state_machine) = parsed.get("state_machine") {
+            new_value["state_machine"] = state_machine.clone();
+        }
+        let transition = (current != to)
+            .then(|| EventTransition::new(current.as_str(), to, actor, TransitionTrigger::Manual));
+        write_event_state(
+            self.state.as_ref(),
+            self.payload_encryptor.as_ref(),
+            &state_key,
+            &new_value.to_string(),
+            transition,
+        )
+        .await?;
+        Ok(Some(current))
+    }
+
+    /// Return the recorded transitions of an event, oldest first.
+    ///
+    /// Events that have never changed state have an empty history.
+    pub async fn event_history(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        fingerprint: &str,
+    ) -> Result<Vec<EventTransition>, GatewayError> {
+        let key = StateKey::new(namespace, tenant, KeyKind::EventHistory, fingerprint);
+        load_history(self.state.as_ref(), self.payload_encryptor.as_ref(), &key).await
+    }
+}
@@ -0,0 +1,127 @@
+//! Per-tenant feature flag store.
+//!
+//! Each `(namespace, tenant)` has a single [`TenantFeatureFlags`] record in
+//! the state store. The dispatch pipeline loads it before rule evaluation,
+//! so rules can branch on `flags.<name>` and providers can read the flags
+//! from their [`DispatchContext`](acteon_provider::DispatchContext). A flag
+//! change therefore takes effect on the next dispatch, with no reload.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use tracing::warn;
+
+use acteon_core::{FeatureFlags, TenantFeatureFlags};
+use acteon_state::{KeyKind, StateKey};
+
+use crate::error::GatewayError;
+use crate::gateway::Gateway;
+
+/// How long a flag mutation may hold the per-tenant lock.
+const FLAG_LOCK_TTL: Duration = Duration::from_secs(10);
+
+/// How long a flag mutation waits for the per-tenant lock.
+const FLAG_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+fn feature_flags_state_key(namespace: &str, tenant: &str) -> StateKey {
+    StateKey::new(namespace, tenant, KeyKind::FeatureFlags, "flags")
+}
+
+impl Gateway {
+    /// Return the feature flags of a `(namespace, tenant)` scope.
+    ///
+    /// A scope with no stored flags yields an empty record.
+    pub async fn get_feature_flags(
+        &self,
+        namespace: &str,
+        tenant: &str,
+    ) -> Result<TenantFeatureFlags, GatewayError> {
+        let key = feature_flags_state_key(namespace, tenant);
+        match self.state.get(&key).await? {
+            Some(raw) => serde_json::from_str(&raw).map_err(|e| {
+                GatewayError::Configuration(format!(
+                    "corrupt feature flags for {namespace}:{tenant}: {e}"
+                ))
+            }),
+            None => Ok(TenantFeatureFlags::new(namespace, tenant)),
+        }
+    }
+
+    /// Set a single flag, creating the scope's record if needed.
+    pub async fn set_feature_flag(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        name: &str,
+        enabled: bool,
+    ) -> Result<TenantFeatureFlags, GatewayError> {
+        self.update_feature_flags(namespace, tenant, |flags| {
+            flags.set(name, enabled);
+            true
+        })
+        .await
+        .map(|(record, _)| record)
+    }
+
+    /// Remove a single flag. Returns `false` if the flag was not set.
+    pub async fn delete_feature_flag(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        name: &str,
+    ) -> Result<bool, GatewayError> {
+        self.update_feature_flags(namespace, tenant, |flags| flags.remove(name).is_some())
+            .await
+            .map(|(_, changed)| changed)
+    }
+
+    /// Load the flags used for dispatch. Failures are logged and treated as
+    /// "no flags" so a state-store hiccup never blocks dispatch.
+    pub(crate) async fn load_feature_flags(&self, namespace: &str, tenant: &str) -> FeatureFlags {
+        match self.get_feature_flags(namespace, tenant).await {
+            Ok(record) => record.flags,
+            Err(e) => {
+                warn!(namespace, tenant, error = %e, "failed to load feature flags, treating all as disabled");
+                FeatureFlags::new()
+            }
+        }
+    }
+
+    /// Read-modify-write the scope's record under a per-tenant lock so that
+    /// concurrent updates to different flags are not lost. `mutate` returns
+    /// whether it changed anything; unchanged records are not rewritten.
+    async fn update_feature_flags(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        mutate: impl FnOnce(&mut FeatureFlags) -> bool,
+    ) -> Result<(TenantFeatureFlags, bool), GatewayError> {
+        let lock_name = format!("feature-flags:{namespace}:{tenant}");
+        let guard = self
+            .lock
+            .acquire(&lock_name, FLAG_LOCK_TTL, FLAG_LOCK_TIMEOUT)
+            .await
+            .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
+
+        let result = async {
+            let mut record = self.get_feature_flags(namespace, tenant).await?;
+            let changed = mutate(&mut record.flags);
+            if changed {
+                record.updated_at = Some(Utc::now());
+                let key = feature_flags_state_key(namespace, tenant);
+                let raw = serde_json::to_string(&record).map_err(|e| {
+                    GatewayError::Configuration(format!("failed to serialize feature flags: {e}"))
+                })?;
+                self.state.set(&key, &raw, None).await?;
+            }
+            Ok((record, changed))
+        }
+        .await;
+
+        guard
+            .release()
+            .await
+            .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
+        result
+    }
+}
@@ -0,0 +1,302 @@
+//! Follow-on action dispatch for rules with `on_success` / `on_failure`.
+//!
+//! A follow-on is a lightweight alternative to a full task chain: once the
+//! primary action's verdict has been executed, the matched rule may declare
+//! a single action to dispatch next, depending on whether execution
+//! succeeded or failed. The follow-on runs through the full dispatch
+//! pipeline (rules, quotas, audit) in the originating scope, off the
+//! triggering action's request path: it is handed to the gateway's
+//! follow-on channel and dispatched by whoever drains it.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+use tracing::{info, warn};
+
+use acteon_core::{Action, ActionOutcome, Caller, ResponseStatus};
+use acteon_rules::FollowOnAction;
+
+use crate::gateway::Gateway;
+
+/// Metadata label carrying the originating action ID on a follow-on action.
+pub const CAUSED_BY_LABEL: &str = "caused_by";
+
+/// Metadata label carrying the name of the rule that triggered a follow-on.
+pub const CAUSED_BY_RULE_LABEL: &str = "caused_by_rule";
+
+/// Which follow-on trigger (if any) an outcome fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FollowOnTrigger {
+    Success,
+    Failure,
+}
+
+impl FollowOnTrigger {
+    /// Classify a dispatch outcome. Only outcomes where a provider was
+    /// actually attempted fire a trigger; suppressed, grouped, deferred,
+    /// and similar outcomes never do.
+    pub(crate) fn from_outcome(outcome: &ActionOutcome) -> Option<Self> {
+        match outcome {
+            ActionOutcome::Executed(resp) | ActionOutcome::Rerouted { response: resp, .. } => {
+                if resp.status == ResponseStatus::Failure {
+                    Some(Self::Failure)
+                } else {
+                    Some(Self::Success)
+                }
+            }
+            ActionOutcome::Failed(_) | ActionOutcome::CircuitOpen { .. } => Some(Self::Failure),
+            _ => None,
+        }
+    }
+}
+
+/// Build the follow-on action from its template and the originating action.
+///
+/// The payload template is resolved with the chain `{{origin.*}}` syntax,
+/// the originating trace context is carried over, and the causal link is
+/// recorded in metadata.
+pub(crate) fn build_follow_on_action(
+    template: &FollowOnAction,
+    origin: &Action,
+    rule_name: &str,
+) -> Action {
+    let payload = crate::chain::resolve_template(
+        &template.payload,
+        origin,
+        &[],
+        &[],
+        "",
+        0,
+        &[],
+        &HashMap::new(),
+    );
+    let mut action = Action::new(
+        origin.namespace.clone(),
+        origin.tenant.clone(),
+        template.provider.as_str(),
+        template.action_type.clone(),
+        payload,
+    )
+    .with_trace_context(origin.trace_context.clone());
+    action
+        .metadata
+        .labels
+        .insert(CAUSED_BY_LABEL.to_owned(), origin.id.to_string());
+    action
+        .metadata
+        .labels
+        .insert(CAUSED_BY_RULE_LABEL.to_owned(), rule_name.to_owned());
+    action
+}
+
+/// A follow-on action waiting to be dispatched off the request path.
+///
+/// Sent on the channel set with
+/// [`GatewayBuilder::follow_on_channel`](crate::GatewayBuilder::follow_on_channel);
+/// pass it to [`Gateway::dispatch_follow_on`] to run it.
+#[derive(Debug, Clone)]
+pub struct FollowOnEvent {
+    /// The follow-on action, already linked to its origin.
+    pub action: Action,
+    /// The caller of the action that triggered the follow-on.
+    pub caller: Option<Caller>,
+}
+
+impl Gateway {
+    /// Enqueue the matched rule's follow-on action for `outcome`, if any.
+    ///
+    /// Follow-ons are single-hop: an action that is itself a follow-on
+    /// (carries [`CAUSED_BY_LABEL`]) never triggers another one, so a pair
+    /// of rules cannot ping-pong indefinitely. Without a follow-on channel
+    /// the action is dispatched inline.
+    ///
+    /// Returns a boxed future so the inline call into [`Gateway::dispatch`]
+    /// does not make the pipeline's own future type contain itself.
+    pub(crate) fn enqueue_follow_on<'a>(
+        &'a self,
+        origin: &'a Action,
+        matched_rule: Option<&'a str>,
+        outcome: &'a ActionOutcome,
+        caller: Option<&'a Caller>,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let Some(rule_name) = matched_rule else {
+                return;
+            };
+            if origin.metadata.labels.contains_key(CAUSED_BY_LABEL) {
+                return;
+            }
+            let Some(trigger) = FollowOnTrigger::from_outcome(outcome) else {
+                return;
+            };
+            let Some(rule) = self.engine.rule_by_name(rule_name) else {
+                return;
+            };
+            let template = match trigger {
+                FollowOnTrigger::Success => rule.on_success.as_ref(),
+                FollowOnTrigger::Failure => rule.on_failure.as_ref(),
+            };
+            let Some(template) = template else {
+                return;
+            };
+
+            let event = FollowOnEvent {
+                action: build_follow_on_action(template, origin, rule_name),
+                caller: caller.cloned(),
+            };
+            info!(
+                origin = %origin.id,
+                follow_on = %event.action.id,
+                rule = rule_name,
+                ?trigger,
+                "enqueueing follow-on action"
+            );
+            match &self.follow_on_tx {
+                Some(tx) => {
+                    if let Err(e) = tx.send(event).await {
+                        warn!(
+                            origin = %origin.id,
+                            follow_on = %e.0.action.id,
+                            "follow-on queue closed; dropping follow-on action"
+                        );
+                    }
+                }
+                None => self.dispatch_follow_on(event).await,
+            }
+        })
+    }
+
+    /// Dispatch a queued follow-on action. Failures are logged and never
+    /// affect the action that triggered it.
+    pub async fn dispatch_follow_on(&self, event: FollowOnEvent) {
+        let follow_on_id = event.action.id.to_string();
+        if let Err(e) = self.dispatch(event.action, event.caller.as_ref()).await {
+            warn!(
+                follow_on = %follow_on_id,
+                error = %e,
+                "follow-on dispatch failed"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use acteon_core::{ActionError, ProviderResponse};
+
+    #[test]
+    fn trigger_classification() {
+        let ok = ActionOutcome::Executed(ProviderResponse::success(serde_json::json!({})));
+        assert_eq!(
+            FollowOnTrigger::from_outcome(&ok),
+            Some(FollowOnTrigger::Success)
+        );
+
+        let failed = ActionOutcome::Failed(ActionError {
+            code: "E".into(),
+            reason_code: acteon_core::ReasonCode::ProviderFailed,
+            message: "boom".into(),
+            retryable: false,
+            attempts: 1,
+        });
+        assert_eq!(
+            FollowOnTrigger::from_outcome(&failed),
+            Some(FollowOnTrigger::Failure)
+        );
+
+        let suppressed = ActionOutcome::Suppressed { rule: "r".into() };
+        assert_eq!(FollowOnTrigger::from_outcome(&suppressed), None);
+    }
+
+    #[test]
+    fn follow_on_carries_causal_link_and_rendered_payload() {
+        let origin = Action::new(
+            "ns",
+            "t1",
+            "email",
+            "place_order",
+            serde_json::json!({"order_id": "o-42"}),
+        );
+        let template = FollowOnAction::new(
+            "slack",
+            "post_message",
+            serde_json::json!({"text": "order {{origin.payload.order_id}} placed"}),
+        );
+
+        let action = build_follow_on_action(&template, &origin, "order-placed");
+        assert_eq!(action.namespace, origin.namespace);
+        assert_eq!(action.tenant, origin.tenant);
+        assert_eq!(action.provider.as_str(), "slack");
+        assert_eq!(action.payload["text"], "order o-42 placed");
+        assert_eq!(
+            action.metadata.labels.get(CAUSED_BY_LABEL),
+            Some(&origin.id.to_string())
+        );
+        assert_eq!(
+            action
+                .metadata
+                .labels
+                .get(CAUSED_BY_RULE_LABEL)
+                .map(String::as_str),
+            Some("order-placed")
+        );
+    }
+
+    struct OkProvider(&'static str);
+
+    #[async_trait::async_trait]
+    impl acteon_provider::DynProvider for OkProvider {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        async fn execute(
+            &self,
+            _action: &Action,
+        ) -> Result<ProviderResponse, acteon_provider::ProviderError> {
+            Ok(ProviderResponse::success(serde_json::json!({})))
+        }
+
+        async fn health_check(&self) -> Result<(), acteon_provider::ProviderError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn follow_on_is_queued_not_dispatched_inline() {
+        use acteon_rules::ir::expr::Expr;
+        use acteon_rules::ir::rule::{Rule, RuleAction};
+
+        let rule = Rule::new("notify", Expr::Bool(true), RuleAction::Allow).with_on_success(
+            FollowOnAction::new("slack", "post_message", serde_json::json!({})),
+        );
+        let (tx, mut rx) = tokio::sync::mpsc::channel(8);
+        let gateway = crate::GatewayBuilder::new()
+            .state(std::sync::Arc::new(
+                acteon_state_memory::MemoryStateStore::new(),
+            ))
+            .lock(std::sync::Arc::new(
+                acteon_state_memory::MemoryDistributedLock::new(),
+            ))
+            .rules(vec![rule])
+            .provider(std::sync::Arc::new(OkProvider("email")))
+            .provider(std::sync::Arc::new(OkProvider("slack")))
+            .follow_on_channel(tx)
+            .build()
+            .unwrap();
+
+        let origin = Action::new("ns", "t1", "email", "send", serde_json::json!({}));
+        let outcome = gateway.dispatch(origin.clone(), None).await.unwrap();
+        assert!(matches!(outcome, ActionOutcome::Executed(_)));
+
+        let event = rx.try_recv().expect("follow-on should be queued");
+        assert_eq!(event.action.provider.as_str(), "slack");
+        assert_eq!(
+            event.action.metadata.labels.get(CAUSED_BY_LABEL),
+            Some(&origin.id.to_string())
+        );
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -2,9 +2,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
-use chrono::Utc;
-use hmac::{Hmac, Mac};
-use sha2::Sha256;
+use chrono::{DateTime, Utc};
 use tokio_util::task::TaskTracker;
 use tracing::{debug, error, info, instrument, warn};
 
@@ -12,12 +10,13 @@ use acteon_audit::AuditRecord;
 use acteon_audit::store::AuditStore;
 use acteon_core::chain::WaitState;
 use acteon_core::{
-    Action, ActionOutcome, Caller, ChainConfig, ChainState, ChainStatus, ChainStepConfig,
-    ExecutionEventType, StateMachineConfig, StepKind, StepResult, StreamEvent, StreamEventType,
-    compute_fingerprint, sanitize_outcome,
+    Action, ActionOutcome, ApprovalUrlParams, Caller, ChainConfig, ChainState, ChainStatus,
+    ChainStepConfig, DedupWindow, EventTransition, ExecutionEventType, FeatureFlags,
+    FingerprintVersion, ProviderResponse, StateMachineConfig, StepKind, StepResult, StreamEvent,
+    StreamEventType, TransitionTrigger, sanitize_outcome,
 };
 use acteon_executor::{ActionExecutor, DeadLetterEntry, DeadLetterSink};
-use acteon_provider::ProviderRegistry;
+use acteon_provider::{DynProvider, ProviderRegistry};
 use acteon_rules::{EvalContext, RuleEngine, RuleVerdict};
 use acteon_state::{DistributedLock, KeyKind, StateKey, StateStore};
 
@@ -29,9 +28,16 @@ use crate::circuit_breaker::CircuitBreakerRegistry;
 use crate::group_manager::GroupManager;
 
 use crate::error::GatewayError;
+use crate::event_history::{event_lock_name, write_event_state};
 use crate::metrics::GatewayMetrics;
+use crate::provider_health::ProviderHealth;
+use crate::reroute::apply_reroute_scope;
 
-type HmacSha256 = Hmac<Sha256>;
+/// Source of the current time for time-windowed pipeline state.
+pub(crate) type Clock = Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>;
+
+/// Compare-and-swap attempts when recording a dedup window occurrence.
+const DEDUP_CAS_ATTEMPTS: u32 = 8;
 
 /// A named HMAC key for signing/verifying approval URLs.
 #[derive(Debug, Clone)]
@@ -197,6 +203,8 @@ pub struct Gateway {
     pub(crate) providers: ProviderRegistry,
     pub(crate) executor: ActionExecutor,
     pub(crate) environment: HashMap<String, String>,
+    /// Named holiday calendars for `business_hours` rule conditions.
+    pub(crate) holiday_calendars: acteon_rules::HolidayCalendars,
     pub(crate) metrics: Arc<GatewayMetrics>,
     pub(crate) audit: Option<Arc<dyn AuditStore>>,
     pub(crate) audit_ttl_seconds: Option<u64>,
@@ -221,6 +229,8 @@ pub struct Gateway {
     /// a pinned definition cannot change — but the cache is size-capped.
     pub(crate) pinned_config_cache: parking_lot::RwLock<PinnedConfigCache>,
     pub(crate) completed_chain_ttl: Option<Duration>,
+    /// Depth, step, and fan-out limits enforced on every chain execution.
+    pub(crate) chain_limits: acteon_core::ChainLimits,
     pub(crate) embedding: Option<Arc<dyn acteon_rules::EmbeddingEvalSupport>>,
     pub(crate) default_timezone: Option<chrono_tz::Tz>,
     pub(crate) circuit_breakers: Option<crate::circuit_breaker::CircuitBreakerRegistry>,
@@ -234,9 +244,29 @@ pub struct Gateway {
     pub(crate) quota_policies: parking_lot::RwLock<HashMap<String, CachedPolicy>>,
     /// Optional payload encryptor for encrypting action payloads at rest.
     pub(crate) payload_encryptor: Option<Arc<acteon_crypto::PayloadEncryptor>>,
+    /// Action types whose payloads are stored in plaintext unless the action
+    /// sets [`Action::encrypt_payload`] explicitly.
+    pub(crate) plaintext_action_types: std::collections::HashSet<String>,
+    /// Fingerprint algorithm version and optional dual-read version.
+    pub(crate) fingerprint_config: acteon_core::FingerprintConfig,
+    /// Consecutive check results needed to change a provider's health.
+    pub(crate) health_hysteresis: acteon_core::HealthHysteresis,
+    /// Classified provider health indexed by provider name, fed by
+    /// [`observe_provider_health`](Self::observe_provider_health).
+    pub(crate) provider_health: parking_lot::Mutex<HashMap<String, ProviderHealth>>,
     /// Data retention policies indexed by `"namespace:tenant"`.
     pub(crate) retention_policies:
         parking_lot::RwLock<HashMap<String, acteon_core::RetentionPolicy>>,
+    /// Dedup key normalization indexed by namespace.
+    pub(crate) dedup_normalization: HashMap<String, acteon_core::DedupKeyNormalization>,
+    /// Clock used to measure dedup windows.
+    pub(crate) clock: Clock,
+    /// Default payload fragments indexed by `(namespace, provider)`.
+    pub(crate) provider_payload_defaults: HashMap<(String, String), serde_json::Value>,
+    /// Default provider and action type indexed by namespace.
+    pub(crate) namespace_defaults: HashMap<String, acteon_core::NamespaceDefaults>,
+    /// Ordered fallback providers tried when the keyed provider fails.
+    pub(crate) provider_failover: HashMap<String, Vec<String>>,
     /// Payload templates indexed by `(namespace, tenant)` → `name` → `Template`.
     pub(crate) templates:
         parking_lot::RwLock<HashMap<(String, String), HashMap<String, acteon_core::Template>>>,
@@ -269,6 +299,8 @@ pub struct Gateway {
     pub(crate) compliance_config: Option<acteon_core::ComplianceConfig>,
     /// Typed reference to the hash chain audit store for chain verification.
     pub(crate) hash_chain_store: Option<Arc<acteon_audit::HashChainAuditStore>>,
+    /// Typed reference to the audit batcher, flushed on shutdown.
+    pub(crate) audit_batcher: Option<Arc<acteon_audit::BatchingAuditStore>>,
     /// Pre-dispatch enrichment configurations.
     pub(crate) enrichments: Vec<acteon_core::EnrichmentConfig>,
     /// Resource lookup providers for enrichment (keyed by provider name).
@@ -277,10 +309,15 @@ pub struct Gateway {
     pub(crate) max_inline_bytes: u64,
     /// Maximum number of attachments per action.
     pub(crate) max_attachments_per_action: usize,
+    /// Content-addressable storage shared by every resolved attachment.
+    pub(crate) attachment_store: acteon_core::AttachmentStore,
     /// Tracks the last cache-sync version observed per domain so the periodic
     /// template/silence/time-interval sync workers can skip the expensive
     /// full-keyspace scan when nothing has changed.
     pub(crate) sync_versions: crate::sync_state::SyncVersionTracker,
+    /// Queue that follow-on actions are handed to instead of being
+    /// dispatched inline. See [`GatewayBuilder::follow_on_channel`](crate::GatewayBuilder::follow_on_channel).
+    pub(crate) follow_on_tx: Option<tokio::sync::mpsc::Sender<crate::follow_on::FollowOnEvent>>,
 }
 
 impl std::fmt::Debug for Gateway {
@@ -301,6 +338,8 @@ impl Gateway {
     /// Resolve all attachments on an action into [`ResolvedAttachment`](acteon_core::ResolvedAttachment) instances.
     ///
     /// Decodes each attachment's `base64` data and validates size limits.
+    /// Decoded content is deduplicated by hash, so identical attachments
+    /// share one copy of their bytes.
     ///
     /// Returns an error if:
     /// - The action has more attachments than `max_attachments_per_action`.
@@ -344,11 +383,13 @@ impl Gateway {
                 )));
             }
 
+            let (content_hash, data) = self.attachment_store.insert(data);
             resolved.push(acteon_core::ResolvedAttachment {
                 id: attachment.id.clone(),
                 name: attachment.name.clone(),
                 filename: attachment.filename.clone(),
                 content_type: attachment.content_type.clone(),
+                content_hash,
                 data,
             });
         }
@@ -385,6 +426,29 @@ impl Gateway {
         }
     }
 
+    /// Recompute the hash of a single audit record and compare it to the
+    /// stored `record_hash`.
+    ///
+    /// Returns `None` if hash chaining is not enabled.
+    pub fn verify_audit_record(
+        &self,
+        record: &acteon_audit::AuditRecord,
+    ) -> Option<acteon_core::RecordHashVerification> {
+        self.hash_chain_store
+            .as_ref()
+            .map(|_| acteon_audit::HashChainAuditStore::verify_record(record))
+    }
+
+    /// Returns the fingerprint version used for new events.
+    pub fn fingerprint_version(&self) -> acteon_core::FingerprintVersion {
+        self.fingerprint_config.version
+    }
+
+    /// Returns the fingerprint configuration, including any dual-read version.
+    pub fn fingerprint_config(&self) -> acteon_core::FingerprintConfig {
+        self.fingerprint_config
+    }
+
     /// Returns a reference to the payload encryptor, if configured.
     pub fn payload_encryptor(&self) -> Option<&acteon_crypto::PayloadEncryptor> {
         self.payload_encryptor.as_deref()
@@ -411,6 +475,13 @@ impl Gateway {
             if let Err(e) = audit.record(record).await {
                 warn!(error = %e, "audit recording failed (sync)");
             }
+        } else if self.audit_batcher.is_some() {
+            // Queueing is cheap and, under the default `reject` overflow
+            // policy, never waits; doing it inline keeps records in dispatch
+            // order, which spawned tasks would not.
+            if let Err(e) = audit.record(record).await {
+                warn!(error = %e, "audit recording failed");
+            }
         } else {
             let audit = Arc::clone(audit);
             self.audit_tracker.spawn(async move {
@@ -470,6 +541,44 @@ impl Gateway {
         }
     }
 
+    /// Encrypt a state value that embeds `action`, honoring its payload
+    /// encryption opt-out.
+    pub(crate) fn encrypt_action_state_value(
+        &self,
+        action: &Action,
+        value: &str,
+    ) -> Result<String, GatewayError> {
+        if action.encrypt_payload == Some(false) {
+            return Ok(value.to_owned());
+        }
+        self.encrypt_state_value(value)
+    }
+
+    /// Settle whether `action`'s payload is encrypted at rest.
+    ///
+    /// An unset [`Action::encrypt_payload`] falls back to the configured
+    /// plaintext action types. When the compliance config forbids opting out,
+    /// any opt-out is dropped and the payload is encrypted as usual.
+    fn resolve_payload_encryption(&self, action: &mut Action) {
+        let forbidden = self
+            .compliance_config
+            .as_ref()
+            .is_some_and(|c| c.forbid_encryption_opt_out);
+        if forbidden {
+            if action.encrypt_payload == Some(false) {
+                debug!(
+                    action_id = %action.id,
+                    "compliance mode forbids payload encryption opt-out, encrypting"
+                );
+            }
+            action.encrypt_payload = None;
+        } else if action.encrypt_payload.is_none()
+            && self.plaintext_action_types.contains(&action.action_type)
+        {
+            action.encrypt_payload = Some(false);
+        }
+    }
+
     /// Decrypt a state value if a payload encryptor is configured, otherwise passthrough.
     pub fn decrypt_state_value(&self, value: &str) -> Result<String, GatewayError> {
         match self.payload_encryptor {
@@ -510,6 +619,99 @@ impl Gateway {
         self.dispatch_inner(action, caller, true).await
     }
 
+    /// Run the pre-execution stages shared by dispatch, dry-run, and
+    /// preview: enrichments, template rendering, rule evaluation, and the
+    /// LLM guardrail. `action` is updated in place with enriched and
+    /// rendered data. None of these stages mutate gateway state.
+    pub(crate) async fn prepare_and_evaluate(
+        &self,
+        action: &mut Action,
+        flags: &FeatureFlags,
+    ) -> Result<RuleVerdict, GatewayError> {
+        // 2c. Apply pre-dispatch enrichments.
+        // Lookups are read-only, so we run them even in dry-run mode to ensure
+        // rule evaluation produces the same verdict as an actual dispatch.
+        // Successful lookups are also kept by enrichment name so conditions
+        // can reference them as `enrichment.<name>`.
+        let mut enrichment_outputs = HashMap::new();
+        if !self.enrichments.is_empty() {
+            crate::enrichment::apply_enrichments_collecting(
+                action,
+                &self.enrichments,
+                &self.resource_lookups,
+                &mut enrichment_outputs,
+            )
+            .await?;
+        }
+
+        // 2d. Template rendering.
+        // If the action has a template profile, render it and merge into payload.
+        // This runs before rule evaluation so rules see the rendered payload.
+        if let Some(ref profile_name) = action.template {
+            let profile = self
+                .template_profile_by_scope(&action.namespace, &action.tenant, profile_name)
+                .ok_or_else(|| {
+                    GatewayError::TemplateRender(format!(
+                        "template profile not found: {profile_name}"
+                    ))
+                })?;
+            let scoped_templates = self.templates_for_scope(&action.namespace, &action.tenant);
+            let payload_snapshot = action.payload.clone();
+            let attachments_snapshot = action.attachments.clone();
+
+            let rendered = tokio::task::spawn_blocking(move || {
+                crate::template_engine::render_profile(
+                    &profile,
+                    &scoped_templates,
+                    &payload_snapshot,
+                    &attachments_snapshot,
+                )
+            })
+            .await
+            .map_err(|e| GatewayError::TemplateRender(format!("render task panicked: {e}")))??;
+
+            crate::template_engine::merge_rendered_into_payload(&mut action.payload, &rendered)?;
+            debug!(profile = profile_name, "template profile rendered");
+        }
+
+        // 3. Build the evaluation context and evaluate rules.
+        let mut eval_ctx = EvalContext::new(action, self.state.as_ref(), &self.environment)
+            .with_flags(flags)
+            .with_enrichment(&enrichment_outputs)
+            .with_holidays(&self.holiday_calendars);
+        if let Some(ref emb) = self.embedding {
+            eval_ctx = eval_ctx.with_embedding(Arc::clone(emb));
+        }
+        let wasm_counters = if self.wasm_runtime.is_some() {
+            let counters = Arc::new(acteon_rules::WasmEvalCounters::default());
+            eval_ctx = eval_ctx.with_wasm_counters(Arc::clone(&counters));
+            Some(counters)
+        } else {
+            None
+        };
+        if let Some(ref wasm) = self.wasm_runtime {
+            eval_ctx = eval_ctx.with_wasm_runtime(Arc::clone(wasm));
+        }
+        if let Some(tz) = self.default_timezone {
+            eval_ctx = eval_ctx.with_timezone(tz);
+        }
+        let verdict = self.engine.evaluate(&eval_ctx).await?;
+
+        // Propagate WASM counters to gateway metrics.
+        if let Some(ref counters) = wasm_counters {
+            self.metrics
+                .add_wasm_invocations(counters.invocation_count());
+            self.metrics.add_wasm_errors(counters.error_count());
+        }
+
+        info!(?verdict, "rule evaluation complete");
+
+        // 3b. LLM guardrail check (skipped for already-denied/suppressed verdicts).
+        let verdict = self.apply_llm_guardrail(action, verdict).await;
+
+        Ok(verdict)
+    }
+
     /// Inner dispatch implementation shared by normal and dry-run modes.
     #[allow(clippy::too_many_lines)]
     #[instrument(
@@ -535,6 +737,11 @@ impl Gateway {
         let dispatched_at = Utc::now();
         let event_id = uuid::Uuid::now_v7().to_string();
 
+        // 0. Fill in the namespace's default provider and action type.
+        let mut action = action;
+        self.apply_namespace_defaults(&mut action)?;
+        self.resolve_payload_encryption(&mut action);
+
         // 1. Build a lock name scoped to this specific action.
         let lock_name = format!(
             "dispatch:{}:{}:{}",
@@ -569,7 +776,6 @@ impl Gateway {
         // without ever sending the message. The hop limit caps
         // how many fallbacks a single dispatch can traverse so
         // a misconfigured chain of degrade policies cannot loop.
-        let mut action = action;
         if !dry_run {
             const MAX_QUOTA_DEGRADE_HOPS: usize = 3;
             let mut hops = 0usize;
@@ -648,6 +854,7 @@ impl Gateway {
                     tenant: action.tenant.to_string(),
                     action_type: Some(action.action_type.clone()),
                     action_id: Some(action.id.to_string()),
+                    traceparent: action.traceparent().map(str::to_owned),
                 };
                 let _ = self.stream_tx.send(stream_event);
                 if let Some(g) = guard.take() {
@@ -657,85 +864,20 @@ impl Gateway {
             }
         }
 
-        // 2c. Apply pre-dispatch enrichments.
-        // Lookups are read-only, so we run them even in dry-run mode to ensure
-        // rule evaluation produces the same verdict as an actual dispatch.
-        if !self.enrichments.is_empty() {
-            crate::enrichment::apply_enrichments(
-                &mut action,
-                &self.enrichments,
-                &self.resource_lookups,
-            )
-            .await?;
-        }
-
-        // 2d. Template rendering.
-        // If the action has a template profile, render it and merge into payload.
-        // This runs before rule evaluation so rules see the rendered payload.
-        if let Some(ref profile_name) = action.template {
-            let profile = self
-                .template_profile_by_scope(&action.namespace, &action.tenant, profile_name)
-                .ok_or_else(|| {
-                    GatewayError::TemplateRender(format!(
-                        "template profile not found: {profile_name}"
-                    ))
-                })?;
-            let scoped_templates = self.templates_for_scope(&action.namespace, &action.tenant);
-            let payload_snapshot = action.payload.clone();
-            let attachments_snapshot = action.attachments.clone();
-
-            let rendered = tokio::task::spawn_blocking(move || {
-                crate::template_engine::render_profile(
-                    &profile,
-                    &scoped_templates,
-                    &payload_snapshot,
-                    &attachments_snapshot,
-                )
-            })
-            .await
-            .map_err(|e| GatewayError::TemplateRender(format!("render task panicked: {e}")))??;
-
-            crate::template_engine::merge_rendered_into_payload(&mut action.payload, &rendered)?;
-            debug!(profile = profile_name, "template profile rendered");
-        }
-
-        // 3. Build the evaluation context and evaluate rules.
-        let mut eval_ctx = EvalContext::new(&action, self.state.as_ref(), &self.environment);
-        if let Some(ref emb) = self.embedding {
-            eval_ctx = eval_ctx.with_embedding(Arc::clone(emb));
-        }
-        let wasm_counters = if self.wasm_runtime.is_some() {
-            let counters = Arc::new(acteon_rules::WasmEvalCounters::default());
-            eval_ctx = eval_ctx.with_wasm_counters(Arc::clone(&counters));
-            Some(counters)
-        } else {
-            None
-        };
-        if let Some(ref wasm) = self.wasm_runtime {
-            eval_ctx = eval_ctx.with_wasm_runtime(Arc::clone(wasm));
-        }
-        if let Some(tz) = self.default_timezone {
-            eval_ctx = eval_ctx.with_timezone(tz);
-        }
-        let verdict = self.engine.evaluate(&eval_ctx).await?;
-
-        // Propagate WASM counters to gateway metrics.
-        if let Some(ref counters) = wasm_counters {
-            self.metrics
-                .add_wasm_invocations(counters.invocation_count());
-            self.metrics.add_wasm_errors(counters.error_count());
-        }
-
-        info!(?verdict, "rule evaluation complete");
-
-        // 3b. LLM guardrail check (skipped for already-denied/suppressed verdicts).
-        let verdict = self.apply_llm_guardrail(&action, verdict).await;
+        // 2c–3b. Enrich, render templates, evaluate rules, apply guardrails.
+        // The tenant's feature flags are loaded once and shared by rule
+        // evaluation and provider execution.
+        let flags = self
+            .load_feature_flags(action.namespace.as_str(), action.tenant.as_str())
+            .await;
+        let verdict = self.prepare_and_evaluate(&mut action, &flags).await?;
 
         // 3c. In dry-run mode, return early with the verdict without executing.
         if dry_run {
             let would_be_provider = match &verdict {
                 RuleVerdict::Reroute {
-                    target_provider, ..
+                    target_provider: Some(target_provider),
+                    ..
                 } => target_provider.clone(),
                 _ => action.provider.to_string(),
             };
@@ -746,6 +888,22 @@ impl Gateway {
             });
         }
 
+        // 3c'. A cross-scope reroute moves the action to its target namespace
+        //      and tenant before anything else sees it, so silences, delivery,
+        //      and the audit record all use the rerouted scope.
+        if let RuleVerdict::Reroute {
+            target_namespace,
+            target_tenant,
+            ..
+        } = &verdict
+        {
+            apply_reroute_scope(
+                &mut action,
+                target_namespace.as_deref(),
+                target_tenant.as_deref(),
+            );
+        }
+
         // 3d. Silence check — if the action matches an active silence,
         //     short-circuit to the Silenced outcome before executing the
         //     verdict. This preserves the rule verdict in the audit record
@@ -786,6 +944,7 @@ impl Gateway {
                 tenant: action.tenant.to_string(),
                 action_type: Some(action.action_type.clone()),
                 action_id: Some(action.id.to_string()),
+                traceparent: action.traceparent().map(str::to_owned),
             };
             let _ = self.stream_tx.send(stream_event);
 
@@ -845,6 +1004,7 @@ impl Gateway {
                 tenant: action.tenant.to_string(),
                 action_type: Some(action.action_type.clone()),
                 action_id: Some(action.id.to_string()),
+                traceparent: action.traceparent().map(str::to_owned),
             };
             let _ = self.stream_tx.send(stream_event);
 
@@ -893,9 +1053,13 @@ impl Gateway {
 
         // 4. Handle the verdict.
         let outcome = match &verdict {
-            RuleVerdict::Allow(_) => self.execute_action(&action).await,
-            RuleVerdict::Deduplicate { ttl_seconds } => {
-                self.handle_dedup(&action, *ttl_seconds).await?
+            RuleVerdict::Allow(_) => self.execute_action(&action, &flags).await,
+            RuleVerdict::Deduplicate {
+                ttl_seconds,
+                window,
+            } => {
+                self.handle_dedup(&action, *ttl_seconds, *window, &flags)
+                    .await?
             }
             RuleVerdict::Suppress(rule) | RuleVerdict::Deny(rule) => {
                 self.metrics.increment_suppressed();
@@ -904,26 +1068,36 @@ impl Gateway {
             RuleVerdict::Reroute {
                 rule: _,
                 target_provider,
-            } => self.handle_reroute(&action, target_provider).await?,
+                target_namespace,
+                target_tenant,
+            } => {
+                self.handle_reroute(
+                    &action,
+                    target_provider.as_deref(),
+                    target_namespace.as_deref(),
+                    target_tenant.as_deref(),
+                )
+                .await?
+            }
             RuleVerdict::Throttle {
                 rule,
                 max_count,
                 window_seconds,
             } => {
-                self.handle_throttle(&action, rule, *max_count, *window_seconds)
+                self.handle_throttle(&action, rule, *max_count, *window_seconds, &flags)
                     .await?
             }
             RuleVerdict::Modify { rule: _, changes } => {
                 let mut modified = action.clone();
                 json_patch::merge(&mut modified.payload, changes);
-                self.execute_action(&modified).await
+                self.execute_action(&modified, &flags).await
             }
             RuleVerdict::StateMachine {
                 rule: _,
                 state_machine,
                 fingerprint_fields,
             } => {
-                self.handle_state_machine(&action, state_machine, fingerprint_fields)
+                self.handle_state_machine(&action, state_machine, fingerprint_fields, caller)
                     .await?
             }
             RuleVerdict::Group {
@@ -933,7 +1107,7 @@ impl Gateway {
                 group_interval_seconds,
                 repeat_interval_seconds,
                 max_group_size,
-                template: _,
+                template,
             } => {
                 self.handle_group(
                     &action,
@@ -942,6 +1116,7 @@ impl Gateway {
                     *group_interval_seconds,
                     *repeat_interval_seconds,
                     *max_group_size,
+                    template.as_deref(),
                 )
                 .await?
             }
@@ -969,6 +1144,23 @@ impl Gateway {
             } => self.handle_schedule(&action, *delay_seconds).await?,
         };
 
+        // 4b. Let the provider's response drive the event's state machine.
+        match &outcome {
+            ActionOutcome::Executed(response) => {
+                self.apply_response_transition(&action, &action.provider, response, caller)
+                    .await;
+            }
+            ActionOutcome::Rerouted {
+                new_provider,
+                response,
+                ..
+            } => {
+                self.apply_response_transition(&action, new_provider, response, caller)
+                    .await;
+            }
+            _ => {}
+        }
+
         // 5. Emit audit record (sync when compliance requires it, async otherwise).
         if let Some(ref audit) = self.audit {
             let record = build_audit_record(
@@ -1000,6 +1192,7 @@ impl Gateway {
                 tenant: action.tenant.to_string(),
                 action_type: Some(action.action_type.clone()),
                 action_id: Some(action.id.to_string()),
+                traceparent: action.traceparent().map(str::to_owned),
             };
             let _ = self.stream_tx.send(stream_event);
         }
@@ -1013,6 +1206,12 @@ impl Gateway {
                 .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
         }
 
+        // 8. Enqueue the matched rule's `on_success` / `on_failure`
+        //    follow-on action, if declared. Runs after the lock is released
+        //    so the follow-on's own pipeline never contends with it.
+        self.enqueue_follow_on(&action, verdict.rule_name(), &outcome, caller)
+            .await;
+
         info!(?outcome, "dispatch complete");
 
         Ok(outcome)
@@ -1161,14 +1360,25 @@ impl Gateway {
         self.providers.list()
     }
 
-    /// Run health checks on all registered providers.
-    pub async fn check_provider_health(&self) -> Vec<acteon_provider::health::HealthStatus> {
-        acteon_provider::health::check_all(&self.providers).await
+    /// Replace the rule engine's rules with a new set, re-sorting by priority.
+    ///
+    /// The previous set is kept for [`rollback_rules`](Self::rollback_rules).
+    /// Returns the version id assigned to the new set.
+    pub fn reload_rules(&mut self, rules: Vec<acteon_rules::Rule>) -> u64 {
+        self.engine.load_rules(rules)
+    }
+
+    /// Atomically restore a previously loaded rule set by version id.
+    pub fn rollback_rules(&mut self, version: u64) -> Result<(), GatewayError> {
+        self.engine
+            .rollback_rules(version)
+            .map_err(|e| GatewayError::Configuration(e.to_string()))
     }
 
-    /// Replace the rule engine's rules with a new set, re-sorting by priority.
-    pub fn reload_rules(&mut self, rules: Vec<acteon_rules::Rule>) {
-        self.engine = RuleEngine::new(rules);
+    /// Return the active rule set version followed by those retained for
+    /// rollback.
+    pub fn rule_set_versions(&self) -> Vec<acteon_rules::RuleSetVersion> {
+        self.engine.rule_set_versions()
     }
 
     /// Return a reference to the sorted rules in the engine.
@@ -1254,10 +1464,14 @@ impl Gateway {
     ///
     /// This method closes the audit task tracker (preventing new tasks from
     /// being spawned) and waits for all in-flight audit recording tasks to
-    /// complete. Call this during server shutdown to avoid losing audit data.
+    /// complete, then flushes any batched audit records. Call this during
+    /// server shutdown to avoid losing audit data.
     pub async fn shutdown(&self) {
         self.audit_tracker.close();
         self.audit_tracker.wait().await;
+        if let Some(ref batcher) = self.audit_batcher {
+            batcher.flush().await;
+        }
         info!("gateway shutdown complete");
     }
 
@@ -1428,7 +1642,12 @@ impl Gateway {
             })
         };
 
-        let mut eval_ctx = EvalContext::new(action, state_store.as_ref(), &self.environment);
+        let flags = self
+            .load_feature_flags(action.namespace.as_str(), action.tenant.as_str())
+            .await;
+        let mut eval_ctx = EvalContext::new(action, state_store.as_ref(), &self.environment)
+            .with_flags(&flags)
+            .with_holidays(&self.holiday_calendars);
         if let Some(ts) = evaluate_at {
             eval_ctx = eval_ctx.with_now(ts);
         }
@@ -1490,10 +1709,14 @@ impl Gateway {
         path: &std::path::Path,
         frontends: &[&dyn acteon_rules::RuleFrontend],
     ) -> Result<usize, GatewayError> {
-        self.engine = RuleEngine::new(vec![]);
-        self.engine
+        // Parse into a staging engine so a bad file leaves the active set
+        // untouched.
+        let mut staged = RuleEngine::new(vec![]);
+        let count = staged
             .load_directory(path, frontends)
-            .map_err(|e| GatewayError::Configuration(e.to_string()))
+            .map_err(|e| GatewayError::Configuration(e.to_string()))?;
+        self.engine.load_rules(staged.rules().to_vec());
+        Ok(count)
     }
 
     // -- Private helpers ------------------------------------------------------
@@ -1566,8 +1789,9 @@ impl Gateway {
             fallback = %target_name,
             "circuit open, rerouting to fallback provider"
         );
+        let action = self.with_provider_defaults(action, target_name);
         let exec_start = std::time::Instant::now();
-        let result = self.executor.execute(action, target.as_ref()).await;
+        let result = self.executor.execute(&action, target.as_ref()).await;
         let latency_us = u64::try_from(exec_start.elapsed().as_micros()).unwrap_or(u64::MAX);
 
         // Record per-provider metrics for the fallback provider.
@@ -1601,19 +1825,64 @@ impl Gateway {
                 original_provider: action.provider.to_string(),
                 new_provider: target_name.to_string(),
                 response: resp.clone(),
+                failed_attempts: Vec::new(),
             },
             other => other,
         }
     }
 
+    /// Execute a chain step's synthetic action, bounded by the step's
+    /// `timeout_seconds` when one is configured.
+    ///
+    /// A call that runs past the limit is abandoned and reported as a
+    /// retryable `TIMEOUT` failure, so it flows through the step's retry and
+    /// `on_failure` policies instead of blocking the chain.
+    async fn execute_chain_step(
+        &self,
+        step: &acteon_core::chain::ChainStepConfig,
+        action: &Action,
+    ) -> ActionOutcome {
+        let flags = self
+            .load_feature_flags(action.namespace.as_str(), action.tenant.as_str())
+            .await;
+        let Some(secs) = step.timeout_seconds else {
+            return self.execute_action(action, &flags).await;
+        };
+        if let Ok(outcome) = tokio::time::timeout(
+            Duration::from_secs(secs),
+            self.execute_action(action, &flags),
+        )
+        .await
+        {
+            return outcome;
+        }
+        warn!(
+            step = %step.name,
+            provider = %action.provider,
+            timeout_seconds = secs,
+            "chain step timed out"
+        );
+        self.metrics.increment_failed();
+        ActionOutcome::Failed(acteon_core::ActionError {
+            code: "TIMEOUT".into(),
+            reason_code: acteon_core::ReasonCode::ProviderTimeout,
+            message: format!("step `{}` timed out after {secs}s", step.name),
+            retryable: true,
+            attempts: 1,
+        })
+    }
+
     /// Look up the action's provider and execute through the executor.
     ///
     /// When a circuit breaker is configured for the provider and the circuit
     /// is open, the request is rejected immediately. If a fallback provider is
     /// configured, the gateway walks the fallback chain recursively until it
     /// finds a healthy provider or exhausts the chain.
+    ///
+    /// When the provider executes and fails, its failover route (if any) is
+    /// tried in order before the failure is reported.
     #[instrument(name = "gateway.execute_action", skip(self, action), fields(provider = %action.provider))]
-    async fn execute_action(&self, action: &Action) -> ActionOutcome {
+    async fn execute_action(&self, action: &Action, flags: &FeatureFlags) -> ActionOutcome {
         // Check circuit breaker before executing — walk the fallback chain.
         if let Some(ref registry) = self.circuit_breakers
             && let Some(cb) = registry.get(action.provider.as_str())
@@ -1643,30 +1912,56 @@ impl Gateway {
             }
         }
 
-        let Some(provider) = self.providers.get(action.provider.as_str()) else {
-            self.metrics.increment_failed();
+        let result = match self
+            .attempt_provider(action, action.provider.as_str(), flags)
+            .await
+        {
+            ActionOutcome::Failed(err) => self.fail_over(action, err, flags).await,
+            other => other,
+        };
+
+        match &result {
+            ActionOutcome::Executed(_) => self.metrics.increment_executed(),
+            ActionOutcome::Failed(_) => self.metrics.increment_failed(),
+            _ => {}
+        }
+        result
+    }
+
+    /// Execute an action on the named provider, recording the result in the
+    /// provider's metrics and circuit breaker.
+    ///
+    /// Does not consult the circuit breaker before executing and does not
+    /// touch the gateway-wide executed/failed counters; callers own both.
+    pub(crate) async fn attempt_provider(
+        &self,
+        action: &Action,
+        provider_name: &str,
+        flags: &FeatureFlags,
+    ) -> ActionOutcome {
+        let Some(provider) = self.providers.get(provider_name) else {
             return ActionOutcome::Failed(acteon_core::ActionError {
                 code: "PROVIDER_NOT_FOUND".into(),
-                message: format!("provider not found: {}", action.provider),
+                reason_code: acteon_core::ReasonCode::ProviderNotFound,
+                message: format!("provider not found: {provider_name}"),
                 retryable: false,
                 attempts: 0,
             });
         };
 
-        let (result, latency_us) = self.execute_provider(action, provider.as_ref()).await;
+        let (result, latency_us) = self
+            .execute_provider(action, provider.as_ref(), flags)
+            .await;
 
         // Record per-provider metrics.
         match &result {
             ActionOutcome::Executed(_) => {
                 self.provider_metrics
-                    .record_success(action.provider.as_str(), latency_us);
+                    .record_success(provider_name, latency_us);
             }
             ActionOutcome::Failed(err) => {
-                self.provider_metrics.record_failure(
-                    action.provider.as_str(),
-                    latency_us,
-                    &err.message,
-                );
+                self.provider_metrics
+                    .record_failure(provider_name, latency_us, &err.message);
             }
             _ => {}
         }
@@ -1676,7 +1971,7 @@ impl Gateway {
         // non-retryable errors (400, 401, 403) are client errors that
         // should not trip the circuit.
         if let Some(ref registry) = self.circuit_breakers
-            && let Some(cb) = registry.get(action.provider.as_str())
+            && let Some(cb) = registry.get(provider_name)
         {
             let transition = match &result {
                 ActionOutcome::Executed(_) => cb.record_success().await,
@@ -1688,32 +1983,38 @@ impl Gateway {
             }
         }
 
-        match &result {
-            ActionOutcome::Executed(_) => self.metrics.increment_executed(),
-            ActionOutcome::Failed(_) => self.metrics.increment_failed(),
-            _ => {}
-        }
         result
     }
 
     /// Resolve attachments (if any) and execute the provider, returning the
     /// outcome along with the execution latency in microseconds.
+    ///
+    /// Feature flags are only handed to providers that declare
+    /// [`uses_feature_flags`](acteon_provider::DynProvider::uses_feature_flags).
     async fn execute_provider(
         &self,
         action: &Action,
-        provider: &dyn acteon_provider::DynProvider,
+        provider: &dyn DynProvider,
+        flags: &FeatureFlags,
     ) -> (ActionOutcome, u64) {
         // Resolve attachments and use context-aware execution if applicable.
+        let flags = if provider.uses_feature_flags() {
+            flags.clone()
+        } else {
+            FeatureFlags::new()
+        };
         let dispatch_ctx = if !action.attachments.is_empty() && provider.supports_attachments() {
             match self.resolve_attachments(action) {
                 Ok(resolved) => Some(acteon_provider::DispatchContext {
                     attachments: resolved,
+                    flags,
                 }),
                 Err(e) => {
                     self.metrics.increment_failed();
                     return (
                         ActionOutcome::Failed(acteon_core::ActionError {
                             code: "ATTACHMENT_ERROR".into(),
+                            reason_code: acteon_core::ReasonCode::ValidationFailed,
                             message: e.to_string(),
                             retryable: false,
                             attempts: 0,
@@ -1722,17 +2023,23 @@ impl Gateway {
                     );
                 }
             }
+        } else if !flags.is_empty() {
+            Some(acteon_provider::DispatchContext {
+                attachments: Vec::new(),
+                flags,
+            })
         } else {
             None
         };
 
+        let action = self.with_provider_defaults(action, provider.name());
         let exec_start = std::time::Instant::now();
         let result = if let Some(ref ctx) = dispatch_ctx {
             self.executor
-                .execute_with_context(action, provider, ctx)
+                .execute_with_context(&action, provider, ctx)
                 .await
         } else {
-            self.executor.execute(action, provider).await
+            self.executor.execute(&action, provider).await
         };
         let latency_us = u64::try_from(exec_start.elapsed().as_micros()).unwrap_or(u64::MAX);
         (result, latency_us)
@@ -1744,44 +2051,123 @@ impl Gateway {
         &self,
         action: &Action,
         ttl_seconds: Option<u64>,
+        window: DedupWindow,
+        flags: &FeatureFlags,
     ) -> Result<ActionOutcome, GatewayError> {
         let dedup_key = action
             .dedup_key
             .as_deref()
             .unwrap_or_else(|| action.id.as_str());
+        let dedup_key = match self.dedup_normalization.get(action.namespace.as_str()) {
+            Some(normalization) => normalization.apply(dedup_key),
+            None => std::borrow::Cow::Borrowed(dedup_key),
+        };
 
         let state_key = StateKey::new(
             action.namespace.as_str(),
             action.tenant.as_str(),
             KeyKind::Dedup,
-            dedup_key,
+            dedup_key.as_ref(),
         );
 
-        let ttl = ttl_seconds.map(Duration::from_secs);
-        let is_new = self.state.check_and_set(&state_key, "1", ttl).await?;
+        // Without a TTL the key never expires, so fixed and sliding windows
+        // behave the same.
+        let is_new = match ttl_seconds {
+            Some(ttl) => !self.observe_dedup_window(&state_key, ttl, window).await?,
+            None => self.state.check_and_set(&state_key, "1", None).await?,
+        };
 
         if is_new {
-            Ok(self.execute_action(action).await)
+            Ok(self.execute_action(action, flags).await)
         } else {
             self.metrics.increment_deduplicated();
             Ok(ActionOutcome::Deduplicated)
         }
     }
 
-    /// Handle the reroute verdict: execute with the target provider.
-    #[instrument(name = "gateway.handle_reroute", skip(self, action), fields(%target_provider))]
+    /// Record an occurrence against a dedup window and report whether it
+    /// is a duplicate.
+    ///
+    /// The key stores the window anchor in epoch milliseconds. The store TTL
+    /// only cleans up idle keys; the duplicate decision is made against
+    /// the gateway clock so windows can be stepped in tests. A value that
+    /// is not a timestamp (written before windows were tracked) counts as
+    /// an occurrence just now.
+    async fn observe_dedup_window(
+        &self,
+        key: &StateKey,
+        ttl_seconds: u64,
+        window: DedupWindow,
+    ) -> Result<bool, GatewayError> {
+        let ttl = Duration::from_secs(ttl_seconds);
+        let window_len = chrono::Duration::seconds(i64::try_from(ttl_seconds).unwrap_or(i64::MAX));
+        for _ in 0..DEDUP_CAS_ATTEMPTS {
+            let now = (self.clock)();
+            let current = self.state.get_versioned(key).await?;
+            let marker = current.as_ref().map(|(value, _)| {
+                value
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(DateTime::from_timestamp_millis)
+                    .unwrap_or(now)
+            });
+            let (duplicate, next) = window.observe(marker, window_len, now);
+            if marker == Some(next) {
+                // Fixed window still open: nothing to record.
+                return Ok(duplicate);
+            }
+            let value = next.timestamp_millis().to_string();
+            let stored = match current {
+                None => self.state.check_and_set(key, &value, Some(ttl)).await?,
+                Some((_, version)) => matches!(
+                    self.state
+                        .compare_and_swap(key, version, &value, Some(ttl))
+                        .await?,
+                    acteon_state::CasResult::Ok
+                ),
+            };
+            if stored {
+                return Ok(duplicate);
+            }
+        }
+        // Lost every race: another dispatch with the same key is being
+        // recorded concurrently, so treat this one as its duplicate.
+        Ok(true)
+    }
+
+    /// Handle the reroute verdict: execute with the target provider, in the
+    /// target namespace and tenant. Unset targets keep the action's own.
+    #[instrument(
+        name = "gateway.handle_reroute",
+        skip(self, action),
+        fields(?target_provider, ?target_namespace, ?target_tenant)
+    )]
     async fn handle_reroute(
         &self,
         action: &Action,
-        target_provider: &str,
+        target_provider: Option<&str>,
+        target_namespace: Option<&str>,
+        target_tenant: Option<&str>,
     ) -> Result<ActionOutcome, GatewayError> {
+        let mut action = std::borrow::Cow::Borrowed(action);
+        if target_namespace.is_some() || target_tenant.is_some() {
+            apply_reroute_scope(action.to_mut(), target_namespace, target_tenant);
+        }
+        let action = action.as_ref();
+        let target_provider = target_provider.unwrap_or(action.provider.as_str());
         let provider = self
             .providers
             .get(target_provider)
             .ok_or_else(|| GatewayError::ProviderNotFound(target_provider.to_owned()))?;
 
         let exec_start = std::time::Instant::now();
-        let result = self.executor.execute(action, provider.as_ref()).await;
+        let result = self
+            .executor
+            .execute(
+                &self.with_provider_defaults(action, target_provider),
+                provider.as_ref(),
+            )
+            .await;
         let latency_us = u64::try_from(exec_start.elapsed().as_micros()).unwrap_or(u64::MAX);
 
         // Record per-provider metrics for the reroute target.
@@ -1804,6 +2190,7 @@ impl Gateway {
                     original_provider: action.provider.to_string(),
                     new_provider: target_provider.to_owned(),
                     response: resp.clone(),
+                    failed_attempts: Vec::new(),
                 })
             }
             ActionOutcome::Failed(_) => {
@@ -1827,6 +2214,7 @@ impl Gateway {
         rule: &str,
         max_count: u64,
         window_seconds: u64,
+        flags: &FeatureFlags,
     ) -> Result<ActionOutcome, GatewayError> {
         let key = StateKey::new(
             action.namespace.as_str(),
@@ -1841,7 +2229,7 @@ impl Gateway {
             Ok(c) => c,
             Err(e) => {
                 warn!(error = %e, "throttle increment failed (fail-open)");
-                return Ok(self.execute_action(action).await);
+                return Ok(self.execute_action(action, flags).await);
             }
         };
 
@@ -1849,7 +2237,7 @@ impl Gateway {
         let used = new_count as u64;
 
         if used <= max_count {
-            Ok(self.execute_action(action).await)
+            Ok(self.execute_action(action, flags).await)
         } else {
             self.metrics.increment_throttled();
             Ok(ActionOutcome::Throttled {
@@ -1858,6 +2246,46 @@ impl Gateway {
         }
     }
 
+    /// Fingerprint an action for state machine tracking.
+    ///
+    /// Uses the active fingerprint version unless a dual-read version is
+    /// configured and the event already has state only under that version's
+    /// fingerprint, in which case the event stays on its original key.
+    async fn resolve_event_fingerprint(
+        &self,
+        action: &Action,
+        fingerprint_fields: &[String],
+    ) -> Result<(String, Option<FingerprintVersion>), GatewayError> {
+        let config = self.fingerprint_config;
+        let active = config.compute(action, fingerprint_fields);
+        let (Some(legacy_version), Some(legacy)) = (
+            config.dual_read_version,
+            config.compute_dual_read(action, fingerprint_fields),
+        ) else {
+            return Ok((active, Some(config.version)));
+        };
+
+        let event_key = |fingerprint: &str| {
+            StateKey::new(
+                action.namespace.as_str(),
+                action.tenant.as_str(),
+                KeyKind::EventState,
+                fingerprint,
+            )
+        };
+        if self.state.get(&event_key(&active)).await?.is_none()
+            && self.state.get(&event_key(&legacy)).await?.is_some()
+        {
+            debug!(
+                fingerprint = %legacy,
+                version = %legacy_version,
+                "event matched under dual-read fingerprint version"
+            );
+            return Ok((legacy, Some(legacy_version)));
+        }
+        Ok((active, Some(config.version)))
+    }
+
     /// Handle the state machine verdict: track event lifecycle.
     #[allow(clippy::too_many_lines)]
     #[instrument(name = "gateway.handle_state_machine", skip_all)]
@@ -1866,24 +2294,24 @@ impl Gateway {
         action: &Action,
         state_machine_name: &str,
         fingerprint_fields: &[String],
+        caller: Option<&Caller>,
     ) -> Result<ActionOutcome, GatewayError> {
         let state_machine = self.state_machines.get(state_machine_name).ok_or_else(|| {
             GatewayError::Configuration(format!("state machine not found: {state_machine_name}"))
         })?;
 
-        // Compute fingerprint from action fields
-        let fingerprint = if let Some(fp) = &action.fingerprint {
-            fp.clone()
+        // Compute fingerprint from action fields. An explicit fingerprint
+        // carries no algorithm version.
+        let (fingerprint, fingerprint_version) = if let Some(fp) = &action.fingerprint {
+            (fp.clone(), None)
         } else {
-            compute_fingerprint(action, fingerprint_fields)
+            self.resolve_event_fingerprint(action, fingerprint_fields)
+                .await?
         };
 
         // Acquire a lock on the fingerprint to prevent race conditions
         // between different actions affecting the same entity
-        let lock_name = format!(
-            "state:{}:{}:{}",
-            action.namespace, action.tenant, fingerprint
-        );
+        let lock_name = event_lock_name(&action.namespace, &action.tenant, &fingerprint);
         let guard = self
             .lock
             .acquire(&lock_name, Duration::from_secs(30), Duration::from_secs(5))
@@ -1942,11 +2370,29 @@ impl Gateway {
         let state_value = serde_json::json!({
             "state": &new_state,
             "fingerprint": &fingerprint,
+            "fingerprint_version": fingerprint_version,
             "updated_at": Utc::now().to_rfc3339(),
             "action_type": &action.action_type,
+            "state_machine": state_machine_name,
+        });
+        // Real transitions are appended to the event's history trail.
+        let transition = (current_state != new_state).then(|| {
+            let actor = caller.map_or("anonymous", |c| c.id.as_str());
+            EventTransition::new(
+                current_state.as_str(),
+                new_state.as_str(),
+                actor,
+                TransitionTrigger::Dispatch,
+            )
         });
-        let state_value_str = self.encrypt_state_value(&state_value.to_string())?;
-        self.state.set(&state_key, &state_value_str, None).await?;
+        write_event_state(
+            self.state.as_ref(),
+            self.payload_encryptor.as_ref(),
+            &state_key,
+            &state_value.to_string(),
+            transition,
+        )
+        .await?;
 
         // Update active events index for inhibition lookups
         let active_key = StateKey::new(
@@ -1959,23 +2405,215 @@ impl Gateway {
             "state": &new_state,
             "fingerprint": &fingerprint,
         });
-        let active_value_str = self.encrypt_state_value(&active_value.to_string())?;
-        self.state.set(&active_key, &active_value_str, None).await?;
+        let active_value_str = self.encrypt_state_value(&active_value.to_string())?;
+        self.state.set(&active_key, &active_value_str, None).await?;
+
+        // Create timeout entry if the new state has a configured timeout
+        if let Some(timeout_config) = state_machine.get_timeout_for_state(&new_state) {
+            #[allow(clippy::cast_possible_wrap)]
+            let expires_at =
+                Utc::now() + chrono::Duration::seconds(timeout_config.after_seconds as i64);
+            let timeout_key = StateKey::new(
+                action.namespace.as_str(),
+                action.tenant.as_str(),
+                KeyKind::EventTimeout,
+                &fingerprint,
+            );
+            let timeout_value = serde_json::json!({
+                "fingerprint": &fingerprint,
+                "state_machine": state_machine_name,
+                "current_state": &new_state,
+                "transition_to": &timeout_config.transition_to,
+                "expires_at": expires_at.to_rfc3339(),
+                "created_at": Utc::now().to_rfc3339(),
+                "trace_context": &action.trace_context,
+            });
+            let timeout_value_str = self.encrypt_state_value(&timeout_value.to_string())?;
+            self.state
+                .set(&timeout_key, &timeout_value_str, None)
+                .await?;
+
+            // Add to timeout index for efficient O(log N) queries
+            self.state
+                .index_timeout(&timeout_key, expires_at.timestamp_millis())
+                .await?;
+
+            debug!(
+                fingerprint = %fingerprint,
+                state = %new_state,
+                timeout_seconds = timeout_config.after_seconds,
+                "created timeout entry"
+            );
+        } else {
+            // Clear any existing timeout if the new state has no timeout
+            let timeout_key = StateKey::new(
+                action.namespace.as_str(),
+                action.tenant.as_str(),
+                KeyKind::EventTimeout,
+                &fingerprint,
+            );
+            let _ = self.state.delete(&timeout_key).await;
+            // Also remove from index (ignore errors if not present)
+            let _ = self.state.remove_timeout_index(&timeout_key).await;
+        }
+
+        // Release the fingerprint lock
+        guard
+            .release()
+            .await
+            .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
+
+        // Emit a top-level ActionStatusChanged so /v1/subscribe consumers
+        // see dispatch-driven transitions without parsing the nested
+        // ActionDispatched.outcome. Only fire on real transitions —
+        // no-ops (target == current) would be noise.
+        if current_state != new_state {
+            self.emit_stream_event(StreamEvent {
+                id: uuid::Uuid::now_v7().to_string(),
+                timestamp: Utc::now(),
+                event_type: StreamEventType::ActionStatusChanged {
+                    action_id: action.id.to_string(),
+                    fingerprint: fingerprint.clone(),
+                    state_machine: state_machine_name.to_string(),
+                    previous_status: current_state.clone(),
+                    new_status: new_state.clone(),
+                },
+                namespace: action.namespace.to_string(),
+                tenant: action.tenant.to_string(),
+                action_type: Some(action.action_type.clone()),
+                action_id: Some(action.id.to_string()),
+                traceparent: action.traceparent().map(str::to_owned),
+            });
+        }
+
+        Ok(ActionOutcome::StateChanged {
+            fingerprint,
+            previous_state: current_state,
+            new_state,
+            notify,
+        })
+    }
+
+    /// Transition the event identified by the action's fingerprint when the
+    /// provider response matches one of its state machine's response
+    /// transitions.
+    ///
+    /// The action has already executed, so failures are logged rather than
+    /// surfaced to the caller.
+    async fn apply_response_transition(
+        &self,
+        action: &Action,
+        provider: &str,
+        response: &ProviderResponse,
+        caller: Option<&Caller>,
+    ) {
+        let Some(fingerprint) = action.fingerprint.as_deref() else {
+            return;
+        };
+        if self
+            .state_machines
+            .values()
+            .all(|sm| sm.response_transitions.is_empty())
+        {
+            return;
+        }
+        if let Err(e) = self
+            .transition_from_response(action, fingerprint, provider, response, caller)
+            .await
+        {
+            warn!(
+                error = %e,
+                fingerprint = %fingerprint,
+                "failed to apply provider response transition"
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    async fn transition_from_response(
+        &self,
+        action: &Action,
+        fingerprint: &str,
+        provider: &str,
+        response: &ProviderResponse,
+        caller: Option<&Caller>,
+    ) -> Result<(), GatewayError> {
+        let lock_name = event_lock_name(&action.namespace, &action.tenant, fingerprint);
+        let guard = self
+            .lock
+            .acquire(&lock_name, Duration::from_secs(30), Duration::from_secs(5))
+            .await
+            .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
+
+        let state_key = StateKey::new(
+            action.namespace.as_str(),
+            action.tenant.as_str(),
+            KeyKind::EventState,
+            fingerprint,
+        );
+        let stored = match self.state.get(&state_key).await? {
+            Some(raw_val) => {
+                let val = self.decrypt_state_value(&raw_val).unwrap_or(raw_val);
+                serde_json::from_str::<serde_json::Value>(&val).ok()
+            }
+            None => None,
+        };
+        // Only events created through a state machine rule carry its name.
+        let Some((current_state, state_machine)) = stored.as_ref().and_then(|v| {
+            let state = v.get("state")?.as_str()?;
+            let sm = self.state_machines.get(v.get("state_machine")?.as_str()?)?;
+            Some((state.to_owned(), sm))
+        }) else {
+            return guard
+                .release()
+                .await
+                .map_err(|e| GatewayError::LockFailed(e.to_string()));
+        };
+        let Some(new_state) = state_machine
+            .response_transition_for(&current_state, provider, &response.body)
+            .map(str::to_owned)
+        else {
+            return guard
+                .release()
+                .await
+                .map_err(|e| GatewayError::LockFailed(e.to_string()));
+        };
+
+        let state_value = serde_json::json!({
+            "state": &new_state,
+            "fingerprint": fingerprint,
+            "updated_at": Utc::now().to_rfc3339(),
+            "action_type": &action.action_type,
+            "state_machine": &state_machine.name,
+        });
+        let actor = caller.map_or("anonymous", |c| c.id.as_str());
+        write_event_state(
+            self.state.as_ref(),
+            self.payload_encryptor.as_ref(),
+            &state_key,
+            &state_value.to_string(),
+            Some(EventTransition::new(
+                current_state.as_str(),
+                new_state.as_str(),
+                actor,
+                TransitionTrigger::ProviderResponse,
+            )),
+        )
+        .await?;
 
-        // Create timeout entry if the new state has a configured timeout
+        let timeout_key = StateKey::new(
+            action.namespace.as_str(),
+            action.tenant.as_str(),
+            KeyKind::EventTimeout,
+            fingerprint,
+        );
         if let Some(timeout_config) = state_machine.get_timeout_for_state(&new_state) {
             #[allow(clippy::cast_possible_wrap)]
             let expires_at =
                 Utc::now() + chrono::Duration::seconds(timeout_config.after_seconds as i64);
-            let timeout_key = StateKey::new(
-                action.namespace.as_str(),
-                action.tenant.as_str(),
-                KeyKind::EventTimeout,
-                &fingerprint,
-            );
             let timeout_value = serde_json::json!({
-                "fingerprint": &fingerprint,
-                "state_machine": state_machine_name,
+                "fingerprint": fingerprint,
+                "state_machine": &state_machine.name,
                 "current_state": &new_state,
                 "transition_to": &timeout_config.transition_to,
                 "expires_at": expires_at.to_rfc3339(),
@@ -1986,73 +2624,47 @@ impl Gateway {
             self.state
                 .set(&timeout_key, &timeout_value_str, None)
                 .await?;
-
-            // Add to timeout index for efficient O(log N) queries
             self.state
                 .index_timeout(&timeout_key, expires_at.timestamp_millis())
                 .await?;
-
-            debug!(
-                fingerprint = %fingerprint,
-                state = %new_state,
-                timeout_seconds = timeout_config.after_seconds,
-                "created timeout entry"
-            );
         } else {
-            // Clear any existing timeout if the new state has no timeout
-            let timeout_key = StateKey::new(
-                action.namespace.as_str(),
-                action.tenant.as_str(),
-                KeyKind::EventTimeout,
-                &fingerprint,
-            );
             let _ = self.state.delete(&timeout_key).await;
-            // Also remove from index (ignore errors if not present)
             let _ = self.state.remove_timeout_index(&timeout_key).await;
         }
 
-        // Release the fingerprint lock
         guard
             .release()
             .await
             .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
 
-        // Emit a top-level ActionStatusChanged so /v1/subscribe consumers
-        // see dispatch-driven transitions without parsing the nested
-        // ActionDispatched.outcome. Only fire on real transitions —
-        // no-ops (target == current) would be noise.
-        if current_state != new_state {
-            self.emit_stream_event(StreamEvent {
-                id: uuid::Uuid::now_v7().to_string(),
-                timestamp: Utc::now(),
-                event_type: StreamEventType::ActionStatusChanged {
-                    action_id: action.id.to_string(),
-                    fingerprint: fingerprint.clone(),
-                    state_machine: state_machine_name.to_string(),
-                    previous_status: current_state.clone(),
-                    new_status: new_state.clone(),
-                },
-                namespace: action.namespace.to_string(),
-                tenant: action.tenant.to_string(),
-                action_type: Some(action.action_type.clone()),
-                action_id: Some(action.id.to_string()),
-            });
-        }
-
-        Ok(ActionOutcome::StateChanged {
-            fingerprint,
-            previous_state: current_state,
-            new_state,
-            notify,
-        })
+        debug!(
+            fingerprint = %fingerprint,
+            from = %current_state,
+            to = %new_state,
+            "provider response transitioned event"
+        );
+        self.emit_stream_event(StreamEvent {
+            id: uuid::Uuid::now_v7().to_string(),
+            timestamp: Utc::now(),
+            event_type: StreamEventType::ActionStatusChanged {
+                action_id: action.id.to_string(),
+                fingerprint: fingerprint.to_owned(),
+                state_machine: state_machine.name.clone(),
+                previous_status: current_state,
+                new_status: new_state,
+            },
+            namespace: action.namespace.to_string(),
+            tenant: action.tenant.to_string(),
+            action_type: Some(action.action_type.clone()),
+            action_id: Some(action.id.to_string()),
+            traceparent: action.traceparent().map(str::to_owned),
+        });
+        Ok(())
     }
 
     /// Compute the HMAC-SHA256 signature for an approval using a specific key.
     ///
-    /// The message uses length-prefixed fields to prevent canonicalization
-    /// attacks (e.g., `ns="a:b", tenant="c"` vs `ns="a", tenant="b:c"`).
-    /// The `expires_at` timestamp binds the signature to a specific expiry
-    /// window so leaked links cannot be replayed after expiration.
+    /// See [`acteon_core::approval_signature`] for the signed message.
     fn compute_approval_sig_with_key(
         key: &ApprovalKey,
         ns: &str,
@@ -2060,19 +2672,7 @@ impl Gateway {
         id: &str,
         expires_at: i64,
     ) -> String {
-        let msg = format!(
-            "{}:{}\n{}:{}\n{}:{}\n{}",
-            ns.len(),
-            ns,
-            tenant.len(),
-            tenant,
-            id.len(),
-            id,
-            expires_at,
-        );
-        let mut mac = HmacSha256::new_from_slice(&key.secret).expect("HMAC accepts any key size");
-        mac.update(msg.as_bytes());
-        hex::encode(mac.finalize().into_bytes())
+        acteon_core::approval_signature(ns, tenant, id, expires_at, &key.secret)
     }
 
     /// Compute the HMAC-SHA256 signature using the current signing key.
@@ -2169,7 +2769,7 @@ impl Gateway {
         let record_json = serde_json::to_string(&record).map_err(|e| {
             GatewayError::Configuration(format!("failed to serialize approval: {e}"))
         })?;
-        let record_encrypted = self.encrypt_state_value(&record_json)?;
+        let record_encrypted = self.encrypt_action_state_value(action, &record_json)?;
 
         // Store the approval record keyed by namespace:tenant:approval:id
         let approval_key = StateKey::new(
@@ -2272,7 +2872,8 @@ impl Gateway {
             let updated_json = serde_json::to_string(&updated).map_err(|e| {
                 GatewayError::Configuration(format!("failed to serialize approval: {e}"))
             })?;
-            let updated_encrypted = self.encrypt_state_value(&updated_json)?;
+            let updated_encrypted =
+                self.encrypt_action_state_value(&updated.action, &updated_json)?;
             self.state
                 .set(&approval_key, &updated_encrypted, ttl)
                 .await?;
@@ -2280,6 +2881,27 @@ impl Gateway {
 
         self.metrics.increment_pending_approval();
 
+        // Unlike the dispatch event, whose approval URLs are redacted, this
+        // event carries the signed URL parameters for subscribers that
+        // decide approvals.
+        self.emit_stream_event(StreamEvent {
+            id: uuid::Uuid::now_v7().to_string(),
+            timestamp: Utc::now(),
+            event_type: StreamEventType::ApprovalRequired {
+                approval_id: id.clone(),
+                url_params: Some(ApprovalUrlParams {
+                    sig,
+                    expires_at: expires_ts,
+                    kid: Some(kid),
+                }),
+            },
+            namespace: action.namespace.to_string(),
+            tenant: action.tenant.to_string(),
+            action_type: Some(action.action_type.clone()),
+            action_id: Some(action.id.to_string()),
+            traceparent: action.traceparent().map(str::to_owned),
+        });
+
         Ok(ActionOutcome::PendingApproval {
             approval_id: id,
             expires_at,
@@ -2300,7 +2922,24 @@ impl Gateway {
         group_interval_seconds: u64,
         repeat_interval_seconds: Option<u64>,
         max_group_size: usize,
+        template: Option<&str>,
     ) -> Result<ActionOutcome, GatewayError> {
+        // Resolve the digest template by name in the action's scope; a
+        // missing template only disables the digest, not the grouping.
+        let digest_template = template.and_then(|name| {
+            let scope = (action.namespace.to_string(), action.tenant.to_string());
+            let content = self
+                .templates
+                .read()
+                .get(&scope)
+                .and_then(|scoped| scoped.get(name))
+                .map(|t| t.content.clone());
+            if content.is_none() {
+                warn!(template = %name, "group digest template not found; flushing without digest");
+            }
+            content
+        });
+
         let (group_id, group_key, group_size, notify_at) = self
             .group_manager
             .add_to_group(
@@ -2310,6 +2949,7 @@ impl Gateway {
                 group_interval_seconds,
                 repeat_interval_seconds,
                 max_group_size,
+                digest_template.as_deref(),
                 self.state.as_ref(),
                 self.payload_encryptor.as_deref(),
             )
@@ -2327,6 +2967,7 @@ impl Gateway {
             tenant: action.tenant.to_string(),
             action_type: Some(action.action_type.clone()),
             action_id: Some(action.id.to_string()),
+            traceparent: action.traceparent().map(str::to_owned),
         });
 
         Ok(ActionOutcome::Grouped {
@@ -2420,7 +3061,7 @@ impl Gateway {
             "scheduled_for": scheduled_for.to_rfc3339(),
             "created_at": now.to_rfc3339(),
         });
-        let sched_value = self.encrypt_state_value(&sched_data.to_string())?;
+        let sched_value = self.encrypt_action_state_value(action, &sched_data.to_string())?;
         self.state.set(&sched_key, &sched_value, ttl).await?;
 
         // Add to pending scheduled index using the timeout index mechanism.
@@ -2534,6 +3175,9 @@ impl Gateway {
                 .map(|(k, v)| (k.clone(), serde_json::Value::String(v.clone())))
                 .collect(),
             wait_state: None,
+            depth: 0,
+            steps_executed: 0,
+            limit_exceeded: None,
         };
 
         // Pin the definition before any state is persisted: an execution
@@ -2684,6 +3328,7 @@ impl Gateway {
                 tenant: tenant.to_string(),
                 action_type: Some(chain_state.chain_name.clone()),
                 action_id: Some(chain_state.origin_action.id.to_string()),
+                traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
             });
             guard
                 .release()
@@ -2745,6 +3390,7 @@ impl Gateway {
                 tenant: tenant.to_string(),
                 action_type: Some(chain_state.chain_name.clone()),
                 action_id: Some(chain_state.origin_action.id.to_string()),
+                traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
             });
             guard
                 .release()
@@ -2763,6 +3409,20 @@ impl Gateway {
 
         let step_config = &chain_config.steps[step_idx];
 
+        // Enforce the global chain limits each time a new step starts.
+        // Waiting statuses resume a step that was already counted.
+        if chain_state.status == ChainStatus::Running
+            && let Some(exceeded) = self.chain_limits.check_step(&mut chain_state, step_config)
+        {
+            self.abort_chain_for_limit(&chain_key, &mut chain_state, exceeded)
+                .await?;
+            guard
+                .release()
+                .await
+                .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
+            return Ok(());
+        }
+
         // Exhaustive step-kind dispatch. The wait-style kinds park or resume
         // the chain and return; only `Provider` falls through to the
         // synthetic-action dispatch below the match.
@@ -3310,6 +3970,8 @@ impl Gateway {
                         match child_state.status {
                             ChainStatus::Completed => {
                                 // Sub-chain completed — extract result and continue.
+                                chain_state.steps_executed =
+                                    chain_state.steps_executed.max(child_state.steps_executed);
                                 let step_result =
                                     Self::extract_sub_chain_result(sub_chain_name, &child_state);
                                 chain_state.step_results[step_idx] = Some(step_result.clone());
@@ -3360,6 +4022,30 @@ impl Gateway {
                             ChainStatus::Failed
                             | ChainStatus::TimedOut
                             | ChainStatus::Cancelled => {
+                                chain_state.steps_executed =
+                                    chain_state.steps_executed.max(child_state.steps_executed);
+                                // A limit breach aborts the whole tree, regardless
+                                // of the step's on_failure policy.
+                                if let Some(exceeded) = child_state.limit_exceeded {
+                                    chain_state.step_results[step_idx] = Some(StepResult::new(
+                                        format!("sub_chain:{sub_chain_name}"),
+                                        false,
+                                        None,
+                                        Some(exceeded.to_string()),
+                                        Utc::now(),
+                                    ));
+                                    self.abort_chain_for_limit(
+                                        &chain_key,
+                                        &mut chain_state,
+                                        exceeded,
+                                    )
+                                    .await?;
+                                    guard
+                                        .release()
+                                        .await
+                                        .map_err(|e| GatewayError::LockFailed(e.to_string()))?;
+                                    return Ok(());
+                                }
                                 // Sub-chain failed — apply step on_failure policy.
                                 let error_msg = format!(
                                     "sub-chain `{sub_chain_name}` {:?}",
@@ -3790,7 +4476,7 @@ impl Gateway {
         }
 
         let step_start = std::time::Instant::now();
-        let outcome = self.execute_action(&step_action).await;
+        let outcome = self.execute_chain_step(step_config, &step_action).await;
         let step_duration = step_start.elapsed();
         let now = Utc::now();
 
@@ -3868,6 +4554,7 @@ impl Gateway {
                         tenant: tenant.to_string(),
                         action_type: Some(step_config.action_type.clone()),
                         action_id: Some(chain_state.origin_action.id.to_string()),
+                        traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                     });
                 } else {
                     // Chain completed successfully.
@@ -3905,6 +4592,7 @@ impl Gateway {
                         tenant: tenant.to_string(),
                         action_type: Some(step_config.action_type.clone()),
                         action_id: Some(chain_state.origin_action.id.to_string()),
+                        traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                     });
                     self.emit_stream_event(StreamEvent {
                         id: uuid::Uuid::now_v7().to_string(),
@@ -3918,6 +4606,7 @@ impl Gateway {
                         tenant: tenant.to_string(),
                         action_type: Some(chain_state.chain_name.clone()),
                         action_id: Some(chain_state.origin_action.id.to_string()),
+                        traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                     });
                     info!(chain_id = %chain_id, "chain completed successfully");
                 }
@@ -4060,6 +4749,7 @@ impl Gateway {
                             tenant: tenant.to_string(),
                             action_type: Some(step_config.action_type.clone()),
                             action_id: Some(chain_state.origin_action.id.to_string()),
+                            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                         });
                         self.emit_stream_event(StreamEvent {
                             id: uuid::Uuid::now_v7().to_string(),
@@ -4073,6 +4763,7 @@ impl Gateway {
                             tenant: tenant.to_string(),
                             action_type: Some(chain_state.chain_name.clone()),
                             action_id: Some(chain_state.origin_action.id.to_string()),
+                            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                         });
                         warn!(
                             chain_id = %chain_id,
@@ -4130,6 +4821,10 @@ impl Gateway {
                                 tenant: tenant.to_string(),
                                 action_type: Some(step_config.action_type.clone()),
                                 action_id: Some(chain_state.origin_action.id.to_string()),
+                                traceparent: chain_state
+                                    .origin_action
+                                    .traceparent()
+                                    .map(str::to_owned),
                             });
                         } else {
                             chain_state.status = ChainStatus::Completed;
@@ -4170,6 +4865,10 @@ impl Gateway {
                                 tenant: tenant.to_string(),
                                 action_type: Some(step_config.action_type.clone()),
                                 action_id: Some(chain_state.origin_action.id.to_string()),
+                                traceparent: chain_state
+                                    .origin_action
+                                    .traceparent()
+                                    .map(str::to_owned),
                             });
                             self.emit_stream_event(StreamEvent {
                                 id: uuid::Uuid::now_v7().to_string(),
@@ -4183,6 +4882,10 @@ impl Gateway {
                                 tenant: tenant.to_string(),
                                 action_type: Some(chain_state.chain_name.clone()),
                                 action_id: Some(chain_state.origin_action.id.to_string()),
+                                traceparent: chain_state
+                                    .origin_action
+                                    .traceparent()
+                                    .map(str::to_owned),
                             });
                         }
                     }
@@ -4232,6 +4935,10 @@ impl Gateway {
                                 tenant: tenant.to_string(),
                                 action_type: Some(step_config.action_type.clone()),
                                 action_id: Some(chain_state.origin_action.id.to_string()),
+                                traceparent: chain_state
+                                    .origin_action
+                                    .traceparent()
+                                    .map(str::to_owned),
                             });
                         }
                         self.emit_stream_event(StreamEvent {
@@ -4246,6 +4953,7 @@ impl Gateway {
                             tenant: tenant.to_string(),
                             action_type: Some(chain_state.chain_name.clone()),
                             action_id: Some(chain_state.origin_action.id.to_string()),
+                            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                         });
                     }
                 }
@@ -4297,6 +5005,7 @@ impl Gateway {
                     tenant: tenant.to_string(),
                     action_type: Some(step_config.action_type.clone()),
                     action_id: Some(chain_state.origin_action.id.to_string()),
+                    traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                 });
                 self.emit_stream_event(StreamEvent {
                     id: uuid::Uuid::now_v7().to_string(),
@@ -4310,6 +5019,7 @@ impl Gateway {
                     tenant: tenant.to_string(),
                     action_type: Some(chain_state.chain_name.clone()),
                     action_id: Some(chain_state.origin_action.id.to_string()),
+                    traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                 });
             }
         }
@@ -4465,6 +5175,7 @@ impl Gateway {
                 tenant: tenant.to_string(),
                 action_type: Some(step_config.action_type.clone()),
                 action_id: Some(chain_state.origin_action.id.to_string()),
+                traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
             });
         } else {
             chain_state.status = ChainStatus::Completed;
@@ -4500,6 +5211,7 @@ impl Gateway {
                 tenant: tenant.to_string(),
                 action_type: Some(step_config.action_type.clone()),
                 action_id: Some(chain_state.origin_action.id.to_string()),
+                traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
             });
             self.emit_stream_event(StreamEvent {
                 id: uuid::Uuid::now_v7().to_string(),
@@ -4513,6 +5225,7 @@ impl Gateway {
                 tenant: tenant.to_string(),
                 action_type: Some(chain_state.chain_name.clone()),
                 action_id: Some(chain_state.origin_action.id.to_string()),
+                traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
             });
             info!(chain_id = %chain_id, "chain completed successfully");
         }
@@ -4598,6 +5311,7 @@ impl Gateway {
             tenant: tenant.to_string(),
             action_type: Some(step_config.action_type.clone()),
             action_id: Some(chain_state.origin_action.id.to_string()),
+            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
         });
         self.emit_stream_event(StreamEvent {
             id: uuid::Uuid::now_v7().to_string(),
@@ -4611,6 +5325,7 @@ impl Gateway {
             tenant: tenant.to_string(),
             action_type: Some(chain_state.chain_name.clone()),
             action_id: Some(chain_state.origin_action.id.to_string()),
+            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
         });
         warn!(
             chain_id = %chain_id,
@@ -4783,7 +5498,7 @@ impl Gateway {
                 let sub_name = sub_step.name.clone();
                 async move {
                     let start = std::time::Instant::now();
-                    let outcome = self.execute_action(&sub_action).await;
+                    let outcome = self.execute_chain_step(sub_step, &sub_action).await;
                     (sub_name, outcome, start.elapsed())
                 }
             })
@@ -4911,6 +5626,7 @@ impl Gateway {
                 tenant: tenant.to_string(),
                 action_type: Some(step_config.action_type.clone()),
                 action_id: Some(chain_state.origin_action.id.to_string()),
+                traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
             });
             self.emit_stream_event(StreamEvent {
                 id: uuid::Uuid::now_v7().to_string(),
@@ -4924,6 +5640,7 @@ impl Gateway {
                 tenant: tenant.to_string(),
                 action_type: Some(chain_state.chain_name.clone()),
                 action_id: Some(chain_state.origin_action.id.to_string()),
+                traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
             });
             // Clean up dedup keys.
             for key in &sub_dedup_keys {
@@ -5102,6 +5819,7 @@ impl Gateway {
                     tenant: tenant.to_string(),
                     action_type: Some(step_config.action_type.clone()),
                     action_id: Some(chain_state.origin_action.id.to_string()),
+                    traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                 });
             } else {
                 chain_state.status = ChainStatus::Completed;
@@ -5139,6 +5857,7 @@ impl Gateway {
                     tenant: tenant.to_string(),
                     action_type: Some(step_config.action_type.clone()),
                     action_id: Some(chain_state.origin_action.id.to_string()),
+                    traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                 });
                 self.emit_stream_event(StreamEvent {
                     id: uuid::Uuid::now_v7().to_string(),
@@ -5152,6 +5871,7 @@ impl Gateway {
                     tenant: tenant.to_string(),
                     action_type: Some(chain_state.chain_name.clone()),
                     action_id: Some(chain_state.origin_action.id.to_string()),
+                    traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                 });
                 info!(chain_id = %chain_id, "chain completed successfully");
             }
@@ -5198,6 +5918,7 @@ impl Gateway {
                         tenant: tenant.to_string(),
                         action_type: Some(step_config.action_type.clone()),
                         action_id: Some(chain_state.origin_action.id.to_string()),
+                        traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                     });
                     self.emit_stream_event(StreamEvent {
                         id: uuid::Uuid::now_v7().to_string(),
@@ -5211,6 +5932,7 @@ impl Gateway {
                         tenant: tenant.to_string(),
                         action_type: Some(chain_state.chain_name.clone()),
                         action_id: Some(chain_state.origin_action.id.to_string()),
+                        traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                     });
                     warn!(
                         chain_id = %chain_id,
@@ -5263,6 +5985,7 @@ impl Gateway {
                             tenant: tenant.to_string(),
                             action_type: Some(step_config.action_type.clone()),
                             action_id: Some(chain_state.origin_action.id.to_string()),
+                            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                         });
                     } else {
                         chain_state.status = ChainStatus::Completed;
@@ -5299,6 +6022,7 @@ impl Gateway {
                             tenant: tenant.to_string(),
                             action_type: Some(step_config.action_type.clone()),
                             action_id: Some(chain_state.origin_action.id.to_string()),
+                            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                         });
                         self.emit_stream_event(StreamEvent {
                             id: uuid::Uuid::now_v7().to_string(),
@@ -5312,6 +6036,7 @@ impl Gateway {
                             tenant: tenant.to_string(),
                             action_type: Some(chain_state.chain_name.clone()),
                             action_id: Some(chain_state.origin_action.id.to_string()),
+                            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                         });
                     }
                 }
@@ -5369,6 +6094,7 @@ impl Gateway {
                         tenant: tenant.to_string(),
                         action_type: Some(step_config.action_type.clone()),
                         action_id: Some(chain_state.origin_action.id.to_string()),
+                        traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                     });
                     self.emit_stream_event(StreamEvent {
                         id: uuid::Uuid::now_v7().to_string(),
@@ -5382,6 +6108,7 @@ impl Gateway {
                         tenant: tenant.to_string(),
                         action_type: Some(chain_state.chain_name.clone()),
                         action_id: Some(chain_state.origin_action.id.to_string()),
+                        traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
                     });
                 }
             }
@@ -5532,6 +6259,10 @@ impl Gateway {
             config_snapshot: None,
             search_attributes: parent.search_attributes.clone(),
             wait_state: None,
+            depth: parent.depth + 1,
+            // The step budget is shared with the parent and its ancestors.
+            steps_executed: parent.steps_executed,
+            limit_exceeded: None,
         };
 
         self.pin_chain_definition(&parent.namespace, &parent.tenant, &sub_config)
@@ -5613,6 +6344,54 @@ impl Gateway {
         Ok(child_id)
     }
 
+    /// Fail a chain that exceeded one of the configured [`ChainLimits`],
+    /// recording the breached limit on its state and in the audit trail.
+    ///
+    /// [`ChainLimits`]: acteon_core::ChainLimits
+    async fn abort_chain_for_limit(
+        &self,
+        chain_key: &StateKey,
+        chain_state: &mut ChainState,
+        exceeded: acteon_core::ChainLimitExceeded,
+    ) -> Result<(), GatewayError> {
+        warn!(
+            chain_id = %chain_state.chain_id,
+            chain_name = %chain_state.chain_name,
+            depth = chain_state.depth,
+            steps_executed = chain_state.steps_executed,
+            "{exceeded}; aborting chain"
+        );
+        chain_state.status = ChainStatus::Failed;
+        chain_state.limit_exceeded = Some(exceeded);
+        chain_state.updated_at = Utc::now();
+        self.persist_chain_state(chain_key, chain_state, self.completed_chain_ttl)
+            .await?;
+        self.cleanup_pending_chain(
+            &chain_state.namespace,
+            &chain_state.tenant,
+            &chain_state.chain_id,
+        )
+        .await?;
+        self.metrics.increment_chains_failed();
+        self.emit_chain_terminal_audit(chain_state, "chain_limit_exceeded")
+            .await;
+        self.emit_stream_event(StreamEvent {
+            id: uuid::Uuid::now_v7().to_string(),
+            timestamp: Utc::now(),
+            event_type: StreamEventType::ChainCompleted {
+                chain_id: chain_state.chain_id.clone(),
+                status: "failed".to_string(),
+                execution_path: chain_state.execution_path.clone(),
+            },
+            namespace: chain_state.namespace.clone(),
+            tenant: chain_state.tenant.clone(),
+            action_type: Some(chain_state.chain_name.clone()),
+            action_id: Some(chain_state.origin_action.id.to_string()),
+            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
+        });
+        Ok(())
+    }
+
     /// Extract the result from a completed sub-chain to use as the parent
     /// step's result.
     fn extract_sub_chain_result(sub_chain_name: &str, child: &ChainState) -> StepResult {
@@ -6489,6 +7268,7 @@ impl Gateway {
             tenant: tenant.to_string(),
             action_type: Some(chain_state.chain_name.clone()),
             action_id: Some(chain_state.origin_action.id.to_string()),
+            traceparent: chain_state.origin_action.traceparent().map(str::to_owned),
         });
 
         // Cascade cancellation to running child chains.
@@ -6687,7 +7467,12 @@ impl Gateway {
         //    transient audit-store outage in compliance mode would otherwise
         //    permanently brick the approval.
         let action = &record.action;
-        let mut eval_ctx = EvalContext::new(action, self.state.as_ref(), &self.environment);
+        let flags = self
+            .load_feature_flags(action.namespace.as_str(), action.tenant.as_str())
+            .await;
+        let mut eval_ctx = EvalContext::new(action, self.state.as_ref(), &self.environment)
+            .with_flags(&flags)
+            .with_holidays(&self.holiday_calendars);
         if let Some(ref emb) = self.embedding {
             eval_ctx = eval_ctx.with_embedding(Arc::clone(emb));
         }
@@ -6738,7 +7523,7 @@ impl Gateway {
         let updated_json = serde_json::to_string(&updated).map_err(|e| {
             GatewayError::Configuration(format!("failed to serialize approval: {e}"))
         })?;
-        let updated_encrypted = self.encrypt_state_value(&updated_json)?;
+        let updated_encrypted = self.encrypt_action_state_value(&updated.action, &updated_json)?;
         self.state
             .set(&approval_key, &updated_encrypted, None)
             .await?;
@@ -6754,6 +7539,7 @@ impl Gateway {
             tenant: tenant.to_string(),
             action_type: Some(record.action.action_type.clone()),
             action_id: Some(record.action.id.to_string()),
+            traceparent: record.action.traceparent().map(str::to_owned),
         });
 
         let outcome = match &verdict {
@@ -6769,22 +7555,32 @@ impl Gateway {
             RuleVerdict::Reroute {
                 rule: _,
                 target_provider,
-            } => self.handle_reroute(action, target_provider).await?,
+                target_namespace,
+                target_tenant,
+            } => {
+                self.handle_reroute(
+                    action,
+                    target_provider.as_deref(),
+                    target_namespace.as_deref(),
+                    target_tenant.as_deref(),
+                )
+                .await?
+            }
             RuleVerdict::Throttle {
                 rule,
                 max_count,
                 window_seconds,
             } => {
-                self.handle_throttle(action, rule, *max_count, *window_seconds)
+                self.handle_throttle(action, rule, *max_count, *window_seconds, &flags)
                     .await?
             }
             RuleVerdict::Modify { rule: _, changes } => {
                 let mut modified = action.clone();
                 json_patch::merge(&mut modified.payload, changes);
-                self.execute_action(&modified).await
+                self.execute_action(&modified, &flags).await
             }
             // Allow, RequestApproval (human already approved), dedup, state machine, group => execute
-            _ => self.execute_action(action).await,
+            _ => self.execute_action(action, &flags).await,
         };
 
         // Durably record the execution OUTCOME. Previously this path emitted no
@@ -6884,7 +7680,7 @@ impl Gateway {
         let updated_json = serde_json::to_string(&updated).map_err(|e| {
             GatewayError::Configuration(format!("failed to serialize approval: {e}"))
         })?;
-        let updated_encrypted = self.encrypt_state_value(&updated_json)?;
+        let updated_encrypted = self.encrypt_action_state_value(&updated.action, &updated_json)?;
         self.state
             .set(&approval_key, &updated_encrypted, None)
             .await?;
@@ -6900,6 +7696,7 @@ impl Gateway {
             tenant: tenant.to_string(),
             action_type: Some(record.action.action_type.clone()),
             action_id: Some(record.action.id.to_string()),
+            traceparent: record.action.traceparent().map(str::to_owned),
         });
 
         Ok(())
@@ -6972,7 +7769,8 @@ impl Gateway {
 
         // Look up the notification provider from the rule that created this approval.
         // We re-evaluate rules to find the matching RequestApproval rule.
-        let mut eval_ctx = EvalContext::new(&record.action, self.state.as_ref(), &self.environment);
+        let mut eval_ctx = EvalContext::new(&record.action, self.state.as_ref(), &self.environment)
+            .with_holidays(&self.holiday_calendars);
         if let Some(ref emb) = self.embedding {
             eval_ctx = eval_ctx.with_embedding(Arc::clone(emb));
         }
@@ -7034,7 +7832,7 @@ impl Gateway {
         let updated_json = serde_json::to_string(&updated).map_err(|e| {
             GatewayError::Configuration(format!("failed to serialize approval: {e}"))
         })?;
-        let updated_encrypted = self.encrypt_state_value(&updated_json)?;
+        let updated_encrypted = self.encrypt_action_state_value(&updated.action, &updated_json)?;
 
         // Preserve the original TTL by computing remaining time
         let remaining = updated.expires_at - Utc::now();
@@ -7184,7 +7982,7 @@ impl Gateway {
     /// Emit a stream event on the broadcast channel (fire-and-forget).
     ///
     /// No-op if there are no subscribers. Does not propagate send errors.
-    fn emit_stream_event(&self, event: StreamEvent) {
+    pub(crate) fn emit_stream_event(&self, event: StreamEvent) {
         let _ = self.stream_tx.send(event);
     }
 }
@@ -7635,6 +8433,7 @@ mod tests {
             Expr::Bool(true),
             RuleAction::Deduplicate {
                 ttl_seconds: Some(300),
+                window: acteon_core::DedupWindow::Fixed,
             },
         )];
         let gw = build_gateway(rules);
@@ -7656,6 +8455,75 @@ mod tests {
         assert_eq!(snap.deduplicated, 1);
     }
 
+    fn build_dedup_gateway(
+        normalization: Option<acteon_core::DedupKeyNormalization>,
+    ) -> crate::gateway::Gateway {
+        let mut builder = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .rules(vec![Rule::new(
+                "dedup",
+                Expr::Bool(true),
+                RuleAction::Deduplicate {
+                    ttl_seconds: Some(300),
+                    window: acteon_core::DedupWindow::Fixed,
+                },
+            )])
+            .provider(Arc::new(MockProvider::new("email")));
+        if let Some(normalization) = normalization {
+            builder = builder.dedup_normalization("notifications", normalization);
+        }
+        builder.build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn dedup_keys_compare_exactly_by_default() {
+        let gw = build_dedup_gateway(None);
+
+        let outcome1 = gw
+            .dispatch(test_action().with_dedup_key("Order-1 "), None)
+            .await
+            .unwrap();
+        let outcome2 = gw
+            .dispatch(test_action().with_dedup_key("order-1"), None)
+            .await
+            .unwrap();
+        assert!(matches!(outcome1, ActionOutcome::Executed(_)));
+        assert!(matches!(outcome2, ActionOutcome::Executed(_)));
+    }
+
+    #[tokio::test]
+    async fn dedup_keys_normalized_per_namespace() {
+        let gw = build_dedup_gateway(Some(acteon_core::DedupKeyNormalization::all()));
+
+        let outcome1 = gw
+            .dispatch(test_action().with_dedup_key("Order-1 "), None)
+            .await
+            .unwrap();
+        let outcome2 = gw
+            .dispatch(test_action().with_dedup_key("order-1"), None)
+            .await
+            .unwrap();
+        assert!(matches!(outcome1, ActionOutcome::Executed(_)));
+        assert!(matches!(outcome2, ActionOutcome::Deduplicated));
+
+        // Other namespaces keep exact comparison.
+        let other = |key: &str| {
+            Action::new(
+                "billing",
+                "tenant-1",
+                "email",
+                "send_email",
+                serde_json::json!({}),
+            )
+            .with_dedup_key(key)
+        };
+        let outcome3 = gw.dispatch(other("Order-1 "), None).await.unwrap();
+        let outcome4 = gw.dispatch(other("order-1"), None).await.unwrap();
+        assert!(matches!(outcome3, ActionOutcome::Executed(_)));
+        assert!(matches!(outcome4, ActionOutcome::Executed(_)));
+    }
+
     #[tokio::test]
     async fn dispatch_suppress() {
         let rules = vec![Rule::new(
@@ -7692,7 +8560,9 @@ mod tests {
             "reroute-sms",
             Expr::Bool(true),
             RuleAction::Reroute {
-                target_provider: "sms-fallback".into(),
+                target_provider: Some("sms-fallback".into()),
+                target_namespace: None,
+                target_tenant: None,
             },
         )];
         let gw = build_gateway(rules);
@@ -7839,21 +8709,161 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn dispatch_provider_not_found() {
-        let gw = build_gateway(vec![]);
-
-        // Action targeting a provider that is not registered.
+    async fn dispatch_provider_not_found() {
+        let gw = build_gateway(vec![]);
+
+        // Action targeting a provider that is not registered.
+        let mut action = test_action();
+        action.provider = "nonexistent".into();
+
+        let outcome = gw.dispatch(action, None).await.unwrap();
+        assert!(
+            matches!(outcome, ActionOutcome::Failed(_)),
+            "missing provider should produce Failed outcome"
+        );
+        assert_eq!(
+            outcome.reason_code(),
+            Some(acteon_core::ReasonCode::ProviderNotFound)
+        );
+
+        let snap = gw.metrics().snapshot();
+        assert_eq!(snap.failed, 1);
+    }
+
+    #[tokio::test]
+    async fn dispatch_invalid_attachment_is_validation_failure() {
+        struct AttachmentProvider;
+
+        #[async_trait]
+        impl DynProvider for AttachmentProvider {
+            fn name(&self) -> &str {
+                "email"
+            }
+
+            async fn execute(&self, _action: &Action) -> Result<ProviderResponse, ProviderError> {
+                Ok(ProviderResponse::success(serde_json::Value::Null))
+            }
+
+            async fn health_check(&self) -> Result<(), ProviderError> {
+                Ok(())
+            }
+
+            fn supports_attachments(&self) -> bool {
+                true
+            }
+        }
+
+        let gw = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::new(AttachmentProvider))
+            .build()
+            .expect("gateway should build");
+
+        let mut action = test_action();
+        action.attachments.push(acteon_core::Attachment {
+            id: "a1".into(),
+            name: "report".into(),
+            filename: "report.pdf".into(),
+            content_type: "application/pdf".into(),
+            data_base64: "not base64!".into(),
+        });
+
+        let outcome = gw.dispatch(action, None).await.unwrap();
+        match outcome {
+            ActionOutcome::Failed(err) => {
+                assert_eq!(err.code, "ATTACHMENT_ERROR");
+                assert_eq!(err.reason_code, acteon_core::ReasonCode::ValidationFailed);
+            }
+            other => panic!("expected Failed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn identical_attachments_resolve_to_shared_bytes() {
+        let gw = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .build()
+            .expect("gateway should build");
+
+        let logo = |id: &str| acteon_core::Attachment {
+            id: id.into(),
+            name: "logo".into(),
+            filename: "logo.png".into(),
+            content_type: "image/png".into(),
+            data_base64: "iVBORw0KGgo=".into(),
+        };
+        let mut first = test_action();
+        first.attachments.push(logo("a1"));
+        let mut second = test_action();
+        second.attachments.push(logo("a2"));
+
+        let a = gw.resolve_attachments(&first).unwrap().remove(0);
+        let b = gw.resolve_attachments(&second).unwrap().remove(0);
+
+        assert_eq!(a.content_hash, b.content_hash);
+        assert_eq!(Some(a.content_hash.clone()), logo("a1").content_hash());
+        assert_eq!(&*a.data, b"\x89PNG\r\n\x1a\n");
+        assert!(Arc::ptr_eq(&a.data, &b.data));
+    }
+
+    fn build_gateway_with_namespace_defaults() -> crate::gateway::Gateway {
+        GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::new(MockProvider::new("email")))
+            .namespace_defaults(
+                "notifications",
+                acteon_core::NamespaceDefaults {
+                    provider: Some("email".into()),
+                    action_type: Some("send_email".into()),
+                },
+            )
+            .build()
+            .expect("gateway should build")
+    }
+
+    #[tokio::test]
+    async fn namespace_defaults_fill_omitted_provider_and_action_type() {
+        let gw = build_gateway_with_namespace_defaults();
+        let mut action = test_action();
+        action.provider = acteon_core::ProviderId::default();
+        action.action_type = String::new();
+
+        let outcome = gw.dispatch(action, None).await.unwrap();
+        assert!(
+            matches!(outcome, ActionOutcome::Executed(_)),
+            "default provider should execute, got {outcome:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn explicit_provider_overrides_namespace_default() {
+        let gw = build_gateway_with_namespace_defaults();
         let mut action = test_action();
         action.provider = "nonexistent".into();
 
+        // The default provider is registered, so only the explicit one can
+        // produce a provider-not-found failure.
         let outcome = gw.dispatch(action, None).await.unwrap();
-        assert!(
-            matches!(outcome, ActionOutcome::Failed(_)),
-            "missing provider should produce Failed outcome"
+        assert_eq!(
+            outcome.reason_code(),
+            Some(acteon_core::ReasonCode::ProviderNotFound)
         );
+    }
 
-        let snap = gw.metrics().snapshot();
-        assert_eq!(snap.failed, 1);
+    #[tokio::test]
+    async fn missing_provider_without_default_is_rejected() {
+        let gw = build_gateway(vec![]);
+        let mut action = test_action();
+        action.provider = acteon_core::ProviderId::default();
+
+        let err = gw.dispatch(action, None).await.unwrap_err();
+        assert!(
+            matches!(err, crate::error::GatewayError::MissingProvider(ref ns) if ns == "notifications"),
+            "expected MissingProvider, got {err:?}"
+        );
     }
 
     #[tokio::test]
@@ -7862,7 +8872,9 @@ mod tests {
             "reroute-missing",
             Expr::Bool(true),
             RuleAction::Reroute {
-                target_provider: "does-not-exist".into(),
+                target_provider: Some("does-not-exist".into()),
+                target_namespace: None,
+                target_tenant: None,
             },
         )];
         let gw = build_gateway(rules);
@@ -8051,6 +9063,49 @@ mod tests {
         assert!(matches!(outcome, ActionOutcome::Suppressed { .. }));
     }
 
+    #[tokio::test]
+    async fn rollback_rules_restores_previous_set() {
+        let mut gw = build_gateway(vec![]);
+
+        let v2 = gw.reload_rules(vec![Rule::new(
+            "block",
+            Expr::Bool(true),
+            RuleAction::Suppress,
+        )]);
+        let v3 = gw.reload_rules(vec![Rule::new(
+            "allow",
+            Expr::Bool(true),
+            RuleAction::Allow,
+        )]);
+        let outcome = gw.dispatch(test_action(), None).await.unwrap();
+        assert!(matches!(outcome, ActionOutcome::Executed(_)));
+
+        gw.rollback_rules(v2).unwrap();
+        let outcome = gw.dispatch(test_action(), None).await.unwrap();
+        assert!(matches!(outcome, ActionOutcome::Suppressed { .. }));
+
+        let versions = gw.rule_set_versions();
+        assert_eq!(versions[0].version, v2);
+        assert!(versions[0].active);
+        assert!(versions.iter().any(|v| v.version == v3 && !v.active));
+        assert!(gw.rollback_rules(99).is_err());
+    }
+
+    #[test]
+    fn failed_directory_load_keeps_active_rules() {
+        let mut gw = build_gateway(vec![Rule::new(
+            "block",
+            Expr::Bool(true),
+            RuleAction::Suppress,
+        )]);
+        let frontends: Vec<&dyn acteon_rules::RuleFrontend> = vec![];
+        let missing = std::path::Path::new("/nonexistent/acteon-rules");
+
+        assert!(gw.load_rules_from_directory(missing, &frontends).is_err());
+        assert_eq!(gw.rules().len(), 1);
+        assert_eq!(gw.rule_set_versions().len(), 1);
+    }
+
     #[tokio::test]
     async fn metrics_increment_correctly() {
         let gw = build_gateway(vec![]);
@@ -8239,6 +9294,40 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn approval_required_event_carries_url_params() {
+        let gw = build_approval_gateway(
+            vec![approval_rule(3600)],
+            vec![
+                Arc::new(MockProvider::new("payments")),
+                Arc::new(MockProvider::new("slack")),
+            ],
+        );
+        let mut rx = gw.stream_tx().subscribe();
+
+        let outcome = gw.dispatch(refund_action(), None).await.unwrap();
+        let ActionOutcome::PendingApproval { approve_url, .. } = outcome else {
+            panic!("expected PendingApproval, got {outcome:?}");
+        };
+
+        let params = loop {
+            let event = rx.try_recv().expect("approval_required event");
+            if let StreamEventType::ApprovalRequired {
+                url_params: Some(params),
+                ..
+            } = event.event_type
+            {
+                break params;
+            }
+        };
+        assert_eq!(Some(params.sig), parse_query_param(&approve_url, "sig"));
+        assert_eq!(
+            Some(params.expires_at.to_string()),
+            parse_query_param(&approve_url, "expires_at")
+        );
+        assert_eq!(params.kid, parse_query_param(&approve_url, "kid"));
+    }
+
     #[tokio::test]
     async fn approval_approve_executes_action() {
         let gw = build_approval_gateway(
@@ -9044,7 +10133,9 @@ mod tests {
             "reroute-sms",
             Expr::Bool(true),
             RuleAction::Reroute {
-                target_provider: "sms-fallback".into(),
+                target_provider: Some("sms-fallback".into()),
+                target_namespace: None,
+                target_tenant: None,
             },
         )];
         let gw = build_gateway(rules);
@@ -9079,6 +10170,7 @@ mod tests {
             Expr::Bool(true),
             RuleAction::Deduplicate {
                 ttl_seconds: Some(300),
+                window: acteon_core::DedupWindow::Fixed,
             },
         )];
         let gw = build_gateway(rules);
@@ -9146,6 +10238,137 @@ mod tests {
         }
     }
 
+    // -- Preview tests --------------------------------------------------------
+
+    #[tokio::test]
+    async fn preview_contains_rendered_template_payload() {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "subject".to_string(),
+            acteon_core::TemplateProfileField::Inline("Hello, {{ name }}!".to_string()),
+        );
+        let profile = acteon_core::TemplateProfile {
+            id: "prof-welcome".into(),
+            name: "welcome".into(),
+            namespace: "notifications".into(),
+            tenant: "tenant-1".into(),
+            fields,
+            output_type: None,
+            description: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            labels: HashMap::new(),
+            deleted_at: None,
+        };
+        let (provider, captured) = CapturingProvider::new("email");
+        let gw = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::new(provider))
+            .template_profile(profile)
+            .build()
+            .unwrap();
+
+        let action = Action::new(
+            "notifications",
+            "tenant-1",
+            "email",
+            "send_email",
+            serde_json::json!({"to": "user@example.com", "name": "Alice"}),
+        )
+        .with_template("welcome");
+        let preview = gw.preview_dispatch(action).await.unwrap();
+
+        assert_eq!(preview.verdict, "allow");
+        assert_eq!(preview.provider.as_deref(), Some("email"));
+        assert_eq!(preview.payload["subject"], "Hello, Alice!");
+        assert_eq!(preview.payload["to"], "user@example.com");
+        assert!(captured.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn preview_applies_modify_and_reroute() {
+        let gw = build_gateway(vec![Rule::new(
+            "bump-priority",
+            Expr::Bool(true),
+            RuleAction::Modify {
+                changes: serde_json::json!({"priority": "high"}),
+            },
+        )]);
+        let preview = gw.preview_dispatch(test_action()).await.unwrap();
+        assert_eq!(preview.verdict, "modify");
+        assert_eq!(preview.matched_rule.as_deref(), Some("bump-priority"));
+        assert_eq!(preview.payload["priority"], "high");
+
+        let gw = build_gateway(vec![Rule::new(
+            "reroute-sms",
+            Expr::Bool(true),
+            RuleAction::Reroute {
+                target_provider: Some("sms-fallback".into()),
+                target_namespace: None,
+                target_tenant: None,
+            },
+        )]);
+        let preview = gw.preview_dispatch(test_action()).await.unwrap();
+        assert_eq!(preview.provider.as_deref(), Some("sms-fallback"));
+        assert_eq!(preview.providers, vec!["sms-fallback"]);
+    }
+
+    #[tokio::test]
+    async fn preview_lists_chain_step_providers() {
+        use acteon_core::chain::{ChainConfig, ChainStepConfig};
+
+        let step = |name: &str, provider: &str| {
+            ChainStepConfig::new(name, provider, "notify", serde_json::json!({}))
+        };
+        let gw = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::new(MockProvider::new("email")))
+            .rules(vec![Rule::new(
+                "escalate",
+                Expr::Bool(true),
+                RuleAction::Chain {
+                    chain: "escalation".into(),
+                },
+            )])
+            .chain(
+                ChainConfig::new("escalation")
+                    .with_step(step("page", "pagerduty"))
+                    .with_step(step("notify", "slack"))
+                    .with_step(step("follow-up", "pagerduty")),
+            )
+            .build()
+            .unwrap();
+
+        let preview = gw.preview_dispatch(test_action()).await.unwrap();
+        assert_eq!(preview.verdict, "chain");
+        assert!(preview.provider.is_none());
+        assert_eq!(preview.providers, vec!["pagerduty", "slack"]);
+    }
+
+    #[tokio::test]
+    async fn preview_deny_has_no_provider() {
+        let gw = build_gateway(vec![Rule::new(
+            "block-all",
+            Expr::Bool(true),
+            RuleAction::Deny,
+        )]);
+        let preview = gw.preview_dispatch(test_action()).await.unwrap();
+        assert_eq!(preview.verdict, "deny");
+        assert!(preview.provider.is_none());
+    }
+
+    #[tokio::test]
+    async fn preview_missing_template_profile_errors() {
+        let gw = build_gateway(vec![]);
+        let err = gw
+            .preview_dispatch(test_action().with_template("missing"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::error::GatewayError::TemplateRender(_)));
+    }
+
     // -- Circuit breaker integration tests ------------------------------------
 
     use crate::circuit_breaker::{CircuitBreakerConfig, CircuitState};
@@ -9217,6 +10440,10 @@ mod tests {
         // Circuit is now open. Next dispatch should be rejected without
         // calling the provider.
         let outcome3 = gw.dispatch(test_action(), None).await.unwrap();
+        assert_eq!(
+            outcome3.reason_code(),
+            Some(acteon_core::ReasonCode::CircuitOpen)
+        );
         match outcome3 {
             ActionOutcome::CircuitOpen {
                 provider,
@@ -9781,7 +11008,9 @@ mod tests {
             "reroute-sms",
             Expr::Bool(true),
             RuleAction::Reroute {
-                target_provider: "sms-fallback".into(),
+                target_provider: Some("sms-fallback".into()),
+                target_namespace: None,
+                target_tenant: None,
             },
         )];
         let gw = build_gateway(rules);
@@ -10221,6 +11450,52 @@ mod tests {
         assert!(saw_lagged, "slow subscriber should experience lagged error");
     }
 
+    // -- Rule evaluation budget -------------------------------------------------
+
+    fn build_gateway_with_budget(budget: acteon_rules::EvalBudget) -> crate::gateway::Gateway {
+        GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .rules(vec![
+                Rule::new("miss", Expr::Bool(false), RuleAction::Allow).with_priority(1),
+                Rule::new("block-all", Expr::Bool(true), RuleAction::Deny).with_priority(2),
+            ])
+            .rule_evaluation_budget(budget)
+            .provider(Arc::new(MockProvider::new("email")))
+            .build()
+            .expect("gateway should build")
+    }
+
+    #[tokio::test]
+    async fn budget_cutoff_is_named_in_the_verdict() {
+        use acteon_rules::{BUDGET_EXCEEDED_RULE, EvalBudget, RuleVerdict};
+
+        use crate::audit_helpers::matched_rule_name;
+
+        let budget = EvalBudget::unlimited().with_max_rules(1);
+        let gw = build_gateway_with_budget(budget);
+        assert!(matches!(
+            gw.dispatch(test_action(), None).await.unwrap(),
+            ActionOutcome::Executed(_)
+        ));
+        let verdict = RuleVerdict::Allow(Some(BUDGET_EXCEEDED_RULE.to_owned()));
+        assert_eq!(
+            matched_rule_name(&verdict).as_deref(),
+            Some(BUDGET_EXCEEDED_RULE)
+        );
+        assert_eq!(
+            matched_rule_name(&RuleVerdict::Allow(Some("x".into()))),
+            None
+        );
+
+        let gw = build_gateway_with_budget(budget.with_fail_closed(true));
+        let outcome = gw.dispatch(test_action(), None).await.unwrap();
+        assert!(
+            matches!(&outcome, ActionOutcome::Suppressed { rule } if rule == BUDGET_EXCEEDED_RULE),
+            "{outcome:?}"
+        );
+    }
+
     // -- resolve_next_step tests -----------------------------------------------
 
     mod resolve_next_step_tests {
@@ -10646,6 +11921,8 @@ mod tests {
             id: uuid::Uuid::new_v4().to_string(),
             namespace: namespace.into(),
             tenant: tenant.into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -10657,6 +11934,7 @@ mod tests {
             updated_at: chrono::Utc::now(),
             description: None,
             labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
         }
     }
 
@@ -10747,6 +12025,10 @@ mod tests {
 
         // 4th dispatch should be blocked.
         let outcome = gw.dispatch(test_action(), None).await.unwrap();
+        assert_eq!(
+            outcome.reason_code(),
+            Some(acteon_core::ReasonCode::QuotaExceeded)
+        );
         match outcome {
             ActionOutcome::QuotaExceeded {
                 tenant,
@@ -10767,6 +12049,72 @@ mod tests {
         assert_eq!(snap.quota_exceeded, 1);
     }
 
+    #[tokio::test]
+    async fn org_quota_shared_across_tenants() {
+        let mut org_policy = make_quota_policy(
+            "notifications",
+            acteon_core::ORG_QUOTA_COUNTER_TENANT,
+            3,
+            acteon_core::QuotaWindow::Hourly,
+            acteon_core::OverageBehavior::Block,
+            true,
+        );
+        org_policy.org = Some("acme".into());
+        org_policy.members = vec!["tenant-a".into(), "tenant-b".into()];
+        let tenant_a_cap = make_quota_policy(
+            "notifications",
+            "tenant-a",
+            2,
+            acteon_core::QuotaWindow::Hourly,
+            acteon_core::OverageBehavior::Block,
+            true,
+        );
+        let gw = build_gateway_with_quota(vec![org_policy, tenant_a_cap]);
+        let action_for = |tenant: &str| {
+            let mut action = test_action();
+            action.tenant = tenant.into();
+            action
+        };
+        let is_blocked = |outcome: &ActionOutcome| matches!(outcome, ActionOutcome::QuotaExceeded { overage_behavior, .. } if overage_behavior == "block");
+
+        // Tenant A uses two of the org's three slots.
+        for i in 0..2 {
+            let outcome = gw.dispatch(action_for("tenant-a"), None).await.unwrap();
+            assert!(
+                matches!(outcome, ActionOutcome::Executed(_)),
+                "tenant-a dispatch {i} should fit both quotas"
+            );
+        }
+
+        // Tenant A's own cap is exhausted even though the org has room.
+        let outcome = gw.dispatch(action_for("tenant-a"), None).await.unwrap();
+        assert!(is_blocked(&outcome), "tenant cap should block: {outcome:?}");
+
+        // The blocked dispatch was rolled back, so tenant B gets the last org slot.
+        let outcome = gw.dispatch(action_for("tenant-b"), None).await.unwrap();
+        assert!(matches!(outcome, ActionOutcome::Executed(_)));
+
+        // Together the two tenants have exhausted the org quota.
+        let outcome = gw.dispatch(action_for("tenant-b"), None).await.unwrap();
+        match outcome {
+            ActionOutcome::QuotaExceeded {
+                tenant,
+                limit,
+                used,
+                ..
+            } => {
+                assert_eq!(tenant, "tenant-b");
+                assert_eq!(limit, 3);
+                assert_eq!(used, 4);
+            }
+            other => panic!("expected org QuotaExceeded, got {other:?}"),
+        }
+
+        // Tenants outside the org are unaffected.
+        let outcome = gw.dispatch(action_for("tenant-c"), None).await.unwrap();
+        assert!(matches!(outcome, ActionOutcome::Executed(_)));
+    }
+
     #[tokio::test]
     async fn quota_warns_when_exceeded() {
         let policy = make_quota_policy(
@@ -11521,7 +12869,9 @@ mod tests {
             "reroute-to-sms",
             Expr::Bool(true),
             RuleAction::Reroute {
-                target_provider: "sms-fallback".into(),
+                target_provider: Some("sms-fallback".into()),
+                target_namespace: None,
+                target_tenant: None,
             },
         )];
 
@@ -11653,6 +13003,7 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             labels: HashMap::new(),
+            deleted_at: None,
         };
         let tpl_b = acteon_core::Template {
             id: "t2".into(),
@@ -11664,6 +13015,7 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             labels: HashMap::new(),
+            deleted_at: None,
         };
         let tpl_other = acteon_core::Template {
             id: "t3".into(),
@@ -11675,6 +13027,7 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             labels: HashMap::new(),
+            deleted_at: None,
         };
 
         let gw = GatewayBuilder::new()
@@ -11714,6 +13067,7 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             labels: HashMap::new(),
+            deleted_at: None,
         };
 
         let gw = GatewayBuilder::new()
@@ -11753,6 +13107,7 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             labels: HashMap::new(),
+            deleted_at: None,
         };
         let key = acteon_state::StateKey::new(
             "_system",
@@ -12090,6 +13445,37 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn traceparent_reaches_audit_record_and_stream_event() {
+        let tp = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let (gw, _captured) = build_compliance_gateway(
+            Arc::new(TestAudit {
+                fail: false,
+                records: Arc::clone(&records),
+            }),
+            Some(acteon_core::ComplianceConfig::new(
+                acteon_core::ComplianceMode::Soc2,
+            )),
+        );
+        let mut rx = gw.stream_tx().subscribe();
+
+        let action = test_action().with_traceparent(tp, Some("vendor=1".into()));
+        let outcome = gw.dispatch(action, None).await.unwrap();
+        assert!(matches!(outcome, ActionOutcome::Executed(_)));
+
+        let event = tokio::time::timeout(Duration::from_secs(1), rx.recv())
+            .await
+            .expect("should receive within timeout")
+            .expect("broadcast should not be closed");
+        assert_eq!(event.traceparent.as_deref(), Some(tp));
+
+        let recs = records.lock().unwrap();
+        let outcome_record = recs.last().expect("outcome record");
+        assert_eq!(outcome_record.metadata["traceparent"], tp);
+        assert_eq!(outcome_record.metadata["tracestate"], "vendor=1");
+    }
+
     // -- advance_chain bounds check (definition shrunk mid-flight) ------------
 
     #[test]
@@ -12156,6 +13542,9 @@ mod tests {
             config_snapshot: None,
             search_attributes: Default::default(),
             wait_state: None,
+            depth: 0,
+            steps_executed: 0,
+            limit_exceeded: None,
         };
         let key = StateKey::new("notifications", "tenant-1", KeyKind::Chain, "c1");
         gw.state_store()
@@ -12230,6 +13619,9 @@ mod tests {
             config_snapshot: None,
             search_attributes: Default::default(),
             wait_state: None,
+            depth: 0,
+            steps_executed: 0,
+            limit_exceeded: None,
         };
         let key = StateKey::new("notifications", "tenant-1", KeyKind::Chain, "g1");
         gw.state_store()
@@ -12310,6 +13702,9 @@ mod tests {
             config_snapshot: None,
             search_attributes: Default::default(),
             wait_state: None,
+            depth: 0,
+            steps_executed: 0,
+            limit_exceeded: None,
         };
 
         let dag = gw
@@ -12374,6 +13769,9 @@ mod tests {
             config_snapshot: None,
             search_attributes: Default::default(),
             wait_state: None,
+            depth: 0,
+            steps_executed: 0,
+            limit_exceeded: None,
         };
         let key = StateKey::new("notifications", "tenant-1", KeyKind::Chain, chain_id);
         let store = gw.state_store();
@@ -12600,6 +13998,9 @@ mod tests {
             config_snapshot: None,
             search_attributes: Default::default(),
             wait_state: None,
+            depth: 0,
+            steps_executed: 0,
+            limit_exceeded: None,
         };
         let key = StateKey::new("notifications", "tenant-1", KeyKind::Chain, "cp1");
         gw.state_store()
@@ -12634,4 +14035,82 @@ mod tests {
             "no sub-step may be recorded when the group failed closed",
         );
     }
+
+    fn build_encrypting_gateway(
+        compliance: acteon_core::ComplianceConfig,
+        records: &Arc<Mutex<Vec<acteon_audit::AuditRecord>>>,
+    ) -> crate::gateway::Gateway {
+        let key = acteon_crypto::parse_master_key(&"ab".repeat(32)).unwrap();
+        let encryptor = Arc::new(acteon_crypto::PayloadEncryptor::new(key));
+        let audit = acteon_audit::EncryptingAuditStore::new(
+            Arc::new(TestAudit {
+                fail: false,
+                records: Arc::clone(records),
+            }),
+            Arc::clone(&encryptor),
+        );
+        GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::new(MockProvider::new("email")))
+            .payload_encryptor(encryptor)
+            .plaintext_action_types(["heartbeat"])
+            .audit(Arc::new(audit))
+            .audit_store_payload(true)
+            .compliance_config(compliance)
+            .build()
+            .expect("gateway should build")
+    }
+
+    /// Dispatch a default, a plaintext-type, and an explicitly opted-out
+    /// action and report which audited payloads were stored in plaintext.
+    async fn plaintext_audit_payloads(compliance: acteon_core::ComplianceConfig) -> Vec<bool> {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let gw = build_encrypting_gateway(compliance, &records);
+
+        let mut heartbeat = test_action();
+        heartbeat.action_type = "heartbeat".into();
+        let actions = [
+            test_action(),
+            heartbeat,
+            test_action().with_encrypt_payload(false),
+        ];
+        let mut ids = Vec::new();
+        for action in actions {
+            ids.push(action.id.to_string());
+            gw.dispatch(action, None).await.unwrap();
+        }
+
+        let recs = records.lock().unwrap();
+        ids.iter()
+            .map(|id| {
+                let record = recs
+                    .iter()
+                    .find(|r| &r.action_id == id)
+                    .expect("action should be audited");
+                record.action_payload.as_ref().expect("payload stored")
+                    == &serde_json::json!({"to": "user@example.com"})
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn encryption_opt_out_stores_payload_in_plaintext() {
+        let compliance = acteon_core::ComplianceConfig::default().with_sync_audit_writes(true);
+        assert_eq!(
+            plaintext_audit_payloads(compliance).await,
+            [false, true, true],
+            "only the default action's payload is encrypted",
+        );
+    }
+
+    #[tokio::test]
+    async fn compliance_mode_forbids_encryption_opt_out() {
+        let compliance = acteon_core::ComplianceConfig::new(acteon_core::ComplianceMode::Soc2);
+        assert_eq!(
+            plaintext_audit_payloads(compliance).await,
+            [false, false, false],
+            "compliance mode encrypts every payload",
+        );
+    }
 }
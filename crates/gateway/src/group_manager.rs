@@ -70,7 +70,11 @@ impl GroupManager {
             // top level so direct deserialization fails.
             let group: Option<EventGroup> = serde_json::from_str::<EventGroup>(&value)
                 .ok()
-                .or_else(|| parse_legacy_group(&value));
+                .or_else(|| parse_legacy_group(&value))
+                .map(|mut group| {
+                    group.sort_events();
+                    group
+                });
 
             match group {
                 Some(mut group) => {
@@ -111,6 +115,8 @@ impl GroupManager {
     ///
     /// Returns a tuple of (group ID, group key, current group size, notify at time).
     /// If an encryptor is provided, group metadata is encrypted before storage.
+    /// `digest_template` is captured on newly created groups and rendered
+    /// by the flush worker.
     ///
     /// ## Scheduling
     ///
@@ -137,6 +143,7 @@ impl GroupManager {
         group_interval_seconds: u64,
         repeat_interval_seconds: Option<u64>,
         max_group_size: usize,
+        digest_template: Option<&str>,
         state: &dyn StateStore,
         encryptor: Option<&PayloadEncryptor>,
     ) -> Result<(String, String, usize, DateTime<Utc>), GatewayError> {
@@ -218,6 +225,9 @@ impl GroupManager {
                     }
                 }
                 group = group.with_labels(labels);
+                if let Some(template) = digest_template {
+                    group = group.with_digest_template(template);
+                }
                 group.add_event(grouped_event);
 
                 let snapshot = group.clone();
@@ -288,7 +298,11 @@ impl GroupManager {
             // partial-JSON format for pre-Phase-2 records.
             let group: Option<EventGroup> = serde_json::from_str::<EventGroup>(&value)
                 .ok()
-                .or_else(|| parse_legacy_group(&value));
+                .or_else(|| parse_legacy_group(&value))
+                .map(|mut group| {
+                    group.sort_events();
+                    group
+                });
 
             match group {
                 Some(g) => {
@@ -595,7 +609,10 @@ mod tests {
             "alert",
             serde_json::json!({"host": "server-1"}),
         )
-        .with_metadata(ActionMetadata { labels })
+        .with_metadata(ActionMetadata {
+            labels,
+            ..Default::default()
+        })
     }
 
     #[test]
@@ -637,6 +654,7 @@ mod tests {
                 300,
                 None,
                 100,
+                None,
                 &state,
                 None,
             )
@@ -664,6 +682,7 @@ mod tests {
                 300,
                 None,
                 100,
+                None,
                 &state,
                 None,
             )
@@ -678,6 +697,7 @@ mod tests {
                 300,
                 None,
                 100,
+                None,
                 &state,
                 None,
             )
@@ -748,6 +768,7 @@ mod tests {
                 300,        // group_interval
                 Some(3600), // repeat_interval → persistent
                 100,
+                None,
                 &state,
                 None,
             )
@@ -785,6 +806,7 @@ mod tests {
                 300,
                 Some(1_800), // 30 minutes
                 100,
+                None,
                 &state,
                 None,
             )
@@ -819,6 +841,7 @@ mod tests {
                 120, // 2-minute group_interval
                 Some(3_600),
                 100,
+                None,
                 &state,
                 None,
             )
@@ -840,6 +863,7 @@ mod tests {
                 120,
                 Some(3_600),
                 100,
+                None,
                 &state,
                 None,
             )
@@ -890,6 +914,7 @@ mod tests {
                 120, // 2-minute group_interval
                 Some(3_600),
                 100,
+                None,
                 &state,
                 None,
             )
@@ -920,6 +945,7 @@ mod tests {
                 120,
                 Some(3_600),
                 100,
+                None,
                 &state,
                 None,
             )
@@ -949,6 +975,7 @@ mod tests {
                 300,
                 None, // no repeat_interval → ephemeral
                 100,
+                None,
                 &state,
                 None,
             )
@@ -985,6 +1012,7 @@ mod tests {
                     300,
                     Some(3_600),
                     3, // max_group_size
+                    None,
                     &state,
                     None,
                 )
@@ -1056,6 +1084,7 @@ mod tests {
                 300,
                 Some(3_600),
                 100,
+                None,
                 &state,
                 None,
             )
@@ -1093,6 +1122,7 @@ mod tests {
                 300,
                 Some(3_600),
                 100,
+                None,
                 &state,
                 None,
             )
@@ -1117,6 +1147,7 @@ mod tests {
                 300,
                 Some(3_600),
                 100,
+                None,
                 &state,
                 None,
             )
@@ -1143,6 +1174,7 @@ mod tests {
                 300,
                 Some(3_600),
                 100,
+                None,
                 &state,
                 None,
             )
@@ -1184,6 +1216,7 @@ mod tests {
                 120,
                 Some(1_800),
                 50,
+                None,
                 &state,
                 None,
             )
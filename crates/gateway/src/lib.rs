@@ -6,13 +6,23 @@ pub mod circuit_breaker;
 pub mod encrypting_dlq;
 pub mod enrichment;
 pub mod error;
+mod event_history;
 pub mod execution;
+mod feature_flags;
+pub mod follow_on;
 pub mod gateway;
 pub mod group_manager;
 pub mod metrics;
+mod namespace_defaults;
+mod preview;
+mod provider_defaults;
+mod provider_failover;
+pub mod provider_health;
 mod quota_enforcement;
 pub mod recurring_overlap;
+pub mod reroute;
 mod silence_enforcement;
+mod streaming_dlq;
 pub(crate) mod sync_state;
 pub mod task_chain_bridge;
 pub mod task_engine;
@@ -36,7 +46,10 @@ pub use execution::ExecutionFilter;
 pub use gateway::{ApprovalKey, ApprovalKeySet, ApprovalRecord, ApprovalStatus, Gateway};
 pub use group_manager::GroupManager;
 pub use metrics::{GatewayMetrics, MetricsSnapshot, ProviderMetrics, ProviderStatsSnapshot};
-pub use recurring_overlap::OverlapDecision;
+pub use provider_health::{ProviderHealth, poll_provider_health};
+pub use recurring_overlap::{
+    OccurrenceOutcome, OverlapDecision, dispatch_shared_recurring_occurrence,
+};
 pub use silence_enforcement::CachedSilence;
 pub use task_chain_bridge::{
     BridgeError as TaskChainBridgeError, link_task_to_chain, project_chain_status_to_task_state,
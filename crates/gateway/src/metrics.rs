@@ -92,6 +92,9 @@ pub struct GatewayMetrics {
     pub stale_tasks_reaped: AtomicU64,
     /// Stale-task reaper errors.
     pub stale_task_reaper_errors: AtomicU64,
+    /// Audit records lost after a batched write and its per-record
+    /// fallback both failed.
+    pub audit_records_dropped: AtomicU64,
     /// WASM plugin invocations.
     pub wasm_invocations: AtomicU64,
     /// WASM plugin invocation errors.
@@ -305,6 +308,12 @@ impl GatewayMetrics {
             .fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Add `count` to the dropped audit records counter.
+    pub fn add_audit_records_dropped(&self, count: u64) {
+        self.audit_records_dropped
+            .fetch_add(count, Ordering::Relaxed);
+    }
+
     /// Increment the WASM invocations counter.
     pub fn increment_wasm_invocations(&self) {
         self.wasm_invocations.fetch_add(1, Ordering::Relaxed);
@@ -407,6 +416,7 @@ impl GatewayMetrics {
             retention_errors: self.retention_errors.load(Ordering::Relaxed),
             stale_tasks_reaped: self.stale_tasks_reaped.load(Ordering::Relaxed),
             stale_task_reaper_errors: self.stale_task_reaper_errors.load(Ordering::Relaxed),
+            audit_records_dropped: self.audit_records_dropped.load(Ordering::Relaxed),
             wasm_invocations: self.wasm_invocations.load(Ordering::Relaxed),
             wasm_errors: self.wasm_errors.load(Ordering::Relaxed),
             signing_verified: self.signing_verified.load(Ordering::Relaxed),
@@ -493,6 +503,9 @@ pub struct MetricsSnapshot {
     pub stale_tasks_reaped: u64,
     /// Stale-task reaper errors.
     pub stale_task_reaper_errors: u64,
+    /// Audit records lost after a batched write and its per-record
+    /// fallback both failed.
+    pub audit_records_dropped: u64,
     /// WASM plugin invocations.
     pub wasm_invocations: u64,
     /// WASM plugin invocation errors.
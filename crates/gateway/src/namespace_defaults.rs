@@ -0,0 +1,30 @@
+//! Namespace-level default provider and action type.
+//!
+//! Single-provider namespaces can configure the provider (and action type)
+//! once instead of repeating it on every action. Defaults are applied at the
+//! very start of dispatch, before quotas, rules, and templates, so every
+//! later stage sees the resolved values.
+
+use acteon_core::{Action, NamespaceDefaults};
+
+use crate::error::GatewayError;
+use crate::gateway::Gateway;
+
+impl Gateway {
+    /// The defaults configured for `namespace`, if any.
+    pub fn namespace_defaults(&self, namespace: &str) -> Option<&NamespaceDefaults> {
+        self.namespace_defaults.get(namespace)
+    }
+
+    /// Fill in the namespace's default provider and action type, then make
+    /// sure the action has a provider to run on.
+    pub(crate) fn apply_namespace_defaults(&self, action: &mut Action) -> Result<(), GatewayError> {
+        if let Some(defaults) = self.namespace_defaults.get(action.namespace.as_str()) {
+            defaults.apply(action);
+        }
+        if action.provider.is_empty() {
+            return Err(GatewayError::MissingProvider(action.namespace.to_string()));
+        }
+        Ok(())
+    }
+}
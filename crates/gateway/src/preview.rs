@@ -0,0 +1,123 @@
+//! Dispatch preview: the provider request an action would produce.
+//!
+//! A preview runs the same pre-execution stages as a real dispatch
+//! (enrichment, template rendering, rule evaluation, guardrails), applies
+//! the verdict's payload changes and the chosen provider's payload defaults,
+//! and resolves attachments, then stops before anything is executed, locked,
+//! or recorded.
+
+use tracing::instrument;
+
+use acteon_core::{Action, AttachmentPreview, ChainStepConfig, DispatchPreview};
+use acteon_rules::RuleVerdict;
+
+use crate::audit_helpers::matched_rule_name;
+use crate::error::GatewayError;
+use crate::gateway::Gateway;
+
+impl Gateway {
+    /// Compute the provider request `action` would produce, without
+    /// executing it.
+    ///
+    /// As with [`dispatch_dry_run`](Self::dispatch_dry_run), quotas are not
+    /// consulted. Template and attachment errors surface exactly as they
+    /// would on dispatch.
+    #[instrument(
+        name = "gateway.preview_dispatch",
+        skip(self, action),
+        fields(action.id = %action.id, action.provider = %action.provider)
+    )]
+    pub async fn preview_dispatch(
+        &self,
+        mut action: Action,
+    ) -> Result<DispatchPreview, GatewayError> {
+        self.apply_namespace_defaults(&mut action)?;
+        let flags = self
+            .load_feature_flags(action.namespace.as_str(), action.tenant.as_str())
+            .await;
+        let verdict = self.prepare_and_evaluate(&mut action, &flags).await?;
+
+        let provider = match &verdict {
+            RuleVerdict::Reroute {
+                target_provider, ..
+            } => Some(
+                target_provider
+                    .clone()
+                    .unwrap_or_else(|| action.provider.to_string()),
+            ),
+            RuleVerdict::Deny(_)
+            | RuleVerdict::Suppress(_)
+            | RuleVerdict::Group { .. }
+            | RuleVerdict::Chain { .. } => None,
+            RuleVerdict::Allow(_)
+            | RuleVerdict::Deduplicate { .. }
+            | RuleVerdict::Throttle { .. }
+            | RuleVerdict::Modify { .. }
+            | RuleVerdict::StateMachine { .. }
+            | RuleVerdict::RequestApproval { .. }
+            | RuleVerdict::Schedule { .. } => Some(action.provider.to_string()),
+        };
+
+        let providers = match &verdict {
+            RuleVerdict::Chain { chain, .. } => self
+                .chain_config(chain)
+                .map(|config| chain_providers(&config.steps))
+                .unwrap_or_default(),
+            _ => provider.iter().cloned().collect(),
+        };
+
+        if let RuleVerdict::Modify { changes, .. } = &verdict {
+            json_patch::merge(&mut action.payload, changes);
+        }
+        if let Some(ref provider) = provider {
+            let namespace = match &verdict {
+                RuleVerdict::Reroute {
+                    target_namespace: Some(namespace),
+                    ..
+                } => namespace.clone(),
+                _ => action.namespace.to_string(),
+            };
+            self.apply_provider_defaults(&mut action.payload, &namespace, provider);
+        }
+
+        let attachments = self
+            .resolve_attachments(&action)?
+            .into_iter()
+            .map(|a| AttachmentPreview {
+                size_bytes: a.data.len() as u64,
+                id: a.id,
+                name: a.name,
+                filename: a.filename,
+                content_type: a.content_type,
+            })
+            .collect();
+
+        Ok(DispatchPreview {
+            verdict: verdict.as_tag().to_owned(),
+            matched_rule: matched_rule_name(&verdict),
+            provider,
+            providers,
+            action_type: action.action_type,
+            payload: action.payload,
+            attachments,
+        })
+    }
+}
+
+/// Distinct providers a chain's steps dispatch to, in step order, including
+/// the sub-steps of parallel groups.
+fn chain_providers(steps: &[ChainStepConfig]) -> Vec<String> {
+    let mut providers: Vec<String> = Vec::new();
+    for step in steps {
+        let nested = step
+            .parallel
+            .as_ref()
+            .map(|group| chain_providers(&group.steps));
+        for provider in std::iter::once(step.provider.clone()).chain(nested.into_iter().flatten()) {
+            if !provider.is_empty() && !providers.contains(&provider) {
+                providers.push(provider);
+            }
+        }
+    }
+    providers
+}
@@ -0,0 +1,123 @@
+//! Provider-scoped payload defaults.
+//!
+//! Operators can register a default payload fragment per `(namespace,
+//! provider)`, so boilerplate such as a Slack footer lives in one place. The
+//! defaults are merged just before an action is handed to a provider, keyed
+//! by the provider that actually runs it: a reroute, circuit-breaker
+//! fallback, or failover picks up the new provider's defaults, not the
+//! original's. Fields the action already sets win.
+
+use std::borrow::Cow;
+
+use acteon_core::Action;
+
+use crate::gateway::Gateway;
+
+/// Deep-merge `defaults` into `payload`, keeping every value `payload`
+/// already has.
+///
+/// Objects merge key by key, recursively. Any other value in `payload`,
+/// including `null` and arrays, overrides the default as a whole. A
+/// non-object `payload` is left untouched.
+pub(crate) fn merge_payload_defaults(
+    payload: &mut serde_json::Value,
+    defaults: &serde_json::Value,
+) {
+    let (Some(target), Some(defaults)) = (payload.as_object_mut(), defaults.as_object()) else {
+        return;
+    };
+    for (key, default) in defaults {
+        match target.get_mut(key) {
+            Some(existing) if existing.is_object() && default.is_object() => {
+                merge_payload_defaults(existing, default);
+            }
+            Some(_) => {}
+            None => {
+                target.insert(key.clone(), default.clone());
+            }
+        }
+    }
+}
+
+impl Gateway {
+    /// Merge the payload defaults registered for `namespace` and `provider`,
+    /// if any, into `payload`.
+    pub(crate) fn apply_provider_defaults(
+        &self,
+        payload: &mut serde_json::Value,
+        namespace: &str,
+        provider: &str,
+    ) {
+        if let Some(defaults) = self
+            .provider_payload_defaults
+            .get(&(namespace.to_owned(), provider.to_owned()))
+        {
+            merge_payload_defaults(payload, defaults);
+        }
+    }
+
+    /// `action` as it should be sent to `provider`: borrowed unchanged when
+    /// no defaults are registered, otherwise a copy with them merged in.
+    pub(crate) fn with_provider_defaults<'a>(
+        &self,
+        action: &'a Action,
+        provider: &str,
+    ) -> Cow<'a, Action> {
+        let Some(defaults) = self
+            .provider_payload_defaults
+            .get(&(action.namespace.to_string(), provider.to_owned()))
+        else {
+            return Cow::Borrowed(action);
+        };
+        let mut action = action.clone();
+        merge_payload_defaults(&mut action.payload, defaults);
+        Cow::Owned(action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn missing_fields_are_filled() {
+        let mut payload = json!({"text": "hi"});
+        merge_payload_defaults(&mut payload, &json!({"footer": "sent by acteon"}));
+        assert_eq!(payload, json!({"text": "hi", "footer": "sent by acteon"}));
+    }
+
+    #[test]
+    fn action_fields_override_defaults() {
+        let mut payload = json!({"footer": "custom", "tags": ["a"], "icon": null});
+        merge_payload_defaults(
+            &mut payload,
+            &json!({"footer": "default", "tags": ["b", "c"], "icon": ":bell:"}),
+        );
+        assert_eq!(
+            payload,
+            json!({"footer": "custom", "tags": ["a"], "icon": null})
+        );
+    }
+
+    #[test]
+    fn nested_objects_merge_recursively() {
+        let mut payload = json!({"meta": {"color": "red"}});
+        merge_payload_defaults(
+            &mut payload,
+            &json!({"meta": {"color": "grey", "source": "acteon"}}),
+        );
+        assert_eq!(
+            payload,
+            json!({"meta": {"color": "red", "source": "acteon"}})
+        );
+    }
+
+    #[test]
+    fn non_object_payload_is_untouched() {
+        let mut payload = json!("raw");
+        merge_payload_defaults(&mut payload, &json!({"footer": "x"}));
+        assert_eq!(payload, json!("raw"));
+    }
+}
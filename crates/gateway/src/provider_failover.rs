@@ -0,0 +1,120 @@
+//! Provider failover routing.
+//!
+//! A failover route lists the providers to try, in order, when an action's
+//! provider fails to execute it (e.g. SMS vendor B when vendor A errors).
+//! The whole route is a single logical delivery: the first provider to
+//! succeed ends it, and only when every provider has failed is the action
+//! reported as failed. Unlike a chain, no step state is persisted and later
+//! providers never see earlier providers' responses.
+
+use acteon_core::{Action, ActionError, ActionOutcome, FailoverAttempt, FeatureFlags, ReasonCode};
+use tracing::warn;
+
+use crate::circuit_breaker::CircuitState;
+use crate::gateway::Gateway;
+
+/// Whether a failed attempt should move on to the next provider.
+///
+/// Only failures that say something about the provider (retryable errors,
+/// timeouts, unreachable or rate-limiting providers, a spent retry budget)
+/// fail over. An action the provider rejected, such as a malformed payload,
+/// would be rejected by the next provider too.
+fn warrants_failover(error: &ActionError) -> bool {
+    error.retryable
+        || matches!(
+            error.reason_code,
+            ReasonCode::ProviderTimeout
+                | ReasonCode::ProviderUnavailable
+                | ReasonCode::ProviderRateLimited
+                | ReasonCode::RetryBudgetExhausted
+        )
+}
+
+impl Gateway {
+    /// Try the failover route registered for the action's provider after
+    /// that provider failed with `primary_error`.
+    ///
+    /// Fallbacks whose circuit is open are skipped without being called,
+    /// and the route stops at the first failure that does not
+    /// [warrant failover](warrants_failover). Returns a `Rerouted` outcome
+    /// for the first fallback that succeeds, listing the attempts that
+    /// failed before it. Otherwise the returned error keeps the last
+    /// provider's code and retryability, sums the attempts, and lists every
+    /// attempt in its message. Without a route, `primary_error` is returned
+    /// unchanged.
+    pub(crate) async fn fail_over(
+        &self,
+        action: &Action,
+        primary_error: ActionError,
+        flags: &FeatureFlags,
+    ) -> ActionOutcome {
+        let Some(fallbacks) = self.provider_failover.get(action.provider.as_str()) else {
+            return ActionOutcome::Failed(primary_error);
+        };
+        if !warrants_failover(&primary_error) {
+            return ActionOutcome::Failed(primary_error);
+        }
+
+        let mut attempt_log = vec![format!("{}: {}", action.provider, primary_error.message)];
+        let mut attempts = primary_error.attempts;
+        let mut failed_attempts = vec![FailoverAttempt {
+            provider: action.provider.to_string(),
+            error: primary_error.clone(),
+        }];
+        let mut last_error = primary_error;
+
+        for fallback in fallbacks {
+            if let Some(ref registry) = self.circuit_breakers
+                && let Some(cb) = registry.get(fallback)
+            {
+                let (state, transition) = cb.try_acquire_permit().await;
+                if transition.is_some() {
+                    self.metrics.increment_circuit_transitions();
+                }
+                if state == CircuitState::Open {
+                    attempt_log.push(format!("{fallback}: circuit open"));
+                    continue;
+                }
+            }
+
+            warn!(
+                provider = %action.provider,
+                fallback = %fallback,
+                error = %last_error.message,
+                "provider failed, failing over"
+            );
+            match self.attempt_provider(action, fallback, flags).await {
+                ActionOutcome::Executed(response) => {
+                    return ActionOutcome::Rerouted {
+                        original_provider: action.provider.to_string(),
+                        new_provider: fallback.clone(),
+                        response,
+                        failed_attempts,
+                    };
+                }
+                ActionOutcome::Failed(err) => {
+                    attempt_log.push(format!("{fallback}: {}", err.message));
+                    attempts += err.attempts;
+                    failed_attempts.push(FailoverAttempt {
+                        provider: fallback.clone(),
+                        error: err.clone(),
+                    });
+                    let stop = !warrants_failover(&err);
+                    last_error = err;
+                    if stop {
+                        break;
+                    }
+                }
+                other => return other,
+            }
+        }
+
+        ActionOutcome::Failed(ActionError {
+            code: last_error.code,
+            reason_code: last_error.reason_code,
+            message: format!("provider failover failed: {}", attempt_log.join("; ")),
+            retryable: last_error.retryable,
+            attempts,
+        })
+    }
+}
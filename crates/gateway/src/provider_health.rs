@@ -0,0 +1,152 @@
+//! Periodic provider health checks.
+//!
+//! The background processor probes every provider on a fixed interval and
+//! feeds the results to a per-provider [`HealthClassifier`], so a provider's
+//! health changes only after the configured number of consecutive failed or
+//! passed checks. The health endpoints read the classified state; they never
+//! run checks themselves, so polling an endpoint cannot advance the
+//! hysteresis.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use acteon_core::HealthClassifier;
+use acteon_provider::DynProvider;
+use acteon_provider::health::HealthStatus;
+use tokio::sync::RwLock;
+
+use crate::gateway::Gateway;
+
+/// How long one provider health check may take before it counts as failed.
+pub const PROVIDER_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The classified health of a provider and the outcome of its latest check.
+#[derive(Debug, Clone)]
+pub struct ProviderHealth {
+    /// Hysteresis-smoothed health.
+    pub classifier: HealthClassifier,
+    /// Error of the latest check, or `None` if it passed.
+    pub last_error: Option<String>,
+}
+
+/// Check every provider of `gateway` once and feed the results to their
+/// classifiers.
+///
+/// The checks run concurrently and without the gateway guard held, since
+/// each one can be a slow network call.
+pub async fn poll_provider_health(gateway: &RwLock<Gateway>) {
+    let providers = gateway.read().await.provider_handles();
+    let results = probe_providers(&providers).await;
+    gateway.read().await.observe_provider_health(&results);
+}
+
+async fn probe_providers(providers: &[Arc<dyn DynProvider>]) -> Vec<HealthStatus> {
+    futures::future::join_all(providers.iter().map(|provider| async move {
+        let error = match tokio::time::timeout(
+            PROVIDER_HEALTH_CHECK_TIMEOUT,
+            provider.health_check(),
+        )
+        .await
+        {
+            Ok(result) => result.err().map(|e| e.to_string()),
+            Err(_) => Some(format!(
+                "health check timed out after {}s",
+                PROVIDER_HEALTH_CHECK_TIMEOUT.as_secs()
+            )),
+        };
+        HealthStatus {
+            provider: provider.name().to_owned(),
+            healthy: error.is_none(),
+            error,
+        }
+    }))
+    .await
+}
+
+impl Gateway {
+    /// Handles to every registered provider, so callers can probe them
+    /// without holding on to the gateway.
+    pub fn provider_handles(&self) -> Vec<Arc<dyn DynProvider>> {
+        self.providers
+            .list()
+            .into_iter()
+            .filter_map(|name| self.providers.get(name))
+            .collect()
+    }
+
+    /// Feed health check results to each provider's [`HealthClassifier`].
+    pub fn observe_provider_health(&self, results: &[HealthStatus]) {
+        let mut health = self.provider_health.lock();
+        for result in results {
+            let entry = health
+                .entry(result.provider.clone())
+                .or_insert_with(|| ProviderHealth {
+                    classifier: HealthClassifier::new(self.health_hysteresis),
+                    last_error: None,
+                });
+            entry.classifier.observe(result.healthy);
+            entry.last_error.clone_from(&result.error);
+        }
+    }
+
+    /// The classified health of `provider`, or `None` if it has not been
+    /// checked yet. Reading it never runs a check.
+    pub fn provider_health(&self, provider: &str) -> Option<ProviderHealth> {
+        self.provider_health.lock().get(provider).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acteon_core::{Action, HealthHysteresis, ProviderResponse};
+    use acteon_provider::ProviderError;
+    use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+
+    use super::*;
+    use crate::builder::GatewayBuilder;
+
+    struct DownProvider;
+
+    impl acteon_provider::Provider for DownProvider {
+        fn name(&self) -> &str {
+            "down"
+        }
+
+        async fn execute(&self, _action: &Action) -> Result<ProviderResponse, ProviderError> {
+            Ok(ProviderResponse::success(serde_json::Value::Null))
+        }
+
+        async fn health_check(&self) -> Result<(), ProviderError> {
+            Err(ProviderError::Connection("refused".into()))
+        }
+    }
+
+    #[tokio::test]
+    async fn only_polling_advances_the_hysteresis() {
+        let gateway = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::new(DownProvider))
+            .health_hysteresis(HealthHysteresis {
+                unhealthy_after: 2,
+                healthy_after: 1,
+            })
+            .build()
+            .unwrap();
+        let gateway = RwLock::new(gateway);
+        assert!(gateway.read().await.provider_health("down").is_none());
+
+        poll_provider_health(&gateway).await;
+        // Reading the health any number of times does not run a check.
+        for _ in 0..3 {
+            let health = gateway.read().await.provider_health("down").unwrap();
+            assert!(health.classifier.is_healthy());
+            assert_eq!(health.classifier.consecutive_failures(), 1);
+            assert!(health.last_error.unwrap().contains("refused"));
+        }
+
+        poll_provider_health(&gateway).await;
+        let health = gateway.read().await.provider_health("down").unwrap();
+        assert!(!health.classifier.is_healthy());
+    }
+}
@@ -6,7 +6,7 @@
 use chrono::Utc;
 use tracing::{info, instrument, warn};
 
-use acteon_core::{Action, ActionOutcome};
+use acteon_core::{Action, ActionOutcome, StreamEvent, StreamEventType};
 
 use crate::error::GatewayError;
 use crate::gateway::{CachedPolicy, Gateway};
@@ -26,7 +26,8 @@ impl Gateway {
     ///
     /// Since Phase 3, a `(namespace, tenant)` pair may hold several
     /// quota policies — one generic catch-all plus any number of
-    /// provider-scoped caps. All policies whose scope matches the
+    /// provider-scoped caps, and any org-level policies whose counter
+    /// is shared with the other tenants of the same org. All policies whose scope matches the
     /// outgoing provider are evaluated; each maintains its own
     /// counter so a burst on one provider does not consume another
     /// provider's budget. If **any** applicable policy blocks the
@@ -73,8 +74,6 @@ impl Gateway {
         principal: Option<&str>,
         only_provider_scoped: bool,
     ) -> Result<Option<ActionOutcome>, GatewayError> {
-        const CACHE_TTL_SECS: i64 = 60;
-
         // Skip quota for internal re-dispatches (scheduled, recurring, groups)
         // to avoid double-counting. The action was already counted when it
         // first entered the gateway.
@@ -97,56 +96,24 @@ impl Gateway {
             return Ok(None);
         }
 
-        let bucket_key = format!("{}:{}", action.namespace, action.tenant);
         let now = Utc::now();
 
-        // 1. Check in-memory cache with a 60-second TTL to ensure we eventually
-        //    see updates made on other instances.
-        let cached = {
-            let map = self.quota_policies.read();
-            map.get(&bucket_key).cloned()
-        };
-
-        let bucket_policies: Vec<acteon_core::QuotaPolicy> = if let Some(c) = cached
-            && (now - c.cached_at).num_seconds() < CACHE_TTL_SECS
-        {
-            c.policies
-        } else {
-            // Cold path: fetch from state store. We fail-open if the store
-            // is down to protect system availability.
-            let found = match self
-                .load_quota_from_state_store(&action.namespace, &action.tenant)
+        // A dispatch is subject to its tenant's own policies plus any
+        // org-level policies that list the tenant as a member. Each
+        // bucket fails open on its own if the state store is down.
+        let mut bucket_policies = Vec::new();
+        for tenant in [
+            action.tenant.as_str(),
+            acteon_core::ORG_QUOTA_COUNTER_TENANT,
+        ] {
+            match self
+                .quota_bucket_policies(&action.namespace, tenant, &now)
                 .await
             {
-                Ok(f) => f,
-                Err(e) => {
-                    warn!(error = %e, "quota policy lookup failed (fail-open)");
-                    return Ok(None);
-                }
-            };
-
-            if found.is_empty() {
-                // Empty cache entry — still mark as warmed so we don't
-                // hammer the state store for every dispatch.
-                self.quota_policies.write().insert(
-                    bucket_key.clone(),
-                    CachedPolicy {
-                        policies: Vec::new(),
-                        cached_at: now,
-                    },
-                );
-                return Ok(None);
+                Ok(found) => bucket_policies.extend(found),
+                Err(e) => warn!(error = %e, "quota policy lookup failed (fail-open)"),
             }
-
-            let cached = CachedPolicy {
-                policies: found.clone(),
-                cached_at: now,
-            };
-            self.quota_policies
-                .write()
-                .insert(bucket_key.clone(), cached);
-            found
-        };
+        }
 
         // Filter to policies that actually apply to this dispatch.
         // In fallback mode (called after a degrade swap), the
@@ -157,6 +124,7 @@ impl Gateway {
             .into_iter()
             .filter(|p| {
                 p.enabled
+                    && p.applies_to_tenant(&action.tenant)
                     && p.applies_to_provider(&action.provider)
                     && p.applies_to_principal(principal)
                     && (!only_provider_scoped || p.provider.is_some())
@@ -171,6 +139,42 @@ impl Gateway {
             .await
     }
 
+    /// Policies in the `(namespace, tenant)` bucket, served from the
+    /// in-memory cache while it is fresh and reloaded from the state
+    /// store otherwise. Empty buckets are cached too, so tenants
+    /// without quotas don't hit the state store on every dispatch.
+    async fn quota_bucket_policies(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        now: &chrono::DateTime<Utc>,
+    ) -> Result<Vec<acteon_core::QuotaPolicy>, GatewayError> {
+        // Cache entries expire so we eventually see updates made on
+        // other instances.
+        const CACHE_TTL_SECS: i64 = 60;
+
+        let bucket_key = format!("{namespace}:{tenant}");
+        let cached = {
+            let map = self.quota_policies.read();
+            map.get(&bucket_key).cloned()
+        };
+        if let Some(c) = cached
+            && (*now - c.cached_at).num_seconds() < CACHE_TTL_SECS
+        {
+            return Ok(c.policies);
+        }
+
+        let found = self.load_quota_from_state_store(namespace, tenant).await?;
+        self.quota_policies.write().insert(
+            bucket_key,
+            CachedPolicy {
+                policies: found.clone(),
+                cached_at: *now,
+            },
+        );
+        Ok(found)
+    }
+
     /// Evaluate every applicable quota policy for a dispatch and
     /// return the strictest outcome.
     ///
@@ -198,11 +202,7 @@ impl Gateway {
         };
 
         let winner_idx = Self::pick_winning_quota(&incremented);
-        let Some((idx, is_block)) = winner_idx else {
-            return Ok(None);
-        };
-
-        if is_block {
+        if let Some((idx, true)) = winner_idx {
             // Roll back every counter this call incremented, in
             // parallel, so the blocked action does not consume any
             // tenant budget. Rollback is best-effort:
@@ -225,10 +225,76 @@ impl Gateway {
                     warn!(error = %e, "quota rollback decrement failed (ghost consumption possible)");
                 }
             }
+            let inc = &incremented[idx];
+            return Ok(self.apply_overage_behavior(action, &inc.policy, inc.used));
         }
 
-        let inc = &incremented[idx];
-        Ok(self.apply_overage_behavior(action, &inc.policy, inc.used))
+        // The dispatch consumed budget, so report any alert
+        // thresholds it pushed usage across.
+        self.alert_quota_thresholds(action, &incremented).await;
+
+        Ok(winner_idx.and_then(|(idx, _)| {
+            let inc = &incremented[idx];
+            self.apply_overage_behavior(action, &inc.policy, inc.used)
+        }))
+    }
+
+    /// Emit a [`StreamEventType::QuotaThresholdCrossed`] event for every
+    /// alert threshold the current dispatch crossed.
+    ///
+    /// Crossings are debounced with a per-window marker key so that a
+    /// counter revisiting a value (e.g. after a block rollback on a
+    /// sibling policy) never yields a second event for the same
+    /// threshold. Marker failures are logged and the alert skipped —
+    /// alerting never affects the dispatch itself.
+    async fn alert_quota_thresholds(&self, action: &Action, incremented: &[Incremented]) {
+        for inc in incremented {
+            for threshold in inc.policy.crossed_thresholds(inc.used) {
+                let marker = acteon_state::StateKey::new(
+                    inc.counter_key.namespace.clone(),
+                    inc.counter_key.tenant.clone(),
+                    acteon_state::KeyKind::QuotaUsage,
+                    format!("{}:alert:{threshold}", inc.counter_key.id),
+                );
+                match self.state.check_and_set(&marker, "1", inc.window_ttl).await {
+                    Ok(true) => {}
+                    Ok(false) => continue,
+                    Err(e) => {
+                        warn!(
+                            error = %e,
+                            policy_id = %inc.policy.id,
+                            threshold,
+                            "quota alert marker write failed — skipping alert"
+                        );
+                        continue;
+                    }
+                }
+                info!(
+                    tenant = %action.tenant,
+                    policy_id = %inc.policy.id,
+                    threshold,
+                    used = inc.used,
+                    limit = inc.policy.max_actions,
+                    "quota alert threshold crossed"
+                );
+                self.emit_stream_event(StreamEvent {
+                    id: uuid::Uuid::now_v7().to_string(),
+                    timestamp: Utc::now(),
+                    event_type: StreamEventType::QuotaThresholdCrossed {
+                        policy_id: inc.policy.id.clone(),
+                        threshold_percent: threshold,
+                        used: inc.used,
+                        limit: inc.policy.max_actions,
+                        window: inc.policy.window.label(),
+                    },
+                    namespace: action.namespace.to_string(),
+                    tenant: action.tenant.to_string(),
+                    action_type: Some(action.action_type.clone()),
+                    action_id: Some(action.id.to_string()),
+                    traceparent: action.traceparent().map(str::to_owned),
+                });
+            }
+        }
     }
 
     /// Increment the counter for every applicable policy, returning
@@ -275,6 +341,7 @@ impl Gateway {
             let Some(counter_id) = acteon_core::quota_counter_key(
                 &action.namespace,
                 &action.tenant,
+                policy.org.as_deref(),
                 key_principal,
                 policy.provider.as_deref(),
                 &policy.window,
@@ -288,9 +355,11 @@ impl Gateway {
                 );
                 continue;
             };
+            // Org-level counters are shared across tenants, so they live
+            // outside any single tenant's key space.
             let counter_key = acteon_state::StateKey::new(
                 action.namespace.as_str(),
-                action.tenant.as_str(),
+                acteon_core::quota_counter_tenant(&action.tenant, policy.org.as_deref()),
                 acteon_state::KeyKind::QuotaUsage,
                 &counter_id,
             );
@@ -1,19 +1,47 @@
-//! Gateway-level enforcement of recurring-action overlap policies.
+//! Gateway-level enforcement of recurring-action overlap and concurrency
+//! policies.
 //!
-//! Overlap only matters when an occurrence spawns a chain execution (plain
-//! provider dispatches complete synchronously and never overlap), and the
-//! chain engine lives here — so the enforcement decision does too. Any
-//! consumer dispatching recurring occurrences (the bundled server's
-//! consumer, or an embedder driving the gateway directly) calls
-//! [`Gateway::enforce_overlap_policy`] before dispatch and honors the
-//! returned [`OverlapDecision`].
+//! An occurrence overlaps its predecessor in two ways: the previous dispatch
+//! may still be in flight (a slow provider, governed by the concurrency
+//! policy), or the chain execution it spawned may still be running
+//! (governed by the overlap policy). The former is guarded by a distributed
+//! lock keyed by the recurring ID, the latter by the chain engine — both
+//! live here, so the enforcement decision does too. Any consumer
+//! dispatching recurring occurrences (the bundled server's consumer, or an
+//! embedder driving the gateway directly) goes through
+//! [`Gateway::dispatch_recurring_occurrence`] (or
+//! [`dispatch_shared_recurring_occurrence`] for a gateway behind a
+//! `RwLock`), or calls [`Gateway::enforce_overlap_policy`] before its own
+//! dispatch and honors the returned [`OverlapDecision`].
 
-use tracing::{debug, info};
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Duration;
 
-use acteon_core::{OverlapPolicy, RecurringAction};
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
 
+use acteon_core::{
+    Action, ActionOutcome, Caller, ConcurrencyPolicy, OverlapPolicy, RecurringAction,
+};
+use acteon_state::StateError;
+
+use crate::error::GatewayError;
 use crate::gateway::Gateway;
 
+/// TTL of the per-recurring occurrence lock. Bounds how long a crashed
+/// dispatcher can block later occurrences.
+const OCCURRENCE_LOCK_TTL: Duration = Duration::from_secs(300);
+
+/// How long a `queue` occurrence waits for its predecessor before it is
+/// skipped. Kept well below typical request timeouts: a dispatch queued
+/// behind a slow predecessor should give up rather than pile up.
+const QUEUE_WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Poll interval while a `queue` occurrence waits for a running chain.
+const QUEUE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Decision for one recurring occurrence under its overlap policy.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OverlapDecision {
@@ -29,6 +57,21 @@ pub enum OverlapDecision {
     },
 }
 
+/// Result of [`Gateway::dispatch_recurring_occurrence`].
+#[derive(Debug)]
+pub enum OccurrenceOutcome {
+    /// The occurrence was dispatched.
+    Dispatched(ActionOutcome),
+    /// The previous occurrence was still running and the overlap or
+    /// concurrency policy dropped this one (counted in the `recurring_skipped` metric). The
+    /// caller keeps the schedule alive but must not count it as executed.
+    Skipped {
+        /// The still-active previous chain execution, or `None` when the
+        /// previous occurrence's dispatch itself was still in flight.
+        previous_execution_id: Option<String>,
+    },
+}
+
 impl Gateway {
     /// Enforce a recurring action's overlap policy ahead of dispatching an
     /// occurrence.
@@ -39,6 +82,8 @@ impl Gateway {
     ///   (counted in the `recurring_skipped` metric).
     /// - `cancel_other`: cancel the still-active previous execution
     ///   (best-effort), then proceed.
+    /// - `queue`: wait up to a bounded timeout for the previous execution
+    ///   to settle, then proceed; skip if it is still active afterwards.
     ///
     /// Enforcement is best-effort by design: a failed status read proceeds
     /// (an overlapping dispatch beats silently stalling the schedule), and
@@ -51,58 +96,258 @@ impl Gateway {
         recurring_id: &str,
         recurring: &RecurringAction,
     ) -> OverlapDecision {
-        if recurring.overlap_policy == OverlapPolicy::AllowAll {
-            return OverlapDecision::Proceed;
-        }
-        let Some(prev_id) = recurring.last_execution_id.as_deref() else {
-            return OverlapDecision::Proceed;
-        };
-        let still_active = matches!(
-            self.get_chain_status(namespace, tenant, prev_id).await,
+        enforce_overlap(
+            || std::future::ready(self),
+            namespace,
+            tenant,
+            recurring_id,
+            recurring,
+        )
+        .await
+    }
+
+    /// Dispatch one recurring occurrence under its overlap and concurrency
+    /// policies.
+    ///
+    /// Applies [`enforce_overlap_policy`](Self::enforce_overlap_policy) for
+    /// chain executions, then, under the concurrency policy, holds a
+    /// distributed lock keyed by the recurring ID for the duration of the
+    /// dispatch, so an occurrence that fires while the previous dispatch is
+    /// still in flight (on this or any other instance) is skipped (`skip`,
+    /// the default) or waits for it (`queue`). `allow` dispatches without
+    /// the lock.
+    ///
+    /// Lock failures are best-effort like the rest of enforcement: a lock
+    /// backend error proceeds without the guard rather than stalling the
+    /// schedule.
+    ///
+    /// A `queue` occurrence can wait on its predecessor for up to
+    /// [`QUEUE_WAIT_TIMEOUT`] per phase, so consumers should run each
+    /// occurrence in its own task rather than awaiting them one by one.
+    /// Consumers sharing the gateway behind a `RwLock` should use
+    /// [`dispatch_shared_recurring_occurrence`] instead, which does not hold
+    /// the read guard while waiting.
+    pub async fn dispatch_recurring_occurrence(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        recurring_id: &str,
+        recurring: &RecurringAction,
+        action: Action,
+        caller: Option<&Caller>,
+    ) -> Result<OccurrenceOutcome, GatewayError> {
+        dispatch_occurrence(
+            || std::future::ready(self),
+            namespace,
+            tenant,
+            recurring_id,
+            recurring,
+            action,
+            caller,
+        )
+        .await
+    }
+
+    async fn chain_still_active(&self, namespace: &str, tenant: &str, chain_id: &str) -> bool {
+        matches!(
+            self.get_chain_status(namespace, tenant, chain_id).await,
             Ok(Some(prev)) if prev.status.is_active()
-        );
-        if !still_active {
-            return OverlapDecision::Proceed;
+        )
+    }
+}
+
+/// [`Gateway::dispatch_recurring_occurrence`] for a gateway shared behind a
+/// `RwLock`.
+///
+/// The read guard is taken only for each status check and for the dispatch
+/// itself, never across a `queue` wait, so a rule reload waiting on the
+/// write lock is not held up by a slow predecessor.
+pub async fn dispatch_shared_recurring_occurrence(
+    gateway: &RwLock<Gateway>,
+    namespace: &str,
+    tenant: &str,
+    recurring_id: &str,
+    recurring: &RecurringAction,
+    action: Action,
+    caller: Option<&Caller>,
+) -> Result<OccurrenceOutcome, GatewayError> {
+    dispatch_occurrence(
+        || gateway.read(),
+        namespace,
+        tenant,
+        recurring_id,
+        recurring,
+        action,
+        caller,
+    )
+    .await
+}
+
+/// Shared implementation of the overlap check. `gateway` yields access to
+/// the gateway; it is called once per step, and the access is dropped
+/// before any wait.
+async fn enforce_overlap<F, Fut, G>(
+    gateway: F,
+    namespace: &str,
+    tenant: &str,
+    recurring_id: &str,
+    recurring: &RecurringAction,
+) -> OverlapDecision
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = G>,
+    G: Deref<Target = Gateway>,
+{
+    if recurring.overlap_policy == OverlapPolicy::AllowAll {
+        return OverlapDecision::Proceed;
+    }
+    let Some(prev_id) = recurring.last_execution_id.as_deref() else {
+        return OverlapDecision::Proceed;
+    };
+    if !gateway()
+        .await
+        .chain_still_active(namespace, tenant, prev_id)
+        .await
+    {
+        return OverlapDecision::Proceed;
+    }
+    match recurring.overlap_policy {
+        OverlapPolicy::Queue => {
+            let deadline = tokio::time::Instant::now() + QUEUE_WAIT_TIMEOUT;
+            while tokio::time::Instant::now() < deadline {
+                tokio::time::sleep(QUEUE_POLL_INTERVAL).await;
+                if !gateway()
+                    .await
+                    .chain_still_active(namespace, tenant, prev_id)
+                    .await
+                {
+                    return OverlapDecision::Proceed;
+                }
+            }
+            info!(
+                recurring_id,
+                previous_execution = prev_id,
+                "previous execution still running after queue wait; occurrence skipped (overlap policy)"
+            );
+            gateway().await.metrics().increment_recurring_skipped();
+            OverlapDecision::Skip {
+                previous_execution_id: prev_id.to_owned(),
+            }
         }
-        match recurring.overlap_policy {
-            OverlapPolicy::Skip => {
-                info!(
-                    recurring_id,
+        OverlapPolicy::Skip => {
+            info!(
+                recurring_id,
+                previous_execution = prev_id,
+                "previous execution still running; occurrence skipped (overlap policy)"
+            );
+            gateway().await.metrics().increment_recurring_skipped();
+            OverlapDecision::Skip {
+                previous_execution_id: prev_id.to_owned(),
+            }
+        }
+        OverlapPolicy::CancelOther => {
+            info!(
+                recurring_id,
+                previous_execution = prev_id,
+                "cancelling still-running previous execution (overlap policy)"
+            );
+            if let Err(e) = gateway()
+                .await
+                .cancel_chain(
+                    namespace,
+                    tenant,
+                    prev_id,
+                    Some("superseded by next recurring occurrence".to_owned()),
+                    Some(format!("recurring:{recurring_id}")),
+                )
+                .await
+            {
+                // The execution may have settled between the status
+                // read and the cancel — proceed.
+                debug!(
                     previous_execution = prev_id,
-                    "previous execution still running; occurrence skipped (overlap policy)"
+                    error = %e,
+                    "overlap cancel failed (execution may have settled)"
                 );
-                self.metrics().increment_recurring_skipped();
-                OverlapDecision::Skip {
-                    previous_execution_id: prev_id.to_owned(),
-                }
             }
-            OverlapPolicy::CancelOther => {
+            OverlapDecision::Proceed
+        }
+        OverlapPolicy::AllowAll => unreachable!(),
+    }
+}
+
+/// Shared implementation of the occurrence dispatch. See
+/// [`enforce_overlap`] for how `gateway` is used.
+async fn dispatch_occurrence<F, Fut, G>(
+    gateway: F,
+    namespace: &str,
+    tenant: &str,
+    recurring_id: &str,
+    recurring: &RecurringAction,
+    action: Action,
+    caller: Option<&Caller>,
+) -> Result<OccurrenceOutcome, GatewayError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = G>,
+    G: Deref<Target = Gateway>,
+{
+    if let OverlapDecision::Skip {
+        previous_execution_id,
+    } = enforce_overlap(&gateway, namespace, tenant, recurring_id, recurring).await
+    {
+        return Ok(OccurrenceOutcome::Skipped {
+            previous_execution_id: Some(previous_execution_id),
+        });
+    }
+
+    let guard = if recurring.concurrency_policy == ConcurrencyPolicy::Allow {
+        None
+    } else {
+        // Clone the lock handle so a `queue` wait does not hold on to the
+        // gateway.
+        let lock = Arc::clone(&gateway().await.lock);
+        let lock_name = format!("recurring-occurrence:{namespace}:{tenant}:{recurring_id}");
+        let acquired = if recurring.concurrency_policy == ConcurrencyPolicy::Queue {
+            match lock
+                .acquire(&lock_name, OCCURRENCE_LOCK_TTL, QUEUE_WAIT_TIMEOUT)
+                .await
+            {
+                Ok(guard) => Ok(Some(guard)),
+                Err(StateError::Timeout(_)) => Ok(None),
+                Err(e) => Err(e),
+            }
+        } else {
+            lock.try_acquire(&lock_name, OCCURRENCE_LOCK_TTL).await
+        };
+        match acquired {
+            Ok(Some(guard)) => Some(guard),
+            Ok(None) => {
                 info!(
                     recurring_id,
-                    previous_execution = prev_id,
-                    "cancelling still-running previous execution (overlap policy)"
+                    "previous occurrence still dispatching; occurrence skipped (concurrency policy)"
                 );
-                if let Err(e) = self
-                    .cancel_chain(
-                        namespace,
-                        tenant,
-                        prev_id,
-                        Some("superseded by next recurring occurrence".to_owned()),
-                        Some(format!("recurring:{recurring_id}")),
-                    )
-                    .await
-                {
-                    // The execution may have settled between the status
-                    // read and the cancel — proceed.
-                    debug!(
-                        previous_execution = prev_id,
-                        error = %e,
-                        "overlap cancel failed (execution may have settled)"
-                    );
-                }
-                OverlapDecision::Proceed
+                gateway().await.metrics().increment_recurring_skipped();
+                return Ok(OccurrenceOutcome::Skipped {
+                    previous_execution_id: None,
+                });
+            }
+            Err(e) => {
+                warn!(
+                    recurring_id,
+                    error = %e,
+                    "occurrence lock unavailable; dispatching without overlap guard"
+                );
+                None
             }
-            OverlapPolicy::AllowAll => unreachable!(),
         }
+    };
+
+    let result = gateway().await.dispatch(action, caller).await;
+    if let Some(guard) = guard
+        && let Err(e) = guard.release().await
+    {
+        debug!(recurring_id, error = %e, "failed to release occurrence lock");
     }
+    result.map(OccurrenceOutcome::Dispatched)
 }
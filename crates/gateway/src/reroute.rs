@@ -0,0 +1,82 @@
+//! Cross-scope rerouting for the `reroute` rule action.
+//!
+//! Besides switching providers, a reroute can move an action to another
+//! namespace or tenant, e.g. to escalate a critical tenant alert to a
+//! central ops tenant. The action is rewritten before it is delivered, and
+//! the scope it came from is kept in its metadata labels so the audit
+//! record shows both.
+
+use acteon_core::Action;
+
+/// Metadata label carrying the namespace a rerouted action came from.
+pub const REROUTED_FROM_NAMESPACE_LABEL: &str = "rerouted_from_namespace";
+
+/// Metadata label carrying the tenant a rerouted action came from.
+pub const REROUTED_FROM_TENANT_LABEL: &str = "rerouted_from_tenant";
+
+/// Move `action` to the target namespace and tenant.
+///
+/// Targets that are unset or equal to the action's current value are left
+/// alone, so applying the same reroute twice records the original scope
+/// only once.
+pub(crate) fn apply_reroute_scope(
+    action: &mut Action,
+    target_namespace: Option<&str>,
+    target_tenant: Option<&str>,
+) {
+    if let Some(namespace) = target_namespace
+        && namespace != action.namespace.as_str()
+    {
+        action.metadata.labels.insert(
+            REROUTED_FROM_NAMESPACE_LABEL.to_owned(),
+            action.namespace.to_string(),
+        );
+        action.namespace = namespace.into();
+    }
+    if let Some(tenant) = target_tenant
+        && tenant != action.tenant.as_str()
+    {
+        action.metadata.labels.insert(
+            REROUTED_FROM_TENANT_LABEL.to_owned(),
+            action.tenant.to_string(),
+        );
+        action.tenant = tenant.into();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn action() -> Action {
+        Action::new("alerts", "acme", "slack", "page", serde_json::json!({}))
+    }
+
+    #[test]
+    fn records_the_original_scope_once() {
+        let mut action = action();
+        apply_reroute_scope(&mut action, Some("ops"), Some("central"));
+        apply_reroute_scope(&mut action, Some("ops"), Some("central"));
+
+        assert_eq!(action.namespace.as_str(), "ops");
+        assert_eq!(action.tenant.as_str(), "central");
+        let labels = &action.metadata.labels;
+        assert_eq!(labels[REROUTED_FROM_NAMESPACE_LABEL], "alerts");
+        assert_eq!(labels[REROUTED_FROM_TENANT_LABEL], "acme");
+    }
+
+    #[test]
+    fn unset_targets_keep_the_scope() {
+        let mut action = action();
+        apply_reroute_scope(&mut action, None, Some("central"));
+
+        assert_eq!(action.namespace.as_str(), "alerts");
+        assert_eq!(action.tenant.as_str(), "central");
+        assert!(
+            !action
+                .metadata
+                .labels
+                .contains_key(REROUTED_FROM_NAMESPACE_LABEL)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Stream-notifying wrapper for dead-letter queue sinks.
+//!
+//! [`StreamingDeadLetterSink`] broadcasts a
+//! [`StreamEventType::DlqEntryAdded`] event for every action pushed to the
+//! dead-letter queue, so dashboards learn about dead-lettered actions as they
+//! happen instead of polling the queue's size.
+
+use std::sync::Arc;
+
+use acteon_core::{Action, StreamEvent, StreamEventType};
+use acteon_executor::{DeadLetterEntry, DeadLetterSink};
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::sync::broadcast;
+
+/// A [`DeadLetterSink`] wrapper that emits a stream event after each push.
+///
+/// The event carries the action's ID, provider, final error, and attempt
+/// count but never its payload. All other calls are delegated unchanged.
+pub(crate) struct StreamingDeadLetterSink {
+    inner: Arc<dyn DeadLetterSink>,
+    stream_tx: broadcast::Sender<StreamEvent>,
+}
+
+impl StreamingDeadLetterSink {
+    /// Wrap `inner`, broadcasting events on `stream_tx`.
+    pub(crate) fn new(
+        inner: Arc<dyn DeadLetterSink>,
+        stream_tx: broadcast::Sender<StreamEvent>,
+    ) -> Self {
+        Self { inner, stream_tx }
+    }
+}
+
+#[async_trait]
+impl DeadLetterSink for StreamingDeadLetterSink {
+    async fn push(&self, action: Action, error: String, attempts: u32) {
+        let event = StreamEvent {
+            id: uuid::Uuid::now_v7().to_string(),
+            timestamp: Utc::now(),
+            event_type: StreamEventType::DlqEntryAdded {
+                provider: action.provider.to_string(),
+                error: error.clone(),
+                attempts,
+            },
+            namespace: action.namespace.to_string(),
+            tenant: action.tenant.to_string(),
+            action_type: Some(action.action_type.clone()),
+            action_id: Some(action.id.to_string()),
+            traceparent: action.traceparent().map(str::to_owned),
+        };
+        self.inner.push(action, error, attempts).await;
+        // No subscribers is not an error.
+        let _ = self.stream_tx.send(event);
+    }
+
+    async fn drain(&self) -> Vec<DeadLetterEntry> {
+        self.inner.drain().await
+    }
+
+    async fn len(&self) -> usize {
+        self.inner.len().await
+    }
+
+    async fn is_empty(&self) -> bool {
+        self.inner.is_empty().await
+    }
+}
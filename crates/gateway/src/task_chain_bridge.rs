@@ -537,6 +537,9 @@ mod tests {
             config_snapshot: None,
             search_attributes: Default::default(),
             wait_state: None,
+            depth: 0,
+            steps_executed: 0,
+            limit_exceeded: None,
         }
     }
 
@@ -240,6 +240,7 @@ impl TaskEngine {
                 tenant: tenant.to_string(),
                 action_type: Some("a2a.task".to_string()),
                 action_id: Some(task_id.to_string()),
+                traceparent: None,
             };
             let _ = tx.send(evt);
         }
@@ -5,9 +5,12 @@
 //! `$ref` references to stored [`Template`] objects.
 
 use std::collections::HashMap;
+use std::fmt::Write as _;
 use std::io::Write;
+use std::sync::Arc;
 
-use acteon_core::template::{Template, TemplateProfile, TemplateProfileField};
+use acteon_core::EventGroup;
+use acteon_core::template::{Template, TemplateOutputType, TemplateProfile, TemplateProfileField};
 
 use crate::error::GatewayError;
 
@@ -17,6 +20,63 @@ const MAX_RENDERED_BYTES: usize = 1_024 * 1_024;
 /// Fuel limit for `MiniJinja` template evaluation (denial-of-service protection).
 const FUEL_LIMIT: u64 = 100_000;
 
+/// Name of the custom auto-escape mode for Slack `mrkdwn` output.
+const MRKDWN_ESCAPE: &str = "mrkdwn";
+
+/// The auto-escape mode for template `name` in a profile rendering to
+/// `output_type`.
+///
+/// Without an output type, only templates with `.html`/`.htm` extensions are
+/// HTML-escaped. Most Acteon templates produce API payloads (JSON, plain
+/// text) where HTML escaping would corrupt data (e.g., `application/pdf` →
+/// `application&#x2f;pdf`).
+///
+/// DO NOT match on keywords like "body" — fields named `body`,
+/// `request_body`, `webhook_body` etc. are overwhelmingly JSON/plain-text,
+/// not HTML.
+fn auto_escape_for(name: &str, output_type: Option<TemplateOutputType>) -> minijinja::AutoEscape {
+    match output_type {
+        Some(TemplateOutputType::Html) => minijinja::AutoEscape::Html,
+        Some(TemplateOutputType::Mrkdwn) => minijinja::AutoEscape::Custom(MRKDWN_ESCAPE),
+        Some(TemplateOutputType::Plain) => minijinja::AutoEscape::None,
+        None => {
+            let ext = std::path::Path::new(name)
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("");
+            if ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm") {
+                minijinja::AutoEscape::Html
+            } else {
+                minijinja::AutoEscape::None
+            }
+        }
+    }
+}
+
+/// Formatter adding `mrkdwn` escaping to `MiniJinja`'s default formatter.
+///
+/// Slack treats `&`, `<`, and `>` as control characters (`<@U123>` is a
+/// mention, `<https://…|text>` a link), so they are replaced with entities.
+fn mrkdwn_formatter(
+    out: &mut minijinja::Output,
+    state: &minijinja::State,
+    value: &minijinja::Value,
+) -> Result<(), minijinja::Error> {
+    if state.auto_escape() != minijinja::AutoEscape::Custom(MRKDWN_ESCAPE) || value.is_safe() {
+        return minijinja::escape_formatter(out, state, value);
+    }
+    let text = value.to_string();
+    for c in text.chars() {
+        match c {
+            '&' => out.write_str("&amp;")?,
+            '<' => out.write_str("&lt;")?,
+            '>' => out.write_str("&gt;")?,
+            c => out.write_char(c)?,
+        }
+    }
+    Ok(())
+}
+
 /// A writer that aborts once a byte-count limit is exceeded.
 struct SizeLimitedWriter {
     buf: Vec<u8>,
@@ -141,6 +201,10 @@ fn build_template_context(
 /// - **`attachments`** -- list of `{id, name, filename, content_type}` objects
 /// - **`attachments_by_id`** -- map from `id` to the same metadata objects
 ///
+/// Interpolated values are escaped for the profile's
+/// [`output_type`](TemplateProfile::output_type): HTML-escaped for `html`,
+/// `&`/`<`/`>`-escaped for `mrkdwn`, and left alone for `plain`.
+///
 /// Output is streamed through a size-limited writer that aborts mid-render
 /// when the per-field limit is exceeded, preventing unbounded memory growth.
 pub fn render_profile<S: ::std::hash::BuildHasher>(
@@ -152,23 +216,9 @@ pub fn render_profile<S: ::std::hash::BuildHasher>(
     let mut env = minijinja::Environment::new();
     env.set_fuel(Some(FUEL_LIMIT));
 
-    // Auto-escape HTML only for templates with .html/.htm file extensions.
-    // Most Acteon templates produce API payloads (JSON, plain text) where
-    // HTML escaping would corrupt data (e.g., `application/pdf` → `application&#x2f;pdf`).
-    //
-    // DO NOT match on keywords like "body" — fields named `body`, `request_body`,
-    // `webhook_body` etc. are overwhelmingly JSON/plain-text, not HTML.
-    env.set_auto_escape_callback(|name| {
-        let ext = std::path::Path::new(name)
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("");
-        if ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm") {
-            minijinja::AutoEscape::Html
-        } else {
-            minijinja::AutoEscape::None
-        }
-    });
+    let output_type = profile.output_type;
+    env.set_auto_escape_callback(move |name| auto_escape_for(name, output_type));
+    env.set_formatter(mrkdwn_formatter);
 
     // Register ALL scoped templates so {% include %} and {% extends %} work.
     for (name, template) in templates_map {
@@ -270,6 +320,48 @@ pub fn merge_rendered_into_payload(
     Ok(())
 }
 
+/// Render a group's digest template at flush time.
+///
+/// Returns `Ok(None)` when the group has no digest template. The template
+/// sees the variables from [`EventGroup::digest_context`] plus a
+/// `distinct(field)` function returning the distinct values of a
+/// dot-separated payload field across member events, e.g.
+/// `{{ count }} disk alerts on {{ distinct("host") | length }} hosts`.
+pub fn render_group_digest(group: &EventGroup) -> Result<Option<String>, GatewayError> {
+    let Some(source) = group.digest_template.as_deref() else {
+        return Ok(None);
+    };
+
+    let mut env = minijinja::Environment::new();
+    env.set_fuel(Some(FUEL_LIMIT));
+    env.set_auto_escape_callback(|_| minijinja::AutoEscape::None);
+
+    let shared = Arc::new(group.clone());
+    env.add_function("distinct", move |field: String| {
+        minijinja::Value::from_serialize(shared.distinct_values(&field))
+    });
+
+    env.add_template("__group_digest__", source).map_err(|e| {
+        GatewayError::TemplateRender(format!(
+            "syntax error in digest template for group '{}': {e}",
+            group.group_id
+        ))
+    })?;
+    let tmpl = env
+        .get_template("__group_digest__")
+        .map_err(|e| GatewayError::TemplateRender(format!("error loading digest template: {e}")))?;
+
+    let ctx = minijinja::Value::from_serialize(group.digest_context());
+    let mut writer = SizeLimitedWriter::new(MAX_RENDERED_BYTES);
+    tmpl.render_to_write(&ctx, &mut writer).map_err(|e| {
+        GatewayError::TemplateRender(format!(
+            "error rendering digest for group '{}': {e}",
+            group.group_id
+        ))
+    })?;
+    writer.into_string().map(Some)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,6 +377,7 @@ mod tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             labels: HashMap::new(),
+            deleted_at: None,
         }
     }
 
@@ -295,10 +388,12 @@ mod tests {
             namespace: "ns".to_string(),
             tenant: "t".to_string(),
             fields,
+            output_type: None,
             description: None,
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             labels: HashMap::new(),
+            deleted_at: None,
         }
     }
 
@@ -742,4 +837,77 @@ mod tests {
         );
         assert!(rendered.contains("&lt;script&gt;"));
     }
+
+    /// Render `template` as inline field `msg` of a profile with
+    /// `output_type`, with `name` set to `value`.
+    fn render_as(output_type: Option<TemplateOutputType>, template: &str, value: &str) -> String {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "msg".to_string(),
+            TemplateProfileField::Inline(template.to_string()),
+        );
+        let profile = TemplateProfile {
+            output_type,
+            ..make_profile("typed", fields)
+        };
+        let payload = serde_json::json!({ "name": value });
+        let mut result = render_profile(&profile, &HashMap::new(), &payload, &[]).unwrap();
+        result.fields.remove("msg").unwrap()
+    }
+
+    #[test]
+    fn output_type_picks_the_escaping() {
+        let script = "<script>alert(1)</script>";
+        let template = "<b>Hi</b> {{ name }}";
+
+        assert_eq!(
+            render_as(Some(TemplateOutputType::Html), template, script),
+            "<b>Hi</b> &lt;script&gt;alert(1)&lt;&#x2f;script&gt;"
+        );
+        assert_eq!(
+            render_as(Some(TemplateOutputType::Plain), template, script),
+            "<b>Hi</b> <script>alert(1)</script>"
+        );
+        assert_eq!(
+            render_as(
+                Some(TemplateOutputType::Mrkdwn),
+                "*Alert* {{ name }}",
+                "<!here> & co"
+            ),
+            "*Alert* &lt;!here&gt; &amp; co"
+        );
+    }
+
+    #[test]
+    fn output_type_overrides_template_extension_and_respects_safe() {
+        // A plain-text profile does not escape even `.html`-named templates.
+        let mut fields = HashMap::new();
+        fields.insert(
+            "sms".to_string(),
+            TemplateProfileField::Ref {
+                template_ref: "alert.html".to_string(),
+            },
+        );
+        let profile = TemplateProfile {
+            output_type: Some(TemplateOutputType::Plain),
+            ..make_profile("sms", fields)
+        };
+        let mut templates = HashMap::new();
+        templates.insert(
+            "alert.html".to_string(),
+            make_template("alert.html", "{{ name }}"),
+        );
+        let payload = serde_json::json!({ "name": "a < b" });
+        let result = render_profile(&profile, &templates, &payload, &[]).unwrap();
+        assert_eq!(result.fields["sms"], "a < b");
+
+        assert_eq!(
+            render_as(
+                Some(TemplateOutputType::Mrkdwn),
+                "{{ name | safe }}",
+                "<https://x.io|link>"
+            ),
+            "<https://x.io|link>"
+        );
+    }
 }
@@ -203,7 +203,9 @@ impl Gateway {
 
         let mut count = 0usize;
         for (_key, value) in &tpl_entries {
-            if let Ok(tpl) = serde_json::from_str::<acteon_core::Template>(value) {
+            if let Ok(tpl) = serde_json::from_str::<acteon_core::Template>(value)
+                && tpl.deleted_at.is_none()
+            {
                 let scope = (tpl.namespace.clone(), tpl.tenant.clone());
                 new_templates
                     .entry(scope)
@@ -220,7 +222,9 @@ impl Gateway {
             .map_err(|e| GatewayError::Configuration(format!("profile sync scan failed: {e}")))?;
 
         for (_key, value) in &prof_entries {
-            if let Ok(prof) = serde_json::from_str::<acteon_core::TemplateProfile>(value) {
+            if let Ok(prof) = serde_json::from_str::<acteon_core::TemplateProfile>(value)
+                && prof.deleted_at.is_none()
+            {
                 let scope = (prof.namespace.clone(), prof.tenant.clone());
                 new_profiles
                     .entry(scope)
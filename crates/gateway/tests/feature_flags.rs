@@ -0,0 +1,220 @@
+//! Integration tests for per-tenant feature flags: rules branch on
+//! `flags.*` at evaluation time, so toggling a flag through the gateway
+//! changes routing on the next dispatch.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use acteon_core::{Action, ActionOutcome, FeatureFlags, ProviderResponse};
+use acteon_executor::ExecutorConfig;
+use acteon_gateway::{Gateway, GatewayBuilder};
+use acteon_provider::{DispatchContext, DynProvider, ProviderError};
+use acteon_rules::ir::expr::Expr;
+use acteon_rules::ir::rule::{Rule, RuleAction};
+use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+
+const NS: &str = "notifications";
+const TENANT: &str = "tenant-1";
+
+/// A provider that records the flags it was dispatched with.
+struct RecordingProvider {
+    name: &'static str,
+    uses_flags: bool,
+    seen_flags: Arc<Mutex<Vec<FeatureFlags>>>,
+}
+
+#[async_trait]
+impl DynProvider for RecordingProvider {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    async fn execute(&self, _action: &Action) -> Result<ProviderResponse, ProviderError> {
+        self.seen_flags.lock().unwrap().push(FeatureFlags::new());
+        Ok(ProviderResponse::success(serde_json::json!({})))
+    }
+
+    async fn health_check(&self) -> Result<(), ProviderError> {
+        Ok(())
+    }
+
+    fn uses_feature_flags(&self) -> bool {
+        self.uses_flags
+    }
+
+    async fn execute_with_context(
+        &self,
+        _action: &Action,
+        ctx: &DispatchContext,
+    ) -> Result<ProviderResponse, ProviderError> {
+        self.seen_flags.lock().unwrap().push(ctx.flags.clone());
+        Ok(ProviderResponse::success(serde_json::json!({})))
+    }
+}
+
+fn build_gateway(seen_flags: &Arc<Mutex<Vec<FeatureFlags>>>, uses_flags: bool) -> Gateway {
+    // `flags.enabled("new_sms_vendor")` in CEL.
+    let rule = Rule::new(
+        "new-sms-vendor",
+        Expr::Call(
+            "enabled".into(),
+            vec![
+                Expr::Ident("flags".into()),
+                Expr::String("new_sms_vendor".into()),
+            ],
+        ),
+        RuleAction::Reroute {
+            target_provider: Some("sms-v2".into()),
+            target_namespace: None,
+            target_tenant: None,
+        },
+    );
+    GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .rules(vec![rule])
+        .provider(Arc::new(RecordingProvider {
+            name: "sms",
+            uses_flags,
+            seen_flags: Arc::clone(seen_flags),
+        }))
+        .provider(Arc::new(RecordingProvider {
+            name: "sms-v2",
+            uses_flags,
+            seen_flags: Arc::clone(seen_flags),
+        }))
+        .executor_config(ExecutorConfig {
+            max_retries: 0,
+            execution_timeout: Duration::from_secs(5),
+            max_concurrent: 10,
+            ..ExecutorConfig::default()
+        })
+        .build()
+        .expect("gateway should build")
+}
+
+fn sms(tenant: &str) -> Action {
+    Action::new(
+        NS,
+        tenant,
+        "sms",
+        "send_sms",
+        serde_json::json!({"to": "+15550100"}),
+    )
+}
+
+#[tokio::test]
+async fn toggling_a_flag_changes_routing() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let gateway = build_gateway(&seen, true);
+
+    // Flag unset: the rule does not match and the action goes to `sms`.
+    let outcome = gateway.dispatch(sms(TENANT), None).await.unwrap();
+    assert!(matches!(outcome, ActionOutcome::Executed(_)), "{outcome:?}");
+
+    // Flag on: the same action is rerouted to the new vendor.
+    gateway
+        .set_feature_flag(NS, TENANT, "new_sms_vendor", true)
+        .await
+        .unwrap();
+    let outcome = gateway.dispatch(sms(TENANT), None).await.unwrap();
+    match outcome {
+        ActionOutcome::Rerouted { new_provider, .. } => assert_eq!(new_provider, "sms-v2"),
+        other => panic!("expected Rerouted, got {other:?}"),
+    }
+
+    // Other tenants are unaffected.
+    let outcome = gateway.dispatch(sms("tenant-2"), None).await.unwrap();
+    assert!(matches!(outcome, ActionOutcome::Executed(_)), "{outcome:?}");
+
+    // Flag explicitly off, then deleted: routing reverts either way.
+    gateway
+        .set_feature_flag(NS, TENANT, "new_sms_vendor", false)
+        .await
+        .unwrap();
+    let outcome = gateway.dispatch(sms(TENANT), None).await.unwrap();
+    assert!(matches!(outcome, ActionOutcome::Executed(_)), "{outcome:?}");
+
+    assert!(
+        gateway
+            .delete_feature_flag(NS, TENANT, "new_sms_vendor")
+            .await
+            .unwrap()
+    );
+    assert!(
+        !gateway
+            .delete_feature_flag(NS, TENANT, "new_sms_vendor")
+            .await
+            .unwrap()
+    );
+    assert!(
+        gateway
+            .get_feature_flags(NS, TENANT)
+            .await
+            .unwrap()
+            .flags
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn providers_receive_tenant_flags() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let gateway = build_gateway(&seen, true);
+
+    gateway
+        .set_feature_flag(NS, TENANT, "sms_unicode", true)
+        .await
+        .unwrap();
+    gateway.dispatch(sms(TENANT), None).await.unwrap();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 1);
+    assert!(seen[0].enabled("sms_unicode"));
+    assert!(!seen[0].enabled("new_sms_vendor"));
+}
+
+#[tokio::test]
+async fn providers_that_ignore_flags_keep_the_plain_path() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let gateway = build_gateway(&seen, false);
+
+    gateway
+        .set_feature_flag(NS, TENANT, "sms_unicode", true)
+        .await
+        .unwrap();
+    gateway.dispatch(sms(TENANT), None).await.unwrap();
+
+    // `execute` records an empty set; `execute_with_context` was not used.
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 1);
+    assert!(seen[0].is_empty());
+}
+
+#[tokio::test]
+async fn flag_updates_merge_per_tenant() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let gateway = build_gateway(&seen, true);
+
+    gateway
+        .set_feature_flag(NS, TENANT, "a", true)
+        .await
+        .unwrap();
+    let record = gateway
+        .set_feature_flag(NS, TENANT, "b", false)
+        .await
+        .unwrap();
+
+    assert_eq!(record.namespace, NS);
+    assert_eq!(record.tenant, TENANT);
+    assert!(record.flags.enabled("a"));
+    assert!(!record.flags.enabled("b"));
+    assert_eq!(record.flags.len(), 2);
+    assert!(record.updated_at.is_some());
+
+    let other = gateway.get_feature_flags(NS, "tenant-2").await.unwrap();
+    assert!(other.flags.is_empty());
+    assert!(other.updated_at.is_none());
+}
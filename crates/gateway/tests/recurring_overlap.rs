@@ -1,9 +1,12 @@
-//! Integration tests for gateway-level recurring overlap enforcement:
-//! `Gateway::enforce_overlap_policy` must make the same decisions for any
-//! consumer (the bundled server's recurring consumer or an embedder).
+//! Integration tests for gateway-level recurring overlap and concurrency
+//! enforcement:
+//! `Gateway::enforce_overlap_policy` and
+//! `Gateway::dispatch_recurring_occurrence` must make the same decisions for
+//! any consumer (the bundled server's recurring consumer or an embedder).
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -11,11 +14,11 @@ use chrono::Utc;
 
 use acteon_core::chain::{ChainConfig, ChainStepConfig, SignalStepConfig};
 use acteon_core::{
-    Action, ActionOutcome, ChainStatus, OverlapPolicy, ProviderResponse, RecurringAction,
-    RecurringActionTemplate,
+    Action, ActionOutcome, ChainStatus, ConcurrencyPolicy, OverlapPolicy, ProviderResponse,
+    RecurringAction, RecurringActionTemplate,
 };
 use acteon_executor::ExecutorConfig;
-use acteon_gateway::{Gateway, GatewayBuilder, OverlapDecision};
+use acteon_gateway::{Gateway, GatewayBuilder, OccurrenceOutcome, OverlapDecision};
 use acteon_provider::{DynProvider, ProviderError};
 use acteon_rules::ir::expr::{BinaryOp, Expr};
 use acteon_rules::ir::rule::{Rule, RuleAction};
@@ -41,6 +44,50 @@ impl DynProvider for MockProvider {
     }
 }
 
+/// A provider that takes a while to answer, so consecutive occurrences
+/// overlap while it is in flight.
+struct SlowProvider {
+    delay: Duration,
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl DynProvider for SlowProvider {
+    fn name(&self) -> &'static str {
+        "email"
+    }
+
+    async fn execute(&self, _action: &Action) -> Result<ProviderResponse, ProviderError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(self.delay).await;
+        Ok(ProviderResponse::success(serde_json::json!({"ok": true})))
+    }
+
+    async fn health_check(&self) -> Result<(), ProviderError> {
+        Ok(())
+    }
+}
+
+fn build_slow_gateway(delay: Duration) -> (Gateway, Arc<AtomicUsize>) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::new(SlowProvider {
+            delay,
+            calls: Arc::clone(&calls),
+        }))
+        .executor_config(ExecutorConfig {
+            max_retries: 0,
+            execution_timeout: Duration::from_secs(5),
+            max_concurrent: 10,
+            ..ExecutorConfig::default()
+        })
+        .build()
+        .expect("gateway should build");
+    (gateway, calls)
+}
+
 fn build_gateway(chain: ChainConfig) -> Gateway {
     let rule = Rule::new(
         "start-chain",
@@ -128,6 +175,7 @@ fn recurring(policy: OverlapPolicy, last_execution_id: Option<String>) -> Recurr
         description: None,
         labels: HashMap::new(),
         overlap_policy: policy,
+        concurrency_policy: ConcurrencyPolicy::default(),
         last_execution_id,
     }
 }
@@ -277,3 +325,82 @@ async fn cancel_other_proceeds_when_previous_already_settled() {
         .unwrap();
     assert_eq!(state.status, ChainStatus::Completed);
 }
+
+/// Fire two occurrences of a slow recurring action back to back, as a tight
+/// schedule would, and return both outcomes.
+async fn fire_overlapping(
+    gateway: &Gateway,
+    policy: ConcurrencyPolicy,
+) -> (OccurrenceOutcome, OccurrenceOutcome) {
+    let rec = RecurringAction {
+        concurrency_policy: policy,
+        ..recurring(OverlapPolicy::AllowAll, None)
+    };
+    let occurrence = || {
+        gateway.dispatch_recurring_occurrence(
+            NS,
+            TENANT,
+            "rec-1",
+            &rec,
+            Action::new(NS, TENANT, "email", "send_digest", serde_json::json!({})),
+            None,
+        )
+    };
+    let first = Box::pin(occurrence());
+    let second = async {
+        // Let the first occurrence reach the provider before the next fires.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        Box::pin(occurrence()).await
+    };
+    let (first, second) = tokio::join!(first, second);
+    (first.unwrap(), second.unwrap())
+}
+
+#[tokio::test]
+async fn skip_drops_occurrence_while_previous_dispatch_in_flight() {
+    let (gateway, calls) = build_slow_gateway(Duration::from_millis(300));
+
+    let (first, second) = fire_overlapping(&gateway, ConcurrencyPolicy::Skip).await;
+    assert!(matches!(first, OccurrenceOutcome::Dispatched(_)));
+    assert!(matches!(
+        second,
+        OccurrenceOutcome::Skipped {
+            previous_execution_id: None
+        }
+    ));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(gateway.metrics().snapshot().recurring_skipped, 1);
+
+    // Once the previous dispatch has finished, the next occurrence runs.
+    let (third, _) = fire_overlapping(&gateway, ConcurrencyPolicy::Skip).await;
+    assert!(matches!(third, OccurrenceOutcome::Dispatched(_)));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn queue_waits_for_previous_dispatch() {
+    let delay = Duration::from_millis(300);
+    let (gateway, calls) = build_slow_gateway(delay);
+
+    let start = std::time::Instant::now();
+    let (first, second) = fire_overlapping(&gateway, ConcurrencyPolicy::Queue).await;
+    assert!(matches!(first, OccurrenceOutcome::Dispatched(_)));
+    assert!(matches!(second, OccurrenceOutcome::Dispatched(_)));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    // The second occurrence only started once the first released the guard.
+    assert!(start.elapsed() >= delay * 2);
+    assert_eq!(gateway.metrics().snapshot().recurring_skipped, 0);
+}
+
+#[tokio::test]
+async fn allow_overlaps_in_flight_dispatches() {
+    let delay = Duration::from_millis(300);
+    let (gateway, calls) = build_slow_gateway(delay);
+
+    let start = std::time::Instant::now();
+    let (first, second) = fire_overlapping(&gateway, ConcurrencyPolicy::Allow).await;
+    assert!(matches!(first, OccurrenceOutcome::Dispatched(_)));
+    assert!(matches!(second, OccurrenceOutcome::Dispatched(_)));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(start.elapsed() < delay * 2);
+}
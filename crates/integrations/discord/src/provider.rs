@@ -158,10 +158,13 @@ impl Provider for DiscordProvider {
 
         debug!("posting message to Discord webhook");
 
-        let response = acteon_provider::inject_trace_context(self.client.post(&url).json(&request))
-            .send()
-            .await
-            .map_err(DiscordError::Http)?;
+        let response = acteon_provider::inject_action_trace_context(
+            self.client.post(&url).json(&request),
+            action,
+        )
+        .send()
+        .await
+        .map_err(DiscordError::Http)?;
 
         self.interpret_response(response, 0).await
     }
@@ -192,11 +195,11 @@ impl Provider for DiscordProvider {
         let mut form = reqwest::multipart::Form::new().text("payload_json", payload_json);
 
         for (i, resolved) in ctx.attachments.iter().enumerate() {
-            let part = reqwest::multipart::Part::bytes(resolved.data.clone())
+            let part = reqwest::multipart::Part::bytes(resolved.data.to_vec())
                 .file_name(resolved.filename.clone())
                 .mime_str(&resolved.content_type)
                 .unwrap_or_else(|_| {
-                    reqwest::multipart::Part::bytes(resolved.data.clone())
+                    reqwest::multipart::Part::bytes(resolved.data.to_vec())
                         .file_name(resolved.filename.clone())
                 });
             form = form.part(format!("files[{i}]"), part);
@@ -117,7 +117,7 @@ impl EmailProvider {
             .map(|r| EmailAttachment {
                 filename: r.filename.clone(),
                 content_type: r.content_type.clone(),
-                data: r.data.clone(),
+                data: r.data.to_vec(),
             })
             .collect();
 
@@ -1,6 +1,115 @@
+use std::fmt;
+
 use acteon_provider::ProviderError;
 use thiserror::Error;
 
+/// An `error` code from a Slack Web API response with `ok: false`.
+///
+/// Codes the provider reacts to are modelled explicitly; anything else is
+/// kept verbatim in [`SlackApiErrorCode::Other`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlackApiErrorCode {
+    /// The channel does not exist or is not visible to the bot.
+    ChannelNotFound,
+    /// The bot is not a member of the channel.
+    NotInChannel,
+    /// The channel has been archived.
+    IsArchived,
+    /// The message text is too long.
+    MsgTooLong,
+    /// Neither text nor blocks were provided.
+    NoText,
+    /// The Block Kit payload was rejected.
+    InvalidBlocks,
+    /// The token is invalid.
+    InvalidAuth,
+    /// No token was provided.
+    NotAuthed,
+    /// The token has been revoked.
+    TokenRevoked,
+    /// The authenticating user or bot has been deactivated.
+    AccountInactive,
+    /// The token lacks a required OAuth scope.
+    MissingScope,
+    /// The method's rate limit was exceeded.
+    RateLimited,
+    /// Slack failed internally; the request may succeed if retried.
+    InternalError,
+    /// Slack hit an unrecoverable server error; the request may succeed if retried.
+    FatalError,
+    /// Slack is temporarily unavailable.
+    ServiceUnavailable,
+    /// Slack timed out processing the request.
+    RequestTimeout,
+    /// Any other error code.
+    Other(String),
+}
+
+impl SlackApiErrorCode {
+    /// Parse a Slack `error` string.
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "channel_not_found" => Self::ChannelNotFound,
+            "not_in_channel" => Self::NotInChannel,
+            "is_archived" => Self::IsArchived,
+            "msg_too_long" => Self::MsgTooLong,
+            "no_text" => Self::NoText,
+            "invalid_blocks" => Self::InvalidBlocks,
+            "invalid_auth" => Self::InvalidAuth,
+            "not_authed" => Self::NotAuthed,
+            "token_revoked" => Self::TokenRevoked,
+            "account_inactive" => Self::AccountInactive,
+            "missing_scope" => Self::MissingScope,
+            "ratelimited" | "rate_limited" => Self::RateLimited,
+            "internal_error" => Self::InternalError,
+            "fatal_error" => Self::FatalError,
+            "service_unavailable" => Self::ServiceUnavailable,
+            "request_timeout" => Self::RequestTimeout,
+            other => Self::Other(other.to_owned()),
+        }
+    }
+
+    /// The Slack `error` string for this code.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ChannelNotFound => "channel_not_found",
+            Self::NotInChannel => "not_in_channel",
+            Self::IsArchived => "is_archived",
+            Self::MsgTooLong => "msg_too_long",
+            Self::NoText => "no_text",
+            Self::InvalidBlocks => "invalid_blocks",
+            Self::InvalidAuth => "invalid_auth",
+            Self::NotAuthed => "not_authed",
+            Self::TokenRevoked => "token_revoked",
+            Self::AccountInactive => "account_inactive",
+            Self::MissingScope => "missing_scope",
+            Self::RateLimited => "ratelimited",
+            Self::InternalError => "internal_error",
+            Self::FatalError => "fatal_error",
+            Self::ServiceUnavailable => "service_unavailable",
+            Self::RequestTimeout => "request_timeout",
+            Self::Other(code) => code,
+        }
+    }
+
+    /// Whether Slack documents this error as safe to retry.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::InternalError
+                | Self::FatalError
+                | Self::ServiceUnavailable
+                | Self::RequestTimeout
+        )
+    }
+}
+
+impl fmt::Display for SlackApiErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Errors specific to the Slack provider.
 ///
 /// These are internal errors that get converted into [`ProviderError`] at the
@@ -11,10 +120,14 @@ pub enum SlackError {
     #[error("HTTP error: {0}")]
     Http(reqwest::Error),
 
-    /// The Slack API returned an error response (ok: false).
+    /// The Slack API returned a non-success HTTP response.
     #[error("Slack API error: {0}")]
     Api(String),
 
+    /// The Slack API accepted the request but rejected it with `ok: false`.
+    #[error("Slack API error: {0}")]
+    Rejected(SlackApiErrorCode),
+
     /// The Slack API returned a **transient** non-success response
     /// (5xx server error or 408 Request Timeout). The request body was
     /// fine; the server was temporarily unable to handle it.
@@ -48,9 +161,15 @@ impl From<SlackError> for ProviderError {
         match err {
             SlackError::Http(e) => ProviderError::Connection(e.to_string()),
             SlackError::Api(msg) => ProviderError::ExecutionFailed(msg),
+            SlackError::RateLimited | SlackError::Rejected(SlackApiErrorCode::RateLimited) => {
+                ProviderError::RateLimited
+            }
+            SlackError::Rejected(code) if code.is_transient() => {
+                ProviderError::Connection(code.to_string())
+            }
+            SlackError::Rejected(code) => ProviderError::ExecutionFailed(code.to_string()),
             SlackError::Transient(msg) => ProviderError::Connection(msg),
             SlackError::InvalidPayload(msg) => ProviderError::Serialization(msg),
-            SlackError::RateLimited => ProviderError::RateLimited,
         }
     }
 }
@@ -73,6 +192,45 @@ mod tests {
         assert!(matches!(provider_err, ProviderError::ExecutionFailed(_)));
     }
 
+    #[test]
+    fn rejected_error_codes_round_trip() {
+        for code in [
+            "channel_not_found",
+            "not_in_channel",
+            "invalid_auth",
+            "weird_new_code",
+        ] {
+            assert_eq!(SlackApiErrorCode::from_code(code).as_str(), code);
+        }
+        assert_eq!(
+            SlackApiErrorCode::from_code("not_in_channel"),
+            SlackApiErrorCode::NotInChannel
+        );
+        assert_eq!(
+            SlackApiErrorCode::from_code("weird_new_code"),
+            SlackApiErrorCode::Other("weird_new_code".into())
+        );
+    }
+
+    #[test]
+    fn rejected_error_maps_by_code() {
+        let provider_err: ProviderError =
+            SlackError::Rejected(SlackApiErrorCode::ChannelNotFound).into();
+        assert!(!provider_err.is_retryable());
+        assert!(
+            matches!(provider_err, ProviderError::ExecutionFailed(ref m) if m == "channel_not_found")
+        );
+
+        let provider_err: ProviderError =
+            SlackError::Rejected(SlackApiErrorCode::RateLimited).into();
+        assert!(matches!(provider_err, ProviderError::RateLimited));
+
+        let provider_err: ProviderError =
+            SlackError::Rejected(SlackApiErrorCode::InternalError).into();
+        assert!(provider_err.is_retryable());
+        assert!(matches!(provider_err, ProviderError::Connection(_)));
+    }
+
     #[test]
     fn transient_error_maps_to_retryable_connection() {
         // 5xx / 408 live-blip errors must be retried instead of
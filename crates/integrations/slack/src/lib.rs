@@ -20,6 +20,8 @@ pub mod provider;
 pub mod types;
 
 pub use config::SlackConfig;
-pub use error::SlackError;
+pub use error::{SlackApiErrorCode, SlackError};
 pub use provider::SlackProvider;
-pub use types::{SlackApiResponse, SlackAuthTestResponse, SlackPostMessageRequest};
+pub use types::{
+    SlackApiResponse, SlackAuthTestResponse, SlackPostMessageRequest, SlackResponseMetadata,
+};
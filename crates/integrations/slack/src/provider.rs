@@ -8,7 +8,7 @@ use serde::Deserialize;
 use tracing::{debug, info, instrument, warn};
 
 use crate::config::SlackConfig;
-use crate::error::SlackError;
+use crate::error::{SlackApiErrorCode, SlackError};
 use crate::types::{SlackApiResponse, SlackAuthTestResponse, SlackPostMessageRequest};
 
 /// Slack provider that sends messages via the Slack Web API.
@@ -28,6 +28,56 @@ struct MessagePayload {
     blocks: Option<serde_json::Value>,
 }
 
+/// Derive a plain-text notification fallback from Block Kit `blocks`.
+///
+/// Slack shows the top-level `text` in push notifications and other places
+/// that cannot render blocks; without it, users see "This content can't be
+/// displayed." The heuristic walks the blocks in order and returns the first
+/// non-empty text it finds:
+///
+/// - `header` and `section` blocks: the block's `text.text`;
+/// - `section` blocks without `text`: their `fields`' texts, one per line;
+/// - `context` blocks: their text elements, joined with spaces.
+///
+/// Other block types (images, dividers, actions, ...) carry no readable text
+/// and are skipped. Returns `None` when no block yields any text.
+fn fallback_text_from_blocks(blocks: &serde_json::Value) -> Option<String> {
+    fn text_of(obj: &serde_json::Value) -> Option<&str> {
+        obj.get("text")
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+    }
+
+    blocks.as_array()?.iter().find_map(|block| {
+        let texts: Vec<&str> = match block.get("type").and_then(serde_json::Value::as_str)? {
+            "header" | "section" => match block.get("text").and_then(text_of) {
+                Some(text) => vec![text],
+                None => block
+                    .get("fields")
+                    .and_then(serde_json::Value::as_array)
+                    .map(|fields| fields.iter().filter_map(text_of).collect())
+                    .unwrap_or_default(),
+            },
+            "context" => block
+                .get("elements")
+                .and_then(serde_json::Value::as_array)
+                .map(|elements| elements.iter().filter_map(text_of).collect())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        if texts.is_empty() {
+            return None;
+        }
+        let separator = if block["type"] == "context" {
+            " "
+        } else {
+            "\n"
+        };
+        Some(texts.join(separator))
+    })
+}
+
 impl SlackProvider {
     /// Create a new Slack provider with the given configuration.
     ///
@@ -115,10 +165,10 @@ impl SlackProvider {
         let api_response: SlackApiResponse = response.json().await?;
 
         if !api_response.ok {
-            let error_code = api_response
-                .error
-                .unwrap_or_else(|| "unknown_error".to_owned());
-            return Err(SlackError::Api(error_code));
+            let error_code = api_response.error.as_deref().unwrap_or("unknown_error");
+            return Err(SlackError::Rejected(SlackApiErrorCode::from_code(
+                error_code,
+            )));
         }
 
         Ok(api_response)
@@ -138,28 +188,46 @@ impl Provider for SlackProvider {
 
         let channel = self.resolve_channel(payload.channel.as_deref())?;
 
-        if payload.text.is_none() && payload.blocks.is_none() {
+        // An empty `text` is no content: Slack would show a blank
+        // notification, so treat it as absent.
+        let text = payload.text.filter(|t| !t.trim().is_empty());
+        if text.is_none() && payload.blocks.is_none() {
             return Err(SlackError::InvalidPayload(
                 "payload must contain at least one of 'text' or 'blocks'".into(),
             )
             .into());
         }
 
+        // Blocks-only messages get a derived notification fallback.
+        let text = text.or_else(|| payload.blocks.as_ref().and_then(fallback_text_from_blocks));
+
         let request = SlackPostMessageRequest {
             channel,
-            text: payload.text,
+            text,
             blocks: payload.blocks,
         };
 
         let api_response = self.post_message(&request).await?;
+        let warnings = api_response.warnings();
 
-        let body = serde_json::json!({
+        let mut body = serde_json::json!({
             "ok": api_response.ok,
             "channel": api_response.channel,
             "ts": api_response.ts,
         });
 
-        Ok(ProviderResponse::success(body))
+        // Slack delivered the message but flagged something about the
+        // request; keep it a success and surface the warnings.
+        if warnings.is_empty() {
+            return Ok(ProviderResponse::success(body));
+        }
+        warn!(warnings = ?warnings, "Slack accepted message with warnings");
+        body["warnings"] = serde_json::json!(warnings);
+        let mut response = ProviderResponse::success(body);
+        response
+            .headers
+            .insert("slack_warnings".to_owned(), warnings.join(","));
+        Ok(response)
     }
 
     fn supports_attachments(&self) -> bool {
@@ -184,11 +252,11 @@ impl Provider for SlackProvider {
 
             for resolved in &ctx.attachments {
                 let url = self.api_url("files.upload");
-                let part = reqwest::multipart::Part::bytes(resolved.data.clone())
+                let part = reqwest::multipart::Part::bytes(resolved.data.to_vec())
                     .file_name(resolved.filename.clone())
                     .mime_str(&resolved.content_type)
                     .unwrap_or_else(|_| {
-                        reqwest::multipart::Part::bytes(resolved.data.clone())
+                        reqwest::multipart::Part::bytes(resolved.data.to_vec())
                             .file_name(resolved.filename.clone())
                     });
 
@@ -400,6 +468,61 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn fallback_text_derived_from_blocks_only_payload() {
+        let blocks = serde_json::json!([
+            {"type": "divider"},
+            {"type": "image", "image_url": "https://example.com/a.png", "alt_text": "chart"},
+            {"type": "section", "text": {"type": "mrkdwn", "text": "*Deploy* finished"}},
+            {"type": "section", "text": {"type": "mrkdwn", "text": "second"}}
+        ]);
+        assert_eq!(
+            fallback_text_from_blocks(&blocks).as_deref(),
+            Some("*Deploy* finished")
+        );
+
+        let header = serde_json::json!([
+            {"type": "header", "text": {"type": "plain_text", "text": "Incident opened"}}
+        ]);
+        assert_eq!(
+            fallback_text_from_blocks(&header).as_deref(),
+            Some("Incident opened")
+        );
+
+        let fields = serde_json::json!([
+            {"type": "section", "fields": [
+                {"type": "mrkdwn", "text": "*Status:* open"},
+                {"type": "mrkdwn", "text": "*Owner:* ops"}
+            ]}
+        ]);
+        assert_eq!(
+            fallback_text_from_blocks(&fields).as_deref(),
+            Some("*Status:* open\n*Owner:* ops")
+        );
+
+        let context = serde_json::json!([
+            {"type": "context", "elements": [
+                {"type": "image", "image_url": "https://example.com/i.png", "alt_text": "i"},
+                {"type": "mrkdwn", "text": "via"},
+                {"type": "plain_text", "text": "Acteon"}
+            ]}
+        ]);
+        assert_eq!(
+            fallback_text_from_blocks(&context).as_deref(),
+            Some("via Acteon")
+        );
+    }
+
+    #[test]
+    fn fallback_text_absent_when_blocks_have_no_text() {
+        let blocks = serde_json::json!([
+            {"type": "divider"},
+            {"type": "section", "text": {"type": "mrkdwn", "text": "   "}}
+        ]);
+        assert_eq!(fallback_text_from_blocks(&blocks), None);
+        assert_eq!(fallback_text_from_blocks(&serde_json::json!({})), None);
+    }
+
     #[tokio::test]
     async fn execute_uses_default_channel() {
         let server = MockSlackServer::start().await;
@@ -452,6 +575,21 @@ mod tests {
         assert!(!err.is_retryable());
     }
 
+    #[tokio::test]
+    async fn execute_empty_text_and_no_blocks() {
+        let config = SlackConfig::new("xoxb-test").with_api_base_url("http://localhost:1");
+        let provider = SlackProvider::new(config);
+
+        let action = make_action(serde_json::json!({
+            "channel": "#general",
+            "text": ""
+        }));
+
+        let err = provider.execute(&action).await.unwrap_err();
+        assert!(matches!(err, ProviderError::Serialization(_)));
+        assert!(!err.is_retryable());
+    }
+
     #[tokio::test]
     async fn execute_rate_limited_is_retryable() {
         let server = MockSlackServer::start().await;
@@ -497,6 +635,121 @@ mod tests {
         assert!(!err.is_retryable());
     }
 
+    #[tokio::test]
+    async fn execute_ok_with_warnings_is_success() {
+        let server = MockSlackServer::start().await;
+        let config = SlackConfig::new("xoxb-test").with_api_base_url(&server.base_url);
+        let provider = SlackProvider::new(config);
+
+        let action = make_action(serde_json::json!({
+            "channel": "#general",
+            "text": "Hello!"
+        }));
+
+        let response_body = r#"{"ok":true,"channel":"C12345","ts":"1.2","warning":"missing_charset","response_metadata":{"warnings":["missing_charset"]}}"#;
+        let server_handle = tokio::spawn(async move {
+            server.respond_once(200, response_body).await;
+        });
+
+        let result = provider.execute(&action).await;
+        server_handle.await.unwrap();
+
+        let response = result.expect("ok with warnings should succeed");
+        assert_eq!(response.status, acteon_core::ResponseStatus::Success);
+        assert_eq!(response.body["ts"], "1.2");
+        assert_eq!(
+            response.body["warnings"],
+            serde_json::json!(["missing_charset"])
+        );
+        assert_eq!(
+            response.headers.get("slack_warnings").map(String::as_str),
+            Some("missing_charset")
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_success_without_warnings_has_no_warning_metadata() {
+        let server = MockSlackServer::start().await;
+        let config = SlackConfig::new("xoxb-test").with_api_base_url(&server.base_url);
+        let provider = SlackProvider::new(config);
+
+        let action = make_action(serde_json::json!({
+            "channel": "#general",
+            "text": "Hello!"
+        }));
+
+        let response_body = r#"{"ok":true,"channel":"C12345","ts":"1.2"}"#;
+        let server_handle = tokio::spawn(async move {
+            server.respond_once(200, response_body).await;
+        });
+
+        let response = provider.execute(&action).await.unwrap();
+        server_handle.await.unwrap();
+
+        assert!(response.body.get("warnings").is_none());
+        assert!(response.headers.is_empty());
+    }
+
+    #[tokio::test]
+    async fn post_message_error_strings_are_typed() {
+        for (code, expected) in [
+            ("channel_not_found", SlackApiErrorCode::ChannelNotFound),
+            ("not_in_channel", SlackApiErrorCode::NotInChannel),
+            ("is_archived", SlackApiErrorCode::IsArchived),
+            (
+                "something_new",
+                SlackApiErrorCode::Other("something_new".into()),
+            ),
+        ] {
+            let server = MockSlackServer::start().await;
+            let config = SlackConfig::new("xoxb-test").with_api_base_url(&server.base_url);
+            let provider = SlackProvider::new(config);
+            let request = SlackPostMessageRequest {
+                channel: "#general".into(),
+                text: Some("Hello!".into()),
+                blocks: None,
+            };
+
+            let response_body = format!(r#"{{"ok":false,"error":"{code}"}}"#);
+            let server_handle = tokio::spawn(async move {
+                server.respond_once(200, &response_body).await;
+            });
+
+            let err = provider.post_message(&request).await.unwrap_err();
+            server_handle.await.unwrap();
+
+            assert!(
+                matches!(&err, SlackError::Rejected(c) if *c == expected),
+                "{code}: {err:?}"
+            );
+            let provider_err: ProviderError = err.into();
+            assert!(!provider_err.is_retryable(), "{code} must not be retried");
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_transient_slack_error_is_retryable() {
+        let server = MockSlackServer::start().await;
+        let config = SlackConfig::new("xoxb-test").with_api_base_url(&server.base_url);
+        let provider = SlackProvider::new(config);
+
+        let action = make_action(serde_json::json!({
+            "channel": "#general",
+            "text": "Hello!"
+        }));
+
+        let response_body = r#"{"ok":false,"error":"internal_error"}"#;
+        let server_handle = tokio::spawn(async move {
+            server.respond_once(200, response_body).await;
+        });
+
+        let err = provider.execute(&action).await.unwrap_err();
+        server_handle.await.unwrap();
+
+        assert!(matches!(err, ProviderError::Connection(_)));
+        assert!(err.is_retryable());
+    }
+
     #[tokio::test]
     async fn health_check_success() {
         let server = MockSlackServer::start().await;
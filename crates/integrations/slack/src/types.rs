@@ -7,7 +7,8 @@ pub struct SlackPostMessageRequest {
     pub channel: String,
 
     /// Plain-text message content. At least one of `text` or `blocks` must be
-    /// present.
+    /// present; for blocks-only payloads the provider derives a notification
+    /// fallback from the blocks.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
@@ -30,6 +31,45 @@ pub struct SlackApiResponse {
 
     /// Timestamp identifier of the posted message (present on success).
     pub ts: Option<String>,
+
+    /// Comma-separated warning codes on an otherwise successful call.
+    pub warning: Option<String>,
+
+    /// Additional details, including structured warnings.
+    pub response_metadata: Option<SlackResponseMetadata>,
+}
+
+impl SlackApiResponse {
+    /// All warnings Slack attached to the response, deduplicated, in the
+    /// order they appear (`warning` first, then `response_metadata.warnings`).
+    pub fn warnings(&self) -> Vec<String> {
+        let top_level = self
+            .warning
+            .iter()
+            .flat_map(|w| w.split(','))
+            .map(str::trim);
+        let metadata = self
+            .response_metadata
+            .iter()
+            .flat_map(|m| m.warnings.iter())
+            .map(String::as_str);
+
+        let mut warnings: Vec<String> = Vec::new();
+        for warning in top_level.chain(metadata) {
+            if !warning.is_empty() && !warnings.iter().any(|w| w == warning) {
+                warnings.push(warning.to_owned());
+            }
+        }
+        warnings
+    }
+}
+
+/// The `response_metadata` object of a Slack Web API response.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SlackResponseMetadata {
+    /// Warning codes describing non-fatal problems with the request.
+    #[serde(default)]
+    pub warnings: Vec<String>,
 }
 
 /// Response from the Slack `auth.test` API.
@@ -95,6 +135,26 @@ mod tests {
         assert_eq!(resp.error.as_deref(), Some("channel_not_found"));
     }
 
+    #[test]
+    fn api_response_collects_warnings() {
+        let json = r#"{
+            "ok": true,
+            "channel": "C12345",
+            "ts": "1.2",
+            "warning": "missing_charset,superfluous_charset",
+            "response_metadata": {"warnings": ["missing_charset", "message_truncated"]}
+        }"#;
+        let resp: SlackApiResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            resp.warnings(),
+            vec![
+                "missing_charset",
+                "superfluous_charset",
+                "message_truncated"
+            ]
+        );
+    }
+
     #[test]
     fn auth_test_response_deserializes() {
         let json = r#"{"ok": true, "user_id": "U123", "team_id": "T456"}"#;
@@ -94,8 +94,9 @@ impl Provider for TeamsProvider {
 
         debug!("posting message to Teams webhook");
 
-        let response = acteon_provider::inject_trace_context(
+        let response = acteon_provider::inject_action_trace_context(
             self.client.post(&self.config.webhook_url).json(&body),
+            action,
         )
         .send()
         .await
@@ -210,8 +210,9 @@ impl Provider for WebhookProvider {
         // Apply authentication (may depend on body bytes for HMAC).
         request = self.apply_auth(request, &body_bytes)?;
 
-        // Inject W3C Trace Context so downstream services can join the trace.
-        request = acteon_provider::inject_trace_context(request);
+        // Inject W3C Trace Context so downstream services can join the trace,
+        // falling back to the caller's traceparent from the action metadata.
+        request = acteon_provider::inject_action_trace_context(request, action);
 
         // Send the request.
         let response = request.send().await.map_err(|e| {
@@ -250,11 +251,11 @@ impl Provider for WebhookProvider {
         let mut form = reqwest::multipart::Form::new().text("payload", body_json.clone());
 
         for (i, resolved) in ctx.attachments.iter().enumerate() {
-            let part = reqwest::multipart::Part::bytes(resolved.data.clone())
+            let part = reqwest::multipart::Part::bytes(resolved.data.to_vec())
                 .file_name(resolved.filename.clone())
                 .mime_str(&resolved.content_type)
                 .unwrap_or_else(|_| {
-                    reqwest::multipart::Part::bytes(resolved.data.clone())
+                    reqwest::multipart::Part::bytes(resolved.data.to_vec())
                         .file_name(resolved.filename.clone())
                 });
             form = form.part(format!("file_{i}"), part);
@@ -272,7 +273,7 @@ impl Provider for WebhookProvider {
         request = self.apply_auth(request, body_json.as_bytes())?;
 
         // Inject W3C Trace Context.
-        request = acteon_provider::inject_trace_context(request);
+        request = acteon_provider::inject_action_trace_context(request, action);
 
         let response = request.send().await.map_err(|e| {
             if e.is_timeout() {
@@ -40,6 +40,14 @@ fn val_opt_str(v: &serde_json::Value, key: &str) -> Option<String> {
         .map(ToString::to_string)
 }
 
+fn val_opt_vec_str(v: &serde_json::Value, key: &str) -> Option<Vec<String>> {
+    v.get(key).and_then(serde_json::Value::as_array).map(|a| {
+        a.iter()
+            .filter_map(|s| s.as_str().map(ToString::to_string))
+            .collect()
+    })
+}
+
 fn val_u64(v: &serde_json::Value, key: &str) -> Result<u64, McpError> {
     v.get(key)
         .and_then(serde_json::Value::as_u64)
@@ -155,7 +163,9 @@ fn build_update_recurring(v: &serde_json::Value) -> Result<UpdateRecurringAction
 fn build_create_quota(v: &serde_json::Value) -> Result<CreateQuotaRequest, McpError> {
     Ok(CreateQuotaRequest {
         namespace: val_str(v, "namespace")?,
-        tenant: val_str(v, "tenant")?,
+        tenant: val_opt_str(v, "tenant").unwrap_or_default(),
+        org: val_opt_str(v, "org"),
+        members: val_opt_vec_str(v, "members").unwrap_or_default(),
         provider: val_opt_str(v, "provider"),
         principal: val_opt_str(v, "principal"),
         per_principal: val_bool_or(v, "per_principal", false),
@@ -164,6 +174,7 @@ fn build_create_quota(v: &serde_json::Value) -> Result<CreateQuotaRequest, McpEr
         overage_behavior: val_str(v, "overage_behavior")?,
         description: val_opt_str(v, "description"),
         labels: val_opt_hashmap_str(v, "labels"),
+        alert_thresholds: Vec::new(),
     })
 }
 
@@ -178,6 +189,8 @@ fn build_update_quota(v: &serde_json::Value) -> Result<UpdateQuotaRequest, McpEr
         enabled: val_opt_bool(v, "enabled"),
         per_principal: val_opt_bool(v, "per_principal"),
         labels: val_opt_hashmap_str(v, "labels"),
+        alert_thresholds: None,
+        members: val_opt_vec_str(v, "members"),
     })
 }
 
@@ -287,6 +300,7 @@ fn build_create_profile(v: &serde_json::Value) -> Result<CreateProfileRequest, M
         namespace: val_str(v, "namespace")?,
         tenant: val_str(v, "tenant")?,
         fields: val_hashmap_json(v, "fields")?,
+        output_type: val_opt_str(v, "output_type"),
         description: val_opt_str(v, "description"),
         labels: val_opt_hashmap_str(v, "labels"),
     })
@@ -295,6 +309,7 @@ fn build_create_profile(v: &serde_json::Value) -> Result<CreateProfileRequest, M
 fn build_update_profile(v: &serde_json::Value) -> UpdateProfileRequest {
     UpdateProfileRequest {
         fields: val_opt_hashmap_json(v, "fields"),
+        output_type: val_opt_str(v, "output_type"),
         description: val_opt_str(v, "description"),
         labels: val_opt_hashmap_str(v, "labels"),
     }
@@ -1645,6 +1660,7 @@ impl ActeonMcpServer {
             to,
             group_by: p.group_by,
             top_n: p.top_n,
+            drill_into: None,
             // Authorization scope is applied server-side from grants; clients
             // never set it (the field is `#[serde(skip)]`).
             tenant_scope: Vec::new(),
@@ -2070,7 +2086,7 @@ impl ActeonMcpServer {
                 let id =
                     p.id.as_deref()
                         .ok_or_else(|| mcp_err("'id' is required for delete"))?;
-                match self.ops.delete_template(id).await {
+                match self.ops.delete_template(id, false).await {
                     Ok(()) => Ok(CallToolResult::success(vec![Content::text(format!(
                         "Template '{id}' deleted."
                     ))])),
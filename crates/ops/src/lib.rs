@@ -11,20 +11,22 @@ pub use config::OpsConfig;
 pub use error::OpsError;
 
 use acteon_client::{
-    ActeonClient, ActeonClientBuilder, ApprovalListResponse, AuditPage, AuditQuery, AuditRecord,
-    BatchResult, ChainDetailResponse, ChainHistoryResponse, ComplianceStatus, CoverageQuery,
-    CoverageReport, CreateProfileRequest, CreateQuotaRequest, CreateRecurringAction,
-    CreateRecurringResponse, CreateRetentionRequest, CreateSilenceRequest, CreateTemplateRequest,
-    DagResponse, DlqDrainResponse, DlqStatsResponse, EvaluateRulesOptions, EventListResponse,
-    EventQuery, EventState, FlushGroupResponse, GroupDetail, GroupListResponse,
-    HashChainVerification, ListChainDefinitionsResponse, ListChainsResponse, ListPluginsResponse,
-    ListProfilesResponse, ListQuotasResponse, ListRecurringResponse, ListSilencesQuery,
-    ListSilencesResponse, ListTemplatesResponse, QuotaPolicy, QuotaUsage, RecurringDetail,
-    RecurringFilter, ReloadResult, RenderPreviewRequest, RenderPreviewResponse, ReplayQuery,
-    ReplayResult, ReplaySummary, RetentionPolicy, RuleEvaluationTrace, RuleInfo, SilenceResponse,
-    TemplateInfo, TemplateProfileInfo, TransitionResponse, UpdateProfileRequest,
-    UpdateQuotaRequest, UpdateRecurringAction, UpdateRetentionRequest, UpdateSilenceRequest,
-    UpdateTemplateRequest, VerifyHashChainRequest,
+    ActeonClient, ActeonClientBuilder, ApprovalActionResponse, ApprovalListResponse,
+    ApprovalStatusResponse, AuditPage, AuditQuery, AuditRecord, BatchResult, ChainDetailResponse,
+    ChainHistoryResponse, ComplianceStatus, CoverageQuery, CoverageReport, CreateProfileRequest,
+    CreateQuotaRequest, CreateRecurringAction, CreateRecurringResponse, CreateRetentionRequest,
+    CreateSilenceRequest, CreateTemplateRequest, DagResponse, DlqDrainResponse, DlqStatsResponse,
+    EvaluateRulesOptions, EventListResponse, EventQuery, EventState, FlushGroupResponse,
+    GroupDetail, GroupListResponse, HashChainVerification, ListChainDefinitionsResponse,
+    ListChainsResponse, ListPluginsResponse, ListProfilesResponse, ListQuotasResponse,
+    ListRecurringResponse, ListSilencesQuery, ListSilencesResponse, ListTemplatesResponse,
+    PluginInvocationRequest, PluginInvocationResponse, QuotaPolicy, QuotaUsage, RecurringDetail,
+    RecurringFilter, RegisterPluginRequest, ReloadResult, RenderPreviewRequest,
+    RenderPreviewResponse, ReplayQuery, ReplayResult, ReplaySummary, RetentionPolicy,
+    RuleEvaluationTrace, RuleInfo, SilenceResponse, TemplateInfo, TemplateProfileInfo,
+    TemplateReference, TransitionResponse, UpdateProfileRequest, UpdateQuotaRequest,
+    UpdateRecurringAction, UpdateRetentionRequest, UpdateSilenceRequest, UpdateTemplateRequest,
+    VerifyHashChainRequest, WasmPlugin,
 };
 use acteon_core::{
     Action, ActionOutcome, CircuitBreakerActionResponse, ListCircuitBreakersResponse,
@@ -605,9 +607,14 @@ impl OpsClient {
         Ok(self.inner.update_template(id, update).await?)
     }
 
-    /// Delete a template.
-    pub async fn delete_template(&self, id: &str) -> Result<(), OpsError> {
-        Ok(self.inner.delete_template(id).await?)
+    /// List the profiles and rules that use a template.
+    pub async fn template_references(&self, id: &str) -> Result<Vec<TemplateReference>, OpsError> {
+        Ok(self.inner.template_references(id).await?)
+    }
+
+    /// Delete a template. Fails while it is referenced unless `force` is set.
+    pub async fn delete_template(&self, id: &str, force: bool) -> Result<(), OpsError> {
+        Ok(self.inner.delete_template(id, force).await?)
     }
 
     /// Create a template profile.
@@ -663,11 +670,33 @@ impl OpsClient {
         Ok(self.inner.list_plugins().await?)
     }
 
+    /// Register a WASM plugin from `wasm_bytes` or a server-side `wasm_path`.
+    pub async fn register_plugin(
+        &self,
+        req: &RegisterPluginRequest,
+    ) -> Result<WasmPlugin, OpsError> {
+        Ok(self.inner.register_plugin(req).await?)
+    }
+
+    /// Get a WASM plugin by name.
+    pub async fn get_plugin(&self, name: &str) -> Result<Option<WasmPlugin>, OpsError> {
+        Ok(self.inner.get_plugin(name).await?)
+    }
+
     /// Delete a WASM plugin.
     pub async fn delete_plugin(&self, name: &str) -> Result<(), OpsError> {
         Ok(self.inner.delete_plugin(name).await?)
     }
 
+    /// Invoke a WASM plugin.
+    pub async fn invoke_plugin(
+        &self,
+        name: &str,
+        req: &PluginInvocationRequest,
+    ) -> Result<PluginInvocationResponse, OpsError> {
+        Ok(self.inner.invoke_plugin(name, req).await?)
+    }
+
     // =========================================================================
     // Groups
     // =========================================================================
@@ -739,4 +768,97 @@ impl OpsClient {
     ) -> Result<ApprovalListResponse, OpsError> {
         Ok(self.inner.list_approvals(namespace, tenant).await?)
     }
+
+    /// Approve a pending action using its signed approval URL parameters.
+    pub async fn approve(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        id: &str,
+        sig: &str,
+        expires_at: i64,
+    ) -> Result<ApprovalActionResponse, OpsError> {
+        Ok(self
+            .inner
+            .approve(namespace, tenant, id, sig, expires_at)
+            .await?)
+    }
+
+    /// Approve a pending action, naming the key that signed the URL.
+    pub async fn approve_with_kid(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        id: &str,
+        sig: &str,
+        expires_at: i64,
+        kid: Option<&str>,
+    ) -> Result<ApprovalActionResponse, OpsError> {
+        Ok(self
+            .inner
+            .approve_with_kid(namespace, tenant, id, sig, expires_at, kid)
+            .await?)
+    }
+
+    /// Reject a pending action using its signed approval URL parameters.
+    pub async fn reject(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        id: &str,
+        sig: &str,
+        expires_at: i64,
+    ) -> Result<ApprovalActionResponse, OpsError> {
+        Ok(self
+            .inner
+            .reject(namespace, tenant, id, sig, expires_at)
+            .await?)
+    }
+
+    /// Reject a pending action, naming the key that signed the URL.
+    pub async fn reject_with_kid(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        id: &str,
+        sig: &str,
+        expires_at: i64,
+        kid: Option<&str>,
+    ) -> Result<ApprovalActionResponse, OpsError> {
+        Ok(self
+            .inner
+            .reject_with_kid(namespace, tenant, id, sig, expires_at, kid)
+            .await?)
+    }
+
+    /// Get the status of an approval. Returns `None` if it does not exist.
+    pub async fn get_approval(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        id: &str,
+        sig: &str,
+        expires_at: i64,
+    ) -> Result<Option<ApprovalStatusResponse>, OpsError> {
+        Ok(self
+            .inner
+            .get_approval(namespace, tenant, id, sig, expires_at)
+            .await?)
+    }
+
+    /// Get the status of an approval, naming the key that signed the URL.
+    pub async fn get_approval_with_kid(
+        &self,
+        namespace: &str,
+        tenant: &str,
+        id: &str,
+        sig: &str,
+        expires_at: i64,
+        kid: Option<&str>,
+    ) -> Result<Option<ApprovalStatusResponse>, OpsError> {
+        Ok(self
+            .inner
+            .get_approval_with_kid(namespace, tenant, id, sig, expires_at, kid)
+            .await?)
+    }
 }
@@ -1,12 +1,15 @@
-use acteon_core::ResolvedAttachment;
+use acteon_core::{FeatureFlags, ResolvedAttachment};
 
 /// Additional context passed to providers during dispatch.
 ///
 /// Contains resolved attachment data that providers can use if they
-/// support file attachments. Providers that don't support attachments
-/// can ignore this context entirely.
+/// support file attachments, and the feature flags of the action's tenant.
+/// Providers that need neither can ignore this context entirely.
 #[derive(Debug, Default)]
 pub struct DispatchContext {
     /// Resolved file attachments (decoded from `base64`).
     pub attachments: Vec<ResolvedAttachment>,
+    /// Feature flags of the action's `(namespace, tenant)`. Empty unless
+    /// the provider declares `uses_feature_flags`.
+    pub flags: FeatureFlags,
 }
@@ -26,4 +26,4 @@ pub use resource_lookup::ResourceLookup;
 #[cfg(feature = "trace-context")]
 pub mod trace_context;
 #[cfg(feature = "trace-context")]
-pub use trace_context::inject_trace_context;
+pub use trace_context::{inject_action_trace_context, inject_trace_context};
@@ -31,6 +31,15 @@ pub trait Provider: Send + Sync {
         false
     }
 
+    /// Whether this provider reads [`DispatchContext::flags`].
+    ///
+    /// Defaults to `false`. The gateway only passes the tenant's feature
+    /// flags, and only takes the context-aware path for them, when this
+    /// returns `true`.
+    fn uses_feature_flags(&self) -> bool {
+        false
+    }
+
     /// Execute the given action with additional dispatch context (e.g. resolved attachments).
     ///
     /// The default implementation ignores the context and delegates to [`execute`](Self::execute).
@@ -66,6 +75,11 @@ pub trait DynProvider: Send + Sync {
         false
     }
 
+    /// Whether this provider reads [`DispatchContext::flags`].
+    fn uses_feature_flags(&self) -> bool {
+        false
+    }
+
     /// Execute the given action with additional dispatch context.
     async fn execute_with_context(
         &self,
@@ -96,6 +110,10 @@ impl<T: Provider + Sync> DynProvider for T {
         Provider::supports_attachments(self)
     }
 
+    fn uses_feature_flags(&self) -> bool {
+        Provider::uses_feature_flags(self)
+    }
+
     async fn execute_with_context(
         &self,
         action: &Action,
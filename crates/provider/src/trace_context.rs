@@ -6,6 +6,7 @@
 //! When OpenTelemetry is disabled (no global propagator registered), the
 //! injector is a no-op and adds zero headers.
 
+use acteon_core::Action;
 use opentelemetry::propagation::Injector;
 use opentelemetry::{Context, global};
 
@@ -44,3 +45,69 @@ pub fn inject_trace_context(builder: reqwest::RequestBuilder) -> reqwest::Reques
     }
     builder
 }
+
+/// Inject trace context for an action into a [`reqwest::RequestBuilder`].
+///
+/// Uses the current span's context when OpenTelemetry is enabled, so the
+/// downstream service sees Acteon's span as its parent. Otherwise, falls back
+/// to the caller-supplied `traceparent` and `tracestate` in the action's
+/// metadata, which keeps the upstream trace connected without a tracer.
+pub fn inject_action_trace_context(
+    builder: reqwest::RequestBuilder,
+    action: &Action,
+) -> reqwest::RequestBuilder {
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cx = Context::current();
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&cx, &mut HeaderInjector(&mut headers));
+    });
+
+    if !headers.contains_key("traceparent")
+        && let Some(traceparent) = &action.metadata.traceparent
+    {
+        let mut injector = HeaderInjector(&mut headers);
+        injector.set("traceparent", traceparent.clone());
+        if let Some(tracestate) = &action.metadata.tracestate {
+            injector.set("tracestate", tracestate.clone());
+        }
+    }
+
+    let mut builder = builder;
+    for (name, value) in headers {
+        if let Some(name) = name {
+            builder = builder.header(name, value);
+        }
+    }
+    builder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    fn build(action: &Action) -> reqwest::Request {
+        let client = reqwest::Client::new();
+        inject_action_trace_context(client.post("http://localhost/hook"), action)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn action_traceparent_becomes_header() {
+        let action = Action::new("ns", "t", "webhook", "notify", serde_json::json!({}))
+            .with_traceparent(TRACEPARENT, Some("vendor=1".into()));
+        let request = build(&action);
+        assert_eq!(request.headers()["traceparent"], TRACEPARENT);
+        assert_eq!(request.headers()["tracestate"], "vendor=1");
+    }
+
+    #[test]
+    fn no_trace_headers_without_context() {
+        let action = Action::new("ns", "t", "webhook", "notify", serde_json::json!({}));
+        let request = build(&action);
+        assert!(request.headers().get("traceparent").is_none());
+        assert!(request.headers().get("tracestate").is_none());
+    }
+}
@@ -4,7 +4,7 @@ use std::path::Path;
 
 use serde::Deserialize;
 
-use acteon_rules::ir::rule::{Rule, RuleAction, RuleSource};
+use acteon_rules::ir::rule::{FollowOnAction, Rule, RuleAction, RuleSource};
 use acteon_rules::{RuleError, RuleFrontend};
 
 use crate::parser::parse_cel_expr;
@@ -54,6 +54,12 @@ struct CelRule {
     /// Names of time intervals during which this rule is active.
     #[serde(default)]
     active_time_intervals: Vec<String>,
+    /// Follow-on action dispatched after a successful execution.
+    #[serde(default)]
+    on_success: Option<FollowOnAction>,
+    /// Follow-on action dispatched after a failed execution.
+    #[serde(default)]
+    on_failure: Option<FollowOnAction>,
 }
 
 /// The action to take when a rule fires.
@@ -68,13 +74,23 @@ enum CelAction {
     Deduplicate {
         /// Time-to-live in seconds for deduplication state.
         ttl_seconds: Option<u64>,
+        /// Whether the TTL window is fixed or slides with each occurrence.
+        #[serde(default)]
+        window: acteon_core::DedupWindow,
     },
     /// Suppress the action entirely.
     Suppress,
-    /// Reroute the action to a different provider.
+    /// Reroute the action to a different provider, namespace, or tenant.
     Reroute {
         /// The target provider to route to.
-        target_provider: String,
+        #[serde(default)]
+        target_provider: Option<String>,
+        /// The target namespace.
+        #[serde(default)]
+        target_namespace: Option<String>,
+        /// The target tenant.
+        #[serde(default)]
+        target_tenant: Option<String>,
     },
     /// Throttle the action based on a sliding window.
     Throttle {
@@ -144,7 +160,7 @@ impl RuleFrontend for CelFrontend {
 /// Compile a single [`CelRule`] into the IR [`Rule`].
 fn compile_rule(cel: CelRule, file: Option<&Path>) -> Result<Rule, RuleError> {
     let condition = parse_cel_expr(&cel.condition)?;
-    let action = compile_action(&cel.action);
+    let action = compile_action(&cel.action)?;
     let source = RuleSource::Yaml {
         file: file.map(|p| p.display().to_string()),
     };
@@ -162,6 +178,8 @@ fn compile_rule(cel: CelRule, file: Option<&Path>) -> Result<Rule, RuleError> {
         timezone: None,
         mute_time_intervals: cel.mute_time_intervals,
         active_time_intervals: cel.active_time_intervals,
+        on_success: cel.on_success,
+        on_failure: cel.on_failure,
     };
     if let Some(tz) = cel.timezone {
         rule = rule.with_timezone(tz);
@@ -170,16 +188,36 @@ fn compile_rule(cel: CelRule, file: Option<&Path>) -> Result<Rule, RuleError> {
 }
 
 /// Compile a [`CelAction`] into a [`RuleAction`].
-fn compile_action(action: &CelAction) -> RuleAction {
-    match action {
+fn compile_action(action: &CelAction) -> Result<RuleAction, RuleError> {
+    let compiled = match action {
         CelAction::Allow => RuleAction::Allow,
         CelAction::Deny => RuleAction::Deny,
-        CelAction::Deduplicate { ttl_seconds } => RuleAction::Deduplicate {
+        CelAction::Deduplicate {
+            ttl_seconds,
+            window,
+        } => RuleAction::Deduplicate {
             ttl_seconds: *ttl_seconds,
+            window: *window,
         },
         CelAction::Suppress => RuleAction::Suppress,
-        CelAction::Reroute { target_provider } => RuleAction::Reroute {
+        CelAction::Reroute {
+            target_provider: None,
+            target_namespace: None,
+            target_tenant: None,
+        } => {
+            return Err(RuleError::Parse(
+                "reroute requires at least one of target_provider, target_namespace, or target_tenant"
+                    .to_owned(),
+            ));
+        }
+        CelAction::Reroute {
+            target_provider,
+            target_namespace,
+            target_tenant,
+        } => RuleAction::Reroute {
             target_provider: target_provider.clone(),
+            target_namespace: target_namespace.clone(),
+            target_tenant: target_tenant.clone(),
         },
         CelAction::Throttle {
             max_count,
@@ -197,7 +235,8 @@ fn compile_action(action: &CelAction) -> RuleAction {
         CelAction::Schedule { delay_seconds } => RuleAction::Schedule {
             delay_seconds: *delay_seconds,
         },
-    }
+    };
+    Ok(compiled)
 }
 
 // ---------------------------------------------------------------------------
@@ -547,7 +586,7 @@ rules:
 
         let verdict = engine.evaluate(&ctx).await.unwrap();
         match verdict {
-            RuleVerdict::Deduplicate { ttl_seconds } => {
+            RuleVerdict::Deduplicate { ttl_seconds, .. } => {
                 assert_eq!(ttl_seconds, Some(300));
             }
             other => panic!("expected Deduplicate, got {other:?}"),
@@ -654,9 +693,10 @@ rules:
             RuleVerdict::Reroute {
                 rule,
                 target_provider,
+                ..
             } => {
                 assert_eq!(rule, "high-priority-email");
-                assert_eq!(target_provider, "express-email");
+                assert_eq!(target_provider.as_deref(), Some("express-email"));
             }
             other => panic!("expected Reroute, got {other:?}"),
         }
@@ -744,6 +784,52 @@ rules:
         assert!(matches!(verdict, RuleVerdict::Allow(_)));
     }
 
+    #[tokio::test]
+    async fn e2e_flags_enabled_method() {
+        let fe = CelFrontend;
+        let content = r#"
+rules:
+  - name: new-vendor
+    priority: 1
+    condition: 'flags.enabled("new_sms_vendor")'
+    action:
+      type: reroute
+      target_provider: sms-v2
+"#;
+        let rules = fe.parse(content).unwrap();
+        let engine = RuleEngine::new(rules);
+
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+
+        let ctx = EvalContext::new(&action, &store, &env);
+        let verdict = engine.evaluate(&ctx).await.unwrap();
+        assert!(matches!(verdict, RuleVerdict::Allow(_)));
+
+        let flags: acteon_core::FeatureFlags = [("new_sms_vendor", true)].into_iter().collect();
+        let ctx = EvalContext::new(&action, &store, &env).with_flags(&flags);
+        let verdict = engine.evaluate(&ctx).await.unwrap();
+        assert!(
+            matches!(verdict, RuleVerdict::Reroute { .. }),
+            "{verdict:?}"
+        );
+    }
+
+    #[test]
+    fn reroute_without_target_rejected() {
+        let fe = CelFrontend;
+        let content = r#"
+rules:
+  - name: reroute-nowhere
+    condition: 'action.action_type == "send_sms"'
+    action:
+      type: reroute
+"#;
+        let result = fe.parse(content);
+        assert!(matches!(result, Err(RuleError::Parse(_))));
+    }
+
     #[tokio::test]
     async fn e2e_negation() {
         let fe = CelFrontend;
@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use crate::engine::verdict::RuleVerdict;
+
+/// Rule name carried by the verdict of a pass that ran out of budget before
+/// any rule matched, so the cutoff shows up in audit records and outcomes.
+pub const BUDGET_EXCEEDED_RULE: &str = "(evaluation budget exceeded)";
+
+/// Limits on how much work a single rule evaluation pass may do.
+///
+/// When either limit is reached, evaluation stops before the next rule. If
+/// nothing matched yet, the engine returns [`EvalBudget::exhausted_verdict`]:
+/// `Allow` by default, or `Deny` when the budget is fail-closed, both naming
+/// [`BUDGET_EXCEEDED_RULE`]. An unset limit is unbounded; the default budget
+/// has no limits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EvalBudget {
+    /// Maximum number of rule conditions to evaluate.
+    pub max_rules: Option<usize>,
+    /// Maximum wall-clock time for the whole pass.
+    pub max_duration: Option<Duration>,
+    /// Deny, rather than allow, actions whose pass ran out of budget.
+    pub fail_closed: bool,
+}
+
+impl EvalBudget {
+    /// A budget with no limits.
+    #[must_use]
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Limit the number of rule conditions evaluated.
+    #[must_use]
+    pub fn with_max_rules(mut self, max_rules: usize) -> Self {
+        self.max_rules = Some(max_rules);
+        self
+    }
+
+    /// Limit the wall-clock time of a pass.
+    #[must_use]
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+
+    /// Deny actions whose pass runs out of budget instead of allowing them.
+    #[must_use]
+    pub fn with_fail_closed(mut self, fail_closed: bool) -> Self {
+        self.fail_closed = fail_closed;
+        self
+    }
+
+    /// The verdict for a pass that ran out of budget before a rule matched.
+    #[must_use]
+    pub fn exhausted_verdict(&self) -> RuleVerdict {
+        if self.fail_closed {
+            RuleVerdict::Deny(BUDGET_EXCEEDED_RULE.to_owned())
+        } else {
+            RuleVerdict::Allow(Some(BUDGET_EXCEEDED_RULE.to_owned()))
+        }
+    }
+
+    /// Returns `true` if neither limit is set.
+    #[must_use]
+    pub fn is_unlimited(&self) -> bool {
+        self.max_rules.is_none() && self.max_duration.is_none()
+    }
+
+    /// Returns `true` if a pass that has evaluated `evaluated` rules over
+    /// `elapsed` may not evaluate another.
+    pub(crate) fn exhausted(&self, evaluated: usize, elapsed: Duration) -> bool {
+        self.max_rules.is_some_and(|max| evaluated >= max)
+            || self.max_duration.is_some_and(|max| elapsed >= max)
+    }
+}
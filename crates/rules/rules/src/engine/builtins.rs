@@ -3,6 +3,7 @@
 use chrono::Utc;
 use regex::Regex;
 
+use crate::engine::units::UnitFamily;
 use crate::engine::value::Value;
 use crate::error::RuleError;
 
@@ -24,6 +25,9 @@ pub fn call_builtin(name: &str, args: &[Value]) -> Result<Value, RuleError> {
         "max" => builtin_max(args),
         "to_string" => builtin_to_string(args),
         "to_int" => builtin_to_int(args),
+        "enabled" => builtin_enabled(args),
+        "parse_size" => builtin_parse_unit(UnitFamily::Size, args),
+        "parse_duration" => builtin_parse_unit(UnitFamily::Duration, args),
         _ => Err(RuleError::UndefinedFunction(name.to_owned())),
     }
 }
@@ -93,6 +97,25 @@ fn builtin_contains(args: &[Value]) -> Result<Value, RuleError> {
     }
 }
 
+/// `enabled(flags, name)` - check whether a feature flag is on.
+///
+/// Missing flags are disabled, so `flags.enabled("x")` is safe to use for
+/// tenants that have never set `x`.
+fn builtin_enabled(args: &[Value]) -> Result<Value, RuleError> {
+    expect_args("enabled", args, 2)?;
+    match (&args[0], &args[1]) {
+        (Value::Map(flags), Value::String(name)) => Ok(Value::Bool(matches!(
+            flags.get(name.as_str()),
+            Some(Value::Bool(true))
+        ))),
+        (a, b) => Err(RuleError::TypeError(format!(
+            "enabled() expects (map, string), got ({}, {})",
+            a.type_name(),
+            b.type_name()
+        ))),
+    }
+}
+
 /// `starts_with(string, prefix)` - check if a string starts with a prefix.
 fn builtin_starts_with(args: &[Value]) -> Result<Value, RuleError> {
     expect_args("starts_with", args, 2)?;
@@ -157,6 +180,32 @@ fn builtin_duration(args: &[Value]) -> Result<Value, RuleError> {
     }
 }
 
+/// `parse_size(value)` / `parse_duration(value)` - parse a human-readable
+/// quantity such as `"1.5GB"` or `"30m"` into bytes or seconds.
+///
+/// Numbers pass through unchanged. Whole results are returned as integers.
+#[allow(clippy::cast_possible_truncation)]
+fn builtin_parse_unit(family: UnitFamily, args: &[Value]) -> Result<Value, RuleError> {
+    let name = family.builtin_name();
+    expect_args(name, args, 1)?;
+    let quantity = match &args[0] {
+        Value::Int(n) => return Ok(Value::Int(*n)),
+        Value::Float(f) => *f,
+        Value::String(s) => family.parse(s)?,
+        other => {
+            return Err(RuleError::TypeError(format!(
+                "{name}() expects string or number, got {}",
+                other.type_name()
+            )));
+        }
+    };
+    if quantity.fract() == 0.0 && quantity.abs() < 9.0e18 {
+        Ok(Value::Int(quantity as i64))
+    } else {
+        Ok(Value::Float(quantity))
+    }
+}
+
 /// `format(template, args...)` - simple string formatting.
 /// The template uses `{}` as placeholders, replaced left-to-right.
 fn builtin_format(args: &[Value]) -> Result<Value, RuleError> {
@@ -469,6 +518,37 @@ mod tests {
         assert!(matches!(result, Err(RuleError::UndefinedFunction(_))));
     }
 
+    #[test]
+    fn enabled_flag_lookup() {
+        let mut m = std::collections::HashMap::new();
+        m.insert("on".to_owned(), Value::Bool(true));
+        m.insert("off".to_owned(), Value::Bool(false));
+        let flags = Value::Map(m);
+
+        let on = call_builtin("enabled", &[flags.clone(), Value::String("on".into())]);
+        assert_eq!(on.unwrap(), Value::Bool(true));
+        let off = call_builtin("enabled", &[flags.clone(), Value::String("off".into())]);
+        assert_eq!(off.unwrap(), Value::Bool(false));
+        let missing = call_builtin("enabled", &[flags, Value::String("nope".into())]);
+        assert_eq!(missing.unwrap(), Value::Bool(false));
+
+        let bad = call_builtin("enabled", &[Value::Int(1), Value::String("on".into())]);
+        assert!(matches!(bad, Err(RuleError::TypeError(_))));
+    }
+
+    #[test]
+    fn parse_size_and_duration() {
+        let size = call_builtin("parse_size", &[Value::String("1.5GB".into())]);
+        assert_eq!(size.unwrap(), Value::Int(1_500_000_000));
+        let ms = call_builtin("parse_duration", &[Value::String("250ms".into())]);
+        assert_eq!(ms.unwrap(), Value::Float(0.25));
+        let raw = call_builtin("parse_duration", &[Value::Int(90)]);
+        assert_eq!(raw.unwrap(), Value::Int(90));
+
+        let bad = call_builtin("parse_size", &[Value::String("huge".into())]);
+        assert!(matches!(bad, Err(RuleError::TypeError(_))));
+    }
+
     #[test]
     fn wrong_arg_count() {
         let result = call_builtin("len", &[]);
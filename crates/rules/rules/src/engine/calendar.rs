@@ -0,0 +1,188 @@
+//! Business-hours evaluation and named holiday calendars.
+//!
+//! `business_hours(start, end, weekdays, calendar)` is true when the
+//! evaluation time — in the rule's timezone — falls on one of `weekdays`
+//! (ISO numbers, 1=Monday … 7=Sunday), within `[start, end)` (`"HH:MM"`),
+//! and the local date is not listed in the named holiday calendar.
+//! `calendar` may be null to ignore holidays.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Datelike as _, NaiveDate, NaiveTime, Timelike as _};
+
+use crate::engine::context::EvalContext;
+use crate::engine::value::Value;
+use crate::error::RuleError;
+
+/// Name of the context-aware built-in evaluated by [`eval_business_hours`].
+pub const BUSINESS_HOURS_FN: &str = "business_hours";
+
+/// Named holiday calendars, each a set of dates (typically one per region).
+#[derive(Debug, Clone, Default)]
+pub struct HolidayCalendars {
+    calendars: HashMap<String, HashSet<NaiveDate>>,
+}
+
+impl HolidayCalendars {
+    /// Create an empty set of calendars.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add (or replace) the calendar `name` with the given dates.
+    #[must_use]
+    pub fn with_calendar(
+        mut self,
+        name: impl Into<String>,
+        dates: impl IntoIterator<Item = NaiveDate>,
+    ) -> Self {
+        self.insert(name, dates);
+        self
+    }
+
+    /// Add (or replace) the calendar `name` with the given dates.
+    pub fn insert(&mut self, name: impl Into<String>, dates: impl IntoIterator<Item = NaiveDate>) {
+        self.calendars
+            .insert(name.into(), dates.into_iter().collect());
+    }
+
+    /// Whether `date` is a holiday in calendar `name`, or `None` if no
+    /// such calendar is configured.
+    pub fn is_holiday(&self, name: &str, date: NaiveDate) -> Option<bool> {
+        self.calendars.get(name).map(|dates| dates.contains(&date))
+    }
+
+    /// Returns `true` if no calendars are configured.
+    pub fn is_empty(&self) -> bool {
+        self.calendars.is_empty()
+    }
+}
+
+/// Evaluate `business_hours(start, end, weekdays, calendar)` against the
+/// context's clock, timezone, and holiday calendars.
+pub(crate) fn eval_business_hours(
+    args: &[Value],
+    ctx: &EvalContext<'_>,
+) -> Result<Value, RuleError> {
+    let [start, end, weekdays, calendar] = args else {
+        return Err(RuleError::TypeError(format!(
+            "{BUSINESS_HOURS_FN}() expects 4 argument(s), got {}",
+            args.len()
+        )));
+    };
+    let start = parse_time(start)?;
+    let end = parse_time(end)?;
+    let Value::List(weekdays) = weekdays else {
+        return Err(RuleError::TypeError(format!(
+            "{BUSINESS_HOURS_FN}() expects a list of weekdays, got {}",
+            weekdays.type_name()
+        )));
+    };
+
+    let local = match ctx.timezone {
+        Some(tz) => ctx.now.with_timezone(&tz).naive_local(),
+        None => ctx.now.naive_utc(),
+    };
+
+    let weekday = i64::from(local.weekday().number_from_monday());
+    if !weekdays
+        .iter()
+        .any(|d| matches!(d, Value::Int(n) if *n == weekday))
+    {
+        return Ok(Value::Bool(false));
+    }
+
+    let time = NaiveTime::from_hms_opt(local.hour(), local.minute(), 0).unwrap_or_default();
+    if time < start || time >= end {
+        return Ok(Value::Bool(false));
+    }
+
+    match calendar {
+        Value::Null => Ok(Value::Bool(true)),
+        Value::String(name) => {
+            let holiday = ctx
+                .holidays
+                .and_then(|h| h.is_holiday(name, local.date()))
+                .ok_or_else(|| {
+                    RuleError::Evaluation(format!("unknown holiday calendar: {name}"))
+                })?;
+            Ok(Value::Bool(!holiday))
+        }
+        other => Err(RuleError::TypeError(format!(
+            "{BUSINESS_HOURS_FN}() expects a calendar name or null, got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+fn parse_time(value: &Value) -> Result<NaiveTime, RuleError> {
+    match value {
+        Value::String(s) => NaiveTime::parse_from_str(s, "%H:%M").map_err(|_| {
+            RuleError::TypeError(format!(
+                "{BUSINESS_HOURS_FN}() expects times as \"HH:MM\", got \"{s}\""
+            ))
+        }),
+        other => Err(RuleError::TypeError(format!(
+            "{BUSINESS_HOURS_FN}() expects times as \"HH:MM\", got {}",
+            other.type_name()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use acteon_core::Action;
+    use acteon_state_memory::MemoryStateStore;
+    use chrono::TimeZone as _;
+
+    use super::*;
+
+    fn args(calendar: Value) -> Vec<Value> {
+        vec![
+            Value::String("09:00".into()),
+            Value::String("17:00".into()),
+            Value::List((1..=5).map(Value::Int).collect()),
+            calendar,
+        ]
+    }
+
+    #[test]
+    fn configured_holiday_is_not_business_hours() {
+        let action = Action::new("ns", "t", "email", "send", serde_json::json!({}));
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        let holidays = HolidayCalendars::new()
+            .with_calendar("us", [NaiveDate::from_ymd_opt(2026, 12, 25).unwrap()]);
+
+        // Friday 2026-12-25 10:00 New York time.
+        let christmas = chrono_tz::America::New_York
+            .with_ymd_and_hms(2026, 12, 25, 10, 0, 0)
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let ctx = EvalContext::new(&action, &store, &env)
+            .with_now(christmas)
+            .with_timezone(chrono_tz::America::New_York)
+            .with_holidays(&holidays);
+        assert_eq!(
+            eval_business_hours(&args(Value::String("us".into())), &ctx).unwrap(),
+            Value::Bool(false)
+        );
+        // Without a calendar the same instant is an ordinary Friday morning.
+        assert_eq!(
+            eval_business_hours(&args(Value::Null), &ctx).unwrap(),
+            Value::Bool(true)
+        );
+
+        // The next business day is unaffected.
+        let monday = christmas + chrono::Duration::days(3);
+        let ctx = ctx.with_now(monday);
+        assert_eq!(
+            eval_business_hours(&args(Value::String("us".into())), &ctx).unwrap(),
+            Value::Bool(true)
+        );
+
+        assert!(eval_business_hours(&args(Value::String("uk".into())), &ctx).is_err());
+    }
+}
@@ -6,10 +6,11 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use chrono_tz::Tz;
 
-use acteon_core::Action;
+use acteon_core::{Action, FeatureFlags};
 use acteon_state::StateStore;
 use acteon_wasm_runtime::WasmPluginRuntime;
 
+use crate::engine::calendar::HolidayCalendars;
 use crate::error::RuleError;
 
 /// Keys that were actually accessed during rule evaluation.
@@ -179,6 +180,14 @@ pub struct EvalContext<'a> {
     /// When set, `eval_wasm_call` increments these counters so the gateway
     /// can propagate totals to its own metrics after evaluation completes.
     pub wasm_counters: Option<Arc<WasmEvalCounters>>,
+    /// Feature flags of the action's tenant, exposed to conditions as
+    /// `flags.<name>`. When `None`, every flag reads as disabled.
+    pub flags: Option<&'a FeatureFlags>,
+    /// Outputs of the enrichments that ran for this action, keyed by
+    /// enrichment name and exposed to conditions as `enrichment.<name>`.
+    pub enrichment: Option<&'a HashMap<String, serde_json::Value>>,
+    /// Named holiday calendars consulted by `business_hours` conditions.
+    pub holidays: Option<&'a HolidayCalendars>,
 }
 
 impl<'a> EvalContext<'a> {
@@ -199,6 +208,9 @@ impl<'a> EvalContext<'a> {
             wasm_runtime: None,
             access_tracker: None,
             wasm_counters: None,
+            flags: None,
+            enrichment: None,
+            holidays: None,
         }
     }
 
@@ -245,4 +257,25 @@ impl<'a> EvalContext<'a> {
         self.wasm_counters = Some(counters);
         self
     }
+
+    /// Set the tenant feature flags visible to rule conditions.
+    #[must_use]
+    pub fn with_flags(mut self, flags: &'a FeatureFlags) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Set the holiday calendars consulted by `business_hours` conditions.
+    #[must_use]
+    pub fn with_holidays(mut self, holidays: &'a HolidayCalendars) -> Self {
+        self.holidays = Some(holidays);
+        self
+    }
+
+    /// Set the enrichment outputs visible to rule conditions.
+    #[must_use]
+    pub fn with_enrichment(mut self, outputs: &'a HashMap<String, serde_json::Value>) -> Self {
+        self.enrichment = Some(outputs);
+        self
+    }
 }
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use regex::Regex;
 
 use crate::engine::builtins::call_builtin;
+use crate::engine::calendar::{BUSINESS_HOURS_FN, eval_business_hours};
 use crate::engine::context::EvalContext;
 use crate::engine::ops_event::{eval_event_in_state, eval_get_event_state, eval_has_active_event};
 use crate::engine::ops_semantic::eval_semantic_match;
@@ -76,6 +77,9 @@ pub async fn eval(expr: &Expr, ctx: &EvalContext<'_>) -> Result<Value, RuleError
             for arg in args {
                 evaluated_args.push(Box::pin(eval(arg, ctx)).await?);
             }
+            if name == BUSINESS_HOURS_FN {
+                return eval_business_hours(&evaluated_args, ctx);
+            }
             call_builtin(name, &evaluated_args)
         }
 
@@ -177,6 +181,24 @@ pub(crate) fn resolve_ident(name: &str, ctx: &EvalContext<'_>) -> Result<Value,
             .time_map_cache
             .get_or_init(|| build_time_map(ctx))
             .clone()),
+        "flags" => {
+            let map: HashMap<String, Value> = ctx
+                .flags
+                .into_iter()
+                .flat_map(acteon_core::FeatureFlags::iter)
+                .map(|(name, enabled)| (name.to_owned(), Value::Bool(enabled)))
+                .collect();
+            Ok(Value::Map(map))
+        }
+        "enrichment" => {
+            let map: HashMap<String, Value> = ctx
+                .enrichment
+                .into_iter()
+                .flatten()
+                .map(|(name, output)| (name.clone(), Value::from_json(output.clone())))
+                .collect();
+            Ok(Value::Map(map))
+        }
         _ => {
             // Try environment lookup as a shortcut.
             if let Some(val) = ctx.environment.get(name) {
@@ -1,8 +1,12 @@
+use std::collections::VecDeque;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use tracing::{debug, instrument};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tracing::{debug, instrument, warn};
 
+use crate::engine::budget::EvalBudget;
 use crate::engine::context::{AccessTracker, EvalContext};
 use crate::engine::eval::{build_time_map, eval};
 use crate::engine::trace::{RuleEvaluationTrace, RuleTraceEntry, RuleTraceResult, TraceContext};
@@ -10,13 +14,47 @@ use crate::engine::verdict::{RuleVerdict, action_to_verdict};
 use crate::error::RuleError;
 use crate::ir::rule::Rule;
 
+/// Number of replaced rule sets a [`RuleEngine`] keeps for rollback by default.
+pub const DEFAULT_RULE_HISTORY: usize = 5;
+
+/// Summary of one rule set version known to a [`RuleEngine`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleSetVersion {
+    /// Version id, assigned in load order starting at 1.
+    pub version: u64,
+    /// When this rule set was loaded.
+    pub loaded_at: DateTime<Utc>,
+    /// Number of rules in the set.
+    pub rule_count: usize,
+    /// Whether this is the rule set currently being evaluated.
+    pub active: bool,
+}
+
+/// A replaced rule set retained for rollback.
+struct RuleSetSnapshot {
+    version: u64,
+    loaded_at: DateTime<Utc>,
+    rules: Vec<Rule>,
+}
+
 /// The rule engine evaluates a set of rules against an evaluation context.
 ///
 /// Rules are evaluated in priority order (lower priority number first).
 /// The first matching rule determines the verdict. If no rule matches,
-/// the default verdict is `Allow`.
+/// the default verdict is `Allow`. An [`EvalBudget`] can bound how many
+/// rules a single pass evaluates.
+///
+/// Replacing the rule set with [`load_rules`](Self::load_rules) keeps the
+/// previous set as a versioned snapshot, so a bad reload can be undone with
+/// [`rollback_rules`](Self::rollback_rules).
 pub struct RuleEngine {
     rules: Vec<Rule>,
+    budget: EvalBudget,
+    version: u64,
+    loaded_at: DateTime<Utc>,
+    next_version: u64,
+    history: VecDeque<RuleSetSnapshot>,
+    history_limit: usize,
 }
 
 impl RuleEngine {
@@ -25,7 +63,106 @@ impl RuleEngine {
     /// Rules are automatically sorted by priority (lower number = higher priority).
     pub fn new(mut rules: Vec<Rule>) -> Self {
         rules.sort_by_key(|r| r.priority);
-        Self { rules }
+        Self {
+            rules,
+            budget: EvalBudget::default(),
+            version: 1,
+            loaded_at: Utc::now(),
+            next_version: 2,
+            history: VecDeque::new(),
+            history_limit: DEFAULT_RULE_HISTORY,
+        }
+    }
+
+    /// Set how many replaced rule sets are kept for rollback. Zero disables
+    /// rollback.
+    #[must_use]
+    pub fn with_history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = limit;
+        self.history.truncate(limit);
+        self
+    }
+
+    /// Replace the rule set, keeping the current one for rollback.
+    ///
+    /// Rules are re-sorted by priority. Returns the version id assigned to
+    /// the new set.
+    pub fn load_rules(&mut self, mut rules: Vec<Rule>) -> u64 {
+        rules.sort_by_key(|r| r.priority);
+        let version = self.next_version;
+        self.next_version += 1;
+        self.install(version, Utc::now(), rules);
+        version
+    }
+
+    /// Restore a previously loaded rule set by version id.
+    ///
+    /// The restored set keeps its original version id and load time, and
+    /// the set it replaces is retained so the rollback can itself be undone.
+    /// Returns [`RuleError::UnknownVersion`] if `version` is not retained.
+    pub fn rollback_rules(&mut self, version: u64) -> Result<(), RuleError> {
+        if version == self.version {
+            return Ok(());
+        }
+        let snapshot = self
+            .history
+            .iter()
+            .position(|s| s.version == version)
+            .and_then(|index| self.history.remove(index))
+            .ok_or(RuleError::UnknownVersion(version))?;
+        self.install(snapshot.version, snapshot.loaded_at, snapshot.rules);
+        Ok(())
+    }
+
+    /// Return the active rule set version followed by retained ones, most
+    /// recently replaced first.
+    pub fn rule_set_versions(&self) -> Vec<RuleSetVersion> {
+        let active = RuleSetVersion {
+            version: self.version,
+            loaded_at: self.loaded_at,
+            rule_count: self.rules.len(),
+            active: true,
+        };
+        std::iter::once(active)
+            .chain(self.history.iter().map(|s| RuleSetVersion {
+                version: s.version,
+                loaded_at: s.loaded_at,
+                rule_count: s.rules.len(),
+                active: false,
+            }))
+            .collect()
+    }
+
+    /// Return the version id of the active rule set.
+    pub fn rule_set_version(&self) -> u64 {
+        self.version
+    }
+
+    /// Swap in a rule set, pushing the current one onto the history.
+    fn install(&mut self, version: u64, loaded_at: DateTime<Utc>, rules: Vec<Rule>) {
+        let previous = RuleSetSnapshot {
+            version: self.version,
+            loaded_at: self.loaded_at,
+            rules: std::mem::replace(&mut self.rules, rules),
+        };
+        self.version = version;
+        self.loaded_at = loaded_at;
+        if self.history_limit > 0 {
+            self.history.push_front(previous);
+            self.history.truncate(self.history_limit);
+        }
+    }
+
+    /// Set the evaluation budget applied to every pass.
+    #[must_use]
+    pub fn with_budget(mut self, budget: EvalBudget) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Return the evaluation budget.
+    pub fn budget(&self) -> EvalBudget {
+        self.budget
     }
 
     /// Return a reference to the sorted rules.
@@ -64,14 +201,33 @@ impl RuleEngine {
     /// Returns the verdict from the first matching rule, or `Allow` if no
     /// rule matches. If a rule has a `timezone` field, its `time.*` fields
     /// are evaluated in that timezone instead of the context default.
-    #[instrument(skip_all, fields(rules_count = self.rules.len(), rule.matched))]
+    ///
+    /// If the evaluation budget runs out before a rule matches, the
+    /// remaining rules are not evaluated and the result is the budget's
+    /// [`EvalBudget::exhausted_verdict`].
+    #[instrument(skip_all, fields(rules_count = self.rules.len(), rule.matched, budget_exceeded))]
     pub async fn evaluate(&self, ctx: &EvalContext<'_>) -> Result<RuleVerdict, RuleError> {
+        let start = std::time::Instant::now();
+        let mut evaluated: usize = 0;
         for rule in &self.rules {
             if !rule.enabled {
                 debug!(rule = %rule.name, "skipping disabled rule");
                 continue;
             }
 
+            if self.budget.exhausted(evaluated, start.elapsed()) {
+                let verdict = self.budget.exhausted_verdict();
+                warn!(
+                    evaluated,
+                    next_rule = %rule.name,
+                    verdict = verdict.as_tag(),
+                    "rule evaluation budget exceeded"
+                );
+                tracing::Span::current().record("budget_exceeded", true);
+                return Ok(verdict);
+            }
+            evaluated += 1;
+
             // If the rule has a per-rule timezone override, create a modified
             // context with that timezone for this rule's evaluation.
             let rule_tz = if let Some(ref tz_name) = rule.timezone {
@@ -97,6 +253,9 @@ impl RuleEngine {
                     access_tracker: None,
                     wasm_runtime: ctx.wasm_runtime.clone(),
                     wasm_counters: ctx.wasm_counters.clone(),
+                    flags: ctx.flags,
+                    enrichment: ctx.enrichment,
+                    holidays: ctx.holidays,
                 };
                 &eval_ctx
             } else {
@@ -174,6 +333,7 @@ impl RuleEngine {
         let mut total_skipped: usize = 0;
         let mut has_errors = false;
         let mut errors_before_match = false;
+        let mut budget_exceeded = false;
 
         // Create a shared access tracker so all per-rule contexts record to it.
         let tracker = Arc::new(AccessTracker::default());
@@ -188,6 +348,9 @@ impl RuleEngine {
             access_tracker: Some(Arc::clone(&tracker)),
             wasm_runtime: ctx.wasm_runtime.clone(),
             wasm_counters: ctx.wasm_counters.clone(),
+            flags: ctx.flags,
+            enrichment: ctx.enrichment,
+            holidays: ctx.holidays,
         };
 
         for rule in &self.rules {
@@ -205,6 +368,17 @@ impl RuleEngine {
                 continue;
             }
 
+            if budget_exceeded
+                || self
+                    .budget
+                    .exhausted(total_evaluated, overall_start.elapsed())
+            {
+                budget_exceeded = true;
+                total_skipped += 1;
+                entries.push(build_skip_entry(rule, "budget_exceeded"));
+                continue;
+            }
+
             let (mut entry, matched) = self.trace_eval_rule(rule, &traced_ctx).await?;
             // Attach semantic match detail if the rule produced one.
             entry.semantic_details = tracker.take_semantic_detail();
@@ -227,32 +401,19 @@ impl RuleEngine {
             action_to_verdict(&rule.name, &rule.action)
                 .as_tag()
                 .to_owned()
+        } else if budget_exceeded {
+            self.budget.exhausted_verdict().as_tag().to_owned()
         } else {
             RuleVerdict::Allow(None).as_tag().to_owned()
         };
 
         let matched_rule = first_match.map(|(name, _)| name);
 
-        // Append a synthetic entry for default allow if no match occurred.
+        // Append a synthetic entry for the default verdict if no match occurred.
         if matched_rule.is_none() && !errors_before_match {
-            entries.push(RuleTraceEntry {
-                rule_name: "(default fallthrough)".to_owned(),
-                priority: i32::MAX,
-                enabled: true,
-                condition_display: "no rules matched".to_owned(),
-                result: RuleTraceResult::Matched,
-                evaluation_duration_us: 0,
-                action: "Allow".to_owned(),
-                source: "System".to_owned(),
-                description: Some(
-                    "No rules matched the action. The system default is to allow.".to_owned(),
-                ),
-                skip_reason: None,
-                error: None,
-                semantic_details: None,
-                modify_patch: None,
-                modified_payload_preview: None,
-            });
+            entries.push(build_fallthrough_entry(
+                budget_exceeded.then_some(self.budget.fail_closed),
+            ));
         }
 
         let trace_ctx = build_trace_context(&traced_ctx, &tracker);
@@ -268,6 +429,7 @@ impl RuleEngine {
             trace: entries,
             context: trace_ctx,
             modified_payload: None,
+            budget_exceeded,
         })
     }
 
@@ -306,6 +468,9 @@ impl RuleEngine {
                 access_tracker: ctx.access_tracker.clone(),
                 wasm_runtime: ctx.wasm_runtime.clone(),
                 wasm_counters: ctx.wasm_counters.clone(),
+                flags: ctx.flags,
+                enrichment: ctx.enrichment,
+                holidays: ctx.holidays,
             };
             &eval_ctx
         } else {
@@ -389,6 +554,45 @@ fn micros_u64(d: std::time::Duration) -> u64 {
     u64::try_from(d.as_micros()).unwrap_or(u64::MAX)
 }
 
+/// Build the synthetic default-verdict entry appended when no rule matched.
+///
+/// `budget_cutoff` is `Some(fail_closed)` when the evaluation budget ran out.
+fn build_fallthrough_entry(budget_cutoff: Option<bool>) -> RuleTraceEntry {
+    let (condition_display, description, action) = match budget_cutoff {
+        Some(false) => (
+            "evaluation budget exceeded",
+            "The evaluation budget ran out before any rule matched. The budget fails open, so the action is allowed.",
+            "Allow",
+        ),
+        Some(true) => (
+            "evaluation budget exceeded",
+            "The evaluation budget ran out before any rule matched. The budget fails closed, so the action is denied.",
+            "Deny",
+        ),
+        None => (
+            "no rules matched",
+            "No rules matched the action. The system default is to allow.",
+            "Allow",
+        ),
+    };
+    RuleTraceEntry {
+        rule_name: "(default fallthrough)".to_owned(),
+        priority: i32::MAX,
+        enabled: true,
+        condition_display: condition_display.to_owned(),
+        result: RuleTraceResult::Matched,
+        evaluation_duration_us: 0,
+        action: action.to_owned(),
+        source: "System".to_owned(),
+        description: Some(description.to_owned()),
+        skip_reason: None,
+        error: None,
+        semantic_details: None,
+        modify_patch: None,
+        modified_payload_preview: None,
+    }
+}
+
 /// Build a trace entry for a skipped rule (disabled or lower-priority).
 fn build_skip_entry(rule: &Rule, reason: &str) -> RuleTraceEntry {
     RuleTraceEntry {
@@ -463,6 +667,7 @@ mod tests {
     use chrono::Utc;
 
     use super::*;
+    use crate::engine::budget::BUDGET_EXCEEDED_RULE;
     use crate::engine::eval::{build_time_map, resolve_ident};
     use crate::engine::value::Value;
     use crate::ir::expr::{BinaryOp, Expr, UnaryOp};
@@ -1169,6 +1374,70 @@ mod tests {
         assert!(matches!(verdict, RuleVerdict::Allow(_)));
     }
 
+    #[tokio::test]
+    async fn engine_rollback_restores_previous_rule_set() {
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        let ctx = test_context(&action, &store, &env);
+
+        let mut engine = RuleEngine::new(vec![Rule::new(
+            "allow-all",
+            Expr::Bool(true),
+            RuleAction::Allow,
+        )]);
+        assert_eq!(engine.rule_set_version(), 1);
+
+        let v2 = engine.load_rules(vec![Rule::new(
+            "deny-all",
+            Expr::Bool(true),
+            RuleAction::Deny,
+        )]);
+        assert_eq!(v2, 2);
+        assert!(matches!(
+            engine.evaluate(&ctx).await.unwrap(),
+            RuleVerdict::Deny(_)
+        ));
+
+        engine.rollback_rules(1).unwrap();
+        assert_eq!(engine.rule_set_version(), 1);
+        assert_eq!(engine.list_rules(), vec!["allow-all"]);
+        assert!(matches!(
+            engine.evaluate(&ctx).await.unwrap(),
+            RuleVerdict::Allow(Some(_))
+        ));
+
+        // The replaced set stays available, so the rollback can be undone.
+        let versions: Vec<(u64, bool)> = engine
+            .rule_set_versions()
+            .iter()
+            .map(|v| (v.version, v.active))
+            .collect();
+        assert_eq!(versions, vec![(1, true), (2, false)]);
+        engine.rollback_rules(2).unwrap();
+        assert_eq!(engine.list_rules(), vec!["deny-all"]);
+
+        assert!(matches!(
+            engine.rollback_rules(7),
+            Err(RuleError::UnknownVersion(7))
+        ));
+    }
+
+    #[test]
+    fn engine_history_is_bounded() {
+        let mut engine = RuleEngine::new(vec![]).with_history_limit(2);
+        for _ in 0..4 {
+            engine.load_rules(vec![]);
+        }
+        let versions: Vec<u64> = engine
+            .rule_set_versions()
+            .iter()
+            .map(|v| v.version)
+            .collect();
+        assert_eq!(versions, vec![5, 4, 3]);
+        assert!(engine.rollback_rules(2).is_err());
+    }
+
     #[tokio::test]
     async fn engine_priority_ordering() {
         let rule_low =
@@ -1237,7 +1506,9 @@ mod tests {
             "reroute-sms",
             Expr::Bool(true),
             RuleAction::Reroute {
-                target_provider: "sms-fallback".into(),
+                target_provider: Some("sms-fallback".into()),
+                target_namespace: None,
+                target_tenant: None,
             },
         );
 
@@ -1252,9 +1523,10 @@ mod tests {
             RuleVerdict::Reroute {
                 rule,
                 target_provider,
+                ..
             } => {
                 assert_eq!(rule, "reroute-sms");
-                assert_eq!(target_provider, "sms-fallback");
+                assert_eq!(target_provider.as_deref(), Some("sms-fallback"));
             }
             other => panic!("expected Reroute, got {other:?}"),
         }
@@ -1329,6 +1601,7 @@ mod tests {
             Expr::Bool(true),
             RuleAction::Deduplicate {
                 ttl_seconds: Some(300),
+                window: acteon_core::DedupWindow::Fixed,
             },
         );
 
@@ -1340,7 +1613,7 @@ mod tests {
 
         let verdict = engine.evaluate(&ctx).await.unwrap();
         match verdict {
-            RuleVerdict::Deduplicate { ttl_seconds } => {
+            RuleVerdict::Deduplicate { ttl_seconds, .. } => {
                 assert_eq!(ttl_seconds, Some(300));
             }
             other => panic!("expected Deduplicate, got {other:?}"),
@@ -1552,7 +1825,9 @@ mod tests {
         assert_eq!(
             RuleVerdict::Reroute {
                 rule: "reroute-rule".into(),
-                target_provider: "sms".into(),
+                target_provider: Some("sms".into()),
+                target_namespace: None,
+                target_tenant: None,
             }
             .rule_name(),
             Some("reroute-rule")
@@ -1569,6 +1844,7 @@ mod tests {
         assert_eq!(
             RuleVerdict::Deduplicate {
                 ttl_seconds: Some(300),
+                window: acteon_core::DedupWindow::Fixed,
             }
             .rule_name(),
             None
@@ -2001,6 +2277,101 @@ mod tests {
         assert!(matches!(verdict, RuleVerdict::Allow(_)));
     }
 
+    // --- Feature flag tests ---
+
+    #[tokio::test]
+    async fn flags_resolve_as_bool_map() {
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        let flags: acteon_core::FeatureFlags = [("new_sms_vendor", true)].into_iter().collect();
+        let ctx = test_context(&action, &store, &env).with_flags(&flags);
+
+        let on = Expr::Field(
+            Box::new(Expr::Ident("flags".into())),
+            "new_sms_vendor".into(),
+        );
+        assert_eq!(eval(&on, &ctx).await.unwrap(), Value::Bool(true));
+
+        let missing = Expr::Field(Box::new(Expr::Ident("flags".into())), "unset".into());
+        assert_eq!(eval(&missing, &ctx).await.unwrap(), Value::Null);
+
+        let call = Expr::Call(
+            "enabled".into(),
+            vec![Expr::Ident("flags".into()), Expr::String("unset".into())],
+        );
+        assert_eq!(eval(&call, &ctx).await.unwrap(), Value::Bool(false));
+
+        // Without flags in the context every flag reads as disabled.
+        let bare = test_context(&action, &store, &env);
+        assert_eq!(eval(&call, &bare).await.unwrap(), Value::Bool(false));
+    }
+
+    #[tokio::test]
+    async fn engine_branches_on_flag() {
+        let condition = Expr::Call(
+            "enabled".into(),
+            vec![
+                Expr::Ident("flags".into()),
+                Expr::String("block_sms".into()),
+            ],
+        );
+        let engine = RuleEngine::new(vec![Rule::new("flagged-deny", condition, RuleAction::Deny)]);
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+
+        let mut flags = acteon_core::FeatureFlags::new();
+        let ctx = test_context(&action, &store, &env).with_flags(&flags);
+        assert!(matches!(
+            engine.evaluate(&ctx).await.unwrap(),
+            RuleVerdict::Allow(_)
+        ));
+
+        flags.set("block_sms", true);
+        let ctx = test_context(&action, &store, &env).with_flags(&flags);
+        assert!(matches!(
+            engine.evaluate(&ctx).await.unwrap(),
+            RuleVerdict::Deny(_)
+        ));
+    }
+
+    // --- Enrichment output tests ---
+
+    #[tokio::test]
+    async fn engine_branches_on_enrichment_output() {
+        let country = Expr::Field(
+            Box::new(Expr::Field(
+                Box::new(Expr::Ident("enrichment".into())),
+                "geoip".into(),
+            )),
+            "country".into(),
+        );
+        let condition = Expr::Binary(
+            BinaryOp::In,
+            Box::new(country),
+            Box::new(Expr::List(vec![Expr::String("KP".into())])),
+        );
+        let engine = RuleEngine::new(vec![Rule::new("geo-block", condition, RuleAction::Deny)]);
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+
+        let blocked = HashMap::from([("geoip".to_owned(), serde_json::json!({"country": "KP"}))]);
+        let ctx = test_context(&action, &store, &env).with_enrichment(&blocked);
+        assert!(matches!(
+            engine.evaluate(&ctx).await.unwrap(),
+            RuleVerdict::Deny(_)
+        ));
+
+        let allowed = HashMap::from([("geoip".to_owned(), serde_json::json!({"country": "FR"}))]);
+        let ctx = test_context(&action, &store, &env).with_enrichment(&allowed);
+        assert!(matches!(
+            engine.evaluate(&ctx).await.unwrap(),
+            RuleVerdict::Allow(_)
+        ));
+    }
+
     // --- Timezone support tests ---
 
     #[tokio::test]
@@ -2538,4 +2909,132 @@ mod tests {
         assert_eq!(fallthrough.rule_name, "(default fallthrough)");
         assert!(matches!(fallthrough.result, RuleTraceResult::Matched));
     }
+
+    // --- Evaluation budget tests ---
+
+    fn budget_rules() -> Vec<Rule> {
+        // Two non-matching rules ahead of a matching deny.
+        vec![
+            Rule::new("miss-1", Expr::Bool(false), RuleAction::Deny).with_priority(1),
+            Rule::new("miss-2", Expr::Bool(false), RuleAction::Deny).with_priority(2),
+            Rule::new("deny-all", Expr::Bool(true), RuleAction::Deny).with_priority(3),
+        ]
+    }
+
+    #[tokio::test]
+    async fn budget_cuts_off_evaluation_with_allow() {
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        let ctx = test_context(&action, &store, &env);
+
+        let engine = RuleEngine::new(budget_rules());
+        assert!(matches!(
+            engine.evaluate(&ctx).await.unwrap(),
+            RuleVerdict::Deny(_)
+        ));
+
+        let engine = engine.with_budget(EvalBudget::unlimited().with_max_rules(2));
+        assert!(matches!(
+            engine.evaluate(&ctx).await.unwrap(),
+            RuleVerdict::Allow(Some(rule)) if rule == BUDGET_EXCEEDED_RULE
+        ));
+    }
+
+    #[tokio::test]
+    async fn fail_closed_budget_denies_on_cutoff() {
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        let ctx = test_context(&action, &store, &env);
+
+        let engine = RuleEngine::new(budget_rules()).with_budget(
+            EvalBudget::unlimited()
+                .with_max_rules(2)
+                .with_fail_closed(true),
+        );
+        assert!(matches!(
+            engine.evaluate(&ctx).await.unwrap(),
+            RuleVerdict::Deny(rule) if rule == BUDGET_EXCEEDED_RULE
+        ));
+
+        let trace = engine
+            .evaluate_with_trace(&ctx, false, false)
+            .await
+            .unwrap();
+        assert!(trace.budget_exceeded);
+        assert_eq!(trace.verdict, "deny");
+        assert_eq!(trace.trace.last().unwrap().action, "Deny");
+    }
+
+    #[tokio::test]
+    async fn budget_cutoff_is_flagged_in_trace() {
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        let ctx = test_context(&action, &store, &env);
+
+        let engine =
+            RuleEngine::new(budget_rules()).with_budget(EvalBudget::unlimited().with_max_rules(2));
+        let trace = engine
+            .evaluate_with_trace(&ctx, false, false)
+            .await
+            .unwrap();
+        assert!(trace.budget_exceeded);
+        assert_eq!(trace.verdict, "allow");
+        assert!(trace.matched_rule.is_none());
+        assert_eq!(trace.total_rules_evaluated, 2);
+        assert_eq!(trace.total_rules_skipped, 1);
+        assert_eq!(trace.trace[2].rule_name, "deny-all");
+        assert_eq!(
+            trace.trace[2].skip_reason.as_deref(),
+            Some("budget_exceeded")
+        );
+
+        // A match inside the budget still wins, and is not flagged.
+        let engine =
+            RuleEngine::new(budget_rules()).with_budget(EvalBudget::unlimited().with_max_rules(3));
+        let trace = engine
+            .evaluate_with_trace(&ctx, false, false)
+            .await
+            .unwrap();
+        assert!(!trace.budget_exceeded);
+        assert_eq!(trace.matched_rule.as_deref(), Some("deny-all"));
+    }
+
+    #[tokio::test]
+    async fn budget_keeps_earlier_match_in_evaluate_all() {
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        let ctx = test_context(&action, &store, &env);
+
+        let rules = vec![
+            Rule::new("deny-all", Expr::Bool(true), RuleAction::Deny).with_priority(1),
+            Rule::new("suppress-all", Expr::Bool(true), RuleAction::Suppress).with_priority(2),
+        ];
+        let engine = RuleEngine::new(rules).with_budget(EvalBudget::unlimited().with_max_rules(1));
+        let trace = engine.evaluate_with_trace(&ctx, false, true).await.unwrap();
+        assert!(trace.budget_exceeded);
+        assert_eq!(trace.verdict, "deny");
+        assert_eq!(trace.matched_rule.as_deref(), Some("deny-all"));
+    }
+
+    #[tokio::test]
+    async fn zero_duration_budget_evaluates_nothing() {
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        let ctx = test_context(&action, &store, &env);
+
+        let engine = RuleEngine::new(budget_rules())
+            .with_budget(EvalBudget::unlimited().with_max_duration(std::time::Duration::ZERO));
+        let trace = engine
+            .evaluate_with_trace(&ctx, false, false)
+            .await
+            .unwrap();
+        assert!(trace.budget_exceeded);
+        assert_eq!(trace.total_rules_evaluated, 0);
+        assert_eq!(trace.verdict, "allow");
+    }
 }
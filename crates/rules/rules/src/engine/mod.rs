@@ -1,4 +1,6 @@
+pub mod budget;
 pub mod builtins;
+pub mod calendar;
 pub mod context;
 pub mod eval;
 pub mod executor;
@@ -6,10 +8,13 @@ pub mod ops_event;
 pub mod ops_semantic;
 pub mod ops_state;
 pub mod trace;
+pub mod units;
 pub mod value;
 pub mod verdict;
 
+pub use budget::{BUDGET_EXCEEDED_RULE, EvalBudget};
+pub use calendar::HolidayCalendars;
 pub use context::{AccessTracker, EmbeddingEvalSupport, EvalContext, SemanticMatchDetail};
-pub use executor::RuleEngine;
+pub use executor::{DEFAULT_RULE_HISTORY, RuleEngine, RuleSetVersion};
 pub use value::Value;
 pub use verdict::RuleVerdict;
@@ -111,4 +111,8 @@ pub struct RuleEvaluationTrace {
     /// payload after applying the JSON merge patch.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_payload: Option<serde_json::Value>,
+    /// `true` when the engine's evaluation budget cut the pass short.
+    /// Rules after the cutoff appear with `skip_reason: "budget_exceeded"`.
+    #[serde(default)]
+    pub budget_exceeded: bool,
 }
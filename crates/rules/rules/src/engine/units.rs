@@ -0,0 +1,138 @@
+//! Parsing of human-readable quantities such as `"1.5GB"` or `"30m"`.
+//!
+//! Two unit families are supported:
+//!
+//! - **Data sizes**, normalized to bytes. Decimal suffixes `B`, `KB`, `MB`,
+//!   `GB`, `TB`, `PB` use powers of 1000; binary suffixes `KiB`, `MiB`,
+//!   `GiB`, `TiB`, `PiB` use powers of 1024.
+//! - **Durations**, normalized to seconds. Suffixes are `ms`, `s`, `m`, `h`,
+//!   `d`, and `w`.
+//!
+//! Suffixes are case-insensitive, except that `m` (minutes) and `ms`
+//! (milliseconds) are matched in lowercase only to keep `M`/`MS` unambiguous.
+//! A bare number is taken to already be in the base unit, and whitespace
+//! between the number and the suffix is allowed.
+
+use crate::error::RuleError;
+
+/// A family of units that quantities can be parsed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitFamily {
+    /// Data sizes, in bytes.
+    Size,
+    /// Durations, in seconds.
+    Duration,
+}
+
+impl UnitFamily {
+    /// Name of the built-in function that parses this family.
+    pub fn builtin_name(self) -> &'static str {
+        match self {
+            Self::Size => "parse_size",
+            Self::Duration => "parse_duration",
+        }
+    }
+
+    /// Parse `input` into the family's base unit.
+    pub fn parse(self, input: &str) -> Result<f64, RuleError> {
+        match self {
+            Self::Size => parse_size(input),
+            Self::Duration => parse_duration(input),
+        }
+    }
+}
+
+/// Parse a data size such as `"512MB"` or `"1.5 GiB"` into bytes.
+pub fn parse_size(input: &str) -> Result<f64, RuleError> {
+    let (number, suffix) = split_quantity(input, "size")?;
+    let multiplier = match suffix.to_ascii_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "kb" => 1e3,
+        "mb" => 1e6,
+        "gb" => 1e9,
+        "tb" => 1e12,
+        "pb" => 1e15,
+        "kib" => 1024.0,
+        "mib" => 1024.0_f64.powi(2),
+        "gib" => 1024.0_f64.powi(3),
+        "tib" => 1024.0_f64.powi(4),
+        "pib" => 1024.0_f64.powi(5),
+        _ => return Err(unknown_unit("size", input, suffix)),
+    };
+    Ok(number * multiplier)
+}
+
+/// Parse a duration such as `"30m"` or `"1.5h"` into seconds.
+pub fn parse_duration(input: &str) -> Result<f64, RuleError> {
+    let (number, suffix) = split_quantity(input, "duration")?;
+    let multiplier = match suffix {
+        "" => 1.0,
+        "ms" => 0.001,
+        "m" => 60.0,
+        _ => match suffix.to_ascii_lowercase().as_str() {
+            "s" => 1.0,
+            "h" => 3_600.0,
+            "d" => 86_400.0,
+            "w" => 604_800.0,
+            _ => return Err(unknown_unit("duration", input, suffix)),
+        },
+    };
+    Ok(number * multiplier)
+}
+
+/// Split `input` into its numeric part and its (trimmed) unit suffix.
+fn split_quantity<'a>(input: &'a str, family: &str) -> Result<(f64, &'a str), RuleError> {
+    let trimmed = input.trim();
+    let split = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(trimmed.len());
+    let (number, suffix) = trimmed.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| RuleError::TypeError(format!("invalid {family} quantity \"{input}\"")))?;
+    Ok((number, suffix.trim_start()))
+}
+
+fn unknown_unit(family: &str, input: &str, suffix: &str) -> RuleError {
+    RuleError::TypeError(format!("unknown {family} unit \"{suffix}\" in \"{input}\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(parsed: Result<f64, RuleError>, expected: f64) {
+        let value = parsed.unwrap();
+        assert!(
+            (value - expected).abs() < f64::EPSILON,
+            "{value} != {expected}"
+        );
+    }
+
+    #[test]
+    fn sizes_use_decimal_and_binary_prefixes() {
+        assert_close(parse_size("512"), 512.0);
+        assert_close(parse_size("1KB"), 1_000.0);
+        assert_close(parse_size("1.5GB"), 1.5e9);
+        assert_close(parse_size("2 MiB"), 2_097_152.0);
+        assert_close(parse_size("1gib"), 1_073_741_824.0);
+    }
+
+    #[test]
+    fn durations_normalize_to_seconds() {
+        assert_close(parse_duration("45"), 45.0);
+        assert_close(parse_duration("500ms"), 0.5);
+        assert_close(parse_duration("30m"), 1_800.0);
+        assert_close(parse_duration("1.5h"), 5_400.0);
+        assert_close(parse_duration("2 d"), 172_800.0);
+        assert_close(parse_duration("1w"), 604_800.0);
+    }
+
+    #[test]
+    fn rejects_unknown_units_and_garbage() {
+        assert!(parse_size("10 parsecs").is_err());
+        assert!(parse_size("GB").is_err());
+        assert!(parse_duration("10M").is_err());
+        assert!(parse_duration("soon").is_err());
+    }
+}
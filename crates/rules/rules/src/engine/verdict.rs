@@ -17,15 +17,25 @@ pub enum RuleVerdict {
     Deduplicate {
         /// Time-to-live in seconds.
         ttl_seconds: Option<u64>,
+        /// Whether the TTL window is fixed or slides with each occurrence.
+        #[serde(default)]
+        window: acteon_core::DedupWindow,
     },
     /// Suppress the action with a reason.
     Suppress(String),
-    /// Reroute to a different provider.
+    /// Reroute to a different provider, namespace, or tenant.
     Reroute {
         /// Name of the rule that triggered the reroute.
         rule: String,
-        /// The target provider.
-        target_provider: String,
+        /// The target provider, or `None` to keep the action's provider.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_provider: Option<String>,
+        /// The target namespace, or `None` to keep the action's namespace.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_namespace: Option<String>,
+        /// The target tenant, or `None` to keep the action's tenant.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_tenant: Option<String>,
     },
     /// Throttle the action.
     Throttle {
@@ -145,13 +155,23 @@ pub(crate) fn action_to_verdict(rule_name: &str, action: &RuleAction) -> RuleVer
     match action {
         RuleAction::Allow => RuleVerdict::Allow(Some(rule_name.to_owned())),
         RuleAction::Deny => RuleVerdict::Deny(rule_name.to_owned()),
-        RuleAction::Deduplicate { ttl_seconds } => RuleVerdict::Deduplicate {
+        RuleAction::Deduplicate {
+            ttl_seconds,
+            window,
+        } => RuleVerdict::Deduplicate {
             ttl_seconds: *ttl_seconds,
+            window: *window,
         },
         RuleAction::Suppress => RuleVerdict::Suppress(rule_name.to_owned()),
-        RuleAction::Reroute { target_provider } => RuleVerdict::Reroute {
+        RuleAction::Reroute {
+            target_provider,
+            target_namespace,
+            target_tenant,
+        } => RuleVerdict::Reroute {
             rule: rule_name.to_owned(),
             target_provider: target_provider.clone(),
+            target_namespace: target_namespace.clone(),
+            target_tenant: target_tenant.clone(),
         },
         RuleAction::Throttle {
             max_count,
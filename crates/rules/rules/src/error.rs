@@ -34,6 +34,10 @@ pub enum RuleError {
     /// An invalid IANA timezone name was supplied.
     #[error("invalid timezone: {0}")]
     InvalidTimezone(String),
+
+    /// A rollback named a rule set version the engine no longer retains.
+    #[error("unknown rule set version: {0}")]
+    UnknownVersion(u64),
 }
 
 #[cfg(test)]
@@ -96,7 +96,9 @@ mod tests {
         assert!(RuleAction::Suppress.is_suppress());
 
         let reroute = RuleAction::Reroute {
-            target_provider: "sms".into(),
+            target_provider: Some("sms".into()),
+            target_namespace: None,
+            target_tenant: None,
         };
         assert!(reroute.is_reroute());
 
@@ -113,6 +115,7 @@ mod tests {
 
         let dedup = RuleAction::Deduplicate {
             ttl_seconds: Some(300),
+            window: acteon_core::DedupWindow::Fixed,
         };
         assert!(dedup.is_deduplicate());
     }
@@ -124,7 +127,9 @@ mod tests {
         assert_eq!(RuleAction::Suppress.kind_label(), "suppress");
         assert_eq!(
             RuleAction::Reroute {
-                target_provider: "x".into()
+                target_provider: Some("x".into()),
+                target_namespace: None,
+                target_tenant: None,
             }
             .kind_label(),
             "reroute"
@@ -145,7 +150,11 @@ mod tests {
             "modify"
         );
         assert_eq!(
-            RuleAction::Deduplicate { ttl_seconds: None }.kind_label(),
+            RuleAction::Deduplicate {
+                ttl_seconds: None,
+                window: acteon_core::DedupWindow::Fixed,
+            }
+            .kind_label(),
             "deduplicate"
         );
         assert_eq!(
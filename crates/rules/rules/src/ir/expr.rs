@@ -366,6 +366,32 @@ impl Expr {
             | Self::WasmCall { .. } => {}
         }
     }
+
+    /// The string literal this condition requires `action.<field>` to equal,
+    /// if any.
+    ///
+    /// Recognizes `action.<field> == "literal"` (either way round), alone or
+    /// as a term of an `&&` / `all` conjunction. Conditions it cannot see
+    /// through, such as a disjunction, return `None`.
+    pub fn required_action_field(&self, field: &str) -> Option<&str> {
+        match self {
+            Self::Binary(BinaryOp::Eq, lhs, rhs) => match (lhs.as_ref(), rhs.as_ref()) {
+                (Self::Field(base, name), Self::String(value))
+                | (Self::String(value), Self::Field(base, name))
+                    if name == field
+                        && matches!(base.as_ref(), Self::Ident(id) if id == "action") =>
+                {
+                    Some(value)
+                }
+                _ => None,
+            },
+            Self::Binary(BinaryOp::And, lhs, rhs) => lhs
+                .required_action_field(field)
+                .or_else(|| rhs.required_action_field(field)),
+            Self::All(terms) => terms.iter().find_map(|t| t.required_action_field(field)),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
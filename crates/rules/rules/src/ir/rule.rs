@@ -15,13 +15,28 @@ pub enum RuleAction {
     Deduplicate {
         /// Time-to-live in seconds for deduplication state.
         ttl_seconds: Option<u64>,
+        /// Whether the TTL window is fixed or slides with each occurrence.
+        #[serde(default)]
+        window: acteon_core::DedupWindow,
     },
     /// Suppress the action entirely.
     Suppress,
-    /// Reroute the action to a different provider.
+    /// Reroute the action to a different provider, namespace, or tenant.
+    ///
+    /// Unset targets keep the action's current value, so a reroute can move
+    /// an action to another tenant (e.g. a central ops tenant) while keeping
+    /// its provider. The action's original namespace and tenant are recorded
+    /// in its metadata.
     Reroute {
         /// The target provider to route to.
-        target_provider: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_provider: Option<String>,
+        /// The target namespace.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_namespace: Option<String>,
+        /// The target tenant.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_tenant: Option<String>,
     },
     /// Throttle the action based on a sliding window.
     Throttle {
@@ -112,6 +127,43 @@ pub enum RuleAction {
     },
 }
 
+/// A follow-on action dispatched after a rule's verdict has been executed.
+///
+/// The follow-on is built from this template when the primary action's
+/// outcome matches the trigger (`on_success` or `on_failure`). It is
+/// dispatched through the full gateway pipeline in the same namespace and
+/// tenant as the originating action, and carries a `caused_by` metadata
+/// label with the originating action ID so the link is visible in audit.
+///
+/// String values in `payload` may reference the originating action using
+/// the same `{{origin.*}}` placeholders as chain step payloads (e.g.
+/// `{{origin.payload.order_id}}`, `{{origin.id}}`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FollowOnAction {
+    /// Provider that executes the follow-on action.
+    pub provider: String,
+    /// Action type of the follow-on action.
+    pub action_type: String,
+    /// Payload template for the follow-on action.
+    #[serde(default)]
+    pub payload: serde_json::Value,
+}
+
+impl FollowOnAction {
+    /// Create a follow-on action template.
+    pub fn new(
+        provider: impl Into<String>,
+        action_type: impl Into<String>,
+        payload: serde_json::Value,
+    ) -> Self {
+        Self {
+            provider: provider.into(),
+            action_type: action_type.into(),
+            payload,
+        }
+    }
+}
+
 /// Where a rule was loaded from.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum RuleSource {
@@ -176,6 +228,14 @@ pub struct Rule {
     /// Alertmanager's `active_time_intervals`.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub active_time_intervals: Vec<String>,
+    /// Follow-on action dispatched when the primary action executes
+    /// successfully under this rule's verdict.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_success: Option<FollowOnAction>,
+    /// Follow-on action dispatched when the primary action fails under
+    /// this rule's verdict.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_failure: Option<FollowOnAction>,
 }
 
 impl Rule {
@@ -196,6 +256,8 @@ impl Rule {
             timezone: None,
             mute_time_intervals: Vec::new(),
             active_time_intervals: Vec::new(),
+            on_success: None,
+            on_failure: None,
         }
     }
 
@@ -262,6 +324,36 @@ impl Rule {
         self.active_time_intervals = intervals;
         self
     }
+
+    /// Set the follow-on action dispatched after a successful execution.
+    #[must_use]
+    pub fn with_on_success(mut self, follow_on: FollowOnAction) -> Self {
+        self.on_success = Some(follow_on);
+        self
+    }
+
+    /// Set the follow-on action dispatched after a failed execution.
+    #[must_use]
+    pub fn with_on_failure(mut self, follow_on: FollowOnAction) -> Self {
+        self.on_failure = Some(follow_on);
+        self
+    }
+
+    /// Returns `false` if the condition can only match actions outside
+    /// `namespace` and `tenant`, because it pins `action.namespace` or
+    /// `action.tenant` to another value.
+    ///
+    /// Rules carry no scope of their own, so any rule the condition does not
+    /// rule out is treated as applying to every scope.
+    pub fn may_match_scope(&self, namespace: &str, tenant: &str) -> bool {
+        self.condition
+            .required_action_field("namespace")
+            .is_none_or(|ns| ns == namespace)
+            && self
+                .condition
+                .required_action_field("tenant")
+                .is_none_or(|t| t == tenant)
+    }
 }
 
 #[cfg(test)]
@@ -294,6 +386,43 @@ mod tests {
         assert!(rule.enabled);
     }
 
+    #[test]
+    fn may_match_scope_honors_pinned_namespace_and_tenant() {
+        let field = |name: &str| {
+            Box::new(Expr::Field(
+                Box::new(Expr::Ident("action".into())),
+                name.into(),
+            ))
+        };
+        let eq = |name: &str, value: &str| {
+            Expr::Binary(
+                BinaryOp::Eq,
+                field(name),
+                Box::new(Expr::String(value.into())),
+            )
+        };
+
+        let pinned = Rule::new(
+            "alerts-only",
+            Expr::All(vec![eq("namespace", "alerts"), eq("tenant", "acme")]),
+            RuleAction::Allow,
+        );
+        assert!(pinned.may_match_scope("alerts", "acme"));
+        assert!(!pinned.may_match_scope("billing", "acme"));
+        assert!(!pinned.may_match_scope("alerts", "globex"));
+
+        // A disjunction pins nothing, so the rule may match anywhere.
+        let either = Rule::new(
+            "either",
+            Expr::Any(vec![eq("namespace", "alerts"), eq("namespace", "billing")]),
+            RuleAction::Allow,
+        );
+        assert!(either.may_match_scope("ops", "acme"));
+
+        let global = Rule::new("global", Expr::Bool(true), RuleAction::Allow);
+        assert!(global.may_match_scope("ops", "acme"));
+    }
+
     #[test]
     fn rule_serde_roundtrip() {
         let rule = Rule::new("test-rule", Expr::Bool(true), RuleAction::Allow).with_priority(5);
@@ -306,6 +435,25 @@ mod tests {
         assert!(back.enabled);
     }
 
+    #[test]
+    fn rule_follow_on_serde_roundtrip() {
+        let rule = Rule::new("notify", Expr::Bool(true), RuleAction::Allow).with_on_success(
+            FollowOnAction::new(
+                "slack",
+                "post_message",
+                serde_json::json!({"text": "sent {{origin.id}}"}),
+            ),
+        );
+
+        let json = serde_json::to_value(&rule).unwrap();
+        assert!(json.get("on_failure").is_none());
+        let back: Rule = serde_json::from_value(json).unwrap();
+        let follow_on = back.on_success.expect("on_success should round-trip");
+        assert_eq!(follow_on.provider, "slack");
+        assert_eq!(follow_on.action_type, "post_message");
+        assert!(back.on_failure.is_none());
+    }
+
     #[test]
     fn rule_action_variants_serde() {
         let actions: Vec<RuleAction> = vec![
@@ -313,10 +461,13 @@ mod tests {
             RuleAction::Deny,
             RuleAction::Deduplicate {
                 ttl_seconds: Some(300),
+                window: acteon_core::DedupWindow::Fixed,
             },
             RuleAction::Suppress,
             RuleAction::Reroute {
-                target_provider: "fallback-sms".into(),
+                target_provider: Some("fallback-sms".into()),
+                target_namespace: None,
+                target_tenant: None,
             },
             RuleAction::Throttle {
                 max_count: 100,
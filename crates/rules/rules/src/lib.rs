@@ -5,8 +5,11 @@ pub mod ir;
 
 pub use engine::context::{AccessTracker, SemanticMatchDetail, WasmEvalCounters};
 pub use engine::trace::{RuleEvaluationTrace, RuleTraceEntry, RuleTraceResult, TraceContext};
-pub use engine::{EmbeddingEvalSupport, EvalContext, RuleEngine, RuleVerdict};
+pub use engine::{
+    BUDGET_EXCEEDED_RULE, DEFAULT_RULE_HISTORY, EmbeddingEvalSupport, EvalBudget, EvalContext,
+    HolidayCalendars, RuleEngine, RuleSetVersion, RuleVerdict,
+};
 pub use error::RuleError;
 pub use frontend::RuleFrontend;
 pub use ir::expr::Expr;
-pub use ir::rule::{Rule, RuleAction, RuleSource};
+pub use ir::rule::{FollowOnAction, Rule, RuleAction, RuleSource};
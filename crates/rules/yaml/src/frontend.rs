@@ -1,12 +1,14 @@
 use std::path::Path;
 
-use acteon_rules::ir::expr::{BinaryOp, Expr};
-use acteon_rules::ir::rule::{Rule, RuleAction, RuleSource};
+use acteon_rules::engine::calendar::BUSINESS_HOURS_FN;
+use acteon_rules::engine::units::UnitFamily;
+use acteon_rules::ir::expr::{BinaryOp, Expr, UnaryOp};
+use acteon_rules::ir::rule::{FollowOnAction, Rule, RuleAction, RuleSource};
 use acteon_rules::{RuleError, RuleFrontend};
 
 use crate::parser::{
-    YamlAction, YamlCondition, YamlFieldOp, YamlNestedCondition, YamlPredicate, YamlRule,
-    YamlRuleFile,
+    YamlAction, YamlBusinessHours, YamlCondition, YamlFieldOp, YamlFollowOn, YamlNestedCondition,
+    YamlPredicate, YamlRule, YamlRuleFile, YamlUnit,
 };
 use crate::template::parse_field_path;
 
@@ -47,7 +49,7 @@ impl RuleFrontend for YamlFrontend {
 /// Compile a single `YamlRule` into the IR `Rule`.
 fn compile_rule(yaml: YamlRule, file: Option<&Path>) -> Result<Rule, RuleError> {
     let condition = compile_condition(&yaml.condition)?;
-    let action = compile_action(&yaml.action);
+    let action = compile_action(&yaml.action)?;
     let source = RuleSource::Yaml {
         file: file.map(|p| p.display().to_string()),
     };
@@ -65,6 +67,8 @@ fn compile_rule(yaml: YamlRule, file: Option<&Path>) -> Result<Rule, RuleError>
         timezone: None,
         mute_time_intervals: yaml.mute_time_intervals,
         active_time_intervals: yaml.active_time_intervals,
+        on_success: yaml.on_success.map(compile_follow_on),
+        on_failure: yaml.on_failure.map(compile_follow_on),
     };
     if let Some(tz) = yaml.timezone {
         rule = rule.with_timezone(tz);
@@ -72,6 +76,11 @@ fn compile_rule(yaml: YamlRule, file: Option<&Path>) -> Result<Rule, RuleError>
     Ok(rule)
 }
 
+/// Compile a `YamlFollowOn` into the IR `FollowOnAction`.
+fn compile_follow_on(follow_on: YamlFollowOn) -> FollowOnAction {
+    FollowOnAction::new(follow_on.provider, follow_on.action_type, follow_on.payload)
+}
+
 /// Maximum allowed nesting depth for condition expressions.
 const MAX_CONDITION_DEPTH: usize = 16;
 
@@ -197,86 +206,129 @@ fn compile_predicate_inner(pred: &YamlPredicate, depth: usize) -> Result<Expr, R
             plugin: wasm_plugin.clone(),
             function: wasm_function.clone(),
         }),
+        YamlPredicate::BusinessHours { business_hours } => {
+            Ok(compile_business_hours(business_hours))
+        }
         YamlPredicate::Nested(inner_cond) => {
             compile_nested_condition(inner_cond.as_ref(), depth + 1)
         }
     }
 }
 
+/// Compile a `business_hours` predicate into a call to the engine's
+/// `business_hours(start, end, weekdays, calendar)` built-in.
+fn compile_business_hours(hours: &YamlBusinessHours) -> Expr {
+    let call = Expr::Call(
+        BUSINESS_HOURS_FN.to_owned(),
+        vec![
+            Expr::String(hours.start.clone()),
+            Expr::String(hours.end.clone()),
+            Expr::List(
+                hours
+                    .weekdays
+                    .iter()
+                    .map(|d| Expr::Int(i64::from(*d)))
+                    .collect(),
+            ),
+            hours
+                .calendar
+                .as_ref()
+                .map_or(Expr::Null, |c| Expr::String(c.clone())),
+        ],
+    );
+    if hours.outside {
+        Expr::Unary(UnaryOp::Not, Box::new(call))
+    } else {
+        call
+    }
+}
+
 /// Compile a `YamlFieldOp` into a comparison expression against the given `lhs`.
 ///
 /// If multiple operator fields are set simultaneously, they are combined with
-/// logical AND.
+/// logical AND. When `unit` is set, the ordering and equality operators
+/// compare parsed quantities instead of raw values.
 fn compile_field_op(lhs: &Expr, op: &YamlFieldOp) -> Result<Expr, RuleError> {
     let mut checks: Vec<Expr> = Vec::new();
 
+    let family = op.unit.map(|unit| match unit {
+        YamlUnit::Size => UnitFamily::Size,
+        YamlUnit::Duration => UnitFamily::Duration,
+    });
+    let quantity_lhs = family.map(|f| Expr::Call(f.builtin_name().to_owned(), vec![lhs.clone()]));
+    let (raw_lhs, lhs) = (lhs, quantity_lhs.as_ref().unwrap_or(lhs));
+    let operand = |val: &serde_json::Value| match family {
+        Some(f) => quantity_operand(f, val),
+        None => Ok(json_to_expr(val)),
+    };
+
     if let Some(ref val) = op.eq {
         checks.push(Expr::Binary(
             BinaryOp::Eq,
             Box::new(lhs.clone()),
-            Box::new(json_to_expr(val)),
+            Box::new(operand(val)?),
         ));
     }
     if let Some(ref val) = op.ne {
         checks.push(Expr::Binary(
             BinaryOp::Ne,
             Box::new(lhs.clone()),
-            Box::new(json_to_expr(val)),
+            Box::new(operand(val)?),
         ));
     }
     if let Some(ref val) = op.gt {
         checks.push(Expr::Binary(
             BinaryOp::Gt,
             Box::new(lhs.clone()),
-            Box::new(json_to_expr(val)),
+            Box::new(operand(val)?),
         ));
     }
     if let Some(ref val) = op.lt {
         checks.push(Expr::Binary(
             BinaryOp::Lt,
             Box::new(lhs.clone()),
-            Box::new(json_to_expr(val)),
+            Box::new(operand(val)?),
         ));
     }
     if let Some(ref val) = op.gte {
         checks.push(Expr::Binary(
             BinaryOp::Ge,
             Box::new(lhs.clone()),
-            Box::new(json_to_expr(val)),
+            Box::new(operand(val)?),
         ));
     }
     if let Some(ref val) = op.lte {
         checks.push(Expr::Binary(
             BinaryOp::Le,
             Box::new(lhs.clone()),
-            Box::new(json_to_expr(val)),
+            Box::new(operand(val)?),
         ));
     }
     if let Some(ref val) = op.contains {
         checks.push(Expr::Binary(
             BinaryOp::Contains,
-            Box::new(lhs.clone()),
+            Box::new(raw_lhs.clone()),
             Box::new(Expr::String(val.clone())),
         ));
     }
     if let Some(ref val) = op.starts_with {
         checks.push(Expr::Binary(
             BinaryOp::StartsWith,
-            Box::new(lhs.clone()),
+            Box::new(raw_lhs.clone()),
             Box::new(Expr::String(val.clone())),
         ));
     }
     if let Some(ref val) = op.ends_with {
         checks.push(Expr::Binary(
             BinaryOp::EndsWith,
-            Box::new(lhs.clone()),
+            Box::new(raw_lhs.clone()),
             Box::new(Expr::String(val.clone())),
         ));
     }
     if let Some(ref val) = op.matches {
         checks.push(Expr::Binary(
             BinaryOp::Matches,
-            Box::new(lhs.clone()),
+            Box::new(raw_lhs.clone()),
             Box::new(Expr::String(val.clone())),
         ));
     }
@@ -284,7 +336,7 @@ fn compile_field_op(lhs: &Expr, op: &YamlFieldOp) -> Result<Expr, RuleError> {
         let list_items: Vec<Expr> = vals.iter().map(json_to_expr).collect();
         checks.push(Expr::Binary(
             BinaryOp::In,
-            Box::new(lhs.clone()),
+            Box::new(raw_lhs.clone()),
             Box::new(Expr::List(list_items)),
         ));
     }
@@ -298,6 +350,26 @@ fn compile_field_op(lhs: &Expr, op: &YamlFieldOp) -> Result<Expr, RuleError> {
     }
 }
 
+/// Parse a unit-comparison operand at compile time so that malformed
+/// thresholds are rejected when the rule is loaded.
+fn quantity_operand(family: UnitFamily, val: &serde_json::Value) -> Result<Expr, RuleError> {
+    let expr = match val {
+        serde_json::Value::String(s) => {
+            let quantity = family
+                .parse(s)
+                .map_err(|e| RuleError::Parse(format!("invalid unit operand: {e}")))?;
+            Expr::Float(quantity)
+        }
+        serde_json::Value::Number(_) => json_to_expr(val),
+        other => {
+            return Err(RuleError::Parse(format!(
+                "unit comparison expects a string or number operand, got {other}"
+            )));
+        }
+    };
+    Ok(expr)
+}
+
 /// Convert a `serde_json::Value` into the corresponding `Expr` literal.
 fn json_to_expr(val: &serde_json::Value) -> Expr {
     match val {
@@ -323,16 +395,36 @@ fn json_to_expr(val: &serde_json::Value) -> Expr {
 }
 
 /// Compile a `YamlAction` into a `RuleAction`.
-fn compile_action(action: &YamlAction) -> RuleAction {
-    match action {
+fn compile_action(action: &YamlAction) -> Result<RuleAction, RuleError> {
+    let compiled = match action {
         YamlAction::Allow => RuleAction::Allow,
         YamlAction::Deny => RuleAction::Deny,
-        YamlAction::Deduplicate { ttl_seconds } => RuleAction::Deduplicate {
+        YamlAction::Deduplicate {
+            ttl_seconds,
+            window,
+        } => RuleAction::Deduplicate {
             ttl_seconds: *ttl_seconds,
+            window: *window,
         },
         YamlAction::Suppress => RuleAction::Suppress,
-        YamlAction::Reroute { target_provider } => RuleAction::Reroute {
+        YamlAction::Reroute {
+            target_provider: None,
+            target_namespace: None,
+            target_tenant: None,
+        } => {
+            return Err(RuleError::Parse(
+                "reroute requires at least one of target_provider, target_namespace, or target_tenant"
+                    .to_owned(),
+            ));
+        }
+        YamlAction::Reroute {
+            target_provider,
+            target_namespace,
+            target_tenant,
+        } => RuleAction::Reroute {
             target_provider: target_provider.clone(),
+            target_namespace: target_namespace.clone(),
+            target_tenant: target_tenant.clone(),
         },
         YamlAction::Throttle {
             max_count,
@@ -381,7 +473,8 @@ fn compile_action(action: &YamlAction) -> RuleAction {
         YamlAction::Schedule { delay_seconds } => RuleAction::Schedule {
             delay_seconds: *delay_seconds,
         },
-    }
+    };
+    Ok(compiled)
 }
 
 #[cfg(test)]
@@ -453,6 +546,77 @@ rules:
         assert!(rules[1].action.is_deduplicate());
     }
 
+    #[test]
+    fn parse_sliding_dedup_window() {
+        let fe = YamlFrontend;
+        let yaml = r#"
+rules:
+  - name: dedup-sliding
+    condition:
+      field: action.action_type
+      eq: "heartbeat"
+    action:
+      type: deduplicate
+      ttl_seconds: 60
+      window: sliding
+  - name: dedup-fixed
+    condition:
+      field: action.action_type
+      eq: "alert"
+    action:
+      type: deduplicate
+      ttl_seconds: 60
+"#;
+        let rules = fe.parse(yaml).unwrap();
+        assert!(matches!(
+            rules[0].action,
+            RuleAction::Deduplicate {
+                ttl_seconds: Some(60),
+                window: acteon_core::DedupWindow::Sliding,
+            }
+        ));
+        assert!(matches!(
+            rules[1].action,
+            RuleAction::Deduplicate {
+                window: acteon_core::DedupWindow::Fixed,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_follow_on_actions() {
+        let fe = YamlFrontend;
+        let yaml = r#"
+rules:
+  - name: order-placed
+    condition:
+      field: action.action_type
+      eq: "place_order"
+    action:
+      type: allow
+    on_success:
+      provider: email
+      action_type: send_receipt
+      payload:
+        order_id: "{{origin.payload.order_id}}"
+    on_failure:
+      provider: pagerduty
+      action_type: page_oncall
+"#;
+        let rules = fe.parse(yaml).unwrap();
+        let on_success = rules[0].on_success.as_ref().expect("on_success");
+        assert_eq!(on_success.provider, "email");
+        assert_eq!(on_success.action_type, "send_receipt");
+        assert_eq!(
+            on_success.payload["order_id"],
+            "{{origin.payload.order_id}}"
+        );
+        let on_failure = rules[0].on_failure.as_ref().expect("on_failure");
+        assert_eq!(on_failure.provider, "pagerduty");
+        assert!(on_failure.payload.is_null());
+    }
+
     #[test]
     fn parse_invalid_yaml_produces_error() {
         let fe = YamlFrontend;
@@ -925,13 +1089,69 @@ rules:
 
         let verdict = engine.evaluate(&ctx).await.unwrap();
         match verdict {
-            RuleVerdict::Deduplicate { ttl_seconds } => {
+            RuleVerdict::Deduplicate { ttl_seconds, .. } => {
                 assert_eq!(ttl_seconds, Some(300));
             }
             other => panic!("expected Deduplicate, got {other:?}"),
         }
     }
 
+    async fn unit_rule_fires(condition: &str, payload: serde_json::Value) -> bool {
+        let fe = YamlFrontend;
+        let yaml = format!(
+            "rules:\n  - name: unit-check\n    priority: 1\n    condition:\n{condition}\n    action:\n      type: deny\n"
+        );
+        let rules = fe.parse(&yaml).unwrap();
+        let engine = RuleEngine::new(rules);
+
+        let action = Action::new("ns", "t", "email", "upload", payload);
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        let ctx = EvalContext::new(&action, &store, &env);
+
+        matches!(engine.evaluate(&ctx).await.unwrap(), RuleVerdict::Deny(_))
+    }
+
+    #[tokio::test]
+    async fn unit_size_comparison_both_ways() {
+        let payload = serde_json::json!({"size": "512MB"});
+        let gt = "      field: action.payload.size\n      gt: \"1GB\"\n      unit: size";
+        let lt = "      field: action.payload.size\n      lt: \"1GB\"\n      unit: size";
+        assert!(!unit_rule_fires(gt, payload.clone()).await);
+        assert!(unit_rule_fires(lt, payload).await);
+
+        let payload = serde_json::json!({"size": "1GB"});
+        let gt = "      field: action.payload.size\n      gt: \"512MB\"\n      unit: size";
+        let lt = "      field: action.payload.size\n      lt: \"512MB\"\n      unit: size";
+        assert!(unit_rule_fires(gt, payload.clone()).await);
+        assert!(!unit_rule_fires(lt, payload).await);
+    }
+
+    #[tokio::test]
+    async fn unit_duration_comparison() {
+        let cond = "      field: action.payload.duration\n      gte: \"1h\"\n      unit: duration";
+        assert!(unit_rule_fires(cond, serde_json::json!({"duration": "90m"})).await);
+        assert!(!unit_rule_fires(cond, serde_json::json!({"duration": "30m"})).await);
+        assert!(unit_rule_fires(cond, serde_json::json!({"duration": 3600})).await);
+    }
+
+    #[test]
+    fn unit_comparison_rejects_malformed_threshold() {
+        let fe = YamlFrontend;
+        let yaml = r#"
+rules:
+  - name: bad-unit
+    condition:
+      field: action.payload.size
+      gt: "lots"
+      unit: size
+    action:
+      type: deny
+"#;
+        let result = fe.parse(yaml);
+        assert!(matches!(result, Err(RuleError::Parse(_))));
+    }
+
     #[tokio::test]
     async fn end_to_end_no_match_allows() {
         let fe = YamlFrontend;
@@ -1043,9 +1263,10 @@ rules:
             RuleVerdict::Reroute {
                 rule,
                 target_provider,
+                ..
             } => {
                 assert_eq!(rule, "reroute-sms");
-                assert_eq!(target_provider, "sms-fallback");
+                assert_eq!(target_provider.as_deref(), Some("sms-fallback"));
             }
             other => panic!("expected Reroute, got {other:?}"),
         }
@@ -1148,6 +1369,22 @@ rules:
         }
     }
 
+    #[test]
+    fn reroute_without_target_rejected() {
+        let fe = YamlFrontend;
+        let yaml = r#"
+rules:
+  - name: reroute-nowhere
+    condition:
+      field: x
+      eq: 1
+    action:
+      type: reroute
+"#;
+        let result = fe.parse(yaml);
+        assert!(matches!(result, Err(RuleError::Parse(_))));
+    }
+
     #[tokio::test]
     async fn end_to_end_throttle() {
         let fe = YamlFrontend;
@@ -1241,6 +1478,64 @@ rules:
         );
     }
 
+    #[tokio::test]
+    async fn business_hours_treats_holiday_as_closed() {
+        use acteon_rules::HolidayCalendars;
+        use chrono::TimeZone as _;
+
+        let yaml = r#"
+rules:
+  - name: page-outside-business-hours
+    priority: 1
+    timezone: "Europe/Berlin"
+    condition:
+      business_hours:
+        calendar: de
+        outside: true
+    action:
+      type: reroute
+      target_provider: pagerduty
+"#;
+        let engine = RuleEngine::new(YamlFrontend.parse(yaml).unwrap());
+        let holidays = HolidayCalendars::new().with_calendar(
+            "de",
+            [chrono::NaiveDate::from_ymd_opt(2026, 12, 25).unwrap()],
+        );
+
+        let action = test_action();
+        let store = MemoryStateStore::new();
+        let env = HashMap::new();
+        // Berlin is UTC+1 in December.
+        let verdict_at = |day, berlin_hour: u32| {
+            let now = chrono::Utc
+                .with_ymd_and_hms(2026, 12, day, berlin_hour - 1, 0, 0)
+                .unwrap();
+            let ctx = EvalContext::new(&action, &store, &env)
+                .with_now(now)
+                .with_holidays(&holidays);
+            let engine = &engine;
+            async move { engine.evaluate(&ctx).await.unwrap() }
+        };
+
+        // Thursday 24 December, 10:00 — an ordinary business morning.
+        let verdict = verdict_at(24, 10).await;
+        assert!(matches!(verdict, RuleVerdict::Allow(_)), "got {verdict:?}");
+
+        // Thursday 24 December, 18:00 — after hours.
+        let verdict = verdict_at(24, 18).await;
+        assert!(
+            matches!(verdict, RuleVerdict::Reroute { .. }),
+            "got {verdict:?}"
+        );
+
+        // Friday 25 December, 10:00 — a configured holiday is not business hours.
+        let verdict = verdict_at(25, 10).await;
+        assert!(
+            matches!(verdict, RuleVerdict::Reroute { .. }),
+            "got {verdict:?}"
+        );
+    }
+
     #[tokio::test]
     async fn end_to_end_time_based_weekday_check() {
         use chrono::TimeZone as _;
@@ -1508,6 +1803,7 @@ rules:
                 ends_with: None,
                 matches: None,
                 in_list: None,
+                unit: None,
             },
         };
 
@@ -37,6 +37,21 @@ fn default_wasm_function() -> String {
     "evaluate".to_owned()
 }
 
+/// Default business-hours opening time.
+fn default_business_start() -> String {
+    "09:00".to_owned()
+}
+
+/// Default business-hours closing time.
+fn default_business_end() -> String {
+    "17:00".to_owned()
+}
+
+/// Default business days: Monday through Friday.
+fn default_business_weekdays() -> Vec<u32> {
+    vec![1, 2, 3, 4, 5]
+}
+
 /// Top-level YAML rule file containing a list of rules.
 #[derive(Debug, Deserialize)]
 pub struct YamlRuleFile {
@@ -75,6 +90,24 @@ pub struct YamlRule {
     /// non-empty and none match, the rule is muted at dispatch time.
     #[serde(default)]
     pub active_time_intervals: Vec<String>,
+    /// Follow-on action dispatched after a successful execution.
+    #[serde(default)]
+    pub on_success: Option<YamlFollowOn>,
+    /// Follow-on action dispatched after a failed execution.
+    #[serde(default)]
+    pub on_failure: Option<YamlFollowOn>,
+}
+
+/// A follow-on action template as represented in YAML.
+#[derive(Debug, Deserialize)]
+pub struct YamlFollowOn {
+    /// Provider that executes the follow-on action.
+    pub provider: String,
+    /// Action type of the follow-on action.
+    pub action_type: String,
+    /// Payload template; string values may use `{{origin.*}}` placeholders.
+    #[serde(default)]
+    pub payload: serde_json::Value,
 }
 
 /// A condition expression that can combine multiple predicates.
@@ -161,6 +194,11 @@ pub enum YamlPredicate {
         #[serde(default = "default_wasm_function")]
         wasm_function: String,
     },
+    /// Check whether the evaluation time is within business hours.
+    BusinessHours {
+        /// Business-hours window and optional holiday calendar.
+        business_hours: YamlBusinessHours,
+    },
     /// A nested condition (allows recursive `all` / `any` grouping).
     ///
     /// Uses `YamlNestedCondition` instead of `YamlCondition` to prevent
@@ -168,6 +206,27 @@ pub enum YamlPredicate {
     Nested(Box<YamlNestedCondition>),
 }
 
+/// A business-hours window, evaluated in the rule's timezone.
+#[derive(Debug, Deserialize)]
+pub struct YamlBusinessHours {
+    /// Opening time as `"HH:MM"` (inclusive). Defaults to `"09:00"`.
+    #[serde(default = "default_business_start")]
+    pub start: String,
+    /// Closing time as `"HH:MM"` (exclusive). Defaults to `"17:00"`.
+    #[serde(default = "default_business_end")]
+    pub end: String,
+    /// ISO weekdays counted as business days (1=Monday … 7=Sunday).
+    /// Defaults to Monday through Friday.
+    #[serde(default = "default_business_weekdays")]
+    pub weekdays: Vec<u32>,
+    /// Name of a configured holiday calendar; its dates are never
+    /// business hours.
+    pub calendar: Option<String>,
+    /// Match *outside* business hours instead (including holidays).
+    #[serde(default)]
+    pub outside: bool,
+}
+
 /// Describes which comparison operator to apply to a field or counter value.
 ///
 /// Exactly one field should be set. If multiple are set, they are combined
@@ -196,6 +255,19 @@ pub struct YamlFieldOp {
     pub matches: Option<String>,
     /// Membership test against a list of values.
     pub in_list: Option<Vec<serde_json::Value>>,
+    /// Parse the field and the operand as human-readable quantities before
+    /// comparing. Applies to `eq`, `ne`, `gt`, `lt`, `gte`, and `lte`.
+    pub unit: Option<YamlUnit>,
+}
+
+/// Unit family for quantity comparisons such as `gt: "1GB"`.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum YamlUnit {
+    /// Data sizes (`512MB`, `1.5GiB`), compared in bytes.
+    Size,
+    /// Durations (`500ms`, `30m`, `2d`), compared in seconds.
+    Duration,
 }
 
 /// The action to take when a rule fires.
@@ -210,13 +282,23 @@ pub enum YamlAction {
     Deduplicate {
         /// Time-to-live in seconds for deduplication state.
         ttl_seconds: Option<u64>,
+        /// Whether the TTL window is fixed or slides with each occurrence.
+        #[serde(default)]
+        window: acteon_core::DedupWindow,
     },
     /// Suppress the action entirely.
     Suppress,
-    /// Reroute the action to a different provider.
+    /// Reroute the action to a different provider, namespace, or tenant.
     Reroute {
         /// The target provider to route to.
-        target_provider: String,
+        #[serde(default)]
+        target_provider: Option<String>,
+        /// The target namespace.
+        #[serde(default)]
+        target_namespace: Option<String>,
+        /// The target tenant.
+        #[serde(default)]
+        target_tenant: Option<String>,
     },
     /// Throttle the action based on a sliding window.
     Throttle {
@@ -710,6 +710,7 @@ mod tests {
             tenant: "demo".to_string(),
             action_type: Some(A2A_TASK_ACTION_TYPE.to_string()),
             action_id: Some(task_id.to_string()),
+            traceparent: None,
         }
     }
 
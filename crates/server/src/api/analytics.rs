@@ -38,6 +38,11 @@ pub struct AnalyticsParams {
     pub group_by: Option<String>,
     /// Number of top entries (default 10).
     pub top_n: Option<usize>,
+    /// Dimension of a top entry to drill into (`namespace`, `tenant`,
+    /// `provider`, `action_type`, `outcome`). Requires `drill_value`.
+    pub drill_dimension: Option<String>,
+    /// Value of the entry to drill into. Requires `drill_dimension`.
+    pub drill_value: Option<String>,
 }
 
 /// `GET /v1/analytics` -- query aggregated action analytics.
@@ -59,9 +64,12 @@ pub struct AnalyticsParams {
         ("to" = Option<String>, Query, description = "End of time range (RFC 3339)"),
         ("group_by" = Option<String>, Query, description = "Dimension to group by"),
         ("top_n" = Option<usize>, Query, description = "Number of top entries (default 10)"),
+        ("drill_dimension" = Option<String>, Query, description = "Dimension of a top entry to drill into"),
+        ("drill_value" = Option<String>, Query, description = "Value of the entry to drill into"),
     ),
     responses(
         (status = 200, description = "Analytics results", body = acteon_core::AnalyticsResponse),
+        (status = 400, description = "Invalid drill-down", body = ErrorResponse),
         (status = 403, description = "Requested tenant is not covered by the caller's grants", body = ErrorResponse),
         (status = 404, description = "Analytics not available", body = ErrorResponse)
     )
@@ -80,25 +88,25 @@ pub async fn query_analytics(
         );
     };
 
-    // Enforce tenant access. A named tenant is pinned exactly; an unnamed
-    // scoped caller gets a hierarchical authorization scope so analytics
-    // aggregates across the union of their granted subtrees.
-    let resolved = match identity.resolve_tenant_query_scope(params.tenant.as_deref()) {
-        Ok(resolved) => resolved,
-        Err(TenantFilterError::NotGranted(requested)) => {
+    let drill_into = match (params.drill_dimension, params.drill_value) {
+        (Some(dimension), Some(value)) => Some((dimension, value)),
+        (None, None) => None,
+        _ => {
             return (
-                StatusCode::FORBIDDEN,
+                StatusCode::BAD_REQUEST,
                 Json(serde_json::json!(ErrorResponse {
-                    error: format!("no grant covers tenant={requested}"),
+                    error: "drill_dimension and drill_value must be set together".into(),
                 })),
             );
         }
     };
 
+    // Fold any drill-down into the plain filters first, so drilling into a
+    // tenant goes through the same grant check as filtering by it.
     let query = AnalyticsQuery {
         metric: params.metric,
         namespace: params.namespace,
-        tenant: resolved.tenant,
+        tenant: params.tenant,
         provider: params.provider,
         action_type: params.action_type,
         outcome: params.outcome,
@@ -107,8 +115,37 @@ pub async fn query_analytics(
         to: params.to,
         group_by: params.group_by,
         top_n: params.top_n,
-        tenant_scope: resolved.scope,
+        drill_into,
+        tenant_scope: Vec::new(),
+    };
+    let mut query = match query.resolve_drill() {
+        Ok(query) => query,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!(ErrorResponse {
+                    error: e.to_string(),
+                })),
+            );
+        }
+    };
+
+    // Enforce tenant access. A named tenant is pinned exactly; an unnamed
+    // scoped caller gets a hierarchical authorization scope so analytics
+    // aggregates across the union of their granted subtrees.
+    let resolved = match identity.resolve_tenant_query_scope(query.tenant.as_deref()) {
+        Ok(resolved) => resolved,
+        Err(TenantFilterError::NotGranted(requested)) => {
+            return (
+                StatusCode::FORBIDDEN,
+                Json(serde_json::json!(ErrorResponse {
+                    error: format!("no grant covers tenant={requested}"),
+                })),
+            );
+        }
     };
+    query.tenant = resolved.tenant;
+    query.tenant_scope = resolved.scope;
 
     match analytics.query_analytics(&query).await {
         Ok(response) => (StatusCode::OK, Json(serde_json::json!(response))),
@@ -16,6 +16,7 @@ use acteon_gateway::GatewayError;
 use crate::error::ServerError;
 
 use super::AppState;
+use super::pagination::offset_pages;
 use super::schemas::ErrorResponse;
 
 /// Response returned when an approval is executed or rejected.
@@ -62,6 +63,12 @@ pub struct ApprovalQueryParams {
     pub namespace: String,
     /// Filter by tenant.
     pub tenant: String,
+    /// Maximum number of results. Omit to return every pending approval.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Number of results to skip (default: 0).
+    #[serde(default)]
+    pub offset: usize,
 }
 
 /// Query parameters for HMAC-authenticated approval endpoints.
@@ -309,10 +316,12 @@ pub async fn get_approval(
     path = "/v1/approvals",
     tag = "Approvals",
     summary = "List pending approvals",
-    description = "Lists pending approvals filtered by namespace and tenant. Requires authentication.",
+    description = "Lists pending approvals filtered by namespace and tenant, oldest first. Requires authentication.",
     params(
         ("namespace" = String, Query, description = "Approval namespace"),
         ("tenant" = String, Query, description = "Approval tenant"),
+        ("limit" = Option<usize>, Query, description = "Maximum number of results"),
+        ("offset" = Option<usize>, Query, description = "Number of results to skip"),
     ),
     responses(
         (status = 200, description = "List of pending approvals", body = ListApprovalsResponse),
@@ -343,7 +352,17 @@ pub async fn list_approvals(
         .await
     {
         Ok(statuses) => {
-            let approvals: Vec<ApprovalStatusResponse> = statuses
+            let page = offset_pages(
+                statuses,
+                |a, b| {
+                    a.created_at
+                        .cmp(&b.created_at)
+                        .then_with(|| a.token.cmp(&b.token))
+                },
+                params.offset,
+                params.limit,
+            );
+            let approvals: Vec<ApprovalStatusResponse> = page
                 .into_iter()
                 .map(|s| ApprovalStatusResponse {
                     token: s.token,
@@ -0,0 +1,213 @@
+//! Legal hold API endpoints.
+//!
+//! A hold exempts one action's audit records from retention deletion, for
+//! cases where a legal or compliance request targets specific records rather
+//! than a whole tenant (see `RetentionPolicy::compliance_hold` for the
+//! tenant-wide variant). Holding a record clears its expiry in the audit
+//! store; the hold itself, with its reason and actor, lives in the state
+//! store so holds can be listed per tenant.
+
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use utoipa::{IntoParams, ToSchema};
+
+use acteon_core::AuditHold;
+use acteon_state::{KeyKind, StateKey};
+
+use crate::auth::identity::CallerIdentity;
+use crate::auth::role::Permission;
+
+use super::AppState;
+use super::schemas::ErrorResponse;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PlaceAuditHoldRequest {
+    /// Why the records are held (e.g. a case or ticket reference).
+    #[schema(example = "litigation case 2026-114")]
+    pub reason: String,
+}
+
+/// Query parameters for listing holds.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ListAuditHoldsParams {
+    /// Namespace to list holds for.
+    pub namespace: String,
+    /// Tenant to list holds for.
+    pub tenant: String,
+}
+
+/// Response for listing holds.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ListAuditHoldsResponse {
+    /// Holds in the requested namespace and tenant.
+    pub holds: Vec<AuditHold>,
+    /// Number of holds returned.
+    pub count: usize,
+}
+
+fn error_response(status: StatusCode, message: &str) -> axum::response::Response {
+    (
+        status,
+        Json(serde_json::json!(ErrorResponse {
+            error: message.to_owned(),
+        })),
+    )
+        .into_response()
+}
+
+/// `POST /v1/audit/{action_id}/hold` -- place a legal hold on an action's records.
+#[utoipa::path(
+    post,
+    path = "/v1/audit/{action_id}/hold",
+    tag = "Audit",
+    summary = "Place a legal hold",
+    description = "Exempts every audit record for the action from retention deletion and records who placed the hold and why. Placing a hold again replaces the reason and actor.",
+    params(
+        ("action_id" = String, Path, description = "Action ID whose records to hold")
+    ),
+    request_body(content = PlaceAuditHoldRequest, description = "Hold reason"),
+    responses(
+        (status = 201, description = "Hold placed", body = AuditHold),
+        (status = 400, description = "Missing reason", body = ErrorResponse),
+        (status = 403, description = "Caller not authorized", body = ErrorResponse),
+        (status = 404, description = "No audit record for the action, or audit not enabled", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+pub async fn place_audit_hold(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Path(action_id): Path<String>,
+    Json(req): Json<PlaceAuditHoldRequest>,
+) -> impl IntoResponse {
+    if !identity.role.has_permission(Permission::AuditHoldManage) {
+        return error_response(
+            StatusCode::FORBIDDEN,
+            "insufficient permissions: audit holds require admin or operator role",
+        );
+    }
+    if req.reason.trim().is_empty() {
+        return error_response(StatusCode::BAD_REQUEST, "hold reason must not be empty");
+    }
+    let Some(ref audit) = state.audit else {
+        return error_response(StatusCode::NOT_FOUND, "audit is not enabled");
+    };
+
+    let record = match audit.get_by_action_id(&action_id).await {
+        Ok(Some(record)) => record,
+        Ok(None) => {
+            return error_response(
+                StatusCode::NOT_FOUND,
+                &format!("no audit record found for action: {action_id}"),
+            );
+        }
+        Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()),
+    };
+    if !identity.is_authorized(
+        &record.tenant,
+        &record.namespace,
+        &record.provider,
+        &record.action_type,
+    ) {
+        return error_response(StatusCode::FORBIDDEN, "no grant covers this audit record");
+    }
+
+    if let Err(e) = audit.clear_expiry(&action_id).await {
+        return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string());
+    }
+
+    let hold = AuditHold {
+        action_id,
+        namespace: record.namespace,
+        tenant: record.tenant,
+        reason: req.reason,
+        held_by: identity.id.clone(),
+        held_at: Utc::now(),
+    };
+    let data = match serde_json::to_string(&hold) {
+        Ok(d) => d,
+        Err(e) => {
+            return error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("serialization error: {e}"),
+            );
+        }
+    };
+    let key = StateKey::new(
+        hold.namespace.as_str(),
+        hold.tenant.as_str(),
+        KeyKind::AuditHold,
+        &hold.action_id,
+    );
+    let gw = state.gateway.read().await;
+    if let Err(e) = gw.state_store().set(&key, &data, None).await {
+        return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string());
+    }
+
+    info!(
+        action_id = %hold.action_id,
+        namespace = %hold.namespace,
+        tenant = %hold.tenant,
+        held_by = %hold.held_by,
+        "audit hold placed"
+    );
+    (StatusCode::CREATED, Json(serde_json::json!(hold))).into_response()
+}
+
+/// `GET /v1/audit/holds` -- list held actions for a tenant.
+#[utoipa::path(
+    get,
+    path = "/v1/audit/holds",
+    tag = "Audit",
+    summary = "List legal holds",
+    description = "Returns the legal holds placed on audit records in the given namespace and tenant, oldest first.",
+    params(ListAuditHoldsParams),
+    responses(
+        (status = 200, description = "Holds for the tenant", body = ListAuditHoldsResponse),
+        (status = 403, description = "Caller not authorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+pub async fn list_audit_holds(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Query(params): Query<ListAuditHoldsParams>,
+) -> impl IntoResponse {
+    if !identity.can_manage_scope(&params.tenant, &params.namespace) {
+        return error_response(
+            StatusCode::FORBIDDEN,
+            &format!(
+                "forbidden: no grant covers tenant={} namespace={}",
+                params.tenant, params.namespace
+            ),
+        );
+    }
+
+    let gw = state.gateway.read().await;
+    let entries = match gw
+        .state_store()
+        .scan_keys(&params.namespace, &params.tenant, KeyKind::AuditHold, None)
+        .await
+    {
+        Ok(entries) => entries,
+        Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()),
+    };
+
+    let mut holds: Vec<AuditHold> = entries
+        .into_iter()
+        .filter_map(|(_, value)| serde_json::from_str(&value).ok())
+        .collect();
+    holds.sort_by_key(|h| h.held_at);
+
+    let count = holds.len();
+    (
+        StatusCode::OK,
+        Json(serde_json::json!(ListAuditHoldsResponse { holds, count })),
+    )
+        .into_response()
+}
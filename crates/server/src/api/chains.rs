@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use axum::Json;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
@@ -10,6 +12,7 @@ use acteon_core::{ChainConfig, ChainStatus, DagResponse, StepKind};
 use acteon_state::{KeyKind, StateKey};
 
 use super::AppState;
+use super::pagination::offset_pages;
 use super::schemas::ErrorResponse;
 use crate::auth::identity::CallerIdentity;
 
@@ -633,6 +636,9 @@ pub struct ChainDefinitionSummary {
     pub on_failure: String,
     /// Optional timeout in seconds.
     pub timeout_seconds: Option<u64>,
+    /// Labels attached to the definition.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
 }
 
 /// Response for listing chain definitions.
@@ -640,6 +646,39 @@ pub struct ChainDefinitionSummary {
 pub struct ListChainDefinitionsResponse {
     /// List of chain definition summaries.
     pub definitions: Vec<ChainDefinitionSummary>,
+    /// Number of definitions matching the filters, before pagination.
+    pub total: usize,
+}
+
+/// Query parameters for listing chain definitions.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct ChainDefinitionQueryParams {
+    /// Only return definitions whose name contains this substring
+    /// (case-insensitive).
+    pub name: Option<String>,
+    /// Comma-separated `key=value` pairs; a definition must carry every
+    /// listed label to match.
+    pub label: Option<String>,
+    /// Maximum number of results. Omit to return every match.
+    pub limit: Option<usize>,
+    /// Number of results to skip (default: 0).
+    #[serde(default)]
+    pub offset: usize,
+}
+
+/// Parse a `key=value,key=value` label selector.
+fn parse_label_selector(selector: &str) -> Result<Vec<(&str, &str)>, String> {
+    selector
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            pair.split_once('=')
+                .map(|(k, v)| (k.trim(), v.trim()))
+                .filter(|(k, _)| !k.is_empty())
+                .ok_or_else(|| format!("invalid label selector '{pair}': expected key=value"))
+        })
+        .collect()
 }
 
 /// Validation error response returned when a chain config is invalid.
@@ -670,37 +709,71 @@ fn format_failure_policy(policy: &acteon_core::ChainFailurePolicy) -> String {
     path = "/v1/chains/definitions",
     tag = "Chains",
     summary = "List chain definitions",
-    description = "Returns all registered chain definitions with summary information.",
+    description = "Returns registered chain definitions with summary information, sorted by name. Supports name-substring and label filters plus limit/offset pagination.",
+    params(ChainDefinitionQueryParams),
     responses(
         (status = 200, description = "Chain definition list", body = ListChainDefinitionsResponse),
+        (status = 400, description = "Invalid label selector", body = ErrorResponse),
     )
 )]
-pub async fn list_definitions(State(state): State<AppState>) -> impl IntoResponse {
+pub async fn list_definitions(
+    State(state): State<AppState>,
+    Query(params): Query<ChainDefinitionQueryParams>,
+) -> impl IntoResponse {
+    let selector = match params.label.as_deref().map(parse_label_selector) {
+        Some(Ok(selector)) => selector,
+        Some(Err(error)) => {
+            return (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response();
+        }
+        None => Vec::new(),
+    };
+    let name_filter = params.name.as_deref().map(str::to_lowercase);
+
     let gw = state.gateway.read().await;
-    let configs = gw.chain_configs();
-
-    let definitions: Vec<ChainDefinitionSummary> = configs
-        .iter()
-        .map(|config| ChainDefinitionSummary {
-            name: config.name.clone(),
-            steps_count: config.steps.len(),
-            has_branches: config.steps.iter().any(|s| !s.branches.is_empty()),
-            has_parallel: config
-                .steps
-                .iter()
-                .any(|s| matches!(s.kind(), StepKind::Parallel(_))),
-            has_sub_chains: config
-                .steps
+    let configs: Vec<ChainConfig> = gw
+        .chain_configs()
+        .into_iter()
+        .filter(|config| {
+            name_filter
+                .as_deref()
+                .is_none_or(|needle| config.name.to_lowercase().contains(needle))
+        })
+        .filter(|config| {
+            selector
                 .iter()
-                .any(|s| matches!(s.kind(), StepKind::SubChain(_))),
-            on_failure: format_failure_policy(&config.on_failure),
-            timeout_seconds: config.timeout_seconds,
+                .all(|(k, v)| config.labels.get(*k).is_some_and(|actual| actual == v))
         })
         .collect();
+    let total = configs.len();
+
+    let definitions: Vec<ChainDefinitionSummary> = offset_pages(
+        configs,
+        |a, b| a.name.cmp(&b.name),
+        params.offset,
+        params.limit,
+    )
+    .iter()
+    .map(|config| ChainDefinitionSummary {
+        name: config.name.clone(),
+        steps_count: config.steps.len(),
+        has_branches: config.steps.iter().any(|s| !s.branches.is_empty()),
+        has_parallel: config
+            .steps
+            .iter()
+            .any(|s| matches!(s.kind(), StepKind::Parallel(_))),
+        has_sub_chains: config
+            .steps
+            .iter()
+            .any(|s| matches!(s.kind(), StepKind::SubChain(_))),
+        on_failure: format_failure_policy(&config.on_failure),
+        timeout_seconds: config.timeout_seconds,
+        labels: config.labels.clone(),
+    })
+    .collect();
 
     (
         StatusCode::OK,
-        Json(ListChainDefinitionsResponse { definitions }),
+        Json(ListChainDefinitionsResponse { definitions, total }),
     )
         .into_response()
 }
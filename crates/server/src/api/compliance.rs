@@ -3,12 +3,14 @@
 //! Provides the current compliance status and audit hash chain verification.
 
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::auth::identity::CallerIdentity;
+
 use super::AppState;
 use super::schemas::ErrorResponse;
 
@@ -18,6 +20,7 @@ use super::schemas::ErrorResponse;
 
 /// Current compliance status including active mode and feature flags.
 #[derive(Debug, Serialize, ToSchema)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ComplianceStatusResponse {
     /// Active compliance mode (`"none"`, `"soc2"`, or `"hipaa"`).
     #[schema(example = "soc2")]
@@ -28,6 +31,8 @@ pub struct ComplianceStatusResponse {
     pub immutable_audit: bool,
     /// Whether `SHA-256` hash chaining is enabled.
     pub hash_chain: bool,
+    /// Whether actions are forbidden from opting out of payload encryption.
+    pub forbid_encryption_opt_out: bool,
 }
 
 /// Request body for verifying an audit hash chain.
@@ -86,12 +91,14 @@ pub async fn get_compliance_status(State(state): State<AppState>) -> impl IntoRe
             sync_audit_writes: config.sync_audit_writes,
             immutable_audit: config.immutable_audit,
             hash_chain: config.hash_chain,
+            forbid_encryption_opt_out: config.forbid_encryption_opt_out,
         },
         None => ComplianceStatusResponse {
             mode: "none".to_string(),
             sync_audit_writes: false,
             immutable_audit: false,
             hash_chain: false,
+            forbid_encryption_opt_out: false,
         },
     };
     Json(response)
@@ -142,3 +149,63 @@ pub async fn verify_audit_chain(
         )),
     }
 }
+
+/// Recompute the hash of the most recent audit record for an action and
+/// compare it to the stored `record_hash`.
+#[utoipa::path(
+    get,
+    path = "/v1/audit/{action_id}/verify",
+    tag = "compliance",
+    params(
+        ("action_id" = String, Path, description = "Action ID whose record to verify")
+    ),
+    responses(
+        (status = 200, description = "Record hash verification result", body = acteon_core::RecordHashVerification),
+        (status = 400, description = "Hash chaining is not enabled", body = ErrorResponse),
+        (status = 403, description = "No grant covers the record", body = ErrorResponse),
+        (status = 404, description = "Not found or audit not enabled", body = ErrorResponse),
+    )
+)]
+pub async fn verify_audit_record(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Path(action_id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    let error = |status: StatusCode, error: String| (status, Json(ErrorResponse { error }));
+    let Some(ref audit) = state.audit else {
+        return Err(error(StatusCode::NOT_FOUND, "audit is not enabled".into()));
+    };
+
+    let record = audit
+        .get_by_action_id(&action_id)
+        .await
+        .map_err(|e| error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .ok_or_else(|| {
+            error(
+                StatusCode::NOT_FOUND,
+                format!("no audit record found for action: {action_id}"),
+            )
+        })?;
+    if !identity.is_authorized(
+        &record.tenant,
+        &record.namespace,
+        &record.provider,
+        &record.action_type,
+    ) {
+        return Err(error(
+            StatusCode::FORBIDDEN,
+            "no grant covers this audit record".into(),
+        ));
+    }
+
+    let gateway = state.gateway.read().await;
+    gateway
+        .verify_audit_record(&record)
+        .map(Json)
+        .ok_or_else(|| {
+            error(
+                StatusCode::BAD_REQUEST,
+                "hash chaining is not enabled".into(),
+            )
+        })
+}
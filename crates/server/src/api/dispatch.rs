@@ -4,7 +4,7 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use serde::Deserialize;
 
-use acteon_core::{Action, ActionOutcome};
+use acteon_core::{Action, ActionOutcome, DispatchPreview};
 
 use crate::auth::identity::CallerIdentity;
 use crate::auth::role::Permission;
@@ -27,6 +27,11 @@ pub struct DispatchQuery {
     /// the action, recording state, or emitting audit records.
     #[serde(default)]
     pub dry_run: bool,
+    /// With `dry_run`, returns a [`DispatchPreview`] (rendered payload,
+    /// routing, and attachments) instead of the bare verdict. Only honored
+    /// by the single-action endpoint.
+    #[serde(default)]
+    pub verbose: bool,
 }
 
 /// `POST /v1/dispatch` -- dispatch a single action through the gateway pipeline.
@@ -34,21 +39,24 @@ pub struct DispatchQuery {
 /// Expects a JSON body that deserializes to an [`Action`]. Returns the
 /// resulting [`ActionOutcome`] as JSON.
 ///
-/// Pass `?dry_run=true` to evaluate rules without executing the action.
+/// Pass `?dry_run=true` to evaluate rules without executing the action, and
+/// add `&verbose=true` to get a [`DispatchPreview`] instead of the verdict.
 #[utoipa::path(
     post,
     path = "/v1/dispatch",
     tag = "Dispatch",
     summary = "Dispatch action",
-    description = "Sends a single action through the gateway pipeline (lock, rules, execute) and returns the outcome. Pass ?dry_run=true to evaluate rules without executing.",
+    description = "Sends a single action through the gateway pipeline (lock, rules, execute) and returns the outcome. Pass ?dry_run=true to evaluate rules without executing; add &verbose=true to also return the rendered payload, routing, and attachments.",
     request_body(content = Action, description = "Action to dispatch"),
     params(
-        ("dry_run" = Option<bool>, Query, description = "Evaluate rules without executing the action")
+        ("dry_run" = Option<bool>, Query, description = "Evaluate rules without executing the action"),
+        ("verbose" = Option<bool>, Query, description = "With dry_run, return a DispatchPreview instead of the verdict")
     ),
     responses(
-        (status = 200, description = "Action dispatched successfully", body = ActionOutcome),
+        (status = 200, description = "Action dispatched successfully; a DispatchPreview for verbose dry-runs", body = ActionOutcome),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 422, description = "Verbose dry-run: template rendering or attachment resolution failed", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse)
     )
 )]
@@ -69,19 +77,25 @@ pub async fn dispatch(
         ));
     }
 
-    // Check grant-level authorization.
-    if !identity.is_authorized(
-        &action.tenant,
-        &action.namespace,
-        &action.provider,
-        &action.action_type,
-    ) {
+    // Check grant-level authorization against the provider and action
+    // type the action resolves to once namespace defaults apply.
+    let (provider, action_type) = resolved_target(&*state.gateway.read().await, &action);
+    if provider.is_empty() {
+        return Ok((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!(ErrorResponse {
+                error: acteon_gateway::GatewayError::MissingProvider(action.namespace.to_string())
+                    .to_string(),
+            })),
+        ));
+    }
+    if !identity.is_authorized(&action.tenant, &action.namespace, &provider, &action_type) {
         return Ok((
             StatusCode::FORBIDDEN,
             Json(serde_json::json!(ErrorResponse {
                 error: format!(
-                    "forbidden: no grant covers tenant={}, namespace={}, provider={}, action={}",
-                    action.tenant, action.namespace, action.provider, action.action_type
+                    "forbidden: no grant covers tenant={}, namespace={}, provider={provider}, action={action_type}",
+                    action.tenant, action.namespace
                 ),
             })),
         ));
@@ -175,6 +189,12 @@ pub async fn dispatch(
     action.trace_context = super::trace_context::capture_trace_context();
 
     let gw = state.gateway.read().await;
+    if query.dry_run && query.verbose {
+        return Ok(match gw.preview_dispatch(action).await {
+            Ok(preview) => (StatusCode::OK, Json(serde_json::json!(preview))),
+            Err(e) => preview_error_response(&e),
+        });
+    }
     let result = if query.dry_run {
         gw.dispatch_dry_run(action, Some(&caller)).await
     } else {
@@ -248,25 +268,27 @@ pub async fn dispatch_batch(
         ));
     }
 
-    // Check each action individually for grant authorization.
+    // Check each action individually for grant authorization. Actions
+    // with no resolvable provider are left for the gateway to reject
+    // per entry.
+    let gw = state.gateway.read().await;
     for action in &actions {
-        if !identity.is_authorized(
-            &action.tenant,
-            &action.namespace,
-            &action.provider,
-            &action.action_type,
-        ) {
+        let (provider, action_type) = resolved_target(&gw, action);
+        if !provider.is_empty()
+            && !identity.is_authorized(&action.tenant, &action.namespace, &provider, &action_type)
+        {
             return Ok((
                 StatusCode::FORBIDDEN,
                 Json(vec![serde_json::json!(ErrorResponse {
                     error: format!(
-                        "forbidden: no grant covers tenant={}, namespace={}, provider={}, action={}",
-                        action.tenant, action.namespace, action.provider, action.action_type,
+                        "forbidden: no grant covers tenant={}, namespace={}, provider={provider}, action={action_type}",
+                        action.tenant, action.namespace,
                     ),
                 })]),
             ));
         }
     }
+    drop(gw);
 
     // Check per-tenant rate limits for all tenants in the batch if enabled (skip for dry-run).
     if !query.dry_run
@@ -354,6 +376,100 @@ pub async fn dispatch_batch(
     Ok((StatusCode::OK, Json(body)))
 }
 
+/// `POST /v1/dispatch/preview` -- compute the provider request an action
+/// would produce without executing it.
+///
+/// Runs enrichment, template rendering, and rule evaluation, applies any
+/// `modify` patch, and returns the final payload. Unlike `?dry_run=true`,
+/// which only reports the verdict, the response carries the rendered
+/// request. Nothing is locked, recorded, or audited, so signature and
+/// replay checks do not apply.
+#[utoipa::path(
+    post,
+    path = "/v1/dispatch/preview",
+    tag = "Dispatch",
+    summary = "Preview dispatch",
+    description = "Runs rules and template rendering for an action and returns the final provider payload, verdict, and target provider without executing anything.",
+    request_body(content = Action, description = "Action to preview"),
+    responses(
+        (status = 200, description = "Rendered provider request", body = DispatchPreview),
+        (status = 400, description = "Action has no provider", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 422, description = "Template rendering or attachment resolution failed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn preview_dispatch(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Json(action): Json<Action>,
+) -> impl IntoResponse {
+    if !identity.role.has_permission(Permission::Dispatch) {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!(ErrorResponse {
+                error: "insufficient permissions: dispatch requires admin or operator role".into(),
+            })),
+        );
+    }
+
+    let gw = state.gateway.read().await;
+    let (provider, action_type) = resolved_target(&gw, &action);
+    if !identity.is_authorized(&action.tenant, &action.namespace, &provider, &action_type) {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!(ErrorResponse {
+                error: format!(
+                    "forbidden: no grant covers tenant={}, namespace={}, provider={provider}, action={action_type}",
+                    action.tenant, action.namespace
+                ),
+            })),
+        );
+    }
+
+    match gw.preview_dispatch(action).await {
+        Ok(preview) => (StatusCode::OK, Json(serde_json::json!(preview))),
+        Err(e) => preview_error_response(&e),
+    }
+}
+
+/// Map a preview failure to a response. Errors in the previewed action
+/// itself -- a missing provider, a template that fails to render, an
+/// unresolvable attachment -- are the caller's to fix, so they are reported
+/// with their message rather than as a server error.
+fn preview_error_response(
+    e: &acteon_gateway::GatewayError,
+) -> (StatusCode, Json<serde_json::Value>) {
+    use acteon_gateway::GatewayError;
+
+    let status = match e {
+        GatewayError::MissingProvider(_) => StatusCode::BAD_REQUEST,
+        GatewayError::TemplateRender(_) | GatewayError::Attachment(_) => {
+            StatusCode::UNPROCESSABLE_ENTITY
+        }
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (
+        status,
+        Json(serde_json::json!(ErrorResponse {
+            error: e.to_string()
+        })),
+    )
+}
+
+/// The provider and action type `action` runs as once its namespace's
+/// defaults fill in any omitted fields.
+fn resolved_target(gw: &acteon_gateway::Gateway, action: &Action) -> (String, String) {
+    match gw.namespace_defaults(&action.namespace) {
+        Some(defaults) => {
+            let (provider, action_type) = defaults.resolve(action);
+            (provider.to_owned(), action_type.to_owned())
+        }
+        None => (action.provider.to_string(), action.action_type.clone()),
+    }
+}
+
 /// Verify every action in a batch against the signature verifier.
 ///
 /// Returns `Ok(rejections)` where `rejections[i]` is the HTTP 400
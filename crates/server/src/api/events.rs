@@ -9,6 +9,7 @@ use axum::response::IntoResponse;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use acteon_core::EventTransition;
 use acteon_state::{KeyKind, StateKey};
 
 use crate::auth::identity::CallerIdentity;
@@ -66,6 +67,25 @@ pub struct TransitionResponse {
     pub notify: bool,
 }
 
+/// Query parameters scoping a single event lookup.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct EventScopeParams {
+    /// Event namespace.
+    pub namespace: String,
+    /// Event tenant.
+    pub tenant: String,
+}
+
+/// Response for an event's transition history.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EventHistoryResponse {
+    /// The event fingerprint.
+    #[schema(example = "abc123")]
+    pub fingerprint: String,
+    /// Recorded transitions, oldest first.
+    pub transitions: Vec<EventTransition>,
+}
+
 /// Query parameters for listing events.
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct EventQueryParams {
@@ -357,26 +377,101 @@ pub async fn transition_event(
     }
 
     let gw = state.gateway.read().await;
-    let state_store = gw.state_store();
+    let current_state = match gw
+        .transition_event(
+            &request.namespace,
+            &request.tenant,
+            &fingerprint,
+            &request.to,
+            identity.id.as_str(),
+        )
+        .await
+    {
+        Ok(Some(current_state)) => current_state,
+        Ok(None) => {
+            return Ok((
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!(ErrorResponse {
+                    error: format!("event not found: {fingerprint}"),
+                })),
+            ));
+        }
+        Err(e) => {
+            return Ok((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!(ErrorResponse {
+                    error: e.to_string(),
+                })),
+            ));
+        }
+    };
+
+    let response = TransitionResponse {
+        fingerprint,
+        previous_state: current_state,
+        new_state: request.to,
+        notify: false, // Could be determined by state machine config
+    };
+
+    Ok((StatusCode::OK, Json(serde_json::json!(response))))
+}
 
+/// `GET /v1/events/{fingerprint}/history` -- get the transition history of an event.
+#[utoipa::path(
+    get,
+    path = "/v1/events/{fingerprint}/history",
+    tag = "Events",
+    summary = "Get event history",
+    description = "Returns the bounded, ordered list of state transitions an event has gone through, oldest first.",
+    params(
+        ("fingerprint" = String, Path, description = "Event fingerprint"),
+        ("namespace" = String, Query, description = "Event namespace"),
+        ("tenant" = String, Query, description = "Event tenant"),
+    ),
+    responses(
+        (status = 200, description = "Event history retrieved", body = EventHistoryResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+    )
+)]
+pub async fn get_event_history(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Path(fingerprint): Path<String>,
+    Query(params): Query<EventScopeParams>,
+) -> Result<impl IntoResponse, ServerError> {
+    // Check role permission.
+    if !identity.role.has_permission(Permission::AuditRead) {
+        return Ok((
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!(ErrorResponse {
+                error: "insufficient permissions".into(),
+            })),
+        ));
+    }
+    if !identity.can_manage_scope(&params.tenant, &params.namespace) {
+        return Ok((
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!(ErrorResponse {
+                error: format!(
+                    "forbidden: no grant covers tenant={} namespace={}",
+                    params.tenant, params.namespace
+                ),
+            })),
+        ));
+    }
+
+    let gw = state.gateway.read().await;
     let state_key = StateKey::new(
-        request.namespace.as_str(),
-        request.tenant.as_str(),
+        params.namespace.as_str(),
+        params.tenant.as_str(),
         KeyKind::EventState,
         &fingerprint,
     );
 
-    // Get current state
-    let current_state = match state_store.get(&state_key).await {
-        Ok(Some(value)) => {
-            let parsed: serde_json::Value =
-                serde_json::from_str(&value).unwrap_or(serde_json::json!({"state": value}));
-            parsed
-                .get("state")
-                .and_then(|s| s.as_str())
-                .unwrap_or("unknown")
-                .to_string()
-        }
+    match gw.state_store().get(&state_key).await {
+        Ok(Some(_)) => {}
         Ok(None) => {
             return Ok((
                 StatusCode::NOT_FOUND,
@@ -393,33 +488,24 @@ pub async fn transition_event(
                 })),
             ));
         }
-    };
-
-    // Update state
-    let new_state_value = serde_json::json!({
-        "state": &request.to,
-        "fingerprint": &fingerprint,
-        "updated_at": chrono::Utc::now().to_rfc3339(),
-    });
+    }
 
-    if let Err(e) = state_store
-        .set(&state_key, &new_state_value.to_string(), None)
+    match gw
+        .event_history(&params.namespace, &params.tenant, &fingerprint)
         .await
     {
-        return Ok((
+        Ok(transitions) => {
+            let response = EventHistoryResponse {
+                fingerprint,
+                transitions,
+            };
+            Ok((StatusCode::OK, Json(serde_json::json!(response))))
+        }
+        Err(e) => Ok((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!(ErrorResponse {
                 error: e.to_string(),
             })),
-        ));
+        )),
     }
-
-    let response = TransitionResponse {
-        fingerprint,
-        previous_state: current_state,
-        new_state: request.to,
-        notify: false, // Could be determined by state machine config
-    };
-
-    Ok((StatusCode::OK, Json(serde_json::json!(response))))
 }
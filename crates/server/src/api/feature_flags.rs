@@ -0,0 +1,185 @@
+//! Feature flags API endpoints.
+//!
+//! Per-tenant boolean flags that rules read as `flags.<name>` and providers
+//! receive in their dispatch context. Flags are resolved on every dispatch,
+//! so a change here takes effect immediately without reloading rules.
+
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use tracing::info;
+use utoipa::ToSchema;
+
+use acteon_core::{TenantFeatureFlags, validate_flag_name};
+
+use crate::auth::identity::CallerIdentity;
+use crate::auth::role::Permission;
+
+use super::AppState;
+use super::schemas::ErrorResponse;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetFeatureFlagRequest {
+    /// Whether the flag is on.
+    #[schema(example = true)]
+    pub enabled: bool,
+}
+
+fn error_response(status: StatusCode, message: &str) -> axum::response::Response {
+    (
+        status,
+        Json(serde_json::json!(ErrorResponse {
+            error: message.to_owned(),
+        })),
+    )
+        .into_response()
+}
+
+fn scope_forbidden(namespace: &str, tenant: &str) -> axum::response::Response {
+    error_response(
+        StatusCode::FORBIDDEN,
+        &format!("forbidden: no grant covers tenant={tenant} namespace={namespace}"),
+    )
+}
+
+fn manage_forbidden() -> axum::response::Response {
+    error_response(
+        StatusCode::FORBIDDEN,
+        "insufficient permissions: feature flags manage requires admin or operator role",
+    )
+}
+
+/// `GET /v1/flags/{namespace}/{tenant}` -- list a tenant's flags.
+#[utoipa::path(
+    get,
+    path = "/v1/flags/{namespace}/{tenant}",
+    tag = "Feature Flags",
+    summary = "List feature flags",
+    description = "Returns every feature flag set for the given namespace and tenant. Flags that are not listed are disabled.",
+    params(
+        ("namespace" = String, Path, description = "Namespace"),
+        ("tenant" = String, Path, description = "Tenant"),
+    ),
+    responses(
+        (status = 200, description = "Tenant feature flags", body = TenantFeatureFlags),
+        (status = 403, description = "Caller not authorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+pub async fn list_feature_flags(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Path((namespace, tenant)): Path<(String, String)>,
+) -> impl IntoResponse {
+    if !identity.can_manage_scope(&tenant, &namespace) {
+        return scope_forbidden(&namespace, &tenant);
+    }
+
+    let gw = state.gateway.read().await;
+    match gw.get_feature_flags(&namespace, &tenant).await {
+        Ok(record) => (StatusCode::OK, Json(serde_json::json!(record))).into_response(),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()),
+    }
+}
+
+/// `PUT /v1/flags/{namespace}/{tenant}/{name}` -- set a flag.
+#[utoipa::path(
+    put,
+    path = "/v1/flags/{namespace}/{tenant}/{name}",
+    tag = "Feature Flags",
+    summary = "Set a feature flag",
+    description = "Turns a single flag on or off for the given namespace and tenant. The change applies to the next dispatch.",
+    params(
+        ("namespace" = String, Path, description = "Namespace"),
+        ("tenant" = String, Path, description = "Tenant"),
+        ("name" = String, Path, description = "Flag name"),
+    ),
+    request_body(content = SetFeatureFlagRequest, description = "Flag value"),
+    responses(
+        (status = 200, description = "Updated tenant feature flags", body = TenantFeatureFlags),
+        (status = 400, description = "Invalid flag name", body = ErrorResponse),
+        (status = 403, description = "Caller not authorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+pub async fn set_feature_flag(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Path((namespace, tenant, name)): Path<(String, String, String)>,
+    Json(req): Json<SetFeatureFlagRequest>,
+) -> impl IntoResponse {
+    if !identity.role.has_permission(Permission::FeatureFlagsManage) {
+        return manage_forbidden();
+    }
+    if !identity.can_manage_scope(&tenant, &namespace) {
+        return scope_forbidden(&namespace, &tenant);
+    }
+    if let Err(e) = validate_flag_name(&name) {
+        return error_response(StatusCode::BAD_REQUEST, &e);
+    }
+
+    let gw = state.gateway.read().await;
+    match gw
+        .set_feature_flag(&namespace, &tenant, &name, req.enabled)
+        .await
+    {
+        Ok(record) => {
+            info!(
+                namespace = %namespace,
+                tenant = %tenant,
+                flag = %name,
+                enabled = req.enabled,
+                "feature flag set"
+            );
+            (StatusCode::OK, Json(serde_json::json!(record))).into_response()
+        }
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()),
+    }
+}
+
+/// `DELETE /v1/flags/{namespace}/{tenant}/{name}` -- remove a flag.
+#[utoipa::path(
+    delete,
+    path = "/v1/flags/{namespace}/{tenant}/{name}",
+    tag = "Feature Flags",
+    summary = "Delete a feature flag",
+    description = "Removes a flag from the tenant. A removed flag reads as disabled.",
+    params(
+        ("namespace" = String, Path, description = "Namespace"),
+        ("tenant" = String, Path, description = "Tenant"),
+        ("name" = String, Path, description = "Flag name"),
+    ),
+    responses(
+        (status = 204, description = "Deleted"),
+        (status = 403, description = "Caller not authorized", body = ErrorResponse),
+        (status = 404, description = "Flag not set", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+pub async fn delete_feature_flag(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Path((namespace, tenant, name)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    if !identity.role.has_permission(Permission::FeatureFlagsManage) {
+        return manage_forbidden();
+    }
+    if !identity.can_manage_scope(&tenant, &namespace) {
+        return scope_forbidden(&namespace, &tenant);
+    }
+
+    let gw = state.gateway.read().await;
+    match gw.delete_feature_flag(&namespace, &tenant, &name).await {
+        Ok(true) => {
+            info!(namespace = %namespace, tenant = %tenant, flag = %name, "feature flag deleted");
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Ok(false) => error_response(
+            StatusCode::NOT_FOUND,
+            &format!("feature flag not set: {namespace}/{tenant}/{name}"),
+        ),
+        Err(e) => error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()),
+    }
+}
@@ -3,7 +3,7 @@
 //! Provides endpoints for querying and managing event groups.
 
 use axum::Json;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use serde::{Deserialize, Serialize};
@@ -16,6 +16,7 @@ use crate::auth::role::Permission;
 use crate::error::ServerError;
 
 use super::AppState;
+use super::pagination::offset_pages;
 use super::schemas::ErrorResponse;
 
 /// Summary of an event group.
@@ -71,7 +72,8 @@ pub struct GroupDetailResponse {
     pub group: GroupSummary,
     /// Common labels for all events in the group.
     pub labels: std::collections::HashMap<String, String>,
-    /// Event fingerprints in this group.
+    /// Event fingerprints in this group, oldest first: ordered by arrival
+    /// time, then action ID.
     #[serde(rename = "events")]
     pub event_fingerprints: Vec<String>,
 }
@@ -90,13 +92,28 @@ pub struct FlushGroupResponse {
     pub status: String,
 }
 
+/// Query parameters for listing groups.
+#[derive(Debug, Default, Deserialize)]
+pub struct ListGroupsParams {
+    /// Maximum number of results. Omit to return every visible group.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Number of results to skip (default: 0).
+    #[serde(default)]
+    pub offset: usize,
+}
+
 /// `GET /v1/groups` -- list all active event groups.
 #[utoipa::path(
     get,
     path = "/v1/groups",
     tag = "Groups",
     summary = "List groups",
-    description = "Lists all active event groups awaiting notification.",
+    description = "Lists active event groups awaiting notification, oldest first.",
+    params(
+        ("limit" = Option<usize>, Query, description = "Maximum number of results"),
+        ("offset" = Option<usize>, Query, description = "Number of results to skip"),
+    ),
     responses(
         (status = 200, description = "List of groups", body = ListGroupsResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
@@ -106,6 +123,7 @@ pub struct FlushGroupResponse {
 pub async fn list_groups(
     State(state): State<AppState>,
     axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Query(params): Query<ListGroupsParams>,
 ) -> Result<impl IntoResponse, ServerError> {
     // Check role permission.
     if !identity.role.has_permission(Permission::AuditRead) {
@@ -137,7 +155,19 @@ pub async fn list_groups(
         pending.len()
     };
 
-    let groups: Vec<GroupSummary> = pending.iter().map(GroupSummary::from).collect();
+    let groups: Vec<GroupSummary> = offset_pages(
+        pending,
+        |a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.group_key.cmp(&b.group_key))
+        },
+        params.offset,
+        params.limit,
+    )
+    .iter()
+    .map(GroupSummary::from)
+    .collect();
 
     let response = ListGroupsResponse { groups, total };
 
@@ -1,10 +1,19 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use axum::Json;
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 
+use acteon_state::{KeyKind, StateKey};
+
 use super::AppState;
-use super::schemas::{EmbeddingMetricsResponse, HealthResponse, MetricsResponse};
+use super::schemas::{
+    ComponentHealth, DetailedHealthResponse, EmbeddingMetricsResponse, HealthResponse,
+    MetricsResponse,
+};
 
 fn build_metrics_response(
     state: &AppState,
@@ -58,6 +67,7 @@ fn build_metrics_response(
         retention_errors: snap.retention_errors,
         stale_tasks_reaped: snap.stale_tasks_reaped,
         stale_task_reaper_errors: snap.stale_task_reaper_errors,
+        audit_records_dropped: snap.audit_records_dropped,
         wasm_invocations: snap.wasm_invocations,
         wasm_errors: snap.wasm_errors,
         signing_verified: snap.signing_verified,
@@ -95,6 +105,128 @@ pub async fn health(State(state): State<AppState>) -> impl IntoResponse {
     (StatusCode::OK, Json(body))
 }
 
+/// How long each component probe may take before it is reported `down`.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// `GET /health/detailed` -- reports the health of each backing component.
+#[utoipa::path(
+    get,
+    path = "/health/detailed",
+    tag = "Health",
+    summary = "Component health",
+    description = "Probes the state store and the audit store and reports each one's status and probe latency. Provider health is read from the periodic provider health checks. Requires authentication, since probe errors can reveal backend details.",
+    responses(
+        (status = 200, description = "Every component is healthy or disabled", body = DetailedHealthResponse),
+        (status = 503, description = "At least one component is degraded or down", body = DetailedHealthResponse)
+    )
+)]
+pub async fn health_detailed(State(state): State<AppState>) -> impl IntoResponse {
+    // Probes can be slow network calls; don't hold the gateway guard
+    // across them. Providers are not probed here: their health comes from
+    // the periodic provider health checks, read before the guard is
+    // dropped.
+    let (state_store, provider_health) = {
+        let gw = state.gateway.read().await;
+        let provider_health: Vec<_> = gw
+            .provider_names()
+            .into_iter()
+            .map(|name| (name.to_owned(), gw.provider_health(name)))
+            .collect();
+        (Arc::clone(gw.state_store()), provider_health)
+    };
+
+    let state_probe = async {
+        let started = Instant::now();
+        let key = StateKey::new(
+            "_health",
+            "_health",
+            KeyKind::Custom("probe".to_owned()),
+            "ping",
+        );
+        let error = match tokio::time::timeout(PROBE_TIMEOUT, state_store.get(&key)).await {
+            Ok(result) => result.err().map(|e| e.to_string()),
+            Err(_) => Some(timed_out()),
+        };
+        component(started, error)
+    };
+    let audit_probe = async {
+        let Some(audit) = &state.audit else {
+            return ComponentHealth {
+                status: "disabled".into(),
+                error: None,
+                latency_ms: None,
+            };
+        };
+        let started = Instant::now();
+        let query = acteon_audit::AuditQuery {
+            limit: Some(1),
+            ..acteon_audit::AuditQuery::default()
+        };
+        let error = match tokio::time::timeout(PROBE_TIMEOUT, audit.query(&query)).await {
+            Ok(result) => result.err().map(|e| e.to_string()),
+            Err(_) => Some(timed_out()),
+        };
+        component(started, error)
+    };
+    let providers = {
+        let unhealthy: Vec<String> = provider_health
+            .iter()
+            .filter_map(|(name, health)| {
+                let health = health.as_ref().filter(|h| !h.classifier.is_healthy())?;
+                Some(match &health.last_error {
+                    Some(error) => format!("{name}: {error}"),
+                    None => name.clone(),
+                })
+            })
+            .collect();
+        let status = if unhealthy.is_empty() {
+            "ok"
+        } else if unhealthy.len() < provider_health.len() {
+            "degraded"
+        } else {
+            "down"
+        };
+        ComponentHealth {
+            status: status.into(),
+            error: (!unhealthy.is_empty()).then(|| unhealthy.join("; ")),
+            latency_ms: None,
+        }
+    };
+    let (state_store, audit_store) = tokio::join!(state_probe, audit_probe);
+
+    let components = BTreeMap::from([
+        ("state_store".to_owned(), state_store),
+        ("audit_store".to_owned(), audit_store),
+        ("providers".to_owned(), providers),
+    ]);
+    let healthy = components
+        .values()
+        .all(|c| c.status == "ok" || c.status == "disabled");
+    let body = DetailedHealthResponse {
+        status: if healthy { "ok" } else { "degraded" }.into(),
+        components,
+    };
+    let status = if healthy {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(body))
+}
+
+fn timed_out() -> String {
+    format!("probe timed out after {}s", PROBE_TIMEOUT.as_secs())
+}
+
+/// A probed component: `ok`, or `down` with `error`.
+fn component(started: Instant, error: Option<String>) -> ComponentHealth {
+    ComponentHealth {
+        status: if error.is_some() { "down" } else { "ok" }.into(),
+        error,
+        latency_ms: Some(u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX)),
+    }
+}
+
 /// `GET /metrics` -- returns gateway metrics as JSON.
 #[utoipa::path(
     get,
@@ -7,6 +7,7 @@ pub mod a2a_ssrf;
 pub mod analytics;
 pub mod approvals;
 pub mod audit;
+pub mod audit_holds;
 pub mod auth;
 pub mod bus;
 pub mod chains;
@@ -18,9 +19,11 @@ pub mod dlq;
 pub mod embeddings;
 pub mod events;
 pub mod executions;
+pub mod feature_flags;
 pub mod groups;
 pub mod health;
 pub mod openapi;
+mod pagination;
 pub mod plugins;
 pub mod prometheus;
 pub mod provider_health;
@@ -50,6 +53,7 @@ use axum::middleware;
 use axum::routing::{delete, get, post, put};
 use tokio::sync::RwLock;
 use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_http::decompression::RequestDecompressionLayer;
 use tower_http::services::ServeDir;
 use tower_http::trace::TraceLayer;
 use utoipa::OpenApi;
@@ -179,6 +183,7 @@ pub fn router(state: AppState) -> Router {
         // Dispatch
         .route("/v1/dispatch", post(dispatch::dispatch))
         .route("/v1/dispatch/batch", post(dispatch::dispatch_batch))
+        .route("/v1/dispatch/preview", post(dispatch::preview_dispatch))
         // A2A protocol — JSON-RPC 2.0 + REST binding (Phase 2). The
         // body-reading routes carry an explicit A2A body-size cap; the
         // REST cancel verb shares the `tasks/{id}` path (its `{id}`
@@ -228,6 +233,8 @@ pub fn router(state: AppState) -> Router {
         .route("/v1/rules", get(rules::list_rules))
         .route("/v1/rules/coverage", get(rules::rule_coverage))
         .route("/v1/rules/reload", post(rules::reload_rules))
+        .route("/v1/rules/versions", get(rules::list_rule_versions))
+        .route("/v1/rules/rollback", post(rules::rollback_rules))
         .route("/v1/rules/{name}/enabled", put(rules::set_rule_enabled))
         .route("/v1/rules/evaluate", post(rules::evaluate_rules))
         // Analytics
@@ -235,8 +242,17 @@ pub fn router(state: AppState) -> Router {
         // Audit
         .route("/v1/audit", get(audit::query_audit))
         .route("/v1/audit/replay", post(replay::replay_audit))
+        .route("/v1/audit/holds", get(audit_holds::list_audit_holds))
         .route("/v1/audit/{action_id}", get(audit::get_audit_by_action))
+        .route(
+            "/v1/audit/{action_id}/hold",
+            post(audit_holds::place_audit_hold),
+        )
         .route("/v1/audit/{action_id}/replay", post(replay::replay_action))
+        .route(
+            "/v1/audit/{action_id}/evaluate",
+            post(rules::evaluate_audit_record),
+        )
         // Action signature verification
         .route("/v1/actions/{id}/verify", get(verify::verify_action))
         // Dead-letter queue
@@ -249,6 +265,10 @@ pub fn router(state: AppState) -> Router {
             "/v1/events/{fingerprint}/transition",
             put(events::transition_event),
         )
+        .route(
+            "/v1/events/{fingerprint}/history",
+            get(events::get_event_history),
+        )
         // Groups (event batching)
         .route("/v1/groups", get(groups::list_groups))
         .route("/v1/groups/{group_key}", get(groups::get_group))
@@ -385,6 +405,15 @@ pub fn router(state: AppState) -> Router {
                 .put(time_intervals::update_time_interval)
                 .delete(time_intervals::delete_time_interval),
         )
+        // Feature flags
+        .route(
+            "/v1/flags/{namespace}/{tenant}",
+            get(feature_flags::list_feature_flags),
+        )
+        .route(
+            "/v1/flags/{namespace}/{tenant}/{name}",
+            put(feature_flags::set_feature_flag).delete(feature_flags::delete_feature_flag),
+        )
         // Retention policies
         .route(
             "/v1/retention",
@@ -402,6 +431,10 @@ pub fn router(state: AppState) -> Router {
             get(compliance::get_compliance_status),
         )
         .route("/v1/audit/verify", post(compliance::verify_audit_chain))
+        .route(
+            "/v1/audit/{action_id}/verify",
+            get(compliance::verify_audit_record),
+        )
         // Embeddings
         .route("/v1/embeddings/similarity", post(embeddings::similarity))
         // Approvals (list requires auth)
@@ -420,6 +453,10 @@ pub fn router(state: AppState) -> Router {
                 .put(templates::update_profile)
                 .delete(templates::delete_profile),
         )
+        .route(
+            "/v1/templates/profiles/{id}/restore",
+            post(templates::restore_profile),
+        )
         .route("/v1/templates/render", post(templates::render_preview))
         .route(
             "/v1/templates/reload",
@@ -435,11 +472,21 @@ pub fn router(state: AppState) -> Router {
                 .put(templates::update_template)
                 .delete(templates::delete_template),
         )
+        .route(
+            "/v1/templates/{id}/restore",
+            post(templates::restore_template),
+        )
+        .route(
+            "/v1/templates/{id}/references",
+            get(templates::list_template_references),
+        )
         // Provider health dashboard
         .route(
             "/v1/providers/health",
             get(provider_health::list_provider_health),
         )
+        // Per-component health (authenticated: probe errors name backends)
+        .route("/health/detailed", get(health::health_detailed))
         // Bus (Phase 1 + 2)
         .route(
             "/v1/bus/topics",
@@ -673,6 +720,9 @@ pub fn router(state: AppState) -> Router {
         // W3C Trace Context propagation (extracts traceparent/tracestate from
         // incoming requests so OTel can link server spans to the caller's trace).
         .layer(middleware::from_fn(trace_context::propagate_trace_context))
+        // Accept `Content-Encoding: gzip` request bodies from clients that
+        // compress large batches; body limits apply to the decompressed size.
+        .layer(RequestDecompressionLayer::new())
         .layer(TraceLayer::new_for_http())
         .layer(cors)
 }
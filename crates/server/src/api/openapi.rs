@@ -3,8 +3,9 @@
 use acteon_audit::{AuditPage, AuditQuery, AuditRecord};
 use acteon_core::{
     Action, ActionError, ActionMetadata, ActionOutcome, AnalyticsBucket, AnalyticsInterval,
-    AnalyticsMetric, AnalyticsQuery, AnalyticsResponse, AnalyticsTopEntry, OverageBehavior,
-    ProviderResponse, QuotaUsage, QuotaWindow, ResponseStatus, TemplateProfileField,
+    AnalyticsMetric, AnalyticsQuery, AnalyticsResponse, AnalyticsTopEntry, FailoverAttempt,
+    OverageBehavior, ProviderResponse, QuotaUsage, QuotaWindow, ReasonCode, ResponseStatus,
+    TemplateOutputType, TemplateProfileField,
 };
 
 use super::approvals::{
@@ -18,7 +19,8 @@ use super::chains::{
 use super::dlq::{DlqDrainResponse, DlqEntry, DlqStatsResponse};
 use super::embeddings::{SimilarityRequest, SimilarityResponse};
 use super::events::{
-    EventStateResponse, ListEventsResponse, TransitionRequest, TransitionResponse,
+    EventHistoryResponse, EventStateResponse, ListEventsResponse, TransitionRequest,
+    TransitionResponse,
 };
 use super::executions::{
     ExecutionHistoryResponse, ExecutionSummary, ListExecutionsResponse, ResetExecutionRequest,
@@ -41,10 +43,13 @@ use super::replay::{ReplayResult, ReplaySummary};
 use super::retention::{
     CreateRetentionRequest, ListRetentionResponse, RetentionResponse, UpdateRetentionRequest,
 };
-use super::rules::{EvaluateRulesRequest, EvaluateRulesResponse, RuleTraceEntryResponse};
+use super::rules::{
+    EvaluateAuditRecordRequest, EvaluateRulesRequest, EvaluateRulesResponse, RuleTraceEntryResponse,
+};
 use super::schemas::{
-    EmbeddingMetricsResponse, ErrorResponse, HealthResponse, MetricsResponse, ReloadRequest,
-    ReloadResponse, RuleSummary, SetEnabledRequest, SetEnabledResponse,
+    ComponentHealth, DetailedHealthResponse, EmbeddingMetricsResponse, ErrorResponse,
+    HealthResponse, MetricsResponse, ReloadRequest, ReloadResponse, RollbackRulesRequest,
+    RuleSetVersionResponse, RuleSummary, SetEnabledRequest, SetEnabledResponse,
 };
 use super::workflows::{
     ListWorkflowsResponse, RecordCheckpointRequest, RecordCheckpointResponse, StartChildRequest,
@@ -84,6 +89,7 @@ use acteon_core::{
         (name = "Retention", description = "Per-tenant data retention policy management"),
         (name = "Provider Health", description = "Per-provider health and performance monitoring"),
         (name = "Plugins", description = "WASM plugin management"),
+        (name = "Feature Flags", description = "Per-tenant feature flags for rules and providers"),
         (name = "Templates", description = "Payload template and profile management"),
         (name = "Analytics", description = "Aggregated action analytics and metrics"),
         (name = "swarm", description = "Long-running agent-swarm runs (ambient agent provider)"),
@@ -91,15 +97,22 @@ use acteon_core::{
     ),
     paths(
         super::health::health,
+        super::health::health_detailed,
         super::health::metrics,
         super::dispatch::dispatch,
         super::dispatch::dispatch_batch,
+        super::dispatch::preview_dispatch,
         super::rules::list_rules,
         super::rules::reload_rules,
+        super::rules::list_rule_versions,
+        super::rules::rollback_rules,
         super::rules::set_rule_enabled,
         super::rules::evaluate_rules,
+        super::rules::evaluate_audit_record,
         super::audit::query_audit,
         super::audit::get_audit_by_action,
+        super::audit_holds::place_audit_hold,
+        super::audit_holds::list_audit_holds,
         super::replay::replay_action,
         super::replay::replay_audit,
         super::dlq::dlq_stats,
@@ -107,6 +120,7 @@ use acteon_core::{
         super::events::list_events,
         super::events::get_event,
         super::events::transition_event,
+        super::events::get_event_history,
         super::groups::list_groups,
         super::groups::get_group,
         super::groups::flush_group,
@@ -174,11 +188,14 @@ use acteon_core::{
         super::templates::get_template,
         super::templates::update_template,
         super::templates::delete_template,
+        super::templates::restore_template,
+        super::templates::list_template_references,
         super::templates::create_profile,
         super::templates::list_profiles,
         super::templates::get_profile,
         super::templates::update_profile,
         super::templates::delete_profile,
+        super::templates::restore_profile,
         super::templates::render_preview,
         super::analytics::query_analytics,
         super::rules::rule_coverage,
@@ -192,6 +209,9 @@ use acteon_core::{
         super::time_intervals::get_time_interval,
         super::time_intervals::update_time_interval,
         super::time_intervals::delete_time_interval,
+        super::feature_flags::list_feature_flags,
+        super::feature_flags::set_feature_flag,
+        super::feature_flags::delete_feature_flag,
         super::signing_keys::discover_signing_keys,
         super::swarm::list_swarm_runs,
         super::swarm::get_swarm_run,
@@ -241,15 +261,17 @@ use acteon_core::{
         super::bus::reject_bus_approval,
     ),
     components(schemas(
-        Action, ActionOutcome, ProviderResponse, ResponseStatus, ActionError,
-        ActionMetadata,
-        HealthResponse, MetricsResponse, RuleSummary,
-        ReloadRequest, ReloadResponse, SetEnabledRequest, SetEnabledResponse,
+        Action, ActionOutcome, ProviderResponse, ResponseStatus, ActionError, ReasonCode,
+        FailoverAttempt, ActionMetadata,
+        HealthResponse, DetailedHealthResponse, ComponentHealth, MetricsResponse, RuleSummary,
+        ReloadRequest, ReloadResponse, RollbackRulesRequest, RuleSetVersionResponse,
+        SetEnabledRequest, SetEnabledResponse,
         ErrorResponse,
         AuditRecord, AuditQuery, AuditPage,
         DlqStatsResponse, DlqEntry, DlqDrainResponse,
         ReplayResult, ReplaySummary,
         EventStateResponse, ListEventsResponse, TransitionRequest, TransitionResponse,
+        EventHistoryResponse, acteon_core::EventTransition, acteon_core::TransitionTrigger,
         GroupSummary, ListGroupsResponse, GroupDetailResponse, FlushGroupResponse,
         ApprovalActionResponse, ApprovalStatusResponse, ApprovalQueryParams, ListApprovalsResponse,
         ChainSummary, ListChainsResponse, ChainDetailResponse, ChainStepStatus, ChainCancelRequest,
@@ -272,7 +294,7 @@ use acteon_core::{
         CreateQuotaRequest, UpdateQuotaRequest, QuotaResponse, QuotaUsageResponse,
         ListQuotasResponse,
         QuotaWindow, OverageBehavior, QuotaUsage,
-        EvaluateRulesRequest, EvaluateRulesResponse, RuleTraceEntryResponse,
+        EvaluateRulesRequest, EvaluateAuditRecordRequest, EvaluateRulesResponse, RuleTraceEntryResponse,
         CreateRetentionRequest, UpdateRetentionRequest, RetentionResponse,
         ListRetentionResponse,
         ProviderHealthStatus, ListProviderHealthResponse,
@@ -282,7 +304,8 @@ use acteon_core::{
         super::templates::CreateProfileRequest, super::templates::UpdateProfileRequest,
         super::templates::ProfileResponse, super::templates::ListProfilesResponse,
         super::templates::RenderPreviewRequest, super::templates::RenderPreviewResponse,
-        TemplateProfileField,
+        super::templates::TemplateReference, super::templates::TemplateReferencesResponse,
+        TemplateProfileField, TemplateOutputType,
         AnalyticsMetric, AnalyticsInterval, AnalyticsQuery, AnalyticsResponse,
         AnalyticsBucket, AnalyticsTopEntry,
         acteon_core::coverage::CoverageQuery, acteon_core::coverage::CoverageKey,
@@ -301,6 +324,11 @@ use acteon_core::{
         super::time_intervals::TimeIntervalResponse,
         super::time_intervals::ListTimeIntervalsResponse,
         super::time_intervals::TimeRangeInput, super::time_intervals::TimeOfDayInput,
+        acteon_core::FeatureFlags, acteon_core::TenantFeatureFlags,
+        acteon_core::AuditHold, super::audit_holds::PlaceAuditHoldRequest,
+        super::audit_holds::ListAuditHoldsResponse,
+        acteon_core::DispatchPreview, acteon_core::AttachmentPreview,
+        super::feature_flags::SetFeatureFlagRequest,
         super::signing_keys::SigningKeyEntry, super::signing_keys::SigningKeysResponse,
         super::swarm::SwarmRunList, super::swarm::SwarmRunApiSnapshot,
         super::bus::CreateTopicRequest, super::bus::TopicResponse,
@@ -0,0 +1,44 @@
+//! Offset pagination for list endpoints that page over in-memory results.
+
+use std::cmp::Ordering;
+
+/// Sort `items` with `compare` and return the page starting at `offset`,
+/// at most `limit` long (everything after `offset` when `None`).
+///
+/// The sources behind these endpoints (state-store scans, in-memory maps)
+/// return items in no particular order, and that order can change between
+/// requests. Sorting by a total order first is what keeps consecutive
+/// offset-based pages from overlapping or skipping items, so `compare`
+/// should break ties, e.g. on an ID.
+pub(crate) fn offset_pages<T>(
+    mut items: Vec<T>,
+    compare: impl FnMut(&T, &T) -> Ordering,
+    offset: usize,
+    limit: Option<usize>,
+) -> Vec<T> {
+    items.sort_by(compare);
+    items
+        .into_iter()
+        .skip(offset)
+        .take(limit.unwrap_or(usize::MAX))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_cover_every_item_once() {
+        let items = vec![(2, "b"), (1, "z"), (2, "a"), (1, "y"), (3, "c")];
+        let compare = |a: &(i32, &str), b: &(i32, &str)| a.cmp(b);
+
+        let mut seen = Vec::new();
+        for offset in (0..items.len()).step_by(2) {
+            seen.extend(offset_pages(items.clone(), compare, offset, Some(2)));
+        }
+        assert_eq!(seen, [(1, "y"), (1, "z"), (2, "a"), (2, "b"), (3, "c")]);
+        assert_eq!(offset_pages(items.clone(), compare, 4, None), [(3, "c")]);
+        assert!(offset_pages(items, compare, 9, Some(2)).is_empty());
+    }
+}
@@ -347,6 +347,12 @@ fn render_snapshot(snap: &MetricsSnapshot) -> String {
         "Stale-task reaper processing errors.",
         snap.stale_task_reaper_errors,
     );
+    write_counter(
+        &mut buf,
+        "acteon_audit_records_dropped_total",
+        "Audit records lost after a batched write and its per-record fallback both failed.",
+        snap.audit_records_dropped,
+    );
     write_counter(
         &mut buf,
         "acteon_wasm_invocations_total",
@@ -631,6 +637,7 @@ mod tests {
             retention_errors: 0,
             stale_tasks_reaped: 0,
             stale_task_reaper_errors: 0,
+            audit_records_dropped: 0,
             wasm_invocations: 0,
             wasm_errors: 0,
             signing_verified: 0,
@@ -680,6 +687,7 @@ mod tests {
             retention_errors: 1,
             stale_tasks_reaped: 4,
             stale_task_reaper_errors: 1,
+            audit_records_dropped: 3,
             wasm_invocations: 6,
             wasm_errors: 2,
             signing_verified: 42,
@@ -893,6 +901,7 @@ mod tests {
         "acteon_retention_errors_total",
         "acteon_stale_tasks_reaped_total",
         "acteon_stale_task_reaper_errors_total",
+        "acteon_audit_records_dropped_total",
         "acteon_wasm_invocations_total",
         "acteon_wasm_errors_total",
         "acteon_signing_verified_total",
@@ -988,6 +997,7 @@ mod tests {
         assert!(output.contains("acteon_retention_errors_total 1"));
         assert!(output.contains("acteon_stale_tasks_reaped_total 4"));
         assert!(output.contains("acteon_stale_task_reaper_errors_total 1"));
+        assert!(output.contains("acteon_audit_records_dropped_total 3"));
         assert!(output.contains("acteon_wasm_invocations_total 6"));
         assert!(output.contains("acteon_wasm_errors_total 2"));
         assert!(output.contains("acteon_signing_verified_total 42"));
@@ -1222,7 +1232,7 @@ mod tests {
         providers.insert("email".to_string(), sample_provider_stats());
         render_provider_metrics(&mut output, &providers);
 
-        // 42 counter metrics from the snapshot
+        // 43 counter metrics from the snapshot
         for metric in EXPECTED_COUNTER_METRICS {
             assert!(output.contains(metric), "Missing snapshot metric: {metric}");
         }
@@ -1232,12 +1242,12 @@ mod tests {
             assert!(output.contains(name), "Missing provider metric: {name}");
         }
 
-        // 43 counters + 1 gauge from the snapshot + 8 provider families = 52.
+        // 44 counters + 1 gauge from the snapshot + 8 provider families = 53.
         let type_lines: Vec<&str> = output
             .lines()
             .filter(|l| l.starts_with("# TYPE "))
             .collect();
-        assert_eq!(type_lines.len(), 52, "Expected 52 TYPE declarations");
+        assert_eq!(type_lines.len(), 53, "Expected 53 TYPE declarations");
     }
 
     #[test]
@@ -1253,8 +1263,8 @@ mod tests {
             .collect();
         assert_eq!(
             type_lines.len(),
-            44,
-            "Expected 44 TYPE declarations without providers (43 counters + 1 gauge)"
+            45,
+            "Expected 45 TYPE declarations without providers (44 counters + 1 gauge)"
         );
     }
 
@@ -13,7 +13,7 @@ use super::AppState;
     path = "/v1/providers/health",
     tag = "Provider Health",
     summary = "Provider health dashboard",
-    description = "Returns per-provider health status, circuit breaker state, execution metrics, and latency percentiles.",
+    description = "Returns per-provider health status, circuit breaker state, execution metrics, and latency percentiles. Health comes from the periodic provider health checks and changes only after consecutive failed or passed checks, per the configured hysteresis. Reading it does not run a check.",
     responses(
         (status = 200, description = "Provider health data", body = ListProviderHealthResponse)
     )
@@ -24,17 +24,13 @@ pub async fn list_provider_health(
 ) -> impl IntoResponse {
     let gw = state.gateway.read().await;
 
-    // Run health checks on all registered providers.
-    let health_results = gw.check_provider_health().await;
-
     // Take a snapshot of per-provider execution metrics.
     let metrics_map = gw.provider_metrics().snapshot();
 
-    let mut providers = Vec::with_capacity(health_results.len());
-
-    for result in &health_results {
-        let name = &result.provider;
+    let names = gw.provider_names();
+    let mut providers = Vec::with_capacity(names.len());
 
+    for name in names {
         // Get circuit breaker state if configured.
         let circuit_breaker_state = if let Some(registry) = gw.circuit_breakers() {
             if let Some(cb) = registry.get(name) {
@@ -47,12 +43,22 @@ pub async fn list_provider_health(
         };
 
         // Merge with execution metrics (if any requests have been made).
-        let pm = metrics_map.get(name.as_str());
+        let pm = metrics_map.get(name);
+
+        // Health as classified by the periodic checks. A provider that has
+        // not been checked yet is reported healthy.
+        let health = gw.provider_health(name);
 
         providers.push(ProviderHealthStatus {
-            provider: name.clone(),
-            healthy: result.healthy,
-            health_check_error: result.error.clone(),
+            provider: name.to_owned(),
+            healthy: health.as_ref().is_none_or(|h| h.classifier.is_healthy()),
+            consecutive_failures: health
+                .as_ref()
+                .map_or(0, |h| h.classifier.consecutive_failures()),
+            consecutive_successes: health
+                .as_ref()
+                .map_or(0, |h| h.classifier.consecutive_successes()),
+            health_check_error: health.and_then(|h| h.last_error),
             circuit_breaker_state,
             total_requests: pm.map_or(0, |s| s.total_requests),
             successes: pm.map_or(0, |s| s.successes),
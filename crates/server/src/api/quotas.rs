@@ -13,8 +13,9 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
 use acteon_core::{
-    MAX_POLICIES_PER_BUCKET, MAX_WINDOW_SECONDS, OverageBehavior, QuotaPolicy, QuotaUsage,
-    QuotaWindow, compute_window_boundaries, quota_counter_key, validate_quota_scope_identifier,
+    MAX_POLICIES_PER_BUCKET, MAX_WINDOW_SECONDS, ORG_QUOTA_COUNTER_TENANT, OverageBehavior,
+    QuotaPolicy, QuotaUsage, QuotaWindow, compute_window_boundaries, quota_counter_key,
+    quota_counter_tenant, validate_quota_scope_identifier,
 };
 use acteon_state::{KeyKind, StateKey};
 
@@ -33,9 +34,21 @@ pub struct CreateQuotaRequest {
     /// Namespace this quota applies to.
     #[schema(example = "notifications")]
     pub namespace: String,
-    /// Tenant this quota applies to.
+    /// Tenant this quota applies to. Omitted for org-level policies.
+    #[serde(default)]
     #[schema(example = "tenant-1")]
     pub tenant: String,
+    /// Optional organization. When set, the policy is an org-level
+    /// quota whose single counter is shared by every tenant in
+    /// `members`, in addition to any per-tenant policies. Requires a
+    /// grant that is wildcard on tenants and namespaces.
+    #[serde(default)]
+    #[schema(example = "acme-corp")]
+    pub org: Option<String>,
+    /// Tenants that belong to `org`. Required with `org`, rejected
+    /// without it.
+    #[serde(default)]
+    pub members: Vec<String>,
     /// Optional provider scope. When omitted, the policy is
     /// generic and counts every dispatch for the tenant. When set,
     /// only dispatches whose `action.provider` equals this value
@@ -72,6 +85,10 @@ pub struct CreateQuotaRequest {
     /// Arbitrary key-value labels.
     #[serde(default)]
     pub labels: HashMap<String, String>,
+    /// Usage percentages (1–100) that emit a `quota_threshold_crossed`
+    /// stream event once per window.
+    #[serde(default)]
+    pub alert_thresholds: Vec<u8>,
 }
 
 /// Request body for updating a quota policy.
@@ -98,6 +115,12 @@ pub struct UpdateQuotaRequest {
     /// Updated labels.
     #[serde(default)]
     pub labels: Option<HashMap<String, String>>,
+    /// Updated alert thresholds.
+    #[serde(default)]
+    pub alert_thresholds: Option<Vec<u8>>,
+    /// Updated member tenants (org-level policies only).
+    #[serde(default)]
+    pub members: Option<Vec<String>>,
 }
 
 /// Full quota policy response (includes optional current usage).
@@ -109,6 +132,12 @@ pub struct QuotaResponse {
     pub namespace: String,
     /// Tenant.
     pub tenant: String,
+    /// Optional organization whose shared counter this quota uses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub org: Option<String>,
+    /// Member tenants of an org-level policy.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<String>,
     /// Optional provider scope (`None` = generic catch-all).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub provider: Option<String>,
@@ -135,6 +164,9 @@ pub struct QuotaResponse {
     /// Arbitrary labels.
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     pub labels: HashMap<String, String>,
+    /// Alert thresholds, as percentages of `max_actions`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub alert_thresholds: Vec<u8>,
     /// Current usage (populated on single-get requests).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage: Option<QuotaUsageResponse>,
@@ -229,6 +261,8 @@ fn policy_to_response(policy: &QuotaPolicy, usage: Option<QuotaUsageResponse>) -
         id: policy.id.clone(),
         namespace: policy.namespace.clone(),
         tenant: policy.tenant.clone(),
+        org: policy.org.clone(),
+        members: policy.members.clone(),
         provider: policy.provider.clone(),
         principal: policy.principal.clone(),
         per_principal: policy.per_principal,
@@ -240,6 +274,7 @@ fn policy_to_response(policy: &QuotaPolicy, usage: Option<QuotaUsageResponse>) -
         updated_at: policy.updated_at,
         description: policy.description.clone(),
         labels: policy.labels.clone(),
+        alert_thresholds: policy.alert_thresholds.clone(),
         usage,
     }
 }
@@ -335,6 +370,7 @@ async fn read_usage(
     let Some(counter_id) = quota_counter_key(
         &policy.namespace,
         &policy.tenant,
+        policy.org.as_deref(),
         policy.principal.as_deref(),
         policy.provider.as_deref(),
         &policy.window,
@@ -350,7 +386,7 @@ async fn read_usage(
     };
     let counter_key = StateKey::new(
         policy.namespace.as_str(),
-        policy.tenant.as_str(),
+        quota_counter_tenant(&policy.tenant, policy.org.as_deref()),
         KeyKind::QuotaUsage,
         &counter_id,
     );
@@ -389,6 +425,50 @@ fn tenant_forbidden(namespace: &str, tenant: &str) -> axum::response::Response {
     )
 }
 
+/// Whether the caller may read or manage `policy`. Org-level policies
+/// span tenants, so only a caller with global grants may touch them.
+fn can_manage_policy(identity: &CallerIdentity, policy: &QuotaPolicy) -> bool {
+    if policy.org.is_some() {
+        identity.has_global_scope()
+    } else {
+        identity.can_manage_scope(&policy.tenant, &policy.namespace)
+    }
+}
+
+/// `403 Forbidden` for a caller without global grants touching an
+/// org-level policy.
+fn org_forbidden() -> axum::response::Response {
+    error_response(
+        StatusCode::FORBIDDEN,
+        "forbidden: org-level quotas require a grant on all tenants and namespaces",
+    )
+}
+
+/// `403 Forbidden` for a caller that fails [`can_manage_policy`].
+fn policy_forbidden(policy: &QuotaPolicy) -> axum::response::Response {
+    if policy.org.is_some() {
+        org_forbidden()
+    } else {
+        tenant_forbidden(&policy.namespace, &policy.tenant)
+    }
+}
+
+/// Validate the member tenants of an org-level policy.
+fn validate_members(members: &[String]) -> Result<(), String> {
+    if members.is_empty() {
+        return Err("org-level quotas must list at least one member tenant".to_owned());
+    }
+    for m in members {
+        validate_quota_scope_identifier(m).map_err(|e| format!("invalid member tenant: {e}"))?;
+        if m == ORG_QUOTA_COUNTER_TENANT {
+            return Err(format!(
+                "tenant {ORG_QUOTA_COUNTER_TENANT} cannot be an org member"
+            ));
+        }
+    }
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Handlers
 // ---------------------------------------------------------------------------
@@ -412,10 +492,44 @@ fn tenant_forbidden(namespace: &str, tenant: &str) -> axum::response::Response {
 pub async fn create_quota(
     State(state): State<AppState>,
     axum::Extension(identity): axum::Extension<CallerIdentity>,
-    Json(req): Json<CreateQuotaRequest>,
+    Json(mut req): Json<CreateQuotaRequest>,
 ) -> impl IntoResponse {
-    if !identity.can_manage_scope(&req.tenant, &req.namespace) {
-        return tenant_forbidden(&req.namespace, &req.tenant);
+    // An org-level policy is the single definition of the org's limit
+    // and membership, and stored under the reserved org tenant rather
+    // than any member's.
+    if let Some(ref o) = req.org {
+        if !identity.has_global_scope() {
+            return org_forbidden();
+        }
+        if !req.tenant.is_empty() {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "org-level quotas take member tenants in `members`, not `tenant`",
+            );
+        }
+        if let Err(e) = validate_quota_scope_identifier(o) {
+            return error_response(StatusCode::BAD_REQUEST, &format!("invalid org: {e}"));
+        }
+        if let Err(e) = validate_members(&req.members) {
+            return error_response(StatusCode::BAD_REQUEST, &e);
+        }
+        ORG_QUOTA_COUNTER_TENANT.clone_into(&mut req.tenant);
+    } else {
+        if !identity.can_manage_scope(&req.tenant, &req.namespace) {
+            return tenant_forbidden(&req.namespace, &req.tenant);
+        }
+        if !req.members.is_empty() {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "members may only be set on org-level quotas",
+            );
+        }
+        if req.tenant == ORG_QUOTA_COUNTER_TENANT {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                &format!("tenant {ORG_QUOTA_COUNTER_TENANT} is reserved for org-level quotas"),
+            );
+        }
     }
     // Validate identifiers first — reject colon injection and
     // oversized names before we touch the state store.
@@ -446,6 +560,9 @@ pub async fn create_quota(
             "max_actions must be greater than 0",
         );
     }
+    if let Err(e) = acteon_core::validate_alert_thresholds(&req.alert_thresholds) {
+        return error_response(StatusCode::BAD_REQUEST, &e);
+    }
 
     let gw = state.gateway.read().await;
     let state_store = gw.state_store();
@@ -473,7 +590,7 @@ pub async fn create_quota(
         );
     }
 
-    // Reject duplicates with the same (ns, tenant, provider,
+    // Reject duplicates with the same (ns, tenant, org, provider,
     // principal, per_principal) tuple: operators should pick exactly
     // one policy per scope. `per_principal` is part of the key
     // because a shared-bucket unscoped policy and a per-caller-bucket
@@ -482,11 +599,15 @@ pub async fn create_quota(
     // coexist.
     for existing_id in &existing_ids {
         if let Ok(Some(p)) = load_quota(state_store.as_ref(), existing_id).await
+            && p.org == req.org
             && p.provider == req.provider
             && p.principal == req.principal
             && p.per_principal == req.per_principal
         {
             let mut scope_parts: Vec<String> = Vec::new();
+            if let Some(ref o) = req.org {
+                scope_parts.push(format!("org={o}"));
+            }
             if let Some(ref pr) = req.provider {
                 scope_parts.push(format!("provider={pr}"));
             }
@@ -518,6 +639,8 @@ pub async fn create_quota(
         id: id.clone(),
         namespace: req.namespace.clone(),
         tenant: req.tenant.clone(),
+        org: req.org.clone(),
+        members: req.members.clone(),
         provider: req.provider.clone(),
         principal: req.principal.clone(),
         per_principal: req.per_principal,
@@ -529,6 +652,7 @@ pub async fn create_quota(
         updated_at: now,
         description: req.description,
         labels: req.labels,
+        alert_thresholds: req.alert_thresholds,
     };
 
     // Persist policy to state store.
@@ -604,7 +728,7 @@ pub async fn list_quotas(
 
         // Tenant authorization: only surface policies the caller's grants
         // cover (hierarchical). Prevents cross-tenant enumeration.
-        if !identity.can_manage_scope(&policy.tenant, &policy.namespace) {
+        if !can_manage_policy(&identity, &policy) {
             continue;
         }
 
@@ -695,8 +819,8 @@ pub async fn get_quota(
 
     match load_quota(state_store.as_ref(), &id).await {
         Ok(Some(policy)) => {
-            if !identity.can_manage_scope(&policy.tenant, &policy.namespace) {
-                return tenant_forbidden(&policy.namespace, &policy.tenant);
+            if !can_manage_policy(&identity, &policy) {
+                return policy_forbidden(&policy);
             }
             let usage = read_usage(state_store.as_ref(), &policy).await.ok();
             (
@@ -749,8 +873,8 @@ pub async fn update_quota(
         Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e),
     };
 
-    if !identity.can_manage_scope(&policy.tenant, &policy.namespace) {
-        return tenant_forbidden(&policy.namespace, &policy.tenant);
+    if !can_manage_policy(&identity, &policy) {
+        return policy_forbidden(&policy);
     }
 
     // Apply updates.
@@ -778,6 +902,24 @@ pub async fn update_quota(
     if let Some(labels) = req.labels {
         policy.labels = labels;
     }
+    if let Some(thresholds) = req.alert_thresholds {
+        if let Err(e) = acteon_core::validate_alert_thresholds(&thresholds) {
+            return error_response(StatusCode::BAD_REQUEST, &e);
+        }
+        policy.alert_thresholds = thresholds;
+    }
+    if let Some(members) = req.members {
+        if policy.org.is_none() {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "members may only be set on org-level quotas",
+            );
+        }
+        if let Err(e) = validate_members(&members) {
+            return error_response(StatusCode::BAD_REQUEST, &e);
+        }
+        policy.members = members;
+    }
 
     policy.updated_at = Utc::now();
 
@@ -838,8 +980,8 @@ pub async fn delete_quota(
         Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e),
     };
 
-    if !identity.can_manage_scope(&policy.tenant, &policy.namespace) {
-        return tenant_forbidden(&policy.namespace, &policy.tenant);
+    if !can_manage_policy(&identity, &policy) {
+        return policy_forbidden(&policy);
     }
 
     // Remove from state store.
@@ -898,14 +1040,15 @@ pub async fn get_quota_usage(
         Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e),
     };
 
-    if !identity.can_manage_scope(&policy.tenant, &policy.namespace) {
-        return tenant_forbidden(&policy.namespace, &policy.tenant);
+    if !can_manage_policy(&identity, &policy) {
+        return policy_forbidden(&policy);
     }
 
     let now = Utc::now();
     let Some(counter_id) = quota_counter_key(
         &policy.namespace,
         &policy.tenant,
+        policy.org.as_deref(),
         policy.principal.as_deref(),
         policy.provider.as_deref(),
         &policy.window,
@@ -928,7 +1071,7 @@ pub async fn get_quota_usage(
     };
     let counter_key = StateKey::new(
         policy.namespace.as_str(),
-        policy.tenant.as_str(),
+        quota_counter_tenant(&policy.tenant, policy.org.as_deref()),
         KeyKind::QuotaUsage,
         &counter_id,
     );
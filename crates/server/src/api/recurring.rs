@@ -86,9 +86,14 @@ pub struct CreateRecurringRequest {
     pub labels: HashMap<String, String>,
     /// Overlap policy: what to do when a new occurrence fires while the
     /// chain execution from the previous occurrence is still running
-    /// (`allow_all` (default) | `skip` | `cancel_other`).
+    /// (`allow_all` (default) | `skip` | `cancel_other` | `queue`).
     #[serde(default)]
     pub overlap_policy: Option<acteon_core::OverlapPolicy>,
+    /// Concurrency policy: what to do when a new occurrence fires while the
+    /// previous occurrence's dispatch is still in flight
+    /// (`allow` | `skip` (default) | `queue`).
+    #[serde(default)]
+    pub concurrency_policy: Option<acteon_core::ConcurrencyPolicy>,
 }
 
 /// Request body for updating a recurring action.
@@ -613,6 +618,7 @@ pub async fn create_recurring(
         description: req.description.or(req.name.clone()),
         labels: req.labels,
         overlap_policy: req.overlap_policy.unwrap_or_default(),
+        concurrency_policy: req.concurrency_policy.unwrap_or_default(),
         last_execution_id: None,
     };
 
@@ -1407,7 +1413,7 @@ pub async fn backfill_recurring(
     let mut dispatched = 0usize;
 
     for occurrence in &occurrences {
-        let mut payload = recurring.action_template.payload.clone();
+        let mut payload = recurring.render_payload(occurrence);
         if let Some(obj) = payload.as_object_mut() {
             // Mark like a normal recurring re-dispatch (quota exemption) and
             // stamp the occurrence so providers can distinguish backfills.
@@ -55,70 +55,28 @@ pub struct ReplaySummary {
     pub results: Vec<ReplayResult>,
 }
 
-/// Reconstruct an [`Action`] from an audit record's stored fields.
+/// Reconstruct an [`Action`] for replay, tagged with a `replayed_from`
+/// label pointing at the original.
 ///
 /// Returns `None` if the record has no stored payload (privacy mode was on).
 fn reconstruct_action(record: &acteon_audit::AuditRecord) -> Option<Action> {
-    let payload = record.action_payload.as_ref()?;
-
-    let mut action = Action::new(
-        record.namespace.as_str(),
-        record.tenant.as_str(),
-        record.provider.as_str(),
-        record.action_type.as_str(),
-        payload.clone(),
-    );
-
-    // Restore metadata and extra Action fields from the audit record.
-    if let Some(labels) = record.metadata.as_object() {
-        for (k, v) in labels {
-            // Skip system-prefixed keys; they are restored as typed fields below.
-            if k.starts_with("__") {
-                continue;
-            }
-            // Convert non-string values to their string representation instead
-            // of silently dropping them.
-            let s = if let Some(s) = v.as_str() {
-                s.to_owned()
-            } else {
-                v.to_string()
-            };
-            action.metadata.labels.insert(k.clone(), s);
-        }
-
-        // Restore extra Action fields stored with __ prefix.
-        if let Some(k) = labels.get("__dedup_key").and_then(|v| v.as_str()) {
-            action = action.with_dedup_key(k);
-        }
-        if let Some(f) = labels.get("__fingerprint").and_then(|v| v.as_str()) {
-            action = action.with_fingerprint(f);
-        }
-        if let Some(s) = labels.get("__status").and_then(|v| v.as_str()) {
-            action = action.with_status(s);
-        }
-        if let Some(t) = labels
-            .get("__starts_at")
-            .and_then(|v| v.as_str())
-            .and_then(|s| s.parse::<chrono::DateTime<chrono::Utc>>().ok())
-        {
-            action = action.with_starts_at(t);
-        }
-        if let Some(t) = labels
-            .get("__ends_at")
-            .and_then(|v| v.as_str())
-            .and_then(|s| s.parse::<chrono::DateTime<chrono::Utc>>().ok())
-        {
-            action = action.with_ends_at(t);
-        }
-    }
+    let mut action = restore_action(record)?;
     action
         .metadata
         .labels
         .insert("replayed_from".to_owned(), record.action_id.clone());
-
     Some(action)
 }
 
+/// Rebuild the [`Action`] an audit record was written for from its stored
+/// fields.
+///
+/// Returns `None` if the record has no stored payload (privacy mode was on).
+pub(crate) fn restore_action(record: &acteon_audit::AuditRecord) -> Option<Action> {
+    let payload = record.action_payload.clone()?;
+    Some(Action::from_audit(record, payload))
+}
+
 /// `POST /v1/audit/{action_id}/replay` -- replay a single action from the audit trail.
 #[utoipa::path(
     post,
@@ -20,8 +20,8 @@ use crate::auth::role::Permission;
 
 use super::AppState;
 use super::schemas::{
-    ErrorResponse, ReloadRequest, ReloadResponse, RuleSummary, SetEnabledRequest,
-    SetEnabledResponse,
+    ErrorResponse, ReloadRequest, ReloadResponse, RollbackRulesRequest, RuleSetVersionResponse,
+    RuleSummary, SetEnabledRequest, SetEnabledResponse,
 };
 
 // ---------------------------------------------------------------------------
@@ -64,6 +64,28 @@ pub struct EvaluateRulesRequest {
     pub mock_state: HashMap<String, String>,
 }
 
+/// Request body for re-evaluating rules against a stored audit record.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct EvaluateAuditRecordRequest {
+    /// When `true`, includes disabled rules in the trace (marked as skipped).
+    #[serde(default)]
+    pub include_disabled: bool,
+    /// When `true`, evaluates every rule even after the first match.
+    #[serde(default)]
+    pub evaluate_all: bool,
+    /// Optional timestamp override for time-sensitive rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evaluate_at: Option<DateTime<Utc>>,
+    /// When `true` and `evaluate_at` is unset, evaluates at the record's
+    /// original dispatch time.
+    #[serde(default)]
+    pub at_dispatch_time: bool,
+    /// Optional state key overrides for testing state-dependent conditions
+    /// without mutating real state.
+    #[serde(default)]
+    pub mock_state: HashMap<String, String>,
+}
+
 /// Per-rule trace entry in the evaluation response.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct RuleTraceEntryResponse {
@@ -280,6 +302,85 @@ pub async fn reload_rules(
     }
 }
 
+/// `GET /v1/rules/versions` -- list the active and retained rule set versions.
+#[utoipa::path(
+    get,
+    path = "/v1/rules/versions",
+    tag = "Rules",
+    summary = "List rule set versions",
+    description = "Returns the active rule set version followed by the versions retained for rollback, most recently replaced first.",
+    responses(
+        (status = 200, description = "Rule set versions", body = Vec<RuleSetVersionResponse>)
+    )
+)]
+pub async fn list_rule_versions(
+    State(state): State<AppState>,
+    axum::Extension(_identity): axum::Extension<CallerIdentity>,
+) -> impl IntoResponse {
+    let gw = state.gateway.read().await;
+    let body: Vec<RuleSetVersionResponse> = gw
+        .rule_set_versions()
+        .iter()
+        .map(version_response)
+        .collect();
+
+    (StatusCode::OK, Json(body))
+}
+
+/// `POST /v1/rules/rollback` -- restore a previously loaded rule set.
+#[utoipa::path(
+    post,
+    path = "/v1/rules/rollback",
+    tag = "Rules",
+    summary = "Roll back rules",
+    description = "Atomically replaces the active rule set with a retained earlier version. The replaced set is retained, so a rollback can itself be undone.",
+    request_body(content = RollbackRulesRequest, description = "Version to restore"),
+    responses(
+        (status = 200, description = "Rule set restored", body = RuleSetVersionResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Version not retained", body = ErrorResponse)
+    )
+)]
+pub async fn rollback_rules(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Json(body): Json<RollbackRulesRequest>,
+) -> impl IntoResponse {
+    if !identity.role.has_permission(Permission::RulesManage) {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!(ErrorResponse {
+                error: "insufficient permissions: rules management requires admin or operator role"
+                    .into(),
+            })),
+        );
+    }
+
+    let mut gw = state.gateway.write().await;
+    if let Err(e) = gw.rollback_rules(body.version) {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!(ErrorResponse {
+                error: e.to_string(),
+            })),
+        );
+    }
+    // The active version is always listed first.
+    let active = gw.rule_set_versions().first().map(version_response);
+    info!(version = body.version, "rules rolled back");
+
+    (StatusCode::OK, Json(serde_json::json!(active)))
+}
+
+fn version_response(v: &acteon_rules::RuleSetVersion) -> RuleSetVersionResponse {
+    RuleSetVersionResponse {
+        version: v.version,
+        loaded_at: v.loaded_at,
+        rule_count: v.rule_count,
+        active: v.active,
+    }
+}
+
 /// `PUT /v1/rules/{name}/enabled` -- enable or disable a rule by name.
 #[utoipa::path(
     put,
@@ -320,14 +421,19 @@ pub async fn set_rule_enabled(
     } else {
         gw.disable_rule(&name)
     };
+    let rule = found
+        .then(|| gw.rules().iter().find(|r| r.name == name))
+        .flatten();
 
-    if found {
+    if let Some(rule) = rule {
         let status = if body.enabled { "enabled" } else { "disabled" };
         (
             StatusCode::OK,
             Json(serde_json::json!(SetEnabledResponse {
-                name,
-                enabled: body.enabled,
+                name: rule.name.clone(),
+                priority: rule.priority,
+                enabled: rule.enabled,
+                description: rule.description.clone(),
                 status: status.into(),
             })),
         )
@@ -398,6 +504,7 @@ pub async fn evaluate_rules(
     )
     .with_metadata(acteon_core::ActionMetadata {
         labels: req.metadata,
+        ..Default::default()
     });
 
     let gw = state.gateway.read().await;
@@ -424,6 +531,124 @@ pub async fn evaluate_rules(
     }
 }
 
+/// `POST /v1/audit/{action_id}/evaluate` -- re-run rule evaluation against
+/// the action a stored audit record was written for.
+///
+/// Answers "why was this action handled the way it was" against the rules
+/// loaded now. Like the playground, nothing is dispatched.
+#[utoipa::path(
+    post,
+    path = "/v1/audit/{action_id}/evaluate",
+    tag = "Rules",
+    summary = "Evaluate rules against an audit record",
+    description = "Reconstructs the action from its audit record and evaluates the loaded rules against it, optionally at the original dispatch time. No side effects are executed.",
+    params(
+        ("action_id" = String, Path, description = "Action ID of the audit record")
+    ),
+    request_body(content = EvaluateAuditRecordRequest, description = "Evaluation options"),
+    responses(
+        (status = 200, description = "Rule evaluation trace", body = EvaluateRulesResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Audit record not found or audit not enabled", body = ErrorResponse),
+        (status = 422, description = "No stored payload to evaluate", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse)
+    )
+)]
+pub async fn evaluate_audit_record(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    extract::Path(action_id): extract::Path<String>,
+    Json(req): Json<EvaluateAuditRecordRequest>,
+) -> impl IntoResponse {
+    if !identity.role.has_permission(Permission::RulesTest) {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!(ErrorResponse {
+                error: "insufficient permissions: rules testing requires appropriate role".into(),
+            })),
+        );
+    }
+
+    let Some(ref audit) = state.audit else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!(ErrorResponse {
+                error: "audit is not enabled".into(),
+            })),
+        );
+    };
+
+    let record = match audit.get_by_action_id(&action_id).await {
+        Ok(Some(r)) => r,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!(ErrorResponse {
+                    error: format!("no audit record found for action: {action_id}"),
+                })),
+            );
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!(ErrorResponse {
+                    error: e.to_string(),
+                })),
+            );
+        }
+    };
+
+    if !identity.is_authorized(
+        &record.tenant,
+        &record.namespace,
+        &record.provider,
+        &record.action_type,
+    ) {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!(ErrorResponse {
+                error: "no grant covers this audit record".into(),
+            })),
+        );
+    }
+
+    let Some(action) = super::replay::restore_action(&record) else {
+        return (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(serde_json::json!(ErrorResponse {
+                error: "audit record has no stored payload (privacy mode was enabled)".into(),
+            })),
+        );
+    };
+
+    let evaluate_at = req
+        .evaluate_at
+        .or(req.at_dispatch_time.then_some(record.dispatched_at));
+
+    let gw = state.gateway.read().await;
+    match gw
+        .evaluate_rules(
+            &action,
+            req.include_disabled,
+            req.evaluate_all,
+            evaluate_at,
+            req.mock_state,
+        )
+        .await
+    {
+        Ok(trace) => {
+            let resp: EvaluateRulesResponse = trace.into();
+            (StatusCode::OK, Json(serde_json::json!(resp)))
+        }
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!(ErrorResponse {
+                error: e.to_string(),
+            })),
+        ),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Rule coverage
 // ---------------------------------------------------------------------------
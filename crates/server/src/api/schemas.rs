@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -11,6 +12,31 @@ pub struct HealthResponse {
     pub metrics: MetricsResponse,
 }
 
+/// Per-component health report.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DetailedHealthResponse {
+    /// `ok` when every component is `ok` or `disabled`, otherwise `degraded`.
+    #[schema(example = "ok")]
+    pub status: String,
+    /// Health of each component, keyed by `state_store`, `audit_store`, and
+    /// `providers`.
+    pub components: std::collections::BTreeMap<String, ComponentHealth>,
+}
+
+/// Health of a single component.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ComponentHealth {
+    /// One of `ok`, `degraded` (partly failing), `down`, or `disabled`.
+    #[schema(example = "ok")]
+    pub status: String,
+    /// Why the component is not `ok`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// How long the probe took, in milliseconds. Absent when disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+}
+
 /// Gateway dispatch metrics counters.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct MetricsResponse {
@@ -128,6 +154,10 @@ pub struct MetricsResponse {
     /// Stale-task reaper errors.
     #[schema(example = 0)]
     pub stale_task_reaper_errors: u64,
+    /// Audit records lost after a batched write and its per-record
+    /// fallback both failed.
+    #[schema(example = 0)]
+    pub audit_records_dropped: u64,
     /// WASM plugin invocations.
     #[schema(example = 0)]
     pub wasm_invocations: u64,
@@ -247,6 +277,30 @@ pub struct ReloadResponse {
     pub directory: String,
 }
 
+/// A rule set version retained by the engine.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RuleSetVersionResponse {
+    /// Version id, assigned in load order starting at 1.
+    #[schema(example = 3)]
+    pub version: u64,
+    /// When this rule set was loaded.
+    pub loaded_at: DateTime<Utc>,
+    /// Number of rules in the set.
+    #[schema(example = 5)]
+    pub rule_count: usize,
+    /// Whether this is the rule set currently being evaluated.
+    #[schema(example = true)]
+    pub active: bool,
+}
+
+/// Request body for rolling back to a previous rule set.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RollbackRulesRequest {
+    /// Version id to restore.
+    #[schema(example = 2)]
+    pub version: u64,
+}
+
 /// Request body for toggling a rule's enabled state.
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct SetEnabledRequest {
@@ -261,9 +315,15 @@ pub struct SetEnabledResponse {
     /// Rule name.
     #[schema(example = "block-spam")]
     pub name: String,
+    /// Evaluation priority (lower is evaluated first).
+    #[schema(example = 10)]
+    pub priority: i32,
     /// New enabled state.
     #[schema(example = true)]
     pub enabled: bool,
+    /// Optional human-readable description.
+    #[schema(example = "Blocks spam actions")]
+    pub description: Option<String>,
     /// Human-readable status string.
     #[schema(example = "enabled")]
     pub status: String,
@@ -321,6 +321,7 @@ async fn replay_from_audit(
             tenant: record.tenant.clone(),
             action_type: Some(record.action_type.clone()),
             action_id: Some(record.action_id.clone()),
+            traceparent: None,
         };
 
         // Track the latest replayed event ID for dedup cutoff.
@@ -483,6 +484,8 @@ pub(crate) fn stream_event_type_tag(event_type: &StreamEventType) -> &'static st
         StreamEventType::TaskTransitioned { .. } => "task_transitioned",
         StreamEventType::TaskHistoryAppended { .. } => "task_history_appended",
         StreamEventType::TaskArtifactUpdated { .. } => "task_artifact_updated",
+        StreamEventType::QuotaThresholdCrossed { .. } => "quota_threshold_crossed",
+        StreamEventType::DlqEntryAdded { .. } => "dlq_entry_added",
         StreamEventType::Unknown => "unknown",
     }
 }
@@ -567,6 +570,7 @@ mod tests {
         assert_eq!(
             stream_event_type_tag(&StreamEventType::ApprovalRequired {
                 approval_id: "a".into(),
+                url_params: None,
             }),
             "approval_required"
         );
@@ -624,6 +628,7 @@ mod tests {
             tenant: tenant.into(),
             action_type: Some(at.into()),
             action_id: Some("a".into()),
+            traceparent: None,
         }
     }
 
@@ -636,6 +641,7 @@ mod tests {
             tenant: tenant.into(),
             action_type: None,
             action_id: None,
+            traceparent: None,
         }
     }
 
@@ -1068,6 +1074,7 @@ mod tests {
             tenant: "t1".into(),
             action_type: Some("s".into()),
             action_id: Some("a".into()),
+            traceparent: None,
         };
 
         // Create an event with ID greater than the cutoff (should pass through).
@@ -1088,6 +1095,7 @@ mod tests {
             tenant: "t1".into(),
             action_type: Some("s".into()),
             action_id: Some("a".into()),
+            traceparent: None,
         };
 
         let s = make_event_stream(rx, None, StreamQuery::default(), guard, Some(last_id));
@@ -1389,6 +1397,7 @@ mod tests {
                     tenant: tenant.into(),
                     action_type: Some("send_email".into()),
                     action_id: Some(action_id.into()),
+                    traceparent: None,
                 }
             };
 
@@ -1466,6 +1475,7 @@ mod tests {
                 "t1",
                 StreamEventType::ApprovalRequired {
                     approval_id: "appr-2".into(),
+                    url_params: None,
                 },
             ),
         ];
@@ -392,6 +392,7 @@ fn build_chain_catchup(chain_state: &acteon_core::ChainState) -> Vec<Result<Even
             tenant: chain_state.tenant.clone(),
             action_type: None,
             action_id: None,
+            traceparent: None,
         };
 
         if let Some(sse_event) = serialize_catchup_event(&stream_event) {
@@ -426,6 +427,7 @@ fn build_chain_catchup(chain_state: &acteon_core::ChainState) -> Vec<Result<Even
             tenant: chain_state.tenant.clone(),
             action_type: None,
             action_id: None,
+            traceparent: None,
         };
         if let Some(sse_event) = serialize_catchup_event(&stream_event) {
             events.push(Ok(sse_event));
@@ -458,6 +460,7 @@ fn build_group_catchup(group: &acteon_core::EventGroup) -> Vec<Result<Event, Inf
         tenant: String::new(),
         action_type: None,
         action_id: None,
+        traceparent: None,
     };
     if let Some(sse_event) = serialize_catchup_event(&stream_event) {
         events.push(Ok(sse_event));
@@ -478,6 +481,7 @@ fn build_group_catchup(group: &acteon_core::EventGroup) -> Vec<Result<Event, Inf
                 tenant: String::new(),
                 action_type: None,
                 action_id: None,
+                traceparent: None,
             };
             if let Some(sse_event) = serialize_catchup_event(&stream_event) {
                 events.push(Ok(sse_event));
@@ -496,6 +500,7 @@ fn build_group_catchup(group: &acteon_core::EventGroup) -> Vec<Result<Event, Inf
                 tenant: String::new(),
                 action_type: None,
                 action_id: None,
+                traceparent: None,
             };
             if let Some(sse_event) = serialize_catchup_event(&stream_event) {
                 events.push(Ok(sse_event));
@@ -564,6 +569,7 @@ async fn build_action_catchup(
         tenant: record.tenant.clone(),
         action_type: Some(record.action_type.clone()),
         action_id: Some(record.action_id.clone()),
+        traceparent: None,
     };
 
     let mut events = Vec::new();
@@ -1181,6 +1187,9 @@ mod tests {
             config_snapshot: None,
             search_attributes: Default::default(),
             wait_state: None,
+            depth: 0,
+            steps_executed: 0,
+            limit_exceeded: None,
         }
     }
 
@@ -13,9 +13,10 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
 use acteon_core::template::{
-    Template, TemplateProfile, TemplateProfileField, validate_template_content,
-    validate_template_name,
+    DELETED_RETENTION_SECONDS, Template, TemplateOutputType, TemplateProfile, TemplateProfileField,
+    validate_template_content, validate_template_name,
 };
+use acteon_rules::RuleAction;
 use acteon_state::{KeyKind, StateKey};
 
 use super::AppState;
@@ -86,6 +87,9 @@ pub struct TemplateResponse {
     /// Arbitrary labels.
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     pub labels: HashMap<String, String>,
+    /// When the template was soft-deleted, if it has been.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 /// Response for listing templates.
@@ -111,6 +115,10 @@ pub struct CreateProfileRequest {
     pub tenant: String,
     /// Field-to-template mappings.
     pub fields: HashMap<String, TemplateProfileField>,
+    /// Output format deciding how values are escaped (`html`, `mrkdwn`,
+    /// or `plain`).
+    #[serde(default)]
+    pub output_type: Option<TemplateOutputType>,
     /// Optional description.
     #[serde(default)]
     pub description: Option<String>,
@@ -125,6 +133,9 @@ pub struct UpdateProfileRequest {
     /// Updated field mappings.
     #[serde(default)]
     pub fields: Option<HashMap<String, TemplateProfileField>>,
+    /// Updated output format.
+    #[serde(default)]
+    pub output_type: Option<TemplateOutputType>,
     /// Updated description.
     #[serde(default)]
     pub description: Option<String>,
@@ -146,6 +157,9 @@ pub struct ProfileResponse {
     pub tenant: String,
     /// Field-to-template mappings.
     pub fields: HashMap<String, TemplateProfileField>,
+    /// Output format deciding how values are escaped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_type: Option<TemplateOutputType>,
     /// Optional description.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -156,6 +170,9 @@ pub struct ProfileResponse {
     /// Arbitrary labels.
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     pub labels: HashMap<String, String>,
+    /// When the profile was soft-deleted, if it has been.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 /// Response for listing template profiles.
@@ -191,6 +208,35 @@ pub struct RenderPreviewResponse {
     pub rendered: HashMap<String, String>,
 }
 
+/// A resource that uses a template.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TemplateReference {
+    /// Kind of the referencing resource: `"profile"` or `"rule"`.
+    pub kind: String,
+    /// Name of the referencing resource.
+    pub name: String,
+    /// ID of the referencing resource, when it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+/// Response for listing the resources that use a template.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TemplateReferencesResponse {
+    /// Resources that reference the template.
+    pub references: Vec<TemplateReference>,
+    /// Total count of references.
+    pub count: usize,
+}
+
+/// Query parameters for deleting a template.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct DeleteTemplateParams {
+    /// Delete even if profiles or rules still reference the template.
+    #[serde(default)]
+    pub force: bool,
+}
+
 /// Query parameters for listing templates and profiles.
 #[derive(Debug, Deserialize, IntoParams)]
 pub struct ListTemplatesParams {
@@ -298,6 +344,7 @@ fn template_to_response(t: &Template) -> TemplateResponse {
         created_at: t.created_at,
         updated_at: t.updated_at,
         labels: t.labels.clone(),
+        deleted_at: t.deleted_at,
     }
 }
 
@@ -308,13 +355,60 @@ fn profile_to_response(p: &TemplateProfile) -> ProfileResponse {
         namespace: p.namespace.clone(),
         tenant: p.tenant.clone(),
         fields: p.fields.clone(),
+        output_type: p.output_type,
         description: p.description.clone(),
         created_at: p.created_at,
         updated_at: p.updated_at,
         labels: p.labels.clone(),
+        deleted_at: p.deleted_at,
     }
 }
 
+/// Collect the profiles and rules that reference `tpl` by name.
+///
+/// Profiles are matched within the template's scope via `$ref` fields;
+/// rules are matched on the template of a `group` action, skipping rules
+/// whose condition pins them to another namespace or tenant, since a group
+/// digest template is resolved in the scope of the grouped action.
+fn template_references(gw: &acteon_gateway::Gateway, tpl: &Template) -> Vec<TemplateReference> {
+    let mut references: Vec<TemplateReference> = gw
+        .template_profiles_for_scope(&tpl.namespace, &tpl.tenant)
+        .values()
+        .filter(|p| {
+            p.fields.values().any(|f| {
+                matches!(f, TemplateProfileField::Ref { template_ref } if template_ref == &tpl.name)
+            })
+        })
+        .map(|p| TemplateReference {
+            kind: "profile".to_owned(),
+            name: p.name.clone(),
+            id: Some(p.id.clone()),
+        })
+        .collect();
+    references.sort_by(|a, b| a.name.cmp(&b.name));
+
+    references.extend(
+        gw.rules()
+            .iter()
+            .filter(|r| {
+                matches!(&r.action, RuleAction::Group { template: Some(t), .. } if t == &tpl.name)
+                    && r.may_match_scope(&tpl.namespace, &tpl.tenant)
+            })
+            .map(|r| TemplateReference {
+                kind: "rule".to_owned(),
+                name: r.name.clone(),
+                id: None,
+            }),
+    );
+    references
+}
+
+/// TTL applied to a soft-deleted record and its name index, after which the
+/// state store purges both.
+fn deleted_retention() -> std::time::Duration {
+    std::time::Duration::from_secs(DELETED_RETENTION_SECONDS)
+}
+
 fn error_response(status: StatusCode, message: &str) -> axum::response::Response {
     (
         status,
@@ -438,6 +532,7 @@ pub async fn create_template(
         created_at: now,
         updated_at: now,
         labels: req.labels,
+        deleted_at: None,
     };
 
     let key = template_state_key(&id);
@@ -504,6 +599,9 @@ pub async fn list_templates(
         let Ok(tpl) = serde_json::from_str::<Template>(&value) else {
             continue;
         };
+        if tpl.deleted_at.is_some() {
+            continue;
+        }
 
         // Tenant scoping: never surface templates the caller's grants don't
         // cover, regardless of the requested namespace/tenant filter.
@@ -582,6 +680,51 @@ pub async fn get_template(
     }
 }
 
+/// `GET /v1/templates/{id}/references` -- list resources using a template.
+#[utoipa::path(
+    get,
+    path = "/v1/templates/{id}/references",
+    tag = "Templates",
+    summary = "List template references",
+    description = "Returns the profiles (via `$ref` fields) and rules (via `group` action templates) that use a template. Deleting a referenced template requires `force`.",
+    params(("id" = String, Path, description = "Template ID")),
+    responses(
+        (status = 200, description = "Template references", body = TemplateReferencesResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+pub async fn list_template_references(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    let gw = state.gateway.read().await;
+    let state_store = gw.state_store();
+
+    let tpl = match load_template(state_store.as_ref(), &id).await {
+        Ok(Some(t)) if t.deleted_at.is_none() => t,
+        Ok(_) => {
+            return error_response(StatusCode::NOT_FOUND, &format!("template not found: {id}"));
+        }
+        Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e),
+    };
+    if !identity.can_manage_scope(&tpl.tenant, &tpl.namespace) {
+        return tenant_forbidden(&tpl.namespace, &tpl.tenant);
+    }
+
+    let references = template_references(&gw, &tpl);
+    let count = references.len();
+    (
+        StatusCode::OK,
+        Json(serde_json::json!(TemplateReferencesResponse {
+            references,
+            count
+        })),
+    )
+        .into_response()
+}
+
 /// `PUT /v1/templates/{id}` -- update a template.
 #[utoipa::path(
     put,
@@ -622,6 +765,12 @@ pub async fn update_template(
     if !identity.can_manage_scope(&tpl.tenant, &tpl.namespace) {
         return tenant_forbidden(&tpl.namespace, &tpl.tenant);
     }
+    if tpl.deleted_at.is_some() {
+        return error_response(
+            StatusCode::CONFLICT,
+            &format!("template is deleted, restore it first: {id}"),
+        );
+    }
 
     if let Some(ref content) = req.content {
         if let Err(e) = validate_template_content(content) {
@@ -671,12 +820,15 @@ pub async fn update_template(
     path = "/v1/templates/{id}",
     tag = "Templates",
     summary = "Delete a template",
-    description = "Removes a template. Returns 409 if profiles reference it.",
-    params(("id" = String, Path, description = "Template ID")),
+    description = "Soft-deletes a template: it is hidden from lists and can no longer be referenced, but can be restored until it is purged after the retention window. Returns 409 if profiles or rules reference it, unless `force` is set.",
+    params(
+        ("id" = String, Path, description = "Template ID"),
+        DeleteTemplateParams,
+    ),
     responses(
         (status = 204, description = "Template deleted"),
         (status = 404, description = "Not found", body = ErrorResponse),
-        (status = 409, description = "Template is referenced by profiles", body = ErrorResponse),
+        (status = 409, description = "Template is referenced by profiles or rules", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse),
     )
 )]
@@ -684,6 +836,7 @@ pub async fn delete_template(
     State(state): State<AppState>,
     axum::Extension(identity): axum::Extension<CallerIdentity>,
     Path(id): Path<String>,
+    Query(params): Query<DeleteTemplateParams>,
 ) -> impl IntoResponse {
     if !identity.role.has_permission(Permission::TemplatesManage) {
         return manage_forbidden();
@@ -703,45 +856,136 @@ pub async fn delete_template(
     if !identity.can_manage_scope(&tpl.tenant, &tpl.namespace) {
         return tenant_forbidden(&tpl.namespace, &tpl.tenant);
     }
+    if tpl.deleted_at.is_some() {
+        return error_response(StatusCode::NOT_FOUND, &format!("template not found: {id}"));
+    }
 
-    // Check if any profiles reference this template.
-    let referencing: Vec<String> = gw
-        .template_profiles_for_scope(&tpl.namespace, &tpl.tenant)
-        .values()
-        .filter(|p| {
-            p.fields.values().any(|f| {
-                matches!(f, TemplateProfileField::Ref { template_ref } if template_ref == &tpl.name)
-            })
-        })
-        .map(|p| p.name.clone())
-        .collect();
+    if !params.force {
+        let referencing: Vec<String> = template_references(&gw, &tpl)
+            .into_iter()
+            .map(|r| format!("{} {}", r.kind, r.name))
+            .collect();
+        if !referencing.is_empty() {
+            return error_response(
+                StatusCode::CONFLICT,
+                &format!(
+                    "template '{}' is referenced by: {}",
+                    tpl.name,
+                    referencing.join(", ")
+                ),
+            );
+        }
+    }
+
+    // Soft delete: keep the record (and its name index, so the name stays
+    // reserved for a restore) until the retention TTL purges both.
+    let mut tpl = tpl;
+    tpl.deleted_at = Some(Utc::now());
+    let key = template_state_key(&id);
+    let data = match serde_json::to_string(&tpl) {
+        Ok(d) => d,
+        Err(e) => {
+            return error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("serialization error: {e}"),
+            );
+        }
+    };
+    if let Err(e) = state_store
+        .set(&key, &data, Some(deleted_retention()))
+        .await
+    {
+        return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string());
+    }
+    let idx_key = template_index_key(&tpl.namespace, &tpl.tenant, &tpl.name);
+    let _ = state_store
+        .set(&idx_key, &id, Some(deleted_retention()))
+        .await;
+    let _ =
+        acteon_state::bump_sync_version(state_store.as_ref(), acteon_state::SyncDomain::Templates)
+            .await;
+    drop(gw);
+
+    let gw = state.gateway.read().await;
+    gw.remove_template(&tpl.namespace, &tpl.tenant, &tpl.name);
+
+    StatusCode::NO_CONTENT.into_response()
+}
 
-    if !referencing.is_empty() {
+/// `POST /v1/templates/{id}/restore` -- restore a soft-deleted template.
+#[utoipa::path(
+    post,
+    path = "/v1/templates/{id}/restore",
+    tag = "Templates",
+    summary = "Restore a deleted template",
+    description = "Restores a soft-deleted template that has not yet been purged.",
+    params(("id" = String, Path, description = "Template ID")),
+    responses(
+        (status = 200, description = "Restored template", body = TemplateResponse),
+        (status = 404, description = "Not found or already purged", body = ErrorResponse),
+        (status = 409, description = "Template is not deleted", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+pub async fn restore_template(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    if !identity.role.has_permission(Permission::TemplatesManage) {
+        return manage_forbidden();
+    }
+
+    let gw = state.gateway.read().await;
+    let state_store = gw.state_store();
+
+    let mut tpl = match load_template(state_store.as_ref(), &id).await {
+        Ok(Some(t)) => t,
+        Ok(None) => {
+            return error_response(StatusCode::NOT_FOUND, &format!("template not found: {id}"));
+        }
+        Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e),
+    };
+
+    if !identity.can_manage_scope(&tpl.tenant, &tpl.namespace) {
+        return tenant_forbidden(&tpl.namespace, &tpl.tenant);
+    }
+    if tpl.deleted_at.is_none() {
         return error_response(
             StatusCode::CONFLICT,
-            &format!(
-                "template '{}' is referenced by profiles: {}",
-                tpl.name,
-                referencing.join(", ")
-            ),
+            &format!("template is not deleted: {id}"),
         );
     }
 
+    tpl.deleted_at = None;
+    tpl.updated_at = Utc::now();
     let key = template_state_key(&id);
-    if let Err(e) = state_store.delete(&key).await {
+    let data = match serde_json::to_string(&tpl) {
+        Ok(d) => d,
+        Err(e) => {
+            return error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("serialization error: {e}"),
+            );
+        }
+    };
+    if let Err(e) = state_store.set(&key, &data, None).await {
         return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string());
     }
     let idx_key = template_index_key(&tpl.namespace, &tpl.tenant, &tpl.name);
-    let _ = state_store.delete(&idx_key).await;
+    if let Err(e) = state_store.set(&idx_key, &id, None).await {
+        return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string());
+    }
     let _ =
         acteon_state::bump_sync_version(state_store.as_ref(), acteon_state::SyncDomain::Templates)
             .await;
     drop(gw);
 
     let gw = state.gateway.read().await;
-    gw.remove_template(&tpl.namespace, &tpl.tenant, &tpl.name);
+    gw.set_template(tpl.clone());
 
-    StatusCode::NO_CONTENT.into_response()
+    let resp = template_to_response(&tpl);
+    (StatusCode::OK, Json(serde_json::json!(resp))).into_response()
 }
 
 // ---------------------------------------------------------------------------
@@ -818,10 +1062,12 @@ pub async fn create_profile(
         namespace: req.namespace,
         tenant: req.tenant,
         fields: req.fields,
+        output_type: req.output_type,
         description: req.description,
         created_at: now,
         updated_at: now,
         labels: req.labels,
+        deleted_at: None,
     };
 
     let key = profile_state_key(&id);
@@ -888,6 +1134,9 @@ pub async fn list_profiles(
         let Ok(prof) = serde_json::from_str::<TemplateProfile>(&value) else {
             continue;
         };
+        if prof.deleted_at.is_some() {
+            continue;
+        }
 
         // Tenant scoping: never surface profiles outside the caller's grants.
         if !identity.can_manage_scope(&prof.tenant, &prof.namespace) {
@@ -1002,6 +1251,12 @@ pub async fn update_profile(
     if !identity.can_manage_scope(&prof.tenant, &prof.namespace) {
         return tenant_forbidden(&prof.namespace, &prof.tenant);
     }
+    if prof.deleted_at.is_some() {
+        return error_response(
+            StatusCode::CONFLICT,
+            &format!("profile is deleted, restore it first: {id}"),
+        );
+    }
 
     if let Some(ref fields) = req.fields {
         // Validate $ref templates exist.
@@ -1017,6 +1272,9 @@ pub async fn update_profile(
         }
         prof.fields.clone_from(fields);
     }
+    if let Some(output_type) = req.output_type {
+        prof.output_type = Some(output_type);
+    }
     if let Some(desc) = req.description {
         prof.description = Some(desc);
     }
@@ -1056,7 +1314,7 @@ pub async fn update_profile(
     path = "/v1/templates/profiles/{id}",
     tag = "Templates",
     summary = "Delete a template profile",
-    description = "Removes a template profile.",
+    description = "Soft-deletes a template profile: it is hidden from lists and no longer renders, but can be restored until it is purged after the retention window.",
     params(("id" = String, Path, description = "Profile ID")),
     responses(
         (status = 204, description = "Profile deleted"),
@@ -1087,13 +1345,32 @@ pub async fn delete_profile(
     if !identity.can_manage_scope(&prof.tenant, &prof.namespace) {
         return tenant_forbidden(&prof.namespace, &prof.tenant);
     }
+    if prof.deleted_at.is_some() {
+        return error_response(StatusCode::NOT_FOUND, &format!("profile not found: {id}"));
+    }
 
+    let mut prof = prof;
+    prof.deleted_at = Some(Utc::now());
     let key = profile_state_key(&id);
-    if let Err(e) = state_store.delete(&key).await {
+    let data = match serde_json::to_string(&prof) {
+        Ok(d) => d,
+        Err(e) => {
+            return error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("serialization error: {e}"),
+            );
+        }
+    };
+    if let Err(e) = state_store
+        .set(&key, &data, Some(deleted_retention()))
+        .await
+    {
         return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string());
     }
     let idx_key = profile_index_key(&prof.namespace, &prof.tenant, &prof.name);
-    let _ = state_store.delete(&idx_key).await;
+    let _ = state_store
+        .set(&idx_key, &id, Some(deleted_retention()))
+        .await;
     let _ =
         acteon_state::bump_sync_version(state_store.as_ref(), acteon_state::SyncDomain::Templates)
             .await;
@@ -1105,6 +1382,93 @@ pub async fn delete_profile(
     StatusCode::NO_CONTENT.into_response()
 }
 
+/// `POST /v1/templates/profiles/{id}/restore` -- restore a soft-deleted profile.
+#[utoipa::path(
+    post,
+    path = "/v1/templates/profiles/{id}/restore",
+    tag = "Templates",
+    summary = "Restore a deleted template profile",
+    description = "Restores a soft-deleted template profile that has not yet been purged. Returns 409 if a template it references no longer exists.",
+    params(("id" = String, Path, description = "Profile ID")),
+    responses(
+        (status = 200, description = "Restored profile", body = ProfileResponse),
+        (status = 404, description = "Not found or already purged", body = ErrorResponse),
+        (status = 409, description = "Profile is not deleted, or references a missing template", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    )
+)]
+pub async fn restore_profile(
+    State(state): State<AppState>,
+    axum::Extension(identity): axum::Extension<CallerIdentity>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    if !identity.role.has_permission(Permission::TemplatesManage) {
+        return manage_forbidden();
+    }
+
+    let gw = state.gateway.read().await;
+    let state_store = gw.state_store();
+
+    let mut prof = match load_profile(state_store.as_ref(), &id).await {
+        Ok(Some(p)) => p,
+        Ok(None) => {
+            return error_response(StatusCode::NOT_FOUND, &format!("profile not found: {id}"));
+        }
+        Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e),
+    };
+
+    if !identity.can_manage_scope(&prof.tenant, &prof.namespace) {
+        return tenant_forbidden(&prof.namespace, &prof.tenant);
+    }
+    if prof.deleted_at.is_none() {
+        return error_response(
+            StatusCode::CONFLICT,
+            &format!("profile is not deleted: {id}"),
+        );
+    }
+    // A template may have been deleted while the profile was.
+    for (field_name, field) in &prof.fields {
+        if let TemplateProfileField::Ref { template_ref } = field
+            && !gw.template_exists(&prof.namespace, &prof.tenant, template_ref)
+        {
+            return error_response(
+                StatusCode::CONFLICT,
+                &format!("field '{field_name}' references unknown template '{template_ref}'"),
+            );
+        }
+    }
+
+    prof.deleted_at = None;
+    prof.updated_at = Utc::now();
+    let key = profile_state_key(&id);
+    let data = match serde_json::to_string(&prof) {
+        Ok(d) => d,
+        Err(e) => {
+            return error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &format!("serialization error: {e}"),
+            );
+        }
+    };
+    if let Err(e) = state_store.set(&key, &data, None).await {
+        return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string());
+    }
+    let idx_key = profile_index_key(&prof.namespace, &prof.tenant, &prof.name);
+    if let Err(e) = state_store.set(&idx_key, &id, None).await {
+        return error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string());
+    }
+    let _ =
+        acteon_state::bump_sync_version(state_store.as_ref(), acteon_state::SyncDomain::Templates)
+            .await;
+    drop(gw);
+
+    let gw = state.gateway.read().await;
+    gw.set_template_profile(prof.clone());
+
+    let resp = profile_to_response(&prof);
+    (StatusCode::OK, Json(serde_json::json!(resp))).into_response()
+}
+
 // ---------------------------------------------------------------------------
 // Render preview handler
 // ---------------------------------------------------------------------------
@@ -125,6 +125,17 @@ impl CallerIdentity {
         })
     }
 
+    /// Check whether this caller holds a grant that is wildcard on both
+    /// tenants and namespaces.
+    ///
+    /// Required for resources that span tenants, such as org-level
+    /// quotas, which a tenant-scoped grant must not be able to touch.
+    pub fn has_global_scope(&self) -> bool {
+        self.grants
+            .iter()
+            .any(|g| g.tenants.iter().any(|t| t == "*") && g.namespaces.iter().any(|n| n == "*"))
+    }
+
     /// Convert to the minimal `Caller` for audit threading.
     pub fn to_caller(&self) -> Caller {
         Caller {
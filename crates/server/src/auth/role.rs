@@ -31,6 +31,8 @@ impl Role {
             | Permission::PluginsManage
             | Permission::SilencesManage
             | Permission::TimeIntervalsManage
+            | Permission::FeatureFlagsManage
+            | Permission::AuditHoldManage
             | Permission::TemplatesManage => matches!(self, Self::Admin | Self::Operator),
             Permission::AuditRead
             | Permission::RulesRead
@@ -65,6 +67,10 @@ pub enum Permission {
     SilencesManage,
     /// Create, update, or delete time intervals. Held by admin and operator.
     TimeIntervalsManage,
+    /// Set or delete per-tenant feature flags. Held by admin and operator.
+    FeatureFlagsManage,
+    /// Place legal holds on audit records. Held by admin and operator.
+    AuditHoldManage,
     /// Create, update, delete, or reload payload templates and profiles.
     /// Held by admin and operator. Reads (get/list/render preview) are open
     /// to all roles but remain tenant-scoped.
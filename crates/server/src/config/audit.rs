@@ -27,6 +27,9 @@ pub struct AuditConfig {
     /// Field redaction configuration.
     #[serde(default)]
     pub redact: AuditRedactConfig,
+    /// Batched write configuration.
+    #[serde(default)]
+    pub batch: AuditBatchingConfig,
     /// AWS region for the `DynamoDB` audit backend.
     #[serde(default)]
     pub region: Option<String>,
@@ -82,6 +85,63 @@ fn default_redact_placeholder() -> String {
     "[REDACTED]".to_owned()
 }
 
+/// Configuration for batching asynchronous audit writes.
+///
+/// Ignored when compliance mode requires synchronous audit writes.
+#[derive(Debug, Deserialize)]
+pub struct AuditBatchingConfig {
+    /// Whether batched writes are enabled.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Flush once this many records are buffered.
+    #[serde(default = "default_batch_max_size")]
+    pub max_batch_size: usize,
+    /// Flush once the oldest buffered record has waited this long.
+    #[serde(default = "default_batch_flush_interval_ms")]
+    pub flush_interval_ms: u64,
+    /// Maximum number of records waiting to be batched.
+    #[serde(default = "default_batch_queue_capacity")]
+    pub queue_capacity: usize,
+    /// What to do when the queue is full: `"reject"` (default) or `"block"`.
+    #[serde(default)]
+    pub overflow: acteon_audit::OverflowPolicy,
+}
+
+impl Default for AuditBatchingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_batch_size: default_batch_max_size(),
+            flush_interval_ms: default_batch_flush_interval_ms(),
+            queue_capacity: default_batch_queue_capacity(),
+            overflow: acteon_audit::OverflowPolicy::default(),
+        }
+    }
+}
+
+impl AuditBatchingConfig {
+    /// Convert to the audit store batching configuration.
+    pub fn to_batch_config(&self) -> acteon_audit::AuditBatchConfig {
+        acteon_audit::AuditBatchConfig::default()
+            .with_max_batch_size(self.max_batch_size)
+            .with_flush_interval(std::time::Duration::from_millis(self.flush_interval_ms))
+            .with_queue_capacity(self.queue_capacity)
+            .with_overflow(self.overflow)
+    }
+}
+
+fn default_batch_max_size() -> usize {
+    100
+}
+
+fn default_batch_flush_interval_ms() -> u64 {
+    100
+}
+
+fn default_batch_queue_capacity() -> usize {
+    10_000
+}
+
 impl Default for AuditConfig {
     fn default() -> Self {
         Self {
@@ -93,6 +153,7 @@ impl Default for AuditConfig {
             cleanup_interval_seconds: default_cleanup_interval(),
             store_payload: true,
             redact: AuditRedactConfig::default(),
+            batch: AuditBatchingConfig::default(),
             region: None,
             table_name: None,
             ssl_mode: None,
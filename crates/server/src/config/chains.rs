@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 /// Configuration for task chain definitions.
@@ -15,6 +17,26 @@ pub struct ChainsConfig {
     /// this duration for audit purposes before expiring. Defaults to 7 days.
     #[serde(default = "default_completed_chain_ttl")]
     pub completed_chain_ttl_seconds: u64,
+    /// Maximum sub-chain nesting depth.
+    #[serde(default = "default_max_depth")]
+    pub max_depth: u32,
+    /// Maximum steps executed per root chain, including its sub-chains.
+    #[serde(default = "default_max_steps")]
+    pub max_steps: u64,
+    /// Maximum number of sub-steps in a single parallel group.
+    #[serde(default = "default_max_parallel_fanout")]
+    pub max_parallel_fanout: usize,
+}
+
+impl ChainsConfig {
+    /// The chain execution limits described by this configuration.
+    pub fn limits(&self) -> acteon_core::ChainLimits {
+        acteon_core::ChainLimits {
+            max_depth: self.max_depth,
+            max_steps: self.max_steps,
+            max_parallel_fanout: self.max_parallel_fanout,
+        }
+    }
 }
 
 impl Default for ChainsConfig {
@@ -23,6 +45,9 @@ impl Default for ChainsConfig {
             definitions: Vec::new(),
             max_concurrent_advances: default_max_concurrent_advances(),
             completed_chain_ttl_seconds: default_completed_chain_ttl(),
+            max_depth: default_max_depth(),
+            max_steps: default_max_steps(),
+            max_parallel_fanout: default_max_parallel_fanout(),
         }
     }
 }
@@ -39,6 +64,18 @@ fn default_completed_chain_ttl() -> u64 {
     604_800 // 7 days
 }
 
+fn default_max_depth() -> u32 {
+    acteon_core::chain::DEFAULT_MAX_CHAIN_DEPTH
+}
+
+fn default_max_steps() -> u64 {
+    acteon_core::chain::DEFAULT_MAX_CHAIN_STEPS
+}
+
+fn default_max_parallel_fanout() -> usize {
+    acteon_core::chain::DEFAULT_MAX_PARALLEL_FANOUT
+}
+
 /// A single chain definition loaded from TOML.
 #[derive(Debug, Deserialize)]
 pub struct ChainConfigToml {
@@ -52,6 +89,9 @@ pub struct ChainConfigToml {
     pub timeout_seconds: Option<u64>,
     /// Optional notification target dispatched when the chain is cancelled.
     pub on_cancel: Option<ChainNotificationTargetToml>,
+    /// Free-form labels used to filter chain definition listings.
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
 }
 
 /// Notification target for chain cancellation events (TOML representation).
@@ -83,6 +123,9 @@ pub struct ChainStepConfigToml {
     pub on_failure: Option<String>,
     /// Optional delay in seconds before executing this step.
     pub delay_seconds: Option<u64>,
+    /// Optional limit in seconds on the step's provider call.
+    #[serde(default)]
+    pub timeout_seconds: Option<u64>,
     /// Conditional branch conditions evaluated after this step completes.
     #[serde(default)]
     pub branches: Vec<BranchConditionToml>,
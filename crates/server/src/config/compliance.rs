@@ -11,12 +11,18 @@ use serde::Deserialize;
 /// ```toml
 /// [encryption]
 /// enabled = true
+/// plaintext_action_types = ["heartbeat"]
 /// ```
 #[derive(Debug, Default, Deserialize)]
 pub struct EncryptionConfig {
     /// Whether payload encryption is enabled.
     #[serde(default)]
     pub enabled: bool,
+    /// Action types whose payloads are stored in plaintext unless the
+    /// action sets `encrypt_payload` itself. Ignored when compliance mode
+    /// forbids encryption opt-out.
+    #[serde(default)]
+    pub plaintext_action_types: Vec<String>,
 }
 
 /// Configuration for tenant quota policies.
@@ -205,6 +211,7 @@ fn default_wasm_timeout_ms() -> u64 {
 /// sync_audit_writes = true
 /// immutable_audit = false
 /// hash_chain = true
+/// forbid_encryption_opt_out = true
 /// ```
 #[derive(Debug, Default, Deserialize)]
 pub struct ComplianceServerConfig {
@@ -223,6 +230,10 @@ pub struct ComplianceServerConfig {
     /// Override: whether `SHA-256` hash chaining is enabled.
     #[serde(default)]
     pub hash_chain: Option<bool>,
+    /// Override: whether actions are forbidden from opting out of payload
+    /// encryption.
+    #[serde(default)]
+    pub forbid_encryption_opt_out: Option<bool>,
 }
 
 impl ComplianceServerConfig {
@@ -246,6 +257,9 @@ impl ComplianceServerConfig {
         if let Some(v) = self.hash_chain {
             config = config.with_hash_chain(v);
         }
+        if let Some(v) = self.forbid_encryption_opt_out {
+            config = config.with_forbid_encryption_opt_out(v);
+        }
 
         config
     }
@@ -257,5 +271,6 @@ impl ComplianceServerConfig {
             || config.sync_audit_writes
             || config.immutable_audit
             || config.hash_chain
+            || config.forbid_encryption_opt_out
     }
 }
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use acteon_core::DedupKeyNormalization;
+
+/// Dedup key normalization, configured per namespace.
+///
+/// Namespaces without an entry compare dedup keys exactly, so existing
+/// deployments keep their behavior until they opt in.
+///
+/// # Example
+///
+/// ```toml
+/// [dedup.namespaces.orders]
+/// trim = true
+/// lowercase = true
+/// unicode_nfc = true
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct DedupServerConfig {
+    /// Normalization settings keyed by namespace.
+    #[serde(default)]
+    pub namespaces: HashMap<String, DedupKeyNormalization>,
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 /// Configuration for loading rules from disk.
@@ -15,6 +17,26 @@ pub struct RulesConfig {
     /// Equivalent to repeatedly calling `POST /v1/rules/reload`.
     #[serde(default = "default_rules_watch")]
     pub watch: bool,
+    /// Maximum number of rule conditions evaluated per action. When reached,
+    /// evaluation stops and the action is allowed (or denied, see
+    /// `budget_fail_closed`). Unbounded if unset.
+    pub max_rules_evaluated: Option<usize>,
+    /// Maximum wall-clock time in milliseconds for evaluating one action's
+    /// rules. When reached, evaluation stops as for `max_rules_evaluated`.
+    /// Unbounded if unset.
+    pub max_evaluation_ms: Option<u64>,
+    /// Deny, rather than allow, actions whose rule evaluation ran out of
+    /// budget before any rule matched.
+    #[serde(default)]
+    pub budget_fail_closed: bool,
+    /// Number of replaced rule sets kept for `POST /v1/rules/rollback`.
+    /// Defaults to 5.
+    pub history_limit: Option<usize>,
+    /// Named holiday calendars (e.g. one per region) consulted by
+    /// `business_hours` conditions. Each maps to a list of `YYYY-MM-DD`
+    /// dates that are never business hours.
+    #[serde(default)]
+    pub holiday_calendars: HashMap<String, Vec<chrono::NaiveDate>>,
 }
 
 fn default_rules_watch() -> bool {
@@ -33,4 +55,15 @@ pub struct ExecutorConfig {
     /// Whether to enable the dead-letter queue for failed actions.
     #[serde(default)]
     pub dlq_enabled: bool,
+    /// Optional per-provider cap on retries across all actions.
+    pub retry_budget: Option<RetryBudgetToml>,
+}
+
+/// Per-provider retry budget (`[executor.retry_budget]`).
+#[derive(Debug, Deserialize)]
+pub struct RetryBudgetToml {
+    /// Retries each provider may absorb per window.
+    pub max_retries: u32,
+    /// Window length in seconds over which the budget refills.
+    pub window_seconds: u64,
 }
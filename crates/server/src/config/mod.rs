@@ -5,9 +5,14 @@ mod bus;
 mod chains;
 mod circuit_breaker;
 mod compliance;
+mod dedup;
 mod enrichment;
 mod executor;
 mod llm;
+mod namespace_defaults;
+mod provider_defaults;
+mod provider_failover;
+mod provider_health;
 mod providers;
 mod server;
 mod signing;
@@ -26,9 +31,14 @@ pub use bus::*;
 pub use chains::*;
 pub use circuit_breaker::*;
 pub use compliance::*;
+pub use dedup::*;
 pub use enrichment::*;
 pub use executor::*;
 pub use llm::*;
+pub use namespace_defaults::*;
+pub use provider_defaults::*;
+pub use provider_failover::*;
+pub use provider_health::*;
 pub use providers::*;
 pub use server::*;
 pub use signing::*;
@@ -81,6 +91,12 @@ pub struct ActeonConfig {
     /// Circuit breaker configuration for provider resilience.
     #[serde(default)]
     pub circuit_breaker: CircuitBreakerServerConfig,
+    /// Per-namespace dedup key normalization.
+    #[serde(default)]
+    pub dedup: DedupServerConfig,
+    /// Periodic provider health checks and their hysteresis.
+    #[serde(default)]
+    pub provider_health: ProviderHealthServerConfig,
     /// OpenTelemetry distributed tracing configuration.
     #[serde(default)]
     pub telemetry: TelemetryConfig,
@@ -114,6 +130,15 @@ pub struct ActeonConfig {
     /// merging live external state into the action payload.
     #[serde(default)]
     pub enrichments: Vec<EnrichmentConfigToml>,
+    /// Default provider and action type per namespace.
+    #[serde(default)]
+    pub namespace_defaults: Vec<NamespaceDefaultsConfig>,
+    /// Default payload fields per `(namespace, provider)`.
+    #[serde(default)]
+    pub provider_defaults: Vec<ProviderDefaultsConfig>,
+    /// Ordered fallback providers tried when a provider fails.
+    #[serde(default)]
+    pub provider_failover: Vec<ProviderFailoverConfig>,
     /// Provider definitions.
     ///
     /// Each entry registers a named provider that actions can be routed to.
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+
+/// Default provider and action type for one namespace, loaded from TOML.
+///
+/// Actions in the namespace that omit `provider` or `action_type` get these
+/// values at dispatch; fields set by the action take precedence.
+///
+/// # Example
+///
+/// ```toml
+/// [[namespace_defaults]]
+/// namespace = "alerts"
+/// provider = "slack"
+/// action_type = "post_message"
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct NamespaceDefaultsConfig {
+    /// Namespace the defaults apply to.
+    pub namespace: String,
+    /// Provider used when an action omits one.
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Action type used when an action omits one.
+    #[serde(default)]
+    pub action_type: Option<String>,
+}
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+/// Default payload fields for one provider within a namespace, loaded from
+/// TOML.
+///
+/// The payload table is deep-merged into the payload of every action the
+/// provider executes in that namespace; fields set by the action take
+/// precedence.
+///
+/// # Example
+///
+/// ```toml
+/// [[provider_defaults]]
+/// namespace = "alerts"
+/// provider = "slack"
+///
+/// [provider_defaults.payload]
+/// footer = "Sent via Acteon"
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ProviderDefaultsConfig {
+    /// Namespace the defaults apply to.
+    pub namespace: String,
+    /// Provider the defaults apply to.
+    pub provider: String,
+    /// Default payload fields.
+    pub payload: serde_json::Value,
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+/// Failover route for one provider, loaded from TOML.
+///
+/// When `provider` fails to execute an action, each of `fallbacks` is tried
+/// in order until one succeeds.
+///
+/// # Example
+///
+/// ```toml
+/// [[provider_failover]]
+/// provider = "sms-vendor-a"
+/// fallbacks = ["sms-vendor-b", "sms-vendor-c"]
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ProviderFailoverConfig {
+    /// Provider whose failures trigger the route.
+    pub provider: String,
+    /// Providers to try next, in order.
+    pub fallbacks: Vec<String>,
+}
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+
+use acteon_core::HealthHysteresis;
+
+/// Periodic provider health checks and the hysteresis applied to their
+/// results.
+///
+/// # Example
+///
+/// ```toml
+/// [provider_health]
+/// check_interval_seconds = 30
+/// unhealthy_after = 3
+/// healthy_after = 2
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct ProviderHealthServerConfig {
+    /// How often every provider is health checked (seconds).
+    #[serde(default = "default_check_interval")]
+    pub check_interval_seconds: u64,
+    /// Consecutive check results needed to change a provider's health.
+    #[serde(flatten)]
+    pub hysteresis: HealthHysteresis,
+}
+
+impl Default for ProviderHealthServerConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_seconds: default_check_interval(),
+            hysteresis: HealthHysteresis::default(),
+        }
+    }
+}
+
+fn default_check_interval() -> u64 {
+    30
+}
@@ -147,6 +147,10 @@ pub struct StateSnapshot {
     pub region: Option<String>,
     /// `DynamoDB` table name.
     pub table_name: Option<String>,
+    /// Active fingerprint algorithm version.
+    pub fingerprint_version: acteon_core::FingerprintVersion,
+    /// Fingerprint version still honored during a migration.
+    pub fingerprint_dual_read_version: Option<acteon_core::FingerprintVersion>,
 }
 
 impl From<&StateConfig> for StateSnapshot {
@@ -157,6 +161,8 @@ impl From<&StateConfig> for StateSnapshot {
             prefix: cfg.prefix.clone(),
             region: cfg.region.clone(),
             table_name: cfg.table_name.clone(),
+            fingerprint_version: cfg.fingerprint_version,
+            fingerprint_dual_read_version: cfg.fingerprint_dual_read_version,
         }
     }
 }
@@ -172,6 +178,10 @@ pub struct ExecutorSnapshot {
     pub max_concurrent: Option<usize>,
     /// Whether the dead-letter queue is enabled.
     pub dlq_enabled: bool,
+    /// Retries each provider may absorb per retry-budget window.
+    pub retry_budget_max_retries: Option<u32>,
+    /// Retry-budget window in seconds.
+    pub retry_budget_window_seconds: Option<u64>,
 }
 
 impl From<&ExecutorConfig> for ExecutorSnapshot {
@@ -181,6 +191,8 @@ impl From<&ExecutorConfig> for ExecutorSnapshot {
             timeout_seconds: cfg.timeout_seconds,
             max_concurrent: cfg.max_concurrent,
             dlq_enabled: cfg.dlq_enabled,
+            retry_budget_max_retries: cfg.retry_budget.as_ref().map(|b| b.max_retries),
+            retry_budget_window_seconds: cfg.retry_budget.as_ref().map(|b| b.window_seconds),
         }
     }
 }
@@ -192,13 +204,18 @@ pub struct RulesSnapshot {
     pub directory: Option<String>,
     /// Default IANA timezone for time-based conditions.
     pub default_timezone: Option<String>,
+    /// Names of the configured holiday calendars.
+    pub holiday_calendars: Vec<String>,
 }
 
 impl From<&RulesConfig> for RulesSnapshot {
     fn from(cfg: &RulesConfig) -> Self {
+        let mut holiday_calendars: Vec<String> = cfg.holiday_calendars.keys().cloned().collect();
+        holiday_calendars.sort();
         Self {
             directory: cfg.directory.clone(),
             default_timezone: cfg.default_timezone.clone(),
+            holiday_calendars,
         }
     }
 }
@@ -545,6 +562,12 @@ pub struct ChainsSnapshot {
     pub max_concurrent_advances: usize,
     /// TTL in seconds for completed chain state records.
     pub completed_chain_ttl_seconds: u64,
+    /// Maximum sub-chain nesting depth.
+    pub max_depth: u32,
+    /// Maximum steps executed per root chain.
+    pub max_steps: u64,
+    /// Maximum sub-steps in a parallel group.
+    pub max_parallel_fanout: usize,
     /// Chain definition summaries.
     pub definitions: Vec<ChainDefinitionSnapshot>,
 }
@@ -554,6 +577,9 @@ impl From<&ChainsConfig> for ChainsSnapshot {
         Self {
             max_concurrent_advances: cfg.max_concurrent_advances,
             completed_chain_ttl_seconds: cfg.completed_chain_ttl_seconds,
+            max_depth: cfg.max_depth,
+            max_steps: cfg.max_steps,
+            max_parallel_fanout: cfg.max_parallel_fanout,
             definitions: cfg
                 .definitions
                 .iter()
@@ -1,3 +1,4 @@
+use acteon_core::FingerprintVersion;
 use serde::Deserialize;
 
 /// Configuration for the state store backend.
@@ -58,6 +59,17 @@ pub struct StateConfig {
     /// backends, which delegate expiry to the underlying store.
     #[serde(default = "default_memory_sweep_interval_secs")]
     pub memory_sweep_interval_secs: u64,
+
+    /// Fingerprint algorithm version for new state machine events
+    /// (`"v1"` or `"v2"`). Defaults to `"v1"`.
+    #[serde(default)]
+    pub fingerprint_version: FingerprintVersion,
+
+    /// Previous fingerprint version to keep honoring while upgrading
+    /// `fingerprint_version`. Events already tracked under this version
+    /// keep their state; remove it once they have resolved.
+    #[serde(default)]
+    pub fingerprint_dual_read_version: Option<FingerprintVersion>,
 }
 
 fn default_memory_sweep_interval_secs() -> u64 {
@@ -80,6 +92,8 @@ impl Default for StateConfig {
             tls_ca_cert_path: None,
             tls_insecure: None,
             memory_sweep_interval_secs: default_memory_sweep_interval_secs(),
+            fingerprint_version: FingerprintVersion::default(),
+            fingerprint_dual_read_version: None,
         }
     }
 }
@@ -116,6 +116,47 @@ fn telemetry_absent_from_acteon_config_uses_defaults() {
     assert_eq!(config.telemetry.endpoint, "http://localhost:4317");
 }
 
+#[test]
+fn executor_retry_budget_parsed_from_toml() {
+    let toml = r"
+        [executor]
+        max_retries = 3
+
+        [executor.retry_budget]
+        max_retries = 50
+        window_seconds = 60
+    ";
+
+    let config: ActeonConfig = toml::from_str(toml).unwrap();
+    let budget = config.executor.retry_budget.as_ref().unwrap();
+    assert_eq!(budget.max_retries, 50);
+    assert_eq!(budget.window_seconds, 60);
+
+    let snapshot = ConfigSnapshot::from(&config);
+    assert_eq!(snapshot.executor.retry_budget_max_retries, Some(50));
+    assert_eq!(snapshot.executor.retry_budget_window_seconds, Some(60));
+}
+
+#[test]
+fn rules_holiday_calendars_parsed_from_toml() {
+    let toml = r#"
+        [rules.holiday_calendars]
+        us = ["2026-11-26", "2026-12-25"]
+        de = ["2026-10-03"]
+    "#;
+
+    let config: ActeonConfig = toml::from_str(toml).unwrap();
+    let us = &config.rules.holiday_calendars["us"];
+    assert_eq!(us.len(), 2);
+    assert_eq!(
+        us[1],
+        chrono::NaiveDate::from_ymd_opt(2026, 12, 25).unwrap()
+    );
+
+    let snapshot = ConfigSnapshot::from(&config);
+    assert_eq!(snapshot.rules.holiday_calendars, ["de", "us"]);
+}
+
 #[test]
 fn providers_default_empty() {
     let config: ActeonConfig = toml::from_str("").unwrap();
@@ -609,3 +650,123 @@ fn enrichment_config_parses_full() {
         acteon_core::EnrichmentFailurePolicy::FailClosed
     );
 }
+
+#[test]
+fn dedup_normalization_defaults_to_none() {
+    let config: ActeonConfig = toml::from_str("").unwrap();
+    assert!(config.dedup.namespaces.is_empty());
+}
+
+#[test]
+fn dedup_normalization_parsed_per_namespace() {
+    let toml = r"
+        [dedup.namespaces.orders]
+        trim = true
+        lowercase = true
+    ";
+    let config: ActeonConfig = toml::from_str(toml).unwrap();
+    let orders = config.dedup.namespaces.get("orders").unwrap();
+    assert!(orders.trim);
+    assert!(orders.lowercase);
+    assert!(!orders.unicode_nfc);
+}
+
+#[test]
+fn provider_health_hysteresis_defaults_and_overrides() {
+    let config: ActeonConfig = toml::from_str("").unwrap();
+    assert_eq!(config.provider_health.check_interval_seconds, 30);
+    assert_eq!(config.provider_health.hysteresis.unhealthy_after, 3);
+    assert_eq!(config.provider_health.hysteresis.healthy_after, 2);
+
+    let toml = r"
+        [provider_health]
+        check_interval_seconds = 10
+        unhealthy_after = 5
+    ";
+    let config: ActeonConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.provider_health.check_interval_seconds, 10);
+    assert_eq!(config.provider_health.hysteresis.unhealthy_after, 5);
+    assert_eq!(config.provider_health.hysteresis.healthy_after, 2);
+}
+
+#[test]
+fn namespace_defaults_parsed() {
+    let toml = r#"
+        [[namespace_defaults]]
+        namespace = "alerts"
+        provider = "slack"
+        action_type = "post_message"
+
+        [[namespace_defaults]]
+        namespace = "billing"
+        provider = "email"
+    "#;
+    let config: ActeonConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.namespace_defaults.len(), 2);
+    let alerts = &config.namespace_defaults[0];
+    assert_eq!(alerts.namespace, "alerts");
+    assert_eq!(alerts.provider.as_deref(), Some("slack"));
+    assert_eq!(alerts.action_type.as_deref(), Some("post_message"));
+    assert_eq!(config.namespace_defaults[1].action_type, None);
+}
+
+#[test]
+fn provider_defaults_parsed() {
+    let toml = r#"
+        [[provider_defaults]]
+        namespace = "alerts"
+        provider = "slack"
+
+        [provider_defaults.payload]
+        footer = "Sent via Acteon"
+        blocks = { divider = true }
+    "#;
+    let config: ActeonConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.provider_defaults.len(), 1);
+    let defaults = &config.provider_defaults[0];
+    assert_eq!(defaults.namespace, "alerts");
+    assert_eq!(defaults.provider, "slack");
+    assert_eq!(
+        defaults.payload,
+        serde_json::json!({"footer": "Sent via Acteon", "blocks": {"divider": true}})
+    );
+}
+
+#[test]
+fn audit_batch_config() {
+    let config: AuditConfig = toml::from_str(
+        "[batch]
+         enabled = true
+         max_batch_size = 50
+         overflow = \"block\"",
+    )
+    .unwrap();
+    assert!(config.batch.enabled);
+    assert_eq!(config.batch.flush_interval_ms, 100);
+
+    let batch = config.batch.to_batch_config();
+    assert_eq!(batch.max_batch_size, 50);
+    assert_eq!(batch.overflow, acteon_audit::OverflowPolicy::Block);
+    assert_eq!(batch.flush_interval, std::time::Duration::from_millis(100));
+
+    let defaults: AuditConfig = toml::from_str("").unwrap();
+    assert!(!defaults.batch.enabled);
+    assert_eq!(
+        defaults.batch.overflow,
+        acteon_audit::OverflowPolicy::Reject
+    );
+}
+
+#[test]
+fn provider_failover_parsed() {
+    let toml = r#"
+        [[provider_failover]]
+        provider = "sms-a"
+        fallbacks = ["sms-b", "sms-c"]
+    "#;
+    let config: ActeonConfig = toml::from_str(toml).unwrap();
+    assert_eq!(config.provider_failover.len(), 1);
+    let route = &config.provider_failover[0];
+    assert_eq!(route.provider, "sms-a");
+    assert_eq!(route.fallbacks, vec!["sms-b", "sms-c"]);
+}
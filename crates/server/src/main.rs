@@ -8,9 +8,10 @@ use tracing::{info, warn};
 
 use acteon_core::{
     Action, BranchCondition, BranchOperator, ChainConfig, ChainFailurePolicy,
-    ChainNotificationTarget, ChainStepConfig, StepFailurePolicy, StreamEvent, StreamEventType,
+    ChainNotificationTarget, ChainStepConfig, FingerprintConfig, StepFailurePolicy, StreamEvent,
+    StreamEventType,
 };
-use acteon_executor::ExecutorConfig;
+use acteon_executor::{ExecutorConfig, RetryBudgetConfig};
 use acteon_gateway::GatewayBuilder;
 use acteon_gateway::background::{BackgroundConfig, BackgroundProcessorBuilder};
 use acteon_gateway::group_manager::GroupManager;
@@ -327,7 +328,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .clone()
         .unwrap_or_else(|| format!("http://{}:{}", config.server.host, config.server.port));
 
+    // Follow-on actions are queued here and dispatched by a consumer
+    // spawned once the gateway is shared, off the triggering request.
+    let (follow_on_tx, mut follow_on_rx) = tokio::sync::mpsc::channel(1024);
+
     let mut builder = GatewayBuilder::new()
+        .follow_on_channel(follow_on_tx)
         .state(Arc::clone(&store))
         .lock(Arc::clone(&lock))
         .executor_config(exec_config)
@@ -335,13 +341,52 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .group_manager(Arc::clone(&group_manager))
         .external_url(external_url);
 
+    if let Some(ref budget) = config.executor.retry_budget {
+        builder = builder.retry_budget(RetryBudgetConfig {
+            max_retries: budget.max_retries,
+            window: Duration::from_secs(budget.window_seconds),
+        });
+    }
+
     if let Some(ref enc) = payload_encryptor {
-        builder = builder.payload_encryptor(Arc::clone(enc));
+        builder = builder
+            .payload_encryptor(Arc::clone(enc))
+            .plaintext_action_types(config.encryption.plaintext_action_types.iter().cloned());
     }
 
+    let mut fingerprint_config = FingerprintConfig::new(config.state.fingerprint_version);
+    if let Some(version) = config.state.fingerprint_dual_read_version {
+        fingerprint_config = fingerprint_config.with_dual_read(version);
+    }
+    info!(
+        version = %fingerprint_config.version,
+        dual_read = ?fingerprint_config.dual_read_version,
+        "fingerprint algorithm configured"
+    );
+    builder = builder.fingerprint_config(fingerprint_config);
+
     if let Some(ref tz) = config.rules.default_timezone {
         builder = builder.default_timezone(tz);
     }
+    for (name, dates) in &config.rules.holiday_calendars {
+        builder = builder.holiday_calendar(name, dates.iter().copied());
+    }
+
+    // Bound rule evaluation cost per action, if configured.
+    let mut rule_budget = acteon_rules::EvalBudget::unlimited();
+    if let Some(max) = config.rules.max_rules_evaluated {
+        rule_budget = rule_budget.with_max_rules(max);
+    }
+    if let Some(ms) = config.rules.max_evaluation_ms {
+        rule_budget = rule_budget.with_max_duration(Duration::from_millis(ms));
+    }
+    if !rule_budget.is_unlimited() {
+        let rule_budget = rule_budget.with_fail_closed(config.rules.budget_fail_closed);
+        builder = builder.rule_evaluation_budget(rule_budget);
+    }
+    if let Some(limit) = config.rules.history_limit {
+        builder = builder.rule_history_limit(limit);
+    }
 
     if let Some(ref key_configs) = config.server.approval_keys {
         let keys: Vec<acteon_gateway::ApprovalKey> = key_configs
@@ -490,6 +535,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             sync_audit_writes = compliance.sync_audit_writes,
             immutable_audit = compliance.immutable_audit,
             hash_chain = compliance.hash_chain,
+            forbid_encryption_opt_out = compliance.forbid_encryption_opt_out,
             "compliance mode enabled"
         );
         builder = builder.compliance_config(compliance);
@@ -511,6 +557,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 action_type: on_cancel.action_type.clone(),
             });
         }
+        for (key, value) in &chain_toml.labels {
+            chain_config = chain_config.with_label(key, value);
+        }
         for step_toml in &chain_toml.steps {
             let mut step = if let Some(ref sub_chain_name) = step_toml.sub_chain {
                 ChainStepConfig::new_sub_chain(&step_toml.name, sub_chain_name)
@@ -533,6 +582,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             if let Some(delay) = step_toml.delay_seconds {
                 step = step.with_delay(delay);
             }
+            if let Some(timeout) = step_toml.timeout_seconds {
+                step = step.with_timeout(timeout);
+            }
             if let Some(ref retry_toml) = step_toml.retry {
                 use acteon_core::chain::{RetryBackoffStrategy, RetryPolicy};
                 let strategy = match retry_toml.strategy.as_deref() {
@@ -572,6 +624,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         builder = builder.chain(chain_config);
     }
+    builder = builder.chain_limits(config.chains.limits());
     if !config.chains.definitions.is_empty() {
         builder = builder.completed_chain_ttl(Duration::from_secs(
             config.chains.completed_chain_ttl_seconds,
@@ -589,6 +642,47 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         if let Some(ttl) = config.audit.ttl_seconds {
             builder = builder.audit_ttl_seconds(ttl);
         }
+        if config.audit.batch.enabled {
+            builder = builder.audit_batching(config.audit.batch.to_batch_config());
+            info!(
+                max_batch_size = config.audit.batch.max_batch_size,
+                flush_interval_ms = config.audit.batch.flush_interval_ms,
+                "audit write batching enabled"
+            );
+        }
+    }
+
+    // Wire per-namespace dedup key normalization.
+    for (namespace, normalization) in &config.dedup.namespaces {
+        builder = builder.dedup_normalization(namespace, *normalization);
+    }
+
+    // Wire provider health hysteresis.
+    builder = builder.health_hysteresis(config.provider_health.hysteresis);
+
+    // Wire namespace default provider and action type.
+    for defaults in &config.namespace_defaults {
+        builder = builder.namespace_defaults(
+            &defaults.namespace,
+            acteon_core::NamespaceDefaults {
+                provider: defaults.provider.clone(),
+                action_type: defaults.action_type.clone(),
+            },
+        );
+    }
+
+    // Wire provider payload defaults.
+    for defaults in &config.provider_defaults {
+        builder = builder.provider_payload_defaults(
+            &defaults.namespace,
+            &defaults.provider,
+            defaults.payload.clone(),
+        );
+    }
+
+    // Wire provider failover routes.
+    for route in &config.provider_failover {
+        builder = builder.provider_failover(&route.provider, &route.fallbacks);
     }
 
     // Wire circuit breakers if enabled.
@@ -1502,7 +1596,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Ok(entries) => {
             let mut count = 0usize;
             for (_key, value) in entries {
-                if let Ok(tpl) = serde_json::from_str::<acteon_core::Template>(&value) {
+                if let Ok(tpl) = serde_json::from_str::<acteon_core::Template>(&value)
+                    && tpl.deleted_at.is_none()
+                {
                     gateway.set_template(tpl);
                     count += 1;
                 }
@@ -1524,7 +1620,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Ok(entries) => {
             let mut count = 0usize;
             for (_key, value) in entries {
-                if let Ok(profile) = serde_json::from_str::<acteon_core::TemplateProfile>(&value) {
+                if let Ok(profile) = serde_json::from_str::<acteon_core::TemplateProfile>(&value)
+                    && profile.deleted_at.is_none()
+                {
                     gateway.set_template_profile(profile);
                     count += 1;
                 }
@@ -1625,6 +1723,37 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let bg_stream_tx = gateway.stream_tx().clone();
     let gateway = Arc::new(RwLock::new(gateway));
 
+    // Follow-on consumer: each follow-on runs in its own task so a slow
+    // provider never holds up the ones queued behind it.
+    {
+        let follow_on_gateway = Arc::clone(&gateway);
+        tokio::spawn(async move {
+            while let Some(event) = follow_on_rx.recv().await {
+                let gw = Arc::clone(&follow_on_gateway);
+                tokio::spawn(async move {
+                    gw.read().await.dispatch_follow_on(event).await;
+                });
+            }
+        });
+    }
+
+    // Provider health checks: probe every provider on a fixed interval so
+    // the hysteresis advances with time, not with how often the health
+    // endpoints are polled.
+    {
+        let health_gateway = Arc::clone(&gateway);
+        let mut ticker = tokio::time::interval(Duration::from_secs(
+            config.provider_health.check_interval_seconds.max(1),
+        ));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        tokio::spawn(async move {
+            loop {
+                ticker.tick().await;
+                acteon_gateway::poll_provider_health(&health_gateway).await;
+            }
+        });
+    }
+
     // Rules directory hot-reload watcher. Mirrors the auth/quotas
     // pattern: debounced (500ms) notify watcher on the configured
     // directory, recursive so changes to nested files trip a
@@ -1741,6 +1870,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .metrics(gateway.read().await.metrics_arc())
             .group_manager(Arc::clone(&group_manager))
             .state(Arc::clone(&store))
+            .lock(Arc::clone(&lock))
             .group_flush_channel(flush_tx)
             .timeout_channel(timeout_tx)
             .stream_tx(bg_stream_tx);
@@ -1813,6 +1943,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     "labels": group.labels,
                     "flushed_at": event.flushed_at.to_rfc3339(),
                 });
+                if let (Some(digest), Some(obj)) = (&event.digest, summary_payload.as_object_mut())
+                {
+                    obj.insert(
+                        "digest".to_string(),
+                        serde_json::Value::String(digest.clone()),
+                    );
+                }
 
                 // Mark as a group re-dispatch so quota enforcement is skipped.
                 if let Some(obj) = summary_payload.as_object_mut() {
@@ -1849,6 +1986,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     tenant: tenant.clone(),
                     action_type: None,
                     action_id: None,
+                    traceparent: None,
                 });
                 let _ = gw.stream_tx().send(StreamEvent {
                     id: uuid::Uuid::now_v7().to_string(),
@@ -1861,6 +1999,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     tenant: tenant.clone(),
                     action_type: None,
                     action_id: None,
+                    traceparent: None,
                 });
                 drop(gw);
 
@@ -1927,6 +2066,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     tenant: timeout_tenant.clone(),
                     action_type: None,
                     action_id: None,
+                    traceparent: None,
                 });
                 drop(gw);
 
@@ -2060,6 +2200,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         tenant: event.tenant.clone(),
                         action_type: None,
                         action_id: None,
+                        traceparent: None,
                     });
 
                     if let Err(e) = gw
@@ -2103,6 +2244,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     tenant: event.tenant.clone(),
                     action_type: Some(event.action.action_type.clone()),
                     action_id: Some(event.action_id.clone()),
+                    traceparent: None,
                 });
 
                 // Mark the action payload so that handle_schedule rejects re-scheduling.
@@ -2152,106 +2294,77 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         // Spawn consumer for recurring action events.
         // Constructs a concrete Action from the recurring template, dispatches it,
-        // updates execution state, and re-indexes the next occurrence.
+        // updates execution state, and re-indexes the next occurrence. Each
+        // occurrence runs in its own task: a `queue` occurrence may wait on
+        // its predecessor, and that wait must not hold up other schedules.
         let recurring_gateway = Arc::clone(&gateway);
         let recurring_store = Arc::clone(&store);
         tokio::spawn(async move {
             while let Some(event) = recurring_action_rx.recv().await {
-                let recurring = &event.recurring_action;
-                info!(
-                    recurring_id = %event.recurring_id,
-                    namespace = %event.namespace,
-                    tenant = %event.tenant,
-                    cron_expr = %recurring.cron_expr,
-                    "processing recurring action"
-                );
+                let recurring_gateway = Arc::clone(&recurring_gateway);
+                let recurring_store = Arc::clone(&recurring_store);
+                tokio::spawn(async move {
+                    let recurring = &event.recurring_action;
+                    info!(
+                        recurring_id = %event.recurring_id,
+                        namespace = %event.namespace,
+                        tenant = %event.tenant,
+                        cron_expr = %recurring.cron_expr,
+                        "processing recurring action"
+                    );
 
-                // Construct a concrete Action from the template.
-                let mut payload = recurring.action_template.payload.clone();
-                // Mark as a recurring re-dispatch so quota enforcement
-                // does not double-count the action.
-                if let Some(obj) = payload.as_object_mut() {
-                    obj.insert(
-                        "_recurring_dispatch".to_string(),
-                        serde_json::Value::Bool(true),
+                    // Construct a concrete Action from the template, rendering
+                    // the occurrence's execution time into the payload.
+                    let mut payload = recurring.render_payload(&event.scheduled_for);
+                    // Mark as a recurring re-dispatch so quota enforcement
+                    // does not double-count the action.
+                    if let Some(obj) = payload.as_object_mut() {
+                        obj.insert(
+                            "_recurring_dispatch".to_string(),
+                            serde_json::Value::Bool(true),
+                        );
+                    }
+                    let mut action = acteon_core::Action::new(
+                        event.namespace.as_str(),
+                        event.tenant.as_str(),
+                        recurring.action_template.provider.as_str(),
+                        recurring.action_template.action_type.as_str(),
+                        payload,
                     );
-                }
-                let mut action = acteon_core::Action::new(
-                    event.namespace.as_str(),
-                    event.tenant.as_str(),
-                    recurring.action_template.provider.as_str(),
-                    recurring.action_template.action_type.as_str(),
-                    payload,
-                );
 
-                // Dispatch through the gateway.
-                let gw = recurring_gateway.read().await;
-                let now = chrono::Utc::now();
-
-                // Resolve the dedup-key template for this occurrence with
-                // the shared helper, so a later backfill of the same window
-                // deduplicates against this dispatch.
-                action.dedup_key = recurring
-                    .action_template
-                    .resolve_dedup_key(&event.recurring_id, &now);
-
-                // Enforce the overlap policy when the previous occurrence
-                // spawned a chain execution that is still running. The
-                // gateway makes the decision (and cancels under
-                // `cancel_other`); the pre-dispatch re-arm in the background
-                // worker has already indexed the next occurrence, so
-                // skipping here keeps the schedule alive.
-                if let acteon_gateway::OverlapDecision::Skip { .. } = gw
-                    .enforce_overlap_policy(
+                    let now = chrono::Utc::now();
+
+                    // Resolve the dedup-key template for this occurrence with
+                    // the shared helper, so a later backfill of the same window
+                    // deduplicates against this dispatch.
+                    action.dedup_key = recurring
+                        .action_template
+                        .resolve_dedup_key(&event.recurring_id, &now);
+
+                    // Dispatch under the overlap policy. The gateway decides
+                    // whether the previous occurrence (an in-flight dispatch or
+                    // the chain execution it spawned) is still running, and
+                    // skips, waits, or cancels accordingly; the pre-dispatch
+                    // re-arm in the background worker has already indexed the
+                    // next occurrence, so skipping here keeps the schedule alive.
+                    // The gateway read guard is taken per step rather than held
+                    // across a `queue` wait, so rule reloads are not blocked.
+                    let result = acteon_gateway::dispatch_shared_recurring_occurrence(
+                        &recurring_gateway,
                         event.namespace.as_str(),
                         event.tenant.as_str(),
                         &event.recurring_id,
                         recurring,
+                        action,
+                        None,
                     )
-                    .await
-                {
-                    // Keep visibility live on skips: refresh
-                    // updated_at and next_execution_at (the worker
-                    // already re-armed the index), without touching
-                    // execution_count / last_executed_at.
-                    let rec_key = acteon_state::StateKey::new(
-                        event.namespace.as_str(),
-                        event.tenant.as_str(),
-                        acteon_state::KeyKind::RecurringAction,
-                        &event.recurring_id,
-                    );
-                    if let Ok(Some(raw_str)) = recurring_store.get(&rec_key).await
-                        && let Ok(data_str) = gw.decrypt_state_value(&raw_str)
-                        && let Ok(mut rec) =
-                            serde_json::from_str::<acteon_core::RecurringAction>(&data_str)
-                    {
-                        rec.updated_at = now;
-                        rec.next_execution_at = acteon_core::validate_cron_expr(&rec.cron_expr)
-                            .ok()
-                            .and_then(|cron| {
-                                acteon_core::validate_timezone(&rec.timezone)
-                                    .ok()
-                                    .and_then(|tz| acteon_core::next_occurrence(&cron, tz, &now))
-                            });
-                        if let Ok(json) = serde_json::to_string(&rec) {
-                            let encrypted = gw.encrypt_state_value(&json).unwrap_or(json);
-                            let _ = recurring_store.set(&rec_key, &encrypted, None).await;
-                        }
-                    }
-                    continue;
-                }
-
-                match gw.dispatch(action, None).await {
-                    Ok(outcome) => {
-                        info!(
-                            recurring_id = %event.recurring_id,
-                            ?outcome,
-                            "recurring action dispatched"
-                        );
-                        gw.metrics().increment_recurring_dispatched();
-
-                        // Update the recurring action state: increment count,
-                        // set last_executed_at, compute and index next occurrence.
+                    .await;
+                    let gw = recurring_gateway.read().await;
+                    if let Ok(acteon_gateway::OccurrenceOutcome::Skipped { .. }) = result {
+                        // Keep visibility live on skips: refresh
+                        // updated_at and next_execution_at (the worker
+                        // already re-armed the index), without touching
+                        // execution_count / last_executed_at.
                         let rec_key = acteon_state::StateKey::new(
                             event.namespace.as_str(),
                             event.tenant.as_str(),
@@ -2263,80 +2376,121 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             && let Ok(mut rec) =
                                 serde_json::from_str::<acteon_core::RecurringAction>(&data_str)
                         {
-                            rec.last_executed_at = Some(now);
-                            rec.execution_count += 1;
                             rec.updated_at = now;
-                            // Track the spawned chain execution so the
-                            // overlap policy can see whether it is still
-                            // running at the next occurrence.
-                            if let acteon_core::ActionOutcome::ChainStarted {
-                                ref chain_id, ..
-                            } = outcome
-                            {
-                                rec.last_execution_id = Some(chain_id.clone());
-                            }
-
-                            // Compute next occurrence (no backfill).
-                            let next = acteon_core::validate_cron_expr(&rec.cron_expr)
+                            rec.next_execution_at = acteon_core::validate_cron_expr(&rec.cron_expr)
                                 .ok()
                                 .and_then(|cron| {
                                     acteon_core::validate_timezone(&rec.timezone).ok().and_then(
                                         |tz| acteon_core::next_occurrence(&cron, tz, &now),
                                     )
                                 });
-
-                            // Check if the action should still be active.
-                            let still_active = rec.enabled
-                                && next.is_some()
-                                && rec.ends_at.is_none_or(|ends| next.unwrap() <= ends)
-                                && rec
-                                    .max_executions
-                                    .is_none_or(|max| rec.execution_count < max);
-
-                            rec.next_execution_at = if still_active { next } else { None };
-
                             if let Ok(json) = serde_json::to_string(&rec) {
                                 let encrypted = gw.encrypt_state_value(&json).unwrap_or(json);
                                 let _ = recurring_store.set(&rec_key, &encrypted, None).await;
                             }
+                        }
+                        return;
+                    }
 
-                            // Re-index or remove from pending index.
-                            let pending_key = acteon_state::StateKey::new(
+                    match result {
+                        Ok(acteon_gateway::OccurrenceOutcome::Skipped { .. }) => {}
+                        Ok(acteon_gateway::OccurrenceOutcome::Dispatched(outcome)) => {
+                            info!(
+                                recurring_id = %event.recurring_id,
+                                ?outcome,
+                                "recurring action dispatched"
+                            );
+                            gw.metrics().increment_recurring_dispatched();
+
+                            // Update the recurring action state: increment count,
+                            // set last_executed_at, compute and index next occurrence.
+                            let rec_key = acteon_state::StateKey::new(
                                 event.namespace.as_str(),
                                 event.tenant.as_str(),
-                                acteon_state::KeyKind::PendingRecurring,
+                                acteon_state::KeyKind::RecurringAction,
                                 &event.recurring_id,
                             );
-
-                            // Route through the shared helpers so the durable
-                            // `recurring_active` counter stays in sync with the
-                            // index (issue #118). Best-effort: a failed counter
-                            // bump self-heals on the next reconciliation scan.
-                            if let Some(next_at) = rec.next_execution_at {
-                                let _ = acteon_state::set_pending_recurring(
-                                    recurring_store.as_ref(),
-                                    &pending_key,
-                                    next_at.timestamp_millis(),
-                                )
-                                .await;
-                            } else {
-                                let _ = acteon_state::remove_pending_recurring(
-                                    recurring_store.as_ref(),
-                                    &pending_key,
-                                )
-                                .await;
+                            if let Ok(Some(raw_str)) = recurring_store.get(&rec_key).await
+                                && let Ok(data_str) = gw.decrypt_state_value(&raw_str)
+                                && let Ok(mut rec) =
+                                    serde_json::from_str::<acteon_core::RecurringAction>(&data_str)
+                            {
+                                rec.last_executed_at = Some(now);
+                                rec.execution_count += 1;
+                                rec.updated_at = now;
+                                // Track the spawned chain execution so the
+                                // overlap policy can see whether it is still
+                                // running at the next occurrence.
+                                if let acteon_core::ActionOutcome::ChainStarted {
+                                    ref chain_id,
+                                    ..
+                                } = outcome
+                                {
+                                    rec.last_execution_id = Some(chain_id.clone());
+                                }
+
+                                // Compute next occurrence (no backfill).
+                                let next = acteon_core::validate_cron_expr(&rec.cron_expr)
+                                    .ok()
+                                    .and_then(|cron| {
+                                        acteon_core::validate_timezone(&rec.timezone).ok().and_then(
+                                            |tz| acteon_core::next_occurrence(&cron, tz, &now),
+                                        )
+                                    });
+
+                                // Check if the action should still be active.
+                                let still_active = rec.enabled
+                                    && next.is_some()
+                                    && rec.ends_at.is_none_or(|ends| next.unwrap() <= ends)
+                                    && rec
+                                        .max_executions
+                                        .is_none_or(|max| rec.execution_count < max);
+
+                                rec.next_execution_at = if still_active { next } else { None };
+
+                                if let Ok(json) = serde_json::to_string(&rec) {
+                                    let encrypted = gw.encrypt_state_value(&json).unwrap_or(json);
+                                    let _ = recurring_store.set(&rec_key, &encrypted, None).await;
+                                }
+
+                                // Re-index or remove from pending index.
+                                let pending_key = acteon_state::StateKey::new(
+                                    event.namespace.as_str(),
+                                    event.tenant.as_str(),
+                                    acteon_state::KeyKind::PendingRecurring,
+                                    &event.recurring_id,
+                                );
+
+                                // Route through the shared helpers so the durable
+                                // `recurring_active` counter stays in sync with the
+                                // index (issue #118). Best-effort: a failed counter
+                                // bump self-heals on the next reconciliation scan.
+                                if let Some(next_at) = rec.next_execution_at {
+                                    let _ = acteon_state::set_pending_recurring(
+                                        recurring_store.as_ref(),
+                                        &pending_key,
+                                        next_at.timestamp_millis(),
+                                    )
+                                    .await;
+                                } else {
+                                    let _ = acteon_state::remove_pending_recurring(
+                                        recurring_store.as_ref(),
+                                        &pending_key,
+                                    )
+                                    .await;
+                                }
                             }
                         }
+                        Err(e) => {
+                            tracing::error!(
+                                recurring_id = %event.recurring_id,
+                                error = %e,
+                                "failed to dispatch recurring action"
+                            );
+                            gw.metrics().increment_recurring_errors();
+                        }
                     }
-                    Err(e) => {
-                        tracing::error!(
-                            recurring_id = %event.recurring_id,
-                            error = %e,
-                            "failed to dispatch recurring action"
-                        );
-                        gw.metrics().increment_recurring_errors();
-                    }
-                }
+                });
             }
         });
 
@@ -10,7 +10,7 @@
 //! API-managed policies are untouched: TOML-loaded records carry a
 //! reserved `_source = "toml"` label that scopes the reconcile to its
 //! own set, and use deterministic `UUIDv5` IDs derived from
-//! `(namespace, tenant, provider, principal, per_principal)` so the
+//! `(namespace, tenant, org, provider, principal, per_principal)` so the
 //! same TOML entry reloaded twice is a no-op rather than a duplicate.
 
 use std::collections::{HashMap, HashSet};
@@ -18,7 +18,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
-use acteon_core::{OverageBehavior, QuotaPolicy, QuotaWindow};
+use acteon_core::{ORG_QUOTA_COUNTER_TENANT, OverageBehavior, QuotaPolicy, QuotaWindow};
 use acteon_gateway::Gateway;
 use acteon_state::{KeyKind, StateKey, StateStore};
 use chrono::Utc;
@@ -65,8 +65,15 @@ pub struct StaticQuotasHandle {
 #[derive(Debug, Clone, Deserialize)]
 pub struct StaticQuotaEntry {
     pub namespace: String,
+    /// Omitted for org-level entries, which list `members` instead.
+    #[serde(default)]
     pub tenant: String,
     #[serde(default)]
+    pub org: Option<String>,
+    /// Member tenants of an org-level entry.
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
     pub provider: Option<String>,
     #[serde(default)]
     pub principal: Option<String>,
@@ -83,6 +90,9 @@ pub struct StaticQuotaEntry {
     pub description: Option<String>,
     #[serde(default)]
     pub labels: HashMap<String, String>,
+    /// Usage percentages that emit a threshold-crossed stream event.
+    #[serde(default)]
+    pub alert_thresholds: Vec<u8>,
 }
 
 fn default_enabled() -> bool {
@@ -133,28 +143,44 @@ pub struct ReconcileReport {
 
 /// Deterministic ID for a TOML-loaded policy. Derived from the scope
 /// tuple so the same entry reloaded twice keeps the same record.
+///
+/// The org component is only appended when set, so IDs of entries
+/// without an org are unchanged from before org-level quotas existed.
 pub fn derive_id(
     namespace: &str,
     tenant: &str,
+    org: Option<&str>,
     provider: Option<&str>,
     principal: Option<&str>,
     per_principal: bool,
 ) -> String {
-    let name = format!(
+    let mut name = format!(
         "{namespace}|{tenant}|{}|{}|{per_principal}",
         provider.unwrap_or(""),
         principal.unwrap_or(""),
     );
+    if let Some(org) = org {
+        name.push_str("|org=");
+        name.push_str(org);
+    }
     Uuid::new_v5(&QUOTA_NS, name.as_bytes()).to_string()
 }
 
 /// Convert a [`StaticQuotaEntry`] into the runtime [`QuotaPolicy`],
 /// applying the deterministic ID and source label.
-pub fn entry_to_policy(entry: StaticQuotaEntry) -> Result<QuotaPolicy, String> {
+pub fn entry_to_policy(mut entry: StaticQuotaEntry) -> Result<QuotaPolicy, String> {
     let window = entry.window.into_window()?;
+    // Org-level entries are stored under the reserved org tenant.
+    if entry.org.is_some() {
+        if !entry.tenant.is_empty() {
+            return Err("org-level entries take member tenants in `members`, not `tenant`".into());
+        }
+        ORG_QUOTA_COUNTER_TENANT.clone_into(&mut entry.tenant);
+    }
     let id = derive_id(
         &entry.namespace,
         &entry.tenant,
+        entry.org.as_deref(),
         entry.provider.as_deref(),
         entry.principal.as_deref(),
         entry.per_principal,
@@ -166,6 +192,8 @@ pub fn entry_to_policy(entry: StaticQuotaEntry) -> Result<QuotaPolicy, String> {
         id,
         namespace: entry.namespace,
         tenant: entry.tenant,
+        org: entry.org,
+        members: entry.members,
         provider: entry.provider,
         principal: entry.principal,
         per_principal: entry.per_principal,
@@ -177,6 +205,7 @@ pub fn entry_to_policy(entry: StaticQuotaEntry) -> Result<QuotaPolicy, String> {
         updated_at: now,
         description: entry.description,
         labels,
+        alert_thresholds: entry.alert_thresholds,
     };
     policy.validate_scope()?;
     Ok(policy)
@@ -477,19 +506,20 @@ mod tests {
 
     #[test]
     fn derive_id_is_deterministic() {
-        let a = derive_id("ns", "t", Some("slack"), Some("alice"), false);
-        let b = derive_id("ns", "t", Some("slack"), Some("alice"), false);
+        let a = derive_id("ns", "t", None, Some("slack"), Some("alice"), false);
+        let b = derive_id("ns", "t", None, Some("slack"), Some("alice"), false);
         assert_eq!(a, b);
     }
 
     #[test]
     fn derive_id_changes_with_each_dimension() {
-        let base = derive_id("ns", "t", None, None, false);
-        assert_ne!(base, derive_id("ns2", "t", None, None, false));
-        assert_ne!(base, derive_id("ns", "t2", None, None, false));
-        assert_ne!(base, derive_id("ns", "t", Some("x"), None, false));
-        assert_ne!(base, derive_id("ns", "t", None, Some("x"), false));
-        assert_ne!(base, derive_id("ns", "t", None, None, true));
+        let base = derive_id("ns", "t", None, None, None, false);
+        assert_ne!(base, derive_id("ns2", "t", None, None, None, false));
+        assert_ne!(base, derive_id("ns", "t2", None, None, None, false));
+        assert_ne!(base, derive_id("ns", "t", None, Some("x"), None, false));
+        assert_ne!(base, derive_id("ns", "t", None, None, Some("x"), false));
+        assert_ne!(base, derive_id("ns", "t", None, None, None, true));
+        assert_ne!(base, derive_id("ns", "t", Some("acme"), None, None, false));
     }
 
     #[test]
@@ -497,6 +527,8 @@ mod tests {
         let entry = StaticQuotaEntry {
             namespace: "n".into(),
             tenant: "t".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: true,
@@ -506,6 +538,7 @@ mod tests {
             enabled: true,
             description: None,
             labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
         };
         let p = entry_to_policy(entry).unwrap();
         assert!(p.per_principal);
@@ -515,6 +548,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn org_entry_is_stored_under_the_org_tenant() {
+        let entry = StaticQuotaEntry {
+            namespace: "n".into(),
+            tenant: String::new(),
+            org: Some("acme".into()),
+            members: vec!["a".into(), "b".into()],
+            provider: None,
+            principal: None,
+            per_principal: false,
+            max_actions: 10,
+            window: WindowSpec::Named("hourly".into()),
+            overage_behavior: OverageBehavior::Block,
+            enabled: true,
+            description: None,
+            labels: HashMap::new(),
+            alert_thresholds: Vec::new(),
+        };
+        let p = entry_to_policy(entry.clone()).unwrap();
+        assert_eq!(p.tenant, ORG_QUOTA_COUNTER_TENANT);
+        assert_eq!(p.members, ["a", "b"]);
+
+        // A member tenant cannot be named as the policy's tenant.
+        let mut bad = entry;
+        bad.tenant = "a".into();
+        assert!(entry_to_policy(bad).is_err());
+    }
+
     #[test]
     fn window_spec_named_and_seconds() {
         assert!(matches!(
@@ -11,7 +11,7 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use acteon_core::{Template, TemplateProfile, TemplateProfileField};
+use acteon_core::{Template, TemplateOutputType, TemplateProfile, TemplateProfileField};
 use acteon_gateway::Gateway;
 use acteon_state::{KeyKind, StateKey, StateStore};
 use chrono::Utc;
@@ -65,6 +65,8 @@ pub struct StaticProfileEntry {
     pub name: String,
     pub fields: HashMap<String, TemplateProfileField>,
     #[serde(default)]
+    pub output_type: Option<TemplateOutputType>,
+    #[serde(default)]
     pub description: Option<String>,
     #[serde(default)]
     pub labels: HashMap<String, String>,
@@ -131,6 +133,7 @@ fn entry_to_template(entry: StaticTemplateEntry, manifest_dir: &Path) -> Result<
         created_at: now,
         updated_at: now,
         labels,
+        deleted_at: None,
     })
 }
 
@@ -144,10 +147,12 @@ fn entry_to_profile(entry: StaticProfileEntry) -> TemplateProfile {
         namespace: entry.namespace,
         tenant: entry.tenant,
         fields: entry.fields,
+        output_type: entry.output_type,
         description: entry.description,
         created_at: now,
         updated_at: now,
         labels,
+        deleted_at: None,
     }
 }
 
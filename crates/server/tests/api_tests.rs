@@ -248,6 +248,52 @@ async fn health_returns_200() {
     assert!(json["metrics"].is_object());
 }
 
+#[tokio::test]
+async fn detailed_health_reports_each_component() {
+    let state = build_test_state(vec![]);
+    let app = build_app(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/health/detailed")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["status"], "ok");
+    let components = &json["components"];
+    assert_eq!(components["state_store"]["status"], "ok");
+    assert!(components["state_store"]["latency_ms"].is_u64());
+    assert_eq!(components["audit_store"]["status"], "disabled");
+    assert_eq!(components["providers"]["status"], "ok");
+}
+
+#[tokio::test]
+async fn detailed_health_requires_auth() {
+    let state = build_test_state_with_auth(vec![default_test_grant()]);
+    let app = build_app(state);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/health/detailed")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
 #[tokio::test]
 async fn metrics_returns_200() {
     let state = build_test_state(vec![]);
@@ -305,6 +351,40 @@ async fn dispatch_returns_valid_outcome() {
     );
 }
 
+#[tokio::test]
+async fn dispatch_without_provider_or_namespace_default_returns_400() {
+    let state = build_test_state(vec![]);
+    let app = build_app(state);
+
+    let mut action = serde_json::to_value(test_action()).unwrap();
+    action.as_object_mut().unwrap().remove("provider");
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/dispatch")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(action.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(
+        json["error"]
+            .as_str()
+            .unwrap()
+            .contains("sets no default provider"),
+        "unexpected error: {json}"
+    );
+}
+
 #[tokio::test]
 async fn dispatch_batch_returns_array() {
     let state = build_test_state(vec![]);
@@ -335,6 +415,41 @@ async fn dispatch_batch_returns_array() {
     assert_eq!(json.as_array().unwrap().len(), 2);
 }
 
+#[tokio::test]
+async fn dispatch_batch_accepts_gzip_body() {
+    use std::io::Write;
+
+    let state = build_test_state(vec![]);
+    let app = build_app(state);
+
+    let actions = vec![test_action(), test_action()];
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(&serde_json::to_vec(&actions).unwrap())
+        .unwrap();
+    let body = encoder.finish().unwrap();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/dispatch/batch")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .header(http::header::CONTENT_ENCODING, "gzip")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 2);
+}
+
 #[tokio::test]
 async fn dispatch_batch_all_signed_all_verified() {
     let (state, key) = build_test_state_with_signing(false);
@@ -628,6 +743,57 @@ rules:
     let _ = std::fs::remove_dir_all(&tmpdir);
 }
 
+#[tokio::test]
+async fn rollback_rules_restores_listed_version() {
+    let state = build_test_state(vec![Rule::new(
+        "v1-rule",
+        Expr::Bool(true),
+        RuleAction::Allow,
+    )]);
+    state.gateway.write().await.reload_rules(vec![Rule::new(
+        "v2-rule",
+        Expr::Bool(true),
+        RuleAction::Deny,
+    )]);
+
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .uri("/v1/rules/versions")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let versions: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(versions[0]["version"], 2);
+    assert_eq!(versions[0]["active"], true);
+    assert_eq!(versions[1]["version"], 1);
+
+    let rollback = |version: u64| {
+        Request::builder()
+            .method(http::Method::POST)
+            .uri("/v1/rules/rollback")
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .body(Body::from(format!(r#"{{"version": {version}}}"#)))
+            .unwrap()
+    };
+    let response = build_app(state.clone()).oneshot(rollback(1)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        state.gateway.read().await.rules()[0].name,
+        "v1-rule",
+        "rollback should restore the first rule set"
+    );
+
+    let response = build_app(state).oneshot(rollback(42)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn set_rule_enabled_toggles() {
     let rules = vec![Rule::new("toggle-me", Expr::Bool(true), RuleAction::Allow)];
@@ -654,6 +820,8 @@ async fn set_rule_enabled_toggles() {
     let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
     assert_eq!(json["enabled"], false);
     assert_eq!(json["name"], "toggle-me");
+    assert_eq!(json["priority"], 0);
+    assert!(json["description"].is_null());
 
     // Verify the rule is actually disabled.
     {
@@ -709,6 +877,72 @@ async fn set_rule_enabled_not_found() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn list_chain_definitions_filters_by_name_and_label() {
+    let state = build_test_state(vec![]);
+    {
+        let gw = state.gateway.read().await;
+        for (name, team) in [
+            ("billing-retry", "payments"),
+            ("onboarding", "growth"),
+            ("user-onboarding-v2", "payments"),
+        ] {
+            let config = acteon_core::ChainConfig::new(name)
+                .with_step(acteon_core::ChainStepConfig::new(
+                    "notify",
+                    "email",
+                    "send",
+                    serde_json::json!({}),
+                ))
+                .with_label("team", team);
+            gw.set_chain_config(config).unwrap();
+        }
+    }
+    let app = build_app(state);
+
+    let list = |uri: &'static str| {
+        let app = app.clone();
+        async move {
+            let response = app
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            let status = response.status();
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            (
+                status,
+                serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            )
+        }
+    };
+    let names = |json: &serde_json::Value| -> Vec<String> {
+        json["definitions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|d| d["name"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let (status, json) = list("/v1/chains/definitions?name=Onboarding").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(names(&json), ["onboarding", "user-onboarding-v2"]);
+    assert_eq!(json["total"], 2);
+
+    let (_, json) = list("/v1/chains/definitions?name=onboarding&label=team%3Dpayments").await;
+    assert_eq!(names(&json), ["user-onboarding-v2"]);
+    assert_eq!(json["definitions"][0]["labels"]["team"], "payments");
+
+    let (_, json) = list("/v1/chains/definitions?limit=2&offset=1").await;
+    assert_eq!(names(&json), ["onboarding", "user-onboarding-v2"]);
+    assert_eq!(json["total"], 3);
+
+    let (status, _) = list("/v1/chains/definitions?label=team").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn swagger_ui_returns_200() {
     let state = build_test_state(vec![]);
@@ -766,6 +1000,10 @@ async fn openapi_json_is_valid() {
         .as_object()
         .expect("paths should be an object");
     assert!(paths.contains_key("/health"), "missing /health");
+    assert!(
+        paths.contains_key("/health/detailed"),
+        "missing /health/detailed"
+    );
     assert!(paths.contains_key("/metrics"), "missing /metrics");
     assert!(paths.contains_key("/v1/dispatch"), "missing /v1/dispatch");
     assert!(
@@ -933,6 +1171,62 @@ async fn audit_get_by_action_id() {
     assert_eq!(json["provider"], "email");
 }
 
+#[tokio::test]
+async fn audit_hold_is_listed_for_tenant() {
+    let audit: Arc<dyn AuditStore> = Arc::new(MemoryAuditStore::new());
+    let state = build_test_state_with_audit(vec![], Some(Arc::clone(&audit)));
+
+    let action = test_action();
+    let action_id = action.id.to_string();
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/dispatch")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_string(&action).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri(format!("/v1/audit/{action_id}/hold"))
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(r#"{"reason": "case 2026-114"}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = build_app(state)
+        .oneshot(
+            Request::builder()
+                .uri("/v1/audit/holds?namespace=notifications&tenant=tenant-1")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["count"], 1);
+    assert_eq!(json["holds"][0]["action_id"], action_id);
+    assert_eq!(json["holds"][0]["reason"], "case 2026-114");
+
+    let record = audit.get_by_action_id(&action_id).await.unwrap().unwrap();
+    assert!(record.expires_at.is_none());
+}
+
 #[tokio::test]
 async fn test_dispatch_concurrency_limit_enforced() {
     let mut state = build_test_state(vec![]);
@@ -1571,6 +1865,62 @@ async fn replay_single_action_works() {
     assert_eq!(metadata["replayed_from"].as_str().unwrap(), original_id);
 }
 
+#[tokio::test]
+async fn evaluate_audit_record_returns_trace() {
+    let audit: Arc<dyn AuditStore> = Arc::new(MemoryAuditStore::new());
+    let rules = vec![Rule::new("block-all", Expr::Bool(true), RuleAction::Deny)];
+    let state = build_test_state_with_audit(rules, Some(Arc::clone(&audit)));
+
+    let action = test_action();
+    let original_id = action.id.to_string();
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/dispatch")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_string(&action).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // Wait for audit
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri(format!("/v1/audit/{original_id}/evaluate"))
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(r#"{"at_dispatch_time": true}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let trace: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(trace["matched_rule"], "block-all");
+
+    let response = build_app(state)
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/audit/does-not-exist/evaluate")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from("{}"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn replay_bulk_actions_works() {
     let audit: Arc<dyn AuditStore> = Arc::new(MemoryAuditStore::new());
@@ -2355,37 +2705,113 @@ async fn rule_coverage_aggregates_dispatched_actions() {
     assert!(json["unmatched_rules"].as_array().unwrap().is_empty());
 }
 
-// =========================================================================
-// Tenant-scoped API key dispatch enforcement
-// =========================================================================
-
-async fn dispatch_with_key(app: axum::Router, action: &Action) -> StatusCode {
-    let body = serde_json::to_string(action).unwrap();
-    let (header_name, header_val) = auth_headers();
-    app.oneshot(
-        Request::builder()
-            .method(http::Method::POST)
-            .uri("/v1/dispatch")
-            .header(http::header::CONTENT_TYPE, "application/json")
-            .header(header_name, header_val)
-            .body(Body::from(body))
-            .unwrap(),
-    )
-    .await
-    .unwrap()
-    .status()
-}
-
-fn action_for(namespace: &str, tenant: &str, provider: &str, action_type: &str) -> Action {
-    Action::new(
-        namespace,
-        tenant,
-        provider,
-        action_type,
-        serde_json::json!({"to": "user@example.com"}),
-    )
-}
-
+#[tokio::test]
+async fn analytics_drill_into_scopes_to_entry() {
+    let audit: Arc<dyn AuditStore> = Arc::new(MemoryAuditStore::new());
+    let state = build_test_state_with_audit_and_analytics(vec![], Some(Arc::clone(&audit)), true);
+
+    // tenant-1: two send_email, one send_digest. tenant-2: three send_email.
+    let app = build_app(state.clone());
+    for (tenant, action_type) in [
+        ("tenant-1", "send_email"),
+        ("tenant-1", "send_email"),
+        ("tenant-1", "send_digest"),
+        ("tenant-2", "send_email"),
+        ("tenant-2", "send_email"),
+        ("tenant-2", "send_email"),
+    ] {
+        let action = Action::new(
+            "notifications",
+            tenant,
+            "email",
+            action_type,
+            serde_json::json!({"to": "user@example.com"}),
+        );
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::POST)
+                    .uri("/v1/dispatch")
+                    .header(http::header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(serde_json::to_string(&action).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    // Give async audit writes time to land.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .uri("/v1/analytics?metric=top_action_types&drill_dimension=tenant&drill_value=tenant-1")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["total_count"].as_u64().unwrap(), 3);
+    let entries = json["top_entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0]["label"], "send_email");
+    assert_eq!(entries[0]["count"].as_u64().unwrap(), 2);
+    assert_eq!(entries[1]["label"], "send_digest");
+    assert_eq!(entries[1]["count"].as_u64().unwrap(), 1);
+
+    // Unsupported dimensions are rejected rather than silently ignored.
+    let response = build_app(state)
+        .oneshot(
+            Request::builder()
+                .uri("/v1/analytics?metric=volume&drill_dimension=matched_rule&drill_value=r")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+// =========================================================================
+// Tenant-scoped API key dispatch enforcement
+// =========================================================================
+
+async fn dispatch_with_key(app: axum::Router, action: &Action) -> StatusCode {
+    let body = serde_json::to_string(action).unwrap();
+    let (header_name, header_val) = auth_headers();
+    app.oneshot(
+        Request::builder()
+            .method(http::Method::POST)
+            .uri("/v1/dispatch")
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(header_name, header_val)
+            .body(Body::from(body))
+            .unwrap(),
+    )
+    .await
+    .unwrap()
+    .status()
+}
+
+fn action_for(namespace: &str, tenant: &str, provider: &str, action_type: &str) -> Action {
+    Action::new(
+        namespace,
+        tenant,
+        provider,
+        action_type,
+        serde_json::json!({"to": "user@example.com"}),
+    )
+}
+
 #[tokio::test]
 async fn scoped_api_key_allows_in_scope_dispatch() {
     let state = build_test_state_with_auth(vec![default_test_grant()]);
@@ -3655,6 +4081,263 @@ async fn tenant_authz_templates_create_viewer_role_forbidden() {
     assert_eq!(status, StatusCode::FORBIDDEN);
 }
 
+/// Send a JSON request to the templates API and return status and body.
+async fn templates_request(
+    state: &AppState,
+    method: http::Method,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let body = body.map_or_else(Body::empty, |b| Body::from(b.to_string()));
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(body)
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null),
+    )
+}
+
+#[tokio::test]
+async fn deleted_template_and_profile_can_be_restored() {
+    let state = build_test_state(vec![]);
+    let list_uri = "/v1/templates?namespace=notifications&tenant=tenant-1";
+    let profiles_uri = "/v1/templates/profiles?namespace=notifications&tenant=tenant-1";
+
+    let (status, tpl) = templates_request(
+        &state,
+        http::Method::POST,
+        "/v1/templates",
+        Some(create_template_body("notifications", "tenant-1")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let tpl_id = tpl["id"].as_str().unwrap().to_owned();
+    let (status, prof) = templates_request(
+        &state,
+        http::Method::POST,
+        "/v1/templates/profiles",
+        Some(serde_json::json!({
+            "name": "prof-test",
+            "namespace": "notifications",
+            "tenant": "tenant-1",
+            "fields": {"body": {"$ref": "tpl-test"}},
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let prof_id = prof["id"].as_str().unwrap().to_owned();
+
+    // Delete the profile first (the template is still referenced), then the
+    // template; both disappear from their lists.
+    let (status, _) = templates_request(
+        &state,
+        http::Method::DELETE,
+        &format!("/v1/templates/profiles/{prof_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = templates_request(
+        &state,
+        http::Method::DELETE,
+        &format!("/v1/templates/{tpl_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, list) = templates_request(&state, http::Method::GET, list_uri, None).await;
+    assert_eq!(list["count"], 0);
+    let (_, list) = templates_request(&state, http::Method::GET, profiles_uri, None).await;
+    assert_eq!(list["count"], 0);
+
+    // The deleted template can no longer be referenced, and the deleted
+    // profile cannot come back before its template does.
+    let (status, _) = templates_request(
+        &state,
+        http::Method::POST,
+        "/v1/templates/profiles",
+        Some(serde_json::json!({
+            "name": "prof-other",
+            "namespace": "notifications",
+            "tenant": "tenant-1",
+            "fields": {"body": {"$ref": "tpl-test"}},
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = templates_request(
+        &state,
+        http::Method::POST,
+        &format!("/v1/templates/profiles/{prof_id}/restore"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, restored) = templates_request(
+        &state,
+        http::Method::POST,
+        &format!("/v1/templates/{tpl_id}/restore"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(restored.get("deleted_at").is_none());
+    let (status, _) = templates_request(
+        &state,
+        http::Method::POST,
+        &format!("/v1/templates/profiles/{prof_id}/restore"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, list) = templates_request(&state, http::Method::GET, list_uri, None).await;
+    assert_eq!(list["count"], 1);
+    assert_eq!(list["templates"][0]["id"], tpl_id.as_str());
+    let (_, list) = templates_request(&state, http::Method::GET, profiles_uri, None).await;
+    assert_eq!(list["count"], 1);
+    assert_eq!(list["profiles"][0]["id"], prof_id.as_str());
+
+    // Restoring a live template is a conflict.
+    let (status, _) = templates_request(
+        &state,
+        http::Method::POST,
+        &format!("/v1/templates/{tpl_id}/restore"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn referenced_template_delete_requires_force() {
+    let state = build_test_state(vec![]);
+
+    let (_, tpl) = templates_request(
+        &state,
+        http::Method::POST,
+        "/v1/templates",
+        Some(create_template_body("notifications", "tenant-1")),
+    )
+    .await;
+    let tpl_id = tpl["id"].as_str().unwrap().to_owned();
+    let (status, _) = templates_request(
+        &state,
+        http::Method::POST,
+        "/v1/templates/profiles",
+        Some(serde_json::json!({
+            "name": "prof-test",
+            "namespace": "notifications",
+            "tenant": "tenant-1",
+            "fields": {"body": {"$ref": "tpl-test"}},
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, refs) = templates_request(
+        &state,
+        http::Method::GET,
+        &format!("/v1/templates/{tpl_id}/references"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(refs["count"], 1);
+    assert_eq!(refs["references"][0]["kind"], "profile");
+    assert_eq!(refs["references"][0]["name"], "prof-test");
+
+    let (status, _) = templates_request(
+        &state,
+        http::Method::DELETE,
+        &format!("/v1/templates/{tpl_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = templates_request(
+        &state,
+        http::Method::DELETE,
+        &format!("/v1/templates/{tpl_id}?force=true"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn template_references_skip_rules_pinned_to_another_scope() {
+    let group = |name: &str, condition: Expr| {
+        Rule::new(
+            name,
+            condition,
+            RuleAction::Group {
+                group_by: vec!["host".into()],
+                group_wait_seconds: 30,
+                group_interval_seconds: 300,
+                repeat_interval_seconds: None,
+                max_group_size: 100,
+                template: Some("tpl-test".into()),
+            },
+        )
+    };
+    let in_namespace = |namespace: &str| {
+        Expr::Binary(
+            BinaryOp::Eq,
+            Box::new(Expr::Field(
+                Box::new(Expr::Ident("action".into())),
+                "namespace".into(),
+            )),
+            Box::new(Expr::String(namespace.into())),
+        )
+    };
+    let state = build_test_state(vec![
+        group("any-scope", Expr::Bool(true)),
+        group("same-namespace", in_namespace("notifications")),
+        group("other-namespace", in_namespace("billing")),
+    ]);
+
+    let (_, tpl) = templates_request(
+        &state,
+        http::Method::POST,
+        "/v1/templates",
+        Some(create_template_body("notifications", "tenant-1")),
+    )
+    .await;
+    let tpl_id = tpl["id"].as_str().unwrap().to_owned();
+
+    let (status, refs) = templates_request(
+        &state,
+        http::Method::GET,
+        &format!("/v1/templates/{tpl_id}/references"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let mut names: Vec<&str> = refs["references"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["name"].as_str().unwrap())
+        .collect();
+    names.sort_unstable();
+    assert_eq!(names, ["any-scope", "same-namespace"]);
+}
+
 #[tokio::test]
 async fn tenant_authz_templates_render_cross_tenant_forbidden() {
     let app = build_app(build_test_state_with_auth(vec![templates_scope_grant()]));
@@ -3810,6 +4493,27 @@ async fn tenant_authz_quota_create_allows_wildcard() {
     assert_ne!(status, StatusCode::FORBIDDEN);
 }
 
+#[tokio::test]
+async fn tenant_authz_org_quota_requires_global_grant() {
+    // An org quota spans tenants, so a grant on one of its members is not
+    // enough to define it — only a wildcard caller may.
+    let body = serde_json::json!({
+        "namespace": "notifications",
+        "org": "acme",
+        "members": ["tenant-1", "tenant-2"],
+        "max_actions": 1000,
+        "window": "daily",
+        "overage_behavior": "block",
+    });
+    let app = build_app(build_test_state_with_auth(vec![default_test_grant()]));
+    let status = auth_post_status(app, "/v1/quotas", body.clone()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let app = build_app(build_test_state_with_auth(vec![wildcard_admin_grant()]));
+    let status = auth_post_status(app, "/v1/quotas", body).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
 #[tokio::test]
 async fn tenant_authz_retention_create_denies_cross_tenant() {
     let app = build_app(build_test_state_with_auth(vec![default_test_grant()]));
@@ -3849,3 +4553,220 @@ async fn tenant_authz_dlq_stats_denies_namespace_scoped_wildcard_tenant() {
     let status = auth_get_status(app, "/v1/dlq/stats").await;
     assert_eq!(status, StatusCode::FORBIDDEN);
 }
+
+#[tokio::test]
+async fn feature_flags_crud_round_trip() {
+    let state = build_test_state(vec![]);
+
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(http::Method::PUT)
+                .uri("/v1/flags/notifications/tenant-1/new_sms_vendor")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(r#"{"enabled": true}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .uri("/v1/flags/notifications/tenant-1")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["tenant"], "tenant-1");
+    assert_eq!(json["flags"]["new_sms_vendor"], true);
+
+    // Invalid names are rejected before touching the store.
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(http::Method::PUT)
+                .uri("/v1/flags/notifications/tenant-1/bad.name")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(r#"{"enabled": true}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    for expected in [StatusCode::NO_CONTENT, StatusCode::NOT_FOUND] {
+        let response = build_app(state.clone())
+            .oneshot(
+                Request::builder()
+                    .method(http::Method::DELETE)
+                    .uri("/v1/flags/notifications/tenant-1/new_sms_vendor")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), expected);
+    }
+}
+
+#[tokio::test]
+async fn dispatch_preview_returns_rendered_payload() {
+    let state = build_test_state(vec![]);
+
+    let profile = serde_json::json!({
+        "name": "welcome",
+        "namespace": "notifications",
+        "tenant": "tenant-1",
+        "fields": {"subject": "Hello, {{ name }}!"},
+    });
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/templates/profiles")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_vec(&profile).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let action = Action::new(
+        "notifications",
+        "tenant-1",
+        "email",
+        "send_email",
+        serde_json::json!({"to": "user@example.com", "name": "Alice"}),
+    )
+    .with_template("welcome");
+    let response = build_app(state)
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/dispatch/preview")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_vec(&action).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["verdict"], "allow");
+    assert_eq!(json["provider"], "email");
+    assert_eq!(json["payload"]["subject"], "Hello, Alice!");
+}
+
+#[tokio::test]
+async fn dispatch_preview_reports_render_errors_as_unprocessable() {
+    let state = build_test_state(vec![]);
+
+    let action = Action::new(
+        "notifications",
+        "tenant-1",
+        "email",
+        "send_email",
+        serde_json::json!({"to": "user@example.com"}),
+    )
+    .with_template("missing");
+    let response = build_app(state)
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/dispatch/preview")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_vec(&action).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(
+        json["error"]
+            .as_str()
+            .unwrap()
+            .contains("template profile not found: missing")
+    );
+}
+
+#[tokio::test]
+async fn verbose_dry_run_returns_rendered_payload_and_routing() {
+    let state = build_test_state(vec![Rule::new(
+        "reroute-sms",
+        Expr::Bool(true),
+        RuleAction::Reroute {
+            target_provider: Some("sms".into()),
+            target_namespace: None,
+            target_tenant: None,
+        },
+    )]);
+
+    let profile = serde_json::json!({
+        "name": "welcome",
+        "namespace": "notifications",
+        "tenant": "tenant-1",
+        "fields": {"subject": "Hello, {{ name }}!"},
+    });
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/templates/profiles")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_vec(&profile).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let action = Action::new(
+        "notifications",
+        "tenant-1",
+        "email",
+        "send_email",
+        serde_json::json!({"to": "user@example.com", "name": "Alice"}),
+    )
+    .with_template("welcome");
+    let response = build_app(state.clone())
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/v1/dispatch?dry_run=true&verbose=true")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(serde_json::to_vec(&action).unwrap()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["verdict"], "reroute");
+    assert_eq!(json["matched_rule"], "reroute-sms");
+    assert_eq!(json["provider"], "sms");
+    assert_eq!(json["providers"], serde_json::json!(["sms"]));
+    assert_eq!(json["payload"]["subject"], "Hello, Alice!");
+
+    // Nothing was executed.
+    let metrics = state.gateway.read().await.metrics().snapshot();
+    assert_eq!(metrics.executed, 0);
+}
@@ -171,6 +171,7 @@ async fn main() {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         })
         .await
@@ -191,6 +192,7 @@ async fn main() {
             to: None,
             group_by: Some("outcome".to_string()),
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         })
         .await
@@ -211,6 +213,7 @@ async fn main() {
             to: None,
             group_by: None,
             top_n: Some(5),
+            drill_into: None,
             tenant_scope: Vec::new(),
         })
         .await
@@ -231,6 +234,7 @@ async fn main() {
             to: None,
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         })
         .await
@@ -251,6 +255,7 @@ async fn main() {
             to: Some(now),
             group_by: None,
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         })
         .await
@@ -271,6 +276,7 @@ async fn main() {
             to: None,
             group_by: Some("provider".to_string()),
             top_n: None,
+            drill_into: None,
             tenant_scope: Vec::new(),
         })
         .await
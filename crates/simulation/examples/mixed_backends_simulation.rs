@@ -1066,6 +1066,7 @@ async fn run_simulation(config: MixedBackendConfig) -> Result<(), Box<dyn std::e
         labels: [("tier".to_string(), "enterprise".to_string())]
             .into_iter()
             .collect(),
+        ..Default::default()
     });
     let outcome = gw_reroute.dispatch(enterprise, None).await?;
     info!("      → Original provider: email");
@@ -501,6 +501,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             300,  // group_interval_seconds
             None, // repeat_interval_seconds — ephemeral
             100,  // max_group_size
+            None,
             &state_store,
             Some(&group_enc),
         )
@@ -515,6 +516,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             300,
             None,
             100,
+            None,
             &state_store,
             Some(&group_enc),
         )
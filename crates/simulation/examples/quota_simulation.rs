@@ -93,6 +93,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             id: "q-tenant-a".into(),
             namespace: "notifications".into(),
             tenant: "tenant-a".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -104,6 +106,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             updated_at: now,
             description: Some("Tenant A: 10 actions/hour, block on exceed".into()),
             labels: Default::default(),
+            alert_thresholds: Vec::new(),
         })
         .build()?;
 
@@ -181,6 +184,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             id: "q-tenant-b".into(),
             namespace: "analytics".into(),
             tenant: "tenant-b".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -192,6 +197,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             updated_at: now,
             description: Some("Tenant B: 100 actions/day, warn on exceed".into()),
             labels: Default::default(),
+            alert_thresholds: Vec::new(),
         })
         .build()?;
 
@@ -268,6 +274,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             id: "q-tenant-c".into(),
             namespace: "messaging".into(),
             tenant: "tenant-c".into(),
+            org: None,
+            members: Vec::new(),
             provider: None,
             principal: None,
             per_principal: false,
@@ -281,6 +289,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             updated_at: now,
             description: Some("Tenant C: 5/hour, degrade to log-fallback".into()),
             labels: Default::default(),
+            alert_thresholds: Vec::new(),
         })
         .build()?;
 
@@ -74,6 +74,7 @@ fn make_recurring(
         description: None,
         labels: HashMap::new(),
         overlap_policy: acteon_core::OverlapPolicy::default(),
+        concurrency_policy: acteon_core::ConcurrencyPolicy::default(),
         last_execution_id: None,
     }
 }
@@ -20,6 +20,7 @@ fn labels(pairs: &[(&str, &str)]) -> ActionMetadata {
             .iter()
             .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
             .collect::<HashMap<_, _>>(),
+        ..Default::default()
     }
 }
 
@@ -64,6 +64,8 @@ fn event_type_label(event_type: &StreamEventType) -> &'static str {
         StreamEventType::TaskTransitioned { .. } => "task_transitioned",
         StreamEventType::TaskHistoryAppended { .. } => "task_history_appended",
         StreamEventType::TaskArtifactUpdated { .. } => "task_artifact_updated",
+        StreamEventType::QuotaThresholdCrossed { .. } => "quota_threshold_crossed",
+        StreamEventType::DlqEntryAdded { .. } => "dlq_entry_added",
         StreamEventType::Unknown => "unknown",
     }
 }
@@ -35,6 +35,7 @@ fn make_template(name: &str, namespace: &str, tenant: &str, content: &str) -> Te
         created_at: Utc::now(),
         updated_at: Utc::now(),
         labels: HashMap::new(),
+        deleted_at: None,
     }
 }
 
@@ -51,10 +52,12 @@ fn make_profile(
         namespace: namespace.to_string(),
         tenant: tenant.to_string(),
         fields,
+        output_type: None,
         description: Some(format!("Profile: {name}")),
         created_at: Utc::now(),
         updated_at: Utc::now(),
         labels: HashMap::new(),
+        deleted_at: None,
     }
 }
 
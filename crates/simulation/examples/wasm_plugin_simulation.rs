@@ -789,7 +789,9 @@ async fn scenario_multiple_plugins() -> Result<(), Box<dyn std::error::Error>> {
             "classify",
             serde_json::json!({ "target": "sms" }),
             RuleAction::Reroute {
-                target_provider: "sms".to_owned(),
+                target_provider: Some("sms".to_owned()),
+                target_namespace: None,
+                target_tenant: None,
             },
         ),
         wasm_rule(
@@ -208,6 +208,18 @@ impl SideEffectAssertions {
         );
     }
 
+    /// Assert that an outcome matches the `PendingApproval` variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the outcome is not `PendingApproval`.
+    pub fn assert_pending_approval(outcome: &ActionOutcome) {
+        assert!(
+            matches!(outcome, ActionOutcome::PendingApproval { .. }),
+            "expected PendingApproval, got {outcome:?}"
+        );
+    }
+
     /// Assert that a state transition occurred to a specific state.
     ///
     /// # Panics
@@ -377,6 +389,12 @@ pub trait ActionOutcomeExt {
 
     /// Check if this outcome is `CircuitOpen`.
     fn is_circuit_open(&self) -> bool;
+
+    /// Assert this outcome is `PendingApproval`.
+    fn assert_pending_approval(&self);
+
+    /// Check if this outcome is `PendingApproval`.
+    fn is_pending_approval(&self) -> bool;
 }
 
 impl ActionOutcomeExt for ActionOutcome {
@@ -470,6 +488,14 @@ impl ActionOutcomeExt for ActionOutcome {
     fn is_circuit_open(&self) -> bool {
         matches!(self, ActionOutcome::CircuitOpen { .. })
     }
+
+    fn assert_pending_approval(&self) {
+        SideEffectAssertions::assert_pending_approval(self);
+    }
+
+    fn is_pending_approval(&self) -> bool {
+        matches!(self, ActionOutcome::PendingApproval { .. })
+    }
 }
 
 #[cfg(test)]
@@ -535,6 +561,7 @@ mod tests {
             original_provider: "email".into(),
             new_provider: "sms".into(),
             response: ProviderResponse::success(serde_json::json!({})),
+            failed_attempts: Vec::new(),
         };
         SideEffectAssertions::assert_rerouted(&outcome);
         outcome.assert_rerouted();
@@ -546,6 +573,7 @@ mod tests {
             original_provider: "email".into(),
             new_provider: "sms".into(),
             response: ProviderResponse::success(serde_json::json!({})),
+            failed_attempts: Vec::new(),
         };
         SideEffectAssertions::assert_rerouted_to(&outcome, "sms");
     }
@@ -563,6 +591,7 @@ mod tests {
     fn assert_failed_passes() {
         let outcome = ActionOutcome::Failed(ActionError {
             code: "TEST".into(),
+            reason_code: acteon_core::ReasonCode::ProviderFailed,
             message: "test error".into(),
             retryable: false,
             attempts: 1,
@@ -743,4 +772,19 @@ mod tests {
         assert!(dry_run.is_dry_run());
         assert!(!dry_run.is_executed());
     }
+
+    #[test]
+    fn assert_pending_approval_passes() {
+        let outcome = ActionOutcome::PendingApproval {
+            approval_id: "appr-1".into(),
+            expires_at: chrono::Utc::now(),
+            approve_url: "http://localhost/approve".into(),
+            reject_url: "http://localhost/reject".into(),
+            notification_sent: true,
+        };
+        SideEffectAssertions::assert_pending_approval(&outcome);
+        outcome.assert_pending_approval();
+        assert!(outcome.is_pending_approval());
+        assert!(!outcome.is_executed());
+    }
 }
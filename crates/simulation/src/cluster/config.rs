@@ -23,6 +23,8 @@ pub struct SimulationConfig {
     pub environment: HashMap<String, String>,
     /// State machine configurations.
     pub state_machines: Vec<StateMachineConfig>,
+    /// Default payload fragments keyed by `(namespace, provider)`.
+    pub provider_defaults: HashMap<(String, String), serde_json::Value>,
 }
 
 impl Default for SimulationConfig {
@@ -36,6 +38,7 @@ impl Default for SimulationConfig {
             providers: Vec::new(),
             environment: HashMap::new(),
             state_machines: Vec::new(),
+            provider_defaults: HashMap::new(),
         }
     }
 }
@@ -58,6 +61,7 @@ pub struct SimulationConfigBuilder {
     providers: Vec<String>,
     environment: HashMap<String, String>,
     state_machines: Vec<StateMachineConfig>,
+    provider_defaults: HashMap<(String, String), serde_json::Value>,
 }
 
 impl SimulationConfigBuilder {
@@ -134,6 +138,19 @@ impl SimulationConfigBuilder {
         self
     }
 
+    /// Add default payload fields for a provider within a namespace.
+    #[must_use]
+    pub fn add_provider_default(
+        mut self,
+        namespace: impl Into<String>,
+        provider: impl Into<String>,
+        defaults: serde_json::Value,
+    ) -> Self {
+        self.provider_defaults
+            .insert((namespace.into(), provider.into()), defaults);
+        self
+    }
+
     /// Build the `SimulationConfig`.
     #[must_use]
     pub fn build(self) -> SimulationConfig {
@@ -146,6 +163,7 @@ impl SimulationConfigBuilder {
             providers: self.providers,
             environment: self.environment,
             state_machines: self.state_machines,
+            provider_defaults: self.provider_defaults,
         }
     }
 }
@@ -44,9 +44,15 @@ impl ServerNode {
         audit: Option<Arc<dyn AuditStore>>,
         environment: std::collections::HashMap<String, String>,
         state_machines: Vec<StateMachineConfig>,
+        provider_defaults: std::collections::HashMap<(String, String), serde_json::Value>,
+        clock: Option<Arc<dyn Fn() -> chrono::DateTime<chrono::Utc> + Send + Sync>>,
     ) -> Result<Self, SimulationError> {
         let mut builder = GatewayBuilder::new().state(state).lock(lock).rules(rules);
 
+        if let Some(clock) = clock {
+            builder = builder.clock(move || clock());
+        }
+
         for provider in providers {
             builder = builder.provider(provider);
         }
@@ -63,14 +69,42 @@ impl ServerNode {
             builder = builder.state_machine(sm);
         }
 
-        let gateway = builder
-            .build()
-            .map_err(|e| SimulationError::Gateway(e.to_string()))?;
+        for ((namespace, provider), defaults) in provider_defaults {
+            builder = builder.provider_payload_defaults(namespace, provider, defaults);
+        }
+
+        // Queue follow-on actions like the server does when a runtime is
+        // available to drain them; otherwise they run inline.
+        let runtime = tokio::runtime::Handle::try_current().ok();
+        let mut follow_on_rx = None;
+        if runtime.is_some() {
+            let (tx, rx) = tokio::sync::mpsc::channel(1024);
+            builder = builder.follow_on_channel(tx);
+            follow_on_rx = Some(rx);
+        }
+
+        let gateway = Arc::new(
+            builder
+                .build()
+                .map_err(|e| SimulationError::Gateway(e.to_string()))?,
+        );
+
+        if let (Some(runtime), Some(mut rx)) = (runtime, follow_on_rx) {
+            let weak = Arc::downgrade(&gateway);
+            runtime.spawn(async move {
+                while let Some(event) = rx.recv().await {
+                    let Some(gateway) = weak.upgrade() else {
+                        return;
+                    };
+                    tokio::spawn(async move { gateway.dispatch_follow_on(event).await });
+                }
+            });
+        }
 
         Ok(Self {
             id: id.into(),
             addr,
-            gateway: Arc::new(gateway),
+            gateway,
             shutdown_tx: None,
             _handle: None,
         })
@@ -148,6 +182,7 @@ pub struct ServerNodeBuilder {
     audit: Option<Arc<dyn AuditStore>>,
     environment: std::collections::HashMap<String, String>,
     state_machines: Vec<StateMachineConfig>,
+    provider_defaults: std::collections::HashMap<(String, String), serde_json::Value>,
 }
 
 impl ServerNodeBuilder {
@@ -221,6 +256,19 @@ impl ServerNodeBuilder {
         self
     }
 
+    /// Add default payload fields for a provider within a namespace.
+    #[must_use]
+    pub fn provider_default(
+        mut self,
+        namespace: impl Into<String>,
+        provider: impl Into<String>,
+        defaults: serde_json::Value,
+    ) -> Self {
+        self.provider_defaults
+            .insert((namespace.into(), provider.into()), defaults);
+        self
+    }
+
     /// Build the `ServerNode`.
     pub fn build(self) -> Result<ServerNode, SimulationError> {
         let id = self.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
@@ -244,6 +292,8 @@ impl ServerNodeBuilder {
             self.audit,
             self.environment,
             self.state_machines,
+            self.provider_defaults,
+            None,
         )
     }
 }
@@ -4,20 +4,22 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use acteon_audit_memory::MemoryAuditStore;
-use acteon_core::{Action, ActionOutcome};
-use acteon_gateway::GatewayError;
+use acteon_core::{Action, ActionOutcome, RecurringAction};
+use acteon_gateway::{GatewayError, OccurrenceOutcome};
 use acteon_provider::DynProvider;
 use acteon_rules::Rule;
 use acteon_rules_yaml::YamlFrontend;
 use acteon_state::DistributedLock;
 use acteon_state::StateStore;
 use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+use chrono::{DateTime, Utc};
 
 use crate::cluster::{
     AuditBackendConfig, PortAllocator, ServerNode, SimulationConfig, StateBackendConfig,
 };
 use crate::error::SimulationError;
 use crate::provider::RecordingProvider;
+use crate::recurring::{FiredOccurrence, RecurringScheduler};
 
 /// Main orchestrator for simulation tests.
 ///
@@ -30,6 +32,7 @@ pub struct SimulationHarness {
     port_allocator: PortAllocator,
     #[allow(dead_code)]
     shared_state: Option<Arc<dyn StateStore>>,
+    scheduler: Arc<parking_lot::Mutex<RecurringScheduler>>,
 }
 
 impl SimulationHarness {
@@ -71,6 +74,10 @@ impl SimulationHarness {
         // Create shared lock
         let shared_lock: Arc<dyn DistributedLock> = Arc::new(MemoryDistributedLock::new());
 
+        // Dedup windows follow the simulated clock, so stepping it across
+        // a window boundary is observable without waiting.
+        let scheduler = Arc::new(parking_lot::Mutex::new(RecurringScheduler::new(Utc::now())));
+
         // Create nodes
         let mut nodes = Vec::with_capacity(config.nodes);
         for i in 0..config.nodes {
@@ -97,6 +104,11 @@ impl SimulationHarness {
                 audit,
                 config.environment.clone(),
                 config.state_machines.clone(),
+                config.provider_defaults.clone(),
+                Some({
+                    let scheduler = Arc::clone(&scheduler);
+                    Arc::new(move || scheduler.lock().now)
+                }),
             )?;
 
             nodes.push(node);
@@ -107,6 +119,7 @@ impl SimulationHarness {
             providers,
             port_allocator,
             shared_state,
+            scheduler,
         })
     }
 
@@ -266,6 +279,90 @@ impl SimulationHarness {
         node.dispatch_batch_dry_run(actions.to_vec()).await
     }
 
+    /// Current time on the simulated clock.
+    ///
+    /// Starts at the wall-clock time the harness was created. Drives the
+    /// recurring scheduler and the gateway's dedup windows.
+    pub fn clock(&self) -> DateTime<Utc> {
+        self.scheduler.lock().now
+    }
+
+    /// Move the simulated clock to `now` without firing any occurrences.
+    pub fn set_clock(&self, now: DateTime<Utc>) {
+        self.scheduler.lock().now = now;
+    }
+
+    /// Register a recurring action with the simulated scheduler.
+    ///
+    /// Occurrences fire only when [`run_until`](Self::run_until) advances
+    /// the clock past them.
+    pub fn add_recurring(&self, recurring: RecurringAction) -> Result<(), SimulationError> {
+        self.scheduler.lock().add(recurring)
+    }
+
+    /// Fast-forward the simulated clock to `until`, dispatching every
+    /// recurring occurrence due on the way through the first node.
+    ///
+    /// Occurrences fire in chronological order under each action's overlap
+    /// policy, exactly as the server's recurring consumer dispatches them.
+    /// Returns the occurrences fired by this call; all fired occurrences
+    /// stay available through [`fired_occurrences`](Self::fired_occurrences).
+    pub async fn run_until(
+        &self,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<FiredOccurrence>, SimulationError> {
+        let node = self
+            .nodes
+            .first()
+            .ok_or_else(|| SimulationError::NodeNotFound("0".into()))?;
+        let due = self.scheduler.lock().due_until(until);
+
+        let mut fired = Vec::with_capacity(due.len());
+        for (index, at) in due {
+            let recurring = self.scheduler.lock().actions[index].clone();
+            let action = RecurringScheduler::occurrence_action(&recurring, at);
+            let result = node
+                .gateway()
+                .dispatch_recurring_occurrence(
+                    &recurring.namespace,
+                    &recurring.tenant,
+                    &recurring.id,
+                    &recurring,
+                    action,
+                    None,
+                )
+                .await;
+
+            let mut scheduler = self.scheduler.lock();
+            scheduler.now = at;
+            let outcome = match result {
+                Ok(OccurrenceOutcome::Dispatched(outcome)) => {
+                    let entry = &mut scheduler.actions[index];
+                    entry.execution_count += 1;
+                    entry.last_executed_at = Some(at);
+                    Some(outcome)
+                }
+                Ok(OccurrenceOutcome::Skipped { .. }) => None,
+                Err(e) => return Err(SimulationError::Dispatch(e.to_string())),
+            };
+            let occurrence = FiredOccurrence {
+                recurring_id: recurring.id.clone(),
+                scheduled_for: at,
+                outcome,
+            };
+            scheduler.fired.push(occurrence.clone());
+            fired.push(occurrence);
+        }
+
+        self.scheduler.lock().now = until;
+        Ok(fired)
+    }
+
+    /// Every occurrence fired by [`run_until`](Self::run_until) so far.
+    pub fn fired_occurrences(&self) -> Vec<FiredOccurrence> {
+        self.scheduler.lock().fired.clone()
+    }
+
     /// Reset all recording providers, clearing captured calls.
     pub fn reset_recordings(&self) {
         for provider in self.providers.values() {
@@ -365,6 +462,20 @@ impl SimulationHarnessBuilder {
         self
     }
 
+    /// Add default payload fields for a provider within a namespace.
+    #[must_use]
+    pub fn add_provider_default(
+        mut self,
+        namespace: impl Into<String>,
+        provider: impl Into<String>,
+        defaults: serde_json::Value,
+    ) -> Self {
+        self.config
+            .provider_defaults
+            .insert((namespace.into(), provider.into()), defaults);
+        self
+    }
+
     /// Build and start the simulation harness.
     pub async fn build(self) -> Result<SimulationHarness, SimulationError> {
         SimulationHarness::start(self.config).await
@@ -103,6 +103,7 @@ pub mod cluster;
 mod error;
 pub mod harness;
 pub mod provider;
+pub mod recurring;
 
 pub use assertions::{ActionOutcomeExt, SideEffectAssertions};
 pub use backend_detector::AvailableBackends;
@@ -113,6 +114,7 @@ pub use cluster::{
 pub use error::SimulationError;
 pub use harness::{SimulationHarness, SimulationHarnessBuilder};
 pub use provider::{CapturedCall, FailingProvider, FailureMode, FailureType, RecordingProvider};
+pub use recurring::FiredOccurrence;
 
 // Re-export acteon-client types for convenience
 pub use acteon_client::{
@@ -136,6 +138,7 @@ pub mod prelude {
     pub use crate::provider::{
         CapturedCall, FailingProvider, FailureMode, FailureType, RecordingProvider,
     };
+    pub use crate::recurring::FiredOccurrence;
     pub use crate::{skip_without_postgres, skip_without_redis};
 }
 
@@ -0,0 +1,224 @@
+//! Time-compressed recurring action scheduling.
+//!
+//! The harness keeps a simulated clock and a set of recurring actions.
+//! [`SimulationHarness::run_until`](crate::SimulationHarness::run_until)
+//! advances that clock and dispatches every occurrence that falls due on the
+//! way, so a test can cover days of schedule in milliseconds. The scheduler
+//! and dedup windows run on simulated time; the rest of the gateway
+//! (quotas, throttles, state-store TTLs) still sees wall-clock time.
+
+use acteon_core::{Action, ActionOutcome, RecurringAction};
+use chrono::{DateTime, Utc};
+
+use crate::error::SimulationError;
+
+/// Upper bound on occurrences fired for one recurring action in a single
+/// `run_until` call, guarding against runaway cron expressions.
+const MAX_OCCURRENCES_PER_RUN: usize = 100_000;
+
+/// A recurring occurrence fired by the simulated scheduler.
+#[derive(Debug, Clone)]
+pub struct FiredOccurrence {
+    /// ID of the recurring action.
+    pub recurring_id: String,
+    /// The simulated time the occurrence was due.
+    pub scheduled_for: DateTime<Utc>,
+    /// The dispatch outcome, or `None` when the overlap policy skipped the
+    /// occurrence.
+    pub outcome: Option<ActionOutcome>,
+}
+
+/// Simulated clock plus the recurring actions it drives.
+pub(crate) struct RecurringScheduler {
+    pub(crate) now: DateTime<Utc>,
+    pub(crate) actions: Vec<RecurringAction>,
+    pub(crate) fired: Vec<FiredOccurrence>,
+}
+
+impl RecurringScheduler {
+    pub(crate) fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now,
+            actions: Vec::new(),
+            fired: Vec::new(),
+        }
+    }
+
+    /// Validate and register a recurring action.
+    pub(crate) fn add(&mut self, recurring: RecurringAction) -> Result<(), SimulationError> {
+        acteon_core::validate_cron_expr(&recurring.cron_expr)
+            .map_err(|e| SimulationError::Configuration(e.to_string()))?;
+        acteon_core::validate_timezone(&recurring.timezone)
+            .map_err(|e| SimulationError::Configuration(e.to_string()))?;
+        self.actions.push(recurring);
+        Ok(())
+    }
+
+    /// Every occurrence due in `(now, until]`, as `(action index, time)`
+    /// pairs in chronological order.
+    ///
+    /// Disabled actions, occurrences at or after `ends_at`, and occurrences
+    /// beyond `max_executions` are left out.
+    pub(crate) fn due_until(&self, until: DateTime<Utc>) -> Vec<(usize, DateTime<Utc>)> {
+        let mut due = Vec::new();
+        for (index, recurring) in self.actions.iter().enumerate() {
+            if !recurring.enabled {
+                continue;
+            }
+            let (Ok(cron), Ok(tz)) = (
+                acteon_core::validate_cron_expr(&recurring.cron_expr),
+                acteon_core::validate_timezone(&recurring.timezone),
+            ) else {
+                continue;
+            };
+            let remaining = recurring
+                .max_executions
+                .map_or(MAX_OCCURRENCES_PER_RUN, |max| {
+                    usize::try_from(max.saturating_sub(recurring.execution_count))
+                        .unwrap_or(MAX_OCCURRENCES_PER_RUN)
+                        .min(MAX_OCCURRENCES_PER_RUN)
+                });
+
+            let mut cursor = self.now;
+            let mut count = 0;
+            while count < remaining {
+                match acteon_core::next_occurrence(&cron, tz, &cursor) {
+                    Some(next)
+                        if next <= until && recurring.ends_at.is_none_or(|ends| next < ends) =>
+                    {
+                        due.push((index, next));
+                        cursor = next;
+                        count += 1;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        due.sort_by_key(|&(index, at)| (at, index));
+        due
+    }
+
+    /// Build the action dispatched for one occurrence, mirroring the
+    /// server's recurring consumer.
+    pub(crate) fn occurrence_action(recurring: &RecurringAction, at: DateTime<Utc>) -> Action {
+        let template = &recurring.action_template;
+        let mut payload = recurring.render_payload(&at);
+        if let Some(obj) = payload.as_object_mut() {
+            obj.insert(
+                "_recurring_dispatch".to_string(),
+                serde_json::Value::Bool(true),
+            );
+        }
+        let mut action = Action::new(
+            recurring.namespace.as_str(),
+            recurring.tenant.as_str(),
+            template.provider.as_str(),
+            template.action_type.as_str(),
+            payload,
+        );
+        action.metadata.labels.clone_from(&template.metadata);
+        action.dedup_key = template.resolve_dedup_key(&recurring.id, &at);
+        action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use acteon_core::{ConcurrencyPolicy, OverlapPolicy, RecurringActionTemplate};
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn recurring(cron_expr: &str) -> RecurringAction {
+        let created = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        RecurringAction {
+            id: "rec-1".into(),
+            namespace: "ns".into(),
+            tenant: "t".into(),
+            cron_expr: cron_expr.into(),
+            timezone: "UTC".into(),
+            enabled: true,
+            action_template: RecurringActionTemplate {
+                provider: "email".into(),
+                action_type: "digest".into(),
+                payload: serde_json::json!({}),
+                metadata: HashMap::new(),
+                dedup_key: Some("{{recurring_id}}:{{execution_time}}".into()),
+            },
+            created_at: created,
+            updated_at: created,
+            last_executed_at: None,
+            next_execution_at: None,
+            ends_at: None,
+            max_executions: None,
+            execution_count: 0,
+            description: None,
+            labels: HashMap::new(),
+            overlap_policy: OverlapPolicy::default(),
+            concurrency_policy: ConcurrencyPolicy::default(),
+            last_execution_id: None,
+        }
+    }
+
+    #[test]
+    fn due_until_is_exclusive_of_now_and_inclusive_of_until() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let mut scheduler = RecurringScheduler::new(start);
+        scheduler.add(recurring("0 * * * *")).unwrap();
+
+        let due = scheduler.due_until(start + chrono::Duration::hours(3));
+        let times: Vec<_> = due.iter().map(|&(_, at)| at).collect();
+        assert_eq!(
+            times,
+            vec![
+                start + chrono::Duration::hours(1),
+                start + chrono::Duration::hours(2),
+                start + chrono::Duration::hours(3),
+            ]
+        );
+    }
+
+    #[test]
+    fn due_until_respects_max_executions_and_ends_at() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let mut scheduler = RecurringScheduler::new(start);
+
+        let mut capped = recurring("0 * * * *");
+        capped.max_executions = Some(5);
+        capped.execution_count = 3;
+        scheduler.add(capped).unwrap();
+
+        let mut ending = recurring("0 * * * *");
+        ending.ends_at = Some(start + chrono::Duration::hours(4));
+        scheduler.add(ending).unwrap();
+
+        let mut disabled = recurring("0 * * * *");
+        disabled.enabled = false;
+        scheduler.add(disabled).unwrap();
+
+        let due = scheduler.due_until(start + chrono::Duration::hours(10));
+        assert_eq!(due.iter().filter(|&&(i, _)| i == 0).count(), 2);
+        assert_eq!(due.iter().filter(|&&(i, _)| i == 1).count(), 3);
+        assert!(due.iter().all(|&(i, _)| i != 2));
+    }
+
+    #[test]
+    fn add_rejects_invalid_cron() {
+        let mut scheduler = RecurringScheduler::new(Utc::now());
+        assert!(scheduler.add(recurring("not a cron")).is_err());
+    }
+
+    #[test]
+    fn occurrence_action_resolves_dedup_key_per_occurrence() {
+        let at = Utc.with_ymd_and_hms(2026, 1, 1, 5, 0, 0).unwrap();
+        let action = RecurringScheduler::occurrence_action(&recurring("0 * * * *"), at);
+        assert_eq!(action.provider.as_str(), "email");
+        assert_eq!(action.payload["_recurring_dispatch"], true);
+        assert_eq!(
+            action.dedup_key.as_deref(),
+            Some("rec-1:2026-01-01T05:00:00+00:00")
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! End-to-end approval tests over HTTP.
+//!
+//! These tests run the REST API in-process and drive approvals through the
+//! Rust client, covering URLs signed outside the server.
+
+use std::sync::Arc;
+
+use acteon_client::{ActeonClient, sign_approval};
+use acteon_core::{Action, ActionOutcome};
+use acteon_gateway::GatewayBuilder;
+use acteon_provider::DynProvider;
+use acteon_rules::RuleFrontend;
+use acteon_rules_yaml::YamlFrontend;
+use acteon_simulation::prelude::*;
+use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+use tokio::net::TcpListener;
+use tokio::sync::RwLock;
+
+const APPROVAL_SECRET: &[u8] = b"simulation-approval-secret";
+
+const APPROVAL_RULE: &str = r#"
+rules:
+  - name: approve-large-refunds
+    priority: 1
+    condition:
+      all:
+        - field: action.action_type
+          eq: "process_refund"
+        - field: action.payload.amount
+          gt: 1000
+    action:
+      type: request_approval
+      notify_provider: slack
+      timeout_seconds: 3600
+"#;
+
+/// Serve the REST API for `gateway` on a random local port and return its
+/// base URL.
+async fn serve(gateway: acteon_gateway::Gateway) -> String {
+    let gateway = Arc::new(RwLock::new(gateway));
+    let metrics = gateway.read().await.metrics_arc();
+    let app_state = acteon_server::api::AppState {
+        gateway,
+        metrics,
+        audit: None,
+        analytics: None,
+        auth: None,
+        rate_limiter: None,
+        embedding: None,
+        embedding_metrics: None,
+        connection_registry: None,
+        a2a_discovery_cache: Arc::new(
+            acteon_server::api::a2a_discovery_cache::DiscoveryCache::new(),
+        ),
+        dispatch_semaphore: Arc::new(tokio::sync::Semaphore::new(100)),
+        config: acteon_server::config::ConfigSnapshot::default(),
+        static_quotas: None,
+        static_templates: None,
+        ui_path: None,
+        ui_enabled: false,
+        cors_allowed_origins: Vec::new(),
+        signature_verifier: None,
+        replay_protection: None,
+        #[cfg(feature = "swarm")]
+        swarm_registry: None,
+        #[cfg(feature = "bus")]
+        bus_backend: None,
+        #[cfg(feature = "bus")]
+        bus_schema_validator: acteon_bus::SchemaValidator::new(),
+    };
+    let app = acteon_server::api::router(app_state);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn locally_signed_approval_is_accepted() {
+    let payments = Arc::new(RecordingProvider::new("payments"));
+    let slack = Arc::new(RecordingProvider::new("slack"));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::clone(&payments) as Arc<dyn DynProvider>)
+        .provider(Arc::clone(&slack) as Arc<dyn DynProvider>)
+        .rules(YamlFrontend.parse(APPROVAL_RULE).unwrap())
+        .approval_secret(APPROVAL_SECRET)
+        .build()
+        .expect("gateway should build");
+    let client = ActeonClient::new(serve(gateway).await);
+
+    let refund = Action::new(
+        "billing",
+        "tenant-1",
+        "payments",
+        "process_refund",
+        serde_json::json!({"amount": 5000}),
+    );
+    let outcome = client.dispatch(&refund).await.expect("dispatch");
+    let ActionOutcome::PendingApproval {
+        approval_id,
+        expires_at,
+        ..
+    } = outcome
+    else {
+        panic!("expected PendingApproval, got {outcome:?}");
+    };
+    payments.assert_not_called();
+
+    // A signature from the wrong secret is refused.
+    let forged = sign_approval(
+        "billing",
+        "tenant-1",
+        &approval_id,
+        expires_at.timestamp(),
+        b"wrong-secret",
+    );
+    assert!(
+        client
+            .approve(
+                "billing",
+                "tenant-1",
+                &approval_id,
+                &forged,
+                expires_at.timestamp()
+            )
+            .await
+            .is_err()
+    );
+
+    let sig = sign_approval(
+        "billing",
+        "tenant-1",
+        &approval_id,
+        expires_at.timestamp(),
+        APPROVAL_SECRET,
+    );
+    let result = client
+        .approve(
+            "billing",
+            "tenant-1",
+            &approval_id,
+            &sig,
+            expires_at.timestamp(),
+        )
+        .await
+        .expect("locally signed approval should be accepted");
+
+    assert_eq!(result.status, "approved");
+    payments.assert_called(1);
+}
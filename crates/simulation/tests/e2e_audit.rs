@@ -0,0 +1,131 @@
+//! End-to-end audit tests over HTTP.
+//!
+//! These tests run the REST API in-process with an audit store and read the
+//! trail back through the Rust client.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use acteon_audit::store::AuditStore;
+use acteon_audit_memory::MemoryAuditStore;
+use acteon_client::{ActeonClient, Action};
+use acteon_gateway::GatewayBuilder;
+use acteon_provider::DynProvider;
+use acteon_simulation::prelude::*;
+use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+use chrono::{TimeZone as _, Utc};
+use tokio::net::TcpListener;
+use tokio::sync::RwLock;
+
+/// Serve the REST API for `gateway` with `audit` on a random local port and
+/// return its base URL.
+async fn serve(gateway: acteon_gateway::Gateway, audit: Arc<dyn AuditStore>) -> String {
+    let gateway = Arc::new(RwLock::new(gateway));
+    let metrics = gateway.read().await.metrics_arc();
+    let app_state = acteon_server::api::AppState {
+        gateway,
+        metrics,
+        audit: Some(audit),
+        analytics: None,
+        auth: None,
+        rate_limiter: None,
+        embedding: None,
+        embedding_metrics: None,
+        connection_registry: None,
+        a2a_discovery_cache: Arc::new(
+            acteon_server::api::a2a_discovery_cache::DiscoveryCache::new(),
+        ),
+        dispatch_semaphore: Arc::new(tokio::sync::Semaphore::new(100)),
+        config: acteon_server::config::ConfigSnapshot::default(),
+        static_quotas: None,
+        static_templates: None,
+        ui_path: None,
+        ui_enabled: false,
+        cors_allowed_origins: Vec::new(),
+        signature_verifier: None,
+        replay_protection: None,
+        #[cfg(feature = "swarm")]
+        swarm_registry: None,
+        #[cfg(feature = "bus")]
+        bus_backend: None,
+        #[cfg(feature = "bus")]
+        bus_schema_validator: acteon_bus::SchemaValidator::new(),
+    };
+    let app = acteon_server::api::router(app_state);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn action_rebuilt_from_audit_matches_dispatch() {
+    let email = Arc::new(RecordingProvider::new("email"));
+    let audit: Arc<dyn AuditStore> = Arc::new(MemoryAuditStore::new());
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::clone(&email) as Arc<dyn DynProvider>)
+        .audit(Arc::clone(&audit))
+        .audit_store_payload(true)
+        .build()
+        .expect("gateway should build");
+    let client = ActeonClient::new(serve(gateway, audit).await);
+
+    let mut original = Action::new(
+        "notifications",
+        "tenant-1",
+        "email",
+        "send_email",
+        serde_json::json!({"to": "ops@example.com", "retries": 2}),
+    )
+    .with_dedup_key("digest-2026-10-16")
+    .with_fingerprint("fp-42")
+    .with_status("firing")
+    .with_starts_at(Utc.with_ymd_and_hms(2026, 10, 16, 9, 0, 0).unwrap())
+    .with_ends_at(Utc.with_ymd_and_hms(2026, 10, 16, 10, 0, 0).unwrap())
+    .with_traceparent(
+        "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+        None,
+    );
+    original
+        .metadata
+        .labels
+        .insert("team".into(), "platform".into());
+    client.dispatch(&original).await.expect("dispatch");
+
+    let action_id = original.id.to_string();
+    let mut record = None;
+    for _ in 0..50 {
+        record = client.get_audit_record(&action_id).await.expect("audit");
+        if record.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    let record = record.expect("audit record should be written");
+    let payload = client
+        .get_audit_payload(&action_id)
+        .await
+        .expect("audit payload")
+        .expect("payload should be stored");
+
+    let rebuilt = Action::from_audit(&record, payload);
+
+    assert_ne!(rebuilt.id, original.id, "rebuilt action gets a fresh ID");
+    assert_eq!(rebuilt.namespace, original.namespace);
+    assert_eq!(rebuilt.tenant, original.tenant);
+    assert_eq!(rebuilt.provider, original.provider);
+    assert_eq!(rebuilt.action_type, original.action_type);
+    assert_eq!(rebuilt.payload, original.payload);
+    assert_eq!(rebuilt.metadata.labels, original.metadata.labels);
+    assert_eq!(rebuilt.traceparent(), original.traceparent());
+    assert_eq!(rebuilt.dedup_key, original.dedup_key);
+    assert_eq!(rebuilt.fingerprint, original.fingerprint);
+    assert_eq!(rebuilt.status, original.status);
+    assert_eq!(rebuilt.starts_at, original.starts_at);
+    assert_eq!(rebuilt.ends_at, original.ends_at);
+}
@@ -0,0 +1,264 @@
+//! End-to-end chain scenario tests.
+//!
+//! These tests drive task chains step by step through a gateway built
+//! with in-memory backends and simulation providers.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use acteon_core::chain::{
+    ChainConfig, ChainState, ChainStatus, ChainStepConfig, ParallelFailurePolicy,
+    ParallelJoinPolicy, ParallelStepGroup, StepFailurePolicy,
+};
+use acteon_core::{Action, ActionOutcome, ChainLimitExceeded, ChainLimits};
+use acteon_gateway::{Gateway, GatewayBuilder};
+use acteon_provider::DynProvider;
+use acteon_rules::RuleFrontend;
+use acteon_rules_yaml::YamlFrontend;
+use acteon_simulation::prelude::*;
+use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+
+const CHAIN_RULE: &str = r#"
+rules:
+  - name: start-pipeline
+    priority: 1
+    condition:
+      field: action.action_type
+      eq: "start"
+    action:
+      type: chain
+      chain: pipeline
+"#;
+
+/// A two-step chain whose first step calls a provider that hangs for far
+/// longer than the step's one-second timeout.
+fn build_gateway(
+    on_failure: StepFailurePolicy,
+) -> (Gateway, Arc<RecordingProvider>, Arc<RecordingProvider>) {
+    let slow = Arc::new(RecordingProvider::new("slow").with_delay(Duration::from_secs(30)));
+    let next = Arc::new(RecordingProvider::new("next"));
+    let chain = ChainConfig::new("pipeline")
+        .with_step(
+            ChainStepConfig::new("enrich", "slow", "enrich", serde_json::json!({}))
+                .with_timeout(1)
+                .with_on_failure(on_failure),
+        )
+        .with_step(ChainStepConfig::new(
+            "notify",
+            "next",
+            "notify",
+            serde_json::json!({}),
+        ));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .rules(YamlFrontend.parse(CHAIN_RULE).expect("rule should parse"))
+        .provider(Arc::clone(&slow) as Arc<dyn DynProvider>)
+        .provider(Arc::clone(&next) as Arc<dyn DynProvider>)
+        .chain(chain)
+        .completed_chain_ttl(Duration::from_secs(3600))
+        .build()
+        .expect("gateway should build");
+    (gateway, slow, next)
+}
+
+async fn start_chain(gateway: &Gateway) -> String {
+    let action = Action::new("ns", "tenant-1", "slow", "start", serde_json::json!({}));
+    match gateway.dispatch(action, None).await.expect("dispatch") {
+        ActionOutcome::ChainStarted { chain_id, .. } => chain_id,
+        other => panic!("expected ChainStarted, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn step_timeout_aborts_chain_by_default() {
+    let (gateway, slow, next) = build_gateway(StepFailurePolicy::Abort);
+    let chain_id = start_chain(&gateway).await;
+
+    let started = std::time::Instant::now();
+    gateway
+        .advance_chain("ns", "tenant-1", &chain_id)
+        .await
+        .expect("advance");
+    assert!(
+        started.elapsed() < Duration::from_secs(10),
+        "the hung provider must not block the chain"
+    );
+
+    let state = gateway
+        .get_chain_status("ns", "tenant-1", &chain_id)
+        .await
+        .unwrap()
+        .expect("chain should exist");
+    assert_eq!(state.status, ChainStatus::Failed);
+
+    let result = state.step_results[0].as_ref().expect("step 0 result");
+    assert!(!result.success);
+    assert!(
+        result
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("timed out after 1s")
+    );
+    assert!(result.started_at.is_some());
+
+    // The abandoned attempt is kept in the step history with its duration.
+    let attempt = &state.step_history[0][0];
+    assert!(!attempt.success);
+    assert!(attempt.duration_ms >= 1000);
+
+    slow.assert_called(1);
+    next.assert_not_called();
+    gateway.shutdown().await;
+}
+
+#[tokio::test]
+async fn step_timeout_with_skip_policy_continues_chain() {
+    let (gateway, _slow, next) = build_gateway(StepFailurePolicy::Skip);
+    let chain_id = start_chain(&gateway).await;
+
+    gateway
+        .advance_chain("ns", "tenant-1", &chain_id)
+        .await
+        .expect("advance timed-out step");
+    gateway
+        .advance_chain("ns", "tenant-1", &chain_id)
+        .await
+        .expect("advance next step");
+
+    let state = gateway
+        .get_chain_status("ns", "tenant-1", &chain_id)
+        .await
+        .unwrap()
+        .expect("chain should exist");
+    assert_eq!(state.status, ChainStatus::Completed);
+    assert!(!state.step_results[0].as_ref().unwrap().success);
+    assert!(state.step_results[1].as_ref().unwrap().success);
+    next.assert_called(1);
+    gateway.shutdown().await;
+}
+
+/// Advance `chain_id`, and every sub-chain it waits on, until it finishes.
+async fn run_chain(gateway: &Gateway, chain_id: &str) -> ChainState {
+    let mut stack = vec![chain_id.to_owned()];
+    for _ in 0..100 {
+        let Some(id) = stack.last().cloned() else {
+            break;
+        };
+        gateway
+            .advance_chain("ns", "tenant-1", &id)
+            .await
+            .expect("advance");
+        let state = gateway
+            .get_chain_status("ns", "tenant-1", &id)
+            .await
+            .unwrap()
+            .expect("chain should exist");
+        if state.status == ChainStatus::WaitingSubChain {
+            stack.push(state.child_chain_ids.last().unwrap().clone());
+        } else if !state.status.is_active() {
+            stack.pop();
+        }
+    }
+    gateway
+        .get_chain_status("ns", "tenant-1", chain_id)
+        .await
+        .unwrap()
+        .expect("chain should exist")
+}
+
+fn limited_gateway(
+    chains: Vec<ChainConfig>,
+    limits: ChainLimits,
+) -> (Gateway, Arc<RecordingProvider>) {
+    let slow = Arc::new(RecordingProvider::new("slow"));
+    let mut builder = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .rules(YamlFrontend.parse(CHAIN_RULE).expect("rule should parse"))
+        .provider(Arc::clone(&slow) as Arc<dyn DynProvider>)
+        .chain_limits(limits);
+    for chain in chains {
+        builder = builder.chain(chain);
+    }
+    (builder.build().expect("gateway should build"), slow)
+}
+
+#[tokio::test]
+async fn deeply_nested_sub_chains_hit_depth_limit() {
+    // pipeline -> level-1 -> ... -> level-5, whose only step calls a provider.
+    let mut chains = vec![
+        ChainConfig::new("pipeline").with_step(ChainStepConfig::new_sub_chain("down", "level-1")),
+    ];
+    for level in 1..5 {
+        chains.push(
+            ChainConfig::new(format!("level-{level}")).with_step(
+                ChainStepConfig::new_sub_chain("down", format!("level-{}", level + 1))
+                    .with_on_failure(StepFailurePolicy::Skip),
+            ),
+        );
+    }
+    chains.push(ChainConfig::new("level-5").with_step(ChainStepConfig::new(
+        "leaf",
+        "slow",
+        "leaf",
+        serde_json::json!({}),
+    )));
+    let (gateway, slow) = limited_gateway(
+        chains,
+        ChainLimits {
+            max_depth: 2,
+            ..ChainLimits::default()
+        },
+    );
+
+    let chain_id = start_chain(&gateway).await;
+    let root = run_chain(&gateway, &chain_id).await;
+
+    // The breach at depth 2 aborts every ancestor, even through the
+    // `skip` policy on the intermediate sub-chain steps.
+    assert_eq!(root.status, ChainStatus::Failed);
+    assert_eq!(
+        root.limit_exceeded,
+        Some(ChainLimitExceeded::Depth { max: 2 })
+    );
+    slow.assert_not_called();
+    gateway.shutdown().await;
+}
+
+#[tokio::test]
+async fn wide_parallel_group_hits_fanout_limit() {
+    let group = ParallelStepGroup {
+        steps: (0..50)
+            .map(|i| {
+                ChainStepConfig::new(format!("shard-{i}"), "slow", "shard", serde_json::json!({}))
+            })
+            .collect(),
+        join: ParallelJoinPolicy::All,
+        on_failure: ParallelFailurePolicy::FailFast,
+        timeout_seconds: None,
+        max_concurrency: None,
+    };
+    let (gateway, slow) = limited_gateway(
+        vec![ChainConfig::new("pipeline").with_step(ChainStepConfig::new_parallel("fan", group))],
+        ChainLimits {
+            max_parallel_fanout: 10,
+            ..ChainLimits::default()
+        },
+    );
+
+    let chain_id = start_chain(&gateway).await;
+    let state = run_chain(&gateway, &chain_id).await;
+
+    assert_eq!(state.status, ChainStatus::Failed);
+    assert_eq!(
+        state.limit_exceeded,
+        Some(ChainLimitExceeded::Fanout {
+            max: 10,
+            requested: 50
+        })
+    );
+    slow.assert_not_called();
+    gateway.shutdown().await;
+}
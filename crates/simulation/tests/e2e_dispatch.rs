@@ -2,8 +2,18 @@
 //!
 //! These tests verify basic action dispatch through running server nodes.
 
-use acteon_core::Action;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use acteon_core::{
+    Action, ActionOutcome, OverageBehavior, QuotaPolicy, QuotaWindow, ReasonCode, StreamEventType,
+};
+use acteon_executor::{ExecutorConfig, RetryBudgetConfig, RetryStrategy};
+use acteon_gateway::{CircuitBreakerConfig, CircuitState, GatewayBuilder};
+use acteon_provider::{DynProvider, ProviderError};
 use acteon_simulation::prelude::*;
+use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
 
 fn test_action(provider: &str) -> Action {
     Action::new(
@@ -96,6 +106,383 @@ async fn provider_execution_verification() {
     harness.teardown().await.expect("teardown should succeed");
 }
 
+#[tokio::test]
+async fn provider_default_footer_applies_unless_overridden() {
+    let harness = SimulationHarness::start(
+        SimulationConfig::builder()
+            .nodes(1)
+            .add_recording_provider("slack")
+            .add_recording_provider("email")
+            .add_provider_default(
+                "ns",
+                "slack",
+                serde_json::json!({"footer": "Sent via Acteon"}),
+            )
+            .build(),
+    )
+    .await
+    .expect("harness should start");
+
+    let plain = Action::new(
+        "ns",
+        "tenant",
+        "slack",
+        "post",
+        serde_json::json!({"text": "deploy finished"}),
+    );
+    harness
+        .dispatch(&plain)
+        .await
+        .expect("dispatch should succeed");
+
+    let slack = harness.provider("slack").unwrap();
+    let dispatched = slack.last_action().expect("should have recorded action");
+    assert_eq!(dispatched.payload["text"], "deploy finished");
+    assert_eq!(dispatched.payload["footer"], "Sent via Acteon");
+
+    let overridden = Action::new(
+        "ns",
+        "tenant",
+        "slack",
+        "post",
+        serde_json::json!({"text": "rollback", "footer": "On-call bot"}),
+    );
+    harness
+        .dispatch(&overridden)
+        .await
+        .expect("dispatch should succeed");
+
+    let dispatched = slack.last_action().expect("should have recorded action");
+    assert_eq!(dispatched.payload["footer"], "On-call bot");
+    slack.assert_called(2);
+
+    // Defaults are scoped to their provider.
+    let email_action = Action::new("ns", "tenant", "email", "send", serde_json::json!({}));
+    harness
+        .dispatch(&email_action)
+        .await
+        .expect("email dispatch");
+    let email = harness.provider("email").unwrap();
+    let dispatched = email.last_action().expect("should have recorded action");
+    assert!(dispatched.payload.get("footer").is_none());
+
+    harness.teardown().await.expect("teardown should succeed");
+}
+
+#[tokio::test]
+async fn failed_primary_fails_over_to_next_provider() {
+    let sms_a = Arc::new(RecordingProvider::new("sms-a").with_failure_mode(FailureMode::Always));
+    let sms_b = Arc::new(RecordingProvider::new("sms-b"));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::clone(&sms_a) as Arc<dyn DynProvider>)
+        .provider(Arc::clone(&sms_b) as Arc<dyn DynProvider>)
+        .provider_failover("sms-a", ["sms-b"])
+        .build()
+        .expect("gateway should build");
+
+    let action = Action::new(
+        "ns",
+        "tenant",
+        "sms-a",
+        "send_sms",
+        serde_json::json!({"to": "+15550100", "body": "code 1234"}),
+    );
+    let outcome = gateway.dispatch(action, None).await.expect("dispatch");
+
+    match outcome {
+        ActionOutcome::Rerouted {
+            original_provider,
+            new_provider,
+            failed_attempts,
+            ..
+        } => {
+            assert_eq!(original_provider, "sms-a");
+            assert_eq!(new_provider, "sms-b");
+            assert_eq!(failed_attempts.len(), 1);
+            assert_eq!(failed_attempts[0].provider, "sms-a");
+            assert_eq!(
+                failed_attempts[0].error.reason_code,
+                ReasonCode::ProviderUnavailable
+            );
+        }
+        other => panic!("expected delivery via fallback, got {other:?}"),
+    }
+    sms_a.assert_called_at_least(1);
+    sms_b.assert_called(1);
+    assert_eq!(sms_b.last_action().unwrap().payload["body"], "code 1234");
+
+    let stats = gateway.provider_metrics().snapshot();
+    assert!(stats["sms-a"].failures >= 1);
+    assert_eq!(stats["sms-b"].successes, 1);
+}
+
+#[tokio::test]
+async fn rejected_action_does_not_fail_over() {
+    let sms_a = Arc::new(
+        RecordingProvider::new("sms-a")
+            .with_response_fn(|_| Err(ProviderError::Configuration("missing sender id".into()))),
+    );
+    let sms_b = Arc::new(RecordingProvider::new("sms-b"));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::clone(&sms_a) as Arc<dyn DynProvider>)
+        .provider(Arc::clone(&sms_b) as Arc<dyn DynProvider>)
+        .provider_failover("sms-a", ["sms-b"])
+        .build()
+        .expect("gateway should build");
+
+    let action = Action::new("ns", "tenant", "sms-a", "send_sms", serde_json::json!({}));
+    match gateway.dispatch(action, None).await.expect("dispatch") {
+        ActionOutcome::Failed(err) => {
+            assert_eq!(err.reason_code, ReasonCode::ProviderMisconfigured);
+        }
+        other => panic!("expected failure, got {other:?}"),
+    }
+    sms_b.assert_not_called();
+}
+
+#[tokio::test]
+async fn failover_reports_every_attempt_when_all_providers_fail() {
+    let sms_a = Arc::new(RecordingProvider::new("sms-a").with_failure_mode(FailureMode::Always));
+    let sms_b = Arc::new(RecordingProvider::new("sms-b").with_failure_mode(FailureMode::Always));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::clone(&sms_a) as Arc<dyn DynProvider>)
+        .provider(Arc::clone(&sms_b) as Arc<dyn DynProvider>)
+        .provider_failover("sms-a", ["sms-b"])
+        .build()
+        .expect("gateway should build");
+
+    let action = Action::new("ns", "tenant", "sms-a", "send_sms", serde_json::json!({}));
+    match gateway.dispatch(action, None).await.expect("dispatch") {
+        ActionOutcome::Failed(err) => {
+            assert!(err.message.contains("sms-a:"), "{}", err.message);
+            assert!(err.message.contains("sms-b:"), "{}", err.message);
+            assert_eq!(
+                err.attempts as usize,
+                sms_a.call_count() + sms_b.call_count()
+            );
+        }
+        other => panic!("expected failure, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn retry_budget_stops_retries_before_breaker_opens() {
+    let flaky = Arc::new(RecordingProvider::new("sms").with_failure_mode(FailureMode::Always));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::clone(&flaky) as Arc<dyn DynProvider>)
+        .executor_config(ExecutorConfig {
+            max_retries: 3,
+            retry_strategy: RetryStrategy::Constant {
+                delay: Duration::from_millis(1),
+            },
+            ..ExecutorConfig::default()
+        })
+        .retry_budget(RetryBudgetConfig {
+            max_retries: 4,
+            window: Duration::from_secs(3600),
+        })
+        .circuit_breaker(CircuitBreakerConfig {
+            failure_threshold: 10,
+            ..CircuitBreakerConfig::default()
+        })
+        .build()
+        .expect("gateway should build");
+
+    let mut attempts = Vec::new();
+    for _ in 0..3 {
+        let action = Action::new("ns", "tenant", "sms", "send_sms", serde_json::json!({}));
+        match gateway.dispatch(action, None).await.expect("dispatch") {
+            ActionOutcome::Failed(err) => attempts.push((err.reason_code, err.attempts)),
+            other => panic!("expected failure, got {other:?}"),
+        }
+    }
+
+    // The first action spends 3 of the 4 retries; the second gets one more
+    // retry before the budget runs dry; the third is never retried.
+    assert_eq!(
+        attempts,
+        [
+            (ReasonCode::ProviderUnavailable, 4),
+            (ReasonCode::RetryBudgetExhausted, 2),
+            (ReasonCode::RetryBudgetExhausted, 1),
+        ]
+    );
+    flaky.assert_called(7);
+
+    let breaker = gateway.circuit_breakers().unwrap().get("sms").unwrap();
+    assert_eq!(breaker.state().await, CircuitState::Closed);
+}
+
+#[tokio::test]
+async fn quota_alert_thresholds_fire_once_each() {
+    let email = Arc::new(RecordingProvider::new("email"));
+    let now = chrono::Utc::now();
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::clone(&email) as Arc<dyn DynProvider>)
+        .quota_policy(QuotaPolicy {
+            id: "q-alerts".into(),
+            namespace: "ns".into(),
+            tenant: "tenant".into(),
+            org: None,
+            members: Vec::new(),
+            provider: None,
+            principal: None,
+            per_principal: false,
+            max_actions: 10,
+            window: QuotaWindow::Daily,
+            overage_behavior: OverageBehavior::Block,
+            enabled: true,
+            created_at: now,
+            updated_at: now,
+            description: None,
+            labels: HashMap::new(),
+            alert_thresholds: vec![80, 100],
+        })
+        .build()
+        .expect("gateway should build");
+    let mut events = gateway.stream_tx().subscribe();
+
+    // Ten actions reach the limit; the next three are blocked.
+    for i in 0..13 {
+        let action = Action::new("ns", "tenant", "email", "send", serde_json::json!({}));
+        let outcome = gateway.dispatch(action, None).await.expect("dispatch");
+        assert_eq!(
+            matches!(outcome, ActionOutcome::QuotaExceeded { .. }),
+            i >= 10,
+            "action {i}: {outcome:?}"
+        );
+    }
+    email.assert_called(10);
+
+    let mut crossings = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        if let StreamEventType::QuotaThresholdCrossed {
+            policy_id,
+            threshold_percent,
+            used,
+            limit,
+            ..
+        } = event.event_type
+        {
+            assert_eq!(policy_id, "q-alerts");
+            assert_eq!(limit, 10);
+            crossings.push((threshold_percent, used));
+        }
+    }
+    assert_eq!(crossings, [(80, 8), (100, 10)]);
+}
+
+#[tokio::test]
+async fn dead_lettered_action_emits_stream_event() {
+    let webhook = Arc::new(FailingProvider::connection_error(
+        "webhook",
+        "connection refused",
+    ));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::clone(&webhook) as Arc<dyn DynProvider>)
+        .executor_config(ExecutorConfig {
+            max_retries: 2,
+            retry_strategy: RetryStrategy::Constant {
+                delay: Duration::from_millis(1),
+            },
+            ..ExecutorConfig::default()
+        })
+        .dlq_enabled(true)
+        .build()
+        .expect("gateway should build");
+    let mut events = gateway.stream_tx().subscribe();
+
+    let action = test_action("webhook");
+    let action_id = action.id.to_string();
+    let outcome = gateway.dispatch(action, None).await.expect("dispatch");
+    assert!(matches!(outcome, ActionOutcome::Failed(_)), "{outcome:?}");
+    assert_eq!(gateway.dlq_len().await, Some(1));
+
+    let mut dead_lettered = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        if let StreamEventType::DlqEntryAdded {
+            provider,
+            error,
+            attempts,
+        } = event.event_type
+        {
+            assert_eq!(event.action_id.as_deref(), Some(action_id.as_str()));
+            assert_eq!(event.namespace, "test-ns");
+            assert_eq!(event.tenant, "test-tenant");
+            dead_lettered.push((provider, error, attempts));
+        }
+    }
+    assert_eq!(dead_lettered.len(), 1, "{dead_lettered:?}");
+    let (provider, error, attempts) = &dead_lettered[0];
+    assert_eq!(provider, "webhook");
+    assert!(error.contains("connection refused"), "{error}");
+    assert_eq!(*attempts, 3);
+}
+
+#[tokio::test]
+async fn rerouted_action_gets_the_new_providers_defaults() {
+    let harness = SimulationHarness::start(
+        SimulationConfig::builder()
+            .nodes(1)
+            .add_recording_provider("slack")
+            .add_recording_provider("sms")
+            .add_provider_default(
+                "ns",
+                "slack",
+                serde_json::json!({"footer": "Sent via Acteon"}),
+            )
+            .add_provider_default("ns", "sms", serde_json::json!({"sender": "ACTEON"}))
+            .add_rule_yaml(
+                r#"
+rules:
+  - name: page-by-sms
+    condition:
+      field: action.action_type
+      eq: "page"
+    action:
+      type: reroute
+      target_provider: sms
+"#,
+            )
+            .build(),
+    )
+    .await
+    .expect("harness should start");
+
+    let action = Action::new(
+        "ns",
+        "tenant",
+        "slack",
+        "page",
+        serde_json::json!({"text": "db down"}),
+    );
+    let outcome = harness
+        .dispatch(&action)
+        .await
+        .expect("dispatch should succeed");
+    outcome.assert_rerouted();
+
+    let sms = harness.provider("sms").unwrap();
+    let dispatched = sms.last_action().expect("should have recorded action");
+    assert_eq!(dispatched.payload["sender"], "ACTEON");
+    assert!(dispatched.payload.get("footer").is_none());
+    harness.provider("slack").unwrap().assert_not_called();
+
+    harness.teardown().await.expect("teardown should succeed");
+}
+
 #[tokio::test]
 async fn multiple_providers() {
     let harness = SimulationHarness::start(
@@ -0,0 +1,344 @@
+//! End-to-end recurring action tests.
+//!
+//! These tests drive the harness's simulated scheduler clock to fire
+//! recurring occurrences without waiting on wall-clock time. The overlap
+//! tests instead fire occurrences of a slow action concurrently against a
+//! gateway, the way the server's recurring consumer does.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use acteon_core::{
+    Action, ConcurrencyPolicy, OverlapPolicy, RecurringAction, RecurringActionTemplate,
+};
+use acteon_gateway::{Gateway, GatewayBuilder, OccurrenceOutcome};
+use acteon_provider::DynProvider;
+use acteon_simulation::prelude::*;
+use acteon_state::DistributedLock;
+use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+fn hourly_digest(created_at: DateTime<Utc>) -> RecurringAction {
+    RecurringAction {
+        id: "hourly-digest".into(),
+        namespace: "reports".into(),
+        tenant: "acme".into(),
+        cron_expr: "0 * * * *".into(),
+        timezone: "UTC".into(),
+        enabled: true,
+        action_template: RecurringActionTemplate {
+            provider: "email".into(),
+            action_type: "send_digest".into(),
+            payload: serde_json::json!({"to": "ops@example.com"}),
+            metadata: HashMap::new(),
+            dedup_key: None,
+        },
+        created_at,
+        updated_at: created_at,
+        last_executed_at: None,
+        next_execution_at: None,
+        ends_at: None,
+        max_executions: None,
+        execution_count: 0,
+        description: None,
+        labels: HashMap::new(),
+        overlap_policy: OverlapPolicy::default(),
+        concurrency_policy: ConcurrencyPolicy::default(),
+        last_execution_id: None,
+    }
+}
+
+#[tokio::test]
+async fn hourly_recurring_fires_24_times_in_a_simulated_day() {
+    let harness = SimulationHarness::start(
+        SimulationConfig::builder()
+            .nodes(1)
+            .add_recording_provider("email")
+            .build(),
+    )
+    .await
+    .expect("harness should start");
+
+    let start = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+    harness.set_clock(start);
+    harness
+        .add_recurring(hourly_digest(start))
+        .expect("valid recurring action");
+
+    let fired = harness
+        .run_until(start + Duration::hours(24))
+        .await
+        .expect("run should succeed");
+
+    assert_eq!(fired.len(), 24);
+    assert!(fired.iter().all(|o| o.outcome.is_some()));
+    assert_eq!(fired[0].scheduled_for, start + Duration::hours(1));
+    assert_eq!(fired[23].scheduled_for, start + Duration::hours(24));
+    assert_eq!(harness.clock(), start + Duration::hours(24));
+    harness.provider("email").unwrap().assert_called(24);
+
+    // Nothing more fires until the clock moves again.
+    let fired = harness
+        .run_until(start + Duration::hours(24))
+        .await
+        .expect("run should succeed");
+    assert!(fired.is_empty());
+    assert_eq!(harness.fired_occurrences().len(), 24);
+
+    harness.teardown().await.expect("teardown should succeed");
+}
+
+#[tokio::test]
+async fn recurring_stops_at_max_executions() {
+    let harness = SimulationHarness::start(
+        SimulationConfig::builder()
+            .nodes(1)
+            .add_recording_provider("email")
+            .build(),
+    )
+    .await
+    .expect("harness should start");
+
+    let start = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+    harness.set_clock(start);
+    let mut recurring = hourly_digest(start);
+    recurring.max_executions = Some(3);
+    harness
+        .add_recurring(recurring)
+        .expect("valid recurring action");
+
+    harness
+        .run_until(start + Duration::days(7))
+        .await
+        .expect("run should succeed");
+
+    harness.provider("email").unwrap().assert_called(3);
+
+    harness.teardown().await.expect("teardown should succeed");
+}
+
+#[tokio::test]
+async fn recurring_payload_renders_each_occurrence_date() {
+    let harness = SimulationHarness::start(
+        SimulationConfig::builder()
+            .nodes(1)
+            .add_recording_provider("email")
+            .build(),
+    )
+    .await
+    .expect("harness should start");
+
+    let start = Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap();
+    harness.set_clock(start);
+    let mut recurring = hourly_digest(start);
+    recurring.cron_expr = "0 8 * * *".into();
+    recurring.action_template.payload = serde_json::json!({
+        "to": "ops@example.com",
+        "subject": "Digest for {{ exec_date }}",
+    });
+    harness
+        .add_recurring(recurring)
+        .expect("valid recurring action");
+
+    harness
+        .run_until(start + Duration::days(2))
+        .await
+        .expect("run should succeed");
+
+    let subjects: Vec<_> = harness
+        .provider("email")
+        .unwrap()
+        .calls()
+        .iter()
+        .map(|call| call.action.payload["subject"].clone())
+        .collect();
+    assert_eq!(
+        subjects,
+        vec!["Digest for 2026-03-01", "Digest for 2026-03-02"]
+    );
+
+    harness.teardown().await.expect("teardown should succeed");
+}
+
+/// A gateway whose `export` provider takes `delay` per call.
+fn slow_export_gateway(delay: std::time::Duration) -> (Gateway, Arc<RecordingProvider>) {
+    let export = Arc::new(RecordingProvider::new("export").with_delay(delay));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::new(MemoryDistributedLock::new()))
+        .provider(Arc::clone(&export) as Arc<dyn DynProvider>)
+        .build()
+        .expect("gateway should build");
+    (gateway, export)
+}
+
+/// Fire `count` occurrences of `recurring` every `interval`, each in its own
+/// task like the server's recurring consumer, and collect the outcomes.
+async fn fire_overlapping(
+    gateway: &Arc<Gateway>,
+    recurring: &RecurringAction,
+    count: usize,
+    interval: std::time::Duration,
+) -> Vec<OccurrenceOutcome> {
+    let start = Utc::now();
+    let mut tasks = Vec::new();
+    for i in 0..count {
+        let gateway = Arc::clone(gateway);
+        let recurring = recurring.clone();
+        let action = Action::new(
+            recurring.namespace.as_str(),
+            recurring.tenant.as_str(),
+            recurring.action_template.provider.as_str(),
+            recurring.action_template.action_type.as_str(),
+            recurring.render_payload(&(start + Duration::seconds(i64::try_from(i).unwrap()))),
+        );
+        tasks.push(tokio::spawn(async move {
+            gateway
+                .dispatch_recurring_occurrence(
+                    &recurring.namespace,
+                    &recurring.tenant,
+                    &recurring.id,
+                    &recurring,
+                    action,
+                    None,
+                )
+                .await
+                .expect("occurrence should not error")
+        }));
+        tokio::time::sleep(interval).await;
+    }
+    let mut outcomes = Vec::new();
+    for task in tasks {
+        outcomes.push(task.await.unwrap());
+    }
+    outcomes
+}
+
+#[tokio::test]
+async fn overlapping_occurrences_of_a_slow_action_are_skipped() {
+    let (gateway, export) = slow_export_gateway(std::time::Duration::from_millis(400));
+    let gateway = Arc::new(gateway);
+    let mut recurring = hourly_digest(Utc::now());
+    recurring.id = "slow-export".into();
+    recurring.action_template.provider = "export".into();
+    // Skipping overlapping occurrences is the default.
+    assert_eq!(recurring.concurrency_policy, ConcurrencyPolicy::Skip);
+
+    // Five fires 50ms apart against a 400ms action: only the first runs.
+    let outcomes = fire_overlapping(
+        &gateway,
+        &recurring,
+        5,
+        std::time::Duration::from_millis(50),
+    )
+    .await;
+
+    let dispatched = outcomes
+        .iter()
+        .filter(|o| matches!(o, OccurrenceOutcome::Dispatched(_)))
+        .count();
+    assert_eq!(dispatched, 1);
+    assert!(matches!(outcomes[0], OccurrenceOutcome::Dispatched(_)));
+    export.assert_called(1);
+    assert_eq!(gateway.metrics().snapshot().recurring_skipped, 4);
+}
+
+#[tokio::test]
+async fn queued_occurrences_run_one_after_another() {
+    let delay = std::time::Duration::from_millis(200);
+    let (gateway, export) = slow_export_gateway(delay);
+    let gateway = Arc::new(gateway);
+    let mut recurring = hourly_digest(Utc::now());
+    recurring.id = "slow-export".into();
+    recurring.action_template.provider = "export".into();
+    recurring.concurrency_policy = ConcurrencyPolicy::Queue;
+
+    let outcomes = fire_overlapping(
+        &gateway,
+        &recurring,
+        3,
+        std::time::Duration::from_millis(20),
+    )
+    .await;
+
+    assert!(
+        outcomes
+            .iter()
+            .all(|o| matches!(o, OccurrenceOutcome::Dispatched(_)))
+    );
+    export.assert_called(3);
+    // The calls never overlapped: each started after the previous finished.
+    let calls = export.calls();
+    for pair in calls.windows(2) {
+        let gap = (pair[1].timestamp - pair[0].timestamp)
+            .to_std()
+            .unwrap_or_default();
+        assert!(gap >= delay, "occurrences overlapped: {gap:?}");
+    }
+}
+
+#[tokio::test]
+async fn queued_occurrence_does_not_block_gateway_writers() {
+    let lock = Arc::new(MemoryDistributedLock::new());
+    let export = Arc::new(RecordingProvider::new("export"));
+    let gateway = GatewayBuilder::new()
+        .state(Arc::new(MemoryStateStore::new()))
+        .lock(Arc::clone(&lock) as Arc<dyn DistributedLock>)
+        .provider(Arc::clone(&export) as Arc<dyn DynProvider>)
+        .build()
+        .expect("gateway should build");
+    let gateway = Arc::new(tokio::sync::RwLock::new(gateway));
+    let mut recurring = hourly_digest(Utc::now());
+    recurring.id = "slow-export".into();
+    recurring.action_template.provider = "export".into();
+    recurring.concurrency_policy = ConcurrencyPolicy::Queue;
+
+    // The previous occurrence is still dispatching on another instance.
+    let held = lock
+        .try_acquire(
+            "recurring-occurrence:reports:acme:slow-export",
+            std::time::Duration::from_secs(60),
+        )
+        .await
+        .unwrap()
+        .expect("lock should be free");
+
+    let queued = {
+        let gateway = Arc::clone(&gateway);
+        let recurring = recurring.clone();
+        tokio::spawn(async move {
+            let action = Action::new(
+                "reports",
+                "acme",
+                "export",
+                "send_digest",
+                serde_json::json!({}),
+            );
+            acteon_gateway::dispatch_shared_recurring_occurrence(
+                &gateway,
+                "reports",
+                "acme",
+                "slow-export",
+                &recurring,
+                action,
+                None,
+            )
+            .await
+            .expect("occurrence should not error")
+        })
+    };
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    // A rule reload takes the write lock while the occurrence is queued.
+    let writer = tokio::time::timeout(std::time::Duration::from_secs(1), gateway.write())
+        .await
+        .expect("writer should not wait on a queued occurrence");
+    drop(writer);
+
+    held.release().await.unwrap();
+    assert!(matches!(
+        queued.await.unwrap(),
+        OccurrenceOutcome::Dispatched(_)
+    ));
+    export.assert_called(1);
+}
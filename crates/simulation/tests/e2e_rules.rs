@@ -3,7 +3,7 @@
 //! These tests verify that different rule types work correctly in
 //! an end-to-end simulation environment.
 
-use acteon_core::Action;
+use acteon_core::{Action, ActionOutcome};
 use acteon_simulation::prelude::*;
 
 // -- Rule Fixtures --
@@ -196,6 +196,83 @@ mod rerouting {
 
         harness.teardown().await.unwrap();
     }
+
+    const ESCALATION_RULE: &str = r#"
+rules:
+  - name: escalate-critical
+    priority: 1
+    condition:
+      field: action.payload.severity
+      eq: "critical"
+    action:
+      type: reroute
+      target_namespace: ops
+      target_tenant: central
+"#;
+
+    #[tokio::test]
+    async fn critical_action_is_escalated_to_ops_tenant() {
+        let harness = SimulationHarness::start(
+            SimulationConfig::builder()
+                .nodes(1)
+                .add_recording_provider("email")
+                .add_rule_yaml(ESCALATION_RULE)
+                .build(),
+        )
+        .await
+        .expect("harness should start");
+
+        let action = Action::new(
+            "alerts",
+            "acme",
+            "email",
+            "send_alert",
+            serde_json::json!({"severity": "critical"}),
+        );
+        let outcome = harness.dispatch(&action).await.expect("dispatch");
+        outcome.assert_rerouted();
+
+        // Delivered through the same provider, under the ops tenant.
+        let email = harness.provider("email").unwrap();
+        email.assert_called(1);
+        let delivered = email.last_action().expect("delivered action");
+        assert_eq!(delivered.namespace.as_str(), "ops");
+        assert_eq!(delivered.tenant.as_str(), "central");
+        assert_eq!(
+            delivered.metadata.labels["rerouted_from_namespace"],
+            "alerts"
+        );
+        assert_eq!(delivered.metadata.labels["rerouted_from_tenant"], "acme");
+
+        // The audit record is filed under the rerouted scope and keeps the
+        // original one.
+        let audit = harness
+            .node(0)
+            .unwrap()
+            .gateway()
+            .audit_store()
+            .expect("audit store");
+        let mut record = None;
+        for _ in 0..50 {
+            record = audit
+                .get_by_action_id(action.id.as_str())
+                .await
+                .expect("audit lookup");
+            if record.is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let record = record.expect("audit record");
+        assert_eq!(record.namespace, "ops");
+        assert_eq!(record.tenant, "central");
+        assert_eq!(record.verdict, "reroute");
+        assert_eq!(record.matched_rule.as_deref(), Some("escalate-critical"));
+        assert_eq!(record.metadata["rerouted_from_namespace"], "alerts");
+        assert_eq!(record.metadata["rerouted_from_tenant"], "acme");
+
+        harness.teardown().await.unwrap();
+    }
 }
 
 // -- Deduplication Tests --
@@ -284,6 +361,64 @@ mod deduplication {
 
         harness.teardown().await.unwrap();
     }
+
+    /// Dispatch a heartbeat every 50 simulated seconds under a 60-second
+    /// dedup window and return the outcomes.
+    async fn heartbeat_outcomes(window: &str) -> Vec<ActionOutcome> {
+        let rule = format!(
+            r#"
+rules:
+  - name: dedup-heartbeat
+    condition:
+      field: action.action_type
+      eq: "heartbeat"
+    action:
+      type: deduplicate
+      ttl_seconds: 60
+      window: {window}
+"#
+        );
+        let harness = SimulationHarness::start(
+            SimulationConfig::builder()
+                .nodes(1)
+                .add_recording_provider("email")
+                .add_rule_yaml(rule)
+                .build(),
+        )
+        .await
+        .expect("harness should start");
+
+        let start = harness.clock();
+        let mut outcomes = Vec::new();
+        for step in 0..3 {
+            harness.set_clock(start + chrono::Duration::seconds(50 * step));
+            let action = Action::new("ns", "tenant", "email", "heartbeat", serde_json::json!({}))
+                .with_dedup_key("host-1");
+            outcomes.push(harness.dispatch(&action).await.expect("dispatch"));
+        }
+
+        harness.teardown().await.unwrap();
+        outcomes
+    }
+
+    #[tokio::test]
+    async fn fixed_window_lets_steady_stream_through_at_boundary() {
+        let outcomes = heartbeat_outcomes("fixed").await;
+        outcomes[0].assert_executed();
+        outcomes[1].assert_deduplicated();
+        // 100s after the window opened: the fixed window has expired even
+        // though the previous heartbeat was only 50s ago.
+        outcomes[2].assert_executed();
+    }
+
+    #[tokio::test]
+    async fn sliding_window_dedupes_steady_stream() {
+        let outcomes = heartbeat_outcomes("sliding").await;
+        outcomes[0].assert_executed();
+        outcomes[1].assert_deduplicated();
+        // The previous heartbeat was 50s ago, inside the trailing window.
+        outcomes[2].assert_deduplicated();
+    }
 }
 
 // -- Throttling Tests --
@@ -655,13 +790,114 @@ rules:
 
         harness.teardown().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn group_flush_renders_digest_over_member_events() {
+        use std::collections::HashMap;
+        use std::sync::Arc;
+        use std::time::Duration;
+
+        use acteon_gateway::{BackgroundConfig, BackgroundProcessorBuilder, GatewayBuilder};
+        use acteon_provider::DynProvider;
+        use acteon_rules::RuleFrontend;
+        use acteon_rules_yaml::YamlFrontend;
+        use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+
+        const DIGEST_RULE: &str = r#"
+rules:
+  - name: disk-digest
+    priority: 10
+    condition:
+      field: action.action_type
+      eq: "disk_alert"
+    action:
+      type: group
+      group_by:
+        - tenant
+      group_wait_seconds: 0
+      max_group_size: 100
+      template: disk-digest
+"#;
+
+        let now = chrono::Utc::now();
+        let template = acteon_core::Template {
+            id: "tpl-disk-digest".into(),
+            name: "disk-digest".into(),
+            namespace: "ops".into(),
+            tenant: "acme".into(),
+            content: "{{ count }} disk alerts on {{ distinct(\"host\") | length }} hosts: \
+                      {{ distinct(\"host\") | join(\", \") }}"
+                .into(),
+            description: None,
+            created_at: now,
+            updated_at: now,
+            labels: HashMap::new(),
+            deleted_at: None,
+        };
+
+        let state = Arc::new(MemoryStateStore::new());
+        let lock = Arc::new(MemoryDistributedLock::new());
+        let pager = Arc::new(RecordingProvider::new("pager"));
+        let gateway = GatewayBuilder::new()
+            .state(Arc::clone(&state) as Arc<dyn acteon_state::StateStore>)
+            .lock(Arc::clone(&lock) as Arc<dyn acteon_state::DistributedLock>)
+            .provider(Arc::clone(&pager) as Arc<dyn DynProvider>)
+            .rules(YamlFrontend.parse(DIGEST_RULE).unwrap())
+            .template(template)
+            .build()
+            .expect("gateway should build");
+
+        for host in ["db-1", "db-2", "db-1", "web-1", "db-2"] {
+            let action = Action::new(
+                "ops",
+                "acme",
+                "pager",
+                "disk_alert",
+                serde_json::json!({"host": host, "usage": 97}),
+            );
+            gateway
+                .dispatch(action, None)
+                .await
+                .expect("dispatch")
+                .assert_grouped();
+        }
+        pager.assert_not_called();
+
+        let (flush_tx, mut flush_rx) = tokio::sync::mpsc::channel(4);
+        let (mut processor, shutdown_tx) = BackgroundProcessorBuilder::new()
+            .config(BackgroundConfig {
+                group_flush_interval: Duration::from_millis(20),
+                ..BackgroundConfig::default()
+            })
+            .group_manager(gateway.group_manager())
+            .state(state)
+            .lock(lock)
+            .metrics(gateway.metrics_arc())
+            .group_flush_channel(flush_tx)
+            .build()
+            .expect("processor should build");
+        let worker = tokio::spawn(async move { processor.run().await });
+
+        let flushed = tokio::time::timeout(Duration::from_secs(5), flush_rx.recv())
+            .await
+            .expect("group should flush")
+            .expect("flush channel open");
+        let _ = shutdown_tx.send(()).await;
+        let _ = worker.await;
+
+        assert_eq!(flushed.group.size(), 5);
+        assert_eq!(
+            flushed.digest.as_deref(),
+            Some("5 disk alerts on 3 hosts: db-1, db-2, web-1")
+        );
+    }
 }
 
 // -- State Machine Tests --
 
 mod state_machine {
     use super::*;
-    use acteon_core::{StateMachineConfig, TransitionConfig};
+    use acteon_core::{Caller, StateMachineConfig, TransitionConfig, TransitionTrigger};
 
     const TICKET_STATE_MACHINE_RULE: &str = r#"
 rules:
@@ -778,6 +1014,304 @@ rules:
         harness.teardown().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn state_machine_records_ordered_history() {
+        let harness = SimulationHarness::start(
+            SimulationConfig::builder()
+                .nodes(1)
+                .add_recording_provider("slack")
+                .add_rule_yaml(TICKET_STATE_MACHINE_RULE)
+                .add_state_machine(ticket_state_machine())
+                .build(),
+        )
+        .await
+        .expect("harness should start");
+        let gateway = harness.node(0).unwrap().gateway();
+
+        let ticket = |status: &str| {
+            Action::new(
+                "support",
+                "acme",
+                "slack",
+                "ticket",
+                serde_json::json!({"ticket_id": "TKT-003", "subject": "Outage"}),
+            )
+            .with_status(status)
+            .with_fingerprint("ticket:TKT-003")
+        };
+        let alice = Caller {
+            id: "alice".into(),
+            auth_method: "api_key".into(),
+        };
+
+        // Creating the event in its initial state is not a transition.
+        harness.dispatch(&ticket("open")).await.expect("open");
+        assert!(
+            gateway
+                .event_history("support", "acme", "ticket:TKT-003")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        gateway
+            .dispatch(ticket("in_progress"), Some(&alice))
+            .await
+            .expect("in_progress")
+            .assert_state_changed();
+        harness.dispatch(&ticket("closed")).await.expect("closed");
+        // closed -> open is not an allowed transition and must not be recorded.
+        harness.dispatch(&ticket("open")).await.expect("reopen");
+
+        let history = gateway
+            .event_history("support", "acme", "ticket:TKT-003")
+            .await
+            .unwrap();
+        let steps: Vec<_> = history
+            .iter()
+            .map(|t| (t.from.as_str(), t.to.as_str(), t.actor.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                ("open", "in_progress", "alice"),
+                ("in_progress", "closed", "anonymous"),
+            ]
+        );
+        assert!(
+            history
+                .iter()
+                .all(|t| t.trigger == TransitionTrigger::Dispatch)
+        );
+        assert!(history[0].at <= history[1].at);
+
+        // Manual transitions write the state and history under the same lock.
+        let previous = gateway
+            .transition_event("support", "acme", "ticket:TKT-003", "open", "bob")
+            .await
+            .unwrap();
+        assert_eq!(previous.as_deref(), Some("closed"));
+        let history = gateway
+            .event_history("support", "acme", "ticket:TKT-003")
+            .await
+            .unwrap();
+        let last = history.last().unwrap();
+        assert_eq!((last.from.as_str(), last.to.as_str()), ("closed", "open"));
+        assert_eq!(last.actor, "bob");
+        assert_eq!(last.trigger, TransitionTrigger::Manual);
+        assert!(
+            gateway
+                .transition_event("support", "acme", "ticket:missing", "open", "bob")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        harness.teardown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn provider_response_resolves_event() {
+        use std::sync::Arc;
+
+        use acteon_core::{ProviderResponse, ResponseTransition};
+        use acteon_gateway::GatewayBuilder;
+        use acteon_provider::DynProvider;
+        use acteon_rules::RuleFrontend;
+        use acteon_rules_yaml::YamlFrontend;
+        use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+
+        let slack = Arc::new(RecordingProvider::new("slack"));
+        let jira = Arc::new(RecordingProvider::new("jira").with_response_fn(|_| {
+            Ok(ProviderResponse::success(
+                serde_json::json!({"issue": {"key": "OPS-1", "status": "closed"}}),
+            ))
+        }));
+        let gateway = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::clone(&slack) as Arc<dyn DynProvider>)
+            .provider(Arc::clone(&jira) as Arc<dyn DynProvider>)
+            .rules(YamlFrontend.parse(TICKET_STATE_MACHINE_RULE).unwrap())
+            .state_machine(
+                StateMachineConfig::new("ticket", "open")
+                    .with_state("resolved")
+                    .with_transition(TransitionConfig::new("open", "resolved"))
+                    .with_response_transition(
+                        ResponseTransition::new("issue.status", "closed", "resolved")
+                            .for_provider("jira"),
+                    ),
+            )
+            .build()
+            .expect("gateway should build");
+
+        let opened = Action::new(
+            "support",
+            "acme",
+            "slack",
+            "ticket",
+            serde_json::json!({"ticket_id": "TKT-004"}),
+        )
+        .with_fingerprint("ticket:TKT-004");
+        gateway
+            .dispatch(opened, None)
+            .await
+            .expect("open")
+            .assert_state_changed();
+
+        // Syncing the ticket to Jira is not a state machine action, but the
+        // closed status in Jira's response resolves the event.
+        let sync = Action::new(
+            "support",
+            "acme",
+            "jira",
+            "ticket_sync",
+            serde_json::json!({"ticket_id": "TKT-004"}),
+        )
+        .with_fingerprint("ticket:TKT-004");
+        let outcome = gateway.dispatch(sync, None).await.expect("sync");
+        assert!(matches!(outcome, ActionOutcome::Executed(_)));
+        jira.assert_called(1);
+
+        let history = gateway
+            .event_history("support", "acme", "ticket:TKT-004")
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].from, "open");
+        assert_eq!(history[0].to, "resolved");
+        assert_eq!(history[0].trigger, TransitionTrigger::ProviderResponse);
+
+        // resolved -> resolved is a no-op, so a second sync records nothing.
+        let sync = Action::new(
+            "support",
+            "acme",
+            "jira",
+            "ticket_sync",
+            serde_json::json!({"ticket_id": "TKT-004"}),
+        )
+        .with_fingerprint("ticket:TKT-004");
+        gateway.dispatch(sync, None).await.expect("second sync");
+        let history = gateway
+            .event_history("support", "acme", "ticket:TKT-004")
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn v1_fingerprint_matches_during_dual_read_window() {
+        use std::sync::Arc;
+
+        use acteon_core::{FingerprintConfig, FingerprintVersion, compute_fingerprint_versioned};
+        use acteon_gateway::GatewayBuilder;
+        use acteon_provider::DynProvider;
+        use acteon_rules::RuleFrontend;
+        use acteon_rules_yaml::YamlFrontend;
+        use acteon_state::{KeyKind, StateKey, StateStore};
+        use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+
+        let state = Arc::new(MemoryStateStore::new());
+        let gateway = |config: FingerprintConfig| {
+            GatewayBuilder::new()
+                .state(Arc::clone(&state) as Arc<dyn StateStore>)
+                .lock(Arc::new(MemoryDistributedLock::new()))
+                .provider(Arc::new(RecordingProvider::new("slack")) as Arc<dyn DynProvider>)
+                .rules(YamlFrontend.parse(TICKET_STATE_MACHINE_RULE).unwrap())
+                .state_machine(ticket_state_machine())
+                .fingerprint_config(config)
+                .build()
+                .expect("gateway should build")
+        };
+        let ticket = |id: &str, status: &str| {
+            Action::new(
+                "support",
+                "acme",
+                "slack",
+                "ticket",
+                serde_json::json!({"ticket_id": id}),
+            )
+            .with_status(status)
+        };
+        let fields = ["action_type".to_string(), "payload.ticket_id".to_string()];
+        let v1_fp = compute_fingerprint_versioned(
+            &ticket("TKT-010", "open"),
+            &fields,
+            FingerprintVersion::V1,
+        );
+        let stored_version = |fingerprint: String| {
+            let state = Arc::clone(&state);
+            async move {
+                let key = StateKey::new("support", "acme", KeyKind::EventState, &fingerprint);
+                let raw = state.get(&key).await.unwrap().expect("event state");
+                let value: serde_json::Value = serde_json::from_str(&raw).unwrap();
+                value["fingerprint_version"].clone()
+            }
+        };
+
+        // The event starts under v1.
+        let v1 = gateway(FingerprintConfig::default());
+        assert_eq!(v1.fingerprint_version(), FingerprintVersion::V1);
+        v1.dispatch(ticket("TKT-010", "in_progress"), None)
+            .await
+            .expect("start")
+            .assert_state_changed();
+        assert_eq!(stored_version(v1_fp.clone()).await, "v1");
+
+        // After upgrading to v2 with v1 dual-read, the event keeps its state.
+        let migrating = gateway(
+            FingerprintConfig::new(FingerprintVersion::V2).with_dual_read(FingerprintVersion::V1),
+        );
+        assert_eq!(migrating.fingerprint_version(), FingerprintVersion::V2);
+        let outcome = migrating
+            .dispatch(ticket("TKT-010", "closed"), None)
+            .await
+            .expect("close");
+        match outcome {
+            ActionOutcome::StateChanged {
+                fingerprint,
+                previous_state,
+                new_state,
+                ..
+            } => {
+                assert_eq!(fingerprint, v1_fp);
+                assert_eq!(previous_state, "in_progress");
+                assert_eq!(new_state, "closed");
+            }
+            other => panic!("expected StateChanged, got {other:?}"),
+        }
+
+        // New events are fingerprinted with v2.
+        let outcome = migrating
+            .dispatch(ticket("TKT-011", "in_progress"), None)
+            .await
+            .expect("new event");
+        let v2_fp = compute_fingerprint_versioned(
+            &ticket("TKT-011", "open"),
+            &fields,
+            FingerprintVersion::V2,
+        );
+        assert!(
+            matches!(&outcome, ActionOutcome::StateChanged { fingerprint, .. } if *fingerprint == v2_fp),
+            "got {outcome:?}"
+        );
+        assert_eq!(stored_version(v2_fp).await, "v2");
+
+        // Without the dual-read window the v1 event would fragment.
+        let upgraded = gateway(FingerprintConfig::new(FingerprintVersion::V2));
+        v1.dispatch(ticket("TKT-012", "in_progress"), None)
+            .await
+            .expect("start");
+        let outcome = upgraded
+            .dispatch(ticket("TKT-012", "closed"), None)
+            .await
+            .expect("close");
+        assert!(
+            matches!(&outcome, ActionOutcome::StateChanged { previous_state, .. } if previous_state == "open"),
+            "got {outcome:?}"
+        );
+    }
+
     #[tokio::test]
     async fn non_matching_action_bypasses_state_machine() {
         let harness = SimulationHarness::start(
@@ -880,3 +1414,321 @@ rules:
         harness.teardown().await.unwrap();
     }
 }
+
+// -- Follow-on Action Tests --
+
+mod follow_on {
+    use super::*;
+
+    const FOLLOW_ON_RULE: &str = r#"
+rules:
+  - name: order-placed
+    priority: 1
+    condition:
+      field: action.action_type
+      eq: "place_order"
+    action:
+      type: allow
+    on_success:
+      provider: slack
+      action_type: post_message
+      payload:
+        text: "order {{origin.payload.order_id}} placed"
+"#;
+
+    #[tokio::test]
+    async fn follow_on_dispatched_after_success_and_linked_in_audit() {
+        let harness = SimulationHarness::start(
+            SimulationConfig::builder()
+                .nodes(1)
+                .add_recording_provider("email")
+                .add_recording_provider("slack")
+                .add_rule_yaml(FOLLOW_ON_RULE)
+                .build(),
+        )
+        .await
+        .expect("harness should start");
+
+        let action = Action::new(
+            "ns",
+            "tenant",
+            "email",
+            "place_order",
+            serde_json::json!({"order_id": "o-42"}),
+        );
+        let outcome = harness.dispatch(&action).await.expect("dispatch");
+        outcome.assert_executed();
+
+        harness.provider("email").unwrap().assert_called(1);
+        // Follow-ons are queued, so wait for the background dispatch.
+        let slack = harness.provider("slack").unwrap();
+        for _ in 0..50 {
+            if slack.call_count() > 0 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        slack.assert_called(1);
+        let follow_on = slack.last_action().expect("follow-on action");
+        assert_eq!(follow_on.action_type, "post_message");
+        assert_eq!(follow_on.payload["text"], "order o-42 placed");
+        assert_eq!(
+            follow_on.metadata.labels.get("caused_by"),
+            Some(&action.id.to_string())
+        );
+
+        // The follow-on's audit record carries the causal link.
+        let audit = harness
+            .node(0)
+            .unwrap()
+            .gateway()
+            .audit_store()
+            .expect("audit store");
+        let mut record = None;
+        for _ in 0..50 {
+            record = audit
+                .get_by_action_id(follow_on.id.as_str())
+                .await
+                .expect("audit lookup");
+            if record.is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let record = record.expect("follow-on audit record");
+        assert_eq!(record.metadata["caused_by"], action.id.to_string());
+        assert_eq!(record.metadata["caused_by_rule"], "order-placed");
+
+        harness.teardown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn follow_on_not_dispatched_when_primary_suppressed() {
+        let rules = r#"
+rules:
+  - name: block
+    priority: 1
+    condition:
+      field: action.action_type
+      eq: "place_order"
+    action:
+      type: suppress
+    on_success:
+      provider: slack
+      action_type: post_message
+"#;
+        let harness = SimulationHarness::start(
+            SimulationConfig::builder()
+                .nodes(1)
+                .add_recording_provider("email")
+                .add_recording_provider("slack")
+                .add_rule_yaml(rules)
+                .build(),
+        )
+        .await
+        .expect("harness should start");
+
+        let action = Action::new(
+            "ns",
+            "tenant",
+            "email",
+            "place_order",
+            serde_json::json!({}),
+        );
+        harness.dispatch(&action).await.expect("dispatch");
+
+        // Give a (wrongly) queued follow-on time to run.
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        harness.provider("slack").unwrap().assert_not_called();
+        harness.teardown().await.unwrap();
+    }
+}
+
+// -- Approval Tests --
+
+mod approval {
+    use super::*;
+
+    const APPROVAL_RULE: &str = r#"
+rules:
+  - name: approve-large-refunds
+    priority: 1
+    condition:
+      all:
+        - field: action.action_type
+          eq: "process_refund"
+        - field: action.payload.amount
+          gt: 1000
+    action:
+      type: request_approval
+      notify_provider: slack
+      timeout_seconds: 3600
+      message: "Refund over $1000 requires approval"
+"#;
+
+    #[tokio::test]
+    async fn approval_rule_returns_pending_approval_and_audits_it() {
+        let harness = SimulationHarness::start(
+            SimulationConfig::builder()
+                .nodes(1)
+                .add_recording_provider("payments")
+                .add_recording_provider("slack")
+                .add_rule_yaml(APPROVAL_RULE)
+                .build(),
+        )
+        .await
+        .expect("harness should start");
+
+        let action = Action::new(
+            "billing",
+            "tenant",
+            "payments",
+            "process_refund",
+            serde_json::json!({"amount": 5000}),
+        );
+        let before = chrono::Utc::now();
+        let outcome = harness.dispatch(&action).await.expect("dispatch");
+        outcome.assert_pending_approval();
+        assert!(!outcome.is_executed());
+
+        let acteon_core::ActionOutcome::PendingApproval {
+            approval_id,
+            expires_at,
+            ..
+        } = &outcome
+        else {
+            unreachable!("asserted above");
+        };
+        assert!(!approval_id.is_empty());
+        assert!(*expires_at >= before + chrono::Duration::seconds(3600));
+
+        // The action is held, not executed; the approver is notified instead.
+        harness.provider("payments").unwrap().assert_not_called();
+        harness.provider("slack").unwrap().assert_called(1);
+
+        let audit = harness
+            .node(0)
+            .unwrap()
+            .gateway()
+            .audit_store()
+            .expect("audit store");
+        let mut record = None;
+        for _ in 0..50 {
+            record = audit
+                .get_by_action_id(action.id.as_str())
+                .await
+                .expect("audit lookup");
+            if record.is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let record = record.expect("audit record");
+        assert_eq!(record.outcome, "pending_approval");
+        assert_eq!(record.outcome_details["approval_id"], approval_id.as_str());
+
+        harness.teardown().await.unwrap();
+    }
+}
+
+// -- Enrichment Tests --
+
+mod enrichment {
+    use std::sync::Arc;
+
+    use acteon_core::ActionOutcome;
+    use acteon_core::enrichment::{EnrichmentConfig, EnrichmentFailurePolicy};
+    use acteon_gateway::GatewayBuilder;
+    use acteon_provider::{DynProvider, ProviderError, ResourceLookup};
+    use acteon_rules::RuleFrontend;
+    use acteon_rules_yaml::YamlFrontend;
+    use acteon_state_memory::{MemoryDistributedLock, MemoryStateStore};
+    use async_trait::async_trait;
+
+    use super::*;
+
+    const GEO_BLOCK_RULE: &str = r#"
+rules:
+  - name: geo-block
+    priority: 1
+    condition:
+      field: enrichment.geoip.country
+      in_list: ["KP", "IR"]
+    action:
+      type: deny
+"#;
+
+    /// Resolves every IP to a fixed country.
+    struct MockGeoIp {
+        country: &'static str,
+    }
+
+    #[async_trait]
+    impl ResourceLookup for MockGeoIp {
+        async fn lookup(
+            &self,
+            _resource_type: &str,
+            _params: &serde_json::Value,
+        ) -> Result<serde_json::Value, ProviderError> {
+            Ok(serde_json::json!({"country": self.country}))
+        }
+
+        fn supported_resource_types(&self) -> Vec<String> {
+            vec!["ip".to_owned()]
+        }
+    }
+
+    async fn dispatch_from(country: &'static str) -> (ActionOutcome, Arc<RecordingProvider>) {
+        let email = Arc::new(RecordingProvider::new("email"));
+        let gateway = GatewayBuilder::new()
+            .state(Arc::new(MemoryStateStore::new()))
+            .lock(Arc::new(MemoryDistributedLock::new()))
+            .provider(Arc::clone(&email) as Arc<dyn DynProvider>)
+            .rules(YamlFrontend.parse(GEO_BLOCK_RULE).unwrap())
+            .resource_lookup("geo", Arc::new(MockGeoIp { country }))
+            .enrichment(EnrichmentConfig {
+                name: "geoip".into(),
+                namespace: None,
+                tenant: None,
+                action_type: None,
+                provider: None,
+                lookup_provider: "geo".into(),
+                resource_type: "ip".into(),
+                params: serde_json::json!({"ip": "{{payload.client_ip}}"}),
+                merge_key: "geo".into(),
+                timeout_seconds: 5,
+                failure_policy: EnrichmentFailurePolicy::FailClosed,
+            })
+            .build()
+            .expect("gateway should build");
+
+        let action = Action::new(
+            "ns",
+            "tenant",
+            "email",
+            "send_email",
+            serde_json::json!({"client_ip": "203.0.113.7"}),
+        );
+        let outcome = gateway.dispatch(action, None).await.expect("dispatch");
+        (outcome, email)
+    }
+
+    #[tokio::test]
+    async fn rule_denies_on_enriched_field() {
+        let (outcome, email) = dispatch_from("KP").await;
+
+        match outcome {
+            ActionOutcome::Suppressed { rule } => assert_eq!(rule, "geo-block"),
+            other => panic!("expected geo-block denial, got {other:?}"),
+        }
+        email.assert_not_called();
+    }
+
+    #[tokio::test]
+    async fn rule_allows_when_enriched_field_does_not_match() {
+        let (outcome, email) = dispatch_from("FR").await;
+
+        outcome.assert_executed();
+        email.assert_called(1);
+    }
+}
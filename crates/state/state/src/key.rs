@@ -16,6 +16,8 @@ pub enum KeyKind {
     EventState,
     /// Event timeout tracking.
     EventTimeout,
+    /// Bounded transition history for a state machine event.
+    EventHistory,
     /// Event group data.
     Group,
     /// Index of pending groups awaiting flush.
@@ -87,6 +89,10 @@ pub enum KeyKind {
     /// `{task_id}:{entry_id}` so a prefix-scan by task id lists
     /// every failed delivery for one task.
     A2aPushDlq,
+    /// Per-tenant feature flags (one record per namespace + tenant).
+    FeatureFlags,
+    /// Legal hold on one action's audit records, keyed by action ID.
+    AuditHold,
     Custom(String),
 }
 
@@ -103,6 +109,7 @@ impl KeyKind {
             Self::RateLimit => "rate_limit",
             Self::EventState => "event_state",
             Self::EventTimeout => "event_timeout",
+            Self::EventHistory => "event_history",
             Self::Group => "group",
             Self::PendingGroups => "pending_groups",
             Self::ActiveEvents => "active_events",
@@ -135,6 +142,8 @@ impl KeyKind {
             Self::BusAgentCard => "bus_agent_card",
             Self::A2aTaskPushConfig => "a2a_task_push_config",
             Self::A2aPushDlq => "a2a_push_dlq",
+            Self::FeatureFlags => "feature_flags",
+            Self::AuditHold => "audit_hold",
             Self::Custom(s) => s.as_str(),
         }
     }
@@ -213,6 +222,7 @@ mod tests {
         assert_eq!(KeyKind::RateLimit.as_str(), "rate_limit");
         assert_eq!(KeyKind::EventState.as_str(), "event_state");
         assert_eq!(KeyKind::EventTimeout.as_str(), "event_timeout");
+        assert_eq!(KeyKind::EventHistory.as_str(), "event_history");
         assert_eq!(KeyKind::Group.as_str(), "group");
         assert_eq!(KeyKind::PendingGroups.as_str(), "pending_groups");
         assert_eq!(KeyKind::ActiveEvents.as_str(), "active_events");
@@ -24,6 +24,7 @@ const SSE_EVENT_TYPES = [
   'group_event_added',
   'group_resolved',
   'approval_resolved',
+  'dlq_entry_added',
 ] as const
 
 export function useStream({ namespace, tenant, action_type, event_type, onEvent, enabled = true }: StreamOptions) {
@@ -34,6 +34,7 @@ const SSE_EVENT_TYPES = [
   'group_event_added',
   'group_resolved',
   'approval_resolved',
+  'dlq_entry_added',
 ] as const
 
 export const useEventStore = create<EventState>((set) => ({
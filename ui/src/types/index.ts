@@ -403,6 +403,8 @@ export interface CircuitBreakerStatus {
 export interface ProviderHealthStatus {
   provider: string
   healthy: boolean
+  consecutive_failures: number
+  consecutive_successes: number
   health_check_error?: string
   circuit_breaker_state?: string
   total_requests: number
@@ -750,6 +752,8 @@ export interface ConfigResponse {
     prefix: string | null
     region: string | null
     table_name: string | null
+    fingerprint_version: 'v1' | 'v2'
+    fingerprint_dual_read_version: 'v1' | 'v2' | null
   }
   executor: {
     max_retries: number | null
@@ -985,12 +989,15 @@ export interface Template {
   labels: Record<string, string>
 }
 
+export type TemplateOutputType = 'html' | 'mrkdwn' | 'plain'
+
 export interface TemplateProfile {
   id: string
   name: string
   namespace: string
   tenant: string
   fields: Record<string, TemplateProfileField>
+  output_type?: TemplateOutputType | null
   description: string | null
   created_at: string
   updated_at: string
@@ -1031,12 +1038,14 @@ export interface CreateProfileRequest {
   namespace: string
   tenant: string
   fields: Record<string, TemplateProfileField>
+  output_type?: TemplateOutputType | null
   description?: string | null
   labels?: Record<string, string>
 }
 
 export interface UpdateProfileRequest {
   fields?: Record<string, TemplateProfileField>
+  output_type?: TemplateOutputType
   description?: string | null
   labels?: Record<string, string>
 }
@@ -1070,6 +1079,7 @@ export interface ComplianceStatus {
   sync_audit_writes: boolean
   immutable_audit: boolean
   hash_chain: boolean
+  forbid_encryption_opt_out: boolean
 }
 
 export interface HashChainVerification {